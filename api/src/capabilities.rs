@@ -18,8 +18,35 @@ bitflags::bitflags! {
     pub struct Capabilities : u128 {
         // Represents base capabilities present in Caliptra ROM v1.0
         const ROM_BASE = 0b1;
+        // ROM supports the GET_IDEV_CSR command
+        const ROM_GET_IDEV_CSR = 0b1 << 1;
+
         // Represents base capabilities present in Caliptra Runtime v1.0
         const RT_BASE = 0b1 << 64;
+        // Runtime supports the LMS_VERIFY command
+        const RT_LMS_VERIFY = 0b1 << 65;
+        // Runtime supports the SET_AUTH_MANIFEST command
+        const RT_SET_AUTH_MANIFEST = 0b1 << 66;
+        // Runtime supports the AUTHORIZE_AND_STASH command
+        const RT_AUTHORIZE_AND_STASH = 0b1 << 67;
+        // AUTHORIZE_AND_STASH accepts the ShaAcc image hash source
+        const RT_AUTHORIZE_AND_STASH_SHA_ACC = 0b1 << 68;
+        // Authorization manifest image metadata entries carry an anti-rollback SVN
+        const RT_AUTH_MANIFEST_IMAGE_SVN = 0b1 << 69;
+        // Runtime supports the GET_IDEV_CSR command
+        const RT_GET_IDEV_CSR = 0b1 << 70;
+        // Runtime supports the GET_FMC_ALIAS_CSR command
+        const RT_GET_FMC_ALIAS_CSR = 0b1 << 71;
+        // Runtime supports the SIGN_WITH_EXPORTED_ECDSA command
+        const RT_SIGN_WITH_EXPORTED_ECDSA = 0b1 << 72;
+        // Runtime supports the REVOKE_EXPORTED_CDI_HANDLE command
+        const RT_REVOKE_EXPORTED_CDI_HANDLE = 0b1 << 73;
+        // Runtime supports the SELF_TEST_START and SELF_TEST_GET_RESULTS commands
+        const RT_FIPS_SELF_TEST = 0b1 << 74;
+        // Runtime supports the GET_PCR_LOG, GET_MEASUREMENT_LOG and GET_FUSE_LOG commands
+        const RT_GET_LOGS = 0b1 << 75;
+        // Runtime supports the GET_IMAGE_DATA command
+        const RT_GET_IMAGE_DATA = 0b1 << 76;
     }
 }
 
@@ -36,9 +63,39 @@ impl TryFrom<&[u8]> for Capabilities {
         if value.len() != Capabilities::SIZE_IN_BYTES {
             Err(())
         } else {
+            // Ignore bits this version doesn't know about so that hosts keep
+            // working against newer firmware.
             let capabilities = u128::from_be_bytes(value.try_into().unwrap());
-            let caps = Capabilities::from_bits(capabilities);
-            caps.ok_or(())
+            Ok(Capabilities::from_bits_truncate(capabilities))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let caps = Capabilities::RT_BASE | Capabilities::RT_AUTHORIZE_AND_STASH;
+        let bytes = caps.to_bytes();
+        assert_eq!(bytes[7], 0b1001);
+        assert_eq!(
+            Capabilities::try_from(&bytes[..]).unwrap().bits(),
+            caps.bits()
+        );
+    }
+
+    #[test]
+    fn test_unknown_bits_ignored() {
+        let mut bytes = Capabilities::ROM_BASE.to_bytes();
+        bytes[0] = 0x80;
+        let caps = Capabilities::try_from(&bytes[..]).unwrap();
+        assert_eq!(caps.bits(), Capabilities::ROM_BASE.bits());
+    }
+
+    #[test]
+    fn test_invalid_len() {
+        assert!(Capabilities::try_from(&[0u8; 15][..]).is_err());
+    }
+}
@@ -43,4 +43,6 @@ pub enum CaliptraApiError {
     FusesAlreadyIniitalized,
     FuseDoneNotSet,
     StashMeasurementFailed,
+    MailboxCmdNotSupported(u32),
+    InvalidCapabilities,
 }
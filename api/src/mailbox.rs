@@ -5,7 +5,7 @@ use caliptra_error::{CaliptraError, CaliptraResult};
 use core::mem::size_of;
 use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Ref};
 
-use crate::CaliptraApiError;
+use crate::{CaliptraApiError, Capabilities};
 use caliptra_registers::mbox;
 use ureg::MmioMut;
 
@@ -63,6 +63,14 @@ impl CommandId {
 
     // The revoke exported CDI handle command.
     pub const REVOKE_EXPORTED_CDI_HANDLE: Self = Self(0x5256_4348); // "RVCH"
+
+    // The log retrieval commands.
+    pub const GET_PCR_LOG: Self = Self(0x504C_4F47); // "PLOG"
+    pub const GET_MEASUREMENT_LOG: Self = Self(0x4D4C_4F47); // "MLOG"
+    pub const GET_FUSE_LOG: Self = Self(0x464C_4F47); // "FLOG"
+
+    // The get image data command.
+    pub const GET_IMAGE_DATA: Self = Self(0x4944_4154); // "IDAT"
 }
 
 impl From<u32> for CommandId {
@@ -82,6 +90,10 @@ impl From<CommandId> for u32 {
 pub trait Request: IntoBytes + FromBytes + Immutable + KnownLayout {
     const ID: CommandId;
     type Resp: Response;
+
+    /// Capabilities indicating support for this command. Firmware reporting
+    /// any of these bits supports the command. Empty for base commands.
+    const CAPABILITIES: Capabilities = Capabilities::empty();
 }
 
 pub trait Response: IntoBytes + FromBytes
@@ -165,6 +177,8 @@ pub enum MailboxResp {
     GetFmcAliasCsr(GetFmcAliasCsrResp),
     SignWithExportedEcdsa(SignWithExportedEcdsaResp),
     RevokeExportedCdiHandle(RevokeExportedCdiHandleResp),
+    GetLog(GetLogResp),
+    GetImageData(GetImageDataResp),
 }
 
 impl MailboxResp {
@@ -189,6 +203,8 @@ impl MailboxResp {
             MailboxResp::GetFmcAliasCsr(resp) => Ok(resp.as_bytes()),
             MailboxResp::SignWithExportedEcdsa(resp) => Ok(resp.as_bytes()),
             MailboxResp::RevokeExportedCdiHandle(resp) => Ok(resp.as_bytes()),
+            MailboxResp::GetLog(resp) => resp.as_bytes_partial(),
+            MailboxResp::GetImageData(resp) => resp.as_bytes_partial(),
         }
     }
 
@@ -213,6 +229,8 @@ impl MailboxResp {
             MailboxResp::GetFmcAliasCsr(resp) => Ok(resp.as_mut_bytes()),
             MailboxResp::SignWithExportedEcdsa(resp) => Ok(resp.as_mut_bytes()),
             MailboxResp::RevokeExportedCdiHandle(resp) => Ok(resp.as_mut_bytes()),
+            MailboxResp::GetLog(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::GetImageData(resp) => resp.as_bytes_partial_mut(),
         }
     }
 
@@ -273,6 +291,10 @@ pub enum MailboxReq {
     AuthorizeAndStash(AuthorizeAndStashReq),
     SignWithExportedEcdsa(SignWithExportedEcdsaReq),
     RevokeExportedCdiHandle(RevokeExportedCdiHandleReq),
+    GetPcrLog(GetPcrLogReq),
+    GetMeasurementLog(GetMeasurementLogReq),
+    GetFuseLog(GetFuseLogReq),
+    GetImageData(GetImageDataReq),
 }
 
 impl MailboxReq {
@@ -300,6 +322,10 @@ impl MailboxReq {
             MailboxReq::AuthorizeAndStash(req) => Ok(req.as_bytes()),
             MailboxReq::SignWithExportedEcdsa(req) => Ok(req.as_bytes()),
             MailboxReq::RevokeExportedCdiHandle(req) => Ok(req.as_bytes()),
+            MailboxReq::GetPcrLog(req) => Ok(req.as_bytes()),
+            MailboxReq::GetMeasurementLog(req) => Ok(req.as_bytes()),
+            MailboxReq::GetFuseLog(req) => Ok(req.as_bytes()),
+            MailboxReq::GetImageData(req) => Ok(req.as_bytes()),
         }
     }
 
@@ -327,6 +353,10 @@ impl MailboxReq {
             MailboxReq::AuthorizeAndStash(req) => Ok(req.as_mut_bytes()),
             MailboxReq::SignWithExportedEcdsa(req) => Ok(req.as_mut_bytes()),
             MailboxReq::RevokeExportedCdiHandle(req) => Ok(req.as_mut_bytes()),
+            MailboxReq::GetPcrLog(req) => Ok(req.as_mut_bytes()),
+            MailboxReq::GetMeasurementLog(req) => Ok(req.as_mut_bytes()),
+            MailboxReq::GetFuseLog(req) => Ok(req.as_mut_bytes()),
+            MailboxReq::GetImageData(req) => Ok(req.as_mut_bytes()),
         }
     }
 
@@ -354,6 +384,10 @@ impl MailboxReq {
             MailboxReq::AuthorizeAndStash(_) => CommandId::AUTHORIZE_AND_STASH,
             MailboxReq::SignWithExportedEcdsa(_) => CommandId::SIGN_WITH_EXPORTED_ECDSA,
             MailboxReq::RevokeExportedCdiHandle(_) => CommandId::REVOKE_EXPORTED_CDI_HANDLE,
+            MailboxReq::GetPcrLog(_) => CommandId::GET_PCR_LOG,
+            MailboxReq::GetMeasurementLog(_) => CommandId::GET_MEASUREMENT_LOG,
+            MailboxReq::GetFuseLog(_) => CommandId::GET_FUSE_LOG,
+            MailboxReq::GetImageData(_) => CommandId::GET_IMAGE_DATA,
         }
     }
 
@@ -582,6 +616,7 @@ pub struct LmsVerifyReq {
 impl Request for LmsVerifyReq {
     const ID: CommandId = CommandId::LMS_VERIFY;
     type Resp = MailboxRespHeader;
+    const CAPABILITIES: Capabilities = Capabilities::RT_LMS_VERIFY;
 }
 // No command-specific output args
 
@@ -811,6 +846,16 @@ pub struct FwInfoResp {
 
 // CAPABILITIES
 // No command-specific input args
+#[repr(C)]
+#[derive(Debug, Default, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
+pub struct CapabilitiesReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for CapabilitiesReq {
+    const ID: CommandId = CommandId::CAPABILITIES;
+    type Resp = CapabilitiesResp;
+}
+
 #[repr(C)]
 #[derive(Debug, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
 pub struct CapabilitiesResp {
@@ -965,6 +1010,120 @@ impl Request for QuotePcrsReq {
     type Resp = QuotePcrsResp;
 }
 
+// GET_PCR_LOG
+#[repr(C)]
+#[derive(Debug, Default, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
+pub struct GetPcrLogReq {
+    pub hdr: MailboxReqHeader,
+    /// Index of the first log entry to return
+    pub start_index: u32,
+}
+impl Request for GetPcrLogReq {
+    const ID: CommandId = CommandId::GET_PCR_LOG;
+    type Resp = GetLogResp;
+    const CAPABILITIES: Capabilities = Capabilities::RT_GET_LOGS;
+}
+
+// GET_MEASUREMENT_LOG
+#[repr(C)]
+#[derive(Debug, Default, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
+pub struct GetMeasurementLogReq {
+    pub hdr: MailboxReqHeader,
+    /// Index of the first log entry to return
+    pub start_index: u32,
+}
+impl Request for GetMeasurementLogReq {
+    const ID: CommandId = CommandId::GET_MEASUREMENT_LOG;
+    type Resp = GetLogResp;
+    const CAPABILITIES: Capabilities = Capabilities::RT_GET_LOGS;
+}
+
+// GET_FUSE_LOG
+#[repr(C)]
+#[derive(Debug, Default, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
+pub struct GetFuseLogReq {
+    pub hdr: MailboxReqHeader,
+    /// Index of the first log entry to return
+    pub start_index: u32,
+}
+impl Request for GetFuseLogReq {
+    const ID: CommandId = CommandId::GET_FUSE_LOG;
+    type Resp = GetLogResp;
+    const CAPABILITIES: Capabilities = Capabilities::RT_GET_LOGS;
+}
+
+/// Output of GET_PCR_LOG, GET_MEASUREMENT_LOG and GET_FUSE_LOG. `data` holds
+/// as many whole log entries, starting at the requested index, as fit.
+#[repr(C)]
+#[derive(Debug, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
+pub struct GetLogResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub data: [u8; GetLogResp::DATA_MAX_SIZE], // variable length
+}
+impl GetLogResp {
+    pub const DATA_MAX_SIZE: usize = 1024;
+
+    pub fn data(&self) -> Option<&[u8]> {
+        self.data.get(..self.data_size as usize)
+    }
+}
+impl ResponseVarSize for GetLogResp {}
+
+impl Default for GetLogResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            data: [0u8; GetLogResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// GET_IMAGE_DATA
+#[repr(C)]
+#[derive(Debug, Default, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
+pub struct GetImageDataReq {
+    pub hdr: MailboxReqHeader,
+    /// ID of the data TOC entry in the firmware manifest
+    pub id: u32,
+    /// Byte offset into the data to start reading from
+    pub offset: u32,
+}
+impl Request for GetImageDataReq {
+    const ID: CommandId = CommandId::GET_IMAGE_DATA;
+    type Resp = GetImageDataResp;
+    const CAPABILITIES: Capabilities = Capabilities::RT_GET_IMAGE_DATA;
+}
+
+/// Output of GET_IMAGE_DATA. `data` holds as many bytes, starting at the
+/// requested offset, as fit; it is empty once the offset reaches the end.
+#[repr(C)]
+#[derive(Debug, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
+pub struct GetImageDataResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub data: [u8; GetImageDataResp::DATA_MAX_SIZE], // variable length
+}
+impl GetImageDataResp {
+    pub const DATA_MAX_SIZE: usize = 1024;
+
+    pub fn data(&self) -> Option<&[u8]> {
+        self.data.get(..self.data_size as usize)
+    }
+}
+impl ResponseVarSize for GetImageDataResp {}
+
+impl Default for GetImageDataResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            data: [0u8; GetImageDataResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
 // SET_AUTH_MANIFEST
 #[repr(C)]
 #[derive(Debug, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq)]
@@ -1012,6 +1171,8 @@ pub struct GetIdevCsrReq {
 impl Request for GetIdevCsrReq {
     const ID: CommandId = CommandId::GET_IDEV_CSR;
     type Resp = GetIdevCsrResp;
+    const CAPABILITIES: Capabilities =
+        Capabilities::ROM_GET_IDEV_CSR.union(Capabilities::RT_GET_IDEV_CSR);
 }
 
 #[repr(C)]
@@ -1046,6 +1207,7 @@ pub struct GetFmcAliasCsrReq {
 impl Request for GetFmcAliasCsrReq {
     const ID: CommandId = CommandId::GET_FMC_ALIAS_CSR;
     type Resp = GetFmcAliasCsrResp;
+    const CAPABILITIES: Capabilities = Capabilities::RT_GET_FMC_ALIAS_CSR;
 }
 
 #[repr(C)]
@@ -1098,6 +1260,7 @@ impl SignWithExportedEcdsaReq {
 impl Request for SignWithExportedEcdsaReq {
     const ID: CommandId = CommandId::SIGN_WITH_EXPORTED_ECDSA;
     type Resp = SignWithExportedEcdsaResp;
+    const CAPABILITIES: Capabilities = Capabilities::RT_SIGN_WITH_EXPORTED_ECDSA;
 }
 
 #[repr(C)]
@@ -1155,6 +1318,7 @@ impl RevokeExportedCdiHandleReq {
 impl Request for RevokeExportedCdiHandleReq {
     const ID: CommandId = CommandId::REVOKE_EXPORTED_CDI_HANDLE;
     type Resp = RevokeExportedCdiHandleResp;
+    const CAPABILITIES: Capabilities = Capabilities::RT_REVOKE_EXPORTED_CDI_HANDLE;
 }
 impl Response for RevokeExportedCdiHandleResp {}
 
@@ -1212,6 +1376,27 @@ pub struct AuthorizeAndStashReq {
     pub svn: u32,
     pub flags: u32,
     pub source: u32,
+    // Optional tail, only required for the ShaAcc source.
+    pub image_offset: u32, // Byte offset of the staged image in the mailbox.
+    pub image_size: u32,   // Size of the staged image in bytes.
+}
+impl AuthorizeAndStashReq {
+    /// Size of a request without the optional `image_offset` and `image_size` tail.
+    pub const BASE_SIZE: usize = size_of::<Self>() - 2 * size_of::<u32>();
+
+    /// Parse a request with or without the optional tail.
+    ///
+    /// Returns `None` if `bytes` is not one of the two valid request sizes.
+    pub fn read_from_bytes_with_tail(bytes: &[u8]) -> Option<Self> {
+        match bytes.len() {
+            Self::BASE_SIZE => {
+                let mut buf = [0u8; size_of::<Self>()];
+                buf[..Self::BASE_SIZE].copy_from_slice(bytes);
+                Self::read_from_bytes(&buf).ok()
+            }
+            _ => Self::read_from_bytes(bytes).ok(),
+        }
+    }
 }
 impl Default for AuthorizeAndStashReq {
     fn default() -> Self {
@@ -1223,12 +1408,15 @@ impl Default for AuthorizeAndStashReq {
             svn: Default::default(),
             flags: AuthAndStashFlags::SKIP_STASH.bits(),
             source: ImageHashSource::InRequest as u32,
+            image_offset: 0,
+            image_size: 0,
         }
     }
 }
 impl Request for AuthorizeAndStashReq {
     const ID: CommandId = CommandId::AUTHORIZE_AND_STASH;
     type Resp = StashMeasurementResp;
+    const CAPABILITIES: Capabilities = Capabilities::RT_AUTHORIZE_AND_STASH;
 }
 
 #[repr(C)]
@@ -1363,4 +1551,39 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_authorize_and_stash_req_optional_tail() {
+        let req = AuthorizeAndStashReq {
+            fw_id: [1, 2, 3, 4],
+            image_offset: 0x80,
+            image_size: 0x100,
+            ..Default::default()
+        };
+        assert_eq!(
+            AuthorizeAndStashReq::read_from_bytes_with_tail(req.as_bytes()).as_ref(),
+            Some(&req)
+        );
+
+        let base = AuthorizeAndStashReq::read_from_bytes_with_tail(
+            &req.as_bytes()[..AuthorizeAndStashReq::BASE_SIZE],
+        )
+        .unwrap();
+        assert_eq!(base.fw_id, [1, 2, 3, 4]);
+        assert_eq!(base.image_offset, 0);
+        assert_eq!(base.image_size, 0);
+
+        assert_eq!(
+            AuthorizeAndStashReq::read_from_bytes_with_tail(
+                &req.as_bytes()[..AuthorizeAndStashReq::BASE_SIZE + 4]
+            ),
+            None
+        );
+        assert_eq!(
+            AuthorizeAndStashReq::read_from_bytes_with_tail(
+                &req.as_bytes()[..AuthorizeAndStashReq::BASE_SIZE - 4]
+            ),
+            None
+        );
+    }
 }
@@ -3,10 +3,10 @@
 use crate::{
     calc_checksum,
     mailbox::{
-        mbox_read_response, mbox_write_fifo, MailboxReqHeader, MailboxRespHeader, Request,
-        Response, StashMeasurementReq,
+        mbox_read_response, mbox_write_fifo, CapabilitiesReq, CapabilitiesResp, MailboxReqHeader,
+        MailboxRespHeader, Request, Response, StashMeasurementReq,
     },
-    CaliptraApiError,
+    CaliptraApiError, Capabilities,
 };
 use caliptra_api_types::Fuses;
 use core::mem;
@@ -157,6 +157,16 @@ pub trait SocManager {
         self.soc_ifc()
             .fuse_soc_stepping_id()
             .write(|w| w.soc_stepping_id(fuses.soc_stepping_id.into()));
+        // There are no ML-DSA fuses in 1.0 hardware.
+        #[cfg(not(feature = "hw-1.0"))]
+        {
+            self.soc_ifc_mldsa()
+                .fuse_mldsa_verify()
+                .write(|w| w.mldsa_verify(fuses.mldsa_verify));
+            self.soc_ifc_mldsa()
+                .fuse_mldsa_revocation()
+                .write(|_| fuses.fuse_mldsa_revocation);
+        }
 
         self.soc_ifc().cptra_fuse_wr_done().write(|w| w.done(true));
 
@@ -177,6 +187,20 @@ pub trait SocManager {
         }
     }
 
+    /// A register block that can be used to manipulate the ML-DSA fuse
+    /// registers of the soc_ifc block
+    #[cfg(not(feature = "hw-1.0"))]
+    fn soc_ifc_mldsa(
+        &mut self,
+    ) -> caliptra_registers::soc_ifc_mldsa::RegisterBlock<Self::TMmio<'_>> {
+        unsafe {
+            caliptra_registers::soc_ifc_mldsa::RegisterBlock::new_with_mmio(
+                Self::SOC_IFC_ADDR as *mut u32,
+                self.mmio_mut(),
+            )
+        }
+    }
+
     /// A register block that can be used to manipulate the soc_ifc peripheral TRNG registers
     /// over the simulated SoC->Caliptra APB bus.
     fn soc_ifc_trng(&mut self) -> caliptra_registers::soc_ifc_trng::RegisterBlock<Self::TMmio<'_>> {
@@ -348,6 +372,31 @@ pub trait SocManager {
         Ok(response)
     }
 
+    /// Queries the capabilities reported by the firmware currently running
+    /// (ROM or runtime).
+    fn get_capabilities(&mut self) -> core::result::Result<Capabilities, CaliptraApiError> {
+        let mut resp_bytes = [0u8; mem::size_of::<CapabilitiesResp>()];
+        let resp = self.mailbox_exec_req(CapabilitiesReq::default(), &mut resp_bytes)?;
+        Capabilities::try_from(&resp.capabilities[..])
+            .map_err(|_| CaliptraApiError::InvalidCapabilities)
+    }
+
+    /// Executes a typed request like `mailbox_exec_req`, but first checks
+    /// `caps` (as returned by `get_capabilities`) for support of the command.
+    /// Returns Err(CaliptraApiError::MailboxCmdNotSupported) without touching
+    /// the mailbox if the firmware does not advertise the command.
+    fn mailbox_exec_req_if_supported<R: Request>(
+        &mut self,
+        caps: Capabilities,
+        req: R,
+        resp_bytes: &mut [u8],
+    ) -> core::result::Result<R::Resp, CaliptraApiError> {
+        if !R::CAPABILITIES.is_empty() && !caps.intersects(R::CAPABILITIES) {
+            return Err(CaliptraApiError::MailboxCmdNotSupported(R::ID.into()));
+        }
+        self.mailbox_exec_req(req, resp_bytes)
+    }
+
     fn send_stash_measurement_req(
         &mut self,
         req: StashMeasurementReq,
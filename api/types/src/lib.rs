@@ -168,6 +168,8 @@ pub struct Fuses {
     pub lms_verify: bool,
     pub fuse_lms_revocation: u32,
     pub soc_stepping_id: u16,
+    pub mldsa_verify: bool,
+    pub fuse_mldsa_revocation: u32,
 }
 impl Default for Fuses {
     fn default() -> Self {
@@ -186,6 +188,8 @@ impl Default for Fuses {
             lms_verify: Default::default(),
             fuse_lms_revocation: Default::default(),
             soc_stepping_id: Default::default(),
+            mldsa_verify: Default::default(),
+            fuse_mldsa_revocation: Default::default(),
         }
     }
 }
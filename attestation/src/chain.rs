@@ -0,0 +1,166 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    chain.rs
+
+Abstract:
+
+    File contains validation of the IDevID -> LDevID -> FMC alias -> RT alias
+    -> DPE leaf certificate chain.
+
+--*/
+
+use std::cmp::Ordering;
+
+use caliptra_common::dice::{FLAG_BIT_DEBUG, FLAG_BIT_NOT_CONFIGURED, FLAG_BIT_NOT_SECURE};
+use openssl::pkey::{PKey, PKeyRef, Public};
+use openssl::x509::{X509Ref, X509};
+
+use crate::x509::DiceTcbInfo;
+use crate::{CertName, VerifyError};
+
+pub(crate) const TCB_DEVICE_INFO: &str = "DEVICE_INFO";
+pub(crate) const TCB_FMC_INFO: &str = "FMC_INFO";
+pub(crate) const TCB_RT_INFO: &str = "RT_INFO";
+
+/// DER certificates returned by GET_LDEV_CERT, GET_FMC_ALIAS_CERT,
+/// GET_RT_ALIAS_CERT and (optionally) DPE CertifyKey.
+#[derive(Clone, Copy, Debug)]
+pub struct CertChain<'a> {
+    pub ldevid: &'a [u8],
+    pub fmc_alias: &'a [u8],
+    pub rt_alias: &'a [u8],
+    pub dpe_leaf: Option<&'a [u8]>,
+}
+
+/// Measurements reported by the TCB info extensions of a validated chain.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChainTcbInfo {
+    /// DEVICE_INFO flags as `caliptra_common::dice::FLAG_BIT_*` values.
+    pub device_flags: u32,
+    pub fmc_digest: Vec<u8>,
+    pub fmc_svn: u32,
+    pub rt_digest: Vec<u8>,
+    pub rt_svn: u32,
+}
+
+pub(crate) fn parse_cert(cert: CertName, der: &[u8]) -> Result<X509, VerifyError> {
+    X509::from_der(der).map_err(|e| VerifyError::MalformedCert {
+        cert,
+        reason: e.to_string(),
+    })
+}
+
+pub(crate) fn public_key(cert: CertName, x509: &X509Ref) -> Result<PKey<Public>, VerifyError> {
+    x509.public_key().map_err(|e| VerifyError::MalformedCert {
+        cert,
+        reason: e.to_string(),
+    })
+}
+
+fn check_issued_by(cert: CertName, x509: &X509Ref, issuer: &X509Ref) -> Result<(), VerifyError> {
+    let issuer_key = public_key(cert, issuer)?;
+    if !x509.verify(&issuer_key).unwrap_or(false) {
+        return Err(VerifyError::CertSignature { cert });
+    }
+    let matches = match (x509.authority_key_id(), issuer.subject_key_id()) {
+        (Some(aki), Some(ski)) => aki.as_slice() == ski.as_slice(),
+        _ => x509.issuer_name().try_cmp(issuer.subject_name()).ok() == Some(Ordering::Equal),
+    };
+    if !matches {
+        return Err(VerifyError::Issuer { cert });
+    }
+    Ok(())
+}
+
+fn tcb_info_err(cert: CertName) -> impl Fn(asn1::ParseError) -> VerifyError {
+    move |e| VerifyError::MalformedCert {
+        cert,
+        reason: format!("invalid TCB info: {e:?}"),
+    }
+}
+
+fn find_tcb_info<'a>(
+    cert: CertName,
+    tcb_infos: &'a [DiceTcbInfo],
+    ty: &'static str,
+) -> Result<&'a DiceTcbInfo, VerifyError> {
+    tcb_infos
+        .iter()
+        .find(|t| t.ty.as_deref() == Some(ty.as_bytes()))
+        .ok_or(VerifyError::MissingTcbInfo { cert, ty })
+}
+
+fn fwid(tcb_info: &DiceTcbInfo) -> Vec<u8> {
+    tcb_info
+        .fwids
+        .first()
+        .map(|f| f.digest.clone())
+        .unwrap_or_default()
+}
+
+// The certificate templates encode the SVN as `(1 << 8) | svn` to give the
+// INTEGER a fixed width.
+fn svn(tcb_info: &DiceTcbInfo) -> u32 {
+    tcb_info.svn.unwrap_or(0) & 0xff
+}
+
+impl CertChain<'_> {
+    /// Checks that each certificate is signed by its issuer, starting from
+    /// the IDevID public key (from the IDevID CSR or the manufacturer's
+    /// IDevID certificate), and extracts the TCB info of the alias
+    /// certificates.
+    pub fn verify(&self, idevid_pub_key: &PKeyRef<Public>) -> Result<ChainTcbInfo, VerifyError> {
+        let ldevid = parse_cert(CertName::LDevId, self.ldevid)?;
+        if !ldevid.verify(idevid_pub_key).unwrap_or(false) {
+            return Err(VerifyError::CertSignature {
+                cert: CertName::LDevId,
+            });
+        }
+
+        let fmc_alias = parse_cert(CertName::FmcAlias, self.fmc_alias)?;
+        check_issued_by(CertName::FmcAlias, &fmc_alias, &ldevid)?;
+
+        let rt_alias = parse_cert(CertName::RtAlias, self.rt_alias)?;
+        check_issued_by(CertName::RtAlias, &rt_alias, &fmc_alias)?;
+
+        if let Some(dpe_leaf) = self.dpe_leaf {
+            let dpe_leaf = parse_cert(CertName::DpeLeaf, dpe_leaf)?;
+            check_issued_by(CertName::DpeLeaf, &dpe_leaf, &rt_alias)?;
+        }
+
+        let fmc_tcb_infos = DiceTcbInfo::find_multiple_in_cert(self.fmc_alias)
+            .map_err(tcb_info_err(CertName::FmcAlias))?;
+        let device_info = find_tcb_info(CertName::FmcAlias, &fmc_tcb_infos, TCB_DEVICE_INFO)?;
+        let fmc_info = find_tcb_info(CertName::FmcAlias, &fmc_tcb_infos, TCB_FMC_INFO)?;
+
+        let rt_tcb_info = DiceTcbInfo::find_single_in_cert(self.rt_alias)
+            .map_err(tcb_info_err(CertName::RtAlias))?
+            .filter(|t| t.ty.as_deref() == Some(TCB_RT_INFO.as_bytes()))
+            .ok_or(VerifyError::MissingTcbInfo {
+                cert: CertName::RtAlias,
+                ty: TCB_RT_INFO,
+            })?;
+
+        Ok(ChainTcbInfo {
+            // The flags are a BIT STRING (flag 0 is the most significant
+            // bit), which DiceTcbInfo reads as a big-endian integer.
+            device_flags: device_info.flags.unwrap_or(0).reverse_bits(),
+            fmc_digest: fwid(fmc_info),
+            fmc_svn: svn(fmc_info),
+            rt_digest: fwid(&rt_tcb_info),
+            rt_svn: svn(&rt_tcb_info),
+        })
+    }
+}
+
+impl ChainTcbInfo {
+    /// True if the device reports that it is unprovisioned, not in a secure
+    /// lifecycle state or debug unlocked.
+    pub fn is_insecure(&self) -> bool {
+        self.device_flags & (FLAG_BIT_NOT_CONFIGURED | FLAG_BIT_NOT_SECURE | FLAG_BIT_DEBUG) != 0
+    }
+}
@@ -0,0 +1,171 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    error.rs
+
+Abstract:
+
+    File contains the error type reported when a piece of evidence fails
+    verification.
+
+--*/
+
+use std::fmt;
+
+use caliptra_common::PcrLogEntryId;
+
+/// Certificates in the Caliptra DICE chain.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CertName {
+    LDevId,
+    FmcAlias,
+    RtAlias,
+    DpeLeaf,
+}
+
+impl fmt::Display for CertName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CertName::LDevId => "LDevID",
+            CertName::FmcAlias => "FMC alias",
+            CertName::RtAlias => "RT alias",
+            CertName::DpeLeaf => "DPE leaf",
+        })
+    }
+}
+
+/// Identifies the check that rejected the evidence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VerifyError {
+    /// The certificate could not be parsed.
+    MalformedCert { cert: CertName, reason: String },
+
+    /// The certificate signature does not verify with the issuer's key.
+    CertSignature { cert: CertName },
+
+    /// The certificate's authority key identifier (or, when it has none, its
+    /// issuer name) does not match the issuer certificate.
+    Issuer { cert: CertName },
+
+    /// The certificate has no TCB info of the given type.
+    MissingTcbInfo { cert: CertName, ty: &'static str },
+
+    /// The FWID in the TCB info differs from the reference value.
+    Fwid {
+        cert: CertName,
+        ty: &'static str,
+        expected: Vec<u8>,
+        actual: Vec<u8>,
+    },
+
+    /// The SVN in the TCB info is below the reference minimum.
+    Svn {
+        cert: CertName,
+        ty: &'static str,
+        min: u32,
+        actual: u32,
+    },
+
+    /// The DEVICE_INFO flags report an unprovisioned, insecure or debug
+    /// unlocked device.
+    DeviceFlags { flags: u32 },
+
+    /// The quote was not generated for the expected nonce.
+    QuoteNonce,
+
+    /// The quote digest is not the hash of the quoted PCRs and nonce.
+    QuoteDigest,
+
+    /// The quote signature does not verify with the signer's key.
+    QuoteSignature,
+
+    /// The PCR log is not a whole number of entries.
+    MalformedPcrLog,
+
+    /// Replaying the PCR log does not produce the quoted PCR value.
+    PcrReplay { pcr: usize },
+
+    /// The PCR log has no entry of the given type.
+    MissingPcrLogEntry { id: PcrLogEntryId },
+
+    /// A PCR log entry differs from the reference value.
+    PcrLogEntry {
+        id: PcrLogEntryId,
+        expected: Vec<u8>,
+        actual: Vec<u8>,
+    },
+
+    /// The reference value file could not be parsed.
+    InvalidReferenceValues(String),
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::MalformedCert { cert, reason } => {
+                write!(f, "{cert} certificate is malformed: {reason}")
+            }
+            VerifyError::CertSignature { cert } => {
+                write!(f, "{cert} certificate signature is invalid")
+            }
+            VerifyError::Issuer { cert } => {
+                write!(f, "{cert} certificate does not match its issuer")
+            }
+            VerifyError::MissingTcbInfo { cert, ty } => {
+                write!(f, "{cert} certificate has no {ty} TCB info")
+            }
+            VerifyError::Fwid {
+                cert,
+                ty,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{cert} certificate {ty} FWID mismatch: expected {}, got {}",
+                hex::encode(expected),
+                hex::encode(actual)
+            ),
+            VerifyError::Svn {
+                cert,
+                ty,
+                min,
+                actual,
+            } => write!(
+                f,
+                "{cert} certificate {ty} SVN {actual} is below the minimum {min}"
+            ),
+            VerifyError::DeviceFlags { flags } => {
+                write!(
+                    f,
+                    "DEVICE_INFO flags 0x{flags:08x} report an insecure device"
+                )
+            }
+            VerifyError::QuoteNonce => write!(f, "PCR quote nonce mismatch"),
+            VerifyError::QuoteDigest => write!(f, "PCR quote digest mismatch"),
+            VerifyError::QuoteSignature => write!(f, "PCR quote signature is invalid"),
+            VerifyError::MalformedPcrLog => write!(f, "PCR log is malformed"),
+            VerifyError::PcrReplay { pcr } => {
+                write!(f, "PCR log replay does not match quoted PCR{pcr}")
+            }
+            VerifyError::MissingPcrLogEntry { id } => write!(f, "PCR log has no {id:?} entry"),
+            VerifyError::PcrLogEntry {
+                id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "PCR log {id:?} mismatch: expected {}, got {}",
+                hex::encode(expected),
+                hex::encode(actual)
+            ),
+            VerifyError::InvalidReferenceValues(reason) => {
+                write!(f, "invalid reference values: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
@@ -0,0 +1,59 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    lib.rs
+
+Abstract:
+
+    Host-side verification of Caliptra attestation evidence: PCR quotes,
+    the DICE certificate chain and the PCR log.
+
+--*/
+
+mod chain;
+mod error;
+mod quote;
+mod reference;
+pub mod x509;
+
+pub use chain::{CertChain, ChainTcbInfo};
+pub use error::{CertName, VerifyError};
+pub use quote::{parse_pcr_log, replay_pcr_log, verify_pcr_quote};
+pub use reference::ReferenceValues;
+
+use caliptra_common::mailbox_api::QuotePcrsResp;
+use openssl::pkey::{PKeyRef, Public};
+
+/// Evidence collected from a device over the mailbox.
+#[derive(Clone, Copy, Debug)]
+pub struct Evidence<'a> {
+    pub certs: CertChain<'a>,
+
+    /// QUOTE_PCRS response for `nonce`.
+    pub quote: &'a QuotePcrsResp,
+    pub nonce: &'a [u8; 32],
+
+    /// Concatenated GET_PCR_LOG response data.
+    pub pcr_log: &'a [u8],
+}
+
+/// Runs every check against `evidence` and stops at the first failure.
+pub fn verify_evidence(
+    evidence: &Evidence,
+    idevid_pub_key: &PKeyRef<Public>,
+    reference: &ReferenceValues,
+) -> Result<(), VerifyError> {
+    let tcb_info = evidence.certs.verify(idevid_pub_key)?;
+    reference.check_chain(&tcb_info)?;
+
+    let fmc_alias = chain::parse_cert(CertName::FmcAlias, evidence.certs.fmc_alias)?;
+    let fmc_alias_key = chain::public_key(CertName::FmcAlias, &fmc_alias)?;
+    verify_pcr_quote(evidence.quote, evidence.nonce, &fmc_alias_key)?;
+
+    let pcr_log = parse_pcr_log(evidence.pcr_log)?;
+    replay_pcr_log(&pcr_log, evidence.quote)?;
+    reference.check_pcr_log(&pcr_log)
+}
@@ -0,0 +1,102 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    quote.rs
+
+Abstract:
+
+    File contains verification of QUOTE_PCRS responses and PCR log replay.
+
+--*/
+
+use caliptra_common::mailbox_api::QuotePcrsResp;
+use caliptra_common::{PcrLogEntry, PcrLogEntryId, RT_FW_CURRENT_PCR};
+use openssl::bn::BigNum;
+use openssl::ecdsa::EcdsaSig;
+use openssl::error::ErrorStack;
+use openssl::pkey::{PKeyRef, Public};
+use openssl::sha::Sha384;
+use zerocopy::FromBytes;
+
+use crate::VerifyError;
+
+/// Checks that `quote` was generated for `nonce` and signed by
+/// `signer_key`.
+///
+/// Caliptra signs quotes with the PCR signing key held by the ECC engine,
+/// which is the FMC alias key; pass the FMC alias certificate's public key.
+pub fn verify_pcr_quote(
+    quote: &QuotePcrsResp,
+    nonce: &[u8; 32],
+    signer_key: &PKeyRef<Public>,
+) -> Result<(), VerifyError> {
+    if &quote.nonce != nonce {
+        return Err(VerifyError::QuoteNonce);
+    }
+
+    let mut hasher = Sha384::new();
+    for pcr in quote.pcrs.iter() {
+        hasher.update(pcr);
+    }
+    hasher.update(&quote.nonce);
+    if hasher.finish() != quote.digest {
+        return Err(VerifyError::QuoteDigest);
+    }
+
+    let verified = (|| -> Result<bool, ErrorStack> {
+        let sig = EcdsaSig::from_private_components(
+            BigNum::from_slice(&quote.signature_r)?,
+            BigNum::from_slice(&quote.signature_s)?,
+        )?;
+        sig.verify(&quote.digest, &signer_key.ec_key()?)
+    })();
+    match verified {
+        Ok(true) => Ok(()),
+        _ => Err(VerifyError::QuoteSignature),
+    }
+}
+
+/// Splits the bytes returned by GET_PCR_LOG into entries.
+pub fn parse_pcr_log(log: &[u8]) -> Result<Vec<PcrLogEntry>, VerifyError> {
+    let entry_size = core::mem::size_of::<PcrLogEntry>();
+    if log.len() % entry_size != 0 {
+        return Err(VerifyError::MalformedPcrLog);
+    }
+    log.chunks_exact(entry_size)
+        .map(|entry| PcrLogEntry::read_from_bytes(entry).map_err(|_| VerifyError::MalformedPcrLog))
+        .collect()
+}
+
+/// Replays `pcr_log` and checks the result against every PCR the log
+/// extends.
+pub fn replay_pcr_log(pcr_log: &[PcrLogEntry], quote: &QuotePcrsResp) -> Result<(), VerifyError> {
+    let mut replayed = [[0u8; 48]; 32];
+    let mut logged = 0u32;
+    for entry in pcr_log {
+        // FMC clears the current RT PCR on every boot before measuring the
+        // runtime, while the journey PCR and the log keep accumulating
+        // across update resets.
+        if PcrLogEntryId::from(entry.id) == PcrLogEntryId::RtTci {
+            replayed[RT_FW_CURRENT_PCR as usize] = [0u8; 48];
+        }
+        for (i, pcr) in replayed.iter_mut().enumerate() {
+            if entry.pcr_ids & (1 << i) != 0 {
+                let mut hasher = Sha384::new();
+                hasher.update(pcr);
+                hasher.update(entry.measured_data());
+                *pcr = hasher.finish();
+            }
+        }
+        logged |= entry.pcr_ids;
+    }
+
+    for (pcr, (replayed, quoted)) in replayed.iter().zip(quote.pcrs.iter()).enumerate() {
+        if logged & (1 << pcr) != 0 && replayed != quoted {
+            return Err(VerifyError::PcrReplay { pcr });
+        }
+    }
+    Ok(())
+}
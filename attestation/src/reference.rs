@@ -0,0 +1,230 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    reference.rs
+
+Abstract:
+
+    File contains the reference values evidence is compared against.
+
+--*/
+
+use std::path::Path;
+
+use caliptra_common::{PcrLogEntry, PcrLogEntryId};
+use serde_derive::Deserialize;
+
+use crate::chain::{TCB_FMC_INFO, TCB_RT_INFO};
+use crate::{CertName, ChainTcbInfo, VerifyError};
+
+/// Expected measurements for a device. Digests are SHA-384 values in the
+/// byte order printed by `sha384sum`. Checks for values that are `None` are
+/// skipped.
+///
+/// The TOML form is:
+///
+/// ```toml
+/// fmc_digest = "<hex>"
+/// rt_digest = "<hex>"
+/// vendor_pub_key_hash = "<hex>"
+/// owner_pub_key_hash = "<hex>"
+/// min_fmc_svn = 0
+/// min_rt_svn = 0
+/// allow_insecure = false
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferenceValues {
+    pub fmc_digest: Option<[u8; 48]>,
+    pub rt_digest: Option<[u8; 48]>,
+    pub vendor_pub_key_hash: Option<[u8; 48]>,
+    pub owner_pub_key_hash: Option<[u8; 48]>,
+    pub min_fmc_svn: Option<u32>,
+    pub min_rt_svn: Option<u32>,
+
+    /// Accept devices whose DEVICE_INFO flags report them as unprovisioned,
+    /// not secure or debug unlocked.
+    pub allow_insecure: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReferenceValuesConfig {
+    fmc_digest: Option<String>,
+    rt_digest: Option<String>,
+    vendor_pub_key_hash: Option<String>,
+    owner_pub_key_hash: Option<String>,
+    min_fmc_svn: Option<u32>,
+    min_rt_svn: Option<u32>,
+    #[serde(default)]
+    allow_insecure: bool,
+}
+
+fn parse_digest(name: &str, value: Option<String>) -> Result<Option<[u8; 48]>, VerifyError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let mut digest = [0u8; 48];
+    hex::decode_to_slice(value.trim(), &mut digest).map_err(|e| {
+        VerifyError::InvalidReferenceValues(format!("{name} is not a SHA-384 digest: {e}"))
+    })?;
+    Ok(Some(digest))
+}
+
+impl ReferenceValues {
+    /// Parses reference values from a TOML string.
+    pub fn from_toml(s: &str) -> Result<Self, VerifyError> {
+        let config: ReferenceValuesConfig =
+            toml::from_str(s).map_err(|e| VerifyError::InvalidReferenceValues(e.to_string()))?;
+        Ok(Self {
+            fmc_digest: parse_digest("fmc_digest", config.fmc_digest)?,
+            rt_digest: parse_digest("rt_digest", config.rt_digest)?,
+            vendor_pub_key_hash: parse_digest("vendor_pub_key_hash", config.vendor_pub_key_hash)?,
+            owner_pub_key_hash: parse_digest("owner_pub_key_hash", config.owner_pub_key_hash)?,
+            min_fmc_svn: config.min_fmc_svn,
+            min_rt_svn: config.min_rt_svn,
+            allow_insecure: config.allow_insecure,
+        })
+    }
+
+    /// Loads reference values from a TOML file.
+    pub fn load(path: &Path) -> Result<Self, VerifyError> {
+        let s = std::fs::read_to_string(path).map_err(|e| {
+            VerifyError::InvalidReferenceValues(format!("failed to read {}: {e}", path.display()))
+        })?;
+        Self::from_toml(&s)
+    }
+
+    /// Compares the TCB info of a validated certificate chain.
+    pub fn check_chain(&self, tcb_info: &ChainTcbInfo) -> Result<(), VerifyError> {
+        if !self.allow_insecure && tcb_info.is_insecure() {
+            return Err(VerifyError::DeviceFlags {
+                flags: tcb_info.device_flags,
+            });
+        }
+        check_fwid(
+            CertName::FmcAlias,
+            TCB_FMC_INFO,
+            self.fmc_digest,
+            &tcb_info.fmc_digest,
+        )?;
+        check_svn(
+            CertName::FmcAlias,
+            TCB_FMC_INFO,
+            self.min_fmc_svn,
+            tcb_info.fmc_svn,
+        )?;
+        check_fwid(
+            CertName::RtAlias,
+            TCB_RT_INFO,
+            self.rt_digest,
+            &tcb_info.rt_digest,
+        )?;
+        check_svn(
+            CertName::RtAlias,
+            TCB_RT_INFO,
+            self.min_rt_svn,
+            tcb_info.rt_svn,
+        )
+    }
+
+    /// Compares the measurements recorded in the PCR log. When an entry type
+    /// appears more than once (RtTci after an update reset), the most recent
+    /// entry is used.
+    pub fn check_pcr_log(&self, pcr_log: &[PcrLogEntry]) -> Result<(), VerifyError> {
+        for (id, expected) in [
+            (PcrLogEntryId::VendorPubKeyHash, self.vendor_pub_key_hash),
+            (PcrLogEntryId::OwnerPubKeyHash, self.owner_pub_key_hash),
+            (PcrLogEntryId::FmcTci, self.fmc_digest),
+            (PcrLogEntryId::RtTci, self.rt_digest),
+        ] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let entry = pcr_log
+                .iter()
+                .rev()
+                .find(|e| PcrLogEntryId::from(e.id) == id)
+                .ok_or(VerifyError::MissingPcrLogEntry { id })?;
+            if entry.measured_data() != expected {
+                return Err(VerifyError::PcrLogEntry {
+                    id,
+                    expected: expected.to_vec(),
+                    actual: entry.measured_data().to_vec(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_fwid(
+    cert: CertName,
+    ty: &'static str,
+    expected: Option<[u8; 48]>,
+    actual: &[u8],
+) -> Result<(), VerifyError> {
+    match expected {
+        Some(expected) if expected != actual => Err(VerifyError::Fwid {
+            cert,
+            ty,
+            expected: expected.to_vec(),
+            actual: actual.to_vec(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn check_svn(
+    cert: CertName,
+    ty: &'static str,
+    min: Option<u32>,
+    actual: u32,
+) -> Result<(), VerifyError> {
+    match min {
+        Some(min) if actual < min => Err(VerifyError::Svn {
+            cert,
+            ty,
+            min,
+            actual,
+        }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_toml() {
+        let fmc = "00".repeat(47) + "01";
+        let values =
+            ReferenceValues::from_toml(&format!("fmc_digest = \"{fmc}\"\nmin_rt_svn = 3\n"))
+                .unwrap();
+        let mut expected_fmc = [0u8; 48];
+        expected_fmc[47] = 1;
+        assert_eq!(
+            values,
+            ReferenceValues {
+                fmc_digest: Some(expected_fmc),
+                min_rt_svn: Some(3),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_toml_invalid() {
+        assert!(matches!(
+            ReferenceValues::from_toml("rt_digest = \"abcd\""),
+            Err(VerifyError::InvalidReferenceValues(_))
+        ));
+        assert!(matches!(
+            ReferenceValues::from_toml("unknown = 1"),
+            Err(VerifyError::InvalidReferenceValues(_))
+        ));
+    }
+}
@@ -16,11 +16,13 @@ use anyhow::Context;
 use caliptra_auth_man_gen::AuthManifestGeneratorKeyConfig;
 use caliptra_auth_man_types::{AuthManifestImageMetadata, AuthManifestPrivKeys};
 use caliptra_auth_man_types::{AuthManifestPubKeys, ImageMetadataFlags};
-#[cfg(feature = "openssl")]
+#[cfg(all(feature = "openssl", not(feature = "pkcs11")))]
 use caliptra_image_crypto::OsslCrypto as Crypto;
-#[cfg(feature = "rustcrypto")]
+#[cfg(feature = "pkcs11")]
+use caliptra_image_crypto::Pkcs11Crypto as Crypto;
+#[cfg(all(feature = "rustcrypto", not(feature = "pkcs11")))]
 use caliptra_image_crypto::RustCrypto as Crypto;
-use caliptra_image_crypto::{lms_priv_key_from_pem, lms_pub_key_from_pem};
+use caliptra_image_crypto::{lms_priv_key_from_pem, lms_pub_key_from_pem, EccPrivKeyLoader};
 use caliptra_image_gen::*;
 use serde_derive::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -43,6 +45,7 @@ pub struct ImageMetadataConfigFromFile {
     source: u32,
     fw_id: u32,
     ignore_auth_check: bool,
+    svn: Option<u32>,
 }
 
 // Authorization Manifest configuration from TOML file
@@ -72,15 +75,30 @@ pub(crate) fn load_auth_man_config_from_file(
     Ok(config)
 }
 
+/// Load only the public keys of a key configuration. Private keys are not
+/// read, so this works with a configuration that has none.
+pub(crate) fn pub_keys_from_file(
+    path: &Path,
+    config: &AuthManifestKeyConfigFromFile,
+) -> anyhow::Result<AuthManifestPubKeys> {
+    Ok(AuthManifestPubKeys {
+        ecc_pub_key: Crypto::ecc_pub_key_from_pem(&path.join(&config.ecc_pub_key))?,
+        lms_pub_key: lms_pub_key_from_pem(&path.join(&config.lms_pub_key))?,
+    })
+}
+
 fn key_config_from_file(
+    crypto: &mut Crypto,
     path: &Path,
     config: &AuthManifestKeyConfigFromFile,
 ) -> anyhow::Result<AuthManifestGeneratorKeyConfig> {
-    // Get the Private Keys.
+    let pub_keys = pub_keys_from_file(path, config)?;
+
+    // Get the Private Keys. Without private keys the manifest is signed
+    // outside of this tool.
     let mut priv_keys = AuthManifestPrivKeys::default();
-    if let Some(pem_file) = &config.ecc_priv_key {
-        let priv_key_path = path.join(pem_file);
-        priv_keys.ecc_priv_key = Crypto::ecc_priv_key_from_pem(&priv_key_path)?;
+    if let Some(key) = &config.ecc_priv_key {
+        priv_keys.ecc_priv_key = crypto.load_ecc_priv_key(path, key, &pub_keys.ecc_pub_key)?;
     }
 
     if let Some(pem_file) = &config.lms_priv_key {
@@ -89,28 +107,28 @@ fn key_config_from_file(
     }
 
     Ok(AuthManifestGeneratorKeyConfig {
-        pub_keys: AuthManifestPubKeys {
-            ecc_pub_key: Crypto::ecc_pub_key_from_pem(&path.join(&config.ecc_pub_key))?,
-            lms_pub_key: lms_pub_key_from_pem(&path.join(&config.lms_pub_key))?,
-        },
+        pub_keys,
 
-        priv_keys: Some(priv_keys),
+        priv_keys: (config.ecc_priv_key.is_some() || config.lms_priv_key.is_some())
+            .then_some(priv_keys),
     })
 }
 
 pub(crate) fn vendor_config_from_file(
+    crypto: &mut Crypto,
     path: &Path,
     config: &AuthManifestKeyConfigFromFile,
 ) -> anyhow::Result<AuthManifestGeneratorKeyConfig> {
-    key_config_from_file(path, config)
+    key_config_from_file(crypto, path, config)
 }
 
 pub(crate) fn owner_config_from_file(
+    crypto: &mut Crypto,
     path: &Path,
     config: &Option<AuthManifestKeyConfigFromFile>,
 ) -> anyhow::Result<Option<AuthManifestGeneratorKeyConfig>> {
     if let Some(config) = config {
-        let gen_config = key_config_from_file(path, config)?;
+        let gen_config = key_config_from_file(crypto, path, config)?;
         Ok(Some(gen_config))
     } else {
         Ok(None)
@@ -141,6 +159,7 @@ pub(crate) fn image_metadata_config_from_file(
         let image_metadata = AuthManifestImageMetadata {
             fw_id: image.fw_id,
             flags: flags.0,
+            svn: image.svn.unwrap_or(0),
             digest: digest_vec.try_into().unwrap(),
         };
 
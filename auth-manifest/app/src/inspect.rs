@@ -0,0 +1,146 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   inspect.rs
+
+Abstract:
+
+    File contains the readable and JSON dump of an authorization manifest.
+
+--*/
+
+use crate::verify::ParsedAuthManifest;
+use caliptra_auth_man_types::{AuthManifestFlags, AuthManifestPubKeys, ImageMetadataFlags};
+use serde_derive::Serialize;
+use zerocopy::IntoBytes;
+
+#[derive(Serialize)]
+struct EccPubKeyDump {
+    x: String,
+    y: String,
+}
+
+#[derive(Serialize)]
+struct PubKeysDump {
+    ecc_pub_key: EccPubKeyDump,
+    lms_pub_key: String,
+}
+
+#[derive(Serialize)]
+struct ImageMetadataDump {
+    fw_id: u32,
+    flags: u32,
+    image_source: u32,
+    ignore_auth_check: bool,
+    svn: u32,
+    digest: String,
+}
+
+#[derive(Serialize)]
+pub(crate) struct AuthManifestDump {
+    marker: u32,
+    size: u32,
+    version: u32,
+    flags: u32,
+    vendor_signature_required: bool,
+    vendor_pub_keys: PubKeysDump,
+    owner_pub_keys: PubKeysDump,
+    entry_count: u32,
+    image_metadata_list: Vec<ImageMetadataDump>,
+}
+
+/// Convert a big-endian word array to a hex string.
+fn words_to_hex(words: &[u32]) -> String {
+    words.iter().map(|word| format!("{word:08x}")).collect()
+}
+
+impl From<&AuthManifestPubKeys> for PubKeysDump {
+    fn from(keys: &AuthManifestPubKeys) -> Self {
+        Self {
+            ecc_pub_key: EccPubKeyDump {
+                x: words_to_hex(&keys.ecc_pub_key.x),
+                y: words_to_hex(&keys.ecc_pub_key.y),
+            },
+            lms_pub_key: hex::encode(keys.lms_pub_key.as_bytes()),
+        }
+    }
+}
+
+impl From<&ParsedAuthManifest> for AuthManifestDump {
+    fn from(parsed: &ParsedAuthManifest) -> Self {
+        let preamble = &parsed.manifest.preamble;
+        Self {
+            marker: preamble.marker,
+            size: preamble.size,
+            version: preamble.version,
+            flags: preamble.flags,
+            vendor_signature_required: AuthManifestFlags::from(preamble.flags)
+                .contains(AuthManifestFlags::VENDOR_SIGNATURE_REQUIRED),
+            vendor_pub_keys: (&preamble.vendor_pub_keys).into(),
+            owner_pub_keys: (&preamble.owner_pub_keys).into(),
+            entry_count: parsed.manifest.image_metadata_col.entry_count,
+            image_metadata_list: parsed
+                .image_metadata_list()
+                .iter()
+                .map(|metadata| {
+                    let flags = ImageMetadataFlags(metadata.flags);
+                    ImageMetadataDump {
+                        fw_id: metadata.fw_id,
+                        flags: metadata.flags,
+                        image_source: flags.image_source(),
+                        ignore_auth_check: flags.ignore_auth_check(),
+                        svn: metadata.svn,
+                        digest: hex::encode(metadata.digest),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl AuthManifestDump {
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        let mut line = |label: &str, value: String| {
+            out += &format!("{:<28}{}\n", format!("{label}:"), value);
+        };
+        line("Marker", format!("0x{:08x}", self.marker));
+        line("Preamble size", self.size.to_string());
+        line("Version", self.version.to_string());
+        line("Flags", format!("0x{:08x}", self.flags));
+        line(
+            "Vendor signature required",
+            self.vendor_signature_required.to_string(),
+        );
+        for (name, keys) in [
+            ("Vendor", &self.vendor_pub_keys),
+            ("Owner", &self.owner_pub_keys),
+        ] {
+            line(
+                &format!("{name} ECC public key X"),
+                keys.ecc_pub_key.x.clone(),
+            );
+            line(
+                &format!("{name} ECC public key Y"),
+                keys.ecc_pub_key.y.clone(),
+            );
+            line(&format!("{name} LMS public key"), keys.lms_pub_key.clone());
+        }
+        line("Image metadata entries", self.entry_count.to_string());
+        for (i, entry) in self.image_metadata_list.iter().enumerate() {
+            out += &format!(
+                "  [{i}] fw_id={} flags=0x{:08x} source={} ignore_auth_check={} svn={}\n",
+                entry.fw_id, entry.flags, entry.image_source, entry.ignore_auth_check, entry.svn
+            );
+            out += &format!("      digest={}\n", entry.digest);
+        }
+        out
+    }
+}
@@ -13,49 +13,131 @@ Abstract:
 --*/
 
 use anyhow::Context;
-use caliptra_auth_man_gen::{AuthManifestGenerator, AuthManifestGeneratorConfig};
-use caliptra_auth_man_types::AuthManifestFlags;
-#[cfg(feature = "openssl")]
+use caliptra_auth_man_gen::{
+    AuthManifestDetachedSignatures, AuthManifestGenerator, AuthManifestGeneratorConfig,
+};
+use caliptra_auth_man_types::{AuthManifestFlags, AuthManifestSignatures};
+#[cfg(all(feature = "openssl", not(feature = "pkcs11")))]
 use caliptra_image_crypto::OsslCrypto as Crypto;
-#[cfg(feature = "rustcrypto")]
+#[cfg(feature = "pkcs11")]
+use caliptra_image_crypto::Pkcs11Crypto as Crypto;
+#[cfg(all(feature = "rustcrypto", not(feature = "pkcs11")))]
 use caliptra_image_crypto::RustCrypto as Crypto;
+use caliptra_image_crypto::{ecc_sig_from_file, lms_sig_from_file, write_tbs};
 use clap::ArgMatches;
-use clap::{arg, value_parser, Command};
+use clap::{arg, value_parser, ArgAction, Command};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use zerocopy::IntoBytes;
 
 mod config;
+mod inspect;
+mod verify;
 
 /// Entry point
 fn main() {
-    let sub_cmds = vec![Command::new("create-auth-man")
-        .about("Create a new authorization manifest")
-        .arg(
-            arg!(--"version" <U32> "Manifest Version Number")
-                .required(true)
-                .value_parser(value_parser!(u32)),
-        )
-        .arg(
-            arg!(--"flags" <U32> "Manifest Flags")
-                .required(true)
-                .value_parser(value_parser!(u32)),
-        )
-        .arg(
-            arg!(--"key-dir" <FILE> "Key files directory path")
-                .required(true)
-                .value_parser(value_parser!(PathBuf)),
-        )
-        .arg(
-            arg!(--"config" <FILE> "Manifest configuration file")
-                .required(true)
-                .value_parser(value_parser!(PathBuf)),
-        )
-        .arg(
-            arg!(--"out" <FILE> "Output file")
-                .required(true)
-                .value_parser(value_parser!(PathBuf)),
-        )];
+    let sub_cmds = vec![
+        Command::new("create-auth-man")
+            .about("Create a new authorization manifest")
+            .arg(
+                arg!(--"version" <U32> "Manifest Version Number")
+                    .required(true)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"flags" <U32> "Manifest Flags")
+                    .required(true)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"key-dir" <FILE> "Key files directory path")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"config" <FILE> "Manifest configuration file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"out" <FILE> "Output file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            ),
+        Command::new("export-tbs")
+            .about("Export the data to sign for an unsigned authorization manifest")
+            .arg(
+                arg!(--"manifest" <FILE> "Unsigned authorization manifest file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"out-dir" <DIR> "Output directory")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            ),
+        Command::new("assemble")
+            .about("Insert externally produced signatures into an authorization manifest")
+            .arg(
+                arg!(--"manifest" <FILE> "Unsigned authorization manifest file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"key-dir" <FILE> "Key files directory path")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"config" <FILE> "Manifest configuration file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"sig-dir" <DIR> "Signature files directory path")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"out" <FILE> "Output file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            ),
+        Command::new("verify")
+            .about("Verify the signatures and image metadata of an authorization manifest")
+            .arg(
+                arg!(--"manifest" <FILE> "Authorization manifest file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"key-dir" <FILE> "Key files directory path")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"config" <FILE> "Manifest configuration file with firmware keys")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"skip-lms" "Skip LMS signature verification")
+                    .required(false)
+                    .action(ArgAction::SetTrue),
+            ),
+        Command::new("inspect")
+            .about("Print the contents of an authorization manifest")
+            .arg(
+                arg!(--"manifest" <FILE> "Authorization manifest file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"json" "Print the manifest as JSON")
+                    .required(false)
+                    .action(ArgAction::SetTrue),
+            ),
+    ];
 
     let cmd = Command::new("caliptra-auth-man-app")
         .arg_required_else_help(true)
@@ -65,6 +147,10 @@ fn main() {
 
     let result = match cmd.subcommand().unwrap() {
         ("create-auth-man", args) => run_auth_man_cmd(args),
+        ("export-tbs", args) => run_export_tbs_cmd(args),
+        ("assemble", args) => run_assemble_cmd(args),
+        ("verify", args) => run_verify_cmd(args),
+        ("inspect", args) => run_inspect_cmd(args),
         (_, _) => unreachable!(),
     };
 
@@ -102,34 +188,216 @@ pub(crate) fn run_auth_man_cmd(args: &ArgMatches) -> anyhow::Result<()> {
         .get_one::<PathBuf>("out")
         .with_context(|| "out arg not specified")?;
 
-    // Load the manifest configuration from the config file.
+    let mut crypto = Crypto::default();
+    let gen_config = load_gen_config(&mut crypto, config_path, key_dir, *version, flags)?;
+
+    let gen = AuthManifestGenerator::new(crypto);
+    let manifest = gen.generate(&gen_config).unwrap();
+
+    let mut out_file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(out_path)
+        .with_context(|| format!("Failed to create file {}", out_path.display()))?;
+
+    out_file.write_all(manifest.as_bytes())?;
+
+    Ok(())
+}
+
+/// Load the manifest configuration from the config file and decode it.
+fn load_gen_config(
+    crypto: &mut Crypto,
+    config_path: &PathBuf,
+    key_dir: &Path,
+    version: u32,
+    flags: AuthManifestFlags,
+) -> anyhow::Result<AuthManifestGeneratorConfig> {
     let config = config::load_auth_man_config_from_file(config_path)?;
 
-    // Decode the configuration.
-    let gen_config = AuthManifestGeneratorConfig {
-        version: *version,
+    Ok(AuthManifestGeneratorConfig {
+        version,
         flags,
         vendor_man_key_info: config::vendor_config_from_file(
+            crypto,
             key_dir,
             &config.vendor_man_key_config,
         )?,
-        owner_man_key_info: config::owner_config_from_file(key_dir, &config.owner_man_key_config)?,
-        vendor_fw_key_info: config::vendor_config_from_file(key_dir, &config.vendor_fw_key_config)?,
-        owner_fw_key_info: config::owner_config_from_file(key_dir, &config.owner_fw_key_config)?,
+        owner_man_key_info: config::owner_config_from_file(
+            crypto,
+            key_dir,
+            &config.owner_man_key_config,
+        )?,
+        vendor_fw_key_info: config::vendor_config_from_file(
+            crypto,
+            key_dir,
+            &config.vendor_fw_key_config,
+        )?,
+        owner_fw_key_info: config::owner_config_from_file(
+            crypto,
+            key_dir,
+            &config.owner_fw_key_config,
+        )?,
         image_metadata_list: config::image_metadata_config_from_file(&config.image_metadata_list)?,
-    };
+    })
+}
+
+/// Names of the signed data of a manifest. The signature files in the
+/// signature directory are named `<name>.ecc.sig` and `<name>.lms.sig`.
+const VENDOR_PUB_KEYS_TBS: &str = "vendor-pub-keys";
+const OWNER_PUB_KEYS_TBS: &str = "owner-pub-keys";
+const IMAGE_METADATA_TBS: &str = "image-metadata";
+const VENDOR_IMAGE_METADATA_SIG: &str = "vendor-image-metadata";
+const OWNER_IMAGE_METADATA_SIG: &str = "owner-image-metadata";
+
+pub(crate) fn run_export_tbs_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let out_dir: &PathBuf = args
+        .get_one::<PathBuf>("out-dir")
+        .with_context(|| "out-dir arg not specified")?;
+
+    let manifest = read_manifest(args)?;
+
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("Failed to create directory {}", out_dir.display()))?;
 
     let gen = AuthManifestGenerator::new(Crypto::default());
-    let manifest = gen.generate(&gen_config).unwrap();
+    let tbs = gen.to_be_signed(&manifest.manifest)?;
 
-    let mut out_file = std::fs::OpenOptions::new()
-        .create(true)
-        .truncate(true)
-        .write(true)
-        .open(out_path)
-        .with_context(|| format!("Failed to create file {}", out_path.display()))?;
+    write_tbs(out_dir, VENDOR_PUB_KEYS_TBS, &tbs.vendor_pub_keys)?;
+    write_tbs(out_dir, OWNER_PUB_KEYS_TBS, &tbs.owner_pub_keys)?;
+    write_tbs(out_dir, IMAGE_METADATA_TBS, &tbs.image_metadata)?;
 
-    out_file.write_all(manifest.as_bytes())?;
+    Ok(())
+}
+
+/// Read `<name>.ecc.sig` and `<name>.lms.sig` if present.
+fn read_sigs(sig_dir: &Path, name: &str) -> anyhow::Result<Option<AuthManifestSignatures>> {
+    let ecc_path = sig_dir.join(format!("{name}.ecc.sig"));
+    let lms_path = sig_dir.join(format!("{name}.lms.sig"));
+
+    match (ecc_path.exists(), lms_path.exists()) {
+        (true, true) => Ok(Some(AuthManifestSignatures {
+            ecc_sig: ecc_sig_from_file(&ecc_path)?,
+            lms_sig: lms_sig_from_file(&lms_path)?,
+        })),
+        (false, false) => Ok(None),
+        _ => Err(anyhow::anyhow!(
+            "Both {} and {} are required",
+            ecc_path.display(),
+            lms_path.display()
+        )),
+    }
+}
+
+pub(crate) fn run_assemble_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let config_path: &PathBuf = args
+        .get_one::<PathBuf>("config")
+        .with_context(|| "config arg not specified")?;
+
+    let key_dir: &PathBuf = args
+        .get_one::<PathBuf>("key-dir")
+        .with_context(|| "key-dir arg not specified")?;
+
+    let sig_dir: &PathBuf = args
+        .get_one::<PathBuf>("sig-dir")
+        .with_context(|| "sig-dir arg not specified")?;
+
+    let out_path: &PathBuf = args
+        .get_one::<PathBuf>("out")
+        .with_context(|| "out arg not specified")?;
+
+    let mut manifest = read_manifest(args)?.manifest;
+
+    let preamble = &manifest.preamble;
+    let mut crypto = Crypto::default();
+    let gen_config = load_gen_config(
+        &mut crypto,
+        config_path,
+        key_dir,
+        preamble.version,
+        AuthManifestFlags::from_bits_truncate(preamble.flags),
+    )?;
+
+    let sigs = AuthManifestDetachedSignatures {
+        vendor_pub_keys: read_sigs(sig_dir, VENDOR_PUB_KEYS_TBS)?,
+        owner_pub_keys: read_sigs(sig_dir, OWNER_PUB_KEYS_TBS)?,
+        vendor_image_metadata: read_sigs(sig_dir, VENDOR_IMAGE_METADATA_SIG)?,
+        owner_image_metadata: read_sigs(sig_dir, OWNER_IMAGE_METADATA_SIG)?,
+    };
+
+    let gen = AuthManifestGenerator::new(crypto);
+    gen.insert_signatures(&gen_config, &mut manifest, &sigs)?;
+
+    std::fs::write(out_path, manifest.as_bytes())
+        .with_context(|| format!("Failed to write file {}", out_path.display()))?;
+
+    Ok(())
+}
+
+fn read_manifest(args: &ArgMatches) -> anyhow::Result<verify::ParsedAuthManifest> {
+    let manifest_path: &PathBuf = args
+        .get_one::<PathBuf>("manifest")
+        .with_context(|| "manifest arg not specified")?;
+
+    let buf = std::fs::read(manifest_path)
+        .with_context(|| format!("Failed to read file {}", manifest_path.display()))?;
+
+    verify::parse_auth_manifest(&buf)
+}
+
+pub(crate) fn run_verify_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let config_path: &PathBuf = args
+        .get_one::<PathBuf>("config")
+        .with_context(|| "config arg not specified")?;
+
+    if !config_path.exists() {
+        return Err(anyhow::anyhow!("Invalid config file path"));
+    }
+
+    let key_dir: &PathBuf = args
+        .get_one::<PathBuf>("key-dir")
+        .with_context(|| "key-dir arg not specified")?;
+
+    if !key_dir.exists() {
+        return Err(anyhow::anyhow!("Invalid key directory path"));
+    }
+
+    let manifest = read_manifest(args)?;
+
+    // The firmware keys sign the manifest preamble. Only their public keys
+    // are needed.
+    let config = config::load_auth_man_config_from_file(config_path)?;
+    let owner_fw_key_config = config
+        .owner_fw_key_config
+        .as_ref()
+        .with_context(|| "owner_fw_key_config not specified")?;
+    let fw_keys = verify::FwPubKeys {
+        vendor: config::pub_keys_from_file(key_dir, &config.vendor_fw_key_config)?,
+        owner: config::pub_keys_from_file(key_dir, owner_fw_key_config)?,
+    };
+
+    let crypto = Crypto::default();
+    let verifier = verify::AuthManifestVerifier::new(&crypto, !args.get_flag("skip-lms"));
+    verifier.verify(&manifest, &fw_keys)?;
+
+    println!(
+        "Authorization manifest OK ({} image metadata entries)",
+        manifest.image_metadata_list().len()
+    );
+
+    Ok(())
+}
+
+pub(crate) fn run_inspect_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let manifest = read_manifest(args)?;
+    let dump = inspect::AuthManifestDump::from(&manifest);
+
+    if args.get_flag("json") {
+        println!("{}", dump.to_json()?);
+    } else {
+        print!("{}", dump.to_text());
+    }
 
     Ok(())
 }
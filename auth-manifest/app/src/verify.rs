@@ -0,0 +1,266 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   verify.rs
+
+Abstract:
+
+    File contains the offline verification of an authorization manifest.
+
+--*/
+
+use anyhow::{anyhow, bail};
+use caliptra_auth_man_types::{
+    AuthManifestFlags, AuthManifestImageMetadata, AuthManifestPreamble, AuthManifestPubKeys,
+    AuthManifestSignatures, AuthorizationManifest, AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT,
+    AUTH_MANIFEST_MARKER,
+};
+use caliptra_image_gen::ImageGeneratorCrypto;
+use caliptra_image_types::ImageDigest;
+use core::mem::size_of;
+use std::cmp::min;
+use zerocopy::{FromZeros, IntoBytes};
+
+/// Authorization manifest parsed from a file.
+pub(crate) struct ParsedAuthManifest {
+    pub manifest: AuthorizationManifest,
+
+    /// Bytes of the image metadata collection present in the file. A manifest
+    /// may omit the unused tail of the collection.
+    pub image_metadata_col_len: usize,
+}
+
+impl ParsedAuthManifest {
+    pub fn image_metadata_col_bytes(&self) -> &[u8] {
+        &self.manifest.image_metadata_col.as_bytes()[..self.image_metadata_col_len]
+    }
+
+    pub fn image_metadata_list(&self) -> &[AuthManifestImageMetadata] {
+        let count = min(
+            self.manifest.image_metadata_col.entry_count as usize,
+            AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT,
+        );
+        &self.manifest.image_metadata_col.image_metadata_list[..count]
+    }
+}
+
+/// Parse an authorization manifest from `buf`.
+pub(crate) fn parse_auth_manifest(buf: &[u8]) -> anyhow::Result<ParsedAuthManifest> {
+    let preamble_size = size_of::<AuthManifestPreamble>();
+    if buf.len() < preamble_size + size_of::<u32>() {
+        bail!(
+            "Manifest too small: {} bytes, expected at least {}",
+            buf.len(),
+            preamble_size + size_of::<u32>()
+        );
+    }
+    if buf.len() > size_of::<AuthorizationManifest>() {
+        bail!(
+            "Manifest too large: {} bytes, expected at most {}",
+            buf.len(),
+            size_of::<AuthorizationManifest>()
+        );
+    }
+
+    let mut manifest = AuthorizationManifest::new_zeroed();
+    manifest.as_mut_bytes()[..buf.len()].copy_from_slice(buf);
+
+    Ok(ParsedAuthManifest {
+        manifest,
+        image_metadata_col_len: buf.len() - preamble_size,
+    })
+}
+
+/// Public keys of the firmware image the manifest is used with. These are
+/// the keys the runtime verifies the manifest preamble against.
+pub(crate) struct FwPubKeys {
+    pub vendor: AuthManifestPubKeys,
+
+    pub owner: AuthManifestPubKeys,
+}
+
+/// Checks an authorization manifest the same way SET_AUTH_MANIFEST does.
+pub(crate) struct AuthManifestVerifier<'a, Crypto: ImageGeneratorCrypto> {
+    crypto: &'a Crypto,
+
+    verify_lms: bool,
+}
+
+impl<'a, Crypto: ImageGeneratorCrypto> AuthManifestVerifier<'a, Crypto> {
+    pub fn new(crypto: &'a Crypto, verify_lms: bool) -> Self {
+        Self { crypto, verify_lms }
+    }
+
+    pub fn verify(&self, parsed: &ParsedAuthManifest, fw_keys: &FwPubKeys) -> anyhow::Result<()> {
+        let preamble = &parsed.manifest.preamble;
+
+        if preamble.marker != AUTH_MANIFEST_MARKER {
+            bail!(
+                "Invalid manifest marker 0x{:08x}, expected 0x{:08x}",
+                preamble.marker,
+                AUTH_MANIFEST_MARKER
+            );
+        }
+
+        if preamble.size as usize != size_of::<AuthManifestPreamble>() {
+            bail!(
+                "Invalid preamble size {}, expected {}",
+                preamble.size,
+                size_of::<AuthManifestPreamble>()
+            );
+        }
+
+        // Vendor signed data (version, flags and vendor manifest public keys).
+        let range = AuthManifestPreamble::vendor_signed_data_range();
+        let digest = self.digest_range(preamble.as_bytes(), range.start, range.len())?;
+        self.verify_sigs(
+            "vendor public keys",
+            &digest,
+            &fw_keys.vendor,
+            &preamble.vendor_pub_keys_signatures,
+        )?;
+
+        // Owner manifest public keys.
+        let range = AuthManifestPreamble::owner_pub_keys_range();
+        let digest = self.digest_range(preamble.as_bytes(), range.start, range.len())?;
+        self.verify_sigs(
+            "owner public keys",
+            &digest,
+            &fw_keys.owner,
+            &preamble.owner_pub_keys_signatures,
+        )?;
+
+        // Image metadata collection.
+        let entry_count = parsed.manifest.image_metadata_col.entry_count;
+        if entry_count == 0 || entry_count as usize > AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT {
+            bail!("Invalid image metadata entry count {}", entry_count);
+        }
+        if parsed.image_metadata_col_len
+            < size_of::<u32>() + entry_count as usize * size_of::<AuthManifestImageMetadata>()
+        {
+            bail!(
+                "Image metadata collection truncated: {} bytes for {} entries",
+                parsed.image_metadata_col_len,
+                entry_count
+            );
+        }
+        let digest = self
+            .crypto
+            .sha384_digest(parsed.image_metadata_col_bytes())?;
+
+        let flags = AuthManifestFlags::from(preamble.flags);
+        if flags.contains(AuthManifestFlags::VENDOR_SIGNATURE_REQUIRED) {
+            self.verify_sigs(
+                "vendor image metadata",
+                &digest,
+                &preamble.vendor_pub_keys,
+                &preamble.vendor_image_metdata_signatures,
+            )?;
+        }
+        self.verify_sigs(
+            "owner image metadata",
+            &digest,
+            &preamble.owner_pub_keys,
+            &preamble.owner_image_metdata_signatures,
+        )?;
+
+        check_sorted_and_unique(parsed.image_metadata_list())
+    }
+
+    fn digest_range(&self, buf: &[u8], offset: u32, len: usize) -> anyhow::Result<ImageDigest> {
+        let data = buf
+            .get(offset as usize..)
+            .and_then(|data| data.get(..len))
+            .ok_or_else(|| anyhow!("Signed data range out of bounds"))?;
+        self.crypto.sha384_digest(data)
+    }
+
+    fn verify_sigs(
+        &self,
+        name: &str,
+        digest: &ImageDigest,
+        pub_keys: &AuthManifestPubKeys,
+        sigs: &AuthManifestSignatures,
+    ) -> anyhow::Result<()> {
+        if !self
+            .crypto
+            .ecdsa384_verify(digest, &pub_keys.ecc_pub_key, &sigs.ecc_sig)?
+        {
+            bail!("Invalid ECC signature over {}", name);
+        }
+
+        if self.verify_lms
+            && !self
+                .crypto
+                .lms_verify(digest, &pub_keys.lms_pub_key, &sigs.lms_sig)?
+        {
+            bail!("Invalid LMS signature over {}", name);
+        }
+
+        Ok(())
+    }
+}
+
+/// The runtime rejects manifests with duplicate firmware IDs. Also require
+/// the list to be sorted so that the manifest matches what the runtime stores.
+fn check_sorted_and_unique(list: &[AuthManifestImageMetadata]) -> anyhow::Result<()> {
+    for pair in list.windows(2) {
+        match pair[0].fw_id.cmp(&pair[1].fw_id) {
+            core::cmp::Ordering::Less => {}
+            core::cmp::Ordering::Equal => {
+                bail!(
+                    "Duplicate firmware ID {} in image metadata list",
+                    pair[0].fw_id
+                )
+            }
+            core::cmp::Ordering::Greater => bail!(
+                "Image metadata list not sorted: firmware ID {} precedes {}",
+                pair[0].fw_id,
+                pair[1].fw_id
+            ),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use caliptra_auth_man_types::AuthManifestImageMetadataCollection;
+
+    fn metadata(fw_id: u32) -> AuthManifestImageMetadata {
+        AuthManifestImageMetadata {
+            fw_id,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_sorted_and_unique() {
+        assert!(check_sorted_and_unique(&[]).is_ok());
+        assert!(check_sorted_and_unique(&[metadata(1), metadata(2), metadata(7)]).is_ok());
+        assert!(check_sorted_and_unique(&[metadata(2), metadata(1)]).is_err());
+        assert!(check_sorted_and_unique(&[metadata(1), metadata(1)]).is_err());
+    }
+
+    #[test]
+    fn test_parse_auth_manifest_size() {
+        let manifest = AuthorizationManifest::default();
+        let bytes = manifest.as_bytes();
+
+        let parsed = parse_auth_manifest(bytes).unwrap();
+        assert_eq!(
+            parsed.image_metadata_col_len,
+            size_of::<AuthManifestImageMetadataCollection>()
+        );
+
+        assert!(parse_auth_manifest(&bytes[..size_of::<AuthManifestPreamble>()]).is_err());
+
+        let mut too_large = bytes.to_vec();
+        too_large.push(0);
+        assert!(parse_auth_manifest(&too_large).is_err());
+    }
+}
@@ -12,7 +12,9 @@ Abstract:
 
 --*/
 
-use caliptra_image_gen::ImageGeneratorCrypto;
+use anyhow::bail;
+use caliptra_image_gen::{ImageGeneratorCrypto, ToBeSigned};
+use caliptra_image_types::ImageDigest;
 use zerocopy::IntoBytes;
 
 use crate::*;
@@ -55,113 +57,212 @@ impl<Crypto: ImageGeneratorCrypto> AuthManifestGenerator<Crypto> {
         auth_manifest.preamble.version = config.version;
         auth_manifest.preamble.flags = config.flags.bits();
 
-        // Sign the vendor manifest public keys.
         auth_manifest.preamble.vendor_pub_keys = config.vendor_man_key_info.pub_keys;
+        if let (Some(_), Some(owner_man_config)) =
+            (&config.owner_fw_key_info, &config.owner_man_key_info)
+        {
+            auth_manifest.preamble.owner_pub_keys = owner_man_config.pub_keys;
+        }
 
-        let range = AuthManifestPreamble::vendor_signed_data_range();
-
-        let data = auth_manifest
-            .preamble
-            .as_bytes()
-            .get(range.start as usize..)
-            .ok_or_else(|| anyhow::anyhow!("Failed to get vendor signed data range start"))?
-            .get(..range.len())
-            .ok_or(anyhow::anyhow!(
-                "Failed to get vendor signed data range length"
-            ))?;
-
-        let digest = self.crypto.sha384_digest(data)?;
+        let tbs = self.to_be_signed(&auth_manifest)?;
 
-        if let Some(priv_keys) = config.vendor_fw_key_info.priv_keys {
-            let sig = self.crypto.ecdsa384_sign(
-                &digest,
-                &priv_keys.ecc_priv_key,
-                &config.vendor_fw_key_info.pub_keys.ecc_pub_key,
+        // Sign the vendor manifest public keys.
+        if let Some(priv_keys) = &config.vendor_fw_key_info.priv_keys {
+            auth_manifest.preamble.vendor_pub_keys_signatures = self.sign(
+                &tbs.vendor_pub_keys.digest,
+                priv_keys,
+                &config.vendor_fw_key_info.pub_keys,
             )?;
-            auth_manifest.preamble.vendor_pub_keys_signatures.ecc_sig = sig;
-
-            let lms_sig = self.crypto.lms_sign(&digest, &priv_keys.lms_priv_key)?;
-            auth_manifest.preamble.vendor_pub_keys_signatures.lms_sig = lms_sig;
         }
 
         // Sign the owner manifest public keys.
-        if let (Some(owner_fw_config), Some(owner_man_config)) =
+        if let (Some(owner_fw_config), Some(_)) =
             (&config.owner_fw_key_info, &config.owner_man_key_info)
         {
-            auth_manifest.preamble.owner_pub_keys = owner_man_config.pub_keys;
-
-            let digest = self
-                .crypto
-                .sha384_digest(auth_manifest.preamble.owner_pub_keys.as_bytes())?;
-
-            if let Some(owner_fw_priv_keys) = owner_fw_config.priv_keys {
-                let sig = self.crypto.ecdsa384_sign(
-                    &digest,
-                    &owner_fw_priv_keys.ecc_priv_key,
-                    &owner_fw_config.pub_keys.ecc_pub_key,
+            if let Some(owner_fw_priv_keys) = &owner_fw_config.priv_keys {
+                auth_manifest.preamble.owner_pub_keys_signatures = self.sign(
+                    &tbs.owner_pub_keys.digest,
+                    owner_fw_priv_keys,
+                    &owner_fw_config.pub_keys,
                 )?;
-                auth_manifest.preamble.owner_pub_keys_signatures.ecc_sig = sig;
-                let lms_sig = self
-                    .crypto
-                    .lms_sign(&digest, &owner_fw_priv_keys.lms_priv_key)?;
-                auth_manifest.preamble.owner_pub_keys_signatures.lms_sig = lms_sig;
             }
         }
 
-        // Hash the IMC.
-        let digest = self
-            .crypto
-            .sha384_digest(auth_manifest.image_metadata_col.as_bytes())?;
-
         // Sign the IMC with the vendor manifest public keys if indicated in the flags.
         if config
             .flags
             .contains(AuthManifestFlags::VENDOR_SIGNATURE_REQUIRED)
         {
-            if let Some(vendor_man_priv_keys) = config.vendor_man_key_info.priv_keys {
-                let sig = self.crypto.ecdsa384_sign(
-                    &digest,
-                    &vendor_man_priv_keys.ecc_priv_key,
-                    &config.vendor_man_key_info.pub_keys.ecc_pub_key,
+            if let Some(vendor_man_priv_keys) = &config.vendor_man_key_info.priv_keys {
+                auth_manifest.preamble.vendor_image_metdata_signatures = self.sign(
+                    &tbs.image_metadata.digest,
+                    vendor_man_priv_keys,
+                    &config.vendor_man_key_info.pub_keys,
                 )?;
-                auth_manifest
-                    .preamble
-                    .vendor_image_metdata_signatures
-                    .ecc_sig = sig;
-
-                let lms_sig = self
-                    .crypto
-                    .lms_sign(&digest, &vendor_man_priv_keys.lms_priv_key)?;
-                auth_manifest
-                    .preamble
-                    .vendor_image_metdata_signatures
-                    .lms_sig = lms_sig;
             }
         }
 
         // Sign the IMC with the owner manifest public keys.
         if let Some(owner_man_config) = &config.owner_man_key_info {
             if let Some(owner_man_priv_keys) = &owner_man_config.priv_keys {
-                let sig = self.crypto.ecdsa384_sign(
-                    &digest,
-                    &owner_man_priv_keys.ecc_priv_key,
-                    &owner_man_config.pub_keys.ecc_pub_key,
+                auth_manifest.preamble.owner_image_metdata_signatures = self.sign(
+                    &tbs.image_metadata.digest,
+                    owner_man_priv_keys,
+                    &owner_man_config.pub_keys,
                 )?;
-                auth_manifest
-                    .preamble
-                    .owner_image_metdata_signatures
-                    .ecc_sig = sig;
-
-                let lms_sig = self
-                    .crypto
-                    .lms_sign(&digest, &owner_man_priv_keys.lms_priv_key)?;
-                auth_manifest
-                    .preamble
-                    .owner_image_metdata_signatures
-                    .lms_sig = lms_sig;
             }
         }
 
         Ok(auth_manifest)
     }
+
+    /// Get the data signed for an authorization manifest
+    ///
+    /// Used to sign a manifest generated without private keys outside of the
+    /// generator. The signatures are added with `insert_signatures`.
+    pub fn to_be_signed(
+        &self,
+        auth_manifest: &AuthorizationManifest,
+    ) -> anyhow::Result<AuthManifestToBeSigned> {
+        let range = AuthManifestPreamble::vendor_signed_data_range();
+        let vendor_signed_data = auth_manifest
+            .preamble
+            .as_bytes()
+            .get(range.start as usize..)
+            .ok_or_else(|| anyhow::anyhow!("Failed to get vendor signed data range start"))?
+            .get(..range.len())
+            .ok_or(anyhow::anyhow!(
+                "Failed to get vendor signed data range length"
+            ))?;
+
+        Ok(AuthManifestToBeSigned {
+            vendor_pub_keys: self.to_be_signed_data(vendor_signed_data)?,
+            owner_pub_keys: self
+                .to_be_signed_data(auth_manifest.preamble.owner_pub_keys.as_bytes())?,
+            image_metadata: self.to_be_signed_data(auth_manifest.image_metadata_col.as_bytes())?,
+        })
+    }
+
+    /// Insert externally produced signatures into the manifest
+    ///
+    /// The signatures required by `config` must be present. Each signature is
+    /// checked against the matching public keys in `config` before the
+    /// manifest is updated.
+    pub fn insert_signatures(
+        &self,
+        config: &AuthManifestGeneratorConfig,
+        auth_manifest: &mut AuthorizationManifest,
+        sigs: &AuthManifestDetachedSignatures,
+    ) -> anyhow::Result<()> {
+        let tbs = self.to_be_signed(auth_manifest)?;
+
+        let owner_man_pub_keys = config
+            .owner_man_key_info
+            .as_ref()
+            .map(|owner_man_config| &owner_man_config.pub_keys);
+        let owner_fw_pub_keys = match owner_man_pub_keys {
+            Some(_) => config
+                .owner_fw_key_info
+                .as_ref()
+                .map(|owner_fw_config| &owner_fw_config.pub_keys),
+            None => None,
+        };
+        let vendor_man_pub_keys = if config
+            .flags
+            .contains(AuthManifestFlags::VENDOR_SIGNATURE_REQUIRED)
+        {
+            Some(&config.vendor_man_key_info.pub_keys)
+        } else {
+            None
+        };
+
+        let preamble = &mut auth_manifest.preamble;
+        self.check_sigs(
+            "vendor public keys",
+            &tbs.vendor_pub_keys.digest,
+            Some(&config.vendor_fw_key_info.pub_keys),
+            sigs.vendor_pub_keys.as_ref(),
+            &mut preamble.vendor_pub_keys_signatures,
+        )?;
+        self.check_sigs(
+            "owner public keys",
+            &tbs.owner_pub_keys.digest,
+            owner_fw_pub_keys,
+            sigs.owner_pub_keys.as_ref(),
+            &mut preamble.owner_pub_keys_signatures,
+        )?;
+        self.check_sigs(
+            "vendor image metadata",
+            &tbs.image_metadata.digest,
+            vendor_man_pub_keys,
+            sigs.vendor_image_metadata.as_ref(),
+            &mut preamble.vendor_image_metdata_signatures,
+        )?;
+        self.check_sigs(
+            "owner image metadata",
+            &tbs.image_metadata.digest,
+            owner_man_pub_keys,
+            sigs.owner_image_metadata.as_ref(),
+            &mut preamble.owner_image_metdata_signatures,
+        )?;
+
+        Ok(())
+    }
+
+    fn to_be_signed_data(&self, data: &[u8]) -> anyhow::Result<ToBeSigned> {
+        Ok(ToBeSigned {
+            data: data.to_vec(),
+            digest: self.crypto.sha384_digest(data)?,
+        })
+    }
+
+    fn sign(
+        &self,
+        digest: &ImageDigest,
+        priv_keys: &AuthManifestPrivKeys,
+        pub_keys: &AuthManifestPubKeys,
+    ) -> anyhow::Result<AuthManifestSignatures> {
+        Ok(AuthManifestSignatures {
+            ecc_sig: self.crypto.ecdsa384_sign(
+                digest,
+                &priv_keys.ecc_priv_key,
+                &pub_keys.ecc_pub_key,
+            )?,
+            lms_sig: self.crypto.lms_sign(digest, &priv_keys.lms_priv_key)?,
+        })
+    }
+
+    /// Check that a signature is present if and only if `pub_keys` is, verify
+    /// it and copy it to `dst`.
+    fn check_sigs(
+        &self,
+        name: &str,
+        digest: &ImageDigest,
+        pub_keys: Option<&AuthManifestPubKeys>,
+        sigs: Option<&AuthManifestSignatures>,
+        dst: &mut AuthManifestSignatures,
+    ) -> anyhow::Result<()> {
+        let (pub_keys, sigs) = match (pub_keys, sigs) {
+            (Some(pub_keys), Some(sigs)) => (pub_keys, sigs),
+            (None, None) => return Ok(()),
+            (Some(_), None) => bail!("Missing {name} signatures"),
+            (None, Some(_)) => bail!("Unexpected {name} signatures"),
+        };
+
+        if !self
+            .crypto
+            .ecdsa384_verify(digest, &pub_keys.ecc_pub_key, &sigs.ecc_sig)?
+        {
+            bail!("Invalid ECC signature over {name}");
+        }
+        if !self
+            .crypto
+            .lms_verify(digest, &pub_keys.lms_pub_key, &sigs.lms_sig)?
+        {
+            bail!("Invalid LMS signature over {name}");
+        }
+
+        *dst = *sigs;
+        Ok(())
+    }
 }
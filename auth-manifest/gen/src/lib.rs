@@ -16,6 +16,7 @@ mod generator;
 pub use generator::AuthManifestGenerator;
 
 use caliptra_auth_man_types::*;
+use caliptra_image_gen::ToBeSigned;
 
 /// Image Generator Vendor Configuration
 #[derive(Default, Clone)]
@@ -42,3 +43,29 @@ pub struct AuthManifestGeneratorConfig {
 
     pub image_metadata_list: Vec<AuthManifestImageMetadata>,
 }
+
+/// Authorization manifest data signed outside of the generator
+#[derive(Default, Clone)]
+pub struct AuthManifestToBeSigned {
+    /// Signed with the vendor firmware keys
+    pub vendor_pub_keys: ToBeSigned,
+
+    /// Signed with the owner firmware keys
+    pub owner_pub_keys: ToBeSigned,
+
+    /// Signed with the owner manifest keys, and with the vendor manifest keys
+    /// if `VENDOR_SIGNATURE_REQUIRED` is set
+    pub image_metadata: ToBeSigned,
+}
+
+/// Externally produced authorization manifest signatures
+#[derive(Default, Clone)]
+pub struct AuthManifestDetachedSignatures {
+    pub vendor_pub_keys: Option<AuthManifestSignatures>,
+
+    pub owner_pub_keys: Option<AuthManifestSignatures>,
+
+    pub vendor_image_metadata: Option<AuthManifestSignatures>,
+
+    pub owner_image_metadata: Option<AuthManifestSignatures>,
+}
@@ -23,7 +23,7 @@ use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 use zeroize::Zeroize;
 
 pub const AUTH_MANIFEST_MARKER: u32 = 0x4154_4D4E;
-pub const AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT: usize = 127;
+pub const AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT: usize = 119;
 
 bitflags::bitflags! {
     #[derive(Default, Copy, Clone, Debug)]
@@ -145,6 +145,8 @@ pub struct AuthManifestImageMetadata {
 
     pub flags: u32, // ImageMetadataFlags(image_source, ignore_auth_check)
 
+    pub svn: u32,
+
     pub digest: [u8; 48],
 }
 
@@ -153,6 +155,7 @@ impl Default for AuthManifestImageMetadata {
         AuthManifestImageMetadata {
             fw_id: u32::MAX,
             flags: 0,
+            svn: 0,
             digest: [0; 48],
         }
     }
@@ -178,6 +181,45 @@ impl Default for AuthManifestImageMetadataCollection {
     }
 }
 
+/// Minimum security version number of a firmware ID
+#[repr(C)]
+#[derive(IntoBytes, FromBytes, Immutable, KnownLayout, Clone, Copy, Debug, Default, Zeroize)]
+pub struct AuthManifestImageMinSvn {
+    pub fw_id: u32,
+
+    pub min_svn: u32,
+}
+
+/// Minimum security version numbers of the firmware IDs seen in authorization manifests
+#[repr(C)]
+#[derive(IntoBytes, FromBytes, Immutable, KnownLayout, Clone, Copy, Debug, Zeroize)]
+pub struct AuthManifestImageMinSvnList {
+    pub entry_count: u32,
+
+    pub min_svn_list: [AuthManifestImageMinSvn; AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT],
+}
+
+impl Default for AuthManifestImageMinSvnList {
+    fn default() -> Self {
+        AuthManifestImageMinSvnList {
+            entry_count: 0,
+            min_svn_list: [AuthManifestImageMinSvn::default();
+                AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT],
+        }
+    }
+}
+
+impl AuthManifestImageMinSvnList {
+    /// Returns the minimum SVN recorded for `fw_id`, or 0 if there is none.
+    pub fn min_svn(&self, fw_id: u32) -> u32 {
+        self.min_svn_list
+            .iter()
+            .take(self.entry_count as usize)
+            .find(|entry| entry.fw_id == fw_id)
+            .map_or(0, |entry| entry.min_svn)
+    }
+}
+
 /// Caliptra Image Authorization Manifest
 #[repr(C)]
 #[derive(IntoBytes, FromBytes, Immutable, KnownLayout, Clone, Copy, Debug, Zeroize, Default)]
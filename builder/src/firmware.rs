@@ -207,6 +207,11 @@ pub mod driver_tests {
         ..BASE_FWID
     };
 
+    pub const ML_DSA87: FwId = FwId {
+        bin_name: "ml_dsa87",
+        ..BASE_FWID
+    };
+
     pub const MAILBOX_DRIVER_RESPONDER: FwId = FwId {
         bin_name: "mailbox_driver_responder",
         ..BASE_FWID
@@ -420,6 +425,7 @@ pub const REGISTERED_FW: &[&FwId] = &[
     &driver_tests::HMAC384,
     &driver_tests::KEYVAULT,
     &driver_tests::KEYVAULT_FPGA,
+    &driver_tests::ML_DSA87,
     &driver_tests::MAILBOX_DRIVER_RESPONDER,
     &driver_tests::MAILBOX_DRIVER_SENDER,
     &driver_tests::MAILBOX_DRIVER_NEGATIVE_TESTS,
@@ -19,19 +19,19 @@ use caliptra_image_crypto::OsslCrypto as Crypto;
 use caliptra_image_crypto::RustCrypto as Crypto;
 use caliptra_image_elf::ElfExecutable;
 use caliptra_image_gen::{
-    ImageGenerator, ImageGeneratorConfig, ImageGeneratorOwnerConfig, ImageGeneratorVendorConfig,
+    compress_image, ImageGenerator, ImageGeneratorConfig, ImageGeneratorData,
+    ImageGeneratorOwnerConfig, ImageGeneratorVendorConfig, ImageGenratorExecutable,
 };
 use caliptra_image_types::{ImageBundle, ImageRevision, RomInfo};
 use elf::endian::LittleEndian;
 use nix::fcntl::FlockArg;
 use zerocopy::IntoBytes;
 
-mod elf_symbols;
 pub mod firmware;
 mod sha256;
 pub mod version;
 
-pub use elf_symbols::{elf_symbols, Symbol, SymbolBind, SymbolType, SymbolVisibility};
+pub use caliptra_elf_symbols::{elf_symbols, Symbol, SymbolBind, SymbolType, SymbolVisibility};
 use once_cell::sync::Lazy;
 
 pub const THIS_WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
@@ -501,6 +501,12 @@ pub fn elf_size(elf_bytes: &[u8]) -> io::Result<u64> {
     })
 }
 
+/// Size of the executable content of an ELF once compressed for the image bundle
+pub fn elf_compressed_size(elf_bytes: &[u8]) -> io::Result<u64> {
+    let exe = ElfExecutable::new(elf_bytes, 0, 0, Default::default()).map_err(other_err)?;
+    Ok(compress_image(exe.content()).len() as u64)
+}
+
 #[derive(Clone, Deserialize)]
 pub struct ImageOptions {
     pub fmc_version: u16,
@@ -509,6 +515,10 @@ pub struct ImageOptions {
     pub app_svn: u32,
     pub vendor_config: ImageGeneratorVendorConfig,
     pub owner_config: Option<ImageGeneratorOwnerConfig>,
+    #[serde(default)]
+    pub data: Vec<ImageGeneratorData>,
+    #[serde(default)]
+    pub compress_runtime: bool,
 }
 impl Default for ImageOptions {
     fn default() -> Self {
@@ -519,6 +529,8 @@ impl Default for ImageOptions {
             app_svn: Default::default(),
             vendor_config: caliptra_image_fake_keys::VENDOR_CONFIG_KEY_0,
             owner_config: Some(caliptra_image_fake_keys::OWNER_CONFIG),
+            data: vec![],
+            compress_runtime: false,
         }
     }
 }
@@ -541,6 +553,8 @@ pub fn build_and_sign_image(
         runtime: ElfExecutable::new(&app_elf, opts.app_version, opts.app_svn, image_revision()?)?,
         vendor_config: opts.vendor_config,
         owner_config: opts.owner_config,
+        data: opts.data,
+        compress_runtime: opts.compress_runtime,
     })?;
     Ok(image)
 }
@@ -6,7 +6,7 @@ use std::{
     path::Path,
 };
 
-use caliptra_builder::{elf_size, firmware, FwId};
+use caliptra_builder::{elf_compressed_size, elf_size, firmware, FwId};
 use serde::{Deserialize, Serialize};
 
 mod cache;
@@ -22,7 +22,7 @@ use crate::{cache_gha::GithubActionCache, util::other_err};
 
 // Increment with non-backwards-compatible changes are made to the cache record
 // format
-const CACHE_FORMAT_VERSION: &str = "v2";
+const CACHE_FORMAT_VERSION: &str = "v3";
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
 struct Sizes {
@@ -30,6 +30,7 @@ struct Sizes {
     rom_size_prod: Option<u64>,
     fmc_size_with_uart: Option<u64>,
     app_size_with_uart: Option<u64>,
+    app_compressed_size_with_uart: Option<u64>,
 }
 impl Sizes {
     fn update_from(&mut self, other: &Sizes) {
@@ -37,6 +38,9 @@ impl Sizes {
         self.rom_size_prod = other.rom_size_prod.or(self.rom_size_prod);
         self.fmc_size_with_uart = other.fmc_size_with_uart.or(self.fmc_size_with_uart);
         self.app_size_with_uart = other.app_size_with_uart.or(self.app_size_with_uart);
+        self.app_compressed_size_with_uart = other
+            .app_compressed_size_with_uart
+            .or(self.app_compressed_size_with_uart);
     }
 }
 
@@ -163,13 +167,12 @@ fn real_main() -> io::Result<()> {
 
 fn compute_size(worktree: &git::WorkTree, commit_id: &str) -> Sizes {
     // TODO: consider using caliptra_builder from the same repo as the firmware
-    let fwid_elf_size = |fwid: &FwId| -> io::Result<u64> {
+    let fwid_elf = |fwid: &FwId| -> io::Result<Vec<u8>> {
         let workspace_dir = Some(worktree.path);
-        let elf_bytes = caliptra_builder::build_firmware_elf_uncached(workspace_dir, fwid)?;
-        elf_size(&elf_bytes)
+        caliptra_builder::build_firmware_elf_uncached(workspace_dir, fwid)
     };
-    let fwid_elf_size_or_none = |fwid: &FwId| -> Option<u64> {
-        match fwid_elf_size(fwid) {
+    let ok_or_none = |result: io::Result<u64>| -> Option<u64> {
+        match result {
             Ok(result) => Some(result),
             Err(err) => {
                 println!("Error building commit {}: {err}", commit_id);
@@ -177,12 +180,26 @@ fn compute_size(worktree: &git::WorkTree, commit_id: &str) -> Sizes {
             }
         }
     };
+    let fwid_elf_size_or_none =
+        |fwid: &FwId| -> Option<u64> { ok_or_none(fwid_elf(fwid).and_then(|elf| elf_size(&elf))) };
+
+    let app_elf = fwid_elf(&firmware::APP_WITH_UART);
+    let app_size = |size: fn(&[u8]) -> io::Result<u64>| -> Option<u64> {
+        match &app_elf {
+            Ok(elf) => ok_or_none(size(elf)),
+            Err(err) => {
+                println!("Error building commit {}: {err}", commit_id);
+                None
+            }
+        }
+    };
 
     Sizes {
         rom_size_with_uart: fwid_elf_size_or_none(&firmware::ROM_WITH_UART),
         rom_size_prod: fwid_elf_size_or_none(&firmware::ROM),
         fmc_size_with_uart: fwid_elf_size_or_none(&firmware::FMC_WITH_UART),
-        app_size_with_uart: fwid_elf_size_or_none(&firmware::APP_WITH_UART),
+        app_size_with_uart: app_size(elf_size),
+        app_compressed_size_with_uart: app_size(elf_compressed_size),
     }
 }
 
@@ -92,6 +92,16 @@ if task_enabled "build"; then
   cargo --config "${EXTRA_CARGO_CONFIG}" build --locked
 fi
 
+if task_enabled "check_crypto_backends"; then
+  echo Build the image tools with each crypto backend
+  # Check each package separately so that features are not unified.
+  for backend in openssl rustcrypto pkcs11; do
+    for package in caliptra-image-crypto caliptra-image-app caliptra-auth-manifest-app; do
+      cargo --config "${EXTRA_CARGO_CONFIG}" check --locked --all-targets --no-default-features --features=$backend -p $package
+    done
+  done
+fi
+
 if task_enabled "build_fw"; then
   echo Build firmware images
   cargo --config "${EXTRA_CARGO_CONFIG}" run -p caliptra-builder  -- --all_elfs "${fw_dir}"
@@ -14,8 +14,10 @@ Abstract:
 
 use caliptra_drivers::*;
 use caliptra_image_types::*;
-use caliptra_image_verify::ImageVerificationEnv;
+use caliptra_image_verify::{ImageVerificationEnv, MldsaPreambleSource};
 use core::ops::Range;
+#[cfg(not(feature = "hw-1.0"))]
+use zerocopy::{FromBytes, IntoBytes};
 
 use caliptra_drivers::memory_layout::ICCM_RANGE;
 
@@ -25,11 +27,31 @@ pub struct FirmwareImageVerificationEnv<'a, 'b> {
     pub sha384: &'a mut Sha384,
     pub soc_ifc: &'a mut SocIfc,
     pub ecc384: &'a mut Ecc384,
+    #[cfg(not(feature = "hw-1.0"))]
+    pub mldsa87: &'a mut MlDsa87,
     pub data_vault: &'a mut DataVault,
     pub pcr_bank: &'a mut PcrBank,
     pub image: &'b [u8],
 }
 
+impl<'a, 'b> FirmwareImageVerificationEnv<'a, 'b> {
+    /// ML-DSA preamble following the manifest and extended TOC in the image.
+    /// There is none in hw-1.0 images.
+    pub fn mldsa_preamble(&self) -> CaliptraResult<MldsaPreambleSource<'b>> {
+        #[cfg(not(feature = "hw-1.0"))]
+        {
+            let (mldsa, _) = self
+                .image
+                .get(IMAGE_MANIFEST_BYTE_SIZE + IMAGE_EXTENDED_TOC_BYTE_SIZE..)
+                .and_then(|rest| ImageMldsaPreamble::ref_from_prefix(rest).ok())
+                .ok_or(CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE)?;
+            Ok(MldsaPreambleSource::Image(mldsa))
+        }
+        #[cfg(feature = "hw-1.0")]
+        Ok(MldsaPreambleSource::None)
+    }
+}
+
 impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b> {
     /// Calculate Digest using SHA-384 Accelerator
     fn sha384_digest(&mut self, offset: u32, len: u32) -> CaliptraResult<ImageDigest> {
@@ -78,6 +100,36 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         Lms::default().verify_lms_signature_cfi(self.sha256, &message, pub_key, sig)
     }
 
+    /// ML-DSA-87 Verification routine
+    #[cfg(not(feature = "hw-1.0"))]
+    fn mldsa87_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> CaliptraResult<LEArray4x16> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE;
+
+        // View the key and signature in place; both are too large to copy
+        // onto the stack.
+        let pub_key = MlDsa87PubKey::ref_from_bytes(pub_key.as_bytes()).map_err(|_| err)?;
+        let sig = MlDsa87Signature::ref_from_bytes(sig.as_bytes()).map_err(|_| err)?;
+        let msg = MlDsa87Msg::new(mldsa_msg_from_digest(digest));
+
+        self.mldsa87.verify_res(pub_key, &msg, sig)
+    }
+
+    /// There is no ML-DSA-87 engine in 1.0 hardware
+    #[cfg(feature = "hw-1.0")]
+    fn mldsa87_verify(
+        &mut self,
+        _digest: &ImageDigest,
+        _pub_key: &ImageMldsaPubKey,
+        _sig: &ImageMldsaSignature,
+    ) -> CaliptraResult<LEArray4x16> {
+        Err(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE)
+    }
+
     /// Retrieve Vendor Public Key Digest
     fn vendor_pub_key_digest(&self) -> ImageDigest {
         self.soc_ifc.fuse_bank().vendor_pub_key_hash().into()
@@ -93,6 +145,17 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         self.soc_ifc.fuse_bank().vendor_lms_pub_key_revocation()
     }
 
+    /// Retrieve Vendor ML-DSA Public Key Revocation Bitmask
+    #[cfg(not(feature = "hw-1.0"))]
+    fn vendor_mldsa_pub_key_revocation(&self) -> u32 {
+        self.soc_ifc.fuse_bank().vendor_mldsa_pub_key_revocation()
+    }
+
+    #[cfg(feature = "hw-1.0")]
+    fn vendor_mldsa_pub_key_revocation(&self) -> u32 {
+        0
+    }
+
     /// Retrieve Owner Public Key Digest from fuses
     fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
         self.soc_ifc.fuse_bank().owner_pub_key_hash().into()
@@ -118,6 +181,11 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         self.data_vault.lms_vendor_pk_index()
     }
 
+    /// Get the vendor ML-DSA key index saved in data vault on cold boot
+    fn vendor_mldsa_pub_key_idx_dv(&self) -> u32 {
+        self.data_vault.mldsa_vendor_pk_index()
+    }
+
     /// Get the owner public key digest saved in the dv on cold boot
     fn owner_pub_key_digest_dv(&self) -> ImageDigest {
         self.data_vault.owner_pk_hash().into()
@@ -146,6 +214,16 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         self.soc_ifc.fuse_bank().lms_verify() == RomVerifyConfig::EcdsaAndLms
     }
 
+    #[cfg(not(feature = "hw-1.0"))]
+    fn mldsa_verify_enabled(&self) -> bool {
+        self.soc_ifc.fuse_bank().mldsa_verify()
+    }
+
+    #[cfg(feature = "hw-1.0")]
+    fn mldsa_verify_enabled(&self) -> bool {
+        false
+    }
+
     fn set_fw_extended_error(&mut self, err: u32) {
         self.soc_ifc.set_fw_extended_error(err);
     }
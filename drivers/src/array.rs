@@ -160,6 +160,108 @@ pub type Array4x12 = Array4xN<12, 48>;
 pub type Array4x16 = Array4xN<16, 64>;
 pub type Array4x32 = Array4xN<32, 128>;
 
+/// The `LEArray4xN` type represents large arrays that the Caliptra
+/// cryptographic hardware holds as little-endian words, such as the ML-DSA-87
+/// keys and signatures, and provides From traits for converting to/from byte
+/// arrays.
+#[repr(transparent)]
+#[derive(
+    Debug, Clone, Copy, IntoBytes, FromBytes, Immutable, KnownLayout, PartialEq, Eq, Zeroize,
+)]
+#[cfg_attr(not(feature = "no-cfi"), derive(Launder))]
+pub struct LEArray4xN<const W: usize, const B: usize>(pub [u32; W]);
+impl<const W: usize, const B: usize> LEArray4xN<W, B> {
+    pub const fn new(val: [u32; W]) -> Self {
+        Self(val)
+    }
+}
+
+impl<const W: usize, const B: usize> Default for LEArray4xN<W, B> {
+    fn default() -> Self {
+        Self([0u32; W])
+    }
+}
+
+impl<const W: usize, const B: usize> LEArray4xN<W, B> {
+    #[inline(always)]
+    #[allow(unused)]
+    pub fn read_from_reg<
+        TReg: ureg::ReadableReg<ReadVal = u32, Raw = u32>,
+        TMmio: ureg::Mmio + Copy,
+    >(
+        reg_array: ureg::Array<W, ureg::RegRef<TReg, TMmio>>,
+    ) -> Self {
+        Self(reg_array.read())
+    }
+
+    #[inline(always)]
+    #[allow(unused)]
+    pub fn write_to_reg<
+        TReg: ureg::ResettableReg + ureg::WritableReg<WriteVal = u32, Raw = u32>,
+        TMmio: ureg::MmioMut + Copy,
+    >(
+        &self,
+        reg_array: ureg::Array<W, ureg::RegRef<TReg, TMmio>>,
+    ) {
+        reg_array.write(&self.0);
+    }
+}
+
+impl<const W: usize, const B: usize> From<[u8; B]> for LEArray4xN<W, B> {
+    #[inline(always)]
+    fn from(value: [u8; B]) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const W: usize, const B: usize> From<&[u8; B]> for LEArray4xN<W, B> {
+    #[inline(never)]
+    fn from(value: &[u8; B]) -> Self {
+        let mut result = Self::default();
+        for (word, bytes) in result.0.iter_mut().zip(value.chunks(4)) {
+            let mut buf = [0u8; 4];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            *word = u32::from_le_bytes(buf);
+        }
+        result
+    }
+}
+
+impl<const W: usize, const B: usize> From<LEArray4xN<W, B>> for [u8; B] {
+    #[inline(always)]
+    fn from(value: LEArray4xN<W, B>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<const W: usize, const B: usize> From<&LEArray4xN<W, B>> for [u8; B] {
+    #[inline(never)]
+    fn from(value: &LEArray4xN<W, B>) -> Self {
+        let mut result = [0u8; B];
+        for (bytes, word) in result.chunks_mut(4).zip(value.0.iter()) {
+            bytes.copy_from_slice(&word.to_le_bytes()[..bytes.len()]);
+        }
+        result
+    }
+}
+
+impl<const W: usize, const B: usize> From<[u32; W]> for LEArray4xN<W, B> {
+    fn from(value: [u32; W]) -> Self {
+        Self(value)
+    }
+}
+
+impl<const W: usize, const B: usize> From<LEArray4xN<W, B>> for [u32; W] {
+    fn from(value: LEArray4xN<W, B>) -> Self {
+        value.0
+    }
+}
+
+pub type LEArray4x8 = LEArray4xN<8, 32>;
+pub type LEArray4x16 = LEArray4xN<16, 64>;
+pub type LEArray4x648 = LEArray4xN<648, 2592>;
+pub type LEArray4x1157 = LEArray4xN<1157, 4627>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,6 +288,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_le_array_bytes() {
+        let bytes = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee,
+        ];
+        let arr = LEArray4xN::<4, 15>::from(&bytes);
+        assert_eq!(arr.0, [0x3322_1100, 0x7766_5544, 0xbbaa_9988, 0x00ee_ddcc]);
+        assert_eq!(<[u8; 15]>::from(&arr), bytes);
+    }
+
     #[test]
     fn test_array_4x4_to_bytes() {
         assert_eq!(
@@ -74,6 +74,7 @@ pub enum ColdResetEntry4 {
     FmcEntryPoint = 2,
     EccVendorPubKeyIndex = 3,
     LmsVendorPubKeyIndex = 4,
+    MlDsaVendorPubKeyIndex = 5,
 }
 
 impl TryFrom<u8> for ColdResetEntry4 {
@@ -84,6 +85,7 @@ impl TryFrom<u8> for ColdResetEntry4 {
             2 => Ok(Self::FmcEntryPoint),
             3 => Ok(Self::EccVendorPubKeyIndex),
             4 => Ok(Self::LmsVendorPubKeyIndex),
+            5 => Ok(Self::MlDsaVendorPubKeyIndex),
             _ => Err(()),
         }
     }
@@ -110,6 +112,7 @@ impl From<ColdResetEntry4> for usize {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WarmResetEntry48 {
     RtTci = 0,
+    MldsaPreambleDigest = 1,
 }
 
 impl From<WarmResetEntry48> for u8 {
@@ -175,6 +178,7 @@ impl TryFrom<u8> for WarmResetEntry48 {
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(Self::RtTci),
+            1 => Ok(Self::MldsaPreambleDigest),
             _ => Err(()),
         }
     }
@@ -334,6 +338,15 @@ impl DataVault {
         self.read_cold_reset_entry4(ColdResetEntry4::LmsVendorPubKeyIndex)
     }
 
+    /// Get the ML-DSA vendor public key index used for image verification.
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Vendor public key index
+    pub fn mldsa_vendor_pk_index(&self) -> u32 {
+        self.read_cold_reset_entry4(ColdResetEntry4::MlDsaVendorPubKeyIndex)
+    }
+
     /// Get the rom cold boot status.
     ///
     /// # Returns
@@ -361,6 +374,15 @@ impl DataVault {
         self.read_warm_reset_entry48(WarmResetEntry48::RtTci)
     }
 
+    /// Get the digest of the ML-DSA preamble the ROM verified.
+    ///
+    /// # Returns
+    /// * ML-DSA preamble digest; zero if ML-DSA verification is disabled
+    ///
+    pub fn mldsa_preamble_digest(&self) -> Array4x12 {
+        self.read_warm_reset_entry48(WarmResetEntry48::MldsaPreambleDigest)
+    }
+
     /// Get the rt security version number.
     ///
     /// # Returns
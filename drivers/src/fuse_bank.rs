@@ -16,6 +16,8 @@ use crate::Array4x12;
 #[cfg(not(feature = "no-cfi"))]
 use caliptra_cfi_derive::Launder;
 use caliptra_registers::soc_ifc::SocIfcReg;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_registers::soc_ifc_mldsa;
 use zerocopy::IntoBytes;
 
 pub struct FuseBank<'a> {
@@ -223,6 +225,19 @@ impl FuseBank<'_> {
         soc_ifc_regs.fuse_lms_revocation().read()
     }
 
+    /// Get the ML-DSA vendor public key revocation mask.
+    ///
+    /// # Arguments
+    /// * None
+    ///
+    /// # Returns
+    ///     ML-DSA vendor public key revocation mask
+    ///
+    #[cfg(not(feature = "hw-1.0"))]
+    pub fn vendor_mldsa_pub_key_revocation(&self) -> u32 {
+        self.mldsa_fuse_regs().fuse_mldsa_revocation().read()
+    }
+
     /// Get the owner public key hash.
     ///
     /// # Arguments
@@ -309,6 +324,30 @@ impl FuseBank<'_> {
             RomVerifyConfig::EcdsaAndLms
         }
     }
+
+    /// Get the ML-DSA verification config.
+    ///
+    /// # Arguments
+    /// * None
+    ///
+    /// # Returns
+    ///     true if Caliptra firmware images must also be verified with ML-DSA
+    ///
+    #[cfg(not(feature = "hw-1.0"))]
+    pub fn mldsa_verify(&self) -> bool {
+        self.mldsa_fuse_regs()
+            .fuse_mldsa_verify()
+            .read()
+            .mldsa_verify()
+    }
+
+    /// The ML-DSA fuse registers, which are part of the soc_ifc block
+    #[cfg(not(feature = "hw-1.0"))]
+    fn mldsa_fuse_regs(&self) -> soc_ifc_mldsa::RegisterBlock<ureg::RealMmio> {
+        // SAFETY: The registers are in the soc_ifc block, which self.soc_ifc
+        // owns.
+        unsafe { soc_ifc_mldsa::RegisterBlock::new(SocIfcReg::PTR) }
+    }
 }
 
 #[cfg(test)]
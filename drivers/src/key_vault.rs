@@ -139,6 +139,11 @@ bitfield! {
     /// Flag indicating if the key can be used aas ECC data part of signature
     /// generation and verification process
     pub ecc_data, set_ecc_data:5;
+
+    /// Flag indicating if the key can be used as ML-DSA-87 Key Generation
+    /// Seed. Hardware with an ML-DSA block reuses the `ecc_data` bit for it.
+    #[cfg(not(feature = "hw-1.0"))]
+    pub mldsa_key_gen_seed, set_mldsa_key_gen_seed: 5;
 }
 
 impl KeyUsage {
@@ -166,6 +171,11 @@ impl KeyUsage {
         self.set_ecc_data(true);
         *self
     }
+    #[cfg(not(feature = "hw-1.0"))]
+    pub fn set_mldsa_key_gen_seed_en(&mut self) -> KeyUsage {
+        self.set_mldsa_key_gen_seed(true);
+        *self
+    }
 }
 
 /// Caliptra Key Vault
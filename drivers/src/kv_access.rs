@@ -16,6 +16,8 @@ Abstract:
 use crate::array::Array4xN;
 use crate::{wait, CaliptraResult, KeyId, KeyUsage, PcrId};
 use caliptra_registers::enums::KvErrorE;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_registers::kv_mldsa::KvWriteCtrlMldsaSeed;
 use caliptra_registers::regs::{KvReadCtrlRegWriteVal, KvStatusRegReadVal, KvWriteCtrlRegWriteVal};
 use ureg::{Mmio, MmioMut};
 
@@ -123,6 +125,7 @@ impl KvAccess {
     /// * `status_reg` - Status register
     /// * `ctrl_reg` - Control register
     /// * `key` - Key slot in key vault
+    #[cfg_attr(feature = "hw-1.0", allow(clippy::let_and_return))]
     pub(crate) fn begin_copy_to_kv<
         StatusReg: ureg::ReadableReg<ReadVal = KvStatusRegReadVal>,
         CtrlReg: ureg::ResettableReg + ureg::WritableReg<WriteVal = KvWriteCtrlRegWriteVal>,
@@ -134,13 +137,17 @@ impl KvAccess {
     ) -> CaliptraResult<()> {
         wait::until(|| status_reg.read().ready());
         ctrl_reg.write(|w| {
-            w.write_en(true)
+            let w = w
+                .write_en(true)
                 .write_entry(key.id.into())
                 .hmac_key_dest_valid(key.usage.hmac_key())
                 .hmac_block_dest_valid(key.usage.hmac_data())
                 .sha_block_dest_valid(key.usage.sha_data())
                 .ecc_pkey_dest_valid(key.usage.ecc_private_key())
-                .ecc_seed_dest_valid(key.usage.ecc_key_gen_seed())
+                .ecc_seed_dest_valid(key.usage.ecc_key_gen_seed());
+            #[cfg(not(feature = "hw-1.0"))]
+            let w = w.mldsa_seed_dest_valid(key.usage.mldsa_key_gen_seed());
+            w
         });
         Ok(())
     }
@@ -35,8 +35,11 @@ mod hmac384_kdf;
 mod key_vault;
 mod kv_access;
 mod lms;
+mod lz4;
 mod mailbox;
 pub mod memory_layout;
+#[cfg(not(feature = "hw-1.0"))]
+mod ml_dsa87;
 mod okref;
 mod pcr_bank;
 pub mod pcr_log;
@@ -52,7 +55,10 @@ mod soc_ifc;
 mod trng;
 mod trng_ext;
 
-pub use array::{Array4x12, Array4x16, Array4x4, Array4x5, Array4x8, Array4xN};
+pub use array::{
+    Array4x12, Array4x16, Array4x4, Array4x5, Array4x8, Array4xN, LEArray4x1157, LEArray4x16,
+    LEArray4x648, LEArray4x8, LEArray4xN,
+};
 pub use array_concat::array_concat3;
 pub use bounded_address::{BoundedAddr, MemBounds, RomAddr};
 pub use caliptra_error::{CaliptraError, CaliptraResult};
@@ -81,7 +87,13 @@ pub use lms::{
     get_lmots_parameters, get_lms_parameters, HashValue, Lms, LmsResult, Sha192Digest,
     Sha256Digest, D_INTR, D_LEAF, D_MESG, D_PBLC,
 };
+pub use lz4::lz4_decompress;
 pub use mailbox::{Mailbox, MailboxRecvTxn, MailboxSendTxn};
+#[cfg(not(feature = "hw-1.0"))]
+pub use ml_dsa87::{
+    MlDsa87, MlDsa87Msg, MlDsa87PubKey, MlDsa87Result, MlDsa87Seed, MlDsa87SeedArray,
+    MlDsa87SignRnd, MlDsa87Signature,
+};
 pub use okref::okmutref;
 pub use okref::okref;
 pub use pcr_bank::{PcrBank, PcrId};
@@ -92,8 +104,8 @@ pub use persistent::AuthManifestImageMetadataList;
 
 pub use persistent::{
     FuseLogArray, IdevIdCsr, PcrLogArray, PersistentData, PersistentDataAccessor,
-    StashMeasurementArray, FUSE_LOG_MAX_COUNT, MAX_CSR_SIZE, MEASUREMENT_MAX_COUNT,
-    PCR_LOG_MAX_COUNT,
+    StashMeasurementArray, FUSE_LOG_MAX_COUNT, IMAGE_DATA_SIZE, MAX_CSR_SIZE,
+    MEASUREMENT_MAX_COUNT, PCR_LOG_MAX_COUNT,
 };
 pub use pic::{IntSource, Pic};
 pub use sha1::{Sha1, Sha1Digest, Sha1DigestOp};
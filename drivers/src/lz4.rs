@@ -0,0 +1,227 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    lz4.rs
+
+Abstract:
+
+    File contains a decoder for the LZ4 block format.
+
+--*/
+
+use caliptra_error::{CaliptraError, CaliptraResult};
+
+/// Writes bytes to a word slice. ICCM only accepts aligned 32-bit stores, so
+/// bytes are staged until a full word is available.
+struct WordWriter<'a> {
+    dest: &'a mut [u32],
+    len: usize,
+    pending: u32,
+}
+
+impl WordWriter<'_> {
+    fn push(&mut self, byte: u8) -> CaliptraResult<()> {
+        let slot = self
+            .dest
+            .get_mut(self.len / 4)
+            .ok_or(CaliptraError::DRIVER_LZ4_OUTPUT_OVERFLOW)?;
+        self.pending |= u32::from(byte) << ((self.len % 4) * 8);
+        self.len += 1;
+        if self.len % 4 == 0 {
+            *slot = self.pending;
+            self.pending = 0;
+        }
+        Ok(())
+    }
+
+    /// Byte `pos` of the output written so far
+    fn get(&self, pos: usize) -> CaliptraResult<u8> {
+        let word = if pos / 4 == self.len / 4 {
+            self.pending
+        } else {
+            *self
+                .dest
+                .get(pos / 4)
+                .ok_or(CaliptraError::DRIVER_LZ4_INVALID_INPUT)?
+        };
+        Ok((word >> ((pos % 4) * 8)) as u8)
+    }
+
+    fn finish(self) -> usize {
+        if self.len % 4 != 0 {
+            if let Some(slot) = self.dest.get_mut(self.len / 4) {
+                *slot = self.pending;
+            }
+        }
+        self.len
+    }
+}
+
+/// Read the extension bytes of a literal or match length
+fn read_len(src: &mut impl Iterator<Item = u8>, nibble: u8) -> CaliptraResult<usize> {
+    let err = CaliptraError::DRIVER_LZ4_INVALID_INPUT;
+    let mut len = usize::from(nibble);
+    if nibble == 0xf {
+        loop {
+            let byte = src.next().ok_or(err)?;
+            len = len.checked_add(usize::from(byte)).ok_or(err)?;
+            if byte != 0xff {
+                break;
+            }
+        }
+    }
+    Ok(len)
+}
+
+/// Decompress an LZ4 block into `dest`.
+///
+/// Every read from `src` and every write to `dest` is bounds checked, so a
+/// malformed block fails instead of writing outside `dest`. A trailing partial
+/// word is zero-padded.
+///
+/// # Arguments
+///
+/// * `src`  - LZ4 block, without a size prefix
+/// * `dest` - Destination buffer
+///
+/// # Returns
+///
+/// * `usize` - Number of bytes written
+pub fn lz4_decompress(src: &[u8], dest: &mut [u32]) -> CaliptraResult<usize> {
+    let err = CaliptraError::DRIVER_LZ4_INVALID_INPUT;
+    let mut out = WordWriter {
+        dest,
+        len: 0,
+        pending: 0,
+    };
+    let mut src = src.iter().copied();
+
+    loop {
+        let token = src.next().ok_or(err)?;
+
+        let literal_len = read_len(&mut src, token >> 4)?;
+        for _ in 0..literal_len {
+            out.push(src.next().ok_or(err)?)?;
+        }
+
+        // The last sequence ends after its literals.
+        let Some(offset_lo) = src.next() else {
+            break;
+        };
+        let offset_hi = src.next().ok_or(err)?;
+        let offset = usize::from(u16::from_le_bytes([offset_lo, offset_hi]));
+        if offset == 0 || offset > out.len {
+            return Err(err);
+        }
+
+        let match_len = read_len(&mut src, token & 0xf)?.checked_add(4).ok_or(err)?;
+        for _ in 0..match_len {
+            out.push(out.get(out.len - offset)?)?;
+        }
+    }
+
+    Ok(out.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::vec;
+    use std::vec::Vec;
+    use zerocopy::IntoBytes;
+
+    fn decompress(src: &[u8], words: usize) -> CaliptraResult<Vec<u8>> {
+        let mut dest = vec![0xffff_ffffu32; words];
+        let len = lz4_decompress(src, &mut dest)?;
+        let mut result = dest.as_bytes().to_vec();
+        // Padding must be zeroed.
+        assert!(result[len..(len + 3) / 4 * 4].iter().all(|&b| b == 0));
+        result.truncate(len);
+        Ok(result)
+    }
+
+    #[test]
+    fn test_literals() {
+        assert_eq!(decompress(b"\x50hello", 2).unwrap(), b"hello");
+
+        // Extended literal length: 15 + 5
+        let mut src = vec![0xf0, 5];
+        src.extend_from_slice(&[0x42; 20]);
+        assert_eq!(decompress(&src, 5).unwrap(), [0x42; 20]);
+    }
+
+    #[test]
+    fn test_overlapping_match() {
+        // 'a' followed by a 5 byte match at offset 1, then an empty sequence.
+        assert_eq!(decompress(b"\x11a\x01\x00\x00", 2).unwrap(), b"aaaaaa");
+
+        // Extended match length: 4 + 15 + 255 + 1
+        let src = [0x1f, b'x', 0x01, 0x00, 0xff, 0x01, 0x00];
+        assert_eq!(decompress(&src, 70).unwrap(), [b'x'; 276]);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        // Empty block
+        assert_eq!(
+            decompress(&[], 1),
+            Err(CaliptraError::DRIVER_LZ4_INVALID_INPUT)
+        );
+        // Truncated literals
+        assert_eq!(
+            decompress(b"\x50hel", 2),
+            Err(CaliptraError::DRIVER_LZ4_INVALID_INPUT)
+        );
+        // Truncated offset
+        assert_eq!(
+            decompress(b"\x11a\x01", 2),
+            Err(CaliptraError::DRIVER_LZ4_INVALID_INPUT)
+        );
+        // Zero offset
+        assert_eq!(
+            decompress(b"\x11a\x00\x00\x00", 2),
+            Err(CaliptraError::DRIVER_LZ4_INVALID_INPUT)
+        );
+        // Offset before the start of the output
+        assert_eq!(
+            decompress(b"\x11a\x02\x00\x00", 2),
+            Err(CaliptraError::DRIVER_LZ4_INVALID_INPUT)
+        );
+        // Truncated length extension
+        assert_eq!(
+            decompress(&[0xf0], 2),
+            Err(CaliptraError::DRIVER_LZ4_INVALID_INPUT)
+        );
+    }
+
+    #[test]
+    fn test_output_overflow() {
+        assert_eq!(
+            decompress(b"\x50hello", 1),
+            Err(CaliptraError::DRIVER_LZ4_OUTPUT_OVERFLOW)
+        );
+        assert_eq!(
+            decompress(b"\x11a\x01\x00\x00", 1),
+            Err(CaliptraError::DRIVER_LZ4_OUTPUT_OVERFLOW)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data: Vec<u8> = (0..10_000u32)
+            .map(|i| (i % 251) as u8 ^ (i / 1000) as u8)
+            .collect();
+        let compressed = lz4_flex::block::compress(&data);
+        assert!(compressed.len() < data.len());
+        assert_eq!(decompress(&compressed, data.len() / 4).unwrap(), data);
+        assert_eq!(
+            decompress(&compressed, data.len() / 4 - 1),
+            Err(CaliptraError::DRIVER_LZ4_OUTPUT_OVERFLOW)
+        );
+    }
+}
@@ -0,0 +1,391 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    ml_dsa87.rs
+
+Abstract:
+
+    File contains API for ML-DSA-87 Cryptography operations
+
+--*/
+
+use crate::array::{LEArray4x1157, LEArray4x16, LEArray4x648, LEArray4x8};
+use crate::kv_access::{KvAccess, KvAccessErr};
+use crate::{okmutref, wait, CaliptraError, CaliptraResult, KeyReadArgs, Trng};
+#[cfg(not(feature = "no-cfi"))]
+use caliptra_cfi_derive::cfi_impl_fn;
+use caliptra_registers::mldsa::{MldsaReg, RegisterBlock};
+use zeroize::Zeroize;
+
+/// ML-DSA-87 Public Key
+pub type MlDsa87PubKey = LEArray4x648;
+
+/// ML-DSA-87 Signature
+pub type MlDsa87Signature = LEArray4x1157;
+
+/// ML-DSA-87 Message
+pub type MlDsa87Msg = LEArray4x16;
+
+/// ML-DSA-87 Signing randomness
+pub type MlDsa87SignRnd = LEArray4x8;
+
+/// ML-DSA-87 Key Generation seed
+pub type MlDsa87SeedArray = LEArray4x8;
+
+#[must_use]
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MlDsa87Result {
+    Success = 0xAAAAAAAA,
+    SigVerifyFailed = 0x55555555,
+}
+
+/// ML-DSA-87 Seed
+#[derive(Debug, Copy, Clone)]
+pub enum MlDsa87Seed<'a> {
+    /// Array
+    Array4x8(&'a MlDsa87SeedArray),
+
+    /// Key Vault Key
+    Key(KeyReadArgs),
+}
+
+impl<'a> From<&'a MlDsa87SeedArray> for MlDsa87Seed<'a> {
+    /// Converts to this type from the input type.
+    fn from(value: &'a MlDsa87SeedArray) -> Self {
+        Self::Array4x8(value)
+    }
+}
+
+impl From<KeyReadArgs> for MlDsa87Seed<'_> {
+    /// Converts to this type from the input type.
+    fn from(value: KeyReadArgs) -> Self {
+        Self::Key(value)
+    }
+}
+
+/// ML-DSA-87 API
+///
+/// The private key is never read out of the engine: signing regenerates it
+/// from the seed, which is normally a key vault entry with the
+/// `mldsa_key_gen_seed` usage.
+pub struct MlDsa87 {
+    mldsa: MldsaReg,
+}
+
+impl MlDsa87 {
+    pub fn new(mldsa: MldsaReg) -> Self {
+        Self { mldsa }
+    }
+
+    // Copy the seed to the hardware, from an array or the key vault
+    fn copy_seed(regs: RegisterBlock<ureg::RealMmioMut>, seed: &MlDsa87Seed) -> CaliptraResult<()> {
+        match seed {
+            MlDsa87Seed::Array4x8(arr) => arr.write_to_reg(regs.seed()),
+            MlDsa87Seed::Key(key) => {
+                KvAccess::copy_from_kv(*key, regs.kv_rd_seed_status(), regs.kv_rd_seed_ctrl())
+                    .map_err(|err| err.into_read_seed_err())?
+            }
+        }
+        Ok(())
+    }
+
+    // Generate an IV for the side-channel countermeasures
+    fn copy_iv(regs: RegisterBlock<ureg::RealMmioMut>, trng: &mut Trng) -> CaliptraResult<()> {
+        let mut iv = [0u32; 16];
+        iv[..12].copy_from_slice(&trng.generate()?.0);
+        iv[12..].copy_from_slice(&trng.generate()?.0[..4]);
+        regs.iv().write(&iv);
+        Ok(())
+    }
+
+    /// Generate ML-DSA-87 Key Pair
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed for deterministic ML-DSA-87 Key Pair generation
+    /// * `trng` - TRNG driver instance
+    ///
+    /// # Returns
+    ///
+    /// * `MlDsa87PubKey` - Generated ML-DSA-87 Public Key
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub fn key_pair(
+        &mut self,
+        seed: &MlDsa87Seed,
+        trng: &mut Trng,
+    ) -> CaliptraResult<MlDsa87PubKey> {
+        self.key_pair_base(seed, trng, None)
+    }
+
+    /// Generate ML-DSA-87 Key Pair for FIPS KAT testing
+    /// ONLY to be used for KAT testing
+    ///
+    /// # Arguments
+    ///
+    /// * `trng` - TRNG driver instance
+    /// * `pct_sig` - Signature generated during the pairwise consistency test
+    ///
+    /// # Returns
+    ///
+    /// * `MlDsa87PubKey` - Generated ML-DSA-87 Public Key
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub fn key_pair_for_fips_kat(
+        &mut self,
+        trng: &mut Trng,
+        pct_sig: &mut MlDsa87Signature,
+    ) -> CaliptraResult<MlDsa87PubKey> {
+        let seed = MlDsa87SeedArray::default();
+        self.key_pair_base(&MlDsa87Seed::from(&seed), trng, Some(pct_sig))
+    }
+
+    /// Private base function to generate ML-DSA-87 Key Pair
+    /// pct_sig should only be provided in the KAT use case
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    #[inline(never)]
+    fn key_pair_base(
+        &mut self,
+        seed: &MlDsa87Seed,
+        trng: &mut Trng,
+        pct_sig: Option<&mut MlDsa87Signature>,
+    ) -> CaliptraResult<MlDsa87PubKey> {
+        let mldsa = self.mldsa.regs_mut();
+
+        // Wait for hardware ready
+        wait::until(|| mldsa.status().read().ready());
+
+        // Copy seed to the hardware
+        Self::copy_seed(mldsa, seed)?;
+
+        // Generate an IV.
+        Self::copy_iv(mldsa, trng)?;
+
+        // Program the command register for key generation
+        mldsa.ctrl().write(|w| w.ctrl(|w| w.keygen()));
+
+        // Wait for command to complete
+        wait::until(|| mldsa.status().read().valid());
+
+        let pub_key = MlDsa87PubKey::read_from_reg(mldsa.pubkey());
+
+        self.zeroize_internal();
+
+        // Pairwise consistency check.
+        let msg = MlDsa87Msg::default();
+        let sign_rnd = MlDsa87SignRnd::default();
+        match self.sign(seed, &pub_key, &msg, &sign_rnd, trng) {
+            Ok(mut sig) => {
+                // Return the signature from this test if requested (only used for KAT)
+                if let Some(output_sig) = pct_sig {
+                    *output_sig = sig;
+                }
+                sig.zeroize();
+            }
+            Err(_) => {
+                // Remap error to a pairwise consistency check failure
+                return Err(CaliptraError::DRIVER_MLDSA87_KEYGEN_PAIRWISE_CONSISTENCY_FAILURE);
+            }
+        }
+
+        Ok(pub_key)
+    }
+
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    fn sign_internal(
+        &mut self,
+        seed: &MlDsa87Seed,
+        msg: &MlDsa87Msg,
+        sign_rnd: &MlDsa87SignRnd,
+        trng: &mut Trng,
+    ) -> CaliptraResult<MlDsa87Signature> {
+        let mldsa = self.mldsa.regs_mut();
+
+        // Wait for hardware ready
+        wait::until(|| mldsa.status().read().ready());
+
+        // Copy seed to the hardware
+        Self::copy_seed(mldsa, seed)?;
+
+        // Copy message and signing randomness
+        msg.write_to_reg(mldsa.msg());
+        sign_rnd.write_to_reg(mldsa.sign_rnd());
+
+        // Generate an IV.
+        Self::copy_iv(mldsa, trng)?;
+
+        // Program the command register to regenerate the private key and sign
+        mldsa.ctrl().write(|w| w.ctrl(|w| w.keygen_sign()));
+
+        // Wait for command to complete
+        wait::until(|| mldsa.status().read().valid());
+
+        // Copy signature
+        let signature = MlDsa87Signature::read_from_reg(mldsa.signature());
+
+        self.zeroize_internal();
+
+        Ok(signature)
+    }
+
+    /// Sign the message with the private key generated from `seed`. To defend
+    /// against glitching attacks that could expose the private key, this
+    /// function also verifies the generated signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Key generation seed of the private key
+    /// * `pub_key` - Public key to verify with
+    /// * `msg` - Message to sign
+    /// * `sign_rnd` - Signing randomness; all zeros for deterministic signing
+    /// * `trng` - TRNG driver instance
+    ///
+    /// # Returns
+    ///
+    /// * `MlDsa87Signature` - Generated signature
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub fn sign(
+        &mut self,
+        seed: &MlDsa87Seed,
+        pub_key: &MlDsa87PubKey,
+        msg: &MlDsa87Msg,
+        sign_rnd: &MlDsa87SignRnd,
+        trng: &mut Trng,
+    ) -> CaliptraResult<MlDsa87Signature> {
+        let mut sig_result = self.sign_internal(seed, msg, sign_rnd, trng);
+        let sig = okmutref(&mut sig_result)?;
+
+        // Verify the signature just created
+        let verify_res = self.verify_res(pub_key, msg, sig)?;
+        if verify_res.0 != sig.0[..16] {
+            return Err(CaliptraError::DRIVER_MLDSA87_SIGN_VALIDATION_FAILED);
+        }
+        // Not using standard error flow here for increased CFI safety
+        // An error here will end up reporting the CFI assert failure
+        #[cfg(not(feature = "no-cfi"))]
+        caliptra_cfi_lib::cfi_assert_eq_12_words(
+            verify_res.0[..12].try_into().unwrap(),
+            sig.0[..12].try_into().unwrap(),
+        );
+
+        sig_result
+    }
+
+    /// Verify signature with specified public key and message
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - Public key
+    /// * `msg` - Message to verify
+    /// * `signature` - Signature to verify
+    ///
+    /// # Result
+    ///
+    /// *  `MlDsa87Result` - MlDsa87Result::Success if the signature verification passed else an error code.
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub fn verify(
+        &mut self,
+        pub_key: &MlDsa87PubKey,
+        msg: &MlDsa87Msg,
+        signature: &MlDsa87Signature,
+    ) -> CaliptraResult<MlDsa87Result> {
+        let mut verify_res = self.verify_res(pub_key, msg, signature)?;
+
+        // Compare the hardware result with the start of the signature
+        let result = if verify_res.0 == signature.0[..16] {
+            #[cfg(not(feature = "no-cfi"))]
+            caliptra_cfi_lib::cfi_assert_eq_12_words(
+                verify_res.0[..12].try_into().unwrap(),
+                signature.0[..12].try_into().unwrap(),
+            );
+            MlDsa87Result::Success
+        } else {
+            MlDsa87Result::SigVerifyFailed
+        };
+
+        verify_res.0.zeroize();
+        Ok(result)
+    }
+
+    /// Returns the verification result of the signature with specified public
+    /// key and message. Caller is expected to compare the result against the
+    /// first 64 bytes of the signature to determine whether it is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - Public key
+    /// * `msg` - Message to verify
+    /// * `signature` - Signature to verify
+    ///
+    /// # Result
+    ///
+    /// *  `LEArray4x16` - verify result
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub fn verify_res(
+        &mut self,
+        pub_key: &MlDsa87PubKey,
+        msg: &MlDsa87Msg,
+        signature: &MlDsa87Signature,
+    ) -> CaliptraResult<LEArray4x16> {
+        let mldsa = self.mldsa.regs_mut();
+
+        // Wait for hardware ready
+        wait::until(|| mldsa.status().read().ready());
+
+        // Copy public key, message and signature to registers
+        pub_key.write_to_reg(mldsa.pubkey());
+        msg.write_to_reg(mldsa.msg());
+        signature.write_to_reg(mldsa.signature());
+
+        // Program the command register
+        mldsa.ctrl().write(|w| w.ctrl(|w| w.verifying()));
+
+        // Wait for command to complete
+        wait::until(|| mldsa.status().read().valid());
+
+        let verify_res = LEArray4x16::read_from_reg(mldsa.verify_res());
+
+        self.zeroize_internal();
+
+        Ok(verify_res)
+    }
+
+    /// Zeroize the hardware registers.
+    fn zeroize_internal(&mut self) {
+        self.mldsa.regs_mut().ctrl().write(|w| w.zeroize(true));
+    }
+
+    /// Zeroize the hardware registers.
+    ///
+    /// This is useful to call from a fatal-error-handling routine.
+    ///
+    /// # Safety
+    ///
+    /// The caller must be certain that the results of any pending cryptographic
+    /// operations will not be used after this function is called.
+    ///
+    /// This function is safe to call from a trap handler.
+    pub unsafe fn zeroize() {
+        let mut mldsa = MldsaReg::new();
+        mldsa.regs_mut().ctrl().write(|w| w.zeroize(true));
+    }
+}
+
+/// ML-DSA-87 key access error trait
+trait MlDsa87KeyAccessErr {
+    /// Convert to read seed operation error
+    fn into_read_seed_err(self) -> CaliptraError;
+}
+
+impl MlDsa87KeyAccessErr for KvAccessErr {
+    /// Convert to read seed operation error
+    fn into_read_seed_err(self) -> CaliptraError {
+        match self {
+            KvAccessErr::KeyRead => CaliptraError::DRIVER_MLDSA87_READ_SEED_KV_READ,
+            KvAccessErr::KeyWrite => CaliptraError::DRIVER_MLDSA87_READ_SEED_KV_WRITE,
+            KvAccessErr::Generic => CaliptraError::DRIVER_MLDSA87_READ_SEED_KV_UNKNOWN,
+        }
+    }
+}
@@ -32,6 +32,7 @@ pub enum PcrLogEntryId {
     StashMeasurement = 5, // data size = 48 bytes
     RtTci = 6,            // data size = 48 bytes
     FwImageManifest = 7,  // data size = 48 bytes
+    ImageData = 8,        // data size = 48 bytes
 }
 
 impl From<u16> for PcrLogEntryId {
@@ -45,6 +46,7 @@ impl From<u16> for PcrLogEntryId {
             5 => PcrLogEntryId::StashMeasurement,
             6 => PcrLogEntryId::RtTci,
             7 => PcrLogEntryId::FwImageManifest,
+            8 => PcrLogEntryId::ImageData,
             _ => PcrLogEntryId::Invalid,
         }
     }
@@ -77,6 +79,7 @@ impl PcrLogEntry {
             PcrLogEntryId::StashMeasurement => 48,
             PcrLogEntryId::RtTci => 48,
             PcrLogEntryId::FwImageManifest => 48,
+            PcrLogEntryId::ImageData => 48,
         };
 
         &self.pcr_data.as_bytes()[..data_len]
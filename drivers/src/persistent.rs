@@ -4,11 +4,11 @@ use core::{marker::PhantomData, mem::size_of, ptr::addr_of};
 
 #[cfg(feature = "runtime")]
 use caliptra_auth_man_types::{
-    AuthManifestImageMetadata, AuthManifestImageMetadataCollection,
+    AuthManifestImageMetadata, AuthManifestImageMetadataCollection, AuthManifestImageMinSvnList,
     AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT,
 };
 use caliptra_error::{CaliptraError, CaliptraResult};
-use caliptra_image_types::ImageManifest;
+use caliptra_image_types::{ImageExtendedToc, ImageManifest, IMAGE_DATA_MAX_BYTE_SIZE};
 #[cfg(feature = "runtime")]
 use dpe::{DpeInstance, U8Bool, MAX_HANDLES};
 use zerocopy::{IntoBytes, KnownLayout, TryFromBytes};
@@ -41,7 +41,10 @@ pub const PCR_RESET_COUNTER_SIZE: u32 = 1024;
 pub const AUTH_MAN_IMAGE_METADATA_MAX_SIZE: u32 = 7 * 1024;
 pub const IDEVID_CSR_SIZE: u32 = 1024;
 pub const FMC_ALIAS_CSR_SIZE: u32 = 1024;
-pub const RESERVED_MEMORY_SIZE: u32 = 3 * 1024;
+pub const AUTH_MAN_IMAGE_MIN_SVN_SIZE: u32 = 1024;
+pub const EXTENDED_TOC_SIZE: u32 = 512;
+pub const IMAGE_DATA_SIZE: u32 = 1024;
+pub const RESERVED_MEMORY_SIZE: u32 = 512;
 
 pub const PCR_LOG_MAX_COUNT: usize = 17;
 pub const FUSE_LOG_MAX_COUNT: usize = 62;
@@ -200,6 +203,12 @@ const _: () = assert!(
     size_of::<AuthManifestImageMetadataCollection>() <= AUTH_MAN_IMAGE_METADATA_MAX_SIZE as usize
 );
 const _: () = assert!(size_of::<IdevIdCsr>() <= IDEVID_CSR_SIZE as usize);
+const _: () = assert!(size_of::<ImageExtendedToc>() <= EXTENDED_TOC_SIZE as usize);
+#[allow(clippy::assertions_on_constants)]
+const _: () = assert!(IMAGE_DATA_MAX_BYTE_SIZE <= IMAGE_DATA_SIZE);
+#[cfg(feature = "runtime")]
+const _: () =
+    assert!(size_of::<AuthManifestImageMinSvnList>() <= AUTH_MAN_IMAGE_MIN_SVN_SIZE as usize);
 
 #[derive(TryFromBytes, IntoBytes, KnownLayout, Zeroize)]
 #[repr(C)]
@@ -264,6 +273,25 @@ pub struct PersistentData {
 
     reserved11: [u8; FMC_ALIAS_CSR_SIZE as usize - size_of::<FmcAliasCsr>()],
 
+    // Taken from the reserved memory below.
+    #[cfg(feature = "runtime")]
+    pub auth_manifest_image_min_svn_list: AuthManifestImageMinSvnList,
+    #[cfg(feature = "runtime")]
+    reserved12:
+        [u8; AUTH_MAN_IMAGE_MIN_SVN_SIZE as usize - size_of::<AuthManifestImageMinSvnList>()],
+
+    #[cfg(not(feature = "runtime"))]
+    pub auth_manifest_image_min_svn_list: [u8; AUTH_MAN_IMAGE_MIN_SVN_SIZE as usize],
+
+    // Extended TOC of the image bundle that manifest1 belongs to.
+    // Taken from the reserved memory below.
+    pub extended_toc: ImageExtendedToc,
+    reserved13: [u8; EXTENDED_TOC_SIZE as usize - size_of::<ImageExtendedToc>()],
+
+    // Data blobs from the image bundle, copied by the ROM in TOC order.
+    // Taken from the reserved memory below.
+    pub image_data: [u8; IMAGE_DATA_SIZE as usize],
+
     // Reserved memory for future objects.
     // New objects should always source memory from this range.
     // Taking memory from this reserve does NOT break hitless updates.
@@ -359,6 +387,24 @@ impl PersistentData {
             );
 
             persistent_data_offset += FMC_ALIAS_CSR_SIZE;
+            assert_eq!(
+                addr_of!((*P).auth_manifest_image_min_svn_list) as u32,
+                memory_layout::PERSISTENT_DATA_ORG + persistent_data_offset
+            );
+
+            persistent_data_offset += AUTH_MAN_IMAGE_MIN_SVN_SIZE;
+            assert_eq!(
+                addr_of!((*P).extended_toc) as u32,
+                memory_layout::PERSISTENT_DATA_ORG + persistent_data_offset
+            );
+
+            persistent_data_offset += EXTENDED_TOC_SIZE;
+            assert_eq!(
+                addr_of!((*P).image_data) as u32,
+                memory_layout::PERSISTENT_DATA_ORG + persistent_data_offset
+            );
+
+            persistent_data_offset += IMAGE_DATA_SIZE;
             assert_eq!(
                 addr_of!((*P).reserved_memory) as u32,
                 memory_layout::PERSISTENT_DATA_ORG + persistent_data_offset
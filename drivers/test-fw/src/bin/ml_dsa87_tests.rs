@@ -0,0 +1,227 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    ml_dsa87_tests.rs
+
+Abstract:
+
+    File contains test cases for ML-DSA-87 API tests
+
+--*/
+
+#![no_std]
+#![no_main]
+
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_cfi_lib::CfiCounter;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_drivers::{
+    Array4x12, Hmac384, KeyId, KeyReadArgs, KeyUsage, KeyWriteArgs, MlDsa87, MlDsa87Msg,
+    MlDsa87PubKey, MlDsa87Result, MlDsa87Seed, MlDsa87SeedArray, MlDsa87SignRnd, Trng,
+};
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_error::CaliptraError;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_kat::MlDsa87Kat;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_registers::{
+    csrng::CsrngReg, entropy_src::EntropySrcReg, hmac::HmacReg, mldsa::MldsaReg,
+    soc_ifc::SocIfcReg, soc_ifc_trng::SocIfcTrngReg,
+};
+use caliptra_test_harness::test_suite;
+
+/// First words of the public key generated from a zero seed
+#[cfg(not(feature = "hw-1.0"))]
+const PUB_KEY_START: [u32; 8] = [
+    0x65cb5f2f, 0x77e4b9c3, 0x89a16fde, 0x93051d2b, 0xf4d2833c, 0xef429f16, 0x4f15a72f, 0x0c42c3af,
+];
+
+/// First words of the signature of a zero message by the zero seed key, with
+/// zero signing randomness
+#[cfg(not(feature = "hw-1.0"))]
+const SIGNATURE_START: [u32; 8] = [
+    0xecdc54d5, 0xb5d9dcba, 0xcde33c8f, 0x6a5dc597, 0x1646f640, 0xc809c31a, 0xbe575e81, 0x3867d82a,
+];
+
+#[cfg(not(feature = "hw-1.0"))]
+fn trng() -> Trng {
+    unsafe {
+        Trng::new(
+            CsrngReg::new(),
+            EntropySrcReg::new(),
+            SocIfcTrngReg::new(),
+            &SocIfcReg::new(),
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(not(feature = "hw-1.0"))]
+fn test_gen_key_pair() {
+    let mut mldsa = unsafe { MlDsa87::new(MldsaReg::new()) };
+    let mut trng = trng();
+
+    let seed = MlDsa87SeedArray::default();
+    let pub_key = mldsa
+        .key_pair(&MlDsa87Seed::from(&seed), &mut trng)
+        .unwrap();
+    assert_eq!(pub_key.0[..8], PUB_KEY_START);
+}
+
+#[cfg(not(feature = "hw-1.0"))]
+fn test_sign() {
+    let mut mldsa = unsafe { MlDsa87::new(MldsaReg::new()) };
+    let mut trng = trng();
+
+    let seed = MlDsa87SeedArray::default();
+    let seed = MlDsa87Seed::from(&seed);
+    let pub_key = mldsa.key_pair(&seed, &mut trng).unwrap();
+    let signature = mldsa
+        .sign(
+            &seed,
+            &pub_key,
+            &MlDsa87Msg::default(),
+            &MlDsa87SignRnd::default(),
+            &mut trng,
+        )
+        .unwrap();
+    assert_eq!(signature.0[..8], SIGNATURE_START);
+}
+
+#[cfg(not(feature = "hw-1.0"))]
+fn test_verify() {
+    let mut mldsa = unsafe { MlDsa87::new(MldsaReg::new()) };
+    let mut trng = trng();
+
+    let seed = MlDsa87SeedArray::new([0x0123_4567; 8]);
+    let seed = MlDsa87Seed::from(&seed);
+    let msg = MlDsa87Msg::from([0xa5u8; 64]);
+    let pub_key = mldsa.key_pair(&seed, &mut trng).unwrap();
+    let signature = mldsa
+        .sign(&seed, &pub_key, &msg, &MlDsa87SignRnd::default(), &mut trng)
+        .unwrap();
+    assert_eq!(
+        mldsa.verify(&pub_key, &msg, &signature).unwrap(),
+        MlDsa87Result::Success
+    );
+}
+
+#[cfg(not(feature = "hw-1.0"))]
+fn test_verify_failure() {
+    let mut mldsa = unsafe { MlDsa87::new(MldsaReg::new()) };
+    let mut trng = trng();
+
+    let seed = MlDsa87SeedArray::default();
+    let seed = MlDsa87Seed::from(&seed);
+    let msg = MlDsa87Msg::default();
+    let pub_key = mldsa.key_pair(&seed, &mut trng).unwrap();
+    let signature = mldsa
+        .sign(&seed, &pub_key, &msg, &MlDsa87SignRnd::default(), &mut trng)
+        .unwrap();
+    let msg = MlDsa87Msg::from([0xffu8; 64]);
+    assert_eq!(
+        mldsa.verify(&pub_key, &msg, &signature).unwrap(),
+        MlDsa87Result::SigVerifyFailed
+    );
+}
+
+#[cfg(not(feature = "hw-1.0"))]
+fn test_kv_seed() {
+    let mut mldsa = unsafe { MlDsa87::new(MldsaReg::new()) };
+    let mut hmac384 = unsafe { Hmac384::new(HmacReg::new()) };
+    let mut trng = trng();
+
+    //
+    // Step 1: Derive a seed into kv slot 3, usable as an ML-DSA-87 seed.
+    //
+    let key = Array4x12::new([0x1122_3344; 12]);
+    hmac384
+        .hmac(
+            &(&key).into(),
+            &(&[0x55u8; 16]).into(),
+            &mut trng,
+            KeyWriteArgs::new(
+                KeyId::KeyId3,
+                KeyUsage::default().set_mldsa_key_gen_seed_en(),
+            )
+            .into(),
+        )
+        .unwrap();
+
+    //
+    // Step 2: Generate the key pair and sign with the seed from the key vault.
+    //
+    let seed = MlDsa87Seed::from(KeyReadArgs::new(KeyId::KeyId3));
+    let msg = MlDsa87Msg::from([0x42u8; 64]);
+    let pub_key = mldsa.key_pair(&seed, &mut trng).unwrap();
+    assert_ne!(pub_key, MlDsa87PubKey::default());
+    assert_ne!(pub_key.0[..8], PUB_KEY_START);
+    let signature = mldsa
+        .sign(&seed, &pub_key, &msg, &MlDsa87SignRnd::default(), &mut trng)
+        .unwrap();
+
+    //
+    // Step 3: Verify the signature generated in step 2.
+    //
+    assert_eq!(
+        mldsa.verify(&pub_key, &msg, &signature).unwrap(),
+        MlDsa87Result::Success
+    );
+}
+
+#[cfg(not(feature = "hw-1.0"))]
+fn test_kv_seed_bad_usage() {
+    let mut mldsa = unsafe { MlDsa87::new(MldsaReg::new()) };
+    let mut hmac384 = unsafe { Hmac384::new(HmacReg::new()) };
+    let mut trng = trng();
+
+    // The caller needs to use set_mldsa_key_gen_seed_en() here to prevent the error
+    let key = Array4x12::new([0x1122_3344; 12]);
+    hmac384
+        .hmac(
+            &(&key).into(),
+            &(&[0x55u8; 16]).into(),
+            &mut trng,
+            KeyWriteArgs::new(KeyId::KeyId4, KeyUsage::default().set_ecc_key_gen_seed_en()).into(),
+        )
+        .unwrap();
+
+    let seed = MlDsa87Seed::from(KeyReadArgs::new(KeyId::KeyId4));
+    assert_eq!(
+        mldsa.key_pair(&seed, &mut trng),
+        Err(CaliptraError::DRIVER_MLDSA87_READ_SEED_KV_READ)
+    );
+}
+
+#[cfg(not(feature = "hw-1.0"))]
+fn test_kat() {
+    let mut mldsa = unsafe { MlDsa87::new(MldsaReg::new()) };
+    let mut trng = trng();
+
+    // Init CFI
+    let mut entropy_gen = || trng.generate().map(|a| a.0);
+    CfiCounter::reset(&mut entropy_gen);
+
+    assert_eq!(
+        MlDsa87Kat::default().execute(&mut mldsa, &mut trng).is_ok(),
+        true
+    );
+}
+
+// There is no ML-DSA-87 engine in 1.0 hardware.
+#[cfg(feature = "hw-1.0")]
+test_suite! {}
+
+#[cfg(not(feature = "hw-1.0"))]
+test_suite! {
+    test_kat,
+    test_gen_key_pair,
+    test_sign,
+    test_verify,
+    test_verify_failure,
+    test_kv_seed,
+    test_kv_seed_bad_usage,
+}
@@ -760,6 +760,12 @@ fn test_lms_32() {
     run_driver_test(&firmware::driver_tests::TEST_LMS_32);
 }
 
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_ml_dsa87() {
+    run_driver_test(&firmware::driver_tests::ML_DSA87);
+}
+
 #[test]
 fn test_negative_lms() {
     run_driver_test(&firmware::driver_tests::TEST_NEGATIVE_LMS);
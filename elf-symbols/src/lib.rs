@@ -1,10 +1,15 @@
 // Licensed under the Apache-2.0 license
 
+//! Reads the symbol table of an ELF file. Used by the firmware builder and by
+//! the emulator's profiler, which must not depend on the builder.
+
 use std::io;
 
 use elf::endian::LittleEndian;
 
-use super::other_err;
+fn other_err(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e)
+}
 
 pub fn elf_symbols(elf_bytes: &[u8]) -> io::Result<Vec<Symbol>> {
     let elf = elf::ElfBytes::<LittleEndian>::minimal_parse(elf_bytes).map_err(other_err)?;
@@ -160,16 +165,15 @@ impl TryFrom<u8> for SymbolBind {
 
 #[cfg(test)]
 mod test {
-    use crate::{self as caliptra_builder, SymbolBind, SymbolType, SymbolVisibility};
+    use crate::{elf_symbols, Symbol, SymbolBind, SymbolType, SymbolVisibility};
 
     #[test]
     fn test_elf_symbols() {
-        let symbols =
-            caliptra_builder::elf_symbols(include_bytes!("testdata/example.elf")).unwrap();
+        let symbols = elf_symbols(include_bytes!("testdata/example.elf")).unwrap();
         let bss_start = symbols.iter().find(|s| s.name == "BSS_START");
         assert_eq!(
             bss_start,
-            Some(&caliptra_builder::elf_symbols::Symbol {
+            Some(&Symbol {
                 name: "BSS_START",
                 size: 0,
                 value: 0x50000000,
@@ -0,0 +1,203 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    decode.rs
+
+Abstract:
+
+    Decodes the values of the Caliptra firmware error registers.
+
+--*/
+
+use caliptra_error::{CaliptraError, ERROR_TABLE};
+use std::process::exit;
+
+const USAGE: &str = "\
+Usage: caliptra-error-decode [OPTIONS] [CODE]...
+
+Decodes Caliptra error codes, such as the values of the CPTRA_FW_ERROR_FATAL,
+CPTRA_FW_ERROR_NON_FATAL and CPTRA_FW_EXTENDED_ERROR_INFO registers. Codes are
+hexadecimal with a 0x prefix, or decimal.
+
+Options:
+    --fatal <CODE>       Value of CPTRA_FW_ERROR_FATAL
+    --non-fatal <CODE>   Value of CPTRA_FW_ERROR_NON_FATAL
+    --extended <CODE>    Value of CPTRA_FW_EXTENDED_ERROR_INFO[0]
+    --list               List every error code
+    --help               Print this message";
+
+fn parse_code(s: &str) -> Option<u32> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(&hex.replace('_', ""), 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+/// Formats a defined error as its name and description
+fn name_and_description(code: u32) -> Option<String> {
+    let info = CaliptraError::try_from(code).ok()?.info()?;
+    Some(format!("{}: {}", info.name, info.description))
+}
+
+fn describe(code: u32) -> String {
+    match name_and_description(code) {
+        Some(name) => name,
+        None if code == 0 => "no error".into(),
+        None => "unknown error".into(),
+    }
+}
+
+/// The `DpeErrorCode` variants without an inner error, from
+/// dpe/dpe/src/response.rs
+const DPE_ERRORS: &[(u32, &str)] = &[
+    (0x1, "InternalError"),
+    (0x2, "InvalidCommand"),
+    (0x3, "InvalidArgument"),
+    (0x4, "ArgumentNotSupported"),
+    (0x1000, "InvalidHandle"),
+    (0x1001, "InvalidLocality"),
+    (0x1003, "MaxTcis"),
+];
+
+/// The extended error is either a `CaliptraError` (from the image verifier,
+/// or from `DpeErrorCode::get_error_detail()` when a DPE command fails in
+/// the crypto driver or platform), or a `DpeErrorCode::get_error_code()`.
+/// The latter is the variant in the top byte and, for the platform, crypto
+/// and validation variants, the inner error in the low bytes. The two ranges
+/// overlap, so every match is listed.
+fn describe_extended(code: u32) -> String {
+    if code == 0 {
+        return "no extended error".into();
+    }
+    let mut matches = vec![];
+    if let Some(name) = name_and_description(code) {
+        matches.push(name);
+    }
+    let inner = match code >> 24 {
+        1 => Some("PlatformError"),
+        2 => Some("CryptoError"),
+        3 => Some("ValidationError"),
+        _ => None,
+    };
+    if let Some(inner) = inner {
+        let variant = inner.trim_end_matches("Error");
+        matches.push(format!(
+            "DpeErrorCode::{variant}({inner} 0x{:x})",
+            code & 0xff_ffff
+        ));
+    } else if let Some((_, name)) = DPE_ERRORS.iter().find(|(value, _)| *value == code) {
+        matches.push(format!("DpeErrorCode::{name}"));
+    }
+    if matches.is_empty() {
+        return "unknown error".into();
+    }
+    matches.join(" or ")
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut decoded = vec![];
+    while let Some(arg) = args.next() {
+        let (label, extended) = match arg.as_str() {
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                return;
+            }
+            "--list" => {
+                for info in ERROR_TABLE {
+                    println!(
+                        "0x{:08x} {}: {}",
+                        u32::from(info.error),
+                        info.name,
+                        info.description
+                    );
+                }
+                return;
+            }
+            "--fatal" => ("CPTRA_FW_ERROR_FATAL", false),
+            "--non-fatal" => ("CPTRA_FW_ERROR_NON_FATAL", false),
+            "--extended" => ("CPTRA_FW_EXTENDED_ERROR_INFO", true),
+            code if !code.starts_with("--") => {
+                let Some(code) = parse_code(code) else {
+                    eprintln!("Invalid error code {code:?}\n\n{USAGE}");
+                    exit(2);
+                };
+                decoded.push(format!("0x{code:08x} {}", describe(code)));
+                continue;
+            }
+            _ => {
+                eprintln!("Unknown option {arg:?}\n\n{USAGE}");
+                exit(2);
+            }
+        };
+        let Some(code) = args.next().as_deref().and_then(parse_code) else {
+            eprintln!("{arg} requires an error code\n\n{USAGE}");
+            exit(2);
+        };
+        let description = match extended {
+            true => describe_extended(code),
+            false => describe(code),
+        };
+        decoded.push(format!("{label}: 0x{code:08x} {description}"));
+    }
+    if decoded.is_empty() {
+        eprintln!("{USAGE}");
+        exit(2);
+    }
+    for line in decoded {
+        println!("{line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_code() {
+        assert_eq!(parse_code("0x000B0003"), Some(0x000b_0003));
+        assert_eq!(parse_code("0X000e_0017"), Some(0x000e_0017));
+        assert_eq!(parse_code("720899"), Some(0x000b_0003));
+        assert_eq!(parse_code("0xg"), None);
+        assert_eq!(parse_code("-1"), None);
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            describe(0x0002_0001),
+            "DRIVER_SHA256_INVALID_STATE: Driver SHA256 invalid state"
+        );
+        assert_eq!(describe(0), "no error");
+        assert_eq!(describe(0xffff_fff0), "unknown error");
+    }
+
+    #[test]
+    fn test_describe_extended() {
+        assert_eq!(
+            describe_extended(0x0003_0001),
+            "DRIVER_SHA384_READ_DATA_KV_READ: Driver SHA384 read data KV read"
+        );
+        assert_eq!(describe_extended(0), "no extended error");
+        assert_eq!(describe_extended(0x4), "DpeErrorCode::ArgumentNotSupported");
+        assert_eq!(describe_extended(0x1000), "DpeErrorCode::InvalidHandle");
+        assert_eq!(
+            describe_extended(0x0100_0002),
+            "ROM_IDEVID_CSR_BUILDER_BUILD_FAILURE: ROM IDEVID CSR builder build failure \
+             or DpeErrorCode::Platform(PlatformError 0x2)"
+        );
+        assert_eq!(
+            describe_extended(0x0200_0011),
+            "DpeErrorCode::Crypto(CryptoError 0x11)"
+        );
+        assert_eq!(
+            describe_extended(0x0300_0005),
+            "DpeErrorCode::Validation(ValidationError 0x5)"
+        );
+        assert_eq!(describe_extended(0x1002), "unknown error");
+        assert_eq!(describe_extended(0x0400_0000), "unknown error");
+    }
+}
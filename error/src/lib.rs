@@ -29,619 +29,1108 @@ impl CaliptraError {
             None => panic!("CaliptraError cannot be 0"),
         }
     }
+}
+
+/// Defines the `CaliptraError` constants from `(NAME, value, "description")`
+/// tuples, along with the table used to look up the name and description of
+/// a code. A `///` comment before a tuple heads the constant's docs.
+macro_rules! define_error_constants {
+    ($($(#[doc = $doc:literal])* ($name:ident, $value:expr, $desc:literal)),* $(,)?) => {
+        impl CaliptraError {
+            $(
+                $(#[doc = $doc] #[doc = ""])*
+                #[doc = $desc]
+                pub const $name: CaliptraError = CaliptraError::new_const($value);
+            )*
+        }
+
+        /// Every defined error code, in order of definition
+        #[cfg(feature = "names")]
+        pub const ERROR_TABLE: &[ErrorInfo] = &[
+            $(
+                ErrorInfo {
+                    error: CaliptraError::$name,
+                    name: stringify!($name),
+                    description: $desc,
+                },
+            )*
+        ];
+    };
+}
 
-    pub const DRIVER_BAD_DATASTORE_VAULT_TYPE: CaliptraError = CaliptraError::new_const(0x00010001);
-    pub const DRIVER_BAD_DATASTORE_REG_TYPE: CaliptraError = CaliptraError::new_const(0x00010002);
+define_error_constants! {
+    (DRIVER_BAD_DATASTORE_VAULT_TYPE, 0x00010001, "Driver bad datastore vault type"),
+    (DRIVER_BAD_DATASTORE_REG_TYPE, 0x00010002, "Driver bad datastore reg type"),
 
-    pub const DRIVER_SHA256_INVALID_STATE: CaliptraError = CaliptraError::new_const(0x00020001);
-    pub const DRIVER_SHA256_MAX_DATA: CaliptraError = CaliptraError::new_const(0x00020002);
-    pub const DRIVER_SHA256_INVALID_SLICE: CaliptraError = CaliptraError::new_const(0x00020003);
-    pub const DRIVER_SHA256_INDEX_OUT_OF_BOUNDS: CaliptraError =
-        CaliptraError::new_const(0x00020004);
+    (DRIVER_SHA256_INVALID_STATE, 0x00020001, "Driver SHA256 invalid state"),
+    (DRIVER_SHA256_MAX_DATA, 0x00020002, "Driver SHA256 max data"),
+    (DRIVER_SHA256_INVALID_SLICE, 0x00020003, "Driver SHA256 invalid slice"),
+    (DRIVER_SHA256_INDEX_OUT_OF_BOUNDS, 0x00020004, "Driver SHA256 index out of bounds"),
 
     /// Driver Error: SHA384
-    pub const DRIVER_SHA384_READ_DATA_KV_READ: CaliptraError = CaliptraError::new_const(0x00030001);
-    pub const DRIVER_SHA384_READ_DATA_KV_WRITE: CaliptraError =
-        CaliptraError::new_const(0x00030002);
-    pub const DRIVER_SHA384_READ_DATA_KV_UNKNOWN: CaliptraError =
-        CaliptraError::new_const(0x00030003);
-    pub const DRIVER_SHA384_INVALID_STATE_ERR: CaliptraError = CaliptraError::new_const(0x00030007);
-    pub const DRIVER_SHA384_MAX_DATA_ERR: CaliptraError = CaliptraError::new_const(0x00030008);
-    pub const DRIVER_SHA384_INVALID_KEY_SIZE: CaliptraError = CaliptraError::new_const(0x00030009);
-    pub const DRIVER_SHA384_INVALID_SLICE: CaliptraError = CaliptraError::new_const(0x0003000A);
-    pub const DRIVER_SHA384_INDEX_OUT_OF_BOUNDS: CaliptraError =
-        CaliptraError::new_const(0x0003000B);
+    (DRIVER_SHA384_READ_DATA_KV_READ, 0x00030001, "Driver SHA384 read data KV read"),
+    (DRIVER_SHA384_READ_DATA_KV_WRITE, 0x00030002, "Driver SHA384 read data KV write"),
+    (DRIVER_SHA384_READ_DATA_KV_UNKNOWN, 0x00030003, "Driver SHA384 read data KV unknown"),
+    (DRIVER_SHA384_INVALID_STATE_ERR, 0x00030007, "Driver SHA384 invalid state error"),
+    (DRIVER_SHA384_MAX_DATA_ERR, 0x00030008, "Driver SHA384 max data error"),
+    (DRIVER_SHA384_INVALID_KEY_SIZE, 0x00030009, "Driver SHA384 invalid key size"),
+    (DRIVER_SHA384_INVALID_SLICE, 0x0003000A, "Driver SHA384 invalid slice"),
+    (DRIVER_SHA384_INDEX_OUT_OF_BOUNDS, 0x0003000B, "Driver SHA384 index out of bounds"),
 
     /// Driver Error: SHA2_512_384ACC
-    pub const DRIVER_SHA2_512_384ACC_UNEXPECTED_ACQUIRED_LOCK_STATE: CaliptraError =
-        CaliptraError::new_const(0x00038000);
+    (
+        DRIVER_SHA2_512_384ACC_UNEXPECTED_ACQUIRED_LOCK_STATE,
+        0x00038000,
+        "Driver SHA2 512 384ACC unexpected acquired lock state"
+    ),
 
     /// Driver Error: HMAC384
-    pub const DRIVER_HMAC384_READ_KEY_KV_READ: CaliptraError = CaliptraError::new_const(0x00040001);
-    pub const DRIVER_HMAC384_READ_KEY_KV_WRITE: CaliptraError =
-        CaliptraError::new_const(0x00040002);
-    pub const DRIVER_HMAC384_READ_KEY_KV_UNKNOWN: CaliptraError =
-        CaliptraError::new_const(0x00040003);
-    pub const DRIVER_HMAC384_READ_DATA_KV_READ: CaliptraError =
-        CaliptraError::new_const(0x00040004);
-    pub const DRIVER_HMAC384_READ_DATA_KV_WRITE: CaliptraError =
-        CaliptraError::new_const(0x00040005);
-    pub const DRIVER_HMAC384_READ_DATA_KV_UNKNOWN: CaliptraError =
-        CaliptraError::new_const(0x00040006);
-    pub const DRIVER_HMAC384_WRITE_TAG_KV_READ: CaliptraError =
-        CaliptraError::new_const(0x00040007);
-    pub const DRIVER_HMAC384_WRITE_TAG_KV_WRITE: CaliptraError =
-        CaliptraError::new_const(0x00040008);
-    pub const DRIVER_HMAC384_WRITE_TAG_KV_UNKNOWN: CaliptraError =
-        CaliptraError::new_const(0x00040009);
-    pub const DRIVER_HMAC384_INVALID_STATE: CaliptraError = CaliptraError::new_const(0x0004000b);
-    pub const DRIVER_HMAC384_MAX_DATA: CaliptraError = CaliptraError::new_const(0x0004000c);
-    pub const DRIVER_HMAC384_INVALID_SLICE: CaliptraError = CaliptraError::new_const(0x0004000d);
-    pub const DRIVER_HMAC384_INDEX_OUT_OF_BOUNDS: CaliptraError =
-        CaliptraError::new_const(0x0004000e);
+    (DRIVER_HMAC384_READ_KEY_KV_READ, 0x00040001, "Driver HMAC384 read key KV read"),
+    (DRIVER_HMAC384_READ_KEY_KV_WRITE, 0x00040002, "Driver HMAC384 read key KV write"),
+    (DRIVER_HMAC384_READ_KEY_KV_UNKNOWN, 0x00040003, "Driver HMAC384 read key KV unknown"),
+    (DRIVER_HMAC384_READ_DATA_KV_READ, 0x00040004, "Driver HMAC384 read data KV read"),
+    (DRIVER_HMAC384_READ_DATA_KV_WRITE, 0x00040005, "Driver HMAC384 read data KV write"),
+    (DRIVER_HMAC384_READ_DATA_KV_UNKNOWN, 0x00040006, "Driver HMAC384 read data KV unknown"),
+    (DRIVER_HMAC384_WRITE_TAG_KV_READ, 0x00040007, "Driver HMAC384 write tag KV read"),
+    (DRIVER_HMAC384_WRITE_TAG_KV_WRITE, 0x00040008, "Driver HMAC384 write tag KV write"),
+    (DRIVER_HMAC384_WRITE_TAG_KV_UNKNOWN, 0x00040009, "Driver HMAC384 write tag KV unknown"),
+    (DRIVER_HMAC384_INVALID_STATE, 0x0004000b, "Driver HMAC384 invalid state"),
+    (DRIVER_HMAC384_MAX_DATA, 0x0004000c, "Driver HMAC384 max data"),
+    (DRIVER_HMAC384_INVALID_SLICE, 0x0004000d, "Driver HMAC384 invalid slice"),
+    (DRIVER_HMAC384_INDEX_OUT_OF_BOUNDS, 0x0004000e, "Driver HMAC384 index out of bounds"),
 
     /// Driver Error: ECC384
-    pub const DRIVER_ECC384_READ_SEED_KV_READ: CaliptraError = CaliptraError::new_const(0x00050001);
-    pub const DRIVER_ECC384_READ_SEED_KV_WRITE: CaliptraError =
-        CaliptraError::new_const(0x00050002);
-    pub const DRIVER_ECC384_READ_SEED_KV_UNKNOWN: CaliptraError =
-        CaliptraError::new_const(0x00050003);
-
-    pub const DRIVER_ECC384_WRITE_PRIV_KEY_KV_READ: CaliptraError =
-        CaliptraError::new_const(0x00050004);
-    pub const DRIVER_ECC384_WRITE_PRIV_KEY_KV_WRITE: CaliptraError =
-        CaliptraError::new_const(0x00050005);
-    pub const DRIVER_ECC384_WRITE_PRIV_KEY_KV_UNKNOWN: CaliptraError =
-        CaliptraError::new_const(0x00050006);
-
-    pub const DRIVER_ECC384_READ_PRIV_KEY_KV_READ: CaliptraError =
-        CaliptraError::new_const(0x00050007);
-    pub const DRIVER_ECC384_READ_PRIV_KEY_KV_WRITE: CaliptraError =
-        CaliptraError::new_const(0x00050008);
-    pub const DRIVER_ECC384_READ_PRIV_KEY_KV_UNKNOWN: CaliptraError =
-        CaliptraError::new_const(0x00050009);
-
-    pub const DRIVER_ECC384_READ_DATA_KV_READ: CaliptraError = CaliptraError::new_const(0x0005000a);
-    pub const DRIVER_ECC384_READ_DATA_KV_WRITE: CaliptraError =
-        CaliptraError::new_const(0x0005000b);
-    pub const DRIVER_ECC384_READ_DATA_KV_UNKNOWN: CaliptraError =
-        CaliptraError::new_const(0x0005000c);
-    pub const DRIVER_ECC384_KEYGEN_PAIRWISE_CONSISTENCY_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x0005000d);
-    pub const DRIVER_ECC384_SIGN_VALIDATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x0005000e);
-    pub const DRIVER_ECC384_SCALAR_RANGE_CHECK_FAILED: CaliptraError =
-        CaliptraError::new_const(0x0005000f);
-    pub const DRIVER_ECC384_KEYGEN_BAD_USAGE: CaliptraError = CaliptraError::new_const(0x00050010);
-    pub const DRIVER_ECC384_HW_ERROR: CaliptraError = CaliptraError::new_const(0x00050011);
-
-    pub const DRIVER_KV_ERASE_USE_LOCK_SET_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x00060001);
-    pub const DRIVER_KV_ERASE_WRITE_LOCK_SET_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x00060002);
-
-    pub const DRIVER_PCR_BANK_ERASE_WRITE_LOCK_SET_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x00070001);
+    (DRIVER_ECC384_READ_SEED_KV_READ, 0x00050001, "Driver ECC384 read seed KV read"),
+    (DRIVER_ECC384_READ_SEED_KV_WRITE, 0x00050002, "Driver ECC384 read seed KV write"),
+    (DRIVER_ECC384_READ_SEED_KV_UNKNOWN, 0x00050003, "Driver ECC384 read seed KV unknown"),
+
+    (DRIVER_ECC384_WRITE_PRIV_KEY_KV_READ, 0x00050004, "Driver ECC384 write priv key KV read"),
+    (DRIVER_ECC384_WRITE_PRIV_KEY_KV_WRITE, 0x00050005, "Driver ECC384 write priv key KV write"),
+    (
+        DRIVER_ECC384_WRITE_PRIV_KEY_KV_UNKNOWN,
+        0x00050006,
+        "Driver ECC384 write priv key KV unknown"
+    ),
+
+    (DRIVER_ECC384_READ_PRIV_KEY_KV_READ, 0x00050007, "Driver ECC384 read priv key KV read"),
+    (DRIVER_ECC384_READ_PRIV_KEY_KV_WRITE, 0x00050008, "Driver ECC384 read priv key KV write"),
+    (DRIVER_ECC384_READ_PRIV_KEY_KV_UNKNOWN, 0x00050009, "Driver ECC384 read priv key KV unknown"),
+
+    (DRIVER_ECC384_READ_DATA_KV_READ, 0x0005000a, "Driver ECC384 read data KV read"),
+    (DRIVER_ECC384_READ_DATA_KV_WRITE, 0x0005000b, "Driver ECC384 read data KV write"),
+    (DRIVER_ECC384_READ_DATA_KV_UNKNOWN, 0x0005000c, "Driver ECC384 read data KV unknown"),
+    (
+        DRIVER_ECC384_KEYGEN_PAIRWISE_CONSISTENCY_FAILURE,
+        0x0005000d,
+        "Driver ECC384 keygen pairwise consistency failure"
+    ),
+    (DRIVER_ECC384_SIGN_VALIDATION_FAILED, 0x0005000e, "Driver ECC384 sign validation failed"),
+    (
+        DRIVER_ECC384_SCALAR_RANGE_CHECK_FAILED,
+        0x0005000f,
+        "Driver ECC384 scalar range check failed"
+    ),
+    (DRIVER_ECC384_KEYGEN_BAD_USAGE, 0x00050010, "Driver ECC384 keygen bad usage"),
+    (DRIVER_ECC384_HW_ERROR, 0x00050011, "Driver ECC384 HW error"),
+
+    (DRIVER_KV_ERASE_USE_LOCK_SET_FAILURE, 0x00060001, "Driver KV erase use lock set failure"),
+    (DRIVER_KV_ERASE_WRITE_LOCK_SET_FAILURE, 0x00060002, "Driver KV erase write lock set failure"),
+
+    (
+        DRIVER_PCR_BANK_ERASE_WRITE_LOCK_SET_FAILURE,
+        0x00070001,
+        "Driver PCR bank erase write lock set failure"
+    ),
 
     /// Mailbox Errors
-    pub const DRIVER_MAILBOX_INVALID_STATE: CaliptraError = CaliptraError::new_const(0x00080001);
-    pub const DRIVER_MAILBOX_INVALID_DATA_LEN: CaliptraError = CaliptraError::new_const(0x00080002);
-    pub const DRIVER_MAILBOX_ENQUEUE_ERR: CaliptraError = CaliptraError::new_const(0x00080004);
-    pub const DRIVER_MAILBOX_UNCORRECTABLE_ECC: CaliptraError =
-        CaliptraError::new_const(0x00080005);
+    (DRIVER_MAILBOX_INVALID_STATE, 0x00080001, "Driver mailbox invalid state"),
+    (DRIVER_MAILBOX_INVALID_DATA_LEN, 0x00080002, "Driver mailbox invalid data length"),
+    (DRIVER_MAILBOX_ENQUEUE_ERR, 0x00080004, "Driver mailbox enqueue error"),
+    (DRIVER_MAILBOX_UNCORRECTABLE_ECC, 0x00080005, "Driver mailbox uncorrectable ECC"),
 
     /// SHA2_512_384ACC Errors.
-    pub const DRIVER_SHA2_512_384ACC_INDEX_OUT_OF_BOUNDS: CaliptraError =
-        CaliptraError::new_const(0x00090003);
+    (
+        DRIVER_SHA2_512_384ACC_INDEX_OUT_OF_BOUNDS,
+        0x00090003,
+        "Driver SHA2 512 384ACC index out of bounds"
+    ),
     /// SHA1 Errors.
-    pub const DRIVER_SHA1_INVALID_STATE: CaliptraError = CaliptraError::new_const(0x000a0001);
-    pub const DRIVER_SHA1_MAX_DATA: CaliptraError = CaliptraError::new_const(0x000a0002);
-    pub const DRIVER_SHA1_INVALID_SLICE: CaliptraError = CaliptraError::new_const(0x000a0003);
-    pub const DRIVER_SHA1_INDEX_OUT_OF_BOUNDS: CaliptraError = CaliptraError::new_const(0x000a0004);
+    (DRIVER_SHA1_INVALID_STATE, 0x000a0001, "Driver SHA1 invalid state"),
+    (DRIVER_SHA1_MAX_DATA, 0x000a0002, "Driver SHA1 max data"),
+    (DRIVER_SHA1_INVALID_SLICE, 0x000a0003, "Driver SHA1 invalid slice"),
+    (DRIVER_SHA1_INDEX_OUT_OF_BOUNDS, 0x000a0004, "Driver SHA1 index out of bounds"),
 
     /// Image Verifier Errors
-    pub const IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b0001);
-    pub const IMAGE_VERIFIER_ERR_MANIFEST_SIZE_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b0002);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b0003);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b0004);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b0005);
-    pub const IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b0006);
-    pub const IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b0007);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_INDEX_OUT_OF_BOUNDS: CaliptraError =
-        CaliptraError::new_const(0x000b0008);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_REVOKED: CaliptraError =
-        CaliptraError::new_const(0x000b0009);
-    pub const IMAGE_VERIFIER_ERR_HEADER_DIGEST_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b000a);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_ECC_VERIFY_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b000b);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_ECC_SIGNATURE_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b000c);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_INDEX_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b000d);
-    pub const IMAGE_VERIFIER_ERR_OWNER_ECC_VERIFY_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b000e);
-    pub const IMAGE_VERIFIER_ERR_OWNER_ECC_SIGNATURE_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b000f);
-    pub const IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b0010);
-    pub const IMAGE_VERIFIER_ERR_TOC_DIGEST_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b0011);
-    pub const IMAGE_VERIFIER_ERR_TOC_DIGEST_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b0012);
-    pub const IMAGE_VERIFIER_ERR_FMC_DIGEST_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b0013);
-    pub const IMAGE_VERIFIER_ERR_FMC_DIGEST_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b0014);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b0015);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b0016);
-    pub const IMAGE_VERIFIER_ERR_FMC_RUNTIME_OVERLAP: CaliptraError =
-        CaliptraError::new_const(0x000b0017);
-    pub const IMAGE_VERIFIER_ERR_FMC_RUNTIME_INCORRECT_ORDER: CaliptraError =
-        CaliptraError::new_const(0x000b0018);
-    pub const IMAGE_VERIFIER_ERR_OWNER_ECC_PUB_KEY_INVALID_ARG: CaliptraError =
-        CaliptraError::new_const(0x000b0019);
-    pub const IMAGE_VERIFIER_ERR_OWNER_ECC_SIGNATURE_INVALID_ARG: CaliptraError =
-        CaliptraError::new_const(0x000b001a);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_INVALID_ARG: CaliptraError =
-        CaliptraError::new_const(0x000b001b);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_ECC_SIGNATURE_INVALID_ARG: CaliptraError =
-        CaliptraError::new_const(0x000b001c);
-    pub const IMAGE_VERIFIER_ERR_UPDATE_RESET_OWNER_DIGEST_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b001d);
-    pub const IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_ECC_PUB_KEY_IDX_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b001e);
-    pub const IMAGE_VERIFIER_ERR_UPDATE_RESET_FMC_DIGEST_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b001f);
-    pub const IMAGE_VERIFIER_ERR_FMC_LOAD_ADDR_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b0021);
-    pub const IMAGE_VERIFIER_ERR_FMC_LOAD_ADDR_UNALIGNED: CaliptraError =
-        CaliptraError::new_const(0x000b0022);
-    pub const IMAGE_VERIFIER_ERR_FMC_ENTRY_POINT_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b0023);
-    pub const IMAGE_VERIFIER_ERR_FMC_ENTRY_POINT_UNALIGNED: CaliptraError =
-        CaliptraError::new_const(0x000b0024);
-    pub const IMAGE_VERIFIER_ERR_FMC_SVN_GREATER_THAN_MAX_SUPPORTED: CaliptraError =
-        CaliptraError::new_const(0x000b0025);
-    pub const IMAGE_VERIFIER_ERR_FMC_SVN_LESS_THAN_MIN_SUPPORTED: CaliptraError =
-        CaliptraError::new_const(0x000b0026);
-    pub const IMAGE_VERIFIER_ERR_FMC_SVN_LESS_THAN_FUSE: CaliptraError =
-        CaliptraError::new_const(0x000b0027);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b0028);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_UNALIGNED: CaliptraError =
-        CaliptraError::new_const(0x000b0029);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_ENTRY_POINT_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b002a);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_ENTRY_POINT_UNALIGNED: CaliptraError =
-        CaliptraError::new_const(0x000b002b);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_SVN_GREATER_THAN_MAX_SUPPORTED: CaliptraError =
-        CaliptraError::new_const(0x000b002c);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_SVN_LESS_THAN_MIN_SUPPORTED: CaliptraError =
-        CaliptraError::new_const(0x000b002d);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_SVN_LESS_THAN_FUSE: CaliptraError =
-        CaliptraError::new_const(0x000b002e);
-    pub const IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE: CaliptraError =
-        CaliptraError::new_const(0x000b002f);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_INDEX_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b0030);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_LMS_VERIFY_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b0031);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_INDEX_OUT_OF_BOUNDS: CaliptraError =
-        CaliptraError::new_const(0x000b0032);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_LMS_SIGNATURE_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b0033);
-    pub const IMAGE_VERIFIER_ERR_FMC_RUNTIME_LOAD_ADDR_OVERLAP: CaliptraError =
-        CaliptraError::new_const(0x000b0034);
-    pub const IMAGE_VERIFIER_ERR_OWNER_LMS_VERIFY_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000b0036);
-    pub const IMAGE_VERIFIER_ERR_OWNER_LMS_SIGNATURE_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000b0038);
-    pub const IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_REVOKED: CaliptraError =
-        CaliptraError::new_const(0x000b0003a);
-    pub const IMAGE_VERIFIER_ERR_FMC_SIZE_ZERO: CaliptraError =
-        CaliptraError::new_const(0x000b003b);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_SIZE_ZERO: CaliptraError =
-        CaliptraError::new_const(0x000b003c);
-    pub const IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_LMS_PUB_KEY_IDX_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000b003d);
-    pub const IMAGE_VERIFIER_ERR_FMC_LOAD_ADDRESS_IMAGE_SIZE_ARITHMETIC_OVERFLOW: CaliptraError =
-        CaliptraError::new_const(0x000b003e);
-    pub const IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDRESS_IMAGE_SIZE_ARITHMETIC_OVERFLOW:
-        CaliptraError = CaliptraError::new_const(0x000b003f);
-    pub const IMAGE_VERIFIER_ERR_TOC_ENTRY_RANGE_ARITHMETIC_OVERFLOW: CaliptraError =
-        CaliptraError::new_const(0x000b0040);
-    pub const IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS: CaliptraError =
-        CaliptraError::new_const(0x000b0041);
+    (
+        IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH,
+        0x000b0001,
+        "Image verifier error manifest marker mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_MANIFEST_SIZE_MISMATCH,
+        0x000b0002,
+        "Image verifier error manifest size mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_INVALID,
+        0x000b0003,
+        "Image verifier error vendor pub key digest invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_FAILURE,
+        0x000b0004,
+        "Image verifier error vendor pub key digest failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH,
+        0x000b0005,
+        "Image verifier error vendor pub key digest mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_FAILURE,
+        0x000b0006,
+        "Image verifier error owner pub key digest failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH,
+        0x000b0007,
+        "Image verifier error owner pub key digest mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_INDEX_OUT_OF_BOUNDS,
+        0x000b0008,
+        "Image verifier error vendor ECC pub key index out of bounds"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_REVOKED,
+        0x000b0009,
+        "Image verifier error vendor ECC pub key revoked"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_HEADER_DIGEST_FAILURE,
+        0x000b000a,
+        "Image verifier error header digest failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_ECC_VERIFY_FAILURE,
+        0x000b000b,
+        "Image verifier error vendor ECC verify failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_ECC_SIGNATURE_INVALID,
+        0x000b000c,
+        "Image verifier error vendor ECC signature invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_INDEX_MISMATCH,
+        0x000b000d,
+        "Image verifier error vendor ECC pub key index mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_ECC_VERIFY_FAILURE,
+        0x000b000e,
+        "Image verifier error owner ECC verify failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_ECC_SIGNATURE_INVALID,
+        0x000b000f,
+        "Image verifier error owner ECC signature invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID,
+        0x000b0010,
+        "Image verifier error TOC entry count invalid"
+    ),
+    (IMAGE_VERIFIER_ERR_TOC_DIGEST_FAILURE, 0x000b0011, "Image verifier error TOC digest failure"),
+    (
+        IMAGE_VERIFIER_ERR_TOC_DIGEST_MISMATCH,
+        0x000b0012,
+        "Image verifier error TOC digest mismatch"
+    ),
+    (IMAGE_VERIFIER_ERR_FMC_DIGEST_FAILURE, 0x000b0013, "Image verifier error FMC digest failure"),
+    (
+        IMAGE_VERIFIER_ERR_FMC_DIGEST_MISMATCH,
+        0x000b0014,
+        "Image verifier error FMC digest mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_FAILURE,
+        0x000b0015,
+        "Image verifier error runtime digest failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH,
+        0x000b0016,
+        "Image verifier error runtime digest mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_RUNTIME_OVERLAP,
+        0x000b0017,
+        "Image verifier error FMC runtime overlap"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_RUNTIME_INCORRECT_ORDER,
+        0x000b0018,
+        "Image verifier error FMC runtime incorrect order"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_ECC_PUB_KEY_INVALID_ARG,
+        0x000b0019,
+        "Image verifier error owner ECC pub key invalid argument"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_ECC_SIGNATURE_INVALID_ARG,
+        0x000b001a,
+        "Image verifier error owner ECC signature invalid argument"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_INVALID_ARG,
+        0x000b001b,
+        "Image verifier error vendor pub key digest invalid argument"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_ECC_SIGNATURE_INVALID_ARG,
+        0x000b001c,
+        "Image verifier error vendor ECC signature invalid argument"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_UPDATE_RESET_OWNER_DIGEST_FAILURE,
+        0x000b001d,
+        "Image verifier error update reset owner digest failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_ECC_PUB_KEY_IDX_MISMATCH,
+        0x000b001e,
+        "Image verifier error update reset vendor ECC pub key index mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_UPDATE_RESET_FMC_DIGEST_MISMATCH,
+        0x000b001f,
+        "Image verifier error update reset FMC digest mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_LOAD_ADDR_INVALID,
+        0x000b0021,
+        "Image verifier error FMC load address invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_LOAD_ADDR_UNALIGNED,
+        0x000b0022,
+        "Image verifier error FMC load address unaligned"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_ENTRY_POINT_INVALID,
+        0x000b0023,
+        "Image verifier error FMC entry point invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_ENTRY_POINT_UNALIGNED,
+        0x000b0024,
+        "Image verifier error FMC entry point unaligned"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_SVN_GREATER_THAN_MAX_SUPPORTED,
+        0x000b0025,
+        "Image verifier error FMC SVN greater than max supported"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_SVN_LESS_THAN_MIN_SUPPORTED,
+        0x000b0026,
+        "Image verifier error FMC SVN less than min supported"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_SVN_LESS_THAN_FUSE,
+        0x000b0027,
+        "Image verifier error FMC SVN less than fuse"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_INVALID,
+        0x000b0028,
+        "Image verifier error runtime load address invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_UNALIGNED,
+        0x000b0029,
+        "Image verifier error runtime load address unaligned"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_ENTRY_POINT_INVALID,
+        0x000b002a,
+        "Image verifier error runtime entry point invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_ENTRY_POINT_UNALIGNED,
+        0x000b002b,
+        "Image verifier error runtime entry point unaligned"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_SVN_GREATER_THAN_MAX_SUPPORTED,
+        0x000b002c,
+        "Image verifier error runtime SVN greater than max supported"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_SVN_LESS_THAN_MIN_SUPPORTED,
+        0x000b002d,
+        "Image verifier error runtime SVN less than min supported"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_SVN_LESS_THAN_FUSE,
+        0x000b002e,
+        "Image verifier error runtime SVN less than fuse"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE,
+        0x000b002f,
+        "Image verifier error image length more than bundle size"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_INDEX_MISMATCH,
+        0x000b0030,
+        "Image verifier error vendor LMS pub key index mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_LMS_VERIFY_FAILURE,
+        0x000b0031,
+        "Image verifier error vendor LMS verify failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_INDEX_OUT_OF_BOUNDS,
+        0x000b0032,
+        "Image verifier error vendor LMS pub key index out of bounds"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_LMS_SIGNATURE_INVALID,
+        0x000b0033,
+        "Image verifier error vendor LMS signature invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_RUNTIME_LOAD_ADDR_OVERLAP,
+        0x000b0034,
+        "Image verifier error FMC runtime load address overlap"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_LMS_VERIFY_FAILURE,
+        0x000b0036,
+        "Image verifier error owner LMS verify failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_LMS_SIGNATURE_INVALID,
+        0x000b0038,
+        "Image verifier error owner LMS signature invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_REVOKED,
+        0x000b0003a,
+        "Image verifier error vendor LMS pub key revoked"
+    ),
+    (IMAGE_VERIFIER_ERR_FMC_SIZE_ZERO, 0x000b003b, "Image verifier error FMC size zero"),
+    (IMAGE_VERIFIER_ERR_RUNTIME_SIZE_ZERO, 0x000b003c, "Image verifier error runtime size zero"),
+    (
+        IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_LMS_PUB_KEY_IDX_MISMATCH,
+        0x000b003d,
+        "Image verifier error update reset vendor LMS pub key index mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_FMC_LOAD_ADDRESS_IMAGE_SIZE_ARITHMETIC_OVERFLOW,
+        0x000b003e,
+        "Image verifier error FMC load address image size arithmetic overflow"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDRESS_IMAGE_SIZE_ARITHMETIC_OVERFLOW,
+        0x000b003f,
+        "Image verifier error runtime load address image size arithmetic overflow"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_TOC_ENTRY_RANGE_ARITHMETIC_OVERFLOW,
+        0x000b0040,
+        "Image verifier error TOC entry range arithmetic overflow"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS,
+        0x000b0041,
+        "Image verifier error digest out of bounds"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_OUT_OF_BOUNDS,
+        0x000b0042,
+        "Image verifier vendor MLDSA pub key index out of bounds"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_REVOKED,
+        0x000b0043,
+        "Image verifier vendor MLDSA pub key revoked"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_DIGEST_FAILURE,
+        0x000b0044,
+        "Image verifier vendor MLDSA pub key digest failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_DIGEST_MISMATCH,
+        0x000b0045,
+        "Image verifier vendor MLDSA pub key digest mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_MISMATCH,
+        0x000b0046,
+        "Image verifier vendor MLDSA pub key index mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE,
+        0x000b0047,
+        "Image verifier vendor MLDSA verify failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID,
+        0x000b0048,
+        "Image verifier vendor MLDSA signature invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_MLDSA_VERIFY_FAILURE,
+        0x000b0049,
+        "Image verifier owner MLDSA verify failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_MLDSA_SIGNATURE_INVALID,
+        0x000b004a,
+        "Image verifier owner MLDSA signature invalid"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_MLDSA_PUB_KEY_IDX_MISMATCH,
+        0x000b004b,
+        "Image verifier update reset vendor MLDSA pub key index mismatch"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_DATA_TYPE_INVALID,
+        0x000b004c,
+        "Image verifier data TOC entry type invalid"
+    ),
+    (IMAGE_VERIFIER_ERR_DATA_SIZE_ZERO, 0x000b004d, "Image verifier data TOC entry size zero"),
+    (
+        IMAGE_VERIFIER_ERR_DATA_INCORRECT_ORDER,
+        0x000b004e,
+        "Image verifier data TOC entries out of order"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_DATA_ID_DUPLICATE,
+        0x000b004f,
+        "Image verifier data TOC entry ID duplicate"
+    ),
+    (IMAGE_VERIFIER_ERR_DATA_TOO_LARGE, 0x000b0050, "Image verifier data too large"),
+    (IMAGE_VERIFIER_ERR_DATA_DIGEST_FAILURE, 0x000b0051, "Image verifier data digest failure"),
+    (IMAGE_VERIFIER_ERR_DATA_DIGEST_MISMATCH, 0x000b0052, "Image verifier data digest mismatch"),
+    (IMAGE_VERIFIER_ERR_FMC_TYPE_INVALID, 0x000b0053, "Image verifier FMC TOC entry type invalid"),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_MLDSA_PUB_KEY_DIGEST_FAILURE,
+        0x000b0054,
+        "Image verifier owner MLDSA pub key digest failure"
+    ),
+    (
+        IMAGE_VERIFIER_ERR_OWNER_MLDSA_PUB_KEY_DIGEST_MISMATCH,
+        0x000b0055,
+        "Image verifier owner MLDSA pub key digest mismatch"
+    ),
+    (IMAGE_VERIFIER_ERR_MLDSA_PREAMBLE_MISSING, 0x000b0056, "Image verifier MLDSA preamble missing"),
+    (
+        IMAGE_VERIFIER_ERR_MLDSA_PREAMBLE_DIGEST_FAILURE,
+        0x000b0057,
+        "Image verifier MLDSA preamble digest failure"
+    ),
 
     /// Driver Error: LMS
-    pub const DRIVER_LMS_INVALID_LMS_ALGO_TYPE: CaliptraError =
-        CaliptraError::new_const(0x000c0001);
-    pub const DRIVER_LMS_INVALID_LMOTS_ALGO_TYPE: CaliptraError =
-        CaliptraError::new_const(0x000c0002);
-    pub const DRIVER_LMS_INVALID_WINTERNITS_PARAM: CaliptraError =
-        CaliptraError::new_const(0x000c0003);
-    pub const DRIVER_LMS_INVALID_PVALUE: CaliptraError = CaliptraError::new_const(0x000c0004);
+    (DRIVER_LMS_INVALID_LMS_ALGO_TYPE, 0x000c0001, "Driver LMS invalid LMS algorithm type"),
+    (DRIVER_LMS_INVALID_LMOTS_ALGO_TYPE, 0x000c0002, "Driver LMS invalid LMOTS algorithm type"),
+    (DRIVER_LMS_INVALID_WINTERNITS_PARAM, 0x000c0003, "Driver LMS invalid winternits parameter"),
+    (DRIVER_LMS_INVALID_PVALUE, 0x000c0004, "Driver LMS invalid pvalue"),
 
-    pub const DRIVER_LMS_INVALID_HASH_WIDTH: CaliptraError = CaliptraError::new_const(0x000c0005);
+    (DRIVER_LMS_INVALID_HASH_WIDTH, 0x000c0005, "Driver LMS invalid hash width"),
 
-    pub const DRIVER_LMS_INVALID_TREE_HEIGHT: CaliptraError = CaliptraError::new_const(0x000c0006);
+    (DRIVER_LMS_INVALID_TREE_HEIGHT, 0x000c0006, "Driver LMS invalid tree height"),
 
-    pub const DRIVER_LMS_INVALID_Q_VALUE: CaliptraError = CaliptraError::new_const(0x000c0007);
+    (DRIVER_LMS_INVALID_Q_VALUE, 0x000c0007, "Driver LMS invalid q value"),
 
-    pub const DRIVER_LMS_INVALID_INDEX: CaliptraError = CaliptraError::new_const(0x000c0008);
+    (DRIVER_LMS_INVALID_INDEX, 0x000c0008, "Driver LMS invalid index"),
 
-    pub const DRIVER_LMS_PATH_OUT_OF_BOUNDS: CaliptraError = CaliptraError::new_const(0x000c0009);
+    (DRIVER_LMS_PATH_OUT_OF_BOUNDS, 0x000c0009, "Driver LMS path out of bounds"),
 
-    pub const DRIVER_LMS_INVALID_SIGNATURE_LENGTH: CaliptraError =
-        CaliptraError::new_const(0x000c000a);
-    pub const DRIVER_LMS_INVALID_PUBLIC_KEY_LENGTH: CaliptraError =
-        CaliptraError::new_const(0x000c000b);
-    pub const DRIVER_LMS_INVALID_SIGNATURE_DEPTH: CaliptraError =
-        CaliptraError::new_const(0x000c000c);
+    (DRIVER_LMS_INVALID_SIGNATURE_LENGTH, 0x000c000a, "Driver LMS invalid signature length"),
+    (DRIVER_LMS_INVALID_PUBLIC_KEY_LENGTH, 0x000c000b, "Driver LMS invalid public key length"),
+    (DRIVER_LMS_INVALID_SIGNATURE_DEPTH, 0x000c000c, "Driver LMS invalid signature depth"),
 
-    pub const DRIVER_LMS_SIGNATURE_LMOTS_DOESNT_MATCH_PUBKEY_LMOTS: CaliptraError =
-        CaliptraError::new_const(0x000c000d);
+    (
+        DRIVER_LMS_SIGNATURE_LMOTS_DOESNT_MATCH_PUBKEY_LMOTS,
+        0x000c000d,
+        "Driver LMS signature LMOTS doesn't match public key LMOTS"
+    ),
 
     /// CSRNG Errors
-    pub const DRIVER_CSRNG_INSTANTIATE: CaliptraError = CaliptraError::new_const(0x000d0001);
-    pub const DRIVER_CSRNG_UNINSTANTIATE: CaliptraError = CaliptraError::new_const(0x000d0002);
-    pub const DRIVER_CSRNG_RESEED: CaliptraError = CaliptraError::new_const(0x000d0003);
-    pub const DRIVER_CSRNG_GENERATE: CaliptraError = CaliptraError::new_const(0x000d0004);
-    pub const DRIVER_CSRNG_UPDATE: CaliptraError = CaliptraError::new_const(0x000d0005);
-    pub const DRIVER_CSRNG_OTHER_HEALTH_CHECK_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000d0006);
-    pub const DRIVER_CSRNG_REPCNT_HEALTH_CHECK_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000d0007);
-    pub const DRIVER_CSRNG_ADAPTP_HEALTH_CHECK_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000d0008);
-
-    pub const DRIVER_HANDOFF_INVALID_VAULT: CaliptraError = CaliptraError::new_const(0x000D100);
-    pub const DRIVER_HANDOFF_INVALID_KEY_ID: CaliptraError = CaliptraError::new_const(0x000D101);
-    pub const DRIVER_HANDOFF_INVALID_COLD_RESET_ENTRY4: CaliptraError =
-        CaliptraError::new_const(0x000D102);
-    pub const DRIVER_HANDOFF_INVALID_COLD_RESET_ENTRY48: CaliptraError =
-        CaliptraError::new_const(0x000D103);
-    pub const DRIVER_HANDOFF_INVALID_WARM_RESET_ENTRY4: CaliptraError =
-        CaliptraError::new_const(0x000D104);
-    pub const DRIVER_HANDOFF_INVALID_WARM_RESET_ENTRY48: CaliptraError =
-        CaliptraError::new_const(0x000D105);
+    (DRIVER_CSRNG_INSTANTIATE, 0x000d0001, "Driver CSRNG instantiate"),
+    (DRIVER_CSRNG_UNINSTANTIATE, 0x000d0002, "Driver CSRNG uninstantiate"),
+    (DRIVER_CSRNG_RESEED, 0x000d0003, "Driver CSRNG reseed"),
+    (DRIVER_CSRNG_GENERATE, 0x000d0004, "Driver CSRNG generate"),
+    (DRIVER_CSRNG_UPDATE, 0x000d0005, "Driver CSRNG update"),
+    (DRIVER_CSRNG_OTHER_HEALTH_CHECK_FAILED, 0x000d0006, "Driver CSRNG other health check failed"),
+    (
+        DRIVER_CSRNG_REPCNT_HEALTH_CHECK_FAILED,
+        0x000d0007,
+        "Driver CSRNG repcnt health check failed"
+    ),
+    (
+        DRIVER_CSRNG_ADAPTP_HEALTH_CHECK_FAILED,
+        0x000d0008,
+        "Driver CSRNG adaptp health check failed"
+    ),
+
+    (DRIVER_HANDOFF_INVALID_VAULT, 0x000D100, "Driver handoff invalid vault"),
+    (DRIVER_HANDOFF_INVALID_KEY_ID, 0x000D101, "Driver handoff invalid key ID"),
+    (
+        DRIVER_HANDOFF_INVALID_COLD_RESET_ENTRY4,
+        0x000D102,
+        "Driver handoff invalid cold reset ENTRY4"
+    ),
+    (
+        DRIVER_HANDOFF_INVALID_COLD_RESET_ENTRY48,
+        0x000D103,
+        "Driver handoff invalid cold reset ENTRY48"
+    ),
+    (
+        DRIVER_HANDOFF_INVALID_WARM_RESET_ENTRY4,
+        0x000D104,
+        "Driver handoff invalid warm reset ENTRY4"
+    ),
+    (
+        DRIVER_HANDOFF_INVALID_WARM_RESET_ENTRY48,
+        0x000D105,
+        "Driver handoff invalid warm reset ENTRY48"
+    ),
 
     /// Runtime Errors
-    pub const RUNTIME_INTERNAL: CaliptraError = CaliptraError::new_const(0x000E0001);
-    pub const RUNTIME_UNIMPLEMENTED_COMMAND: CaliptraError = CaliptraError::new_const(0x000E0002);
-    pub const RUNTIME_INSUFFICIENT_MEMORY: CaliptraError = CaliptraError::new_const(0x000E0003);
-    pub const RUNTIME_ECDSA_VERIFY_FAILED: CaliptraError = CaliptraError::new_const(0x000E0004);
-    pub const RUNTIME_INVALID_CHECKSUM: CaliptraError = CaliptraError::new_const(0x000E0005);
-    pub const RUNTIME_HANDOFF_FHT_NOT_LOADED: CaliptraError = CaliptraError::new_const(0x000E0006);
-    pub const RUNTIME_UNEXPECTED_UPDATE_RETURN: CaliptraError =
-        CaliptraError::new_const(0x000E0007);
-    pub const RUNTIME_SHUTDOWN: CaliptraError = CaliptraError::new_const(0x000E0008);
-    pub const RUNTIME_MAILBOX_INVALID_PARAMS: CaliptraError = CaliptraError::new_const(0x000E0009);
-    pub const RUNTIME_GLOBAL_NMI: CaliptraError = CaliptraError::new_const(0x000E000A);
-    pub const RUNTIME_GLOBAL_EXCEPTION: CaliptraError = CaliptraError::new_const(0x000E000B);
-    pub const RUNTIME_GLOBAL_PANIC: CaliptraError = CaliptraError::new_const(0x000E000C);
-    pub const RUNTIME_HMAC_VERIFY_FAILED: CaliptraError = CaliptraError::new_const(0x000E000D);
-    pub const RUNTIME_INITIALIZE_DPE_FAILED: CaliptraError = CaliptraError::new_const(0x000E000E);
-    pub const RUNTIME_GET_IDEVID_CERT_FAILED: CaliptraError = CaliptraError::new_const(0x000E000F);
-    pub const RUNTIME_CERT_CHAIN_CREATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0010);
-    pub const RUNTIME_SELF_TEST_IN_PROGRESS: CaliptraError = CaliptraError::new_const(0x000E0011);
-    pub const RUNTIME_SELF_TEST_NOT_STARTED: CaliptraError = CaliptraError::new_const(0x000E0012);
-    pub const RUNTIME_INVALID_FMC_SIZE: CaliptraError = CaliptraError::new_const(0x000E0013);
-    pub const RUNTIME_INVALID_RUNTIME_SIZE: CaliptraError = CaliptraError::new_const(0x000E0014);
-    pub const RUNTIME_FMC_CERT_HANDOFF_FAILED: CaliptraError = CaliptraError::new_const(0x000E0015);
-    pub const RUNTIME_INCORRECT_PAUSER_PRIVILEGE_LEVEL: CaliptraError =
-        CaliptraError::new_const(0x000E0016);
-    pub const RUNTIME_DPE_VALIDATION_FAILED: CaliptraError = CaliptraError::new_const(0x000E0017);
-    pub const RUNTIME_UNKNOWN_RESET_FLOW: CaliptraError = CaliptraError::new_const(0x000E0018);
-    pub const RUNTIME_PL0_USED_DPE_CONTEXT_THRESHOLD_EXCEEDED: CaliptraError =
-        CaliptraError::new_const(0x000E0019);
-    pub const RUNTIME_PL1_USED_DPE_CONTEXT_THRESHOLD_EXCEEDED: CaliptraError =
-        CaliptraError::new_const(0x000E001A);
-    pub const RUNTIME_GLOBAL_WDT_EXPIRED: CaliptraError = CaliptraError::new_const(0x000E001B);
-    pub const RUNTIME_IDEV_CERT_POPULATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E001C);
-    pub const RUNTIME_ADD_ROM_MEASUREMENTS_TO_DPE_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E001D);
-    pub const RUNTIME_TAGGING_FAILURE: CaliptraError = CaliptraError::new_const(0x000E001E);
-    pub const RUNTIME_DUPLICATE_TAG: CaliptraError = CaliptraError::new_const(0x000E001F);
-    pub const RUNTIME_CONTEXT_ALREADY_TAGGED: CaliptraError = CaliptraError::new_const(0x000E0020);
-    pub const RUNTIME_ADD_VALID_PAUSER_MEASUREMENT_TO_DPE_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0021);
-    pub const RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE: CaliptraError =
-        CaliptraError::new_const(0x000E0022);
-    pub const RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE: CaliptraError =
-        CaliptraError::new_const(0x000E0023);
-    pub const RUNTIME_LDEVID_CERT_HANDOFF_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0024);
-    pub const RUNTIME_CONTEXT_TAGS_VALIDATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0025);
-    pub const RUNTIME_COULD_NOT_GET_DPE_PROFILE: CaliptraError =
-        CaliptraError::new_const(0x000E0026);
-    pub const RUNTIME_DPE_COMMAND_DESERIALIZATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0027);
-    pub const RUNTIME_GET_LDEVID_CERT_FAILED: CaliptraError = CaliptraError::new_const(0x000E0028);
-    pub const RUNTIME_GET_FMC_ALIAS_CERT_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0029);
-    pub const RUNTIME_GET_RT_ALIAS_CERT_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E002A);
-    pub const RUNTIME_CMD_BUSY_DURING_WARM_RESET: CaliptraError =
-        CaliptraError::new_const(0x000E002B);
-    pub const RUNTIME_RT_SVN_HANDOFF_FAILED: CaliptraError = CaliptraError::new_const(0x000E002C);
-    pub const RUNTIME_RT_MIN_SVN_HANDOFF_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E002D);
-    pub const RUNTIME_FMC_SVN_HANDOFF_FAILED: CaliptraError = CaliptraError::new_const(0x000E002E);
-    pub const RUNTIME_CONTEXT_HAS_TAG_VALIDATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E002F);
-    pub const RUNTIME_LDEV_ID_CERT_TOO_BIG: CaliptraError = CaliptraError::new_const(0x000E0030);
-    pub const RUNTIME_FMC_ALIAS_CERT_TOO_BIG: CaliptraError = CaliptraError::new_const(0x000E0031);
-    pub const RUNTIME_RT_ALIAS_CERT_TOO_BIG: CaliptraError = CaliptraError::new_const(0x000E0032);
-    pub const RUNTIME_COMPUTE_RT_ALIAS_SN_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0033);
-    pub const RUNTIME_RT_JOURNEY_PCR_VALIDATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0034);
-    pub const RUNTIME_UNABLE_TO_FIND_DPE_ROOT_CONTEXT: CaliptraError =
-        CaliptraError::new_const(0x000E0035);
-    pub const RUNTIME_INCREMENT_PCR_RESET_MAX_REACHED: CaliptraError =
-        CaliptraError::new_const(0x000E0036);
-    pub const RUNTIME_PL0_USED_DPE_CONTEXT_THRESHOLD_REACHED: CaliptraError =
-        CaliptraError::new_const(0x000E0037);
-    pub const RUNTIME_PL1_USED_DPE_CONTEXT_THRESHOLD_REACHED: CaliptraError =
-        CaliptraError::new_const(0x000E0038);
-    pub const RUNTIME_CDI_KV_HDL_HANDOFF_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0039);
-    pub const RUNTIME_PRIV_KEY_KV_HDL_HANDOFF_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E003A);
-    pub const RUNTIME_HASH_CHAIN_HANDOFF_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E003B);
+    (RUNTIME_INTERNAL, 0x000E0001, "Runtime internal"),
+    (RUNTIME_UNIMPLEMENTED_COMMAND, 0x000E0002, "Runtime unimplemented command"),
+    (RUNTIME_INSUFFICIENT_MEMORY, 0x000E0003, "Runtime insufficient memory"),
+    (RUNTIME_ECDSA_VERIFY_FAILED, 0x000E0004, "Runtime ECDSA verify failed"),
+    (RUNTIME_INVALID_CHECKSUM, 0x000E0005, "Runtime invalid checksum"),
+    (RUNTIME_HANDOFF_FHT_NOT_LOADED, 0x000E0006, "Runtime handoff FHT not loaded"),
+    (RUNTIME_UNEXPECTED_UPDATE_RETURN, 0x000E0007, "Runtime unexpected update return"),
+    (RUNTIME_SHUTDOWN, 0x000E0008, "Runtime shutdown"),
+    (RUNTIME_MAILBOX_INVALID_PARAMS, 0x000E0009, "Runtime mailbox invalid parameters"),
+    (RUNTIME_GLOBAL_NMI, 0x000E000A, "Runtime global NMI"),
+    (RUNTIME_GLOBAL_EXCEPTION, 0x000E000B, "Runtime global exception"),
+    (RUNTIME_GLOBAL_PANIC, 0x000E000C, "Runtime global panic"),
+    (RUNTIME_HMAC_VERIFY_FAILED, 0x000E000D, "Runtime HMAC verify failed"),
+    (RUNTIME_INITIALIZE_DPE_FAILED, 0x000E000E, "Runtime initialize DPE failed"),
+    (RUNTIME_GET_IDEVID_CERT_FAILED, 0x000E000F, "Runtime get IDEVID cert failed"),
+    (RUNTIME_CERT_CHAIN_CREATION_FAILED, 0x000E0010, "Runtime cert chain creation failed"),
+    (RUNTIME_SELF_TEST_IN_PROGRESS, 0x000E0011, "Runtime self test in progress"),
+    (RUNTIME_SELF_TEST_NOT_STARTED, 0x000E0012, "Runtime self test not started"),
+    (RUNTIME_INVALID_FMC_SIZE, 0x000E0013, "Runtime invalid FMC size"),
+    (RUNTIME_INVALID_RUNTIME_SIZE, 0x000E0014, "Runtime invalid runtime size"),
+    (RUNTIME_FMC_CERT_HANDOFF_FAILED, 0x000E0015, "Runtime FMC cert handoff failed"),
+    (
+        RUNTIME_INCORRECT_PAUSER_PRIVILEGE_LEVEL,
+        0x000E0016,
+        "Runtime incorrect pauser privilege level"
+    ),
+    (RUNTIME_DPE_VALIDATION_FAILED, 0x000E0017, "Runtime DPE validation failed"),
+    (RUNTIME_UNKNOWN_RESET_FLOW, 0x000E0018, "Runtime unknown reset flow"),
+    (
+        RUNTIME_PL0_USED_DPE_CONTEXT_THRESHOLD_EXCEEDED,
+        0x000E0019,
+        "Runtime PL0 used DPE context threshold exceeded"
+    ),
+    (
+        RUNTIME_PL1_USED_DPE_CONTEXT_THRESHOLD_EXCEEDED,
+        0x000E001A,
+        "Runtime PL1 used DPE context threshold exceeded"
+    ),
+    (RUNTIME_GLOBAL_WDT_EXPIRED, 0x000E001B, "Runtime global WDT expired"),
+    (RUNTIME_IDEV_CERT_POPULATION_FAILED, 0x000E001C, "Runtime IDEV cert population failed"),
+    (
+        RUNTIME_ADD_ROM_MEASUREMENTS_TO_DPE_FAILED,
+        0x000E001D,
+        "Runtime add ROM measurements to DPE failed"
+    ),
+    (RUNTIME_TAGGING_FAILURE, 0x000E001E, "Runtime tagging failure"),
+    (RUNTIME_DUPLICATE_TAG, 0x000E001F, "Runtime duplicate tag"),
+    (RUNTIME_CONTEXT_ALREADY_TAGGED, 0x000E0020, "Runtime context already tagged"),
+    (
+        RUNTIME_ADD_VALID_PAUSER_MEASUREMENT_TO_DPE_FAILED,
+        0x000E0021,
+        "Runtime add valid pauser measurement to DPE failed"
+    ),
+    (
+        RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE,
+        0x000E0022,
+        "Runtime mailbox API response data length too large"
+    ),
+    (
+        RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE,
+        0x000E0023,
+        "Runtime mailbox API request data length too large"
+    ),
+    (RUNTIME_LDEVID_CERT_HANDOFF_FAILED, 0x000E0024, "Runtime LDEVID cert handoff failed"),
+    (RUNTIME_CONTEXT_TAGS_VALIDATION_FAILED, 0x000E0025, "Runtime context tags validation failed"),
+    (RUNTIME_COULD_NOT_GET_DPE_PROFILE, 0x000E0026, "Runtime could not get DPE profile"),
+    (
+        RUNTIME_DPE_COMMAND_DESERIALIZATION_FAILED,
+        0x000E0027,
+        "Runtime DPE command deserialization failed"
+    ),
+    (RUNTIME_GET_LDEVID_CERT_FAILED, 0x000E0028, "Runtime get LDEVID cert failed"),
+    (RUNTIME_GET_FMC_ALIAS_CERT_FAILED, 0x000E0029, "Runtime get FMC alias cert failed"),
+    (RUNTIME_GET_RT_ALIAS_CERT_FAILED, 0x000E002A, "Runtime get RT alias cert failed"),
+    (RUNTIME_CMD_BUSY_DURING_WARM_RESET, 0x000E002B, "Runtime command busy during warm reset"),
+    (RUNTIME_RT_SVN_HANDOFF_FAILED, 0x000E002C, "Runtime RT SVN handoff failed"),
+    (RUNTIME_RT_MIN_SVN_HANDOFF_FAILED, 0x000E002D, "Runtime RT min SVN handoff failed"),
+    (RUNTIME_FMC_SVN_HANDOFF_FAILED, 0x000E002E, "Runtime FMC SVN handoff failed"),
+    (
+        RUNTIME_CONTEXT_HAS_TAG_VALIDATION_FAILED,
+        0x000E002F,
+        "Runtime context has tag validation failed"
+    ),
+    (RUNTIME_LDEV_ID_CERT_TOO_BIG, 0x000E0030, "Runtime LDEV ID cert too big"),
+    (RUNTIME_FMC_ALIAS_CERT_TOO_BIG, 0x000E0031, "Runtime FMC alias cert too big"),
+    (RUNTIME_RT_ALIAS_CERT_TOO_BIG, 0x000E0032, "Runtime RT alias cert too big"),
+    (RUNTIME_COMPUTE_RT_ALIAS_SN_FAILED, 0x000E0033, "Runtime compute RT alias SN failed"),
+    (
+        RUNTIME_RT_JOURNEY_PCR_VALIDATION_FAILED,
+        0x000E0034,
+        "Runtime RT journey PCR validation failed"
+    ),
+    (
+        RUNTIME_UNABLE_TO_FIND_DPE_ROOT_CONTEXT,
+        0x000E0035,
+        "Runtime unable to find DPE root context"
+    ),
+    (
+        RUNTIME_INCREMENT_PCR_RESET_MAX_REACHED,
+        0x000E0036,
+        "Runtime increment PCR reset max reached"
+    ),
+    (
+        RUNTIME_PL0_USED_DPE_CONTEXT_THRESHOLD_REACHED,
+        0x000E0037,
+        "Runtime PL0 used DPE context threshold reached"
+    ),
+    (
+        RUNTIME_PL1_USED_DPE_CONTEXT_THRESHOLD_REACHED,
+        0x000E0038,
+        "Runtime PL1 used DPE context threshold reached"
+    ),
+    (RUNTIME_CDI_KV_HDL_HANDOFF_FAILED, 0x000E0039, "Runtime CDI KV handle handoff failed"),
+    (
+        RUNTIME_PRIV_KEY_KV_HDL_HANDOFF_FAILED,
+        0x000E003A,
+        "Runtime priv key KV handle handoff failed"
+    ),
+    (RUNTIME_HASH_CHAIN_HANDOFF_FAILED, 0x000E003B, "Runtime hash chain handoff failed"),
     /// PCR Runtime Errors
-    pub const RUNTIME_PCR_RESERVED: CaliptraError = CaliptraError::new_const(0x000E003C);
-    pub const RUNTIME_PCR_INVALID_INDEX: CaliptraError = CaliptraError::new_const(0x000E003D);
-    pub const RUNTIME_DMTF_DEVICE_INFO_VALIDATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E003E);
-    pub const RUNTIME_STORE_DMTF_DEVICE_INFO_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E003F);
-    pub const RUNTIME_CERTIFY_KEY_EXTENDED_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0040);
-    pub const RUNTIME_DPE_RESPONSE_SERIALIZATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0041);
-    pub const RUNTIME_LMS_VERIFY_FAILED: CaliptraError = CaliptraError::new_const(0x000E0042);
-    pub const RUNTIME_LMS_VERIFY_INVALID_LMS_ALGORITHM: CaliptraError =
-        CaliptraError::new_const(0x000E0043);
-    pub const RUNTIME_LMS_VERIFY_INVALID_LMOTS_ALGORITHM: CaliptraError =
-        CaliptraError::new_const(0x000E0044);
-    pub const RUNTIME_INVALID_AUTH_MANIFEST_MARKER: CaliptraError =
-        CaliptraError::new_const(0x000E0045);
-    pub const RUNTIME_AUTH_MANIFEST_PREAMBLE_SIZE_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x000E0046);
-    pub const RUNTIME_AUTH_MANIFEST_VENDOR_ECC_SIGNATURE_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000E0047);
-    pub const RUNTIME_AUTH_MANIFEST_VENDOR_LMS_SIGNATURE_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000E0048);
-    pub const RUNTIME_AUTH_MANIFEST_OWNER_ECC_SIGNATURE_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000E0049);
-    pub const RUNTIME_AUTH_MANIFEST_OWNER_LMS_SIGNATURE_INVALID: CaliptraError =
-        CaliptraError::new_const(0x000E004A);
-    pub const RUNTIME_AUTH_MANIFEST_PREAMBLE_SIZE_LT_MIN: CaliptraError =
-        CaliptraError::new_const(0x000E004B);
-    pub const RUNTIME_AUTH_MANIFEST_IMAGE_METADATA_LIST_INVALID_SIZE: CaliptraError =
-        CaliptraError::new_const(0x000E004C);
-    pub const RUNTIME_AUTH_MANIFEST_IMAGE_METADATA_LIST_INVALID_ENTRY_COUNT: CaliptraError =
-        CaliptraError::new_const(0x000E004D);
-    pub const RUNTIME_AUTH_AND_STASH_UNSUPPORTED_IMAGE_SOURCE: CaliptraError =
-        CaliptraError::new_const(0x000E004E);
-    pub const RUNTIME_CMD_RESERVED_PAUSER: CaliptraError = CaliptraError::new_const(0x000E004F);
-    pub const RUNTIME_AUTH_AND_STASH_MEASUREMENT_DPE_ERROR: CaliptraError =
-        CaliptraError::new_const(0x000E0050);
-    pub const RUNTIME_GET_IDEV_ID_UNPROVISIONED: CaliptraError =
-        CaliptraError::new_const(0x000E0051);
-    pub const RUNTIME_GET_IDEV_ID_UNSUPPORTED_ROM: CaliptraError =
-        CaliptraError::new_const(0x000E0052);
-    pub const RUNTIME_AUTH_MANIFEST_IMAGE_METADATA_LIST_DUPLICATE_FIRMWARE_ID: CaliptraError =
-        CaliptraError::new_const(0x000E0053);
-    pub const RUNTIME_SIGN_WITH_EXPORTED_ECDSA_KEY_DERIVIATION_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0054);
-    pub const RUNTIME_SIGN_WITH_EXPORTED_ECDSA_SIGNATURE_FAILED: CaliptraError =
-        CaliptraError::new_const(0x000E0055);
-    pub const RUNTIME_SIGN_WITH_EXPORTED_ECDSA_INVALID_DIGEST: CaliptraError =
-        CaliptraError::new_const(0x000E0056);
-    pub const RUNTIME_SIGN_WITH_EXPORTED_ECDSA_INVALID_SIGNATURE: CaliptraError =
-        CaliptraError::new_const(0x000E0057);
-
-    pub const RUNTIME_GET_FMC_CSR_UNPROVISIONED: CaliptraError =
-        CaliptraError::new_const(0x000E0058);
-
-    pub const RUNTIME_GET_FMC_CSR_UNSUPPORTED_FMC: CaliptraError =
-        CaliptraError::new_const(0x000E0059);
-
-    pub const RUNTIME_REVOKE_EXPORTED_CDI_HANDLE_NOT_FOUND: CaliptraError =
-        CaliptraError::new_const(0x000E005A);
+    (RUNTIME_PCR_RESERVED, 0x000E003C, "Runtime PCR reserved"),
+    (RUNTIME_PCR_INVALID_INDEX, 0x000E003D, "Runtime PCR invalid index"),
+    (
+        RUNTIME_DMTF_DEVICE_INFO_VALIDATION_FAILED,
+        0x000E003E,
+        "Runtime DMTF device info validation failed"
+    ),
+    (RUNTIME_STORE_DMTF_DEVICE_INFO_FAILED, 0x000E003F, "Runtime store DMTF device info failed"),
+    (RUNTIME_CERTIFY_KEY_EXTENDED_FAILED, 0x000E0040, "Runtime certify key extended failed"),
+    (
+        RUNTIME_DPE_RESPONSE_SERIALIZATION_FAILED,
+        0x000E0041,
+        "Runtime DPE response serialization failed"
+    ),
+    (RUNTIME_LMS_VERIFY_FAILED, 0x000E0042, "Runtime LMS verify failed"),
+    (
+        RUNTIME_LMS_VERIFY_INVALID_LMS_ALGORITHM,
+        0x000E0043,
+        "Runtime LMS verify invalid LMS algorithm"
+    ),
+    (
+        RUNTIME_LMS_VERIFY_INVALID_LMOTS_ALGORITHM,
+        0x000E0044,
+        "Runtime LMS verify invalid LMOTS algorithm"
+    ),
+    (RUNTIME_INVALID_AUTH_MANIFEST_MARKER, 0x000E0045, "Runtime invalid auth manifest marker"),
+    (
+        RUNTIME_AUTH_MANIFEST_PREAMBLE_SIZE_MISMATCH,
+        0x000E0046,
+        "Runtime auth manifest preamble size mismatch"
+    ),
+    (
+        RUNTIME_AUTH_MANIFEST_VENDOR_ECC_SIGNATURE_INVALID,
+        0x000E0047,
+        "Runtime auth manifest vendor ECC signature invalid"
+    ),
+    (
+        RUNTIME_AUTH_MANIFEST_VENDOR_LMS_SIGNATURE_INVALID,
+        0x000E0048,
+        "Runtime auth manifest vendor LMS signature invalid"
+    ),
+    (
+        RUNTIME_AUTH_MANIFEST_OWNER_ECC_SIGNATURE_INVALID,
+        0x000E0049,
+        "Runtime auth manifest owner ECC signature invalid"
+    ),
+    (
+        RUNTIME_AUTH_MANIFEST_OWNER_LMS_SIGNATURE_INVALID,
+        0x000E004A,
+        "Runtime auth manifest owner LMS signature invalid"
+    ),
+    (
+        RUNTIME_AUTH_MANIFEST_PREAMBLE_SIZE_LT_MIN,
+        0x000E004B,
+        "Runtime auth manifest preamble size LT min"
+    ),
+    (
+        RUNTIME_AUTH_MANIFEST_IMAGE_METADATA_LIST_INVALID_SIZE,
+        0x000E004C,
+        "Runtime auth manifest image metadata list invalid size"
+    ),
+    (
+        RUNTIME_AUTH_MANIFEST_IMAGE_METADATA_LIST_INVALID_ENTRY_COUNT,
+        0x000E004D,
+        "Runtime auth manifest image metadata list invalid entry count"
+    ),
+    (
+        RUNTIME_AUTH_AND_STASH_UNSUPPORTED_IMAGE_SOURCE,
+        0x000E004E,
+        "Runtime auth and stash unsupported image source"
+    ),
+    (RUNTIME_CMD_RESERVED_PAUSER, 0x000E004F, "Runtime command reserved pauser"),
+    (
+        RUNTIME_AUTH_AND_STASH_MEASUREMENT_DPE_ERROR,
+        0x000E0050,
+        "Runtime auth and stash measurement DPE error"
+    ),
+    (RUNTIME_GET_IDEV_ID_UNPROVISIONED, 0x000E0051, "Runtime get IDEV ID unprovisioned"),
+    (RUNTIME_GET_IDEV_ID_UNSUPPORTED_ROM, 0x000E0052, "Runtime get IDEV ID unsupported ROM"),
+    (
+        RUNTIME_AUTH_MANIFEST_IMAGE_METADATA_LIST_DUPLICATE_FIRMWARE_ID,
+        0x000E0053,
+        "Runtime auth manifest image metadata list duplicate firmware ID"
+    ),
+    (
+        RUNTIME_SIGN_WITH_EXPORTED_ECDSA_KEY_DERIVIATION_FAILED,
+        0x000E0054,
+        "Runtime sign with exported ECDSA key deriviation failed"
+    ),
+    (
+        RUNTIME_SIGN_WITH_EXPORTED_ECDSA_SIGNATURE_FAILED,
+        0x000E0055,
+        "Runtime sign with exported ECDSA signature failed"
+    ),
+    (
+        RUNTIME_SIGN_WITH_EXPORTED_ECDSA_INVALID_DIGEST,
+        0x000E0056,
+        "Runtime sign with exported ECDSA invalid digest"
+    ),
+    (
+        RUNTIME_SIGN_WITH_EXPORTED_ECDSA_INVALID_SIGNATURE,
+        0x000E0057,
+        "Runtime sign with exported ECDSA invalid signature"
+    ),
+
+    (RUNTIME_GET_FMC_CSR_UNPROVISIONED, 0x000E0058, "Runtime get FMC CSR unprovisioned"),
+
+    (RUNTIME_GET_FMC_CSR_UNSUPPORTED_FMC, 0x000E0059, "Runtime get FMC CSR unsupported FMC"),
+
+    (
+        RUNTIME_REVOKE_EXPORTED_CDI_HANDLE_NOT_FOUND,
+        0x000E005A,
+        "Runtime revoke exported CDI handle not found"
+    ),
+
+    (
+        RUNTIME_AUTH_AND_STASH_SHA_ACC_LOCK_FAILURE,
+        0x000E005B,
+        "Runtime auth and stash SHA acc lock failure"
+    ),
+
+    (
+        RUNTIME_AUTH_AND_STASH_INVALID_IMAGE_RANGE,
+        0x000E005C,
+        "Runtime auth and stash invalid image range"
+    ),
+
+    (
+        RUNTIME_AUTH_MANIFEST_IMAGE_MIN_SVN_LIST_FULL,
+        0x000E005D,
+        "Runtime auth manifest image min SVN list full"
+    ),
+
+    (RUNTIME_GET_IMAGE_DATA_NOT_FOUND, 0x000E005E, "Runtime get image data not found"),
+
+    (
+        RUNTIME_FIPS_SELF_TEST_IMAGE_DIGEST_MISMATCH,
+        0x000E005F,
+        "Runtime FIPS self test image digest mismatch"
+    ),
 
     /// FMC Errors
-    pub const FMC_GLOBAL_NMI: CaliptraError = CaliptraError::new_const(0x000F0001);
-    pub const FMC_GLOBAL_EXCEPTION: CaliptraError = CaliptraError::new_const(0x000F0002);
-    pub const FMC_GLOBAL_PANIC: CaliptraError = CaliptraError::new_const(0x000F0003);
-    pub const FMC_HANDOFF_INVALID_PARAM: CaliptraError = CaliptraError::new_const(0x000F0004);
-    pub const FMC_RT_ALIAS_DERIVE_FAILURE: CaliptraError = CaliptraError::new_const(0x000F0005);
-    pub const FMC_RT_ALIAS_CERT_VERIFY: CaliptraError = CaliptraError::new_const(0x000F0006);
-    pub const FMC_RT_ALIAS_TBS_SIZE_EXCEEDED: CaliptraError = CaliptraError::new_const(0x000F0007);
-    pub const FMC_CDI_KV_COLLISION: CaliptraError = CaliptraError::new_const(0x000F0008);
-    pub const FMC_ALIAS_KV_COLLISION: CaliptraError = CaliptraError::new_const(0x000F0009);
-    pub const FMC_GLOBAL_PCR_LOG_EXHAUSTED: CaliptraError = CaliptraError::new_const(0x000F000A);
-    pub const ADDRESS_NOT_IN_ICCM: CaliptraError = CaliptraError::new_const(0x000F000B);
-    pub const FMC_HANDOFF_NOT_READY_FOR_RT: CaliptraError = CaliptraError::new_const(0x000F000C);
-    pub const FMC_GLOBAL_WDT_EXPIRED: CaliptraError = CaliptraError::new_const(0x000F000D);
-    pub const FMC_UNKNOWN_RESET: CaliptraError = CaliptraError::new_const(0x000F000E);
+    (FMC_GLOBAL_NMI, 0x000F0001, "FMC global NMI"),
+    (FMC_GLOBAL_EXCEPTION, 0x000F0002, "FMC global exception"),
+    (FMC_GLOBAL_PANIC, 0x000F0003, "FMC global panic"),
+    (FMC_HANDOFF_INVALID_PARAM, 0x000F0004, "FMC handoff invalid parameter"),
+    (FMC_RT_ALIAS_DERIVE_FAILURE, 0x000F0005, "FMC RT alias derive failure"),
+    (FMC_RT_ALIAS_CERT_VERIFY, 0x000F0006, "FMC RT alias cert verify"),
+    (FMC_RT_ALIAS_TBS_SIZE_EXCEEDED, 0x000F0007, "FMC RT alias TBS size exceeded"),
+    (FMC_CDI_KV_COLLISION, 0x000F0008, "FMC CDI KV collision"),
+    (FMC_ALIAS_KV_COLLISION, 0x000F0009, "FMC alias KV collision"),
+    (FMC_GLOBAL_PCR_LOG_EXHAUSTED, 0x000F000A, "FMC global PCR log exhausted"),
+    (ADDRESS_NOT_IN_ICCM, 0x000F000B, "Address not in ICCM"),
+    (FMC_HANDOFF_NOT_READY_FOR_RT, 0x000F000C, "FMC handoff not ready for RT"),
+    (FMC_GLOBAL_WDT_EXPIRED, 0x000F000D, "FMC global WDT expired"),
+    (FMC_UNKNOWN_RESET, 0x000F000E, "FMC unknown reset"),
 
     /// FMC Alias CSR Errors
-    pub const FMC_ALIAS_CSR_BUILDER_INIT_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000F000F);
-    pub const FMC_ALIAS_CSR_BUILDER_BUILD_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000F0010);
-    pub const FMC_ALIAS_INVALID_CSR: CaliptraError = CaliptraError::new_const(0x000F0011);
-    pub const FMC_ALIAS_CSR_VERIFICATION_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x000F0012);
-    pub const FMC_ALIAS_CSR_OVERFLOW: CaliptraError = CaliptraError::new_const(0x000F0013);
+    (FMC_ALIAS_CSR_BUILDER_INIT_FAILURE, 0x000F000F, "FMC alias CSR builder init failure"),
+    (FMC_ALIAS_CSR_BUILDER_BUILD_FAILURE, 0x000F0010, "FMC alias CSR builder build failure"),
+    (FMC_ALIAS_INVALID_CSR, 0x000F0011, "FMC alias invalid CSR"),
+    (FMC_ALIAS_CSR_VERIFICATION_FAILURE, 0x000F0012, "FMC alias CSR verification failure"),
+    (FMC_ALIAS_CSR_OVERFLOW, 0x000F0013, "FMC alias CSR overflow"),
 
     /// TRNG_EXT Errors
-    pub const DRIVER_TRNG_EXT_TIMEOUT: CaliptraError = CaliptraError::new_const(0x00100001);
+    (DRIVER_TRNG_EXT_TIMEOUT, 0x00100001, "Driver TRNG ext timeout"),
 
     /// SOC_IFC driver Errors
-    pub const DRIVER_SOC_IFC_INVALID_TIMER_CONFIG: CaliptraError =
-        CaliptraError::new_const(0x00100002);
+    (DRIVER_SOC_IFC_INVALID_TIMER_CONFIG, 0x00100002, "Driver SOC IFC invalid timer config"),
 
     /// Bounded address Errors
-    pub const ADDRESS_MISALIGNED: CaliptraError = CaliptraError::new_const(0x00110000);
-    pub const ADDRESS_NOT_IN_ROM: CaliptraError = CaliptraError::new_const(0x00110001);
+    (ADDRESS_MISALIGNED, 0x00110000, "Address misaligned"),
+    (ADDRESS_NOT_IN_ROM, 0x00110001, "Address not in ROM"),
+
+    /// MLDSA87 Errors
+    (DRIVER_MLDSA87_READ_SEED_KV_READ, 0x00120001, "Driver MLDSA87 read seed KV read"),
+    (DRIVER_MLDSA87_READ_SEED_KV_WRITE, 0x00120002, "Driver MLDSA87 read seed KV write"),
+    (DRIVER_MLDSA87_READ_SEED_KV_UNKNOWN, 0x00120003, "Driver MLDSA87 read seed KV unknown"),
+    (
+        DRIVER_MLDSA87_KEYGEN_PAIRWISE_CONSISTENCY_FAILURE,
+        0x00120004,
+        "Driver MLDSA87 keygen pairwise consistency failure"
+    ),
+    (DRIVER_MLDSA87_SIGN_VALIDATION_FAILED, 0x00120005, "Driver MLDSA87 sign validation failed"),
+
+    /// LZ4 Errors
+    (DRIVER_LZ4_INVALID_INPUT, 0x00130001, "Driver LZ4 invalid input"),
+    (DRIVER_LZ4_OUTPUT_OVERFLOW, 0x00130002, "Driver LZ4 output overflow"),
 
     /// Initial Device ID Errors
-    pub const ROM_IDEVID_CSR_BUILDER_INIT_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x01000001);
-    pub const ROM_IDEVID_CSR_BUILDER_BUILD_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x01000002);
-    pub const ROM_IDEVID_INVALID_CSR: CaliptraError = CaliptraError::new_const(0x01000003);
-    pub const ROM_IDEVID_CSR_VERIFICATION_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x01000004);
-    pub const ROM_IDEVID_CSR_OVERFLOW: CaliptraError = CaliptraError::new_const(0x01000005);
+    (ROM_IDEVID_CSR_BUILDER_INIT_FAILURE, 0x01000001, "ROM IDEVID CSR builder init failure"),
+    (ROM_IDEVID_CSR_BUILDER_BUILD_FAILURE, 0x01000002, "ROM IDEVID CSR builder build failure"),
+    (ROM_IDEVID_INVALID_CSR, 0x01000003, "ROM IDEVID invalid CSR"),
+    (ROM_IDEVID_CSR_VERIFICATION_FAILURE, 0x01000004, "ROM IDEVID CSR verification failure"),
+    (ROM_IDEVID_CSR_OVERFLOW, 0x01000005, "ROM IDEVID CSR overflow"),
 
     /// ROM Local Device ID Errors
-    pub const ROM_LDEVID_CSR_VERIFICATION_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x01010001);
+    (ROM_LDEVID_CSR_VERIFICATION_FAILURE, 0x01010001, "ROM LDEVID CSR verification failure"),
 
     /// Firmware Processor Errors
-    pub const FW_PROC_MANIFEST_READ_FAILURE: CaliptraError = CaliptraError::new_const(0x01020001);
-    pub const FW_PROC_INVALID_IMAGE_SIZE: CaliptraError = CaliptraError::new_const(0x01020002);
-    pub const FW_PROC_MAILBOX_STATE_INCONSISTENT: CaliptraError =
-        CaliptraError::new_const(0x01020003);
-    pub const FW_PROC_MAILBOX_INVALID_COMMAND: CaliptraError = CaliptraError::new_const(0x01020004);
-    pub const FW_PROC_MAILBOX_INVALID_CHECKSUM: CaliptraError =
-        CaliptraError::new_const(0x01020005);
-    pub const FW_PROC_MAILBOX_INVALID_REQUEST_LENGTH: CaliptraError =
-        CaliptraError::new_const(0x01020006);
-    pub const FW_PROC_MAILBOX_PROCESS_FAILURE: CaliptraError = CaliptraError::new_const(0x01020007);
-    pub const FW_PROC_MAILBOX_STASH_MEASUREMENT_MAX_LIMIT: CaliptraError =
-        CaliptraError::new_const(0x01020008);
-    pub const FW_PROC_MAILBOX_RESERVED_PAUSER: CaliptraError = CaliptraError::new_const(0x01020009);
-    pub const FW_PROC_MAILBOX_GET_IDEV_CSR_UNPROVISIONED_CSR: CaliptraError =
-        CaliptraError::new_const(0x0102000A);
+    (FW_PROC_MANIFEST_READ_FAILURE, 0x01020001, "FW proc manifest read failure"),
+    (FW_PROC_INVALID_IMAGE_SIZE, 0x01020002, "FW proc invalid image size"),
+    (FW_PROC_MAILBOX_STATE_INCONSISTENT, 0x01020003, "FW proc mailbox state inconsistent"),
+    (FW_PROC_MAILBOX_INVALID_COMMAND, 0x01020004, "FW proc mailbox invalid command"),
+    (FW_PROC_MAILBOX_INVALID_CHECKSUM, 0x01020005, "FW proc mailbox invalid checksum"),
+    (FW_PROC_MAILBOX_INVALID_REQUEST_LENGTH, 0x01020006, "FW proc mailbox invalid request length"),
+    (FW_PROC_MAILBOX_PROCESS_FAILURE, 0x01020007, "FW proc mailbox process failure"),
+    (
+        FW_PROC_MAILBOX_STASH_MEASUREMENT_MAX_LIMIT,
+        0x01020008,
+        "FW proc mailbox stash measurement max limit"
+    ),
+    (FW_PROC_MAILBOX_RESERVED_PAUSER, 0x01020009, "FW proc mailbox reserved pauser"),
+    (
+        FW_PROC_MAILBOX_GET_IDEV_CSR_UNPROVISIONED_CSR,
+        0x0102000A,
+        "FW proc mailbox get IDEV CSR unprovisioned CSR"
+    ),
 
     /// FMC Alias Layer : Certificate Verification Failure.
-    pub const FMC_ALIAS_CERT_VERIFY: CaliptraError = CaliptraError::new_const(0x01030001);
+    (FMC_ALIAS_CERT_VERIFY, 0x01030001, "FMC alias cert verify"),
 
     /// Update Reset Errors
-    pub const ROM_UPDATE_RESET_FLOW_MANIFEST_READ_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x01040002);
-    pub const ROM_UPDATE_RESET_FLOW_INVALID_FIRMWARE_COMMAND: CaliptraError =
-        CaliptraError::new_const(0x01040003);
-    pub const ROM_UPDATE_RESET_FLOW_MAILBOX_ACCESS_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x01040004);
-    pub const ROM_UPDATE_RESET_READ_FHT_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x01040005);
+    (
+        ROM_UPDATE_RESET_FLOW_MANIFEST_READ_FAILURE,
+        0x01040002,
+        "ROM update reset flow manifest read failure"
+    ),
+    (
+        ROM_UPDATE_RESET_FLOW_INVALID_FIRMWARE_COMMAND,
+        0x01040003,
+        "ROM update reset flow invalid firmware command"
+    ),
+    (
+        ROM_UPDATE_RESET_FLOW_MAILBOX_ACCESS_FAILURE,
+        0x01040004,
+        "ROM update reset flow mailbox access failure"
+    ),
+    (ROM_UPDATE_RESET_READ_FHT_FAILURE, 0x01040005, "ROM update reset read FHT failure"),
 
     // Warm Reset Errors
-    pub const ROM_WARM_RESET_UNSUCCESSFUL_PREVIOUS_COLD_RESET: CaliptraError =
-        CaliptraError::new_const(0x01040010);
-
-    pub const ROM_WARM_RESET_UNSUCCESSFUL_PREVIOUS_UPDATE_RESET: CaliptraError =
-        CaliptraError::new_const(0x01040011);
+    (
+        ROM_WARM_RESET_UNSUCCESSFUL_PREVIOUS_COLD_RESET,
+        0x01040010,
+        "ROM warm reset unsuccessful previous cold reset"
+    ),
+
+    (
+        ROM_WARM_RESET_UNSUCCESSFUL_PREVIOUS_UPDATE_RESET,
+        0x01040011,
+        "ROM warm reset unsuccessful previous update reset"
+    ),
 
     /// Unknown Reset Error
-    pub const ROM_UNKNOWN_RESET_FLOW: CaliptraError = CaliptraError::new_const(0x01040020);
+    (ROM_UNKNOWN_RESET_FLOW, 0x01040020, "ROM unknown reset flow"),
 
     /// ROM CFI Errors
-    pub const ROM_CFI_PANIC_UNKNOWN: CaliptraError = CaliptraError::new_const(0x1040050);
-    pub const ROM_CFI_PANIC_COUNTER_CORRUPT: CaliptraError = CaliptraError::new_const(0x1040051);
-    pub const ROM_CFI_PANIC_COUNTER_OVERFLOW: CaliptraError = CaliptraError::new_const(0x1040052);
-    pub const ROM_CFI_PANIC_COUNTER_UNDERFLOW: CaliptraError = CaliptraError::new_const(0x1040053);
-    pub const ROM_CFI_PANIC_COUNTER_MISMATCH: CaliptraError = CaliptraError::new_const(0x1040054);
-    pub const ROM_CFI_PANIC_ASSERT_EQ_FAILURE: CaliptraError = CaliptraError::new_const(0x1040055);
-    pub const ROM_CFI_PANIC_ASSERT_NE_FAILURE: CaliptraError = CaliptraError::new_const(0x1040056);
-    pub const ROM_CFI_PANIC_ASSERT_GT_FAILURE: CaliptraError = CaliptraError::new_const(0x1040057);
-    pub const ROM_CFI_PANIC_ASSERT_LT_FAILURE: CaliptraError = CaliptraError::new_const(0x1040058);
-    pub const ROM_CFI_PANIC_ASSERT_GE_FAILURE: CaliptraError = CaliptraError::new_const(0x1040059);
-    pub const ROM_CFI_PANIC_ASSERT_LE_FAILURE: CaliptraError = CaliptraError::new_const(0x104005A);
-    pub const ROM_CFI_PANIC_TRNG_FAILURE: CaliptraError = CaliptraError::new_const(0x104005B);
-    pub const ROM_CFI_PANIC_UNEXPECTED_MATCH_BRANCH: CaliptraError =
-        CaliptraError::new_const(0x104005C);
-    pub const ROM_CFI_PANIC_FAKE_TRNG_USED_WITH_DEBUG_LOCK: CaliptraError =
-        CaliptraError::new_const(0x104005D);
+    (ROM_CFI_PANIC_UNKNOWN, 0x1040050, "ROM CFI panic unknown"),
+    (ROM_CFI_PANIC_COUNTER_CORRUPT, 0x1040051, "ROM CFI panic counter corrupt"),
+    (ROM_CFI_PANIC_COUNTER_OVERFLOW, 0x1040052, "ROM CFI panic counter overflow"),
+    (ROM_CFI_PANIC_COUNTER_UNDERFLOW, 0x1040053, "ROM CFI panic counter underflow"),
+    (ROM_CFI_PANIC_COUNTER_MISMATCH, 0x1040054, "ROM CFI panic counter mismatch"),
+    (ROM_CFI_PANIC_ASSERT_EQ_FAILURE, 0x1040055, "ROM CFI panic assert EQ failure"),
+    (ROM_CFI_PANIC_ASSERT_NE_FAILURE, 0x1040056, "ROM CFI panic assert NE failure"),
+    (ROM_CFI_PANIC_ASSERT_GT_FAILURE, 0x1040057, "ROM CFI panic assert GT failure"),
+    (ROM_CFI_PANIC_ASSERT_LT_FAILURE, 0x1040058, "ROM CFI panic assert LT failure"),
+    (ROM_CFI_PANIC_ASSERT_GE_FAILURE, 0x1040059, "ROM CFI panic assert GE failure"),
+    (ROM_CFI_PANIC_ASSERT_LE_FAILURE, 0x104005A, "ROM CFI panic assert LE failure"),
+    (ROM_CFI_PANIC_TRNG_FAILURE, 0x104005B, "ROM CFI panic TRNG failure"),
+    (ROM_CFI_PANIC_UNEXPECTED_MATCH_BRANCH, 0x104005C, "ROM CFI panic unexpected match branch"),
+    (
+        ROM_CFI_PANIC_FAKE_TRNG_USED_WITH_DEBUG_LOCK,
+        0x104005D,
+        "ROM CFI panic fake TRNG used with debug lock"
+    ),
 
     /// ROM Global Errors
-    pub const ROM_GLOBAL_NMI: CaliptraError = CaliptraError::new_const(0x01050001);
-    pub const ROM_GLOBAL_EXCEPTION: CaliptraError = CaliptraError::new_const(0x01050002);
-    pub const ROM_GLOBAL_PANIC: CaliptraError = CaliptraError::new_const(0x01050003);
-    pub const ROM_GLOBAL_PCR_LOG_INVALID_ENTRY_ID: CaliptraError =
-        CaliptraError::new_const(0x01050004);
-    pub const ROM_GLOBAL_PCR_LOG_UNSUPPORTED_DATA_LENGTH: CaliptraError =
-        CaliptraError::new_const(0x01050005);
-    pub const ROM_GLOBAL_PCR_LOG_EXHAUSTED: CaliptraError = CaliptraError::new_const(0x01050006);
+    (ROM_GLOBAL_NMI, 0x01050001, "ROM global NMI"),
+    (ROM_GLOBAL_EXCEPTION, 0x01050002, "ROM global exception"),
+    (ROM_GLOBAL_PANIC, 0x01050003, "ROM global panic"),
+    (ROM_GLOBAL_PCR_LOG_INVALID_ENTRY_ID, 0x01050004, "ROM global PCR log invalid entry ID"),
+    (
+        ROM_GLOBAL_PCR_LOG_UNSUPPORTED_DATA_LENGTH,
+        0x01050005,
+        "ROM global PCR log unsupported data length"
+    ),
+    (ROM_GLOBAL_PCR_LOG_EXHAUSTED, 0x01050006, "ROM global PCR log exhausted"),
+
+    (ROM_GLOBAL_FUSE_LOG_INVALID_ENTRY_ID, 0x01050007, "ROM global fuse log invalid entry ID"),
+    (
+        ROM_GLOBAL_FUSE_LOG_UNSUPPORTED_DATA_LENGTH,
+        0x01050008,
+        "ROM global fuse log unsupported data length"
+    ),
+
+    (ROM_GLOBAL_UNSUPPORTED_LDEVID_TBS_SIZE, 0x01050009, "ROM global unsupported LDEVID TBS size"),
+    (
+        ROM_GLOBAL_UNSUPPORTED_FMCALIAS_TBS_SIZE,
+        0x0105000A,
+        "ROM global unsupported FMCALIAS TBS size"
+    ),
+
+    (ROM_GLOBAL_FAKE_ROM_IN_PRODUCTION, 0x0105000B, "ROM global fake ROM in production"),
+
+    (ROM_GLOBAL_WDT_EXPIRED, 0x0105000C, "ROM global WDT expired"),
+
+    (ROM_GLOBAL_MEASUREMENT_LOG_EXHAUSTED, 0x0105000D, "ROM global measurement log exhausted"),
+
+    (ROM_GLOBAL_FIPS_HOOKS_ROM_EXIT, 0x0105000F, "ROM global FIPS hooks ROM exit"),
+
+    (ROM_GLOBAL_IMAGE_DATA_LOAD_FAILURE, 0x01050010, "ROM global image data load failure"),
+
+    (
+        ROM_GLOBAL_RUNTIME_EXPANDED_SIZE_MISMATCH,
+        0x01050011,
+        "ROM global runtime expanded size mismatch"
+    ),
+
+    (
+        ROM_GLOBAL_RUNTIME_EXPANDED_DIGEST_MISMATCH,
+        0x01050012,
+        "ROM global runtime expanded digest mismatch"
+    ),
 
-    pub const ROM_GLOBAL_FUSE_LOG_INVALID_ENTRY_ID: CaliptraError =
-        CaliptraError::new_const(0x01050007);
-    pub const ROM_GLOBAL_FUSE_LOG_UNSUPPORTED_DATA_LENGTH: CaliptraError =
-        CaliptraError::new_const(0x01050008);
+    /// ROM KAT Errors
+    (KAT_SHA256_DIGEST_FAILURE, 0x90010001, "KAT SHA256 digest failure"),
+    (KAT_SHA256_DIGEST_MISMATCH, 0x90010002, "KAT SHA256 digest mismatch"),
 
-    pub const ROM_GLOBAL_UNSUPPORTED_LDEVID_TBS_SIZE: CaliptraError =
-        CaliptraError::new_const(0x01050009);
-    pub const ROM_GLOBAL_UNSUPPORTED_FMCALIAS_TBS_SIZE: CaliptraError =
-        CaliptraError::new_const(0x0105000A);
+    (KAT_SHA384_DIGEST_FAILURE, 0x90020001, "KAT SHA384 digest failure"),
+    (KAT_SHA384_DIGEST_MISMATCH, 0x90020002, "KAT SHA384 digest mismatch"),
 
-    pub const ROM_GLOBAL_FAKE_ROM_IN_PRODUCTION: CaliptraError =
-        CaliptraError::new_const(0x0105000B);
+    (KAT_HMAC384_FAILURE, 0x90030001, "KAT HMAC384 failure"),
+    (KAT_HMAC384_TAG_MISMATCH, 0x90030002, "KAT HMAC384 tag mismatch"),
 
-    pub const ROM_GLOBAL_WDT_EXPIRED: CaliptraError = CaliptraError::new_const(0x0105000C);
+    // 0x90040001 was KAT_ECC384_SIGNATURE_GENERATE_FAILURE
+    // 0x90040002 was KAT_ECC384_SIGNATURE_VERIFY_FAILURE
+    (KAT_ECC384_SIGNATURE_MISMATCH, 0x90040003, "KAT ECC384 signature mismatch"),
+    (KAT_ECC384_KEY_PAIR_GENERATE_FAILURE, 0x90040004, "KAT ECC384 key pair generate failure"),
+    (KAT_ECC384_KEY_PAIR_VERIFY_FAILURE, 0x90040005, "KAT ECC384 key pair verify failure"),
 
-    pub const ROM_GLOBAL_MEASUREMENT_LOG_EXHAUSTED: CaliptraError =
-        CaliptraError::new_const(0x0105000D);
+    (
+        KAT_SHA2_512_384_ACC_DIGEST_START_OP_FAILURE,
+        0x90050001,
+        "KAT SHA2 512 384 acc digest start op failure"
+    ),
+    (KAT_SHA2_512_384_ACC_DIGEST_FAILURE, 0x90050002, "KAT SHA2 512 384 acc digest failure"),
+    (KAT_SHA2_512_384_ACC_DIGEST_MISMATCH, 0x90050003, "KAT SHA2 512 384 acc digest mismatch"),
 
-    pub const ROM_GLOBAL_FIPS_HOOKS_ROM_EXIT: CaliptraError = CaliptraError::new_const(0x0105000F);
+    (KAT_SHA1_DIGEST_FAILURE, 0x90060001, "KAT SHA1 digest failure"),
+    (KAT_SHA1_DIGEST_MISMATCH, 0x90060002, "KAT SHA1 digest mismatch"),
 
-    /// ROM KAT Errors
-    pub const KAT_SHA256_DIGEST_FAILURE: CaliptraError = CaliptraError::new_const(0x90010001);
-    pub const KAT_SHA256_DIGEST_MISMATCH: CaliptraError = CaliptraError::new_const(0x90010002);
+    (KAT_LMS_DIGEST_FAILURE, 0x90070001, "KAT LMS digest failure"),
+    (KAT_LMS_DIGEST_MISMATCH, 0x90070002, "KAT LMS digest mismatch"),
 
-    pub const KAT_SHA384_DIGEST_FAILURE: CaliptraError = CaliptraError::new_const(0x90020001);
-    pub const KAT_SHA384_DIGEST_MISMATCH: CaliptraError = CaliptraError::new_const(0x90020002);
+    (ROM_INTEGRITY_FAILURE, 0x90080001, "ROM integrity failure"),
 
-    pub const KAT_HMAC384_FAILURE: CaliptraError = CaliptraError::new_const(0x90030001);
-    pub const KAT_HMAC384_TAG_MISMATCH: CaliptraError = CaliptraError::new_const(0x90030002);
+    (KAT_MLDSA87_KEY_PAIR_GENERATE_FAILURE, 0x90090001, "KAT MLDSA87 key pair generate failure"),
+    (KAT_MLDSA87_KEY_PAIR_VERIFY_FAILURE, 0x90090002, "KAT MLDSA87 key pair verify failure"),
+    (KAT_MLDSA87_SIGNATURE_MISMATCH, 0x90090003, "KAT MLDSA87 signature mismatch"),
 
-    // 0x90040001 was KAT_ECC384_SIGNATURE_GENERATE_FAILURE
-    // 0x90040002 was KAT_ECC384_SIGNATURE_VERIFY_FAILURE
-    pub const KAT_ECC384_SIGNATURE_MISMATCH: CaliptraError = CaliptraError::new_const(0x90040003);
-    pub const KAT_ECC384_KEY_PAIR_GENERATE_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x90040004);
-    pub const KAT_ECC384_KEY_PAIR_VERIFY_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x90040005);
+    // TODO: What base value is right for this?
+    // FIPS Hooks
+    (FIPS_HOOKS_INJECTED_ERROR, 0x90100000, "FIPS hooks injected error"),
+}
 
-    pub const KAT_SHA2_512_384_ACC_DIGEST_START_OP_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x90050001);
-    pub const KAT_SHA2_512_384_ACC_DIGEST_FAILURE: CaliptraError =
-        CaliptraError::new_const(0x90050002);
-    pub const KAT_SHA2_512_384_ACC_DIGEST_MISMATCH: CaliptraError =
-        CaliptraError::new_const(0x90050003);
+/// The name and description of a defined [`CaliptraError`] code
+#[cfg(feature = "names")]
+#[derive(Debug)]
+pub struct ErrorInfo {
+    pub error: CaliptraError,
+    pub name: &'static str,
+    pub description: &'static str,
+}
 
-    pub const KAT_SHA1_DIGEST_FAILURE: CaliptraError = CaliptraError::new_const(0x90060001);
-    pub const KAT_SHA1_DIGEST_MISMATCH: CaliptraError = CaliptraError::new_const(0x90060002);
+#[cfg(feature = "names")]
+impl CaliptraError {
+    /// Returns the name and description of this error code, or `None` if it
+    /// isn't defined in this version of the firmware.
+    pub fn info(self) -> Option<&'static ErrorInfo> {
+        ERROR_TABLE.iter().find(|info| info.error == self)
+    }
 
-    pub const KAT_LMS_DIGEST_FAILURE: CaliptraError = CaliptraError::new_const(0x90070001);
-    pub const KAT_LMS_DIGEST_MISMATCH: CaliptraError = CaliptraError::new_const(0x90070002);
+    /// Returns the name of the constant for this error code, such as
+    /// `"DRIVER_SHA256_INVALID_STATE"`.
+    pub fn name(self) -> Option<&'static str> {
+        self.info().map(|info| info.name)
+    }
 
-    pub const ROM_INTEGRITY_FAILURE: CaliptraError = CaliptraError::new_const(0x90080001);
+    /// Returns a short description of this error code, such as
+    /// `"Driver SHA256 invalid state"`.
+    pub fn description(self) -> Option<&'static str> {
+        self.info().map(|info| info.description)
+    }
+}
 
-    // TODO: What base value is right for this?
-    // FIPS Hooks
-    pub const FIPS_HOOKS_INJECTED_ERROR: CaliptraError = CaliptraError::new_const(0x90100000);
+/// Formats the error as its name followed by the code, such as
+/// `DRIVER_SHA256_INVALID_STATE (0x00020001)`, or just the code if it isn't
+/// defined.
+#[cfg(feature = "names")]
+impl core::fmt::Display for CaliptraError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{name} (0x{:08x})", self.0.get()),
+            None => write!(f, "0x{:08x}", self.0.get()),
+        }
+    }
 }
 
 impl From<core::num::NonZeroU32> for crate::CaliptraError {
@@ -686,4 +1175,29 @@ mod tests {
             CaliptraError::try_from(0x00020001)
         );
     }
+
+    #[test]
+    #[cfg(feature = "names")]
+    fn test_names() {
+        let err = CaliptraError::try_from(0x00020001).unwrap();
+        assert_eq!(err.name(), Some("DRIVER_SHA256_INVALID_STATE"));
+        assert_eq!(err.description(), Some("Driver SHA256 invalid state"));
+        assert_eq!(err.to_string(), "DRIVER_SHA256_INVALID_STATE (0x00020001)");
+
+        let err = CaliptraError::try_from(0xfffffff0).unwrap();
+        assert_eq!(err.name(), None);
+        assert_eq!(err.description(), None);
+        assert_eq!(err.to_string(), "0xfffffff0");
+
+        // Every code must be unique for the lookup to be unambiguous.
+        for (i, info) in ERROR_TABLE.iter().enumerate() {
+            assert!(
+                ERROR_TABLE[..i]
+                    .iter()
+                    .all(|other| other.error != info.error),
+                "{} reuses the code of another error",
+                info.name
+            );
+        }
+    }
 }
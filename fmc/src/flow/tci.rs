@@ -16,7 +16,6 @@ Environment:
 
 
 --*/
-use crate::flow::crypto::Crypto;
 use crate::fmc_env::FmcEnv;
 use caliptra_drivers::{Array4x12, CaliptraResult};
 use zerocopy::IntoBytes;
@@ -30,7 +29,7 @@ impl Tci {
     ///
     /// * `env` - ROM Environment
     pub fn image_manifest_digest(env: &mut FmcEnv) -> CaliptraResult<Array4x12> {
-        let manifest = env.persistent_data.get().manifest1;
-        Crypto::sha384_digest(env, manifest.as_bytes())
+        let manifest = &env.persistent_data.get().manifest1;
+        env.sha384.digest(manifest.as_bytes())
     }
 }
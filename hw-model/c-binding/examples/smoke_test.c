@@ -5,9 +5,33 @@
 #include <string.h>
 #include <errno.h>
 #include <unistd.h>
-#include "api/caliptra_api.h"
+#include "caliptra_model.h"
 
 static const uint32_t RT_READY_FOR_COMMANDS = 0x600;
+static const uint32_t FW_INFO_CMD_OPCODE = 0x494E464Fu; // "INFO"
+
+// Prefix of the FW_INFO response
+struct fw_info_resp {
+    uint32_t chksum;
+    uint32_t fips_status;
+    uint32_t pl0_pauser;
+    uint32_t runtime_svn;
+    uint32_t min_runtime_svn;
+    uint32_t fmc_manifest_svn;
+};
+
+// Mailbox checksum over the command opcode and the data
+static uint32_t mbox_checksum(uint32_t cmd, const uint8_t *data, size_t len)
+{
+    uint32_t sum = 0;
+    for (size_t i = 0; i < sizeof(cmd); i++) {
+        sum += (cmd >> (8 * i)) & 0xff;
+    }
+    for (size_t i = 0; i < len; i++) {
+        sum += data[i];
+    }
+    return 0 - sum;
+}
 
 static struct caliptra_buffer read_file_or_die(const char* path)
 {
@@ -92,12 +116,18 @@ int main(int argc, char *argv[])
     caliptra_model_init_default(init_params, &model);
 
     // Initialize Fuses (Todo: Set real fuse values)
-    struct caliptra_fuses fuses = {0};
-    caliptra_init_fuses(model, &fuses);
+    struct caliptra_model_fuses fuses = {0};
+    if (caliptra_model_init_fuses(model, &fuses) != CALIPTRA_MODEL_STATUS_OK) {
+        printf("Failed to initialize fuses\n");
+        return -EIO;
+    }
 
     // Initialize FSM GO
-    caliptra_bootfsm_go(model);
-    caliptra_model_step(model);
+    caliptra_model_bootfsm_go(model);
+    if (caliptra_model_step_until_output(model, "\nRunning Caliptra ROM ...") != CALIPTRA_MODEL_STATUS_OK) {
+        printf("ROM banner not found in output\n");
+        return -EIO;
+    }
 
     // Step until read for FW
     while (!caliptra_model_ready_for_fw(model)) {
@@ -106,16 +136,40 @@ int main(int argc, char *argv[])
 
     // Load Image Bundle
     struct caliptra_buffer image_bundle = read_file_or_die(fw_path);
-    caliptra_upload_fw(model, &image_bundle);
+    if (caliptra_model_upload_fw(model, image_bundle) != CALIPTRA_MODEL_STATUS_OK) {
+        printf("Firmware upload failed\n");
+        return -EIO;
+    }
 
     // Run Until RT is ready to receive commands
     caliptra_model_step_until_boot_status(model, RT_READY_FOR_COMMANDS);
 
+    // Issue a runtime command
+    uint32_t fw_info_req = mbox_checksum(FW_INFO_CMD_OPCODE, NULL, 0);
+    uint8_t resp_data[256];
+    struct caliptra_buffer req = {.data = (const uint8_t *)&fw_info_req, .len = sizeof(fw_info_req)};
+    struct caliptra_buffer resp = {.data = resp_data, .len = sizeof(resp_data)};
+    int status = caliptra_model_mailbox_execute(model, FW_INFO_CMD_OPCODE, req, &resp);
+    if (status != CALIPTRA_MODEL_STATUS_OK) {
+        printf("FW_INFO failed with status %d\n", status);
+        return -EIO;
+    }
+
+    struct fw_info_resp fw_info;
+    if (resp.len < sizeof(fw_info)) {
+        printf("FW_INFO response too short (%ld bytes)\n", resp.len);
+        return -EIO;
+    }
+    memcpy(&fw_info, resp_data, sizeof(fw_info));
+    if (fw_info.chksum != mbox_checksum(0, resp_data + sizeof(fw_info.chksum), resp.len - sizeof(fw_info.chksum))) {
+        printf("FW_INFO response has an invalid checksum\n");
+        return -EIO;
+    }
+    printf("FW_INFO: runtime_svn=%u fmc_manifest_svn=%u\n", fw_info.runtime_svn, fw_info.fmc_manifest_svn);
+
     // Free the model
     caliptra_model_destroy(model);
 
     printf("Caliptra C Smoke Test Passed \n");
     return 0;
 }
-
-
@@ -3,7 +3,10 @@
 use caliptra_api::soc_mgr::SocManager;
 use caliptra_emu_bus::Bus;
 use caliptra_emu_periph::MailboxRequester;
-use caliptra_hw_model::{DefaultHwModel, HwModel, InitParams, SecurityState};
+use caliptra_hw_model::{
+    DefaultHwModel, DeviceLifecycle, Fuses, HwModel, InitParams, ModelError, SecurityState, U4,
+};
+use caliptra_hw_model_types::ErrorInjectionMode;
 use std::ffi::*;
 use std::slice;
 
@@ -37,7 +40,51 @@ pub const CALIPTRA_SEC_STATE_DBG_LOCKED_MANUFACTURING: c_int = 0b101;
 pub const CALIPTRA_SEC_STATE_DBG_UNLOCKED_PRODUCTION: c_int = 0b011;
 pub const CALIPTRA_SEC_STATE_DBG_LOCKED_PRODUCTION: c_int = 0b111;
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct caliptra_model_fuses {
+    pub uds_seed: [u32; 12],
+    pub field_entropy: [u32; 8],
+    pub key_manifest_pk_hash: [u32; 12],
+    pub key_manifest_pk_hash_mask: u32,
+    pub owner_pk_hash: [u32; 12],
+    pub fmc_key_manifest_svn: u32,
+    pub runtime_svn: [u32; 4],
+    pub anti_rollback_disable: bool,
+    pub idevid_cert_attr: [u32; 24],
+    pub idevid_manuf_hsm_id: [u32; 4],
+    pub life_cycle: u32,
+    pub lms_verify: bool,
+    pub fuse_lms_revocation: u32,
+    pub soc_stepping_id: u16,
+    pub mldsa_verify: bool,
+    pub fuse_mldsa_revocation: u32,
+}
+
 pub const CALIPTRA_MODEL_STATUS_OK: c_int = 0;
+pub const CALIPTRA_MODEL_STATUS_INVALID_PARAM: c_int = 1;
+pub const CALIPTRA_MODEL_STATUS_MBOX_LOCKED: c_int = 2;
+pub const CALIPTRA_MODEL_STATUS_MBOX_CMD_FAILED: c_int = 3;
+pub const CALIPTRA_MODEL_STATUS_MBOX_TIMEOUT: c_int = 4;
+pub const CALIPTRA_MODEL_STATUS_BUFFER_TOO_SMALL: c_int = 5;
+pub const CALIPTRA_MODEL_STATUS_UNEXPECTED_OUTPUT: c_int = 6;
+pub const CALIPTRA_MODEL_STATUS_UNSUPPORTED: c_int = 7;
+pub const CALIPTRA_MODEL_STATUS_ERROR: c_int = 0xff;
+
+pub const CALIPTRA_ERROR_INJECTION_NONE: c_int = 0;
+pub const CALIPTRA_ERROR_INJECTION_ICCM_DOUBLE_BIT_ECC: c_int = 1;
+pub const CALIPTRA_ERROR_INJECTION_DCCM_DOUBLE_BIT_ECC: c_int = 2;
+
+fn model_error_status(err: &ModelError) -> c_int {
+    match err {
+        ModelError::UnableToLockMailbox => CALIPTRA_MODEL_STATUS_MBOX_LOCKED,
+        ModelError::MailboxCmdFailed(_) => CALIPTRA_MODEL_STATUS_MBOX_CMD_FAILED,
+        ModelError::MailboxTimeout => CALIPTRA_MODEL_STATUS_MBOX_TIMEOUT,
+        ModelError::BufferTooLargeForMailbox => CALIPTRA_MODEL_STATUS_INVALID_PARAM,
+        ModelError::ReadBufferTooSmall => CALIPTRA_MODEL_STATUS_BUFFER_TOO_SMALL,
+        _ => CALIPTRA_MODEL_STATUS_ERROR,
+    }
+}
 
 /// # Safety
 #[no_mangle]
@@ -177,3 +224,187 @@ pub unsafe extern "C" fn caliptra_model_set_apb_pauser(model: *mut caliptra_mode
     assert!(!model.is_null());
     (*{ model as *mut DefaultHwModel }).set_apb_pauser(pauser);
 }
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn caliptra_model_init_fuses(
+    model: *mut caliptra_model,
+    fuses: *const caliptra_model_fuses,
+) -> c_int {
+    // Parameter check
+    assert!(!model.is_null() && !fuses.is_null());
+    let fuses = &*fuses;
+    let (Ok(key_manifest_pk_hash_mask), Ok(life_cycle)) = (
+        U4::try_from(fuses.key_manifest_pk_hash_mask),
+        DeviceLifecycle::try_from(fuses.life_cycle),
+    ) else {
+        return CALIPTRA_MODEL_STATUS_INVALID_PARAM;
+    };
+    let fuses = Fuses {
+        uds_seed: fuses.uds_seed,
+        field_entropy: fuses.field_entropy,
+        key_manifest_pk_hash: fuses.key_manifest_pk_hash,
+        key_manifest_pk_hash_mask,
+        owner_pk_hash: fuses.owner_pk_hash,
+        fmc_key_manifest_svn: fuses.fmc_key_manifest_svn,
+        runtime_svn: fuses.runtime_svn,
+        anti_rollback_disable: fuses.anti_rollback_disable,
+        idevid_cert_attr: fuses.idevid_cert_attr,
+        idevid_manuf_hsm_id: fuses.idevid_manuf_hsm_id,
+        life_cycle,
+        lms_verify: fuses.lms_verify,
+        fuse_lms_revocation: fuses.fuse_lms_revocation,
+        soc_stepping_id: fuses.soc_stepping_id,
+        mldsa_verify: fuses.mldsa_verify,
+        fuse_mldsa_revocation: fuses.fuse_mldsa_revocation,
+    };
+    match SocManager::init_fuses(&mut *{ model as *mut DefaultHwModel }, &fuses) {
+        Ok(()) => CALIPTRA_MODEL_STATUS_OK,
+        Err(e) => model_error_status(&e.into()),
+    }
+}
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn caliptra_model_bootfsm_go(model: *mut caliptra_model) -> c_int {
+    // Parameter check
+    assert!(!model.is_null());
+    (*{ model as *mut DefaultHwModel })
+        .soc_ifc()
+        .cptra_bootfsm_go()
+        .write(|w| w.go(true));
+
+    CALIPTRA_MODEL_STATUS_OK
+}
+
+/// Executes mailbox command `cmd` with the request in `req`. On input,
+/// `resp->data` must point to a writable buffer of `resp->len` bytes (or
+/// `resp` may be NULL if no response data is expected). On success,
+/// `resp->len` is set to the length of the response, which is 0 if the
+/// firmware completed the command without data.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn caliptra_model_mailbox_execute(
+    model: *mut caliptra_model,
+    cmd: c_uint,
+    req: caliptra_buffer,
+    resp: *mut caliptra_buffer,
+) -> c_int {
+    // Parameter check
+    assert!(!model.is_null());
+    let req = if req.len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(req.data, req.len)
+    };
+    let data = match (*{ model as *mut DefaultHwModel }).mailbox_execute(cmd, req) {
+        Ok(data) => data.unwrap_or_default(),
+        Err(e) => return model_error_status(&e),
+    };
+    if resp.is_null() {
+        return if data.is_empty() {
+            CALIPTRA_MODEL_STATUS_OK
+        } else {
+            CALIPTRA_MODEL_STATUS_BUFFER_TOO_SMALL
+        };
+    }
+    let resp = &mut *resp;
+    if data.len() > resp.len {
+        return CALIPTRA_MODEL_STATUS_BUFFER_TOO_SMALL;
+    }
+    if !data.is_empty() {
+        slice::from_raw_parts_mut(resp.data as *mut u8, data.len()).copy_from_slice(&data);
+    }
+    resp.len = data.len();
+
+    CALIPTRA_MODEL_STATUS_OK
+}
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn caliptra_model_upload_fw(
+    model: *mut caliptra_model,
+    fw: caliptra_buffer,
+) -> c_int {
+    // Parameter check
+    assert!(!model.is_null() && !fw.data.is_null());
+    match (*{ model as *mut DefaultHwModel })
+        .upload_firmware(slice::from_raw_parts(fw.data, fw.len))
+    {
+        Ok(()) => CALIPTRA_MODEL_STATUS_OK,
+        Err(e) => model_error_status(&e),
+    }
+}
+
+/// Warm resets Caliptra. As after `caliptra_model_init_default`, the fuses
+/// must then be written and BootFSM go set before the ROM runs again.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn caliptra_model_warm_reset(model: *mut caliptra_model) -> c_int {
+    // Parameter check
+    assert!(!model.is_null());
+    (*{ model as *mut DefaultHwModel }).warm_reset();
+
+    CALIPTRA_MODEL_STATUS_OK
+}
+
+/// Toggles the reset and pwrgood pins and steps until Caliptra is ready for
+/// fuses. Only the verilated model supports this; the emulator returns
+/// `CALIPTRA_MODEL_STATUS_UNSUPPORTED`, and callers should destroy the model
+/// and create a new one instead.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn caliptra_model_cold_reset(model: *mut caliptra_model) -> c_int {
+    // Parameter check
+    assert!(!model.is_null());
+    if !cfg!(feature = "verilator") {
+        return CALIPTRA_MODEL_STATUS_UNSUPPORTED;
+    }
+    (*{ model as *mut DefaultHwModel }).cold_reset();
+
+    CALIPTRA_MODEL_STATUS_OK
+}
+
+/// Steps until the output starts with the NUL-terminated `expected_output`.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn caliptra_model_step_until_output(
+    model: *mut caliptra_model,
+    expected_output: *const c_char,
+) -> c_int {
+    // Parameter check
+    assert!(!model.is_null() && !expected_output.is_null());
+    let Ok(expected_output) = CStr::from_ptr(expected_output).to_str() else {
+        return CALIPTRA_MODEL_STATUS_INVALID_PARAM;
+    };
+    match (*{ model as *mut DefaultHwModel }).step_until_output(expected_output) {
+        Ok(()) => CALIPTRA_MODEL_STATUS_OK,
+        Err(_) => CALIPTRA_MODEL_STATUS_UNEXPECTED_OUTPUT,
+    }
+}
+
+/// Enables one of the `CALIPTRA_ERROR_INJECTION_*` modes, or disables error
+/// injection with `CALIPTRA_ERROR_INJECTION_NONE`.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn caliptra_model_ecc_error_injection(
+    model: *mut caliptra_model,
+    mode: c_int,
+) -> c_int {
+    // Parameter check
+    assert!(!model.is_null());
+    let mode = match mode {
+        CALIPTRA_ERROR_INJECTION_NONE => ErrorInjectionMode::None,
+        CALIPTRA_ERROR_INJECTION_ICCM_DOUBLE_BIT_ECC => ErrorInjectionMode::IccmDoubleBitEcc,
+        CALIPTRA_ERROR_INJECTION_DCCM_DOUBLE_BIT_ECC => ErrorInjectionMode::DccmDoubleBitEcc,
+        _ => return CALIPTRA_MODEL_STATUS_INVALID_PARAM,
+    };
+    (*{ model as *mut DefaultHwModel }).ecc_error_injection(mode);
+
+    CALIPTRA_MODEL_STATUS_OK
+}
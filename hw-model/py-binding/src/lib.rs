@@ -0,0 +1,508 @@
+// Licensed under the Apache-2.0 license
+
+//! Python bindings for the emulated Caliptra hardware model.
+//!
+//! The `caliptra_hw_model` Python module exposes [`ModelEmulated`] as the
+//! `Model` class. Init and boot parameters are passed as dicts whose keys are
+//! the field names of [`InitParams`], [`BootParams`] and [`Fuses`]; see
+//! `README.md` for the supported keys.
+
+use caliptra_api::calc_checksum;
+use caliptra_api::mailbox::{
+    CommandId, FwInfoResp, GetFmcAliasCertReq, GetLdevCertReq, GetRtAliasCertReq, MailboxReqHeader,
+    ResponseVarSize,
+};
+use caliptra_api::SocManager;
+use caliptra_emu_bus::Bus;
+use caliptra_emu_types::RvSize;
+use caliptra_hw_model::{
+    BootParams, DeviceLifecycle, Fuses, HwModel, InitParams, ModelEmulated, SecurityState, U4,
+};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use zerocopy::{FromBytes, IntoBytes};
+
+create_exception!(caliptra_hw_model, ModelError, PyException);
+create_exception!(caliptra_hw_model, MailboxCmdFailed, ModelError);
+
+fn model_err(err: impl std::fmt::Display) -> PyErr {
+    ModelError::new_err(err.to_string())
+}
+
+fn hw_model_err(err: caliptra_hw_model::ModelError) -> PyErr {
+    match err {
+        // The firmware error code is available as `e.args[0]`
+        caliptra_hw_model::ModelError::MailboxCmdFailed(code) => MailboxCmdFailed::new_err(code),
+        err => model_err(err),
+    }
+}
+
+fn check_keys(dict: &PyDict, allowed: &[&str]) -> PyResult<()> {
+    for key in dict.keys() {
+        let key: &str = key.extract()?;
+        if !allowed.contains(&key) {
+            return Err(PyValueError::new_err(format!(
+                "unknown key {key:?}; expected one of {allowed:?}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn get<'a, T: FromPyObject<'a>>(dict: &'a PyDict, key: &str) -> PyResult<Option<T>> {
+    dict.get_item(key)?.map(|v| v.extract()).transpose()
+}
+
+fn parse_lifecycle(val: &PyAny) -> PyResult<DeviceLifecycle> {
+    if let Ok(val) = val.extract::<u32>() {
+        return DeviceLifecycle::try_from(val)
+            .map_err(|_| PyValueError::new_err(format!("invalid device lifecycle {val}")));
+    }
+    match val.extract::<&str>()? {
+        "unprovisioned" => Ok(DeviceLifecycle::Unprovisioned),
+        "manufacturing" => Ok(DeviceLifecycle::Manufacturing),
+        "production" => Ok(DeviceLifecycle::Production),
+        other => Err(PyValueError::new_err(format!(
+            "invalid device lifecycle {other:?}"
+        ))),
+    }
+}
+
+/// Owned copy of the init parameters, kept so the model can be recreated on
+/// a cold reset.
+#[derive(Clone)]
+struct InitConfig {
+    rom: Vec<u8>,
+    dccm: Vec<u8>,
+    iccm: Vec<u8>,
+    security_state: SecurityState,
+    cptra_obf_key: Option<[u32; 8]>,
+}
+
+impl InitConfig {
+    fn from_dict(dict: &PyDict) -> PyResult<Self> {
+        check_keys(
+            dict,
+            &[
+                "rom",
+                "dccm",
+                "iccm",
+                "debug_locked",
+                "device_lifecycle",
+                "cptra_obf_key",
+            ],
+        )?;
+        let rom = get::<&[u8]>(dict, "rom")?
+            .ok_or_else(|| PyValueError::new_err("missing key \"rom\""))?
+            .to_vec();
+        let mut security_state = SecurityState::default();
+        if let Some(debug_locked) = get(dict, "debug_locked")? {
+            security_state.set_debug_locked(debug_locked);
+        }
+        if let Some(lifecycle) = dict.get_item("device_lifecycle")? {
+            security_state.set_device_lifecycle(parse_lifecycle(lifecycle)?);
+        }
+        Ok(Self {
+            rom,
+            dccm: get::<&[u8]>(dict, "dccm")?.unwrap_or_default().to_vec(),
+            iccm: get::<&[u8]>(dict, "iccm")?.unwrap_or_default().to_vec(),
+            security_state,
+            cptra_obf_key: get(dict, "cptra_obf_key")?,
+        })
+    }
+
+    fn params(&self) -> InitParams<'_> {
+        let mut params = InitParams {
+            rom: &self.rom,
+            dccm: &self.dccm,
+            iccm: &self.iccm,
+            security_state: self.security_state,
+            ..Default::default()
+        };
+        if let Some(cptra_obf_key) = self.cptra_obf_key {
+            params.cptra_obf_key = cptra_obf_key;
+        }
+        params
+    }
+}
+
+fn fuses_from_dict(dict: &PyDict) -> PyResult<Fuses> {
+    check_keys(
+        dict,
+        &[
+            "uds_seed",
+            "field_entropy",
+            "key_manifest_pk_hash",
+            "key_manifest_pk_hash_mask",
+            "owner_pk_hash",
+            "fmc_key_manifest_svn",
+            "runtime_svn",
+            "anti_rollback_disable",
+            "idevid_cert_attr",
+            "idevid_manuf_hsm_id",
+            "life_cycle",
+            "lms_verify",
+            "fuse_lms_revocation",
+            "soc_stepping_id",
+            "mldsa_verify",
+            "fuse_mldsa_revocation",
+        ],
+    )?;
+    let mut fuses = Fuses::default();
+    if let Some(val) = get(dict, "uds_seed")? {
+        fuses.uds_seed = val;
+    }
+    if let Some(val) = get(dict, "field_entropy")? {
+        fuses.field_entropy = val;
+    }
+    if let Some(val) = get(dict, "key_manifest_pk_hash")? {
+        fuses.key_manifest_pk_hash = val;
+    }
+    if let Some(val) = get::<u32>(dict, "key_manifest_pk_hash_mask")? {
+        fuses.key_manifest_pk_hash_mask = U4::try_from(val).map_err(|_| {
+            PyValueError::new_err(format!("key_manifest_pk_hash_mask {val} is not 4 bits"))
+        })?;
+    }
+    if let Some(val) = get(dict, "owner_pk_hash")? {
+        fuses.owner_pk_hash = val;
+    }
+    if let Some(val) = get(dict, "fmc_key_manifest_svn")? {
+        fuses.fmc_key_manifest_svn = val;
+    }
+    if let Some(val) = get(dict, "runtime_svn")? {
+        fuses.runtime_svn = val;
+    }
+    if let Some(val) = get(dict, "anti_rollback_disable")? {
+        fuses.anti_rollback_disable = val;
+    }
+    if let Some(val) = get(dict, "idevid_cert_attr")? {
+        fuses.idevid_cert_attr = val;
+    }
+    if let Some(val) = get(dict, "idevid_manuf_hsm_id")? {
+        fuses.idevid_manuf_hsm_id = val;
+    }
+    if let Some(val) = dict.get_item("life_cycle")? {
+        fuses.life_cycle = parse_lifecycle(val)?;
+    }
+    if let Some(val) = get(dict, "lms_verify")? {
+        fuses.lms_verify = val;
+    }
+    if let Some(val) = get(dict, "fuse_lms_revocation")? {
+        fuses.fuse_lms_revocation = val;
+    }
+    if let Some(val) = get(dict, "soc_stepping_id")? {
+        fuses.soc_stepping_id = val;
+    }
+    if let Some(val) = get(dict, "mldsa_verify")? {
+        fuses.mldsa_verify = val;
+    }
+    if let Some(val) = get(dict, "fuse_mldsa_revocation")? {
+        fuses.fuse_mldsa_revocation = val;
+    }
+    Ok(fuses)
+}
+
+struct BootConfig {
+    fuses: Fuses,
+    fw_image: Option<Vec<u8>>,
+    initial_dbg_manuf_service_reg: u32,
+    wdt_timeout_cycles: Option<u64>,
+}
+
+impl BootConfig {
+    fn from_dict(dict: Option<&PyDict>) -> PyResult<Self> {
+        let Some(dict) = dict else {
+            return Ok(Self {
+                fuses: Fuses::default(),
+                fw_image: None,
+                initial_dbg_manuf_service_reg: 0,
+                wdt_timeout_cycles: None,
+            });
+        };
+        check_keys(
+            dict,
+            &[
+                "fuses",
+                "fw_image",
+                "initial_dbg_manuf_service_reg",
+                "wdt_timeout_cycles",
+            ],
+        )?;
+        Ok(Self {
+            fuses: match get::<&PyDict>(dict, "fuses")? {
+                Some(fuses) => fuses_from_dict(fuses)?,
+                None => Fuses::default(),
+            },
+            fw_image: get::<&[u8]>(dict, "fw_image")?.map(<[u8]>::to_vec),
+            initial_dbg_manuf_service_reg: get(dict, "initial_dbg_manuf_service_reg")?
+                .unwrap_or_default(),
+            wdt_timeout_cycles: get(dict, "wdt_timeout_cycles")?,
+        })
+    }
+
+    fn params(&self) -> BootParams<'_> {
+        let mut params = BootParams {
+            fuses: self.fuses.clone(),
+            fw_image: self.fw_image.as_deref(),
+            initial_dbg_manuf_service_reg: self.initial_dbg_manuf_service_reg,
+            ..Default::default()
+        };
+        if let Some(wdt_timeout_cycles) = self.wdt_timeout_cycles {
+            params.wdt_timeout_cycles = wdt_timeout_cycles;
+        }
+        params
+    }
+}
+
+/// An emulated Caliptra.
+///
+/// `Model(init_params, boot_params=None)` creates the model and boots it;
+/// use `Model.new_unbooted(init_params)` to control the boot sequence.
+#[pyclass(unsendable, name = "Model")]
+struct Model {
+    model: ModelEmulated,
+    init: InitConfig,
+    // The fuses written at the last boot, rewritten on reset
+    fuses: Fuses,
+}
+
+impl Model {
+    fn create(init: &InitConfig) -> PyResult<ModelEmulated> {
+        ModelEmulated::new_unbooted(init.params()).map_err(model_err)
+    }
+}
+
+#[pymethods]
+impl Model {
+    #[new]
+    #[pyo3(signature = (init_params, boot_params = None))]
+    fn new(init_params: &PyDict, boot_params: Option<&PyDict>) -> PyResult<Self> {
+        let mut model = Self::new_unbooted(init_params)?;
+        model.boot(boot_params)?;
+        Ok(model)
+    }
+
+    #[staticmethod]
+    fn new_unbooted(init_params: &PyDict) -> PyResult<Self> {
+        let init = InitConfig::from_dict(init_params)?;
+        Ok(Self {
+            model: Self::create(&init)?,
+            init,
+            fuses: Fuses::default(),
+        })
+    }
+
+    /// Writes the fuses, sets BOOTFSM_GO and, if `boot_params` contains
+    /// `fw_image`, uploads the firmware once the ROM asks for it.
+    #[pyo3(signature = (boot_params = None))]
+    fn boot(&mut self, boot_params: Option<&PyDict>) -> PyResult<()> {
+        let boot = BootConfig::from_dict(boot_params)?;
+        self.fuses = boot.fuses.clone();
+        self.model.boot(boot.params()).map_err(model_err)
+    }
+
+    /// Writes the fuses and CPTRA_FUSE_WR_DONE.
+    fn init_fuses(&mut self, fuses: &PyDict) -> PyResult<()> {
+        self.fuses = fuses_from_dict(fuses)?;
+        SocManager::init_fuses(&mut self.model, &self.fuses).map_err(|e| hw_model_err(e.into()))
+    }
+
+    fn bootfsm_go(&mut self) {
+        self.model
+            .soc_ifc()
+            .cptra_bootfsm_go()
+            .write(|w| w.go(true));
+    }
+
+    #[pyo3(signature = (cycles = 1))]
+    fn step(&mut self, cycles: u64) {
+        for _ in 0..cycles {
+            self.model.step();
+        }
+    }
+
+    /// Steps until `predicate(model)` returns true. Raises `ModelError` if
+    /// `max_cycles` is given and the predicate is still false after that
+    /// many cycles.
+    #[pyo3(signature = (predicate, max_cycles = None))]
+    fn step_until(slf: &PyCell<Self>, predicate: &PyAny, max_cycles: Option<u64>) -> PyResult<()> {
+        let mut cycles = 0;
+        while !predicate.call1((slf,))?.is_true()? {
+            if max_cycles.is_some_and(|max| cycles >= max) {
+                return Err(ModelError::new_err(format!(
+                    "predicate still false after {cycles} cycles"
+                )));
+            }
+            slf.borrow_mut().model.step();
+            cycles += 1;
+        }
+        Ok(())
+    }
+
+    /// Steps until the UART output starts with `expected`.
+    fn step_until_output(&mut self, expected: &str) -> PyResult<()> {
+        self.model.step_until_output(expected).map_err(model_err)
+    }
+
+    /// Steps until `substr` is written to the UART output.
+    fn step_until_output_contains(&mut self, substr: &str) -> PyResult<()> {
+        self.model
+            .step_until_output_contains(substr)
+            .map_err(model_err)
+    }
+
+    fn step_until_boot_status(&mut self, status: u32) {
+        self.model.step_until_boot_status(status, true);
+    }
+
+    fn step_until_exit_success(&mut self) -> PyResult<()> {
+        self.model.step_until_exit_success().map_err(model_err)
+    }
+
+    fn ready_for_fw(&self) -> bool {
+        self.model.ready_for_fw()
+    }
+
+    fn upload_firmware(&mut self, firmware: &[u8]) -> PyResult<()> {
+        self.model.upload_firmware(firmware).map_err(hw_model_err)
+    }
+
+    /// Executes mailbox command `cmd` with the raw request `data` (including
+    /// the checksum). Returns the response data, or None if the firmware
+    /// completed the command without data. Raises `MailboxCmdFailed` if the
+    /// firmware failed the command.
+    fn mailbox_execute<'py>(
+        &mut self,
+        py: Python<'py>,
+        cmd: u32,
+        data: &[u8],
+    ) -> PyResult<Option<&'py PyBytes>> {
+        let resp = self
+            .model
+            .mailbox_execute(cmd, data)
+            .map_err(hw_model_err)?;
+        Ok(resp.map(|resp| PyBytes::new(py, &resp)))
+    }
+
+    /// Returns the DER-encoded LDevID certificate (GET_LDEV_CERT).
+    fn get_ldev_cert<'py>(&mut self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let resp = self
+            .model
+            .mailbox_execute_req(GetLdevCertReq::default())
+            .map_err(hw_model_err)?;
+        let data = ResponseVarSize::data(&resp).map_err(|e| model_err(format!("{e:?}")))?;
+        Ok(PyBytes::new(py, data))
+    }
+
+    /// Returns the DER-encoded FMC alias certificate (GET_FMC_ALIAS_CERT).
+    fn get_fmc_alias_cert<'py>(&mut self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let resp = self
+            .model
+            .mailbox_execute_req(GetFmcAliasCertReq::default())
+            .map_err(hw_model_err)?;
+        let data = ResponseVarSize::data(&resp).map_err(|e| model_err(format!("{e:?}")))?;
+        Ok(PyBytes::new(py, data))
+    }
+
+    /// Returns the DER-encoded RT alias certificate (GET_RT_ALIAS_CERT).
+    fn get_rt_alias_cert<'py>(&mut self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let resp = self
+            .model
+            .mailbox_execute_req(GetRtAliasCertReq::default())
+            .map_err(hw_model_err)?;
+        let data = ResponseVarSize::data(&resp).map_err(|e| model_err(format!("{e:?}")))?;
+        Ok(PyBytes::new(py, data))
+    }
+
+    /// Returns the FW_INFO response as a dict.
+    fn fw_info<'py>(&mut self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let cmd = u32::from(CommandId::FW_INFO);
+        let req = MailboxReqHeader {
+            chksum: calc_checksum(cmd, &[]),
+        };
+        let resp = self
+            .model
+            .mailbox_execute(cmd, req.as_bytes())
+            .map_err(hw_model_err)?
+            .ok_or_else(|| model_err("FW_INFO returned no data"))?;
+        let resp = FwInfoResp::read_from_bytes(resp.as_slice())
+            .map_err(|_| model_err("FW_INFO response has the wrong size"))?;
+        let dict = PyDict::new(py);
+        dict.set_item("pl0_pauser", resp.pl0_pauser)?;
+        dict.set_item("runtime_svn", resp.runtime_svn)?;
+        dict.set_item("min_runtime_svn", resp.min_runtime_svn)?;
+        dict.set_item("fmc_manifest_svn", resp.fmc_manifest_svn)?;
+        dict.set_item("attestation_disabled", resp.attestation_disabled != 0)?;
+        dict.set_item("rom_revision", PyBytes::new(py, &resp.rom_revision))?;
+        dict.set_item("fmc_revision", PyBytes::new(py, &resp.fmc_revision))?;
+        dict.set_item("runtime_revision", PyBytes::new(py, &resp.runtime_revision))?;
+        dict.set_item("rom_sha256_digest", resp.rom_sha256_digest.to_vec())?;
+        dict.set_item("fmc_sha384_digest", resp.fmc_sha384_digest.to_vec())?;
+        dict.set_item("runtime_sha384_digest", resp.runtime_sha384_digest.to_vec())?;
+        dict.set_item("owner_pub_key_hash", resp.owner_pub_key_hash.to_vec())?;
+        Ok(dict)
+    }
+
+    /// Returns the CAPABILITIES bits as an int.
+    fn capabilities(&mut self) -> PyResult<u128> {
+        let caps = self.model.capabilities().map_err(hw_model_err)?;
+        Ok(caps.bits())
+    }
+
+    fn apb_read_u32(&mut self, addr: u32) -> PyResult<u32> {
+        self.model
+            .apb_bus()
+            .read(RvSize::Word, addr)
+            .map_err(|e| model_err(format!("APB read of 0x{addr:08x} failed: {e:?}")))
+    }
+
+    fn apb_write_u32(&mut self, addr: u32, val: u32) -> PyResult<()> {
+        self.model
+            .apb_bus()
+            .write(RvSize::Word, addr, val)
+            .map_err(|e| model_err(format!("APB write of 0x{addr:08x} failed: {e:?}")))
+    }
+
+    /// Returns the UART output that hasn't been taken yet.
+    fn output_peek(&mut self) -> String {
+        self.model.output().peek().to_string()
+    }
+
+    /// Returns and removes the UART output that hasn't been taken yet.
+    fn output_take(&mut self) -> String {
+        self.model.output().take(usize::MAX)
+    }
+
+    fn exit_requested(&mut self) -> bool {
+        self.model.output().exit_requested()
+    }
+
+    /// Toggles the reset pin, then rewrites the fuses from the last boot and
+    /// sets BOOTFSM_GO. The ROM takes the warm reset path and relaunches the
+    /// firmware that is already loaded.
+    fn warm_reset(&mut self) {
+        self.model.warm_reset_flow(&self.fuses);
+    }
+
+    /// Power cycles the model: all state is lost and the ROM restarts from
+    /// the init parameters. The fuses from the last boot are rewritten and
+    /// BOOTFSM_GO is set; firmware must be uploaded again with
+    /// `upload_firmware()` once `ready_for_fw()` is true.
+    fn cold_reset(&mut self) -> PyResult<()> {
+        self.model = Self::create(&self.init)?;
+        SocManager::init_fuses(&mut self.model, &self.fuses).map_err(|e| hw_model_err(e.into()))?;
+        self.bootfsm_go();
+        Ok(())
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "caliptra_hw_model")]
+fn py_module(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<Model>()?;
+    m.add("ModelError", py.get_type::<ModelError>())?;
+    m.add("MailboxCmdFailed", py.get_type::<MailboxCmdFailed>())?;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+# Licensed under the Apache-2.0 license
+
+import os
+import subprocess
+from pathlib import Path
+
+import pytest
+
+REPO_ROOT = Path(__file__).resolve().parents[3]
+
+
+@pytest.fixture(scope="session")
+def images(tmp_path_factory):
+    """Builds the ROM (with logging) and the firmware bundle.
+
+    Set CALIPTRA_ROM and CALIPTRA_FW to use prebuilt images instead.
+    """
+    rom = os.environ.get("CALIPTRA_ROM")
+    fw = os.environ.get("CALIPTRA_FW")
+    if rom is None or fw is None:
+        out = tmp_path_factory.mktemp("images")
+        rom = out / "caliptra_rom.bin"
+        fw = out / "image_bundle.bin"
+        subprocess.run(
+            [
+                "cargo",
+                "run",
+                "--manifest-path",
+                REPO_ROOT / "builder" / "Cargo.toml",
+                "--bin",
+                "image",
+                "--",
+                "--rom-with-log",
+                rom,
+                "--fw",
+                fw,
+            ],
+            check=True,
+        )
+    return Path(rom).read_bytes(), Path(fw).read_bytes()
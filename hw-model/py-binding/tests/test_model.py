@@ -0,0 +1,76 @@
+# Licensed under the Apache-2.0 license
+
+import struct
+
+import pytest
+
+from caliptra_hw_model import MailboxCmdFailed, Model
+
+RT_READY_FOR_COMMANDS = 0x600
+RT_LISTENING = "[rt] RT listening for mailbox commands...\n"
+
+
+def checksum(cmd, data=b""):
+    return (-(sum(cmd.to_bytes(4, "little")) + sum(data))) & 0xFFFFFFFF
+
+
+@pytest.fixture
+def model(images):
+    rom, fw = images
+    model = Model({"rom": rom}, {"fw_image": fw})
+    model.step_until_output_contains(RT_LISTENING)
+    return model
+
+
+def test_rom_banner(images):
+    rom, _ = images
+    model = Model.new_unbooted({"rom": rom})
+    model.init_fuses({})
+    model.bootfsm_go()
+    model.step_until_output("\nRunning Caliptra ROM ...")
+    model.step_until(lambda m: m.ready_for_fw(), max_cycles=10_000_000)
+
+
+def test_get_ldev_cert(model):
+    cert = model.get_ldev_cert()
+    # DER SEQUENCE
+    assert cert[0] == 0x30
+
+    x509 = pytest.importorskip("cryptography.x509")
+    parsed = x509.load_der_x509_certificate(cert)
+    assert "Caliptra 1.0 LDevID" in parsed.subject.rfc4514_string()
+    assert "Caliptra 1.0 IDevID" in parsed.issuer.rfc4514_string()
+
+
+def test_fw_info(model):
+    info = model.fw_info()
+    assert info["runtime_svn"] == 0
+    assert len(info["fmc_sha384_digest"]) == 12
+
+
+def test_mailbox_execute(model):
+    # FW_INFO without the typed helper
+    cmd = 0x494E464F
+    resp = model.mailbox_execute(cmd, struct.pack("<I", checksum(cmd)))
+    chksum, fips_status = struct.unpack_from("<II", resp)
+    assert fips_status == 0
+    assert chksum == checksum(0, resp[4:])
+
+    with pytest.raises(MailboxCmdFailed) as e:
+        model.mailbox_execute(0xFFFFFFFF, struct.pack("<I", checksum(0xFFFFFFFF)))
+    assert e.value.args[0] != 0
+
+
+def test_warm_reset(model):
+    model.warm_reset()
+    model.step_until_output_contains(RT_LISTENING)
+    assert model.get_ldev_cert()[0] == 0x30
+
+
+def test_cold_reset(images, model):
+    _, fw = images
+    model.cold_reset()
+    model.step_until(lambda m: m.ready_for_fw())
+    model.upload_firmware(fw)
+    model.step_until_boot_status(RT_READY_FOR_COMMANDS)
+    assert model.get_ldev_cert()[0] == 0x30
@@ -0,0 +1,250 @@
+// Licensed under the Apache-2.0 license
+
+//! Sweeps injected faults over a code range and reports which of them
+//! changed the outcome of a scenario without being caught.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::ops::Range;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use caliptra_api::SocManager;
+use caliptra_emu_cpu::{Fault, FaultKind, FaultTrigger};
+use caliptra_error::CaliptraError;
+
+use crate::{InitParams, ModelEmulated, ModelSnapshot};
+
+/// A fault injection campaign.
+///
+/// Every run starts from `start`. The golden run records the instructions
+/// executed inside `code_range`; each `stride`th of those executions is then
+/// faulted once with every kind in `kinds`.
+pub struct FaultCampaign {
+    pub start: ModelSnapshot,
+    pub code_range: Range<u32>,
+    pub kinds: Vec<FaultKind>,
+    pub stride: usize,
+}
+
+/// How the scenario reacted to a fault.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FaultOutcome<T> {
+    /// The scenario finished before the fault fired.
+    NotTriggered,
+
+    /// The scenario produced the same result as the golden run.
+    NoEffect,
+
+    /// The firmware reported a CFI panic.
+    CfiPanic(u32),
+
+    /// The firmware reported a fatal error the golden run did not.
+    FatalError(u32),
+
+    /// The scenario closure panicked, e.g. because the model timed out.
+    Panicked(String),
+
+    /// The result differs from the golden run and nothing noticed.
+    Undetected(T),
+}
+
+/// The outcome of a single fault.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FaultResult<T> {
+    pub fault: Fault,
+    pub outcome: FaultOutcome<T>,
+}
+
+/// Results of [`FaultCampaign::run`].
+#[derive(Clone, Debug)]
+pub struct CampaignReport<T> {
+    pub golden: T,
+    pub results: Vec<FaultResult<T>>,
+}
+
+impl<T> CampaignReport<T> {
+    /// Faults that changed the result without being detected.
+    pub fn undetected(&self) -> impl Iterator<Item = &FaultResult<T>> {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, FaultOutcome::Undetected(_)))
+    }
+}
+
+impl<T: Debug> Display for CampaignReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut counts = [0usize; 6];
+        for result in &self.results {
+            counts[match result.outcome {
+                FaultOutcome::NotTriggered => 0,
+                FaultOutcome::NoEffect => 1,
+                FaultOutcome::CfiPanic(_) => 2,
+                FaultOutcome::FatalError(_) => 3,
+                FaultOutcome::Panicked(_) => 4,
+                FaultOutcome::Undetected(_) => 5,
+            }] += 1;
+        }
+        writeln!(f, "{} faults injected", self.results.len())?;
+        writeln!(f, "  not triggered: {}", counts[0])?;
+        writeln!(f, "  no effect:     {}", counts[1])?;
+        writeln!(f, "  CFI panic:     {}", counts[2])?;
+        writeln!(f, "  fatal error:   {}", counts[3])?;
+        writeln!(f, "  panicked:      {}", counts[4])?;
+        writeln!(f, "  undetected:    {}", counts[5])?;
+        for result in self.undetected() {
+            writeln!(f, "    {:x?} -> {:?}", result.fault, result.outcome)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_cfi_panic(code: u32) -> bool {
+    (u32::from(CaliptraError::ROM_CFI_PANIC_UNKNOWN)
+        ..=u32::from(CaliptraError::ROM_CFI_PANIC_FAKE_TRNG_USED_WITH_DEBUG_LOCK))
+        .contains(&code)
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
+
+impl FaultCampaign {
+    fn restore(&self) -> Result<ModelEmulated, Box<dyn Error>> {
+        ModelEmulated::restore(
+            &self.start,
+            InitParams {
+                log_writer: Box::new(std::io::sink()),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Run `scenario` once without faults, then once per fault.
+    ///
+    /// `scenario` drives the model and returns what it observed; it must
+    /// give up on its own (panicking is fine) if a fault hangs the firmware.
+    pub fn run<T: Clone + Debug + PartialEq>(
+        &self,
+        mut scenario: impl FnMut(&mut ModelEmulated) -> T,
+    ) -> Result<CampaignReport<T>, Box<dyn Error>> {
+        let mut model = self.restore()?;
+        model.trace_pcs(Some(self.code_range.clone()));
+        let golden = scenario(&mut model);
+        let golden_fatal = model.soc_ifc().cptra_fw_error_fatal().read();
+        let pcs = model.take_traced_pcs();
+
+        let mut occurrences = HashMap::new();
+        let triggers: Vec<_> = pcs
+            .into_iter()
+            .map(|pc| {
+                let occurrence = occurrences.entry(pc).or_insert(0u32);
+                *occurrence += 1;
+                FaultTrigger::Pc {
+                    pc,
+                    occurrence: *occurrence - 1,
+                }
+            })
+            .step_by(self.stride.max(1))
+            .collect();
+
+        let mut results = vec![];
+        for trigger in triggers {
+            for &kind in &self.kinds {
+                let fault = Fault { trigger, kind };
+                let mut model = self.restore()?;
+                model.inject_fault(fault);
+                let observed = catch_unwind(AssertUnwindSafe(|| scenario(&mut model)));
+                let fatal = model.soc_ifc().cptra_fw_error_fatal().read();
+                let outcome = match observed {
+                    Err(payload) => FaultOutcome::Panicked(panic_message(payload)),
+                    Ok(_) if model.fault_hit().is_none() => FaultOutcome::NotTriggered,
+                    Ok(_) if fatal != 0 && fatal != golden_fatal && is_cfi_panic(fatal) => {
+                        FaultOutcome::CfiPanic(fatal)
+                    }
+                    Ok(_) if fatal != 0 && fatal != golden_fatal => FaultOutcome::FatalError(fatal),
+                    Ok(observed) if observed == golden => FaultOutcome::NoEffect,
+                    Ok(observed) => FaultOutcome::Undetected(observed),
+                };
+                results.push(FaultResult { fault, outcome });
+            }
+        }
+        Ok(CampaignReport { golden, results })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mmio::Rv32GenMmio;
+    use crate::HwModel;
+    use caliptra_registers::soc_ifc;
+
+    #[test]
+    fn test_skip_instr_campaign() {
+        let rv32_gen = Rv32GenMmio::new();
+        let soc_ifc =
+            unsafe { soc_ifc::RegisterBlock::new_with_mmio(0x3003_0000 as *mut u32, &rv32_gen) };
+        soc_ifc
+            .cptra_generic_output_wires()
+            .at(0)
+            .write(|_| b'h'.into());
+        soc_ifc
+            .cptra_generic_output_wires()
+            .at(0)
+            .write(|_| b'i'.into());
+        soc_ifc.cptra_generic_output_wires().at(0).write(|_| 0xff);
+        let rom = rv32_gen.into_inner().empty_loop().build();
+
+        let mut model = ModelEmulated::new_unbooted(InitParams {
+            rom: &rom,
+            ..Default::default()
+        })
+        .unwrap();
+        model.soc_ifc().cptra_fuse_wr_done().write(|w| w.done(true));
+        model.soc_ifc().cptra_bootfsm_go().write(|w| w.go(true));
+
+        let campaign = FaultCampaign {
+            start: model.snapshot().unwrap(),
+            code_range: 0..rom.len() as u32,
+            kinds: vec![FaultKind::SkipInstr],
+            stride: 1,
+        };
+        let report = campaign
+            .run(|model| {
+                for _ in 0..1000 {
+                    if model.output().exit_requested() {
+                        break;
+                    }
+                    model.step();
+                }
+                (
+                    model.output().take(usize::MAX),
+                    model.output().exit_requested(),
+                )
+            })
+            .unwrap();
+
+        assert_eq!(report.golden, ("hi".to_string(), true));
+        assert!(!report.results.is_empty());
+        assert!(report
+            .results
+            .iter()
+            .all(|r| !matches!(r.outcome, FaultOutcome::NotTriggered)));
+        // Skipping the store of 'h' goes unnoticed by this ROM.
+        assert!(report.undetected().any(|r| matches!(
+            &r.outcome,
+            FaultOutcome::Undetected((output, true)) if output == "i"
+        )));
+        // So does skipping the exit.
+        assert!(report
+            .undetected()
+            .any(|r| matches!(&r.outcome, FaultOutcome::Undetected((_, false)))));
+    }
+}
@@ -5,6 +5,7 @@ use caliptra_api as api;
 use caliptra_api::SocManager;
 use caliptra_api_types as api_types;
 use caliptra_emu_bus::Bus;
+use caliptra_error::CaliptraError;
 use core::panic;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -34,6 +35,8 @@ pub mod mmio;
 mod model_emulated;
 
 mod bus_logger;
+mod fault_campaign;
+mod mbox_trace;
 #[cfg(feature = "verilator")]
 mod model_verilated;
 
@@ -41,16 +44,23 @@ mod model_verilated;
 mod model_fpga_realtime;
 
 mod output;
+pub mod remote_bitbang;
 mod rv32_builder;
+pub mod soc_socket;
 
 pub use api::mailbox::mbox_write_fifo;
 pub use api_types::{DeviceLifecycle, Fuses, SecurityState, U4};
 pub use caliptra_emu_bus::BusMmio;
-pub use caliptra_emu_cpu::{CodeRange, ImageInfo, StackInfo, StackRange};
+pub use caliptra_emu_cpu::{
+    CodeRange, Fault, FaultHit, FaultKind, FaultTrigger, ImageInfo, StackInfo, StackRange,
+};
+pub use fault_campaign::{CampaignReport, FaultCampaign, FaultOutcome, FaultResult};
+pub use mbox_trace::MailboxTracer;
 use output::ExitStatus;
 pub use output::Output;
+pub use soc_socket::SocketSocManager;
 
-pub use model_emulated::ModelEmulated;
+pub use model_emulated::{ModelEmulated, ModelSnapshot};
 
 #[cfg(feature = "verilator")]
 pub use model_verilated::ModelVerilated;
@@ -61,10 +71,19 @@ pub enum ShaAccMode {
     Sha512Stream,
 }
 
+/// Why the JTAG port couldn't be used, as reported by
+/// [`HwModel::launch_openocd`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OpenOcdError {
+    Unsupported,
+    Closed,
+    CaliptraNotAccessible,
+    VeerNotAccessible,
+    WrongVersion,
+}
+
 #[cfg(feature = "fpga_realtime")]
 pub use model_fpga_realtime::ModelFpgaRealtime;
-#[cfg(feature = "fpga_realtime")]
-pub use model_fpga_realtime::OpenOcdError;
 
 /// Ideally, general-purpose functions would return `impl HwModel` instead of
 /// `DefaultHwModel` to prevent users from calling functions that aren't
@@ -90,9 +109,11 @@ pub const DEFAULT_APB_PAUSER: u32 = 0x01;
 /// The model returned by this function does not have any fuses programmed and
 /// is not yet ready to execute code in the microcontroller. Most test cases
 /// should use [`new`] instead.
-pub fn new_unbooted(params: InitParams) -> Result<DefaultHwModel, Box<dyn Error>> {
+pub fn new_unbooted(mut params: InitParams) -> Result<DefaultHwModel, Box<dyn Error>> {
     let summary = params.summary();
-    DefaultHwModel::new_unbooted(params).map(|hw| {
+    let mbox_tracer = mbox_tracer_or_env(params.mbox_trace_path.take());
+    DefaultHwModel::new_unbooted(params).map(|mut hw| {
+        hw.output().set_mailbox_tracer(mbox_tracer);
         println!(
             "Using hardware-model {} trng={:?}",
             hw.type_name(),
@@ -179,6 +200,12 @@ pub struct InitParams<'a> {
     // will be used
     pub trace_path: Option<PathBuf>,
 
+    // A path to write a JSON Lines trace of mailbox transactions to. If None,
+    // the CPTRA_MBOX_TRACE_PATH environment variable will be used. Only
+    // honored by `caliptra_hw_model::new_unbooted()` and `HwModel::new()`;
+    // `HwModel::new_unbooted()` ignores it.
+    pub mbox_trace_path: Option<PathBuf>,
+
     // Information about the stack Caliptra is using. When set the emulator will check if the stack
     // overflows.
     pub stack_info: Option<StackInfo>,
@@ -218,6 +245,7 @@ impl<'a> Default for InitParams<'a> {
             }),
             random_sram_puf: true,
             trace_path: None,
+            mbox_trace_path: None,
             stack_info: None,
             soc_user: MailboxRequester::SocUser(1u32),
         }
@@ -256,6 +284,21 @@ fn trace_path_or_env(trace_path: Option<PathBuf>) -> Option<PathBuf> {
     std::env::var("CPTRA_TRACE_PATH").ok().map(PathBuf::from)
 }
 
+fn mbox_tracer_or_env(mbox_trace_path: Option<PathBuf>) -> Option<MailboxTracer> {
+    let path = mbox_trace_path.or_else(|| {
+        std::env::var("CPTRA_MBOX_TRACE_PATH")
+            .ok()
+            .map(PathBuf::from)
+    })?;
+    match MailboxTracer::open(&path) {
+        Ok(tracer) => Some(tracer),
+        Err(e) => {
+            eprintln!("Unable to open file {path:?}: {e}");
+            None
+        }
+    }
+}
+
 pub struct BootParams<'a> {
     pub fuses: Fuses,
     pub fw_image: Option<&'a [u8]>,
@@ -319,6 +362,9 @@ pub enum ModelError {
     FuseDoneNotSet,
     FusesAlreadyInitialized,
     StashMeasurementFailed,
+    MailboxCmdNotSupported(u32),
+    InvalidCapabilities,
+    SocketError(std::io::ErrorKind),
 }
 
 impl From<CaliptraApiError> for ModelError {
@@ -366,14 +412,30 @@ impl From<CaliptraApiError> for ModelError {
                 ModelError::StashMeasurementFailed
             }
             caliptra_api::CaliptraApiError::UnableToSetPauser => ModelError::UnableToSetPauser,
+            caliptra_api::CaliptraApiError::MailboxCmdNotSupported(cmd) => {
+                ModelError::MailboxCmdNotSupported(cmd)
+            }
+            caliptra_api::CaliptraApiError::InvalidCapabilities => ModelError::InvalidCapabilities,
         }
     }
 }
 impl Error for ModelError {}
+
+/// Formats the value of a firmware error register with the name of the
+/// error, if it's a known `CaliptraError`.
+fn fw_error_str(code: u32) -> String {
+    match CaliptraError::try_from(code) {
+        Ok(err) => err.to_string(),
+        Err(_) => "0".into(),
+    }
+}
+
 impl Display for ModelError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ModelError::MailboxCmdFailed(err) => write!(f, "Mailbox command failed. fw_err={err}"),
+            ModelError::MailboxCmdFailed(err) => {
+                write!(f, "Mailbox command failed. fw_err={}", fw_error_str(*err))
+            }
             ModelError::UnableToLockMailbox => write!(f, "Unable to lock mailbox"),
             ModelError::BufferTooLargeForMailbox => write!(f, "Buffer too large for mailbox"),
             ModelError::UploadFirmwareUnexpectedResponse => {
@@ -448,6 +510,15 @@ impl Display for ModelError {
             ModelError::StashMeasurementFailed => {
                 write!(f, "Stash measurement request failed")
             }
+            ModelError::MailboxCmdNotSupported(cmd) => {
+                write!(f, "Mailbox command 0x{cmd:x} not supported by firmware")
+            }
+            ModelError::InvalidCapabilities => {
+                write!(f, "Invalid capabilities in response")
+            }
+            ModelError::SocketError(kind) => {
+                write!(f, "SoC socket error: {kind}")
+            }
             ModelError::UnableToSetPauser => {
                 write!(f, "Valid PAUSER locked")
             }
@@ -508,6 +579,66 @@ fn mbox_read_fifo(mbox: mbox::RegisterBlock<impl MmioMut>) -> Vec<u8> {
     buf
 }
 
+// Split out of HwModel::finish_mailbox_execute() so the tracer sees every
+// return path.
+fn wait_for_mailbox_response<T: HwModel + ?Sized>(
+    model: &mut T,
+) -> std::result::Result<Option<Vec<u8>>, ModelError> {
+    // Wait for the microcontroller to finish executing
+    let mut timeout_cycles = 40000000; // 100ms @400MHz
+    while model.soc_mbox().status().read().status().cmd_busy() {
+        model.step();
+        timeout_cycles -= 1;
+        if timeout_cycles == 0 {
+            return Err(ModelError::MailboxTimeout);
+        }
+    }
+    let status = model.soc_mbox().status().read().status();
+    if status.cmd_failure() {
+        writeln!(model.output().logger(), ">>> mbox cmd response: failed").unwrap();
+        model.soc_mbox().execute().write(|w| w.execute(false));
+        let soc_ifc = model.soc_ifc();
+        return Err(ModelError::MailboxCmdFailed(
+            if soc_ifc.cptra_fw_error_fatal().read() != 0 {
+                soc_ifc.cptra_fw_error_fatal().read()
+            } else {
+                soc_ifc.cptra_fw_error_non_fatal().read()
+            },
+        ));
+    }
+    if status.cmd_complete() {
+        writeln!(model.output().logger(), ">>> mbox cmd response: success").unwrap();
+        model.soc_mbox().execute().write(|w| w.execute(false));
+        return Ok(None);
+    }
+    if !status.data_ready() {
+        return Err(ModelError::UnknownCommandStatus(status as u32));
+    }
+
+    let dlen = model.soc_mbox().dlen().read();
+    writeln!(
+        model.output().logger(),
+        ">>> mbox cmd response data ({dlen} bytes)"
+    )
+    .unwrap();
+    let result = mbox_read_fifo(model.soc_mbox());
+
+    model.soc_mbox().execute().write(|w| w.execute(false));
+
+    if cfg!(not(feature = "fpga_realtime")) {
+        // Don't check for mbox_idle() unless the hw-model supports
+        // fine-grained timing control; the firmware may proceed to lock the
+        // mailbox shortly after the mailbox transcation finishes (for example, to
+        // test the sha2_512_384_acc peripheral).
+
+        // mbox_fsm_ps isn't updated immediately after execute is cleared (!?),
+        // so step an extra clock cycle to wait for fm_ps to update
+        model.step();
+        assert!(model.soc_mbox().status().read().mbox_fsm_ps().mbox_idle());
+    }
+    Ok(Some(result))
+}
+
 /// Firmware Load Command Opcode
 const FW_LOAD_CMD_OPCODE: u32 = 0x4657_4C44;
 
@@ -531,13 +662,15 @@ pub trait HwModel: SocManager {
     /// Create a model, and boot it to the point where CPU execution can
     /// occur. This includes programming the fuses, initializing the
     /// boot_fsm state machine, and (optionally) uploading firmware.
-    fn new(init_params: InitParams, boot_params: BootParams) -> Result<Self, Box<dyn Error>>
+    fn new(mut init_params: InitParams, boot_params: BootParams) -> Result<Self, Box<dyn Error>>
     where
         Self: Sized,
     {
         let init_params_summary = init_params.summary();
+        let mbox_tracer = mbox_tracer_or_env(init_params.mbox_trace_path.take());
 
         let mut hw: Self = HwModel::new_unbooted(init_params)?;
+        hw.output().set_mailbox_tracer(mbox_tracer);
         let hw_rev_id = hw.soc_ifc().cptra_hw_rev_id().read();
         println!(
             "Using hardware-model {} trng={:?} hw_rev_id={{cptra_generation=0x{:04x}, soc_stepping_id={:04x}}}",
@@ -663,6 +796,12 @@ pub trait HwModel: SocManager {
         panic!("cold_reset unimplemented");
     }
 
+    /// Launch OpenOCD and connect it to the JTAG port
+    fn launch_openocd(&mut self) -> Result<(), OpenOcdError> {
+        // To be overridden by HwModel implementations that support this
+        Err(OpenOcdError::Unsupported)
+    }
+
     /// Returns true if the microcontroller has signalled that it is ready for
     /// firmware to be written to the mailbox. For RTL implementations, this
     /// should come via a caliptra_top wire rather than an APB register.
@@ -808,17 +947,19 @@ pub trait HwModel: SocManager {
 
             if actual_error != initial_error {
                 panic!(
-                    "Expected the fatal error to be  \
-                    ({expected_error}), but error changed from \
-                    {initial_error} to {actual_error})"
+                    "Expected the fatal error to be {}, but error changed from {} to {}",
+                    fw_error_str(expected_error),
+                    fw_error_str(initial_error),
+                    fw_error_str(actual_error)
                 );
             }
             self.step();
             cycle_count += 1;
             if cycle_count >= max_wait_cycles {
                 panic!(
-                    "Expected fatal error to be  \
-                    ({expected_error}), but was stuck at ({initial_error})"
+                    "Expected fatal error to be {}, but was stuck at {}",
+                    fw_error_str(expected_error),
+                    fw_error_str(initial_error)
                 );
             }
         }
@@ -845,6 +986,24 @@ pub trait HwModel: SocManager {
             .map_err(ModelError::from)
     }
 
+    /// Queries the capabilities of the firmware currently running.
+    fn capabilities(&mut self) -> std::result::Result<api::Capabilities, ModelError> {
+        self.get_capabilities().map_err(ModelError::from)
+    }
+
+    /// Executes a typed request if the firmware advertises support for it in
+    /// `caps`, otherwise returns `ModelError::MailboxCmdNotSupported`.
+    fn mailbox_execute_req_if_supported<R: api::mailbox::Request>(
+        &mut self,
+        caps: api::Capabilities,
+        req: R,
+    ) -> std::result::Result<R::Resp, ModelError> {
+        let mut response = R::Resp::new_zeroed();
+
+        self.mailbox_exec_req_if_supported(caps, req, response.as_mut_bytes())
+            .map_err(ModelError::from)
+    }
+
     /// Executes `cmd` with request data `buf`. Returns `Ok(Some(_))` if
     /// the uC responded with data, `Ok(None)` if the uC indicated success
     /// without data, Err(ModelError::MailboxCmdFailed) if the microcontroller
@@ -882,6 +1041,10 @@ pub trait HwModel: SocManager {
             buf.len(),
         )
         .unwrap();
+        let now = self.output().sink().now();
+        if let Some(tracer) = self.output().mailbox_tracer() {
+            tracer.start(now, cmd, buf);
+        }
 
         self.soc_mbox().cmd().write(|_| cmd);
         mbox_write_fifo(&self.soc_mbox(), buf).map_err(ModelError::from)?;
@@ -894,59 +1057,12 @@ pub trait HwModel: SocManager {
 
     /// Wait for the response to a previous call to `start_mailbox_execute()`.
     fn finish_mailbox_execute(&mut self) -> std::result::Result<Option<Vec<u8>>, ModelError> {
-        // Wait for the microcontroller to finish executing
-        let mut timeout_cycles = 40000000; // 100ms @400MHz
-        while self.soc_mbox().status().read().status().cmd_busy() {
-            self.step();
-            timeout_cycles -= 1;
-            if timeout_cycles == 0 {
-                return Err(ModelError::MailboxTimeout);
-            }
-        }
-        let status = self.soc_mbox().status().read().status();
-        if status.cmd_failure() {
-            writeln!(self.output().logger(), ">>> mbox cmd response: failed").unwrap();
-            self.soc_mbox().execute().write(|w| w.execute(false));
-            let soc_ifc = self.soc_ifc();
-            return Err(ModelError::MailboxCmdFailed(
-                if soc_ifc.cptra_fw_error_fatal().read() != 0 {
-                    soc_ifc.cptra_fw_error_fatal().read()
-                } else {
-                    soc_ifc.cptra_fw_error_non_fatal().read()
-                },
-            ));
-        }
-        if status.cmd_complete() {
-            writeln!(self.output().logger(), ">>> mbox cmd response: success").unwrap();
-            self.soc_mbox().execute().write(|w| w.execute(false));
-            return Ok(None);
-        }
-        if !status.data_ready() {
-            return Err(ModelError::UnknownCommandStatus(status as u32));
-        }
-
-        let dlen = self.soc_mbox().dlen().read();
-        writeln!(
-            self.output().logger(),
-            ">>> mbox cmd response data ({dlen} bytes)"
-        )
-        .unwrap();
-        let result = mbox_read_fifo(self.soc_mbox());
-
-        self.soc_mbox().execute().write(|w| w.execute(false));
-
-        if cfg!(not(feature = "fpga_realtime")) {
-            // Don't check for mbox_idle() unless the hw-model supports
-            // fine-grained timing control; the firmware may proceed to lock the
-            // mailbox shortly after the mailbox transcation finishes (for example, to
-            // test the sha2_512_384_acc peripheral).
-
-            // mbox_fsm_ps isn't updated immediately after execute is cleared (!?),
-            // so step an extra clock cycle to wait for fm_ps to update
-            self.step();
-            assert!(self.soc_mbox().status().read().mbox_fsm_ps().mbox_idle());
+        let result = wait_for_mailbox_response(self);
+        let now = self.output().sink().now();
+        if let Some(tracer) = self.output().mailbox_tracer() {
+            tracer.finish(now, &result);
         }
-        Ok(Some(result))
+        result
     }
 
     /// Streams `data` to the sha512acc SoC interface. If `sha384` computes
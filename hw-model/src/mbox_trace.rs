@@ -0,0 +1,290 @@
+// Licensed under the Apache-2.0 license
+
+//! Typed mailbox transaction tracing.
+//!
+//! Each transaction sent through `HwModel::start_mailbox_execute()` /
+//! `HwModel::finish_mailbox_execute()` is written as a single JSON object per
+//! line. Requests and responses for known commands are decoded into their
+//! `caliptra-api` types and their checksums are verified, so a failing run
+//! can be diffed against a passing one without decoding the raw FIFO bytes
+//! by hand.
+
+use std::fmt::Debug;
+use std::fs::File;
+use std::io::{self, LineWriter, Write};
+use std::path::Path;
+use std::time::SystemTime;
+
+use caliptra_api::calc_checksum;
+use caliptra_api::mailbox::*;
+use serde_json::{json, Value};
+use zerocopy::{FromBytes, IntoBytes};
+
+use crate::ModelError;
+
+type DecodeFn = fn(&[u8]) -> Option<String>;
+
+struct CommandInfo {
+    name: &'static str,
+    /// None for commands whose payload is not a checksummed struct
+    /// (FIRMWARE_LOAD).
+    decode: Option<(DecodeFn, DecodeFn)>,
+}
+
+fn decode<T: FromBytes + IntoBytes + Debug>(data: &[u8]) -> Option<String> {
+    // Variable-length structs are sent truncated; zero-fill the remainder.
+    let mut val = T::new_zeroed();
+    val.as_mut_bytes()
+        .get_mut(..data.len())?
+        .copy_from_slice(data);
+    Some(format!("{val:?}"))
+}
+
+macro_rules! command_table {
+    ($($id:ident => $req:ty, $resp:ty;)*) => {
+        fn command_info(cmd: u32) -> Option<CommandInfo> {
+            if cmd == CommandId::FIRMWARE_LOAD.0 {
+                return Some(CommandInfo {
+                    name: "FIRMWARE_LOAD",
+                    decode: None,
+                });
+            }
+            $(
+                if cmd == CommandId::$id.0 {
+                    return Some(CommandInfo {
+                        name: stringify!($id),
+                        decode: Some((decode::<$req>, decode::<$resp>)),
+                    });
+                }
+            )*
+            None
+        }
+    };
+}
+
+command_table! {
+    GET_IDEV_CERT => GetIdevCertReq, GetIdevCertResp;
+    GET_IDEV_INFO => MailboxReqHeader, GetIdevInfoResp;
+    POPULATE_IDEV_CERT => PopulateIdevCertReq, MailboxRespHeader;
+    GET_LDEV_CERT => GetLdevCertReq, GetLdevCertResp;
+    GET_FMC_ALIAS_CERT => GetFmcAliasCertReq, GetFmcAliasCertResp;
+    GET_RT_ALIAS_CERT => GetRtAliasCertReq, GetRtAliasCertResp;
+    ECDSA384_VERIFY => EcdsaVerifyReq, MailboxRespHeader;
+    LMS_VERIFY => LmsVerifyReq, MailboxRespHeader;
+    STASH_MEASUREMENT => StashMeasurementReq, StashMeasurementResp;
+    INVOKE_DPE => InvokeDpeReq, InvokeDpeResp;
+    DISABLE_ATTESTATION => MailboxReqHeader, MailboxRespHeader;
+    FW_INFO => MailboxReqHeader, FwInfoResp;
+    DPE_TAG_TCI => TagTciReq, MailboxRespHeader;
+    DPE_GET_TAGGED_TCI => GetTaggedTciReq, GetTaggedTciResp;
+    INCREMENT_PCR_RESET_COUNTER => IncrementPcrResetCounterReq, MailboxRespHeader;
+    QUOTE_PCRS => QuotePcrsReq, QuotePcrsResp;
+    EXTEND_PCR => ExtendPcrReq, MailboxRespHeader;
+    ADD_SUBJECT_ALT_NAME => AddSubjectAltNameReq, MailboxRespHeader;
+    CERTIFY_KEY_EXTENDED => CertifyKeyExtendedReq, CertifyKeyExtendedResp;
+    VERSION => MailboxReqHeader, FipsVersionResp;
+    SELF_TEST_START => MailboxReqHeader, MailboxRespHeader;
+    SELF_TEST_GET_RESULTS => MailboxReqHeader, MailboxRespHeader;
+    SHUTDOWN => MailboxReqHeader, MailboxRespHeader;
+    CAPABILITIES => CapabilitiesReq, CapabilitiesResp;
+    SET_AUTH_MANIFEST => SetAuthManifestReq, MailboxRespHeader;
+    AUTHORIZE_AND_STASH => AuthorizeAndStashReq, AuthorizeAndStashResp;
+    GET_IDEV_CSR => GetIdevCsrReq, GetIdevCsrResp;
+    GET_FMC_ALIAS_CSR => GetFmcAliasCsrReq, GetFmcAliasCsrResp;
+    SIGN_WITH_EXPORTED_ECDSA => SignWithExportedEcdsaReq, SignWithExportedEcdsaResp;
+    REVOKE_EXPORTED_CDI_HANDLE => RevokeExportedCdiHandleReq, RevokeExportedCdiHandleResp;
+    GET_PCR_LOG => GetPcrLogReq, GetLogResp;
+    GET_MEASUREMENT_LOG => GetMeasurementLogReq, GetLogResp;
+    GET_FUSE_LOG => GetFuseLogReq, GetLogResp;
+    GET_IMAGE_DATA => GetImageDataReq, GetImageDataResp;
+}
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Describes one direction of a transaction. `checksum_cmd` is the value
+/// mixed into the checksum (the command id for requests, 0 for responses).
+fn describe_payload(data: &[u8], checksum_cmd: u32, decode: Option<DecodeFn>) -> Value {
+    let Some(decode) = decode else {
+        return json!({
+            "len": data.len(),
+            "checksum": null,
+            "decoded": null,
+            "data": to_hex(data),
+        });
+    };
+    let checksum = match data.get(..4) {
+        Some(chksum) => {
+            let actual = u32::from_le_bytes(chksum.try_into().unwrap());
+            let expected = calc_checksum(checksum_cmd, &data[4..]);
+            json!({
+                "actual": format!("0x{actual:08x}"),
+                "expected": format!("0x{expected:08x}"),
+                "valid": actual == expected,
+            })
+        }
+        None => Value::Null,
+    };
+    json!({
+        "len": data.len(),
+        "checksum": checksum,
+        "decoded": decode(data),
+        "data": to_hex(data),
+    })
+}
+
+struct PendingTransaction {
+    cmd: u32,
+    request: Vec<u8>,
+    start_cycle: u64,
+}
+
+/// Writes a JSON Lines record for every mailbox transaction executed by a
+/// hw-model.
+pub struct MailboxTracer {
+    writer: Box<dyn Write>,
+    pending: Option<PendingTransaction>,
+    seq: u64,
+}
+
+impl MailboxTracer {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer,
+            pending: None,
+            seq: 0,
+        }
+    }
+
+    /// Creates (or truncates) the trace file at `path`.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        Ok(Self::new(Box::new(LineWriter::new(File::create(path)?))))
+    }
+
+    pub(crate) fn start(&mut self, cycle: u64, cmd: u32, request: &[u8]) {
+        self.pending = Some(PendingTransaction {
+            cmd,
+            request: request.to_vec(),
+            start_cycle: cycle,
+        });
+    }
+
+    pub(crate) fn finish(&mut self, cycle: u64, result: &Result<Option<Vec<u8>>, ModelError>) {
+        let Some(pending) = self.pending.take() else {
+            return;
+        };
+        let info = command_info(pending.cmd);
+        let (decode_req, decode_resp) = match info.as_ref().and_then(|i| i.decode) {
+            Some((req, resp)) => (Some(req), Some(resp)),
+            None => (None, None),
+        };
+        let (status, response, error) = match result {
+            Ok(None) => ("success", Value::Null, Value::Null),
+            Ok(Some(data)) => ("data", describe_payload(data, 0, decode_resp), Value::Null),
+            Err(err) => ("failed", Value::Null, Value::String(err.to_string())),
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_micros())
+            .unwrap_or(0);
+        let record = json!({
+            "seq": self.seq,
+            "timestamp_us": timestamp as u64,
+            "start_cycle": pending.start_cycle,
+            "end_cycle": cycle,
+            "cycles": cycle.saturating_sub(pending.start_cycle),
+            "cmd": format!("0x{:08x}", pending.cmd),
+            "cmd_name": info.map(|i| i.name),
+            "request": describe_payload(&pending.request, pending.cmd, decode_req),
+            "status": status,
+            "response": response,
+            "error": error,
+        });
+        self.seq += 1;
+        // Tracing is a debugging aid; don't fail the transaction if the
+        // trace can't be written.
+        let _ = writeln!(self.writer, "{record}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use zerocopy::FromZeros;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn records(buf: &SharedBuf) -> Vec<Value> {
+        String::from_utf8(buf.0.borrow().clone())
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_trace_decoded_transaction() {
+        let buf = SharedBuf::default();
+        let mut tracer = MailboxTracer::new(Box::new(buf.clone()));
+
+        let mut req = QuotePcrsReq {
+            hdr: MailboxReqHeader { chksum: 0 },
+            nonce: [0xab; 32],
+        };
+        req.hdr.chksum = calc_checksum(CommandId::QUOTE_PCRS.0, &req.as_bytes()[4..]);
+        // Response with a corrupted checksum
+        let mut resp = QuotePcrsResp::new_zeroed();
+        resp.hdr.chksum = 0x1234_5678;
+
+        tracer.start(100, CommandId::QUOTE_PCRS.0, req.as_bytes());
+        tracer.finish(350, &Ok(Some(resp.as_bytes().to_vec())));
+
+        let records = records(&buf);
+        assert_eq!(records.len(), 1);
+        let r = &records[0];
+        assert_eq!(r["seq"], 0);
+        assert_eq!(r["cmd"], "0x50435251");
+        assert_eq!(r["cmd_name"], "QUOTE_PCRS");
+        assert_eq!(r["cycles"], 250);
+        assert_eq!(r["status"], "data");
+        assert_eq!(r["request"]["checksum"]["valid"], true);
+        assert!(r["request"]["decoded"]
+            .as_str()
+            .unwrap()
+            .starts_with("QuotePcrsReq"));
+        assert_eq!(r["response"]["checksum"]["valid"], false);
+        assert_eq!(r["response"]["len"], std::mem::size_of::<QuotePcrsResp>());
+    }
+
+    #[test]
+    fn test_trace_unknown_and_failed() {
+        let buf = SharedBuf::default();
+        let mut tracer = MailboxTracer::new(Box::new(buf.clone()));
+
+        tracer.start(0, 0x1000_0000, &[1, 2, 3]);
+        tracer.finish(10, &Err(ModelError::MailboxCmdFailed(0x42)));
+        // finish() without a matching start() is ignored
+        tracer.finish(20, &Ok(None));
+
+        let records = records(&buf);
+        assert_eq!(records.len(), 1);
+        let r = &records[0];
+        assert_eq!(r["cmd_name"], Value::Null);
+        assert_eq!(r["request"]["data"], "010203");
+        assert_eq!(r["request"]["decoded"], Value::Null);
+        assert_eq!(r["status"], "failed");
+        assert_eq!(r["error"], "Mailbox command failed. fw_err=0x00000042");
+    }
+}
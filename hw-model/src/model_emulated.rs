@@ -4,19 +4,25 @@ use std::cell::Cell;
 use std::collections::hash_map::DefaultHasher;
 use std::error::Error;
 use std::hash::Hasher;
-use std::io::Write;
+use std::io::{BufRead, BufReader, Write};
+use std::ops::Range;
 use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
 use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc::{self, TryRecvError};
 
-use caliptra_emu_bus::Clock;
+use caliptra_elf_symbols::SymbolType;
+use caliptra_emu_bus::{Clock, ClockSnapshot};
 #[cfg(feature = "coverage")]
 use caliptra_emu_cpu::CoverageBitmaps;
-use caliptra_emu_cpu::{Cpu, InstrTracer};
+use caliptra_emu_cpu::{Cpu, CpuSnapshot, DebugModule, Fault, FaultHit, InstrTracer, Profiler};
 use caliptra_emu_periph::ActionCb;
 use caliptra_emu_periph::MailboxExternal;
 use caliptra_emu_periph::ReadyForFwCb;
 use caliptra_emu_periph::{
-    CaliptraRootBus, CaliptraRootBusArgs, MailboxRequester, SocToCaliptraBus, TbServicesCb,
+    CaliptraDmi, CaliptraRootBus, CaliptraRootBusArgs, CaliptraRootBusSnapshot, Dmi,
+    MailboxRequester, SnapshotError, SocToCaliptraBus, TbServicesCb,
 };
 use caliptra_emu_types::{RvAddr, RvData, RvSize};
 use caliptra_hw_model_types::ErrorInjectionMode;
@@ -24,15 +30,21 @@ use caliptra_image_types::IMAGE_MANIFEST_BYTE_SIZE;
 
 use crate::bus_logger::BusLogger;
 use crate::bus_logger::LogFile;
+use crate::remote_bitbang::RemoteBitbangServer;
 use crate::trace_path_or_env;
 use crate::HwModel;
 use crate::InitParams;
 use crate::ModelError;
+use crate::OpenOcdError;
 use crate::Output;
 use crate::TrngMode;
 use caliptra_emu_bus::{Bus, BusMmio};
 
 use caliptra_api::soc_mgr::SocManager;
+
+/// The number of steps between polls of the JTAG remote-bitbang socket.
+const JTAG_POLL_INTERVAL: u64 = 256;
+
 pub struct EmulatedApbBus<'a> {
     model: &'a mut ModelEmulated,
 }
@@ -64,6 +76,10 @@ pub struct ModelEmulated {
     ready_for_fw: Rc<Cell<bool>>,
     cpu_enabled: Rc<Cell<bool>>,
     trace_path: Option<PathBuf>,
+    dm: DebugModule,
+    jtag_server: Option<RemoteBitbangServer>,
+    openocd: Option<OpenOcd>,
+    steps: u64,
 
     // Keep this even when not including the coverage feature to keep the
     // interface consistent
@@ -72,6 +88,154 @@ pub struct ModelEmulated {
     trng_mode: TrngMode,
 }
 
+/// Saved machine state of a [`ModelEmulated`], returned by
+/// [`ModelEmulated::snapshot`]. A snapshot can be restored any number of
+/// times, so tests can fork independent models from a common checkpoint.
+#[derive(Clone)]
+pub struct ModelSnapshot {
+    clock: ClockSnapshot,
+    cpu: CpuSnapshot,
+    bus: CaliptraRootBusSnapshot,
+    ready_for_fw: bool,
+    cpu_enabled: bool,
+    rom_image_tag: u64,
+    iccm_image_tag: Option<u64>,
+    trng_mode: TrngMode,
+}
+
+impl ModelEmulated {
+    /// Capture the CPU, clock and peripheral state of the model, including
+    /// cryptographic engine operations in flight.
+    ///
+    /// Fails if a firmware upload is in flight; step the model until it
+    /// completes and try again.
+    pub fn snapshot(&self) -> Result<ModelSnapshot, SnapshotError> {
+        Ok(ModelSnapshot {
+            clock: self.cpu.clock.snapshot(),
+            cpu: self.cpu.snapshot(),
+            bus: self.cpu.bus.bus.snapshot()?,
+            ready_for_fw: self.ready_for_fw.get(),
+            cpu_enabled: self.cpu_enabled.get(),
+            rom_image_tag: self._rom_image_tag,
+            iccm_image_tag: self.iccm_image_tag,
+            trng_mode: self.trng_mode,
+        })
+    }
+
+    /// Create a new model from `snapshot`.
+    ///
+    /// The ROM, ICCM, DCCM and TRNG mode come from the snapshot. Everything
+    /// that can't be captured, such as the log writer, the TRNG sources and
+    /// the tracing configuration, comes from `params`. The itrng source in
+    /// `params` is advanced to the position it had when the snapshot was
+    /// taken.
+    pub fn restore(snapshot: &ModelSnapshot, params: InitParams) -> Result<Self, Box<dyn Error>> {
+        let mut m = Self::new_unbooted(InitParams {
+            rom: &[],
+            iccm: &[],
+            dccm: &[],
+            ..params
+        })?;
+        m.cpu.clock.restore(&snapshot.clock);
+        m.cpu.restore(&snapshot.cpu);
+        m.cpu.bus.bus.restore(&snapshot.bus);
+        m.ready_for_fw.set(snapshot.ready_for_fw);
+        m.cpu_enabled.set(snapshot.cpu_enabled);
+        m._rom_image_tag = snapshot.rom_image_tag;
+        m.iccm_image_tag = snapshot.iccm_image_tag;
+        m.trng_mode = snapshot.trng_mode;
+        Ok(m)
+    }
+
+    /// Arm a fault in the CPU. See [`caliptra_emu_cpu::Fault`].
+    pub fn inject_fault(&mut self, fault: Fault) {
+        self.cpu.inject_fault(fault);
+    }
+
+    /// Returns where the last injected fault fired, or None if it hasn't.
+    pub fn fault_hit(&self) -> Option<FaultHit> {
+        self.cpu.fault_hit()
+    }
+
+    /// Record the PC of every instruction executed in `range`, or stop
+    /// recording if `range` is None.
+    pub fn trace_pcs(&mut self, range: Option<Range<RvAddr>>) {
+        self.cpu.trace_pcs(range);
+    }
+
+    /// Returns the PCs recorded since the last call, in execution order.
+    pub fn take_traced_pcs(&mut self) -> Vec<RvAddr> {
+        self.cpu.take_traced_pcs()
+    }
+
+    /// Start sampling the call stack every `interval` cycles, attributing
+    /// cycles to the function symbols of `elfs` (typically the ROM, FMC and
+    /// runtime ELFs).
+    pub fn start_profiling(&mut self, interval: u64, elfs: &[&[u8]]) -> std::io::Result<()> {
+        let mut profiler = Profiler::new(interval);
+        for elf in elfs {
+            for sym in caliptra_elf_symbols::elf_symbols(elf)? {
+                if sym.ty == SymbolType::Func {
+                    profiler.add_symbol(sym.name, sym.value as RvAddr, sym.size as u32);
+                }
+            }
+        }
+        self.cpu.set_profiler(profiler);
+        Ok(())
+    }
+
+    /// Stop profiling and write the samples to `w` as folded stacks, ready
+    /// for `flamegraph.pl` or `inferno-flamegraph`. Writes nothing if
+    /// profiling wasn't started.
+    pub fn stop_profiling(&mut self, w: &mut impl Write) -> std::io::Result<()> {
+        match self.cpu.take_profiler() {
+            Some(profiler) => profiler.write_folded(w),
+            None => Ok(()),
+        }
+    }
+
+    fn dmi(&mut self) -> CaliptraDmi<'_, BusLogger<CaliptraRootBus>> {
+        CaliptraDmi {
+            soc_reg: self.cpu.bus.bus.soc_reg.clone(),
+            cpu: &mut self.cpu,
+            dm: &mut self.dm,
+        }
+    }
+
+    /// Read the JTAG Debug Module Interface register at `addr`, subject to
+    /// the debug lock and lifecycle state. See [`CaliptraDmi`].
+    pub fn dmi_read(&mut self, addr: u32) -> u32 {
+        self.dmi().dmi_read(addr)
+    }
+
+    /// Write the JTAG Debug Module Interface register at `addr`, subject to
+    /// the debug lock and lifecycle state. See [`CaliptraDmi`].
+    pub fn dmi_write(&mut self, addr: u32, val: u32) {
+        self.dmi().dmi_write(addr, val)
+    }
+
+    /// Serve the JTAG port to OpenOCD on `addr` while the model is stepped.
+    /// See [`crate::remote_bitbang`].
+    pub fn serve_jtag(&mut self, addr: &str) -> std::io::Result<()> {
+        self.jtag_server = Some(RemoteBitbangServer::bind(addr)?);
+        Ok(())
+    }
+}
+
+/// An OpenOCD process started by [`ModelEmulated::launch_openocd`]
+struct OpenOcd {
+    child: Child,
+    socket: PathBuf,
+}
+
+impl Drop for OpenOcd {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_file(&self.socket);
+    }
+}
+
 #[cfg(feature = "coverage")]
 impl Drop for ModelEmulated {
     fn drop(&mut self) {
@@ -133,6 +297,80 @@ impl SocManager for ModelEmulated {
 impl HwModel for ModelEmulated {
     type TBus<'a> = EmulatedApbBus<'a>;
 
+    /// Serve the JTAG port on a Unix socket and connect OpenOCD to it with
+    /// `openocd_emulator.txt`, stepping until OpenOCD has probed the port the
+    /// same way `ModelFpgaRealtime::launch_openocd` does. OpenOCD's GDB server
+    /// then listens on port 3333 until the model is dropped, and is only
+    /// serviced while the model is stepped.
+    fn launch_openocd(&mut self) -> Result<(), OpenOcdError> {
+        static NEXT_SOCKET_ID: AtomicU32 = AtomicU32::new(0);
+
+        self.openocd = None;
+        let socket = std::env::temp_dir().join(format!(
+            "caliptra-jtag-{}-{}",
+            std::process::id(),
+            NEXT_SOCKET_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let socket_str = socket.to_str().unwrap().to_string();
+        if let Err(e) = self.serve_jtag(&socket_str) {
+            println!("Failed to serve JTAG on {socket_str}: {e}");
+            return Err(OpenOcdError::Closed);
+        }
+
+        let mut child = Command::new("openocd")
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .arg("--command")
+            .arg(format!("set _JTAG_SOCKET {{{socket_str}}}"))
+            .arg("--command")
+            .arg(include_str!("openocd_emulator.txt"))
+            .spawn()
+            .unwrap();
+        let stderr = child.stderr.take().unwrap();
+        let openocd = OpenOcd { child, socket };
+
+        // OpenOCD only gets answers while the model steps, so read its log on
+        // another thread. The thread keeps draining the log after setup so
+        // OpenOCD never blocks on a full pipe.
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                let _ = tx.send(line);
+            }
+        });
+        let mut output = String::new();
+        loop {
+            match rx.try_recv() {
+                Ok(line) => {
+                    output.push_str(&line);
+                    output.push('\n');
+                    if line.contains("OpenOCD setup finished") {
+                        break;
+                    }
+                }
+                Err(TryRecvError::Empty) => self.step(),
+                Err(TryRecvError::Disconnected) => {
+                    println!("openocd log returned EOF. Log: {output}");
+                    return Err(OpenOcdError::Closed);
+                }
+            }
+        }
+        if !output.contains("Open On-Chip Debugger 0.12.0") {
+            return Err(OpenOcdError::WrongVersion);
+        }
+        if output.contains("Caliptra not accessible") {
+            return Err(OpenOcdError::CaliptraNotAccessible);
+        }
+        if output.contains("Core not accessible") {
+            return Err(OpenOcdError::VeerNotAccessible);
+        }
+        self.openocd = Some(openocd);
+        Ok(())
+    }
+
     fn new_unbooted(params: InitParams) -> Result<Self, Box<dyn Error>>
     where
         Self: Sized,
@@ -210,6 +448,10 @@ impl HwModel for ModelEmulated {
             ready_for_fw,
             cpu_enabled,
             trace_path: trace_path_or_env(params.trace_path),
+            dm: DebugModule::new(),
+            jtag_server: None,
+            openocd: None,
+            steps: 0,
             _rom_image_tag: image_tag,
             iccm_image_tag: None,
             trng_mode,
@@ -239,6 +481,13 @@ impl HwModel for ModelEmulated {
         if self.cpu_enabled.get() {
             self.cpu.step(self.trace_fn.as_deref_mut());
         }
+        self.steps += 1;
+        if self.steps % JTAG_POLL_INTERVAL == 0 {
+            if let Some(mut server) = self.jtag_server.take() {
+                server.poll(&mut self.dmi());
+                self.jtag_server = Some(server);
+            }
+        }
     }
 
     fn output(&mut self) -> &mut Output {
@@ -303,3 +552,50 @@ impl HwModel for ModelEmulated {
         self.step();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BootParams;
+    use caliptra_builder::firmware;
+
+    #[test]
+    fn test_snapshot_restore() {
+        let message: [u8; 4] = [0x90, 0x5e, 0x1f, 0xad];
+        let echo = [[0x00, 0x00, 0x00, 0x10].as_slice(), &message].concat();
+
+        let rom =
+            caliptra_builder::build_firmware_rom(&firmware::hw_model_tests::MAILBOX_RESPONDER)
+                .unwrap();
+
+        let mut model = ModelEmulated::new(
+            InitParams {
+                rom: &rom,
+                ..Default::default()
+            },
+            BootParams::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            model.mailbox_execute(0x1000_0000, &message),
+            Ok(Some(echo.clone()))
+        );
+
+        let snapshot = model.snapshot().unwrap();
+
+        let mut forks: Vec<ModelEmulated> = (0..2)
+            .map(|_| ModelEmulated::restore(&snapshot, InitParams::default()).unwrap())
+            .collect();
+        for fork in forks.iter_mut() {
+            assert_eq!(fork.cpu.clock.now(), model.cpu.clock.now());
+            assert_eq!(
+                fork.mailbox_execute(0x1000_1000, &[]),
+                Ok(Some(vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd]))
+            );
+        }
+
+        // The forks don't affect each other or the original model.
+        assert_eq!(forks[0].cpu.clock.now(), forks[1].cpu.clock.now());
+        assert_eq!(model.mailbox_execute(0x1000_0000, &message), Ok(Some(echo)));
+    }
+}
@@ -18,15 +18,7 @@ use uio::{UioDevice, UioError};
 
 use crate::EtrngResponse;
 use crate::Output;
-use crate::{HwModel, SecurityState, SocManager, TrngMode};
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub enum OpenOcdError {
-    Closed,
-    CaliptraNotAccessible,
-    VeerNotAccessible,
-    WrongVersion,
-}
+use crate::{HwModel, OpenOcdError, SecurityState, SocManager, TrngMode};
 
 // UIO mapping indices
 const FPGA_WRAPPER_MAPPING: usize = 0;
@@ -343,6 +335,47 @@ impl SocManager for ModelFpgaRealtime {
 impl HwModel for ModelFpgaRealtime {
     type TBus<'a> = FpgaRealtimeBus<'a>;
 
+    fn launch_openocd(&mut self) -> Result<(), OpenOcdError> {
+        let _ = Command::new("sudo")
+            .arg("pkill")
+            .arg("openocd")
+            .spawn()
+            .unwrap()
+            .wait();
+
+        let mut openocd = Command::new("sudo")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .arg("openocd")
+            .arg("--command")
+            .arg(include_str!("../../hw/fpga/openocd_caliptra.txt"))
+            .spawn()
+            .unwrap();
+
+        let mut child_err = BufReader::new(openocd.stderr.as_mut().unwrap());
+        let mut output = String::new();
+        loop {
+            if 0 == child_err.read_line(&mut output).unwrap() {
+                println!("openocd log returned EOF. Log: {output}");
+                return Err(OpenOcdError::Closed);
+            }
+            if output.contains("OpenOCD setup finished") {
+                break;
+            }
+        }
+        if !output.contains("Open On-Chip Debugger 0.12.0") {
+            return Err(OpenOcdError::WrongVersion);
+        }
+        if output.contains("Caliptra not accessible") {
+            return Err(OpenOcdError::CaliptraNotAccessible);
+        }
+        if output.contains("Core not accessible") {
+            return Err(OpenOcdError::VeerNotAccessible);
+        }
+        self.openocd = Some(openocd);
+        Ok(())
+    }
+
     fn apb_bus(&mut self) -> Self::TBus<'_> {
         FpgaRealtimeBus {
             mmio: self.mmio,
@@ -530,48 +563,6 @@ impl HwModel for ModelFpgaRealtime {
     }
 }
 
-impl ModelFpgaRealtime {
-    pub fn launch_openocd(&mut self) -> Result<(), OpenOcdError> {
-        let _ = Command::new("sudo")
-            .arg("pkill")
-            .arg("openocd")
-            .spawn()
-            .unwrap()
-            .wait();
-
-        let mut openocd = Command::new("sudo")
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .arg("openocd")
-            .arg("--command")
-            .arg(include_str!("../../hw/fpga/openocd_caliptra.txt"))
-            .spawn()
-            .unwrap();
-
-        let mut child_err = BufReader::new(openocd.stderr.as_mut().unwrap());
-        let mut output = String::new();
-        loop {
-            if 0 == child_err.read_line(&mut output).unwrap() {
-                println!("openocd log returned EOF. Log: {output}");
-                return Err(OpenOcdError::Closed);
-            }
-            if output.contains("OpenOCD setup finished") {
-                break;
-            }
-        }
-        if !output.contains("Open On-Chip Debugger 0.12.0") {
-            return Err(OpenOcdError::WrongVersion);
-        }
-        if output.contains("Caliptra not accessible") {
-            return Err(OpenOcdError::CaliptraNotAccessible);
-        }
-        if output.contains("Core not accessible") {
-            return Err(OpenOcdError::VeerNotAccessible);
-        }
-        self.openocd = Some(openocd);
-        Ok(())
-    }
-}
 impl Drop for ModelFpgaRealtime {
     fn drop(&mut self) {
         // Ask the realtime thread to exit and wait for it to finish
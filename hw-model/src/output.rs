@@ -1,5 +1,6 @@
 // Licensed under the Apache-2.0 license
 
+use crate::MailboxTracer;
 use std::fmt::Display;
 use std::io::LineWriter;
 use std::{
@@ -163,6 +164,8 @@ pub struct Output {
     search_term: Option<String>,
     search_pos: usize, // Position to start searching from
     search_matched: bool,
+
+    mbox_tracer: Option<MailboxTracer>,
 }
 impl Output {
     pub fn new(log_writer: impl std::io::Write + 'static) -> Self {
@@ -182,6 +185,7 @@ impl Output {
             search_term: None,
             search_pos: 0,
             search_matched: false,
+            mbox_tracer: None,
         }
     }
     pub fn sink(&self) -> &OutputSink {
@@ -191,6 +195,14 @@ impl Output {
         &self.sink
     }
 
+    /// Record every mailbox transaction to `tracer` (or stop tracing if None).
+    pub fn set_mailbox_tracer(&mut self, tracer: Option<MailboxTracer>) {
+        self.mbox_tracer = tracer;
+    }
+    pub(crate) fn mailbox_tracer(&mut self) -> Option<&mut MailboxTracer> {
+        self.mbox_tracer.as_mut()
+    }
+
     /// Peek at all the output captured so far
     pub fn peek(&mut self) -> &str {
         self.process_new_data();
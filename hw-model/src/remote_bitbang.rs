@@ -0,0 +1,248 @@
+// Licensed under the Apache-2.0 license
+
+//! OpenOCD `remote_bitbang` server for the emulated JTAG port.
+//!
+//! `caliptra-emu --jtag-bitbang <ADDR>` and [`ModelEmulated::serve_jtag`]
+//! (which [`ModelEmulated::launch_openocd`] uses) accept an OpenOCD connection on `ADDR`, either a TCP socket address
+//! (`127.0.0.1:44853`) or a Unix socket path. Each byte sent by OpenOCD is
+//! one command:
+//!
+//! | Command         | Action                                          |
+//! |-----------------|-------------------------------------------------|
+//! | `'0'`..=`'7'`   | set TCK (4), TMS (2) and TDI (1)                |
+//! | `'R'`           | reply with the value of TDO, `'0'` or `'1'`     |
+//! | `'r'`..=`'u'`   | set TRST (2) and SRST (1); TRST resets the TAP  |
+//! | `'B'`, `'b'`    | blink the LED on or off (ignored)               |
+//! | `'Q'`           | close the connection                            |
+//!
+//! An OpenOCD configuration for the emulator:
+//!
+//! ```text
+//! adapter driver remote_bitbang
+//! remote_bitbang host localhost
+//! remote_bitbang port 44853
+//! transport select jtag
+//! jtag newtap riscv cpu -irlen 5
+//! target create riscv.cpu riscv -chain-position riscv.cpu
+//! init
+//! ```
+//!
+//! [`ModelEmulated::serve_jtag`]: crate::ModelEmulated::serve_jtag
+//! [`ModelEmulated::launch_openocd`]: crate::ModelEmulated::launch_openocd
+
+use std::io::{self, ErrorKind, Read, Write};
+
+use caliptra_emu_periph::{Dmi, JtagTap};
+
+use crate::soc_socket::{SocketListener, SocketStream};
+
+/// Serves one OpenOCD client at a time. The emulator calls `poll()` between
+/// CPU steps, so the debugger's accesses are interleaved with firmware
+/// execution.
+pub struct RemoteBitbangServer {
+    listener: SocketListener,
+    client: Option<SocketStream>,
+    tap: JtagTap,
+    tck: bool,
+}
+
+impl RemoteBitbangServer {
+    pub fn bind(addr: &str) -> io::Result<Self> {
+        let listener = SocketListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self {
+            listener,
+            client: None,
+            tap: JtagTap::new(),
+            tck: false,
+        })
+    }
+
+    /// Accepts a client if none is connected and executes any commands it
+    /// has sent against `dmi`. Never blocks waiting for the client.
+    pub fn poll(&mut self, dmi: &mut impl Dmi) {
+        if self.client.is_none() {
+            match self.listener.accept() {
+                Ok(stream) => {
+                    if let Err(e) = stream.set_nonblocking(true) {
+                        println!("Failed to configure JTAG client: {e}");
+                        return;
+                    }
+                    self.tap.reset();
+                    self.tck = false;
+                    self.client = Some(stream);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return,
+                Err(e) => {
+                    println!("Failed to accept JTAG client: {e}");
+                    return;
+                }
+            }
+        }
+        if let Err(e) = self.serve_client(dmi) {
+            if e.kind() != ErrorKind::UnexpectedEof {
+                println!("JTAG client error: {e}");
+            }
+            self.client = None;
+        }
+    }
+
+    fn serve_client(&mut self, dmi: &mut impl Dmi) -> io::Result<()> {
+        let Some(client) = self.client.as_mut() else {
+            return Ok(());
+        };
+        loop {
+            let mut buf = [0u8; 256];
+            let len = match client.read(&mut buf) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(len) => len,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            let mut resp = vec![];
+            for &cmd in &buf[..len] {
+                match cmd {
+                    b'0'..=b'7' => {
+                        let bits = cmd - b'0';
+                        let tck = bits & 4 != 0;
+                        if tck && !self.tck {
+                            self.tap.clock(bits & 2 != 0, bits & 1 != 0, dmi);
+                        }
+                        self.tck = tck;
+                    }
+                    b'R' => resp.push(if self.tap.tdo() { b'1' } else { b'0' }),
+                    b'r'..=b'u' => {
+                        if (cmd - b'r') & 2 != 0 {
+                            self.tap.reset();
+                        }
+                    }
+                    b'B' | b'b' => {}
+                    b'Q' => return Err(ErrorKind::UnexpectedEof.into()),
+                    _ => {
+                        return Err(io::Error::new(
+                            ErrorKind::InvalidData,
+                            format!("unknown remote_bitbang command 0x{cmd:02x}"),
+                        ))
+                    }
+                }
+            }
+            if !resp.is_empty() {
+                // Responses are tiny; block until they have been sent.
+                client.set_nonblocking(false)?;
+                client.write_all(&resp)?;
+                client.set_nonblocking(true)?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::os::unix::net::UnixStream;
+
+    #[derive(Default)]
+    struct FakeDmi(HashMap<u32, u32>);
+
+    impl Dmi for FakeDmi {
+        fn dmi_read(&mut self, addr: u32) -> u32 {
+            self.0.get(&addr).copied().unwrap_or(0)
+        }
+        fn dmi_write(&mut self, addr: u32, val: u32) {
+            self.0.insert(addr, val);
+        }
+    }
+
+    /// Appends the commands for one TCK cycle, sampling TDO first if `read`
+    fn clock(cmds: &mut Vec<u8>, tms: bool, tdi: bool, read: bool) {
+        let bits = (u8::from(tms) << 1) | u8::from(tdi);
+        cmds.push(b'0' + bits);
+        if read {
+            cmds.push(b'R');
+        }
+        cmds.push(b'4' + bits);
+    }
+
+    /// Appends the commands to shift `len` bits of `val` from Run-Test/Idle
+    /// through Shift-IR (or Shift-DR) and back, sampling every TDO bit.
+    fn scan(cmds: &mut Vec<u8>, ir: bool, val: u64, len: u32) {
+        clock(cmds, true, false, false);
+        if ir {
+            clock(cmds, true, false, false);
+        }
+        clock(cmds, false, false, false);
+        clock(cmds, false, false, false);
+        for i in 0..len {
+            clock(cmds, i == len - 1, (val >> i) & 1 != 0, true);
+        }
+        clock(cmds, true, false, false);
+        clock(cmds, false, false, false);
+    }
+
+    fn scan_dr(client: &mut UnixStream, val: u64, len: u32) -> u64 {
+        let mut cmds = vec![];
+        scan(&mut cmds, false, val, len);
+        client.write_all(&cmds).unwrap();
+        let mut tdo = vec![0; len as usize];
+        client.read_exact(&mut tdo).unwrap();
+        tdo.iter()
+            .enumerate()
+            .map(|(i, &bit)| u64::from(bit == b'1') << i)
+            .sum()
+    }
+
+    #[test]
+    fn test_dmi_access_over_socket() {
+        let path = std::env::temp_dir().join(format!(
+            "caliptra-remote-bitbang-test-{}",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap().to_string();
+        let mut server = RemoteBitbangServer::bind(&path).unwrap();
+
+        let client = std::thread::spawn({
+            let path = path.clone();
+            move || {
+                let mut client = UnixStream::connect(path).unwrap();
+                let dmi_scan = |addr: u64, data: u64, op: u64| (addr << 34) | (data << 2) | op;
+
+                // Test-Logic-Reset -> Run-Test/Idle
+                let mut cmds = vec![];
+                for tms in [true, true, true, true, true, false] {
+                    clock(&mut cmds, tms, false, false);
+                }
+                client.write_all(&cmds).unwrap();
+                // IDCODE is selected after reset
+                assert_eq!(scan_dr(&mut client, 0, 32), 0x1000_0001);
+
+                let mut cmds = vec![];
+                scan(&mut cmds, true, 0x11, 5);
+                client.write_all(&cmds).unwrap();
+                let mut tdo = [0; 5];
+                client.read_exact(&mut tdo).unwrap();
+                // Capture-IR loads 0b00001
+                assert_eq!(&tdo, b"10000");
+
+                scan_dr(&mut client, dmi_scan(0x60, 0x1234_5678, 2), 41);
+                scan_dr(&mut client, dmi_scan(0x60, 0, 1), 41);
+                assert_eq!(scan_dr(&mut client, 0, 41), dmi_scan(0x60, 0x1234_5678, 0));
+
+                client.write_all(b"Q").unwrap();
+            }
+        });
+
+        let mut dmi = FakeDmi::default();
+        while !client.is_finished() {
+            server.poll(&mut dmi);
+        }
+        client.join().unwrap();
+        // Handle the quit command if it arrived after the last poll.
+        server.poll(&mut dmi);
+        assert!(server.client.is_none());
+        assert_eq!(dmi.0.get(&0x60), Some(&0x1234_5678));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,479 @@
+// Licensed under the Apache-2.0 license
+
+//! SoC register access over a stream socket.
+//!
+//! `caliptra-emu --mbox-socket <ADDR>` serves the SoC-facing APB bus (the
+//! mailbox, the SHA-512 accelerator and the `soc_ifc` registers) so software
+//! in another process can drive the emulated Caliptra through
+//! [`SocketSocManager`]. `ADDR` is either a TCP socket address
+//! (`127.0.0.1:6000`) or a Unix socket path.
+//!
+//! Every request is answered by exactly one response; all fields are
+//! little-endian.
+//!
+//! Request (12 bytes):
+//!
+//! | Offset | Size | Field                                  |
+//! |--------|------|----------------------------------------|
+//! | 0      | 1    | op: 1 = read, 2 = write                |
+//! | 1      | 1    | access size in bytes: 1, 2 or 4        |
+//! | 2      | 2    | reserved                               |
+//! | 4      | 4    | address                                |
+//! | 8      | 4    | value to write (ignored for reads)     |
+//!
+//! Response (8 bytes):
+//!
+//! | Offset | Size | Field                                  |
+//! |--------|------|----------------------------------------|
+//! | 0      | 1    | status (see [`SocketStatus`])          |
+//! | 1      | 3    | reserved                               |
+//! | 4      | 4    | value read (0 for writes)              |
+
+use std::cell::RefCell;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::time::Duration;
+
+use caliptra_api::SocManager;
+use caliptra_emu_bus::{Bus, BusError};
+use caliptra_emu_types::{RvAddr, RvData, RvSize};
+
+use crate::ModelError;
+
+pub const REQUEST_LEN: usize = 12;
+pub const RESPONSE_LEN: usize = 8;
+
+const OP_READ: u8 = 1;
+const OP_WRITE: u8 = 2;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SocketStatus {
+    Success = 0,
+    LoadAccessFault = 1,
+    LoadAddrMisaligned = 2,
+    StoreAccessFault = 3,
+    StoreAddrMisaligned = 4,
+    /// Unknown op or access size
+    InvalidRequest = 0xff,
+}
+
+impl From<BusError> for SocketStatus {
+    fn from(err: BusError) -> Self {
+        match err {
+            BusError::LoadAccessFault | BusError::InstrAccessFault => Self::LoadAccessFault,
+            BusError::LoadAddrMisaligned => Self::LoadAddrMisaligned,
+            BusError::StoreAccessFault => Self::StoreAccessFault,
+            BusError::StoreAddrMisaligned => Self::StoreAddrMisaligned,
+        }
+    }
+}
+
+/// A connected Unix or TCP stream.
+pub enum SocketStream {
+    Tcp(TcpStream),
+    Unix(UnixStream),
+}
+
+impl SocketStream {
+    /// Connects to a TCP socket address or, if `addr` isn't one, a Unix
+    /// socket path.
+    pub fn connect(addr: &str) -> io::Result<Self> {
+        match addr.parse::<SocketAddr>() {
+            Ok(addr) => {
+                let stream = TcpStream::connect(addr)?;
+                // Every request is a single small frame
+                stream.set_nodelay(true)?;
+                Ok(Self::Tcp(stream))
+            }
+            Err(_) => Ok(Self::Unix(UnixStream::connect(addr)?)),
+        }
+    }
+
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        match self {
+            Self::Tcp(s) => s.set_nonblocking(nonblocking),
+            Self::Unix(s) => s.set_nonblocking(nonblocking),
+        }
+    }
+}
+
+impl Read for SocketStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Tcp(s) => s.read(buf),
+            Self::Unix(s) => s.read(buf),
+        }
+    }
+}
+
+impl Write for SocketStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Tcp(s) => s.write(buf),
+            Self::Unix(s) => s.write(buf),
+        }
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Tcp(s) => s.flush(),
+            Self::Unix(s) => s.flush(),
+        }
+    }
+}
+
+/// A listening Unix or TCP socket.
+pub enum SocketListener {
+    Tcp(TcpListener),
+    Unix(UnixListener),
+}
+
+impl SocketListener {
+    /// Binds to a TCP socket address or, if `addr` isn't one, a Unix socket
+    /// path. A stale Unix socket left at the path is replaced.
+    pub fn bind(addr: &str) -> io::Result<Self> {
+        match addr.parse::<SocketAddr>() {
+            Ok(addr) => Ok(Self::Tcp(TcpListener::bind(addr)?)),
+            Err(_) => {
+                if std::fs::metadata(addr).map_or(false, |m| m.file_type().is_socket()) {
+                    std::fs::remove_file(addr)?;
+                }
+                Ok(Self::Unix(UnixListener::bind(addr)?))
+            }
+        }
+    }
+
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        match self {
+            Self::Tcp(l) => l.set_nonblocking(nonblocking),
+            Self::Unix(l) => l.set_nonblocking(nonblocking),
+        }
+    }
+
+    pub fn accept(&self) -> io::Result<SocketStream> {
+        match self {
+            Self::Tcp(l) => {
+                let (stream, _) = l.accept()?;
+                stream.set_nodelay(true)?;
+                Ok(SocketStream::Tcp(stream))
+            }
+            Self::Unix(l) => Ok(SocketStream::Unix(l.accept()?.0)),
+        }
+    }
+}
+
+fn access_size(size: u8) -> Option<RvSize> {
+    match RvSize::from(size as usize) {
+        RvSize::Invalid => None,
+        size => Some(size),
+    }
+}
+
+/// Executes a single request against `bus` and returns the response frame.
+pub fn handle_request(bus: &mut impl Bus, req: &[u8; REQUEST_LEN]) -> [u8; RESPONSE_LEN] {
+    let addr = RvAddr::from_le_bytes(req[4..8].try_into().unwrap());
+    let val = RvData::from_le_bytes(req[8..12].try_into().unwrap());
+    let result = match (req[0], access_size(req[1])) {
+        (OP_READ, Some(size)) => bus.read(size, addr).map_err(SocketStatus::from),
+        (OP_WRITE, Some(size)) => bus
+            .write(size, addr, val)
+            .map(|_| 0)
+            .map_err(SocketStatus::from),
+        _ => Err(SocketStatus::InvalidRequest),
+    };
+    let (status, val) = match result {
+        Ok(val) => (SocketStatus::Success, val),
+        Err(status) => (status, 0),
+    };
+    let mut resp = [0u8; RESPONSE_LEN];
+    resp[0] = status as u8;
+    resp[4..8].copy_from_slice(&val.to_le_bytes());
+    resp
+}
+
+/// Client side of the protocol, presented as the SoC's view of the bus.
+///
+/// A socket I/O error is reported as an access fault and kept in `error`;
+/// once an error is kept, later accesses fault without using the socket.
+pub struct SocketBus<'a> {
+    stream: &'a mut SocketStream,
+    error: &'a mut Option<io::Error>,
+}
+
+impl SocketBus<'_> {
+    fn transact(
+        &mut self,
+        op: u8,
+        size: RvSize,
+        addr: RvAddr,
+        val: RvData,
+    ) -> io::Result<(u8, RvData)> {
+        let mut req = [0u8; REQUEST_LEN];
+        req[0] = op;
+        req[1] = usize::from(size) as u8;
+        req[4..8].copy_from_slice(&addr.to_le_bytes());
+        req[8..12].copy_from_slice(&val.to_le_bytes());
+        let mut resp = [0u8; RESPONSE_LEN];
+        self.stream.write_all(&req)?;
+        self.stream.read_exact(&mut resp)?;
+        Ok((
+            resp[0],
+            RvData::from_le_bytes(resp[4..8].try_into().unwrap()),
+        ))
+    }
+
+    fn checked_transact(
+        &mut self,
+        op: u8,
+        size: RvSize,
+        addr: RvAddr,
+        val: RvData,
+    ) -> Option<(u8, RvData)> {
+        if self.error.is_some() {
+            return None;
+        }
+        match self.transact(op, size, addr, val) {
+            Ok(resp) => Some(resp),
+            Err(e) => {
+                *self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+impl Bus for SocketBus<'_> {
+    fn read(&mut self, size: RvSize, addr: RvAddr) -> Result<RvData, BusError> {
+        match self.checked_transact(OP_READ, size, addr, 0) {
+            Some((status, val)) if status == SocketStatus::Success as u8 => Ok(val),
+            Some((status, _)) if status == SocketStatus::LoadAddrMisaligned as u8 => {
+                Err(BusError::LoadAddrMisaligned)
+            }
+            _ => Err(BusError::LoadAccessFault),
+        }
+    }
+
+    fn write(&mut self, size: RvSize, addr: RvAddr, val: RvData) -> Result<(), BusError> {
+        match self.checked_transact(OP_WRITE, size, addr, val) {
+            Some((status, _)) if status == SocketStatus::Success as u8 => Ok(()),
+            Some((status, _)) if status == SocketStatus::StoreAddrMisaligned as u8 => {
+                Err(BusError::StoreAddrMisaligned)
+            }
+            _ => Err(BusError::StoreAccessFault),
+        }
+    }
+}
+
+/// [`ureg::Mmio`] over a [`SocketBus`]. Unlike [`caliptra_emu_bus::BusMmio`],
+/// a socket I/O error doesn't panic: reads return 0, writes are dropped and
+/// the error is returned by [`SocketSocManager::take_error`]. Faults reported
+/// by the emulator still panic.
+pub struct SocketMmio<'a> {
+    bus: RefCell<SocketBus<'a>>,
+}
+
+impl SocketMmio<'_> {
+    fn check(&self, result: Result<RvData, BusError>) -> RvData {
+        match result {
+            Ok(val) => val,
+            Err(_) if self.bus.borrow().error.is_some() => 0,
+            Err(e) => panic!("SoC socket access faulted: {e:?}"),
+        }
+    }
+}
+
+impl ureg::Mmio for SocketMmio<'_> {
+    unsafe fn read_volatile<T: Clone + Copy + Sized>(&self, src: *const T) -> T {
+        let result = self
+            .bus
+            .borrow_mut()
+            .read(rvsize::<T>(), src as usize as u32);
+        let val = self.check(result);
+        match std::mem::size_of::<T>() {
+            1 => std::mem::transmute_copy::<u8, T>(&(val as u8)),
+            2 => std::mem::transmute_copy::<u16, T>(&(val as u16)),
+            4 => std::mem::transmute_copy::<u32, T>(&val),
+            _ => panic!("Unsupported read size"),
+        }
+    }
+}
+
+impl ureg::MmioMut for SocketMmio<'_> {
+    unsafe fn write_volatile<T: Clone + Copy>(&self, dst: *mut T, src: T) {
+        let val = match std::mem::size_of::<T>() {
+            1 => std::mem::transmute_copy::<T, u8>(&src).into(),
+            2 => std::mem::transmute_copy::<T, u16>(&src).into(),
+            4 => std::mem::transmute_copy::<T, u32>(&src),
+            _ => panic!("Unsupported write size"),
+        };
+        let result = self
+            .bus
+            .borrow_mut()
+            .write(rvsize::<T>(), dst as usize as u32, val);
+        self.check(result.map(|_| 0));
+    }
+}
+
+fn rvsize<T>() -> RvSize {
+    match std::mem::size_of::<T>() {
+        1 => RvSize::Byte,
+        2 => RvSize::HalfWord,
+        4 => RvSize::Word,
+        other => panic!("Unsupported RvSize: {other}"),
+    }
+}
+
+/// A [`SocManager`] for a Caliptra emulator running in another process with
+/// `--mbox-socket`.
+///
+/// If the socket fails, every access fails from then on and [`SocManager`]
+/// calls return errors such as `MailboxTimeout`; use
+/// [`SocketSocManager::take_error`] to get the underlying I/O error.
+pub struct SocketSocManager {
+    stream: SocketStream,
+    error: Option<io::Error>,
+}
+
+impl SocketSocManager {
+    /// Time to wait between mailbox status polls.
+    const POLL_DELAY: Duration = Duration::from_micros(10);
+
+    pub fn connect(addr: &str) -> io::Result<Self> {
+        Ok(Self {
+            stream: SocketStream::connect(addr)?,
+            error: None,
+        })
+    }
+
+    /// Returns the socket I/O error that failed an access, if any, and
+    /// clears it.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+}
+
+impl From<io::Error> for ModelError {
+    fn from(error: io::Error) -> Self {
+        ModelError::SocketError(error.kind())
+    }
+}
+
+impl SocManager for SocketSocManager {
+    type TMmio<'a> = SocketMmio<'a>;
+
+    fn delay(&mut self) {
+        // Polling a failed socket can't succeed; let the caller time out
+        // without waiting.
+        if self.error.is_none() {
+            std::thread::sleep(Self::POLL_DELAY);
+        }
+    }
+
+    fn mmio_mut(&mut self) -> Self::TMmio<'_> {
+        SocketMmio {
+            bus: RefCell::new(SocketBus {
+                stream: &mut self.stream,
+                error: &mut self.error,
+            }),
+        }
+    }
+
+    const SOC_IFC_ADDR: u32 = 0x3003_0000;
+    const SOC_IFC_TRNG_ADDR: u32 = 0x3003_0000;
+    const SOC_SHA512_ACC_ADDR: u32 = 0x3002_1000;
+    const SOC_MBOX_ADDR: u32 = 0x3002_0000;
+
+    // Roughly a minute of polling
+    const MAX_WAIT_CYCLES: u32 = 5_000_000;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use caliptra_emu_bus::Ram;
+
+    fn serve(mut stream: SocketStream, mut bus: Ram) {
+        let mut req = [0u8; REQUEST_LEN];
+        while stream.read_exact(&mut req).is_ok() {
+            let resp = handle_request(&mut bus, &req);
+            stream.write_all(&resp).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_socket_bus_round_trip() {
+        let (client, server) = UnixStream::pair().unwrap();
+        let server =
+            std::thread::spawn(move || serve(SocketStream::Unix(server), Ram::new(vec![0; 16])));
+
+        let mut client = SocketStream::Unix(client);
+        let mut error = None;
+        let mut bus = SocketBus {
+            stream: &mut client,
+            error: &mut error,
+        };
+        bus.write(RvSize::Word, 4, 0x1234_5678).unwrap();
+        bus.write(RvSize::Byte, 8, 0xab).unwrap();
+        assert_eq!(bus.read(RvSize::Word, 4), Ok(0x1234_5678));
+        assert_eq!(bus.read(RvSize::HalfWord, 4), Ok(0x5678));
+        assert_eq!(bus.read(RvSize::Word, 8), Ok(0xab));
+        assert_eq!(bus.read(RvSize::Word, 16), Err(BusError::LoadAccessFault));
+        assert_eq!(
+            bus.write(RvSize::Word, 16, 0),
+            Err(BusError::StoreAccessFault)
+        );
+
+        assert!(error.is_none());
+        drop(client);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_socket_io_error() {
+        let (client, server) = UnixStream::pair().unwrap();
+        drop(server);
+
+        let mut soc = SocketSocManager {
+            stream: SocketStream::Unix(client),
+            error: None,
+        };
+        let mut bus = SocketBus {
+            stream: &mut soc.stream,
+            error: &mut soc.error,
+        };
+        assert_eq!(bus.read(RvSize::Word, 0), Err(BusError::LoadAccessFault));
+        assert_eq!(
+            bus.write(RvSize::Word, 0, 0),
+            Err(BusError::StoreAccessFault)
+        );
+
+        // Mailbox commands fail instead of panicking.
+        assert!(soc.get_capabilities().is_err());
+        let error = soc.take_error().unwrap();
+        assert_eq!(
+            ModelError::from(error),
+            ModelError::SocketError(io::ErrorKind::BrokenPipe)
+        );
+        assert!(soc.take_error().is_none());
+    }
+
+    #[test]
+    fn test_invalid_request() {
+        let mut bus = Ram::new(vec![0; 4]);
+        let mut req = [0u8; REQUEST_LEN];
+        req[0] = 7;
+        req[1] = 4;
+        assert_eq!(
+            handle_request(&mut bus, &req)[0],
+            SocketStatus::InvalidRequest as u8
+        );
+        req[0] = OP_READ;
+        req[1] = 3;
+        assert_eq!(
+            handle_request(&mut bus, &req)[0],
+            SocketStatus::InvalidRequest as u8
+        );
+    }
+}
@@ -133,6 +133,8 @@ impl std::fmt::Debug for FusesWrapper {
             .field("lms_verify", &self.0.lms_verify)
             .field("fuse_lms_revocation", &self.0.fuse_lms_revocation)
             .field("soc_stepping_id", &self.0.soc_stepping_id)
+            .field("mldsa_verify", &self.0.mldsa_verify)
+            .field("fuse_mldsa_revocation", &self.0.fuse_mldsa_revocation)
             .finish()
     }
 }
@@ -18,9 +18,14 @@ use anyhow::anyhow;
 use anyhow::Context;
 use caliptra_image_crypto::lms_priv_key_from_pem;
 use caliptra_image_crypto::lms_pub_key_from_pem;
-#[cfg(feature = "openssl")]
+use caliptra_image_crypto::mldsa_priv_key_from_file;
+use caliptra_image_crypto::mldsa_pub_key_from_file;
+use caliptra_image_crypto::EccPrivKeyLoader;
+#[cfg(all(feature = "openssl", not(feature = "pkcs11")))]
 use caliptra_image_crypto::OsslCrypto as Crypto;
-#[cfg(feature = "rustcrypto")]
+#[cfg(feature = "pkcs11")]
+use caliptra_image_crypto::Pkcs11Crypto as Crypto;
+#[cfg(all(feature = "rustcrypto", not(feature = "pkcs11")))]
 use caliptra_image_crypto::RustCrypto as Crypto;
 use caliptra_image_gen::*;
 use caliptra_image_serde::ImageBundleWriter;
@@ -28,6 +33,7 @@ use caliptra_image_types::*;
 use clap::ArgMatches;
 use std::path::Path;
 use std::path::PathBuf;
+use zerocopy::IntoBytes;
 
 use caliptra_image_elf::ElfExecutable;
 use config::{OwnerKeyConfig, VendorKeyConfig};
@@ -72,6 +78,25 @@ fn check_date(from_date: &str, to_date: &str) -> anyhow::Result<bool> {
     Ok(true)
 }
 
+/// Parse a `<ID>:<FILE>` data argument. The ID may be decimal or `0x` prefixed hex.
+fn parse_data_arg(arg: &str) -> anyhow::Result<ImageGeneratorData> {
+    let (id, path) = arg
+        .split_once(':')
+        .with_context(|| format!("Invalid data arg {arg}, expected <ID>:<FILE>"))?;
+    let id = match id.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => id.parse(),
+    }
+    .with_context(|| format!("Invalid data id {id}"))?;
+    let content =
+        std::fs::read(path).with_context(|| format!("Failed to read data file {path}"))?;
+    Ok(ImageGeneratorData {
+        id,
+        version: 0,
+        content,
+    })
+}
+
 /// Run the command
 pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
     let config_path: &PathBuf = args
@@ -118,6 +143,9 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
         .get_one::<u32>("lms-pk-idx")
         .with_context(|| "lms-pk-idx arg not specified")?;
 
+    // ML-DSA keys are optional
+    let mldsa_key_idx = args.get_one::<u32>("mldsa-pk-idx").copied().unwrap_or(0);
+
     let out_path: &PathBuf = args
         .get_one::<PathBuf>("out")
         .with_context(|| "out arg not specified")?;
@@ -166,25 +194,44 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
         runtime_rev[..IMAGE_REVISION_BYTE_SIZE].try_into()?,
     )?;
 
+    let compress_runtime = args.get_flag("rt-compress");
+
+    let data = args
+        .get_many::<String>("data")
+        .map(|data| data.map(|arg| parse_data_arg(arg)).collect())
+        .unwrap_or_else(|| Ok(vec![]))?;
+
     let config_dir = config_path
         .parent()
         .with_context(|| "Invalid parent path")?;
 
+    let mut crypto = Crypto::default();
+
     let gen_config = ImageGeneratorConfig::<ElfExecutable> {
         vendor_config: vendor_config(
+            &mut crypto,
             config_dir,
             &config.vendor,
             *ecc_key_idx,
             *lms_key_idx,
+            mldsa_key_idx,
             mfg_from_date,
             mfg_to_date,
         )?,
-        owner_config: owner_config(config_dir, &config.owner, own_from_date, own_to_date)?,
+        owner_config: owner_config(
+            &mut crypto,
+            config_dir,
+            &config.owner,
+            own_from_date,
+            own_to_date,
+        )?,
         fmc,
         runtime,
+        data,
+        compress_runtime,
     };
 
-    let gen = ImageGenerator::new(Crypto::default());
+    let gen = ImageGenerator::new(crypto);
     let image = gen.generate(&gen_config).unwrap();
 
     let out_file = std::fs::OpenOptions::new()
@@ -201,11 +248,14 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
 }
 
 /// Generate Vendor Config
+#[allow(clippy::too_many_arguments)]
 fn vendor_config(
+    crypto: &mut Crypto,
     path: &Path,
     config: &VendorKeyConfig,
     ecc_key_idx: u32,
     lms_key_idx: u32,
+    mldsa_key_idx: u32,
     from_date: [u8; 15],
     to_date: [u8; 15],
 ) -> anyhow::Result<ImageGeneratorVendorConfig> {
@@ -232,15 +282,29 @@ fn vendor_config(
         gen_config.pub_keys.lms_pub_keys[i] = lms_pub_key_from_pem(&pub_key_path)?;
     }
 
+    if let Some(mldsa_pub_keys) = &config.mldsa_pub_keys {
+        for (i, file) in mldsa_pub_keys
+            .iter()
+            .enumerate()
+            .take(VENDOR_MLDSA_KEY_COUNT as usize)
+        {
+            let pub_key_path = path.join(file);
+            let pub_key = mldsa_pub_key_from_file(&pub_key_path)?;
+            gen_config.pub_keys.mldsa_pub_key_digests[i] =
+                crypto.sha384_digest(pub_key.as_bytes())?;
+            gen_config.mldsa_pub_keys[i] = pub_key;
+        }
+    }
+
     let mut priv_keys = ImageVendorPrivKeys::default();
     if let Some(ecc_priv_keys) = &config.ecc_priv_keys {
-        for (i, pem_file) in ecc_priv_keys
+        for (i, key) in ecc_priv_keys
             .iter()
             .enumerate()
             .take(VENDOR_ECC_KEY_COUNT as usize)
         {
-            let priv_key_path = path.join(pem_file);
-            priv_keys.ecc_priv_keys[i] = Crypto::ecc_priv_key_from_pem(&priv_key_path)?;
+            priv_keys.ecc_priv_keys[i] =
+                crypto.load_ecc_priv_key(path, key, &gen_config.pub_keys.ecc_pub_keys[i])?;
         }
         gen_config.priv_keys = Some(priv_keys);
     }
@@ -257,8 +321,21 @@ fn vendor_config(
         gen_config.priv_keys = Some(priv_keys);
     }
 
+    if let Some(mldsa_priv_keys) = &config.mldsa_priv_keys {
+        for (i, file) in mldsa_priv_keys
+            .iter()
+            .enumerate()
+            .take(VENDOR_MLDSA_KEY_COUNT as usize)
+        {
+            let priv_key_path = path.join(file);
+            priv_keys.mldsa_priv_keys[i] = mldsa_priv_key_from_file(&priv_key_path)?;
+        }
+        gen_config.priv_keys = Some(priv_keys);
+    }
+
     gen_config.ecc_key_idx = ecc_key_idx;
     gen_config.lms_key_idx = lms_key_idx;
+    gen_config.mldsa_key_idx = mldsa_key_idx;
     gen_config.not_before = from_date;
     gen_config.not_after = to_date;
 
@@ -267,6 +344,7 @@ fn vendor_config(
 
 /// Generate owner config
 fn owner_config(
+    crypto: &mut Crypto,
     path: &Path,
     config: &Option<OwnerKeyConfig>,
     from_date: [u8; 15],
@@ -284,10 +362,17 @@ fn owner_config(
         let pub_key_path = path.join(pem_file);
         gen_config.pub_keys.lms_pub_key = lms_pub_key_from_pem(&pub_key_path)?;
 
+        if let Some(file) = &config.mldsa_pub_key {
+            let pub_key_path = path.join(file);
+            gen_config.mldsa_pub_key = mldsa_pub_key_from_file(&pub_key_path)?;
+            gen_config.pub_keys.mldsa_pub_key_digest =
+                crypto.sha384_digest(gen_config.mldsa_pub_key.as_bytes())?;
+        }
+
         let mut priv_keys = ImageOwnerPrivKeys::default();
-        if let Some(pem_file) = &config.ecc_priv_key {
-            let pub_key_path = path.join(pem_file);
-            priv_keys.ecc_priv_key = Crypto::ecc_priv_key_from_pem(&pub_key_path)?;
+        if let Some(key) = &config.ecc_priv_key {
+            priv_keys.ecc_priv_key =
+                crypto.load_ecc_priv_key(path, key, &gen_config.pub_keys.ecc_pub_key)?;
             gen_config.priv_keys = Some(priv_keys);
         }
 
@@ -296,6 +381,12 @@ fn owner_config(
             priv_keys.lms_priv_key = lms_priv_key_from_pem(&priv_key_path)?;
             gen_config.priv_keys = Some(priv_keys);
         }
+
+        if let Some(file) = &config.mldsa_priv_key {
+            let priv_key_path = path.join(file);
+            priv_keys.mldsa_priv_key = mldsa_priv_key_from_file(&priv_key_path)?;
+            gen_config.priv_keys = Some(priv_keys);
+        }
         gen_config.not_before = from_date;
         gen_config.not_after = to_date;
 
@@ -0,0 +1,240 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   detached.rs
+
+Abstract:
+
+    File contains the commands to sign an image outside of this tool.
+
+--*/
+
+use anyhow::{anyhow, Context};
+#[cfg(all(feature = "openssl", not(feature = "pkcs11")))]
+use caliptra_image_crypto::OsslCrypto as Crypto;
+#[cfg(feature = "pkcs11")]
+use caliptra_image_crypto::Pkcs11Crypto as Crypto;
+#[cfg(all(feature = "rustcrypto", not(feature = "pkcs11")))]
+use caliptra_image_crypto::RustCrypto as Crypto;
+use caliptra_image_crypto::{ecc_sig_from_file, lms_sig_from_file, mldsa_sig_from_file, write_tbs};
+use caliptra_image_gen::{DetachedSignatures, ImageGenerator};
+use caliptra_image_types::*;
+use clap::ArgMatches;
+use std::path::PathBuf;
+use zerocopy::{FromBytes, IntoBytes};
+
+/// Run the export-tbs command
+pub(crate) fn run_export_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image = read_image(args)?;
+    let (manifest, _) = read_manifest(&image)?;
+
+    let out_dir: &PathBuf = args
+        .get_one::<PathBuf>("out-dir")
+        .with_context(|| "out-dir arg not specified")?;
+
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("Failed to create directory {}", out_dir.display()))?;
+
+    let gen = ImageGenerator::new(Crypto::default());
+    let tbs = gen.to_be_signed(&manifest)?;
+
+    write_tbs(out_dir, "vendor", &tbs.vendor)?;
+    write_tbs(out_dir, "owner", &tbs.owner)?;
+
+    Ok(())
+}
+
+/// Run the assemble command
+pub(crate) fn run_assemble_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image = read_image(args)?;
+    let (mut manifest, rest) = read_manifest(&image)?;
+    let too_small =
+        || {
+            anyhow!(
+            "Image too small: {} bytes, manifest, extended TOC and ML-DSA preamble are {} bytes",
+            image.len(),
+            IMAGE_MANIFEST_BYTE_SIZE + IMAGE_EXTENDED_TOC_BYTE_SIZE + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE
+        )
+        };
+    let (toc, rest) = ImageExtendedToc::read_from_prefix(rest).map_err(|_| too_small())?;
+    let (mut mldsa, rest) = ImageMldsaPreamble::read_from_prefix(rest).map_err(|_| too_small())?;
+
+    let out_path: &PathBuf = args
+        .get_one::<PathBuf>("out")
+        .with_context(|| "out arg not specified")?;
+
+    let vendor_sigs = read_sigs(args, "vendor-ecc-sig", "vendor-lms-sig", "vendor-mldsa-sig")?
+        .with_context(|| "vendor-ecc-sig and vendor-lms-sig args not specified")?;
+    let owner_sigs = read_sigs(args, "owner-ecc-sig", "owner-lms-sig", "owner-mldsa-sig")?;
+
+    let gen = ImageGenerator::new(Crypto::default());
+    gen.insert_signatures(&mut manifest, &mut mldsa, &vendor_sigs, owner_sigs.as_ref())?;
+
+    let mut out = manifest.as_bytes().to_vec();
+    out.extend_from_slice(toc.as_bytes());
+    out.extend_from_slice(mldsa.as_bytes());
+    out.extend_from_slice(rest);
+    std::fs::write(out_path, out)
+        .with_context(|| format!("Failed to write file {}", out_path.display()))?;
+
+    Ok(())
+}
+
+fn read_image(args: &ArgMatches) -> anyhow::Result<Vec<u8>> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    std::fs::read(image_path)
+        .with_context(|| format!("Failed to read image file {}", image_path.display()))
+}
+
+fn read_manifest(image: &[u8]) -> anyhow::Result<(ImageManifest, &[u8])> {
+    ImageManifest::read_from_prefix(image).map_err(|_| {
+        anyhow!(
+            "Image too small: {} bytes, manifest is {} bytes",
+            image.len(),
+            IMAGE_MANIFEST_BYTE_SIZE
+        )
+    })
+}
+
+/// Read the ECC and LMS signature files. Both or neither must be specified.
+/// The ML-DSA signature is only needed for images with an ML-DSA public key.
+fn read_sigs(
+    args: &ArgMatches,
+    ecc_arg: &str,
+    lms_arg: &str,
+    mldsa_arg: &str,
+) -> anyhow::Result<Option<DetachedSignatures>> {
+    let mldsa_sig = match args.get_one::<PathBuf>(mldsa_arg) {
+        Some(mldsa_path) => mldsa_sig_from_file(mldsa_path)?,
+        None => ImageMldsaSignature::default(),
+    };
+    match (
+        args.get_one::<PathBuf>(ecc_arg),
+        args.get_one::<PathBuf>(lms_arg),
+    ) {
+        (Some(ecc_path), Some(lms_path)) => Ok(Some(DetachedSignatures {
+            sigs: ImageSignatures {
+                ecc_sig: ecc_sig_from_file(ecc_path)?,
+                lms_sig: lms_sig_from_file(lms_path)?,
+            },
+            mldsa_sig,
+        })),
+        (None, None) => Ok(None),
+        _ => Err(anyhow!(
+            "{ecc_arg} and {lms_arg} must be specified together"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use caliptra_image_fake_keys::*;
+    use caliptra_image_gen::ImageGeneratorCrypto;
+
+    #[test]
+    fn test_insert_signatures() {
+        let crypto = Crypto::default();
+        let gen = ImageGenerator::new(Crypto::default());
+
+        let mut manifest = ImageManifest::default();
+        manifest.preamble.vendor_pub_keys = VENDOR_PUBLIC_KEYS;
+        manifest.preamble.vendor_ecc_pub_key_idx = 1;
+        manifest.preamble.vendor_lms_pub_key_idx = 2;
+        manifest.preamble.vendor_mldsa_pub_key_idx = 3;
+        manifest.preamble.owner_pub_keys = OWNER_PUBLIC_KEYS;
+        manifest.header.vendor_ecc_pub_key_idx = 1;
+        manifest.header.vendor_lms_pub_key_idx = 2;
+        manifest.header.vendor_mldsa_pub_key_idx = 3;
+        let mut mldsa = ImageMldsaPreamble {
+            vendor_pub_key: VENDOR_MLDSA_KEY_3_PUBLIC,
+            owner_pub_key: OWNER_MLDSA_KEY_PUBLIC,
+            ..Default::default()
+        };
+
+        let tbs = gen.to_be_signed(&manifest).unwrap();
+        assert_eq!(
+            crypto.sha384_digest(&tbs.vendor.data).unwrap(),
+            tbs.vendor.digest
+        );
+        assert_eq!(tbs.owner.data, manifest.header.as_bytes());
+
+        let vendor_sigs = DetachedSignatures {
+            sigs: ImageSignatures {
+                ecc_sig: crypto
+                    .ecdsa384_sign(
+                        &tbs.vendor.digest,
+                        &VENDOR_ECC_KEY_1_PRIVATE,
+                        &VENDOR_ECC_KEY_1_PUBLIC,
+                    )
+                    .unwrap(),
+                lms_sig: crypto
+                    .lms_sign(&tbs.vendor.digest, &VENDOR_LMS_KEY_2_PRIVATE)
+                    .unwrap(),
+            },
+            mldsa_sig: crypto
+                .mldsa87_sign(&tbs.vendor.digest, &VENDOR_MLDSA_KEY_3_PRIVATE)
+                .unwrap(),
+        };
+        let owner_sigs = DetachedSignatures {
+            sigs: ImageSignatures {
+                ecc_sig: crypto
+                    .ecdsa384_sign(
+                        &tbs.owner.digest,
+                        &OWNER_ECC_KEY_PRIVATE,
+                        &OWNER_ECC_KEY_PUBLIC,
+                    )
+                    .unwrap(),
+                lms_sig: crypto
+                    .lms_sign(&tbs.owner.digest, &OWNER_LMS_KEY_PRIVATE)
+                    .unwrap(),
+            },
+            mldsa_sig: crypto
+                .mldsa87_sign(&tbs.owner.digest, &OWNER_MLDSA_KEY_PRIVATE)
+                .unwrap(),
+        };
+
+        // Signatures swapped between vendor and owner are rejected.
+        assert!(gen
+            .insert_signatures(&mut manifest, &mut mldsa, &owner_sigs, Some(&vendor_sigs))
+            .is_err());
+        assert_eq!(
+            manifest.preamble.vendor_sigs.as_bytes(),
+            ImageSignatures::default().as_bytes()
+        );
+        assert_eq!(mldsa.vendor_sig, ImageMldsaSignature::default());
+
+        // A missing ML-DSA signature is rejected when there is an ML-DSA key.
+        let no_mldsa_sigs = DetachedSignatures {
+            mldsa_sig: ImageMldsaSignature::default(),
+            ..vendor_sigs.clone()
+        };
+        assert!(gen
+            .insert_signatures(&mut manifest, &mut mldsa, &no_mldsa_sigs, Some(&owner_sigs))
+            .is_err());
+
+        // Owner signatures are required when there are owner public keys.
+        assert!(gen
+            .insert_signatures(&mut manifest, &mut mldsa, &vendor_sigs, None)
+            .is_err());
+
+        gen.insert_signatures(&mut manifest, &mut mldsa, &vendor_sigs, Some(&owner_sigs))
+            .unwrap();
+        assert_eq!(
+            manifest.preamble.vendor_sigs.as_bytes(),
+            vendor_sigs.sigs.as_bytes()
+        );
+        assert_eq!(
+            manifest.preamble.owner_sigs.as_bytes(),
+            owner_sigs.sigs.as_bytes()
+        );
+        assert_eq!(mldsa.vendor_sig, vendor_sigs.mldsa_sig);
+        assert_eq!(mldsa.owner_sig, owner_sigs.mldsa_sig);
+    }
+}
@@ -0,0 +1,366 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   inspect.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image inspection command.
+
+--*/
+
+use anyhow::{anyhow, Context};
+#[cfg(all(feature = "openssl", not(feature = "pkcs11")))]
+use caliptra_image_crypto::OsslCrypto as Crypto;
+#[cfg(feature = "pkcs11")]
+use caliptra_image_crypto::Pkcs11Crypto as Crypto;
+#[cfg(all(feature = "rustcrypto", not(feature = "pkcs11")))]
+use caliptra_image_crypto::RustCrypto as Crypto;
+use caliptra_image_gen::ImageGeneratorCrypto;
+use caliptra_image_types::*;
+use clap::ArgMatches;
+use core::ops::Range;
+use serde_derive::Serialize;
+use std::path::PathBuf;
+use zerocopy::{FromBytes, IntoBytes};
+
+#[derive(Serialize)]
+struct EccPubKeyDump {
+    x: String,
+    y: String,
+}
+
+#[derive(Serialize)]
+struct PreambleDump {
+    vendor_ecc_pub_key_idx: u32,
+    vendor_lms_pub_key_idx: u32,
+    vendor_mldsa_pub_key_idx: u32,
+    vendor_ecc_pub_keys: Vec<EccPubKeyDump>,
+    vendor_lms_pub_keys: Vec<String>,
+    vendor_mldsa_pub_key_digests: Vec<String>,
+    /// Digest of the vendor ML-DSA public key in the ML-DSA preamble, which
+    /// must match the digest at `vendor_mldsa_pub_key_idx`
+    computed_vendor_mldsa_pub_key_digest: String,
+    /// Value the vendor public key hash fuses must hold
+    vendor_pub_keys_digest: String,
+    owner_ecc_pub_key: EccPubKeyDump,
+    owner_lms_pub_key: String,
+    owner_mldsa_pub_key_digest: String,
+    /// Digest of the owner ML-DSA public key in the ML-DSA preamble, which
+    /// must match `owner_mldsa_pub_key_digest`
+    computed_owner_mldsa_pub_key_digest: String,
+    /// Value the owner public key hash fuses must hold, if provisioned
+    owner_pub_keys_digest: String,
+}
+
+#[derive(Serialize)]
+struct HeaderDump {
+    revision: [u32; 2],
+    vendor_ecc_pub_key_idx: u32,
+    vendor_lms_pub_key_idx: u32,
+    vendor_mldsa_pub_key_idx: u32,
+    flags: u32,
+    toc_len: u32,
+    pl0_pauser: u32,
+    toc_digest: String,
+    computed_toc_digest: String,
+    toc_digest_match: bool,
+    vendor_not_before: String,
+    vendor_not_after: String,
+    owner_not_before: String,
+    owner_not_after: String,
+    owner_epoch: u16,
+}
+
+#[derive(Serialize)]
+struct TocEntryDump {
+    id: u32,
+    image_type: u32,
+    revision: String,
+    version: u32,
+    svn: u32,
+    load_addr: u32,
+    entry_point: u32,
+    offset: u32,
+    size: u32,
+    digest: String,
+    /// `None` if the image lies outside the bundle
+    computed_digest: Option<String>,
+    digest_match: bool,
+}
+
+#[derive(Serialize)]
+struct RuntimeExpansionDump {
+    size: u32,
+    digest: String,
+}
+
+#[derive(Serialize)]
+struct ImageBundleDump {
+    marker: u32,
+    size: u32,
+    preamble: PreambleDump,
+    header: HeaderDump,
+    fmc: TocEntryDump,
+    runtime: TocEntryDump,
+    /// Data TOC entries in use, per the header TOC entry count
+    data: Vec<TocEntryDump>,
+    /// `None` unless the runtime is compressed
+    runtime_expansion: Option<RuntimeExpansionDump>,
+}
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let image = std::fs::read(image_path)
+        .with_context(|| format!("Failed to read image file {}", image_path.display()))?;
+
+    let dump = inspect(&Crypto::default(), &image)?;
+    println!("{}", serde_json::to_string_pretty(&dump)?);
+
+    let mut mismatches = vec![];
+    if !dump.header.toc_digest_match {
+        mismatches.push("TOC");
+    }
+    if !dump.fmc.digest_match {
+        mismatches.push("FMC");
+    }
+    if !dump.runtime.digest_match {
+        mismatches.push("Runtime");
+    }
+    let data_mismatches: Vec<_> = dump
+        .data
+        .iter()
+        .filter(|entry| !entry.digest_match)
+        .map(|entry| format!("Data 0x{:08x}", entry.id))
+        .collect();
+    mismatches.extend(data_mismatches.iter().map(String::as_str));
+    if !mismatches.is_empty() {
+        return Err(anyhow!("Digest mismatch: {}", mismatches.join(", ")));
+    }
+
+    Ok(())
+}
+
+fn inspect(crypto: &impl ImageGeneratorCrypto, image: &[u8]) -> anyhow::Result<ImageBundleDump> {
+    let (manifest, _) = ImageManifest::read_from_prefix(image).map_err(|_| {
+        anyhow!(
+            "Image too small: {} bytes, manifest is {} bytes",
+            image.len(),
+            IMAGE_MANIFEST_BYTE_SIZE
+        )
+    })?;
+    let (toc, mldsa) = image
+        .get(IMAGE_MANIFEST_BYTE_SIZE..)
+        .and_then(|rest| ImageExtendedToc::read_from_prefix(rest).ok())
+        .and_then(|(toc, rest)| {
+            let (mldsa, _) = ImageMldsaPreamble::read_from_prefix(rest).ok()?;
+            Some((toc, mldsa))
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Image too small: {} bytes, manifest, extended TOC and ML-DSA preamble are {} bytes",
+                image.len(),
+                IMAGE_MANIFEST_BYTE_SIZE
+                    + IMAGE_EXTENDED_TOC_BYTE_SIZE
+                    + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE
+            )
+        })?;
+    let manifest_bytes = manifest.as_bytes();
+
+    let preamble = &manifest.preamble;
+    let header = &manifest.header;
+
+    let vendor_pub_keys_digest = crypto.sha384_digest(range_bytes(
+        manifest_bytes,
+        ImageManifest::vendor_pub_keys_range(),
+    ))?;
+    let owner_pub_keys_digest = crypto.sha384_digest(range_bytes(
+        manifest_bytes,
+        ImageManifest::owner_pub_key_range(),
+    ))?;
+    let vendor_mldsa_pub_key_digest = crypto.sha384_digest(mldsa.vendor_pub_key.as_bytes())?;
+    let owner_mldsa_pub_key_digest = crypto.sha384_digest(mldsa.owner_pub_key.as_bytes())?;
+    let computed_toc_digest =
+        crypto.sha384_digest(range_bytes(image, ImageManifest::toc_range()))?;
+
+    Ok(ImageBundleDump {
+        marker: manifest.marker,
+        size: manifest.size,
+        preamble: PreambleDump {
+            vendor_ecc_pub_key_idx: preamble.vendor_ecc_pub_key_idx,
+            vendor_lms_pub_key_idx: preamble.vendor_lms_pub_key_idx,
+            vendor_mldsa_pub_key_idx: preamble.vendor_mldsa_pub_key_idx,
+            vendor_ecc_pub_keys: preamble
+                .vendor_pub_keys
+                .ecc_pub_keys
+                .iter()
+                .map(EccPubKeyDump::from)
+                .collect(),
+            vendor_lms_pub_keys: preamble
+                .vendor_pub_keys
+                .lms_pub_keys
+                .iter()
+                .map(|key| hex::encode(key.as_bytes()))
+                .collect(),
+            vendor_mldsa_pub_key_digests: preamble
+                .vendor_pub_keys
+                .mldsa_pub_key_digests
+                .iter()
+                .map(|digest| words_to_hex(digest))
+                .collect(),
+            computed_vendor_mldsa_pub_key_digest: words_to_hex(&vendor_mldsa_pub_key_digest),
+            vendor_pub_keys_digest: words_to_hex(&vendor_pub_keys_digest),
+            owner_ecc_pub_key: (&preamble.owner_pub_keys.ecc_pub_key).into(),
+            owner_lms_pub_key: hex::encode(preamble.owner_pub_keys.lms_pub_key.as_bytes()),
+            owner_mldsa_pub_key_digest: words_to_hex(&preamble.owner_pub_keys.mldsa_pub_key_digest),
+            computed_owner_mldsa_pub_key_digest: words_to_hex(&owner_mldsa_pub_key_digest),
+            owner_pub_keys_digest: words_to_hex(&owner_pub_keys_digest),
+        },
+        header: HeaderDump {
+            revision: header.revision,
+            vendor_ecc_pub_key_idx: header.vendor_ecc_pub_key_idx,
+            vendor_lms_pub_key_idx: header.vendor_lms_pub_key_idx,
+            vendor_mldsa_pub_key_idx: header.vendor_mldsa_pub_key_idx,
+            flags: header.flags,
+            toc_len: header.toc_len,
+            pl0_pauser: header.pl0_pauser,
+            toc_digest: words_to_hex(&header.toc_digest),
+            computed_toc_digest: words_to_hex(&computed_toc_digest),
+            toc_digest_match: header.toc_digest == computed_toc_digest,
+            vendor_not_before: String::from_utf8_lossy(&header.vendor_data.vendor_not_before)
+                .into_owned(),
+            vendor_not_after: String::from_utf8_lossy(&header.vendor_data.vendor_not_after)
+                .into_owned(),
+            owner_not_before: String::from_utf8_lossy(&header.owner_data.owner_not_before)
+                .into_owned(),
+            owner_not_after: String::from_utf8_lossy(&header.owner_data.owner_not_after)
+                .into_owned(),
+            owner_epoch: u16::from_le_bytes(header.owner_data.epoch),
+        },
+        fmc: toc_entry_dump(crypto, image, &manifest.fmc)?,
+        runtime: toc_entry_dump(crypto, image, &manifest.runtime)?,
+        data: toc
+            .data
+            .iter()
+            .take(header.toc_len.saturating_sub(MIN_TOC_ENTRY_COUNT) as usize)
+            .map(|entry| toc_entry_dump(crypto, image, entry))
+            .collect::<anyhow::Result<_>>()?,
+        runtime_expansion: manifest
+            .runtime
+            .is_compressed()
+            .then(|| RuntimeExpansionDump {
+                size: toc.runtime_expansion.size,
+                digest: words_to_hex(&toc.runtime_expansion.digest),
+            }),
+    })
+}
+
+fn toc_entry_dump(
+    crypto: &impl ImageGeneratorCrypto,
+    image: &[u8],
+    entry: &ImageTocEntry,
+) -> anyhow::Result<TocEntryDump> {
+    let computed_digest = match entry
+        .image_range()
+        .ok()
+        .and_then(|range| image.get(range.start as usize..range.end as usize))
+    {
+        Some(data) => Some(crypto.sha384_digest(data)?),
+        None => None,
+    };
+
+    Ok(TocEntryDump {
+        id: entry.id,
+        image_type: entry.image_type,
+        revision: hex::encode(entry.revision),
+        version: entry.version,
+        svn: entry.svn,
+        load_addr: entry.load_addr,
+        entry_point: entry.entry_point,
+        offset: entry.offset,
+        size: entry.size,
+        digest: words_to_hex(&entry.digest),
+        computed_digest: computed_digest.map(|digest| words_to_hex(&digest)),
+        digest_match: computed_digest == Some(entry.digest),
+    })
+}
+
+impl From<&ImageEccPubKey> for EccPubKeyDump {
+    fn from(key: &ImageEccPubKey) -> Self {
+        Self {
+            x: words_to_hex(&key.x),
+            y: words_to_hex(&key.y),
+        }
+    }
+}
+
+fn range_bytes(buf: &[u8], range: Range<u32>) -> &[u8] {
+    &buf[range.start as usize..range.end as usize]
+}
+
+/// Convert a big-endian word array to a hex string.
+fn words_to_hex(words: &[u32]) -> String {
+    words.iter().map(|word| format!("{word:08x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inspect_digest_mismatch() {
+        let crypto = Crypto::default();
+
+        let offset = (IMAGE_MANIFEST_BYTE_SIZE
+            + IMAGE_EXTENDED_TOC_BYTE_SIZE
+            + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE) as u32;
+        let mut manifest = ImageManifest::default();
+        let mut toc = ImageExtendedToc::default();
+        manifest.fmc.offset = offset;
+        manifest.fmc.size = 4;
+        manifest.runtime.offset = offset + 4;
+        manifest.runtime.size = 8;
+        manifest.fmc.digest = crypto.sha384_digest(&[0x11; 4]).unwrap();
+        manifest.header.toc_len = MIN_TOC_ENTRY_COUNT + 1;
+        toc.data[0].id = 0x1000;
+        toc.data[0].offset = offset + 12;
+        toc.data[0].size = 2;
+        toc.data[0].digest = crypto.sha384_digest(&[0x33; 2]).unwrap();
+        let mut toc_bytes = manifest.as_bytes().to_vec();
+        toc_bytes.extend_from_slice(toc.as_bytes());
+        manifest.header.toc_digest = crypto
+            .sha384_digest(range_bytes(&toc_bytes, ImageManifest::toc_range()))
+            .unwrap();
+
+        let mut image = manifest.as_bytes().to_vec();
+        image.extend_from_slice(toc.as_bytes());
+        image.extend_from_slice(ImageMldsaPreamble::default().as_bytes());
+        image.extend_from_slice(&[0x11; 4]);
+        image.extend_from_slice(&[0x22; 8]);
+        image.extend_from_slice(&[0x33; 2]);
+
+        let dump = inspect(&crypto, &image).unwrap();
+        assert!(dump.header.toc_digest_match);
+        assert!(dump.fmc.digest_match);
+        assert!(!dump.runtime.digest_match);
+        assert!(dump.runtime.computed_digest.is_some());
+        assert_eq!(dump.data.len(), 1);
+        assert!(dump.data[0].digest_match);
+
+        // Runtime extends past the end of the bundle.
+        image.truncate(image.len() - 3);
+        let dump = inspect(&crypto, &image).unwrap();
+        assert!(dump.data[0].computed_digest.is_none());
+        assert!(dump.runtime.computed_digest.is_none());
+        assert!(!dump.runtime.digest_match);
+
+        assert!(inspect(&crypto, &image[..16]).is_err());
+    }
+}
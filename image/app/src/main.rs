@@ -13,94 +13,188 @@ Abstract:
 --*/
 use std::path::PathBuf;
 
-use clap::{arg, value_parser, Command};
+use clap::{arg, value_parser, ArgAction, Command};
 
 mod create;
+mod detached;
+mod inspect;
+mod verify;
 
 /// Entry point
 fn main() {
-    let sub_cmds = vec![Command::new("create")
-        .about("Create a new firmware image bundle")
-        .arg(
-            arg!(--"key-config" <FILE> "Key Configuration file")
-                .required(true)
-                .value_parser(value_parser!(PathBuf)),
-        )
-        .arg(
-            arg!(--"ecc-pk-idx" <U32> "Vendor ECC Public Key Index")
-                .required(true)
-                .value_parser(value_parser!(u32)),
-        )
-        .arg(
-            arg!(--"lms-pk-idx" <U32> "Vendor LMS Public Key Index")
-                .required(false)
-                .value_parser(value_parser!(u32)),
-        )
-        .arg(
-            arg!(--"fmc" <FILE> "FMC ELF binary")
-                .required(true)
-                .value_parser(value_parser!(PathBuf)),
-        )
-        .arg(
-            arg!(--"fmc-rev" <SHA256HASH> "FMC GIT Revision")
-                .required(false)
-                .value_parser(value_parser!(String)),
-        )
-        .arg(
-            arg!(--"fmc-version" <U32> "FMC Firmware Version Number")
-                .required(true)
-                .value_parser(value_parser!(u32)),
-        )
-        .arg(
-            arg!(--"fmc-svn" <U32> "FMC Security Version Number")
-                .required(true)
-                .value_parser(value_parser!(u32)),
-        )
-        .arg(
-            arg!(--"rt" <FILE> "Runtime ELF binary")
-                .required(true)
-                .value_parser(value_parser!(PathBuf)),
-        )
-        .arg(
-            arg!(--"rt-rev" <SHA256HASH> "Runtime GIT Revision")
-                .required(false)
-                .value_parser(value_parser!(String)),
-        )
-        .arg(
-            arg!(--"rt-version" <U32> "Runtime Firmware Version Number")
-                .required(true)
-                .value_parser(value_parser!(u32)),
-        )
-        .arg(
-            arg!(--"rt-svn" <U32> "Runtime Security Version Number")
-                .required(true)
-                .value_parser(value_parser!(u32)),
-        )
-        .arg(
-            arg!(--"out" <FILE> "Output file")
-                .required(true)
-                .value_parser(value_parser!(PathBuf)),
-        )
-        .arg(
-            arg!(--"own-from-date" <String> "Certificate Validity Start Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
-                .required(false)
-                .value_parser(value_parser!(String)),
-        )
-        .arg(
-            arg!(--"own-to-date" <String> "Certificate Validity End Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
-                .required(false)
-                .value_parser(value_parser!(String)),
-        )
-        .arg(
-            arg!(--"mfg-from-date" <String> "Certificate Validity Start Date By Manufacturer [YYYYMMDDHHMMSS - Zulu Time]")
-                .required(false)
-                .value_parser(value_parser!(String)),
-        )
-        .arg(
-            arg!(--"mfg-to-date" <String> "Certificate Validity End Date By Manufacturer [YYYYMMDDHHMMSS - Zulu Time]")
-                .required(false)
-                .value_parser(value_parser!(String)),
-        )];
+    let sub_cmds = vec![
+        Command::new("create")
+            .about("Create a new firmware image bundle")
+            .arg(
+                arg!(--"key-config" <FILE> "Key Configuration file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"ecc-pk-idx" <U32> "Vendor ECC Public Key Index")
+                    .required(true)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"lms-pk-idx" <U32> "Vendor LMS Public Key Index")
+                    .required(false)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"mldsa-pk-idx" <U32> "Vendor ML-DSA Public Key Index")
+                    .required(false)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"fmc" <FILE> "FMC ELF binary")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"fmc-rev" <SHA256HASH> "FMC GIT Revision")
+                    .required(false)
+                    .value_parser(value_parser!(String)),
+            )
+            .arg(
+                arg!(--"fmc-version" <U32> "FMC Firmware Version Number")
+                    .required(true)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"fmc-svn" <U32> "FMC Security Version Number")
+                    .required(true)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"rt" <FILE> "Runtime ELF binary")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"rt-rev" <SHA256HASH> "Runtime GIT Revision")
+                    .required(false)
+                    .value_parser(value_parser!(String)),
+            )
+            .arg(
+                arg!(--"rt-version" <U32> "Runtime Firmware Version Number")
+                    .required(true)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"rt-svn" <U32> "Runtime Security Version Number")
+                    .required(true)
+                    .value_parser(value_parser!(u32)),
+            )
+            .arg(
+                arg!(--"rt-compress" "Compress the runtime image with LZ4")
+                    .required(false)
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                arg!(--"data" <ID_FILE> "Data blob to append to the image, as <ID>:<FILE>; may be repeated")
+                    .required(false)
+                    .action(ArgAction::Append)
+                    .value_parser(value_parser!(String)),
+            )
+            .arg(
+                arg!(--"out" <FILE> "Output file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"own-from-date" <String> "Certificate Validity Start Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
+                    .required(false)
+                    .value_parser(value_parser!(String)),
+            )
+            .arg(
+                arg!(--"own-to-date" <String> "Certificate Validity End Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
+                    .required(false)
+                    .value_parser(value_parser!(String)),
+            )
+            .arg(
+                arg!(--"mfg-from-date" <String> "Certificate Validity Start Date By Manufacturer [YYYYMMDDHHMMSS - Zulu Time]")
+                    .required(false)
+                    .value_parser(value_parser!(String)),
+            )
+            .arg(
+                arg!(--"mfg-to-date" <String> "Certificate Validity End Date By Manufacturer [YYYYMMDDHHMMSS - Zulu Time]")
+                    .required(false)
+                    .value_parser(value_parser!(String)),
+            ),
+        Command::new("verify")
+            .about("Verify a firmware image bundle against fuse values")
+            .arg(
+                arg!(--"image" <FILE> "Firmware image bundle")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"fuse-config" <FILE> "Fuse Configuration file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            ),
+        Command::new("export-tbs")
+            .about("Export the data to sign for an unsigned firmware image bundle")
+            .arg(
+                arg!(--"image" <FILE> "Unsigned firmware image bundle")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"out-dir" <DIR> "Output directory")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            ),
+        Command::new("assemble")
+            .about("Insert externally produced signatures into a firmware image bundle")
+            .arg(
+                arg!(--"image" <FILE> "Unsigned firmware image bundle")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"vendor-ecc-sig" <FILE> "Vendor ECC signature (big-endian r || s)")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"vendor-lms-sig" <FILE> "Vendor LMS signature")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"vendor-mldsa-sig" <FILE> "Vendor ML-DSA signature")
+                    .required(false)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"owner-ecc-sig" <FILE> "Owner ECC signature (big-endian r || s)")
+                    .required(false)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"owner-lms-sig" <FILE> "Owner LMS signature")
+                    .required(false)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"owner-mldsa-sig" <FILE> "Owner ML-DSA signature")
+                    .required(false)
+                    .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(--"out" <FILE> "Output file")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            ),
+        Command::new("inspect")
+            .about("Dump a firmware image bundle as JSON")
+            .arg(
+                arg!(--"image" <FILE> "Firmware image bundle")
+                    .required(true)
+                    .value_parser(value_parser!(PathBuf)),
+            ),
+    ];
 
     let cmd = Command::new("caliptra-image-app")
         .arg_required_else_help(true)
@@ -110,6 +204,10 @@ fn main() {
 
     let result = match cmd.subcommand().unwrap() {
         ("create", args) => create::run_cmd(args),
+        ("verify", args) => verify::run_cmd(args),
+        ("inspect", args) => inspect::run_cmd(args),
+        ("export-tbs", args) => detached::run_export_cmd(args),
+        ("assemble", args) => detached::run_assemble_cmd(args),
         (_, _) => unreachable!(),
     };
 
@@ -0,0 +1,174 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   config.rs
+
+Abstract:
+
+    File contains utilities for parsing fuse configuration files
+
+--*/
+
+use anyhow::{anyhow, Context};
+use caliptra_drivers::{Lifecycle, VendorPubKeyRevocation};
+use caliptra_image_types::{ImageDigest, SHA384_DIGEST_BYTE_SIZE};
+use serde_derive::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Fuse Configuration
+///
+/// SVN values are the decoded values the ROM compares against, not the raw
+/// fuse bitmaps.
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct FuseConfig {
+    pub vendor_pk_hash: String,
+
+    pub owner_pk_hash: Option<String>,
+
+    #[serde(default)]
+    pub vendor_ecc_pk_revocation: u32,
+
+    #[serde(default)]
+    pub vendor_lms_pk_revocation: u32,
+
+    #[serde(default)]
+    pub vendor_mldsa_pk_revocation: u32,
+
+    #[serde(default)]
+    pub fmc_key_manifest_svn: u32,
+
+    #[serde(default)]
+    pub runtime_svn: u32,
+
+    #[serde(default)]
+    pub anti_rollback_disable: bool,
+
+    pub life_cycle: String,
+
+    #[serde(default)]
+    pub lms_verify: bool,
+
+    #[serde(default)]
+    pub mldsa_verify: bool,
+}
+
+/// Fuse values as seen by the ROM
+pub(crate) struct Fuses {
+    pub vendor_pk_hash: ImageDigest,
+
+    pub owner_pk_hash: ImageDigest,
+
+    pub vendor_ecc_pk_revocation: VendorPubKeyRevocation,
+
+    pub vendor_lms_pk_revocation: u32,
+
+    pub vendor_mldsa_pk_revocation: u32,
+
+    pub fmc_key_manifest_svn: u32,
+
+    pub runtime_svn: u32,
+
+    pub anti_rollback_disable: bool,
+
+    pub life_cycle: Lifecycle,
+
+    pub lms_verify: bool,
+
+    pub mldsa_verify: bool,
+}
+
+impl TryFrom<&FuseConfig> for Fuses {
+    type Error = anyhow::Error;
+
+    fn try_from(config: &FuseConfig) -> anyhow::Result<Self> {
+        let owner_pk_hash = match &config.owner_pk_hash {
+            Some(hash) => parse_digest("owner_pk_hash", hash)?,
+            None => ImageDigest::default(),
+        };
+
+        Ok(Self {
+            vendor_pk_hash: parse_digest("vendor_pk_hash", &config.vendor_pk_hash)?,
+            owner_pk_hash,
+            vendor_ecc_pk_revocation: VendorPubKeyRevocation::from_bits(
+                config.vendor_ecc_pk_revocation,
+            )
+            .ok_or_else(|| {
+                anyhow!(
+                    "Invalid vendor_ecc_pk_revocation 0x{:x}",
+                    config.vendor_ecc_pk_revocation
+                )
+            })?,
+            vendor_lms_pk_revocation: config.vendor_lms_pk_revocation,
+            vendor_mldsa_pk_revocation: config.vendor_mldsa_pk_revocation,
+            fmc_key_manifest_svn: config.fmc_key_manifest_svn,
+            runtime_svn: config.runtime_svn,
+            anti_rollback_disable: config.anti_rollback_disable,
+            life_cycle: parse_life_cycle(&config.life_cycle)?,
+            lms_verify: config.lms_verify,
+            mldsa_verify: config.mldsa_verify,
+        })
+    }
+}
+
+/// Parse a SHA-384 digest from a big-endian hex string
+fn parse_digest(name: &str, hex_str: &str) -> anyhow::Result<ImageDigest> {
+    let bytes: [u8; SHA384_DIGEST_BYTE_SIZE] = hex::decode(hex_str)
+        .with_context(|| format!("Invalid hex string for {name}"))?
+        .try_into()
+        .map_err(|_| anyhow!("{name} must be {SHA384_DIGEST_BYTE_SIZE} bytes"))?;
+
+    let mut digest = ImageDigest::default();
+    for (word, chunk) in digest.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    Ok(digest)
+}
+
+fn parse_life_cycle(life_cycle: &str) -> anyhow::Result<Lifecycle> {
+    match life_cycle.to_ascii_lowercase().as_str() {
+        "unprovisioned" => Ok(Lifecycle::Unprovisioned),
+        "manufacturing" => Ok(Lifecycle::Manufacturing),
+        "production" => Ok(Lifecycle::Production),
+        _ => Err(anyhow!(
+            "Invalid life_cycle {life_cycle}, expected unprovisioned, manufacturing or production"
+        )),
+    }
+}
+
+/// Load Fuse Configuration from file
+pub(crate) fn load_fuse_config(path: &PathBuf) -> anyhow::Result<FuseConfig> {
+    let config_str = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read the config file {}", path.display()))?;
+
+    let config: FuseConfig = toml::from_str(&config_str)
+        .with_context(|| format!("Failed to parse config file {}", path.display()))?;
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_digest() {
+        let hex_str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f\
+                       202122232425262728292a2b2c2d2e2f";
+        let digest = parse_digest("test", hex_str).unwrap();
+        assert_eq!(digest[0], 0x00010203);
+        assert_eq!(digest[11], 0x2c2d2e2f);
+
+        assert!(parse_digest("test", "0011").is_err());
+        assert!(parse_digest("test", "zz").is_err());
+    }
+
+    #[test]
+    fn test_parse_life_cycle() {
+        assert!(parse_life_cycle("Production").unwrap() == Lifecycle::Production);
+        assert!(parse_life_cycle("unprovisioned").unwrap() == Lifecycle::Unprovisioned);
+        assert!(parse_life_cycle("reserved2").is_err());
+    }
+}
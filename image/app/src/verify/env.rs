@@ -0,0 +1,203 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   env.rs
+
+Abstract:
+
+    File contains the software image verification environment.
+
+--*/
+
+use super::config::Fuses;
+use caliptra_drivers::memory_layout::ICCM_RANGE;
+use caliptra_drivers::*;
+#[cfg(all(feature = "openssl", not(feature = "pkcs11")))]
+use caliptra_image_crypto::OsslCrypto as Crypto;
+#[cfg(feature = "pkcs11")]
+use caliptra_image_crypto::Pkcs11Crypto as Crypto;
+#[cfg(all(feature = "rustcrypto", not(feature = "pkcs11")))]
+use caliptra_image_crypto::RustCrypto as Crypto;
+use caliptra_image_gen::ImageGeneratorCrypto;
+use caliptra_image_types::*;
+use caliptra_image_verify::ImageVerificationEnv;
+use core::ops::Range;
+
+/// Software Verification Environment
+///
+/// Performs the checks of the ROM verification environment in software, using
+/// fuse values from a configuration file instead of the fuse bank.
+pub(crate) struct SwImageVerificationEnv<'a> {
+    pub image: &'a [u8],
+    pub fuses: &'a Fuses,
+    pub crypto: Crypto,
+    pub fw_extended_error: u32,
+}
+
+impl<'a> ImageVerificationEnv for &mut SwImageVerificationEnv<'a> {
+    /// Calculate Digest using SHA-384
+    fn sha384_digest(&mut self, offset: u32, len: u32) -> CaliptraResult<ImageDigest> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let data = self
+            .image
+            .get(offset as usize..)
+            .ok_or(err)?
+            .get(..len as usize)
+            .ok_or(err)?;
+        self.crypto
+            .sha384_digest(data)
+            .map_err(|_| CaliptraError::DRIVER_SHA384_INVALID_STATE_ERR)
+    }
+
+    /// ECC-384 Verification routine
+    ///
+    /// The hardware returns the computed `r` value, which the verifier compares
+    /// against the signature. Mimic that by returning the signature `r` only if
+    /// the signature is valid.
+    fn ecc384_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> CaliptraResult<Array4xN<12, 48>> {
+        // A public key that is not on the curve fails verification.
+        let valid = self
+            .crypto
+            .ecdsa384_verify(digest, pub_key, sig)
+            .unwrap_or(false);
+        if valid {
+            Ok(Array4x12::from(sig.r))
+        } else {
+            Ok(Array4x12::from(sig.r.map(|word| !word)))
+        }
+    }
+
+    /// LMS Verification routine
+    ///
+    /// Returns the public key digest as the candidate key if the signature is
+    /// valid.
+    fn lms_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> CaliptraResult<HashValue<SHA192_DIGEST_WORD_SIZE>> {
+        let pub_key_digest = HashValue::from(pub_key.digest);
+        match self.crypto.lms_verify(digest, pub_key, sig) {
+            Ok(true) => Ok(pub_key_digest),
+            Ok(false) => Ok(HashValue(pub_key_digest.0.map(|word| !word))),
+            Err(_) if pub_key.tree_type != sig.tree_type => {
+                Err(CaliptraError::DRIVER_LMS_INVALID_LMS_ALGO_TYPE)
+            }
+            Err(_) => Err(CaliptraError::DRIVER_LMS_INVALID_LMOTS_ALGO_TYPE),
+        }
+    }
+
+    /// ML-DSA-87 Verification routine
+    ///
+    /// The hardware returns the start of the signature if it is valid, which
+    /// the verifier compares against the signature.
+    fn mldsa87_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> CaliptraResult<LEArray4x16> {
+        let valid = self
+            .crypto
+            .mldsa87_verify(digest, pub_key, sig)
+            .unwrap_or(false);
+        let mut verify_res = LEArray4x16::default();
+        let len = verify_res.0.len();
+        verify_res.0.copy_from_slice(&sig.0[..len]);
+        if !valid {
+            verify_res.0 = verify_res.0.map(|word| !word);
+        }
+        Ok(verify_res)
+    }
+
+    /// Retrieve Vendor Public Key Digest
+    fn vendor_pub_key_digest(&self) -> ImageDigest {
+        self.fuses.vendor_pk_hash
+    }
+
+    /// Retrieve Vendor ECC Public Key Revocation Bitmask
+    fn vendor_ecc_pub_key_revocation(&self) -> VendorPubKeyRevocation {
+        self.fuses.vendor_ecc_pk_revocation
+    }
+
+    /// Retrieve Vendor LMS Public Key Revocation Bitmask
+    fn vendor_lms_pub_key_revocation(&self) -> u32 {
+        self.fuses.vendor_lms_pk_revocation
+    }
+
+    /// Retrieve Vendor ML-DSA Public Key Revocation Bitmask
+    fn vendor_mldsa_pub_key_revocation(&self) -> u32 {
+        self.fuses.vendor_mldsa_pk_revocation
+    }
+
+    /// Retrieve Owner Public Key Digest from fuses
+    fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
+        self.fuses.owner_pk_hash
+    }
+
+    /// Retrieve Anti-Rollback disable fuse value
+    fn anti_rollback_disable(&self) -> bool {
+        self.fuses.anti_rollback_disable
+    }
+
+    /// Retrieve Device Lifecycle state
+    fn dev_lifecycle(&self) -> Lifecycle {
+        self.fuses.life_cycle
+    }
+
+    /// Only cold reset verification is supported, so the data vault is unused.
+    fn vendor_ecc_pub_key_idx_dv(&self) -> u32 {
+        0
+    }
+
+    fn vendor_lms_pub_key_idx_dv(&self) -> u32 {
+        0
+    }
+
+    fn vendor_mldsa_pub_key_idx_dv(&self) -> u32 {
+        0
+    }
+
+    fn owner_pub_key_digest_dv(&self) -> ImageDigest {
+        ImageDigest::default()
+    }
+
+    fn get_fmc_digest_dv(&self) -> ImageDigest {
+        ImageDigest::default()
+    }
+
+    // Get Fuse FMC Key Manifest SVN
+    fn fmc_fuse_svn(&self) -> u32 {
+        self.fuses.fmc_key_manifest_svn
+    }
+
+    // Get Runtime fuse SVN
+    fn runtime_fuse_svn(&self) -> u32 {
+        self.fuses.runtime_svn
+    }
+
+    fn iccm_range(&self) -> Range<u32> {
+        ICCM_RANGE
+    }
+
+    fn lms_verify_enabled(&self) -> bool {
+        self.fuses.lms_verify
+    }
+
+    fn mldsa_verify_enabled(&self) -> bool {
+        self.fuses.mldsa_verify
+    }
+
+    fn set_fw_extended_error(&mut self, err: u32) {
+        self.fw_extended_error = err;
+    }
+}
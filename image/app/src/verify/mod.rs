@@ -0,0 +1,130 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image verification command.
+
+--*/
+
+mod config;
+mod env;
+
+use anyhow::{anyhow, Context};
+use caliptra_drivers::ResetReason;
+use caliptra_error::CaliptraError;
+use caliptra_image_types::{
+    ImageExtendedToc, ImageManifest, ImageMldsaPreamble, IMAGE_EXTENDED_TOC_BYTE_SIZE,
+    IMAGE_MANIFEST_BYTE_SIZE, IMAGE_MLDSA_PREAMBLE_BYTE_SIZE,
+};
+use caliptra_image_verify::{ImageVerificationExeInfo, ImageVerifier, MldsaPreambleSource};
+use clap::ArgMatches;
+use std::path::PathBuf;
+use zerocopy::FromBytes;
+
+use config::Fuses;
+use env::SwImageVerificationEnv;
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let fuse_config_path: &PathBuf = args
+        .get_one::<PathBuf>("fuse-config")
+        .with_context(|| "fuse-config arg not specified")?;
+
+    let image = std::fs::read(image_path)
+        .with_context(|| format!("Failed to read image file {}", image_path.display()))?;
+
+    let fuses = Fuses::try_from(&config::load_fuse_config(fuse_config_path)?)?;
+
+    let (manifest, _) = ImageManifest::read_from_prefix(&image).map_err(|_| {
+        anyhow!(
+            "Image too small: {} bytes, manifest is {} bytes",
+            image.len(),
+            IMAGE_MANIFEST_BYTE_SIZE
+        )
+    })?;
+    let (toc, mldsa) = image
+        .get(IMAGE_MANIFEST_BYTE_SIZE..)
+        .and_then(|rest| ImageExtendedToc::read_from_prefix(rest).ok())
+        .and_then(|(toc, rest)| {
+            let (mldsa, _) = ImageMldsaPreamble::read_from_prefix(rest).ok()?;
+            Some((toc, mldsa))
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Image too small: {} bytes, manifest, extended TOC and ML-DSA preamble are {} bytes",
+                image.len(),
+                IMAGE_MANIFEST_BYTE_SIZE
+                    + IMAGE_EXTENDED_TOC_BYTE_SIZE
+                    + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE
+            )
+        })?;
+
+    let mut env = SwImageVerificationEnv {
+        image: &image,
+        fuses: &fuses,
+        crypto: Default::default(),
+        fw_extended_error: 0,
+    };
+
+    let result = ImageVerifier::new(&mut env).verify(
+        &manifest,
+        &toc,
+        MldsaPreambleSource::Image(&mldsa),
+        image.len() as u32,
+        ResetReason::ColdReset,
+    );
+
+    let info = match result {
+        Ok(info) => info,
+        Err(err) => {
+            let mut msg = format!("Image verification failed with error {err}");
+            if let Ok(extended_err) = CaliptraError::try_from(env.fw_extended_error) {
+                msg += &format!(" (extended error {extended_err})");
+            }
+            return Err(anyhow!(msg));
+        }
+    };
+
+    println!("Image verification passed");
+    println!(
+        "Vendor ECC public key index: {}",
+        info.vendor_ecc_pub_key_idx
+    );
+    if let Some(idx) = info.vendor_lms_pub_key_idx {
+        println!("Vendor LMS public key index: {idx}");
+    }
+    if let Some(idx) = info.vendor_mldsa_pub_key_idx {
+        println!("Vendor ML-DSA public key index: {idx}");
+    }
+    println!(
+        "Owner public key digest in fuses: {}",
+        info.owner_pub_keys_digest_in_fuses
+    );
+    print_exe_info("FMC", &info.fmc);
+    print_exe_info("Runtime", &info.runtime);
+    for data in &info.data[..info.data_count as usize] {
+        println!(
+            "Data: id 0x{:08x}, offset {}, size {}",
+            data.id, data.offset, data.size
+        );
+    }
+
+    Ok(())
+}
+
+fn print_exe_info(name: &str, info: &ImageVerificationExeInfo) {
+    println!(
+        "{name}: load address 0x{:08x}, size {}, entry point 0x{:08x}, svn {}, fuse svn {}",
+        info.load_addr, info.size, info.entry_point, info.svn, info.effective_fuse_svn
+    );
+}
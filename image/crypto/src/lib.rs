@@ -12,20 +12,31 @@ Abstract:
 
 --*/
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::{anyhow, Context};
+use anyhow::{anyhow, bail, Context};
 
+use caliptra_image_gen::{ImageGeneratorCrypto, ToBeSigned};
 use caliptra_image_types::*;
 use caliptra_lms_types::{LmotsAlgorithmType, LmsAlgorithmType};
+use fips204::ml_dsa_87;
+use fips204::traits::{SerDes, Signer, Verifier};
+use rand::{rngs::StdRng, SeedableRng};
 
 #[cfg(feature = "openssl")]
 mod openssl;
-#[cfg(feature = "rustcrypto")]
+#[cfg(feature = "pkcs11")]
+mod pkcs11;
+#[cfg(any(
+    feature = "rustcrypto",
+    all(feature = "pkcs11", not(feature = "openssl"))
+))]
 mod rustcrypto;
 
 #[cfg(feature = "openssl")]
 pub use crate::openssl::*;
+#[cfg(feature = "pkcs11")]
+pub use crate::pkcs11::*;
 #[cfg(feature = "rustcrypto")]
 pub use crate::rustcrypto::*;
 
@@ -44,6 +55,34 @@ const D_MESG: u16 = 0x8181;
 const D_LEAF: u16 = 0x8282;
 const D_INTR: u16 = 0x8383;
 
+const PKCS11_URI_SCHEME: &str = "pkcs11:";
+
+/// Returns true if `key` is a PKCS#11 URI rather than a file name
+pub fn is_pkcs11_uri(key: &str) -> bool {
+    key.starts_with(PKCS11_URI_SCHEME)
+}
+
+/// Loads the ECC private keys named in configuration files
+pub trait EccPrivKeyLoader: ImageGeneratorCrypto {
+    /// Load the ECC private key `key`, a PEM file in `dir`
+    ///
+    /// Backends with token support also accept a PKCS#11 URI that references a
+    /// key matching `pub_key`. The key then stays on the token and the
+    /// returned private key is unused.
+    fn load_ecc_priv_key(
+        &mut self,
+        dir: &Path,
+        key: &str,
+        pub_key: &ImageEccPubKey,
+    ) -> anyhow::Result<ImageEccPrivKey> {
+        let _ = pub_key;
+        if is_pkcs11_uri(key) {
+            bail!("{key}: PKCS#11 keys require the pkcs11 feature");
+        }
+        Self::ecc_priv_key_from_pem(&dir.join(key))
+    }
+}
+
 pub trait Sha256Hasher: Sized {
     /// Create a new Sha256Hasher
     fn new() -> Self;
@@ -80,6 +119,150 @@ pub fn lms_priv_key_from_pem(path: &PathBuf) -> anyhow::Result<ImageLmsPrivKey>
         .map_err(|_| anyhow!("Error parsing LMS priv key"))
 }
 
+/// Write data to be signed outside of the tools and its digest
+///
+/// Writes `<name>.tbs` with the signed data and `<name>.digest` with its
+/// SHA-384 digest. The digest is the input to an ECDSA-SHA384 signing
+/// operation on a prehashed message and the LMS message. The ML-DSA message is
+/// the digest followed by 16 zero bytes.
+pub fn write_tbs(out_dir: &Path, name: &str, tbs: &ToBeSigned) -> anyhow::Result<()> {
+    let data_path = out_dir.join(format!("{name}.tbs"));
+    std::fs::write(&data_path, &tbs.data)
+        .with_context(|| format!("Failed to write file {}", data_path.display()))?;
+
+    let digest_path = out_dir.join(format!("{name}.digest"));
+    std::fs::write(&digest_path, tbs.digest_bytes())
+        .with_context(|| format!("Failed to write file {}", digest_path.display()))?;
+
+    println!(
+        "{name}: {} ({} bytes), {} (SHA-384 {})",
+        data_path.display(),
+        tbs.data.len(),
+        digest_path.display(),
+        hex::encode(tbs.digest_bytes())
+    );
+
+    Ok(())
+}
+
+/// Read ECC-384 signature from file
+///
+/// The file contains the big-endian `r` and `s` values, 48 bytes each.
+pub fn ecc_sig_from_file(path: &PathBuf) -> anyhow::Result<ImageEccSignature> {
+    let sig_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read signature file {}", path.display()))?;
+
+    if sig_bytes.len() != 2 * ECC384_SCALAR_BYTE_SIZE {
+        return Err(anyhow!(
+            "Invalid ECC signature size {}, expected {}",
+            sig_bytes.len(),
+            2 * ECC384_SCALAR_BYTE_SIZE
+        ));
+    }
+
+    Ok(ImageEccSignature {
+        r: to_hw_format(&sig_bytes[..ECC384_SCALAR_BYTE_SIZE]),
+        s: to_hw_format(&sig_bytes[ECC384_SCALAR_BYTE_SIZE..]),
+    })
+}
+
+/// Read LMS SHA192 signature from file
+///
+/// The file contains the RFC 8554 encoding of the signature, which must use
+/// the image LMS parameters.
+pub fn lms_sig_from_file(path: &PathBuf) -> anyhow::Result<ImageLmsSignature> {
+    let sig_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read signature file {}", path.display()))?;
+
+    ImageLmsSignature::read_from_bytes(&sig_bytes[..])
+        .map_err(|_| anyhow!("Error parsing LMS signature"))
+}
+
+/// Read ML-DSA-87 public key from file
+///
+/// The file contains the FIPS 204 encoding of the key.
+pub fn mldsa_pub_key_from_file(path: &PathBuf) -> anyhow::Result<ImageMldsaPubKey> {
+    let key_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read public key file {}", path.display()))?;
+
+    ImageMldsaPubKey::read_from_bytes(&key_bytes[..])
+        .map_err(|_| anyhow!("Error parsing ML-DSA public key"))
+}
+
+/// Read ML-DSA-87 private key from file
+///
+/// The file contains the FIPS 204 encoding of the key.
+pub fn mldsa_priv_key_from_file(path: &PathBuf) -> anyhow::Result<ImageMldsaPrivKey> {
+    let key_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read private key file {}", path.display()))?;
+
+    ImageMldsaPrivKey::read_from_bytes(&key_bytes[..])
+        .map_err(|_| anyhow!("Error parsing ML-DSA private key"))
+}
+
+/// Read ML-DSA-87 signature from file
+///
+/// The file contains the FIPS 204 encoding of the signature.
+pub fn mldsa_sig_from_file(path: &PathBuf) -> anyhow::Result<ImageMldsaSignature> {
+    let sig_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read signature file {}", path.display()))?;
+
+    if sig_bytes.len() != MLDSA87_SIGNATURE_BYTE_SIZE {
+        return Err(anyhow!(
+            "Invalid ML-DSA signature size {}, expected {}",
+            sig_bytes.len(),
+            MLDSA87_SIGNATURE_BYTE_SIZE
+        ));
+    }
+
+    let mut sig = ImageMldsaSignature::default();
+    sig.as_mut_bytes()[..MLDSA87_SIGNATURE_BYTE_SIZE].copy_from_slice(&sig_bytes);
+    Ok(sig)
+}
+
+/// Returns the ML-DSA-87 message signed for `digest`: the digest bytes
+/// followed by zeros, matching `mldsa_msg_from_digest()`.
+fn mldsa_msg(digest: &ImageDigest) -> [u8; MLDSA87_MSG_BYTE_SIZE] {
+    let mut msg = [0u8; MLDSA87_MSG_BYTE_SIZE];
+    msg[..SHA384_DIGEST_BYTE_SIZE].copy_from_slice(&from_hw_format(digest));
+    msg
+}
+
+/// Sign `digest` with an ML-DSA-87 private key
+///
+/// `rnd` seeds the signing randomness the same way the ML-DSA engine does.
+fn sign_with_mldsa_key(
+    priv_key: &ImageMldsaPrivKey,
+    digest: &ImageDigest,
+    rnd: [u8; 32],
+) -> anyhow::Result<ImageMldsaSignature> {
+    let priv_key = ml_dsa_87::PrivateKey::try_from_bytes(priv_key.as_bytes().try_into().unwrap())
+        .map_err(|e| anyhow!("Error parsing ML-DSA private key: {e}"))?;
+    let sig_bytes = priv_key
+        .try_sign_with_rng(&mut StdRng::from_seed(rnd), &mldsa_msg(digest))
+        .map_err(|e| anyhow!("Error generating ML-DSA signature: {e}"))?;
+
+    let mut sig = ImageMldsaSignature::default();
+    sig.as_mut_bytes()[..MLDSA87_SIGNATURE_BYTE_SIZE].copy_from_slice(&sig_bytes);
+    Ok(sig)
+}
+
+/// Verify an ML-DSA-87 signature over `digest`
+fn verify_with_mldsa_key(
+    pub_key: &ImageMldsaPubKey,
+    digest: &ImageDigest,
+    sig: &ImageMldsaSignature,
+) -> anyhow::Result<bool> {
+    let pub_key = ml_dsa_87::PublicKey::try_from_bytes(pub_key.as_bytes().try_into().unwrap())
+        .map_err(|e| anyhow!("Error parsing ML-DSA public key: {e}"))?;
+    let (sig_bytes, padding) = sig.as_bytes().split_at(MLDSA87_SIGNATURE_BYTE_SIZE);
+    if padding.iter().any(|&b| b != 0) {
+        return Ok(false);
+    }
+
+    Ok(pub_key.verify(&mldsa_msg(digest), sig_bytes.try_into().unwrap()))
+}
+
 /// Convert the slice to hardware format
 fn to_hw_format<const NUM_WORDS: usize>(value: &[u8]) -> [u32; NUM_WORDS] {
     let mut result = [0u32; NUM_WORDS];
@@ -454,13 +637,121 @@ fn sign_with_lms_key<T: Sha256Hasher>(
     Ok(sig.unwrap())
 }
 
+// https://datatracker.ietf.org/doc/html/rfc8554#section-5.4.2
+fn verify_with_lms_key<T: Sha256Hasher>(
+    pub_key: &ImageLmsPublicKey,
+    message: &[u8],
+    sig: &ImageLmsSignature,
+) -> anyhow::Result<bool> {
+    if sig.tree_type != pub_key.tree_type || sig.ots.ots_type != pub_key.otstype {
+        return Ok(false);
+    }
+    let height = match pub_key.tree_type {
+        IMAGE_LMS_TREE_TYPE => 15,
+        IMAGE_LMS_TREE_TYPE_HT_5 => 5,
+        _ => return Err(anyhow!("Error looking up lms tree type")),
+    };
+    let (alg_p, width, ls) = match pub_key.otstype {
+        IMAGE_LMS_OTS_TYPE_8 => (26usize, 8usize, 0u8),
+        IMAGE_LMS_OTS_TYPE => (51usize, 4usize, 4u8),
+        _ => return Err(anyhow!("Error looking up lms ots type")),
+    };
+    let q = u32::from(sig.q);
+    if q >= (1 << height) {
+        return Ok(false);
+    }
+    let id = &pub_key.id;
+
+    // Compute the candidate LM-OTS public key.
+    let mut q_arr = [0u8; SHA192_DIGEST_BYTE_SIZE];
+    let mut hasher = T::new();
+    hasher.update(id);
+    hasher.update(&q.to_be_bytes());
+    hasher.update(&D_MESG.to_be_bytes());
+    hasher.update(sig.ots.nonce.as_bytes());
+    hasher.update(message);
+    q_arr.clone_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+
+    let mut checksum: u16 = 0;
+    let data_coeff: usize = (SHA192_DIGEST_BYTE_SIZE * 8) / width;
+    let alg_chksum_max: u16 = (1 << width) - 1;
+    for i in 0..data_coeff {
+        checksum += alg_chksum_max - (coefficient(&q_arr, i, width)? as u16);
+    }
+    checksum <<= ls;
+    let checksum_str: [u8; 2] = checksum.to_be_bytes();
+
+    let mut z = vec![0u8; alg_p * SHA192_DIGEST_BYTE_SIZE];
+    for i in 0..alg_p {
+        let a: u8 = if i < data_coeff {
+            coefficient(&q_arr, i, width)?
+        } else {
+            coefficient(&checksum_str, i - data_coeff, width)?
+        };
+
+        let tmp = &mut z[i * SHA192_DIGEST_BYTE_SIZE..][..SHA192_DIGEST_BYTE_SIZE];
+        tmp.copy_from_slice(sig.ots.y[i].as_bytes());
+        let i_str: [u8; 2] = (i as u16).to_be_bytes();
+        for j in a..alg_chksum_max as u8 {
+            let j_str: [u8; 1] = [j];
+            hasher = T::new();
+            hasher.update(id);
+            hasher.update(&q.to_be_bytes());
+            hasher.update(&i_str);
+            hasher.update(&j_str);
+            hasher.update(tmp);
+            tmp.copy_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+        }
+    }
+
+    let mut k = [0u8; SHA192_DIGEST_BYTE_SIZE];
+    hasher = T::new();
+    hasher.update(id);
+    hasher.update(&q.to_be_bytes());
+    hasher.update(&D_PBLC.to_be_bytes());
+    hasher.update(&z);
+    k.clone_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+
+    // Walk the authentication path up to the root.
+    let mut node_num: u32 = (1 << height) + q;
+    hasher = T::new();
+    hasher.update(id);
+    hasher.update(&node_num.to_be_bytes());
+    hasher.update(&D_LEAF.to_be_bytes());
+    hasher.update(&k);
+    k.clone_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+
+    let mut level = 0;
+    while node_num > 1 {
+        let path = sig.tree_path[level].as_bytes();
+        hasher = T::new();
+        hasher.update(id);
+        hasher.update(&(node_num / 2).to_be_bytes());
+        hasher.update(&D_INTR.to_be_bytes());
+        if node_num % 2 == 1 {
+            hasher.update(path);
+            hasher.update(&k);
+        } else {
+            hasher.update(&k);
+            hasher.update(path);
+        }
+        k.clone_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+        node_num /= 2;
+        level += 1;
+    }
+
+    Ok(k.as_slice() == pub_key.digest.as_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "openssl"))]
+    use crate::rustcrypto::RustCryptoHasher;
     #[cfg(feature = "openssl")]
     use ::openssl::rand::rand_bytes;
     use caliptra_lms_types::bytes_to_words_6;
-    #[cfg(feature = "rustcrypto")]
+    #[cfg(not(feature = "openssl"))]
     use rand::{rngs::OsRng, RngCore};
     use zerocopy::{LittleEndian, U32};
 
@@ -477,13 +768,13 @@ mod tests {
             rand_bytes(&mut priv_key.id).unwrap();
             #[cfg(feature = "openssl")]
             rand_bytes(priv_key.seed.as_mut_bytes()).unwrap();
-            #[cfg(feature = "rustcrypto")]
+            #[cfg(not(feature = "openssl"))]
             OsRng.fill_bytes(&mut priv_key.id);
-            #[cfg(feature = "rustcrypto")]
+            #[cfg(not(feature = "openssl"))]
             OsRng.fill_bytes(priv_key.seed.as_mut_bytes());
             #[cfg(feature = "openssl")]
             let pub_key = generate_lms_pubkey::<OpensslHasher>(&priv_key).unwrap();
-            #[cfg(feature = "rustcrypto")]
+            #[cfg(not(feature = "openssl"))]
             let pub_key = generate_lms_pubkey::<RustCryptoHasher>(&priv_key).unwrap();
             println!("pub const VENDOR_LMS_KEY{i}_PRIVATE: ImageLmsPrivKey = {priv_key:#04x?};");
             println!("pub const VENDOR_LMS_KEY{i}_PUBLIC: ImageLmsPublicKey = {pub_key:#04x?};");
@@ -493,19 +784,61 @@ mod tests {
             rand_bytes(&mut priv_key.id).unwrap();
             #[cfg(feature = "openssl")]
             rand_bytes(priv_key.seed.as_mut_bytes()).unwrap();
-            #[cfg(feature = "rustcrypto")]
+            #[cfg(not(feature = "openssl"))]
             OsRng.fill_bytes(&mut priv_key.id);
-            #[cfg(feature = "rustcrypto")]
+            #[cfg(not(feature = "openssl"))]
             OsRng.fill_bytes(priv_key.seed.as_mut_bytes());
             #[cfg(feature = "openssl")]
             let pub_key = generate_lms_pubkey::<OpensslHasher>(&priv_key).unwrap();
-            #[cfg(feature = "rustcrypto")]
+            #[cfg(not(feature = "openssl"))]
             let pub_key = generate_lms_pubkey::<RustCryptoHasher>(&priv_key).unwrap();
             println!("pub const OWNER_LMS_KEY{i}_PRIVATE: ImageLmsPrivKey = {priv_key:#04x?};");
             println!("pub const OWNER_LMS_KEY{i}_PUBLIC: ImageLmsPublicKey = {pub_key:#04x?};");
         }
     }
 
+    fn mldsa_keygen(seed: [u8; 32]) -> (ImageMldsaPubKey, ImageMldsaPrivKey) {
+        let (pk, sk) = ml_dsa_87::try_keygen_with_rng(&mut StdRng::from_seed(seed)).unwrap();
+        let mut pub_key = ImageMldsaPubKey::default();
+        pub_key.as_mut_bytes().copy_from_slice(&pk.into_bytes());
+        let mut priv_key = ImageMldsaPrivKey::default();
+        priv_key.as_mut_bytes().copy_from_slice(&sk.into_bytes());
+        (pub_key, priv_key)
+    }
+
+    #[test]
+    #[ignore]
+    fn test_print_mldsa_private_pub_key() {
+        for i in 0..4 {
+            let (pub_key, priv_key) = mldsa_keygen([i as u8 + 1; 32]);
+            println!(
+                "pub const VENDOR_MLDSA_KEY_{i}_PRIVATE: ImageMldsaPrivKey = {priv_key:#010x?};"
+            );
+            println!("pub const VENDOR_MLDSA_KEY_{i}_PUBLIC: ImageMldsaPubKey = {pub_key:#010x?};");
+        }
+        let (pub_key, priv_key) = mldsa_keygen([0x10; 32]);
+        println!("pub const OWNER_MLDSA_KEY_PRIVATE: ImageMldsaPrivKey = {priv_key:#010x?};");
+        println!("pub const OWNER_MLDSA_KEY_PUBLIC: ImageMldsaPubKey = {pub_key:#010x?};");
+    }
+
+    #[test]
+    fn test_mldsa_verify() {
+        let (pub_key, priv_key) = mldsa_keygen([0x5a; 32]);
+        let digest: ImageDigest = core::array::from_fn(|i| i as u32 * 0x01010101);
+
+        let mut sig = sign_with_mldsa_key(&priv_key, &digest, [0xa5; 32]).unwrap();
+        assert!(verify_with_mldsa_key(&pub_key, &digest, &sig).unwrap());
+
+        // Wrong digest
+        let mut wrong_digest = digest;
+        wrong_digest[0] ^= 1;
+        assert!(!verify_with_mldsa_key(&pub_key, &wrong_digest, &sig).unwrap());
+
+        // Non-zero padding
+        *sig.0.last_mut().unwrap() |= 0x0100_0000;
+        assert!(!verify_with_mldsa_key(&pub_key, &digest, &sig).unwrap());
+    }
+
     #[test]
     fn test_lms() {
         let priv_key = ImageLmsPrivKey {
@@ -531,11 +864,47 @@ mod tests {
         };
         #[cfg(feature = "openssl")]
         let pub_key = generate_lms_pubkey::<OpensslHasher>(&priv_key).unwrap();
-        #[cfg(feature = "rustcrypto")]
+        #[cfg(not(feature = "openssl"))]
         let pub_key = generate_lms_pubkey::<RustCryptoHasher>(&priv_key).unwrap();
         assert_eq!(expected_pub_key, pub_key);
     }
 
+    #[test]
+    fn test_lms_verify() {
+        let priv_key = ImageLmsPrivKey {
+            tree_type: IMAGE_LMS_TREE_TYPE_HT_5,
+            otstype: IMAGE_LMS_OTS_TYPE_8,
+            id: [
+                0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d,
+                0x2e, 0x2f,
+            ],
+            seed: bytes_to_words_6([
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+                0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+            ]),
+        };
+        let message = [0x5au8; 48];
+        let nonce = [0xa5u8; 24];
+
+        #[cfg(feature = "openssl")]
+        type Hasher = OpensslHasher;
+        #[cfg(not(feature = "openssl"))]
+        type Hasher = RustCryptoHasher;
+
+        let pub_key = generate_lms_pubkey::<Hasher>(&priv_key).unwrap();
+        let mut sig = sign_with_lms_key::<Hasher>(&priv_key, &message, &nonce, 5).unwrap();
+        assert!(verify_with_lms_key::<Hasher>(&pub_key, &message, &sig).unwrap());
+
+        // Wrong message
+        let mut wrong_message = message;
+        wrong_message[0] ^= 1;
+        assert!(!verify_with_lms_key::<Hasher>(&pub_key, &wrong_message, &sig).unwrap());
+
+        // Corrupted authentication path
+        sig.tree_path[2][0] = (u32::from(sig.tree_path[2][0]) ^ 1).into();
+        assert!(!verify_with_lms_key::<Hasher>(&pub_key, &message, &sig).unwrap());
+    }
+
     #[test]
     fn test_lms_sig() {
         let priv_key = ImageLmsPrivKey {
@@ -723,7 +1092,7 @@ mod tests {
         ];
         #[cfg(feature = "openssl")]
         let sig = sign_with_lms_key::<OpensslHasher>(&priv_key, &message, &nonce, 5).unwrap();
-        #[cfg(feature = "rustcrypto")]
+        #[cfg(not(feature = "openssl"))]
         let sig = sign_with_lms_key::<RustCryptoHasher>(&priv_key, &message, &nonce, 5).unwrap();
 
         assert_eq!(
@@ -1034,7 +1403,7 @@ mod tests {
         #[cfg(feature = "openssl")]
         let sig =
             sign_with_lms_key::<OpensslHasher>(&priv_key, &message, nonce.as_bytes(), 5).unwrap();
-        #[cfg(feature = "rustcrypto")]
+        #[cfg(not(feature = "openssl"))]
         let sig = sign_with_lms_key::<RustCryptoHasher>(&priv_key, &message, nonce.as_bytes(), 5)
             .unwrap();
 
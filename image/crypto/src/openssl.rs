@@ -28,7 +28,10 @@ use openssl::{
     sha::{Sha256, Sha384},
 };
 
-use crate::{from_hw_format, sign_with_lms_key, to_hw_format, Sha256Hasher, SUPPORTED_LMS_Q_VALUE};
+use crate::{
+    from_hw_format, sign_with_lms_key, sign_with_mldsa_key, to_hw_format, verify_with_lms_key,
+    verify_with_mldsa_key, EccPrivKeyLoader, Sha256Hasher, SUPPORTED_LMS_Q_VALUE,
+};
 
 #[derive(Default)]
 pub struct OsslCrypto {}
@@ -105,6 +108,63 @@ impl ImageGeneratorCrypto for OsslCrypto {
         sign_with_lms_key::<OpensslHasher>(priv_key, &message, &nonce, SUPPORTED_LMS_Q_VALUE)
     }
 
+    fn mldsa87_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageMldsaPrivKey,
+    ) -> anyhow::Result<ImageMldsaSignature> {
+        let mut rnd = [0u8; 32];
+        rand_bytes(&mut rnd)?;
+        sign_with_mldsa_key(priv_key, digest, rnd)
+    }
+
+    fn ecdsa384_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> anyhow::Result<bool> {
+        let pub_key_x: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&pub_key.x);
+        let pub_key_y: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&pub_key.y);
+        let r: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&sig.r);
+        let s: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&sig.s);
+        let digest: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(digest);
+
+        let group = EcGroup::from_curve_name(Nid::SECP384R1)?;
+        let mut ctx = BigNumContext::new()?;
+
+        let pub_key_x = BigNum::from_slice(&pub_key_x)?;
+        let pub_key_y = BigNum::from_slice(&pub_key_y)?;
+
+        let mut pub_key = EcPoint::new(&group)?;
+        pub_key.set_affine_coordinates_gfp(&group, &pub_key_x, &pub_key_y, &mut ctx)?;
+
+        let ec_key = EcKey::from_public_key(&group, &pub_key)?;
+        let sig =
+            EcdsaSig::from_private_components(BigNum::from_slice(&r)?, BigNum::from_slice(&s)?)?;
+
+        Ok(sig.verify(&digest, &ec_key)?)
+    }
+
+    fn lms_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> anyhow::Result<bool> {
+        let message: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(digest);
+        verify_with_lms_key::<OpensslHasher>(pub_key, &message, sig)
+    }
+
+    fn mldsa87_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> anyhow::Result<bool> {
+        verify_with_mldsa_key(pub_key, digest, sig)
+    }
+
     fn ecc_pub_key_from_pem(path: &Path) -> anyhow::Result<ImageEccPubKey> {
         let key_bytes = std::fs::read(path)
             .with_context(|| format!("Failed to read public key PEM file {}", path.display()))?;
@@ -141,6 +201,8 @@ impl ImageGeneratorCrypto for OsslCrypto {
     }
 }
 
+impl EccPrivKeyLoader for OsslCrypto {}
+
 pub struct OpensslHasher(Sha256);
 
 impl Sha256Hasher for OpensslHasher {
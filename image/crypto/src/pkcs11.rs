@@ -0,0 +1,438 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   pkcs11.rs
+
+Abstract:
+
+    File contains an ECDSA-P384 signing backend for PKCS#11 tokens.
+
+    ECC private keys are referenced by PKCS#11 URIs (RFC 7512) and never
+    leave the token, e.g.
+
+        pkcs11:token=caliptra;object=vendor-ecc-0?module-path=/usr/lib/softhsm/libsofthsm2.so
+
+    If the URI does not contain `module-path`, the `PKCS11_MODULE_PATH`
+    environment variable is used. The PIN is always read from the `PKCS11_PIN`
+    environment variable; URIs with a `pin-value` are rejected so that PINs
+    are not stored in configuration files. The token must hold the public key
+    with the same label or id as the private key.
+
+    All other operations, including LMS signing, are performed in software.
+
+    To test with SoftHSM:
+
+        softhsm2-util --init-token --free --label caliptra --pin 1234 --so-pin 1234
+        pkcs11-tool --module /usr/lib/softhsm/libsofthsm2.so --token-label caliptra \
+            --login --pin 1234 --keypairgen --key-type EC:secp384r1 --label vendor-ecc-0
+        PKCS11_MODULE_PATH=/usr/lib/softhsm/libsofthsm2.so PKCS11_PIN=1234 \
+            CALIPTRA_PKCS11_TEST_KEY="pkcs11:token=caliptra;object=vendor-ecc-0" \
+            cargo test -p caliptra-image-crypto --features pkcs11 -- --ignored
+
+--*/
+
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+
+use caliptra_image_gen::ImageGeneratorCrypto;
+use caliptra_image_types::*;
+
+use cryptoki::context::{CInitializeArgs, Pkcs11};
+use cryptoki::mechanism::Mechanism;
+use cryptoki::object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle};
+use cryptoki::session::{Session, UserType};
+use cryptoki::types::AuthPin;
+
+use crate::{is_pkcs11_uri, to_hw_format, EccPrivKeyLoader, PKCS11_URI_SCHEME};
+
+#[cfg(not(feature = "openssl"))]
+use crate::rustcrypto::RustCrypto as SwCrypto;
+#[cfg(feature = "openssl")]
+use crate::OsslCrypto as SwCrypto;
+
+/// DER encoding of the secp384r1 named curve OID
+const SECP384R1_EC_PARAMS: [u8; 7] = [0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x22];
+
+/// Parsed PKCS#11 URI
+///
+/// Only the attributes needed to locate a key are supported.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct Pkcs11Uri {
+    pub token: Option<String>,
+
+    pub object: Option<String>,
+
+    pub id: Option<Vec<u8>>,
+
+    pub module_path: Option<String>,
+}
+
+impl Pkcs11Uri {
+    /// Parse a PKCS#11 URI
+    pub fn parse(uri: &str) -> anyhow::Result<Self> {
+        let Some(rest) = uri.strip_prefix(PKCS11_URI_SCHEME) else {
+            bail!("Invalid PKCS#11 URI {uri}: missing {PKCS11_URI_SCHEME} scheme");
+        };
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let mut result = Self::default();
+        for (attr, value) in Self::attributes(path, ';')? {
+            match attr {
+                "token" => result.token = Some(String::from_utf8(value)?),
+                "object" => result.object = Some(String::from_utf8(value)?),
+                "id" => result.id = Some(value),
+                // Only private keys are referenced.
+                "type" if value == b"private" => {}
+                "type" => bail!("Invalid PKCS#11 URI {uri}: type must be private"),
+                // Other attributes narrow down the token and are not needed.
+                _ => {}
+            }
+        }
+        for (attr, value) in Self::attributes(query, '&')? {
+            match attr {
+                "module-path" => result.module_path = Some(String::from_utf8(value)?),
+                // Do not echo the URI, it contains the PIN.
+                "pin-value" => {
+                    bail!("PKCS#11 URIs must not contain a pin-value, set PKCS11_PIN instead")
+                }
+                _ => {}
+            }
+        }
+
+        if result.object.is_none() && result.id.is_none() {
+            bail!("Invalid PKCS#11 URI {uri}: object or id required");
+        }
+        Ok(result)
+    }
+
+    fn attributes(s: &str, separator: char) -> anyhow::Result<Vec<(&str, Vec<u8>)>> {
+        s.split(separator)
+            .filter(|attr| !attr.is_empty())
+            .map(|attr| {
+                let (name, value) = attr
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("Invalid PKCS#11 URI attribute {attr}"))?;
+                Ok((name, percent_decode(value)?))
+            })
+            .collect()
+    }
+}
+
+fn percent_decode(s: &str) -> anyhow::Result<Vec<u8>> {
+    let bytes = s.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .ok_or_else(|| anyhow!("Invalid percent encoding in {s}"))?;
+            let hex = std::str::from_utf8(hex)?;
+            result.push(
+                u8::from_str_radix(hex, 16)
+                    .with_context(|| format!("Invalid percent encoding in {s}"))?,
+            );
+            i += 3;
+        } else {
+            result.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Ok(result)
+}
+
+/// ECC private key on a PKCS#11 token
+struct Pkcs11EccKey {
+    pub_key: ImageEccPubKey,
+
+    session: Session,
+
+    handle: ObjectHandle,
+}
+
+/// Image generator crypto that signs with ECC keys on PKCS#11 tokens
+#[derive(Default)]
+pub struct Pkcs11Crypto {
+    sw: SwCrypto,
+
+    /// Loaded PKCS#11 modules by path
+    modules: Vec<(String, Pkcs11)>,
+
+    keys: Vec<Pkcs11EccKey>,
+}
+
+impl Pkcs11Crypto {
+    /// Load the ECC private key at `uri`
+    ///
+    /// Returns the public key. `ecdsa384_sign` uses the token for signatures
+    /// with this public key, and software for all other keys.
+    pub fn load_ecc_key(&mut self, uri: &str) -> anyhow::Result<ImageEccPubKey> {
+        let uri = Pkcs11Uri::parse(uri)?;
+
+        let module_path = match &uri.module_path {
+            Some(module_path) => module_path.clone(),
+            None => std::env::var("PKCS11_MODULE_PATH")
+                .with_context(|| "PKCS#11 module-path not specified")?,
+        };
+        let pin = std::env::var("PKCS11_PIN").with_context(|| "PKCS11_PIN not specified")?;
+
+        let session = self.open_session(&module_path, uri.token.as_deref())?;
+        session.login(UserType::User, Some(&AuthPin::new(pin)))?;
+
+        let handle = Self::find_key(&session, &uri, ObjectClass::PRIVATE_KEY)?;
+        let pub_key_handle = Self::find_key(&session, &uri, ObjectClass::PUBLIC_KEY)?;
+        let pub_key = Self::read_pub_key(&session, pub_key_handle)?;
+
+        self.keys.push(Pkcs11EccKey {
+            pub_key,
+            session,
+            handle,
+        });
+        Ok(pub_key)
+    }
+
+    fn open_session(&mut self, module_path: &str, token: Option<&str>) -> anyhow::Result<Session> {
+        if !self.modules.iter().any(|(path, _)| path == module_path) {
+            let module = Pkcs11::new(module_path)
+                .with_context(|| format!("Failed to load PKCS#11 module {module_path}"))?;
+            module.initialize(CInitializeArgs::OsThreads)?;
+            self.modules.push((module_path.into(), module));
+        }
+        let (_, module) = self
+            .modules
+            .iter()
+            .find(|(path, _)| path == module_path)
+            .unwrap();
+
+        for slot in module.get_slots_with_token()? {
+            let label_matches = match token {
+                Some(token) => module.get_token_info(slot)?.label() == token,
+                None => true,
+            };
+            if label_matches {
+                return Ok(module.open_ro_session(slot)?);
+            }
+        }
+        bail!("PKCS#11 token {} not found", token.unwrap_or("<any>"));
+    }
+
+    fn find_key(
+        session: &Session,
+        uri: &Pkcs11Uri,
+        class: ObjectClass,
+    ) -> anyhow::Result<ObjectHandle> {
+        let mut template = vec![Attribute::Class(class), Attribute::KeyType(KeyType::EC)];
+        if let Some(object) = &uri.object {
+            template.push(Attribute::Label(object.as_bytes().to_vec()));
+        }
+        if let Some(id) = &uri.id {
+            template.push(Attribute::Id(id.clone()));
+        }
+
+        match session.find_objects(&template)?.as_slice() {
+            [handle] => Ok(*handle),
+            [] => bail!("PKCS#11 {class} {:?} not found", uri.object),
+            _ => bail!("PKCS#11 {class} {:?} is ambiguous", uri.object),
+        }
+    }
+
+    fn read_pub_key(session: &Session, handle: ObjectHandle) -> anyhow::Result<ImageEccPubKey> {
+        let attrs =
+            session.get_attributes(handle, &[AttributeType::EcParams, AttributeType::EcPoint])?;
+
+        let mut ec_point = None;
+        for attr in attrs {
+            match attr {
+                Attribute::EcParams(params) if params != SECP384R1_EC_PARAMS => {
+                    bail!("PKCS#11 key is not a secp384r1 key")
+                }
+                Attribute::EcPoint(point) => ec_point = Some(point),
+                _ => {}
+            }
+        }
+        let ec_point = ec_point.ok_or_else(|| anyhow!("PKCS#11 key has no EC point"))?;
+
+        // The point is usually wrapped in a DER OCTET STRING.
+        const POINT_LEN: usize = 1 + 2 * ECC384_SCALAR_BYTE_SIZE;
+        let point = match ec_point.as_slice() {
+            [0x04, len, point @ ..] if *len as usize == POINT_LEN && point.len() == POINT_LEN => {
+                point
+            }
+            point if point.len() == POINT_LEN => point,
+            _ => bail!("Invalid PKCS#11 EC point length {}", ec_point.len()),
+        };
+        if point[0] != 0x04 {
+            bail!("PKCS#11 EC point is not uncompressed");
+        }
+
+        Ok(ImageEccPubKey {
+            x: to_hw_format(&point[1..][..ECC384_SCALAR_BYTE_SIZE]),
+            y: to_hw_format(&point[1 + ECC384_SCALAR_BYTE_SIZE..]),
+        })
+    }
+}
+
+impl ImageGeneratorCrypto for Pkcs11Crypto {
+    type Sha256Hasher = <SwCrypto as ImageGeneratorCrypto>::Sha256Hasher;
+
+    fn sha256_start(&self) -> Self::Sha256Hasher {
+        self.sw.sha256_start()
+    }
+
+    fn sha384_digest(&self, data: &[u8]) -> anyhow::Result<ImageDigest> {
+        self.sw.sha384_digest(data)
+    }
+
+    fn ecdsa384_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageEccPrivKey,
+        pub_key: &ImageEccPubKey,
+    ) -> anyhow::Result<ImageEccSignature> {
+        let Some(key) = self.keys.iter().find(|key| key.pub_key == *pub_key) else {
+            return self.sw.ecdsa384_sign(digest, priv_key, pub_key);
+        };
+
+        let digest_bytes: Vec<u8> = digest.iter().flat_map(|word| word.to_be_bytes()).collect();
+        let sig = key
+            .session
+            .sign(&Mechanism::Ecdsa, key.handle, &digest_bytes)?;
+        if sig.len() != 2 * ECC384_SCALAR_BYTE_SIZE {
+            bail!("Invalid PKCS#11 ECDSA signature length {}", sig.len());
+        }
+
+        let sig = ImageEccSignature {
+            r: to_hw_format(&sig[..ECC384_SCALAR_BYTE_SIZE]),
+            s: to_hw_format(&sig[ECC384_SCALAR_BYTE_SIZE..]),
+        };
+        if !self.sw.ecdsa384_verify(digest, pub_key, &sig)? {
+            bail!("PKCS#11 token produced an invalid signature");
+        }
+        Ok(sig)
+    }
+
+    fn lms_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageLmsPrivKey,
+    ) -> anyhow::Result<ImageLmsSignature> {
+        self.sw.lms_sign(digest, priv_key)
+    }
+
+    fn mldsa87_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageMldsaPrivKey,
+    ) -> anyhow::Result<ImageMldsaSignature> {
+        self.sw.mldsa87_sign(digest, priv_key)
+    }
+
+    fn ecdsa384_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> anyhow::Result<bool> {
+        self.sw.ecdsa384_verify(digest, pub_key, sig)
+    }
+
+    fn lms_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> anyhow::Result<bool> {
+        self.sw.lms_verify(digest, pub_key, sig)
+    }
+
+    fn mldsa87_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> anyhow::Result<bool> {
+        self.sw.mldsa87_verify(digest, pub_key, sig)
+    }
+
+    fn ecc_pub_key_from_pem(path: &Path) -> anyhow::Result<ImageEccPubKey> {
+        SwCrypto::ecc_pub_key_from_pem(path)
+    }
+
+    fn ecc_priv_key_from_pem(path: &Path) -> anyhow::Result<ImageEccPrivKey> {
+        SwCrypto::ecc_priv_key_from_pem(path)
+    }
+}
+
+impl EccPrivKeyLoader for Pkcs11Crypto {
+    fn load_ecc_priv_key(
+        &mut self,
+        dir: &Path,
+        key: &str,
+        pub_key: &ImageEccPubKey,
+    ) -> anyhow::Result<ImageEccPrivKey> {
+        if !is_pkcs11_uri(key) {
+            return Self::ecc_priv_key_from_pem(&dir.join(key));
+        }
+
+        let token_pub_key = self.load_ecc_key(key)?;
+        if token_pub_key != *pub_key {
+            bail!("Public key of {key} does not match the configured public key");
+        }
+        Ok(ImageEccPrivKey::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_uri() {
+        let uri = Pkcs11Uri::parse(
+            "pkcs11:token=caliptra;object=vendor%20key;id=%01%02;type=private\
+             ?module-path=/usr/lib/softhsm/libsofthsm2.so",
+        )
+        .unwrap();
+        assert_eq!(
+            uri,
+            Pkcs11Uri {
+                token: Some("caliptra".into()),
+                object: Some("vendor key".into()),
+                id: Some(vec![1, 2]),
+                module_path: Some("/usr/lib/softhsm/libsofthsm2.so".into()),
+            }
+        );
+
+        let uri = Pkcs11Uri::parse("pkcs11:object=owner").unwrap();
+        assert_eq!(uri.object.as_deref(), Some("owner"));
+        assert_eq!(uri.module_path, None);
+
+        assert!(Pkcs11Uri::parse("owner.pem").is_err());
+        assert!(Pkcs11Uri::parse("pkcs11:token=caliptra").is_err());
+        assert!(Pkcs11Uri::parse("pkcs11:object=owner;type=public").is_err());
+        assert!(Pkcs11Uri::parse("pkcs11:object=owner%2").is_err());
+        assert!(Pkcs11Uri::parse("pkcs11:object=owner?pin-value=1234").is_err());
+        assert!(is_pkcs11_uri("pkcs11:object=owner"));
+        assert!(!is_pkcs11_uri("own-priv-key.pem"));
+    }
+
+    #[test]
+    #[ignore = "requires a SoftHSM token, see the top of this file"]
+    fn test_pkcs11_sign() {
+        let uri = std::env::var("CALIPTRA_PKCS11_TEST_KEY")
+            .expect("CALIPTRA_PKCS11_TEST_KEY not specified");
+
+        let mut crypto = Pkcs11Crypto::default();
+        let pub_key = crypto.load_ecc_key(&uri).unwrap();
+
+        let digest = crypto.sha384_digest(b"caliptra").unwrap();
+        let sig = crypto
+            .ecdsa384_sign(&digest, &ImageEccPrivKey::default(), &pub_key)
+            .unwrap();
+        assert!(crypto.ecdsa384_verify(&digest, &pub_key, &sig).unwrap());
+    }
+}
@@ -21,14 +21,20 @@ use caliptra_image_gen::{ImageGeneratorCrypto, ImageGeneratorHasher};
 use caliptra_image_types::*;
 
 use {
-    ecdsa::{elliptic_curve::sec1::ToEncodedPoint, signature::hazmat::PrehashSigner},
+    ecdsa::{
+        elliptic_curve::sec1::ToEncodedPoint,
+        signature::hazmat::{PrehashSigner, PrehashVerifier},
+    },
     p384::pkcs8::DecodePublicKey,
     rand::{rngs::OsRng, RngCore},
     sec1::DecodeEcPrivateKey,
     sha2::{Digest, Sha256, Sha384},
 };
 
-use crate::{from_hw_format, sign_with_lms_key, to_hw_format, Sha256Hasher, SUPPORTED_LMS_Q_VALUE};
+use crate::{
+    from_hw_format, sign_with_lms_key, sign_with_mldsa_key, to_hw_format, verify_with_lms_key,
+    verify_with_mldsa_key, EccPrivKeyLoader, Sha256Hasher, SUPPORTED_LMS_Q_VALUE,
+};
 
 #[derive(Default)]
 pub struct RustCrypto {}
@@ -93,6 +99,61 @@ impl ImageGeneratorCrypto for RustCrypto {
         sign_with_lms_key::<RustCryptoHasher>(priv_key, &message, &nonce, SUPPORTED_LMS_Q_VALUE)
     }
 
+    fn mldsa87_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageMldsaPrivKey,
+    ) -> anyhow::Result<ImageMldsaSignature> {
+        let mut rnd = [0u8; 32];
+        OsRng.fill_bytes(&mut rnd);
+        sign_with_mldsa_key(priv_key, digest, rnd)
+    }
+
+    fn ecdsa384_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> anyhow::Result<bool> {
+        let pub_key_x: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&pub_key.x);
+        let pub_key_y: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&pub_key.y);
+        let r: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&sig.r);
+        let s: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&sig.s);
+        let digest: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(digest);
+
+        let point = p384::EncodedPoint::from_affine_coordinates(
+            p384::FieldBytes::from_slice(&pub_key_x),
+            p384::FieldBytes::from_slice(&pub_key_y),
+            false,
+        );
+        let verifying_key = p384::ecdsa::VerifyingKey::from_encoded_point(&point)?;
+        let sig = p384::ecdsa::Signature::from_scalars(
+            *p384::FieldBytes::from_slice(&r),
+            *p384::FieldBytes::from_slice(&s),
+        )?;
+
+        Ok(verifying_key.verify_prehash(&digest, &sig).is_ok())
+    }
+
+    fn lms_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> anyhow::Result<bool> {
+        let message: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(digest);
+        verify_with_lms_key::<RustCryptoHasher>(pub_key, &message, sig)
+    }
+
+    fn mldsa87_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> anyhow::Result<bool> {
+        verify_with_mldsa_key(pub_key, digest, sig)
+    }
+
     fn ecc_pub_key_from_pem(path: &Path) -> anyhow::Result<ImageEccPubKey> {
         let key_bytes = std::fs::read(path)
             .with_context(|| format!("Failed to read public key PEM file {}", path.display()))?;
@@ -120,6 +181,8 @@ impl ImageGeneratorCrypto for RustCrypto {
     }
 }
 
+impl EccPrivKeyLoader for RustCrypto {}
+
 pub struct RustCryptoHasher(Sha256);
 
 impl Sha256Hasher for RustCryptoHasher {
@@ -2,9 +2,10 @@
 
 use caliptra_image_gen::{ImageGeneratorOwnerConfig, ImageGeneratorVendorConfig};
 use caliptra_image_types::{
-    ImageEccPrivKey, ImageEccPubKey, ImageLmsPrivKey, ImageLmsPublicKey, ImageOwnerPrivKeys,
-    ImageOwnerPubKeys, ImageVendorPrivKeys, ImageVendorPubKeys, IMAGE_LMS_OTS_TYPE,
-    IMAGE_LMS_TREE_TYPE,
+    ImageDigest, ImageEccPrivKey, ImageEccPubKey, ImageLmsPrivKey, ImageLmsPublicKey,
+    ImageMldsaPrivKey, ImageMldsaPubKey, ImageOwnerPrivKeys, ImageOwnerPubKeys,
+    ImageVendorPrivKeys, ImageVendorPubKeys, IMAGE_LMS_OTS_TYPE, IMAGE_LMS_TREE_TYPE,
+    VENDOR_MLDSA_KEY_COUNT,
 };
 use caliptra_lms_types::bytes_to_words_6;
 
@@ -237,6 +238,1220 @@ pub const OWNER_ECC_KEY_PRIVATE: ImageEccPrivKey = [
     0x59fdf849, 0xe39f4256, 0x19342ed2, 0x81d28d3d, 0x45ab3219, 0x5174582c, 0xecb4e9df, 0x9cc2e991,
     0xb75f88fd, 0xfa4bc6a4, 0x6b88340f, 0x05dd8890,
 ];
+/// Generated with `test_print_mldsa_private_pub_key` in caliptra-image-crypto.
+/// The digests are the SHA-384 of the public keys.
+pub const VENDOR_MLDSA_KEY_0_PUBLIC: ImageMldsaPubKey = ImageMldsaPubKey([
+    0xd32877ca, 0xfe81700b, 0x02974a22, 0x04866bf6, 0x3fa9c1fc, 0xb5c9598a, 0x13ffcf2c, 0x35716c58,
+    0x08ca0048, 0x39c35ce0, 0xedd2cfe5, 0x5397c48b, 0x0cd79a22, 0x029102f4, 0xdd6fee23, 0xecc8963e,
+    0xc8c50a0a, 0x6fedb481, 0x6336e73f, 0x17316c00, 0x5d8b76a1, 0x9fc8effc, 0xa7b71128, 0x4e4dbf76,
+    0x704aa338, 0xb0da02a3, 0x12f7b843, 0xbf882f89, 0x7229eccc, 0x7358356f, 0xb1871719, 0xf10ce5b8,
+    0xd0da9c66, 0xd6d8f2d2, 0x68822d36, 0x4f19ffb8, 0xd87a14a9, 0x3935f001, 0xd999419d, 0x4497d6df,
+    0x34284aa1, 0x5a1951d5, 0xf07f74ba, 0x88da64e5, 0x02412675, 0xb82ddb69, 0x694cb208, 0xe54d2bdf,
+    0x75552a5e, 0xe09e4e91, 0xbb26ddbf, 0x99e65d9d, 0xa0a2a98e, 0x1b6ba702, 0x60585e26, 0x442ed1e6,
+    0xf3a6d970, 0x73e487fc, 0xe65d324e, 0xc5558e91, 0x6e062aee, 0x34aadc99, 0x79411019, 0xf5336caa,
+    0xbd26daa6, 0x99b9b239, 0xa3ad741b, 0x37cb5cae, 0x9ce1ae81, 0x5b6c9360, 0x26254c1c, 0x53b8ffd1,
+    0x77d05eec, 0x14eec79a, 0xb569b431, 0x7508eebc, 0x311b0698, 0xf2be420d, 0x05b00edf, 0xca24c943,
+    0xc68c8f01, 0xdc56a5b4, 0xb15a8ab8, 0x5008020c, 0x58c29857, 0xbc0816cc, 0x1c083097, 0x16c9c00a,
+    0xf7f6ee6b, 0x75d7b8b7, 0x5d5e8a1d, 0xe97065a8, 0x62716873, 0xedea9df5, 0x2796f174, 0x10ac99ec,
+    0x21fae8e9, 0xef184dd3, 0xe519e5f4, 0xb6a98fb6, 0x5b61a73b, 0x4b74a0fe, 0x58fece71, 0x26a7a4b6,
+    0xb288411c, 0xd57a190b, 0xbd611ab7, 0xc76c254e, 0xdc6f934a, 0xce80116b, 0xfbf48dd4, 0x92aa40c0,
+    0x61176b3e, 0x785c4b21, 0xb730674f, 0x8744bf42, 0xdd7b674b, 0xae33647a, 0x3beee426, 0x207cbc45,
+    0x3e04c381, 0x2aa4ccad, 0xad5d69e0, 0xe9d26796, 0x4e7f622f, 0x5cd13960, 0x5761005f, 0xd6259ba7,
+    0xb2a50ab3, 0x5c9f5eda, 0x29689ba6, 0xa5d3acb6, 0x77a01737, 0x3a9b0057, 0x57bc6f74, 0x607fcd43,
+    0x2aa2c132, 0x029daa1d, 0x0b95d176, 0xc843e8f6, 0xd486e874, 0x4cf5e59d, 0x37ad80f0, 0xddc89beb,
+    0x20e56970, 0x0a4d7341, 0x25d774d5, 0x2831f862, 0x30e2ead0, 0x6ebdadb7, 0x82f0a135, 0x3068c47f,
+    0x5cd85e12, 0x96669af4, 0x7b0796a9, 0x6f7d3853, 0x6b7091c6, 0x30600bb5, 0x5508ce0e, 0x13d8f340,
+    0x08923551, 0x211a2296, 0x5c6edfac, 0xa358a0a9, 0x08355314, 0x4033b936, 0xdd10d6b1, 0xc4a95b70,
+    0x17206825, 0xed45d271, 0xa2eec4a4, 0x47ee2601, 0x38e6694f, 0xc0ee994e, 0xca206eec, 0x6e408a47,
+    0x63d0246f, 0xefb1b7bc, 0x593028d3, 0xf918a7cd, 0xc0032f64, 0x13e92091, 0xf95a8f5f, 0x5793b228,
+    0x9a23ffc9, 0x58a9dbfb, 0xcb1584a2, 0x6c80b52c, 0x7361ee8e, 0xc598eb07, 0x24b1b167, 0x1182da39,
+    0xcfdc520f, 0xc4c22070, 0xf4aac98c, 0x5199e762, 0xd92d7399, 0x0c1cdf1a, 0xadbb3923, 0x816a7ea6,
+    0x58a39b94, 0xcd920a35, 0x04f2802c, 0x9513f79b, 0x89214988, 0xc00061ab, 0x1251bbef, 0x0924415a,
+    0xe1a13ef9, 0xf150a307, 0xdadb3552, 0xe589dc04, 0x6278cafa, 0xfa912096, 0x79ad8b72, 0x8af2f6e9,
+    0x5e1eb8b7, 0x56cdb613, 0xf2bea06b, 0x1920eaca, 0x2917db69, 0xc4fed29e, 0x343166ff, 0xb0fe472a,
+    0x0fa25bf7, 0x3cc554ae, 0x9a07e171, 0xd50943c1, 0x641b6e3d, 0xe9c1577f, 0x9f3a6c2a, 0x794989a8,
+    0x1e050558, 0x556460d7, 0xf049e330, 0x12856050, 0x7d179169, 0xd4dd9ab3, 0x78593a64, 0x5e97086e,
+    0xe5ded275, 0x1c7bdc26, 0x22e9c52f, 0x8768aacf, 0xa9158584, 0xa60fe030, 0x435436b7, 0x650636a7,
+    0xe62ee1ae, 0x221d4107, 0xf3955dca, 0x89be3d40, 0x0b22bc25, 0x1cdf1572, 0xc7092b16, 0xbfaaaa59,
+    0x852e8d52, 0x4c461637, 0x5eb48697, 0x47c8acf5, 0x5729f8be, 0x8c7f6a8e, 0x099c56b7, 0x8bb87f74,
+    0x687aa2de, 0xd9b6840b, 0x760b93e0, 0x697c125e, 0xd57abd09, 0x512cc252, 0x2c6cc441, 0x289eb0dd,
+    0x1ee0f88a, 0xd244802c, 0xb1903e1d, 0x61c9ca2f, 0xfb2f413c, 0x4bf0e75d, 0x9e763ee7, 0x4dacc4b6,
+    0x77c89840, 0x9dc271a4, 0x97d4b8d3, 0x4ba67c50, 0x9016f095, 0x449f1528, 0x2718914e, 0x02596823,
+    0xe373f948, 0x91850a35, 0x2f0ab8fc, 0xc36795e7, 0xcbda307a, 0x907e2b7a, 0x5c626ad9, 0xf858d1e8,
+    0x6f331f42, 0xa3914de4, 0xfbc58627, 0xa49306a0, 0x4ccd9835, 0xec970ed6, 0x89903c46, 0x4da51710,
+    0x0cdfdd85, 0xdf0a23c4, 0xcf7f885d, 0xe7a8cd09, 0x6043b9fb, 0x4366df3f, 0x907b64d1, 0x8cd6ebfe,
+    0xc1a06078, 0xc55a432c, 0xcdd1d638, 0xda118c42, 0xcdbc9082, 0xc94feff3, 0x390cf3e1, 0xcd29659e,
+    0x7d9a7b3e, 0xafa3da1f, 0x30ea8336, 0x464828e1, 0xf55972df, 0x9ef0f9b5, 0x3af6fb82, 0x80fe7437,
+    0xa54823ef, 0x098c3a82, 0x53c73a2f, 0x4132b0b8, 0xd2800b1e, 0x28a68a51, 0x3a868b05, 0xaae058f9,
+    0xedda20f1, 0x4e042611, 0xb32633c6, 0x6a3fe05e, 0x7c738bc3, 0x213af10d, 0xd89b52cf, 0xb6ef5bd7,
+    0x8ecbd570, 0xf17ee7a6, 0xcb409f0c, 0xa56a39b1, 0x4c2c7ce6, 0xc598d25c, 0x3895566f, 0x17cc8d83,
+    0x25a47354, 0x0b35e092, 0x34542773, 0xc0bc4cda, 0xd08314fe, 0x3e0e0f5d, 0x9aac9a97, 0x05974091,
+    0x1bb91d38, 0x2c47432f, 0x4998a621, 0x9d8e35f5, 0xc5424007, 0x96083b60, 0x3db98642, 0x5f473444,
+    0x4daa7b12, 0x9bd698db, 0xd386417f, 0x1fc41a88, 0x58ee1333, 0x59484b01, 0x8160da44, 0xa089e6cd,
+    0x10f0828a, 0x9da6ba40, 0xd6e3a233, 0x93088483, 0x55b354c0, 0xf8debd8c, 0x93dff4ca, 0xe010bd2d,
+    0x28ca74a8, 0x53d6ecf7, 0x40ff7fb9, 0x63026d25, 0x87eeddab, 0x5728c77a, 0xa4fb7a6d, 0x1b92fe28,
+    0xb923e2ac, 0xfc84da74, 0xa23f067c, 0x4809ea40, 0x859c12d8, 0x384b6a97, 0x11600dbf, 0xd560be69,
+    0x4371ffc4, 0x1fa300f6, 0x23836e67, 0x7c1b445f, 0x63bd9faa, 0xab378853, 0xd0890caa, 0x76c28a3e,
+    0xba509901, 0x0f2e01af, 0x2346d3be, 0x39c23d0c, 0x5558e315, 0x004cee8b, 0xb1177b8e, 0x74ae556d,
+    0xa244473d, 0x039e2a69, 0xe2b946c1, 0xa337e12b, 0xa5683a23, 0x92479cdb, 0xbae5928b, 0x4df78e35,
+    0x29af06bb, 0x29a507c5, 0x0153f229, 0xb09325fd, 0xa0b213d7, 0x341956b6, 0x3bc04bf9, 0xf4f5a09a,
+    0x4839c138, 0xab5ad6fe, 0x194d6f91, 0x20004c14, 0x315afc41, 0xfc47f5e8, 0x7a4378c1, 0xaf5e7595,
+    0x1beb6b82, 0x62a30a4e, 0xa06ff89d, 0x788ed3d6, 0xa87dd789, 0x4c8f630e, 0x7d7a5ffe, 0x9b894ee1,
+    0x233bc146, 0xce6fa10e, 0x61df9e88, 0x33fd7e85, 0x68f8c1a8, 0xf142e5c5, 0x0ef321ee, 0x534c7de5,
+    0x4e141e9d, 0x24c4e5ca, 0xecd66b9d, 0x9ce48e1f, 0xc8e91831, 0x32626575, 0x12d49de1, 0x0d3d9c04,
+    0xea6ec55c, 0xca6f5fde, 0x9d13a262, 0x9ef2990e, 0x7810cf07, 0xf5257f58, 0xa8453103, 0x13fe19aa,
+    0x9019d976, 0x919886e2, 0x3c3e82ac, 0xd0261790, 0xddb1d795, 0xa6e814e7, 0xaf8f16af, 0xdb99b743,
+    0xfe7d9d6c, 0x30718a57, 0x8a4ea200, 0xb0c357b3, 0xa925ed2b, 0x4ce85249, 0xd0219704, 0x074d19f2,
+    0x05ded820, 0x9d0264ce, 0x07dc040f, 0xd8e1800c, 0x2cb49b3d, 0x25c43ade, 0x9797afe9, 0x9f5d21ae,
+    0xbaf9a4be, 0xfcea962c, 0xcdc893e1, 0x6eefa3cf, 0x4efbd9a9, 0xa1bd36b4, 0x1ff03c91, 0x9cc01583,
+    0x06e2b267, 0x8f001618, 0x7d7899c6, 0xd28a4211, 0xae9f3b35, 0x185f7277, 0x4282f2b9, 0xb1b72686,
+    0xbe38f709, 0xdd25584a, 0xac7db9df, 0xb8ec73b0, 0x63660888, 0xc539666e, 0x7e589fb9, 0x4643bef6,
+    0x5c0d80a3, 0x454de7d4, 0x2896689d, 0xc600d06e, 0xf50009be, 0x9e1f1fae, 0x137521ad, 0x7a09583b,
+    0x1f1a8641, 0xf914d5bd, 0x265b0079, 0x85d7f559, 0xcf08f1bd, 0x4ea81c02, 0xb46fba2d, 0x5741c8bd,
+    0xd787ff0b, 0x36e6b945, 0xbb2dc84c, 0x8f4ce474, 0x3413b54d, 0xf71f5b8d, 0xa3281682, 0x4e2f96b4,
+    0xc57636d3, 0x471753cd, 0xf60a9ce7, 0x64df3a7e, 0x14e135ce, 0x4741e4ea, 0x75b987c5, 0xd760bb38,
+    0x96c0e06d, 0x9b9ca57d, 0x5443e102, 0x5a3fd626, 0xf74a5ebf, 0xda0c37eb, 0x20ee11a6, 0x13470a54,
+    0x4847cf95, 0x922816b7, 0x345fc2aa, 0x9308caa4, 0x782f65e7, 0xb4111418, 0xbc668b65, 0xb6c89223,
+    0xdc85e850, 0x997dc5a3, 0xb10a16e8, 0xb6b5dea6, 0xaf89d210, 0xaa8ea1f8, 0x9243d980, 0xfc4e6d79,
+    0xa2d21a1a, 0x939b5360, 0x205c0b61, 0xcd8911f1, 0x774b1eb6, 0xf825d511, 0x21dcceff, 0xd409f018,
+    0x0b989782, 0xee9e9818, 0xb044928f, 0x1d1796cb, 0xf74d1ea3, 0x5c5a2df9, 0x7336e8bd, 0x62413dd1,
+    0x68ec818c, 0xf4f0dddc, 0x8450112d, 0x5f292efa, 0xbd6a6f8e, 0x5de0575f, 0x7f35689c, 0x5b6079ec,
+    0xa1e17dee, 0xa29eb0f9, 0x8597e456, 0x20064b4a, 0xf80801a0, 0x3b5f8848, 0x6eeb5fa6, 0xfe3b3722,
+    0xe6ebb700, 0xf7cc6864, 0x85d3f11a, 0x8100afc9, 0xdcfa2be0, 0xb0cd5b4d, 0xd137026c, 0xbc607c6f,
+    0x93f4c058, 0x9ee2f055, 0x2d8f892d, 0xec071069, 0xdfcbc270, 0x3fa34ac0, 0x101b65bf, 0x57d37add,
+    0xe6fa4310, 0xe45ad47b, 0x1be13cab, 0xadc07e87, 0x9dfb5c76, 0xbedab929, 0xf3ced358, 0x65e3123c,
+    0xd4a66ca6, 0x686e2ac2, 0xe3ae65c1, 0x8bb015b5, 0xcb20288c, 0x0be6c32b, 0x00d763f2, 0x48738f48,
+    0x82cb139f, 0x98d122fd, 0x67aad867, 0x6bf58efa, 0xc80108b3, 0x586fa66c, 0x1157f263, 0xaa946503,
+]);
+pub const VENDOR_MLDSA_KEY_0_PRIVATE: ImageMldsaPrivKey = ImageMldsaPrivKey([
+    0xd32877ca, 0xfe81700b, 0x02974a22, 0x04866bf6, 0x3fa9c1fc, 0xb5c9598a, 0x13ffcf2c, 0x35716c58,
+    0x59fd5bfa, 0x388629ee, 0xe19d84b5, 0x87cbe05d, 0xbf97164e, 0x8a072e04, 0x64355658, 0x2d495a49,
+    0x244e2be9, 0xa8429aa2, 0x45d12f38, 0x55e8a6a1, 0xed873b1f, 0x9099757d, 0x34ff2bae, 0x67c63837,
+    0xeb637206, 0xf804a3a3, 0x542e82b8, 0x4e582c0e, 0xb234bfe1, 0xe59d358b, 0x27ac9192, 0xa8605eae,
+    0x0308c001, 0x80d26da4, 0x0ca11128, 0x9a441819, 0x24c84638, 0x7216624e, 0xd2023813, 0x18592012,
+    0x4980c288, 0x00520858, 0x421b8d97, 0x0c36dc71, 0x0c2c0013, 0x44c38912, 0x4d801930, 0x090d8303,
+    0x40030597, 0x8a41092c, 0x2270c40b, 0x09098c48, 0x24461806, 0x09618904, 0x090344a7, 0x7036d92a,
+    0x9468a2e0, 0x18a08896, 0x7224c112, 0xd2820260, 0xc72370b6, 0x8e02c248, 0x09619258, 0x08a16c19,
+    0x66171122, 0x916e3123, 0xb4196612, 0x7094d271, 0xc8052442, 0x39182182, 0x8990c461, 0x0c862110,
+    0x009069a4, 0x8cc4d408, 0x0429a31b, 0xc91b3194, 0x88348171, 0x01709054, 0x32ca4184, 0x69329a91,
+    0x5988b442, 0x34184628, 0x69a8a30a, 0x5b4d8623, 0xb0548030, 0x91928464, 0xdb71b509, 0x94611018,
+    0x86481a31, 0x59061251, 0xb44020c6, 0x7136c368, 0x892934a2, 0x44d088c2, 0x6e16d805, 0x945228da,
+    0x481849b6, 0x60404a89, 0xc9708011, 0x491b2648, 0x8020da00, 0x0288a909, 0x825b0900, 0x6524c164,
+    0x1409300a, 0x44046635, 0x04c28165, 0xc3862401, 0x80dc4512, 0x4e424425, 0xe16604c0, 0x284b3014,
+    0x46250951, 0x00411603, 0x18930421, 0x84a68431, 0x902c1848, 0x36444608, 0x0228c382, 0x104a431b,
+    0x409b11a9, 0x5128506a, 0xc9521848, 0x04580228, 0x20c9146e, 0xa2122719, 0x22584598, 0x85b9142e,
+    0x1c21a20c, 0x94827029, 0x4e148969, 0x4b4a2890, 0xb28a2800, 0x85280244, 0x824482cc, 0x111405c2,
+    0x89882161, 0x202db6d4, 0x44892a00, 0x6c36d348, 0x23259052, 0x98849083, 0x0d38d051, 0x638026c4,
+    0x07120120, 0x04980821, 0x484c0710, 0x90630404, 0x6e185a49, 0x092e2321, 0x150b0234, 0x2d86d868,
+    0x49519489, 0x02a249b2, 0x8e38602e, 0x638996a4, 0x90e460a6, 0x5144d091, 0x08498652, 0x84120c14,
+    0x81c89b0c, 0xd320c90b, 0x390049a0, 0x7092a46d, 0xc8104920, 0x931c8d04, 0x01865128, 0x5c8d90d8,
+    0x38810908, 0x09184b82, 0x1180085c, 0x00838611, 0x0244916a, 0x48262443, 0xc6033232, 0x92369441,
+    0x84004901, 0x04482114, 0x7104828d, 0x48719924, 0x988b68c2, 0x50c2d40c, 0x10822860, 0x10cb7244,
+    0x8d40894d, 0x0c80b10a, 0xa4230591, 0x2014a428, 0x14042822, 0xb0194428, 0x89060450, 0x2129a61c,
+    0xb4594444, 0x28424284, 0x904c808b, 0xc9142410, 0x6824e151, 0x03120048, 0x350024c3, 0x44c42249,
+    0xca2c848c, 0x121920b4, 0x2609212c, 0x5c518893, 0x44888a30, 0x01800b2d, 0x9930b899, 0x46896a46,
+    0x2998e428, 0xc2422240, 0x00493196, 0x6830222c, 0x0949a8d2, 0x265a1224, 0x0894434a, 0x9a85b0c4,
+    0x18c34482, 0x64a09926, 0xe48528a0, 0x16803110, 0x8a34a230, 0x108224d1, 0x96e10039, 0x85150001,
+    0xdb26441c, 0x491b6906, 0x3184e012, 0x012e1310, 0x10a37123, 0x81c7006e, 0x01664014, 0x96013004,
+    0x08986224, 0xe324429a, 0x241b91b4, 0x31a2d086, 0x9290820b, 0x22994422, 0x9082e404, 0x52008510,
+    0x226160b2, 0x31b11070, 0x0b89088a, 0x32a36da4, 0x00249309, 0x1c6ca4a1, 0xb8c125b6, 0x09384b8d,
+    0x824e22d9, 0x40406136, 0x64342146, 0x0224020b, 0x862421b9, 0x61121964, 0x8c60a89a, 0x38938806,
+    0x2c40416a, 0xa02db710, 0x32d10012, 0x6982036c, 0x0b6446e0, 0x30522101, 0x01a8816c, 0x098d34dc,
+    0x02915119, 0x90891401, 0xd16da29a, 0x96d90da2, 0x04900a6d, 0xcb689648, 0xb4512642, 0x0000d184,
+    0x926200a2, 0x34220dc6, 0x6192e265, 0x09889693, 0x38928106, 0x4932236c, 0xd361860c, 0xb5222a10,
+    0x04245185, 0x03282482, 0x84204e18, 0x2101220d, 0x196800a3, 0x29034808, 0x71944368, 0x00401699,
+    0xc05025c2, 0x52245328, 0x10098403, 0x14e009b2, 0x24b0086e, 0xa151b84c, 0x25187096, 0x62350088,
+    0x0010149a, 0x38408419, 0x30c01b0e, 0x11240691, 0xb1180a11, 0x51824a68, 0xdc60c689, 0x34090e22,
+    0x71a0414d, 0x206e430b, 0x38c44c10, 0x6d165048, 0x110a2691, 0xc80430b9, 0x10492340, 0x0025980a,
+    0xb0036242, 0x0db31c44, 0x88640062, 0x36914204, 0x64c2e040, 0x538c8009, 0x009a0226, 0x6cc2d164,
+    0x204dc51a, 0x30018c08, 0x84c8602e, 0x18424014, 0x06a14836, 0x62050111, 0x58062454, 0xb6602dc8,
+    0x70200364, 0x8b41c90b, 0x384951a6, 0x4c162452, 0x0328841a, 0x96044ca6, 0x70908825, 0x1488b2a4,
+    0x229111c3, 0x28361940, 0xd9883063, 0x444185a0, 0x40089268, 0x0165345c, 0x149b8db7, 0x90940b88,
+    0xd865a6d4, 0x14640a28, 0x11011821, 0xd1608811, 0x180b4890, 0x61120b44, 0xc14604e4, 0x490a7092,
+    0x10424112, 0xd9899501, 0xc72148b6, 0x0095106d, 0x9284a003, 0x34db8a08, 0x6d210970, 0x198cc6a4,
+    0xa6516e15, 0x0412092d, 0x005204d3, 0x061949a1, 0x42280990, 0x01841482, 0xa4510d31, 0x71029344,
+    0x1886311a, 0x34004e01, 0x81821c20, 0x935202c4, 0x908a3232, 0x2c322228, 0xd38c82e3, 0x06116880,
+    0x4848e129, 0xd26c8453, 0x48512d36, 0x88120985, 0x13422612, 0x24006243, 0x22380c2d, 0x01012882,
+    0x30932436, 0x44a84a72, 0x0225385a, 0x14a18a35, 0x70b05165, 0x848922d2, 0xb2e34580, 0x25430348,
+    0x132cf08d, 0xeab99da9, 0x2c076413, 0x75a60dbd, 0x17ea8a65, 0x4d513b32, 0x4a34f099, 0xfd35973e,
+    0xc246bce4, 0x281c3306, 0x9fdeb3fb, 0xfa67b46e, 0x9f49d1a4, 0x49e818e2, 0xbb517b0f, 0xaa3f94ca,
+    0x2f019e6e, 0x4536d0cb, 0xefe80b51, 0x3293c61e, 0x4a0d0a46, 0xb0fd1ad0, 0x90d46c95, 0x29ecfc68,
+    0x9e081061, 0x0d384120, 0x6d73f0eb, 0x02ff0186, 0x408b2822, 0x8e994687, 0x6ddc6845, 0xfb56605a,
+    0x745be71a, 0xf7f0135d, 0x711f0e9d, 0x9c3b15b5, 0xcafb2f1c, 0x9a857ca5, 0xbfd70091, 0xaa0133b9,
+    0xa1f33671, 0xba6c1ed7, 0x13051f6a, 0xdb9adfbe, 0x056e18ef, 0x54d2a3b8, 0xe368053c, 0x91b013a2,
+    0xe96c0ebb, 0xee0516f8, 0x9137291c, 0xa860157a, 0xae1959cb, 0xbb94cd0b, 0xbb4f2144, 0x28bc2db9,
+    0x8a3d15f4, 0x58de4018, 0x8f494e0b, 0x96bea3e8, 0xa2231781, 0xc2f0575e, 0x430d5d3c, 0x1fc302f7,
+    0xba9ccf6d, 0xc8387256, 0xb9d05707, 0xe7d0ec1b, 0x168b7934, 0x30303506, 0x406fa222, 0x4c687403,
+    0xbf21a457, 0xd6ed974b, 0xed948551, 0x0f305539, 0xfe912ca5, 0x1ab9cd30, 0x3299da3e, 0xb8d6be56,
+    0x1477d977, 0x217b4a8f, 0x60c9810a, 0x4c08c9f9, 0xf457e27f, 0x5f05b0cf, 0x15b3ca10, 0x5a2801fb,
+    0x85b8eb8f, 0x3caad567, 0xd8dcf0fc, 0x57f5a8f0, 0xdec882d9, 0x0fed2da4, 0x31ce24a3, 0x222811f7,
+    0x32763f2f, 0x042390a7, 0xb41a00d9, 0x4815111e, 0x132a3a54, 0xfd86dbe5, 0xee3ee706, 0x8972c706,
+    0x0a7879b4, 0xa9191d85, 0x37734d90, 0x81bd097c, 0x166471d9, 0xff7cb15a, 0x0559fee9, 0x75047ae6,
+    0x4d8c3bbb, 0xf090a42a, 0x02a4c4d7, 0x7fb44cd1, 0x5a6efbf6, 0xcf0ac855, 0xf9cbfde0, 0x92b733d0,
+    0x3def24a0, 0x64500ccf, 0x47737417, 0xe5e89511, 0x76e9b689, 0x5c6cc20f, 0x74f4cf2b, 0x02eaf059,
+    0x2a03d908, 0xe2b24931, 0x966b9c36, 0x74ca6abc, 0x2a1f7e88, 0xa55ee60a, 0x112459c4, 0x55d83d70,
+    0xc83d6932, 0xdc4aeb7d, 0xc7a56343, 0xc24ee1ec, 0x5cb500d0, 0xe2bab945, 0x7d6a7d7e, 0x30dfb9f7,
+    0xb480c1d4, 0x897c4cab, 0x107f734e, 0x221585b4, 0xa5bb4e60, 0x56be5969, 0x17ac0728, 0x26c4f6a0,
+    0xa78372b4, 0xd36db4d8, 0x22af2b5e, 0x5d150f61, 0x64dca75c, 0x72078848, 0x24d764cd, 0x1c244343,
+    0x31b07f7d, 0x9701d735, 0xf3092923, 0xff5d39e2, 0xc5f25e84, 0x3c29a1be, 0xfdd20380, 0xe78abba4,
+    0xbc9c2ab7, 0x063e45c1, 0xfdd66749, 0x26fae56c, 0x8f387d6e, 0x1ac94c1e, 0xf7fe2fac, 0x22a1a217,
+    0x1effa0f7, 0x96210f7e, 0x0b43b501, 0x75b93a00, 0xda543d6f, 0xe83bf87a, 0xf42e24bf, 0xacac7c2c,
+    0x2d5966d9, 0x2ccb1ce3, 0x85aac147, 0x08c48f11, 0xb49a691f, 0xea827378, 0x65f3af5b, 0x004baab8,
+    0x207aa460, 0xfbe1defc, 0x998ec50d, 0x637bea2f, 0xf0ce5ca8, 0x2af73a61, 0xf4753e31, 0x141d573f,
+    0xce0f3298, 0x9fc5c0c3, 0xfcd54a3c, 0xdeee2fe4, 0x171b6c5f, 0x40791250, 0x30360f15, 0xc3257ec8,
+    0xeb7b67cc, 0xfcfa0f34, 0xf892b40c, 0x98e4c4ae, 0x69b9062e, 0x89c5fe9e, 0x85a16392, 0xd2d20d34,
+    0xc84edc28, 0x20ee8fb4, 0xb348f035, 0x9e1819b0, 0x2f4ecb55, 0xd9d3fc97, 0x06bff948, 0x8d3b5f30,
+    0x6e2cf8d1, 0x0db8b2d5, 0xee34e32a, 0x6f8e3984, 0x75f6d12e, 0x86a18aad, 0x07d1fa33, 0xc2775a6d,
+    0xbd589582, 0xb2d0aa56, 0xf9f1dfe1, 0xe303e0a3, 0x39b74f88, 0x9316f32a, 0xdc9cb3c1, 0x9ff50386,
+    0x13946f11, 0x2926ee52, 0xc94cd455, 0xdf69b068, 0x570b46b2, 0x7e12301c, 0x3ca5067d, 0xb6db5e0c,
+    0x5d007374, 0x098ad89b, 0x93f20931, 0x97c38aa0, 0x7b72ccef, 0xac6ae71a, 0x98191754, 0x25d147e7,
+    0x3af07b70, 0xaa66926d, 0x33c4ee0d, 0xcdac7ca0, 0x1e2315f1, 0x49ff2e9d, 0x39f36a0a, 0xa41282d0,
+    0x8d448697, 0xc0c0c62b, 0xf02b2b5e, 0xe829ea5a, 0xd88a2947, 0xeceb738b, 0x55e5cda6, 0x200fb969,
+    0x33b56aa6, 0x38fa1e3c, 0xd91340ef, 0x4893cbbc, 0xa4bce032, 0x08b389bb, 0x537521a6, 0x45fbee67,
+    0xc7e114fa, 0x74de7380, 0xdd1a21c9, 0x4db77db5, 0xa2a265fe, 0x90b18505, 0x31a33a90, 0xe7d7443b,
+    0x583fc31e, 0xddfa6488, 0x9e194d1d, 0x0278513f, 0x6afdb2a6, 0xe173c1e3, 0xef19c9ad, 0xce9ba278,
+    0x952b052d, 0x0139753d, 0x1dfba142, 0xd4bf1f06, 0x3fa80f6d, 0x26af0887, 0x6c71afec, 0x3a5a7a39,
+    0x9191506c, 0x9948442b, 0x54e5f6ee, 0x3c418f2e, 0xd79f909a, 0x37b8402a, 0xe93f4443, 0xa70f2129,
+    0x52a1909a, 0xe9478b30, 0xe21fc940, 0x340de28e, 0x74843d1d, 0x69a2bcaa, 0x385660ec, 0xa099e7e4,
+    0x9d43135c, 0x69b88dd5, 0x33ad30a0, 0x9b647575, 0xec150c89, 0xb5ec02bf, 0x62720e1d, 0xdb7915a9,
+    0x9003a1bc, 0x7c1dda0b, 0xfc96b88b, 0x394bca01, 0x14d4db2f, 0x61aa690c, 0xc00d606d, 0x0dccd0af,
+    0xe9d8138e, 0x86ce5075, 0x5e293f42, 0xa00ce2df, 0xa30675ff, 0xb0ced900, 0x267e564b, 0x1aa0dbd8,
+    0xf7731e66, 0x1beda180, 0x44b48f87, 0x669f9fd0, 0x3e532d5d, 0xd818ae26, 0x1e073b45, 0x56bce8b7,
+    0x4c1e5ca5, 0xb0b6e8ca, 0xf7ae2967, 0xd9e84932, 0x601ce566, 0x33471619, 0x6c286dbe, 0xc27fc948,
+    0x320ca264, 0x2500e735, 0x717cb8ed, 0x644a58a6, 0xa59c8e9f, 0xcd0ecdb6, 0x46d79efc, 0x34ba6f34,
+    0x787a5127, 0x1c51500f, 0xc898b2f7, 0x9eb960ea, 0xb18c8ad6, 0x26abe721, 0x369426ec, 0x9c4a9911,
+    0x52ec9a18, 0x9360b036, 0xc382955d, 0x22b24901, 0x36926724, 0x34e9506e, 0xec087b08, 0x0cfb5a54,
+    0x5b0f5315, 0x9cad76de, 0x36d6254e, 0x0593d8ff, 0xcdde2a8b, 0xa559bc97, 0xa57b11d1, 0x9f4590b3,
+    0x1f1ce954, 0x459b66d3, 0xeb1fb87b, 0x6a5403a5, 0x30bb9942, 0x318c2326, 0x4d24035c, 0x1a4a74c1,
+    0xb417e00a, 0xd99acffa, 0x6d0683e4, 0xd40113ff, 0x3b9ee294, 0xfe811c11, 0x61fdc079, 0xefec9f67,
+    0x17277707, 0xf432347d, 0xca93862a, 0x3dfbb287, 0xa026e092, 0x9c2a42fc, 0xc87e551e, 0xb0f4abf2,
+    0x625f921b, 0xbef90e4b, 0x508b6e0b, 0xa03bf2be, 0xad61495b, 0xaa95c5d0, 0x5935f209, 0xffb1a1f0,
+    0x012ea743, 0x10ae4cfd, 0x1a69bd9d, 0xde07ebdf, 0x1a18fc3d, 0xcda60ab6, 0xa674b313, 0xcb0c87c4,
+    0xe0ff7358, 0xd49de054, 0x7a99145e, 0x3fb1b44b, 0x04317f43, 0xedaf3193, 0x89554bc3, 0x59fb1cc8,
+    0x16ff475c, 0xff862556, 0x3530da7a, 0x2746d04a, 0x30c9162a, 0x6d02f910, 0x0d83aaba, 0xaa97bf15,
+    0xd35852d5, 0x2151e671, 0x8347e9b4, 0xddacc30f, 0xce31587e, 0xaf718706, 0x65616c21, 0x6a695468,
+    0xf85830ef, 0xfbfbf9ed, 0xb0f652c4, 0x3093336c, 0x5669dc23, 0x63f5154c, 0x3945cb89, 0xe508f8a2,
+    0xac7c1db0, 0x3e7957c8, 0x7a637d6e, 0x5f043617, 0x379684ea, 0x4b721a04, 0x66f1a4a3, 0x61ed6a2e,
+    0x2ed25ac5, 0x257ae023, 0x2605e9bb, 0x90090253, 0x0b9fad5f, 0x3e8c70b5, 0x460bcfba, 0xae061495,
+    0x515d4d34, 0x4500152a, 0x0b5287f8, 0xa67565c0, 0x382b1ce8, 0xd35a50cf, 0xb50834f3, 0x91b9b033,
+    0xa54b0ee6, 0xb2c2ed95, 0x624ce72f, 0xd40d3a7c, 0x44ab6d4a, 0x20a33816, 0x12007db0, 0x13ceb6e8,
+    0x0740c996, 0xe94c0612, 0xd58fe0aa, 0x4bc0e752, 0x980e7f1d, 0x120fc637, 0x652f32d3, 0xaea20750,
+    0xe77fffff, 0x56e0df06, 0x30d0b5df, 0x0908a630, 0x537bf578, 0x85fd822b, 0xb33ce089, 0x50094d21,
+    0xbed913a4, 0xe999a9be, 0x7915598a, 0x7cc3aa43, 0x576345ac, 0x9156f10c, 0x84a8c366, 0x9c2264f9,
+    0xe99a194a, 0x1d0a53f9, 0x95f4cb74, 0xa297fa55, 0x36e843d0, 0xb34e8d53, 0x55919ea2, 0xa1251c0a,
+    0xd0866f6d, 0xcd6bad64, 0xc003bd03, 0xe046d38d, 0xe9be73fc, 0x70fd5412, 0x3a183fe7, 0x44fd9ac3,
+    0x910b4ccd, 0x127691de, 0x65b3a0a2, 0x35bd92b6, 0x290ca1c1, 0xe4a9a45e, 0x840be568, 0x6f198088,
+    0x5a6acb01, 0x6d8cf382, 0xe24094d4, 0x0e1a5413, 0x92f01371, 0xc83c6a8b, 0x53836427, 0x6999c374,
+    0x4f917b91, 0x41c9c1ab, 0xa85b6f70, 0x5a4037f3, 0x091475e0, 0xaf2f410e, 0xc2669bba, 0xfd77d69f,
+    0xd8a565f4, 0xa380f651, 0xf82cdce3, 0x8000b363, 0xeb8489bc, 0x35433c2e, 0x0b8bd293, 0xc3cdeb10,
+    0x0de05447, 0x722a3fc5, 0xcfc08175, 0xc60c29e4, 0xdb3a487e, 0x1257dfe9, 0x2e043f0e, 0xd67fa82a,
+    0x6f5f2540, 0x812bfd61, 0x358e4bda, 0xbc54f9aa, 0x7d013dfb, 0xa1299750, 0x302c10a3, 0x9769a5cc,
+    0xf2309be2, 0xba5026bf, 0x5d97369c, 0x286cd734, 0x0137944c, 0xa91fb132, 0xd15b586b, 0xc150d66e,
+    0xaef8ccbe, 0x63bb2e33, 0x6b16c688, 0x4f5efaec, 0x1d8f5d16, 0x0f0fa9b4, 0xdccb23fa, 0x1ecaf27b,
+    0xf0195c3d, 0xb25d63da, 0x0dda269c, 0xaa1a233b, 0x3697a669, 0xce654994, 0x1767e9f4, 0xd434309f,
+    0x17ee07b5, 0xf59b247a, 0x4df30f42, 0x4ecab722, 0xe56a19b7, 0xf27cdcb3, 0x444c3224, 0x62ea9efa,
+    0x233ddfeb, 0x7017b66a, 0x72271302, 0x561b8dba, 0xaadeffc2, 0x5555f6fe, 0x206f3a30, 0xf9e644bb,
+    0xe88bbdaf, 0xc491078f, 0xc1c71276, 0x33444757, 0x3ba19a31, 0x3f71ac47, 0xea4f2fee, 0x4949dadf,
+    0x80db98f2, 0xdf6e5006, 0xa5df4471, 0xdb7ec58a, 0xd75395a7, 0x336eab5d, 0xe23409e9, 0x98742284,
+    0xff512264, 0x4b9e056e, 0xdfb1470a, 0xce8e0540, 0x5ee4f1b4, 0x48efc714, 0xfd9950b4, 0x10001b9d,
+    0x9c7e8723, 0x90f0e1c8, 0xdfc6fae0, 0x8697d2c9, 0x5d56f7b3, 0x6253c9d7, 0xe49145f9, 0x9c098d02,
+    0x66010dd2, 0x4df4e467, 0x88e2b483, 0x5b113433, 0x1be92641, 0xdc0736da, 0xb438137a, 0x07e6ba6d,
+    0xb44e6540, 0xa71224c3, 0xa6bf8063, 0x43366d91, 0x0ee45fc8, 0x7c6f6390, 0x5774515f, 0x9231c5f2,
+    0x910d12bb, 0x07ed3325, 0xf575de91, 0xdde3f9a6, 0xd44a7d0e, 0xf11da83a, 0xc0157d29, 0xe86e687e,
+    0x4909b5bc, 0xe80d9b0d, 0x32c977e9, 0xccc6cfe5, 0x5cb8e0da, 0x1f41625e, 0xce0a6ba9, 0xf29612ee,
+    0x47cd8cff, 0x73d764aa, 0x5c98b54f, 0xa4353033, 0x58f8db40, 0xfd776136, 0x99115d5d, 0xc4ecd961,
+    0x8a561945, 0x26ed603e, 0x9a57e992, 0xbd1a3125, 0x34da8a9b, 0xc677140b, 0x260434b8, 0x46f6bf0e,
+    0xb3daebeb, 0x6e9121b5, 0xd295c0a7, 0x33390cec, 0x3b84c54a, 0x76e00461, 0xa6e2c42c, 0xcfb814a9,
+    0x42c9ec42, 0x17191d1d, 0x9cd4ed14, 0x1557f275, 0x4d206304, 0xc3caa01f, 0xa61070dc, 0xe5b6b278,
+    0x3c25fe85, 0xa8d06f24, 0x7cdd77a1, 0xe6cdfd9c, 0x79b1b0a7, 0xa1b26cd2, 0x9aff58ab, 0x66e8e6bf,
+    0x63330ff7, 0x02230d7f, 0xe38c8a21, 0xb9055021, 0xe866ba59, 0x723bce61, 0x9b22a1d8, 0x93505802,
+    0x7585a8de, 0xf6e22a60, 0x41684dba, 0x19895a21, 0xfecd5e1b, 0xb659981e, 0x5ace9d0e, 0x0b67f44c,
+    0x31b12401, 0x22caadf7, 0xc599bade, 0x1aba6d95, 0x9edd7b9d, 0xf7297a91, 0x7095ef6d, 0x53367ac4,
+    0x1f0c5e73, 0xfeeaefec, 0x219dc8e3, 0xa8a5100f, 0x3e28248f, 0x3359ab72, 0xab717d84, 0x19b27ac4,
+    0xf1fcdd70, 0x1613b765, 0xec5ae06d, 0x1a26721b, 0xe1b18a2b, 0xf703f0c7, 0xe203354e, 0xe737af32,
+    0x50591c47, 0x4d329128, 0x10e1e683, 0xbc73f6d2, 0x1ac424af, 0xf054fa11, 0x089691a3, 0x7bdfdcb1,
+    0xb06706dd, 0x671bd831, 0x354db7c1, 0x0d6fbc08, 0xee071613, 0x6bd84460, 0x9a01b216, 0x3ce265ab,
+    0x11f36632, 0xecb87188, 0x99a80841, 0xfc2e1419, 0x52c873fa, 0x88a538a4, 0x017596c7, 0xf0219996,
+    0x3b61984d, 0x1f04e395, 0xd634cbdc, 0xb01e9d3a, 0xb3adecbb, 0x50ad5834, 0xadac970b, 0xf405ac3c,
+    0x9db0acb7, 0x2ff023c4, 0x2d9ba7e1, 0x47bb40e2, 0xa47522fb, 0xc98c1d1d, 0x94b7b01f, 0xa9c777be,
+    0xce18e6c9, 0xaabebcdc, 0x65f2122b, 0xba426983, 0xbcc1cf41, 0x5b52db0b, 0x1723ccec, 0xbc8407a7,
+    0xcb0f1f39, 0x2e780b2f, 0xb4d98665, 0x2ccceac2, 0x9defdff7, 0x3537ba85, 0x899b5e57, 0xe2aac6f4,
+    0x64d58efd, 0x57f23edf, 0xe55332e1, 0x5db37c2d, 0x04508c3b, 0x9755c643, 0x82ffc487, 0x0be4ea6a,
+]);
+pub const VENDOR_MLDSA_KEY_1_PUBLIC: ImageMldsaPubKey = ImageMldsaPubKey([
+    0x5b544270, 0xf6cfd5ca, 0x96f0fbf5, 0xb0a1b148, 0x6e76fdfc, 0x54bd848f, 0x9c5b0f46, 0x55b56c64,
+    0xb9cef3fe, 0x3a6c7807, 0xb4e07e35, 0xb1baea88, 0x69f272c0, 0x1a7f9e59, 0x650f1c71, 0xec1be285,
+    0xab5a076d, 0x136e6c68, 0x099f0342, 0xaf4e3e6d, 0x0b8790ec, 0x5fcc9ea6, 0x1700af12, 0xf828d552,
+    0xa20ea0d3, 0x6d5205e8, 0x91cbcbe8, 0xa986c794, 0x9690a694, 0x7663373d, 0xfaebb6b2, 0xf46bf695,
+    0x05d073f5, 0x04733a4d, 0x1b0c3ddc, 0xe40eb867, 0xc30e19bd, 0x8d5627b9, 0xc0f2550b, 0x38e3b3fa,
+    0x1636d7a6, 0x8916d54b, 0x26d92691, 0xd1dec24f, 0x18c672f0, 0xcc094de9, 0xbb1b9976, 0x9e0c298a,
+    0x5e85d2c3, 0x2f0b5319, 0xe0151812, 0xc3e4d210, 0xcce9e2e8, 0x2d21035d, 0xf3830fe7, 0xd360a9a9,
+    0x45aaac4b, 0xfa74aee7, 0x90dfb0ea, 0xab11558f, 0x174b35a0, 0xd63e9081, 0xa718231e, 0x3edf9059,
+    0xb151add5, 0x3636ed66, 0x7d630682, 0xdbf28f9c, 0x6448b9d4, 0xfad59caa, 0x4d9cf58f, 0x8c1a33f3,
+    0xdc2c8f09, 0x9de3863b, 0x7278c3d0, 0xdac1c84c, 0x69f40ac4, 0x772f7141, 0x44bdccd4, 0xc76c71e9,
+    0x4f1a50ab, 0x941f2973, 0xe5ee9b5f, 0x4661edd7, 0x77353dc5, 0x7f080e73, 0xc785e567, 0xb093368f,
+    0xa247e37b, 0xf6e9fe6e, 0xa8d54f8d, 0x82c01b1d, 0x0bae3100, 0x57dc8d8f, 0x68d3f65e, 0x8a7b3674,
+    0x721b17df, 0x7cb3c9d3, 0x8abfda90, 0xa7057b75, 0x2e9f9537, 0x020faf62, 0xc3916b14, 0xae5282d6,
+    0xcdc0843b, 0xdd2443a3, 0x27e74e5b, 0x2cadabb8, 0x5f4638be, 0xf65d8b97, 0xd86900fa, 0xab063031,
+    0x9ff431ef, 0x6c52c479, 0xfc6644c1, 0x10a5eab6, 0xb8bd55de, 0xa22b7c41, 0x8b04a3b6, 0x01d58c81,
+    0x75a161b4, 0x60e57f71, 0xe819e90e, 0x7053e3b7, 0xe227c436, 0x3fa42b82, 0xaf18bdd3, 0x1327b2f9,
+    0x13b028a4, 0xf14d8ca0, 0xae2dc488, 0xd1def593, 0x85f7c5ba, 0x579a5734, 0xe4dae03b, 0x807b5531,
+    0x06701b1b, 0x2ea729fd, 0x244e0ad3, 0xa64e713e, 0xecde3862, 0x92d4ad63, 0x9b8f0d00, 0x0ffb373b,
+    0xd617b41f, 0x3654ddb1, 0x1a954353, 0x6d6c4eec, 0xd5925ff5, 0xb6a9372b, 0x4727cac6, 0x6143181e,
+    0xad1ac28a, 0xb1c48018, 0x2bc64a7c, 0xbf7b959c, 0x3a1597f4, 0xa9a459ff, 0x1dea47d5, 0xf3723cf6,
+    0xe807ad1e, 0xa2a0f90a, 0x0de7aec3, 0xd863ed9e, 0xcd60d034, 0x9608681a, 0x841cd52f, 0xa49bb8fc,
+    0x262c61bc, 0xdc1c8cbd, 0xfe40ba64, 0xae476437, 0xdb691d62, 0xe8166bba, 0x52f805d5, 0xa640a28e,
+    0x0e656f08, 0xca32e3c9, 0xf8356a65, 0x13552a9f, 0x8ad9fce6, 0x9a42b6ef, 0xb13a5458, 0x6ab464b8,
+    0x09ffe1c7, 0xdae36d5b, 0xe6a3f0f8, 0x7cad500e, 0x0a601edd, 0xb3e79d49, 0x406f0b0c, 0x4606977c,
+    0x9529f20b, 0x9f127916, 0x8bbf502a, 0x1f68231a, 0xfedb30a9, 0x5b7da52a, 0x8b59777f, 0xe27bcf6c,
+    0xb81d8b3b, 0xc13b9749, 0x06dcf374, 0x2c399862, 0x8a885aa0, 0x428518ed, 0x715e13ef, 0x0cd05bca,
+    0x612d2bf4, 0xd47943f8, 0x8d022f4f, 0xb9f1c6f4, 0x100fd797, 0x3716fb6a, 0x9124cb32, 0xbd9c5805,
+    0xa244b362, 0xb08a7eaa, 0xe1fedb91, 0x4467ba3a, 0x5e841e2a, 0x97772af6, 0xad622f9e, 0x7ec6f4c9,
+    0x0c8c4e31, 0xfdf36b46, 0xba6297ed, 0x1e1d3735, 0x3887315f, 0x688b5684, 0xb44f4b58, 0xfc54cd0f,
+    0x7fd307a1, 0x951dd69e, 0x930c684b, 0xb3f0163c, 0x67df02b3, 0xd7d9cd2a, 0xde1509e5, 0xfdb9f64a,
+    0xac712619, 0x20c3455b, 0xb997da3d, 0x0adb4738, 0x6390394f, 0x6cdff5c9, 0xbf7a9fee, 0x59e43884,
+    0x8e94472f, 0x065516d6, 0xaa97061f, 0x0bded271, 0x54c3961a, 0x9ec86a33, 0x46f95418, 0x1b8498d6,
+    0x523ef292, 0x867951f1, 0xf9f052eb, 0x3683d5a5, 0x23a5895b, 0xe8b5b271, 0x4668f72f, 0x7402bf04,
+    0xed04423c, 0x5b8887fe, 0xdaacd251, 0xc421b482, 0x2813d573, 0x65368776, 0x67fde2fd, 0x750fbc07,
+    0x8acebb69, 0x7601ef6b, 0x49ae6dc1, 0x8fbbc9fe, 0x9e10a6ad, 0x4c980ff7, 0x59af7ff3, 0x9338f66f,
+    0x89c45532, 0x7af889d0, 0xae70425a, 0x5c63b7b2, 0x66930010, 0x5d0767c3, 0x3ca5ccd1, 0x07b60bb3,
+    0xcee2d549, 0xec89d0cd, 0xbb665daa, 0xee3a3c08, 0x7d878b3a, 0x249971ab, 0xb235c4c5, 0x3b461013,
+    0xa202f42b, 0x57500b13, 0xdd40e01a, 0x20e8a51d, 0x3dcc03a2, 0x33837a4e, 0xe568fd1c, 0x506b7938,
+    0x8dad41dd, 0x981341d6, 0x195c8b97, 0x1ab05e02, 0xd6c6bfc2, 0x259b8e5d, 0xddc178c5, 0x9b27ef05,
+    0x288d6e16, 0xaf9da975, 0x54e9989c, 0x2ba743f6, 0xf3c3609a, 0x93395c63, 0x16c65388, 0x1425bf9a,
+    0xcbf5c362, 0x5cfd7a2b, 0x14524de9, 0x9d4f1b9a, 0x59c8a0c8, 0xf82c5798, 0x7a21051b, 0xde944c1d,
+    0xe4b12a99, 0xa105391e, 0x6ff8eeb6, 0x2c7d06a3, 0x94f30aff, 0x586b007a, 0x8a369e5f, 0xcf2545e3,
+    0x275a10bd, 0x27ad806a, 0xd5944750, 0x3a1c1ddc, 0xfd516ef9, 0x9bd18f30, 0x2ae6d135, 0x45357db1,
+    0xc14131c1, 0xd3fc7fb8, 0x6be1747f, 0xf00e714a, 0x3b67981a, 0xd6c8fe0e, 0x3e092e5d, 0x3ad66038,
+    0x6b6def86, 0x6673815a, 0xa2a552dc, 0xb7fcd00c, 0xd81f1cc6, 0x5994c991, 0x05f99b4e, 0x71058da1,
+    0x28c9ac99, 0x05fe5c91, 0xbf59a2cc, 0xaf90a6ad, 0xd6cf9154, 0x181365d7, 0x4932e506, 0xed69c492,
+    0x58d65c44, 0xedc63526, 0x16693db9, 0xb4574235, 0x53eaf675, 0xb118942b, 0x26dfe1be, 0x1ace82f5,
+    0xdd8f4192, 0x53a2ac04, 0xd4ba0769, 0xa7d6ac6c, 0x5970a978, 0xfce08ea6, 0xbf962615, 0x06d47f48,
+    0xa4259dbc, 0x1577ead0, 0x4f64c32e, 0xd31118a8, 0x29dc8262, 0x18265aa1, 0x9b1ff6fd, 0xdde13572,
+    0x526bc4d0, 0xea98d1e8, 0xeca666e3, 0x6b89edb1, 0x14348914, 0x349db90e, 0x492124e6, 0x54f340ee,
+    0x839d3c5f, 0x71b3a7e3, 0x165eba48, 0x780bb088, 0xf48a6858, 0xd8b787e2, 0x8b9ebda3, 0xd90c1aa7,
+    0xba14ddb5, 0x391f8058, 0x95e75923, 0x87851103, 0x60c9a746, 0xbe272961, 0x8bd58a69, 0xfb632b09,
+    0xd9fbefac, 0x3708cca1, 0x093ad448, 0xe3d884d9, 0xfe858c3b, 0x900156c0, 0xe1ed3b94, 0xe85728ef,
+    0x1d8b04c9, 0x38301abb, 0x9a3428da, 0x04b46714, 0xb9e62ef2, 0x1f0f078b, 0x0735747c, 0x00cadad1,
+    0x6d6a48eb, 0x9b0c1450, 0xd5d955e7, 0xbc1f4186, 0xbb06dc35, 0x1cdb8352, 0x57b48439, 0x06fe888e,
+    0xf621084c, 0x80b74435, 0x9cd82051, 0xee4c3641, 0xa98e8845, 0xb2b2c40f, 0xe857f80c, 0x417d83b3,
+    0x420bfcc5, 0xe9dc17fc, 0x2ccf8182, 0xd7e086e8, 0xd4a2a1e8, 0x6b399d3b, 0x7a4209dc, 0xeded6895,
+    0xac4ff866, 0x98a062e1, 0xb1abca54, 0xaa608f07, 0xc6d2b394, 0x304eab58, 0x749d0965, 0xa6b9004c,
+    0x20c625b4, 0xa1c670b7, 0x9b2cd81b, 0x1faa3161, 0xdd7d220a, 0x9845637b, 0xf7cf6b9e, 0x7af7efc9,
+    0x7c260580, 0x4d05efb4, 0x5633c73a, 0x00255749, 0x99563f47, 0xaa065e39, 0x8ef09f8e, 0x65bc1f12,
+    0x5bb34b0f, 0x54b19a31, 0x7e38be43, 0x66104c9f, 0x41249626, 0xf3cc4fe3, 0x46e3910f, 0xb5327f4d,
+    0x3ea070eb, 0xb2abec6c, 0x4e112bf0, 0x0ff6df0f, 0xeb4cc3af, 0xf4dca2b9, 0x8a20c617, 0xce736bf3,
+    0x55dcd1f6, 0x98a97124, 0xed714949, 0x85758d15, 0x38a2f6d4, 0x7c651ef3, 0x3178d884, 0xb43d0b8c,
+    0xe3889bc1, 0x88e986aa, 0x1d6c1995, 0xb0e17f99, 0xff10f8b9, 0xe77e4c30, 0x5f97196f, 0xaa5d94f2,
+    0xea64bced, 0x07fe3165, 0x07ff2789, 0x7e4d916b, 0x5b95ebc2, 0xbe08e841, 0x09434848, 0x5c106846,
+    0x139f8b00, 0xd331bc0d, 0xacd2ecac, 0x3c58fce0, 0x59174e24, 0x8363f5d1, 0x5ad73a28, 0x32760fc8,
+    0x1e787927, 0x18f68b54, 0x6ffb7496, 0x53fdfc94, 0xc89bcac4, 0x5cdca59d, 0x44053729, 0x50f9a23f,
+    0x22254291, 0xe8eb46a5, 0xdc80cf25, 0xdddaae98, 0x97bb01e7, 0x7d0cbbff, 0xb2314469, 0x05eb726c,
+    0x0bbfc195, 0xd62ba3fd, 0xa467126b, 0x345e043d, 0x4896e6ae, 0x2f6f3d4a, 0x1dbb68ff, 0x855e6ef0,
+    0x276a20c8, 0x1b6251ab, 0xa337c8fb, 0x536fd85c, 0xec590261, 0xfe9f523a, 0x2d53663d, 0x70796a6f,
+    0xc92d1143, 0xb22af130, 0xeb155e33, 0xc284b8e2, 0xed76a73d, 0x5bcc8bd0, 0x05730680, 0x84995919,
+    0x17b2dc2a, 0x6719c55c, 0xb6141366, 0x7b2e00f4, 0xf85e9d33, 0xe6f6b37c, 0x9e14d472, 0x14f431f2,
+    0xe17096af, 0x88453a55, 0x54028813, 0x67502d7f, 0x4afea856, 0xe476f320, 0xa2ffb923, 0xaf1feb49,
+    0x25552be6, 0x01e140ff, 0xbfe5f802, 0xfb3310f4, 0x79c28a41, 0x4057d9ff, 0x3b6c72f4, 0xf942a816,
+    0xb3892092, 0xbaac5ecf, 0x5279de52, 0x5b71f97d, 0xf5c47363, 0xba120704, 0x7e7ad929, 0x5b744ace,
+    0x3fbadf49, 0xf1c8fe77, 0x03edbd9a, 0x35918691, 0x05cdcaf2, 0x0c6ed752, 0xd7ca8a57, 0xa8275117,
+    0x95f4bd07, 0x3e4a40ca, 0x09a87d7e, 0x4aacdf67, 0x94ddbdff, 0xa9ef4ffb, 0x225f5c84, 0x0b9d661d,
+    0x5601f0ca, 0x78bc275c, 0x6f99f627, 0xce5605b8, 0xd05dac2c, 0xf181d5ac, 0x95f9748f, 0x13f7cda4,
+    0x5d3823a9, 0x00b95c3c, 0xcc97f6d7, 0xcd43bafb, 0x186aac5c, 0xdf441b1a, 0xf592ea97, 0x34e79e1a,
+    0x07c621c5, 0x72f045b8, 0x6475da08, 0xdd2b33a1, 0xd8aaee85, 0x8a55ffd3, 0xa54177cf, 0xcad7c0c4,
+    0x6be66240, 0xf8b7be23, 0xc32657f7, 0x0843d377, 0xa0b9c20c, 0x09d22d09, 0x45f68924, 0x45f66f4c,
+]);
+pub const VENDOR_MLDSA_KEY_1_PRIVATE: ImageMldsaPrivKey = ImageMldsaPrivKey([
+    0x5b544270, 0xf6cfd5ca, 0x96f0fbf5, 0xb0a1b148, 0x6e76fdfc, 0x54bd848f, 0x9c5b0f46, 0x55b56c64,
+    0x365a947c, 0x322da63f, 0xe05a5e73, 0xc0c4f672, 0x2a6db4b5, 0xa9e3c1ef, 0xae9a51fe, 0x363df512,
+    0xa30ff2ad, 0xf46eb7d2, 0xfb512d5e, 0x1144f1b0, 0x98c58232, 0x8a49e029, 0x01f5575f, 0x2557dbdd,
+    0x0e5010dc, 0xa807641a, 0x395c25a6, 0xc2ac735f, 0x1c4aa5bf, 0x37da99ac, 0x0bb45b12, 0xade4d6a1,
+    0x114a0498, 0x20144216, 0x71a06126, 0x18822201, 0x991249a1, 0x01904c08, 0x0c814484, 0xa30b6903,
+    0x4184e364, 0x20913649, 0x07080184, 0x0a36820d, 0x08283711, 0x38120dc5, 0x6dc00201, 0x5a44024c,
+    0x921910c6, 0x88a05204, 0x1c80c814, 0x805180b6, 0x8e32ca24, 0x1a688043, 0x09122085, 0x4846d930,
+    0xd3201000, 0x930a0116, 0x80308c91, 0x6460b0a1, 0x26919220, 0x0110e281, 0xd04996e3, 0x38c005a0,
+    0x52350212, 0xe228285b, 0x36623186, 0x8482c089, 0x628c0724, 0x30214130, 0x09131a8c, 0x9320b410,
+    0xc4412820, 0x2592002d, 0x02243644, 0xc69a81a1, 0x04a41364, 0x180e10d8, 0x220b8c30, 0x89385928,
+    0x58109662, 0x37116496, 0x6a202010, 0x02523624, 0x26414a48, 0x11300c2c, 0x6170941a, 0x868a11c0,
+    0x6216cc8d, 0x242914ca, 0x961a4c45, 0x89180408, 0x5a0998c3, 0x88a08090, 0x8508e365, 0x20219912,
+    0x268305a5, 0x91a04892, 0xcc0cb309, 0x18030224, 0x28a26308, 0xa211c922, 0x185b6d12, 0x6106da10,
+    0x0360c692, 0x162025b2, 0x01864186, 0x09641223, 0x12d10939, 0x06348230, 0x916d4701, 0x20a45096,
+    0x1044d464, 0xd0090844, 0x46c17032, 0x08221908, 0x116d86a2, 0x445a41c2, 0x84b5130d, 0x9c8190d9,
+    0x482324b4, 0x46042425, 0xa1120510, 0x43194c90, 0x4d029068, 0x216500e2, 0x20c289c6, 0x21b2d32a,
+    0x4309a722, 0xc61009b0, 0x31420c8c, 0x208db858, 0xb21c20c6, 0x25c84b2d, 0x2062104b, 0x96046e43,
+    0x82082209, 0x2291201c, 0x20116833, 0x0e46e028, 0xe288c603, 0x08c481b8, 0x66046349, 0xa4513642,
+    0xb0c490a2, 0x88226244, 0x4101a099, 0x41242c12, 0x9126e14a, 0x134c8264, 0x94d30223, 0x2040602c,
+    0x09054403, 0x150a1007, 0x52301360, 0x18219409, 0x44841237, 0x61930460, 0xcc859913, 0x141c50c2,
+    0x21b10a11, 0xd16e090a, 0x40d88242, 0x0ca6e12e, 0xa121b021, 0x469a0ca6, 0x45144848, 0xc8309892,
+    0xa9112512, 0x8082c961, 0x8949a2a1, 0x465a60c2, 0x2a00c810, 0x1c60b509, 0xc0449124, 0x68b6830d,
+    0x6324371a, 0x26603182, 0x6a470089, 0xd2090411, 0x970a6e06, 0x6d130104, 0x4324b44c, 0x30cb0184,
+    0x0d988c06, 0x0446168b, 0x984b1084, 0x0a468128, 0x4428c843, 0x30cb2928, 0x81964b80, 0xc46a2251,
+    0x80148504, 0x26085c41, 0x522e02cb, 0x300a0c18, 0x8a326088, 0x89708623, 0xa8022990, 0x0a171300,
+    0x030db08b, 0x111210a6, 0x4db00032, 0x18201298, 0x21199034, 0x0a46e049, 0xc24d28cb, 0x989a6cc4,
+    0x11390980, 0x19521111, 0xa8c06224, 0x66285181, 0xe1909509, 0xc6d18a38, 0x8c26492c, 0xd368a6e3,
+    0xc2898812, 0x65371961, 0xd40994c3, 0xc7039206, 0x0881084d, 0xd24a0254, 0x971c0da8, 0x0c44ca31,
+    0x18858908, 0xc0582a00, 0x6512d909, 0x41914323, 0x00990906, 0x2ca26070, 0xc489c700, 0x24c34210,
+    0x8502a042, 0x23512299, 0x10da8449, 0x8420190c, 0x5c814519, 0x02628446, 0x31444085, 0x242a3451,
+    0xb6906132, 0x6946db24, 0xe3450689, 0x26514808, 0x04a01892, 0xd20d2113, 0x949b0896, 0x9242cc21,
+    0xe3819282, 0xb51c3010, 0x04908385, 0x1349850c, 0x805a9247, 0x4c99040c, 0x1220a48c, 0xb5232526,
+    0x6e26e021, 0x504c9112, 0x08a345b2, 0x65b48002, 0xa3663512, 0x84504d42, 0x4216c36c, 0x0c812623,
+    0x14d400b6, 0x8120a361, 0x0348a841, 0x98128210, 0x50a32424, 0x2225a100, 0x06630526, 0x0e370008,
+    0x104084dc, 0x38cc91c3, 0x8418d810, 0x5c6ca44a, 0x48120c92, 0x80006162, 0xe069c119, 0xb6602034,
+    0x50b8e181, 0x0b29950a, 0x340909a1, 0x8196cc0c, 0x084012e3, 0xc6894608, 0x2dc6ca8c, 0xe00924a2,
+    0x32894912, 0x90c0cc88, 0x1284c254, 0x988b6db0, 0x6506e248, 0x14843660, 0x28da1113, 0x4c42e170,
+    0x606d88a0, 0x989c4938, 0x2e491261, 0xa2711311, 0x48130234, 0x0882c810, 0x0245b70b, 0x428a4494,
+    0x4013048a, 0x00713490, 0x18614031, 0x0038020d, 0xa28cb21b, 0x145350b2, 0x0e200351, 0x134110c3,
+    0x405c2606, 0x4198d104, 0x643106da, 0xb7042c36, 0x91b8a071, 0x9020c822, 0x289484c0, 0x2e352301,
+    0x542830db, 0xc6624c02, 0x8cc05430, 0xdc462644, 0x16e14a44, 0x4492248c, 0x5c423488, 0x921c45b8,
+    0x25289451, 0xe40cc018, 0x12424120, 0x0a24a248, 0x8b858702, 0x450b7104, 0x8a02102a, 0xa452269b,
+    0x10a06534, 0x6e391486, 0x5468a603, 0x04d98c86, 0x0d38590a, 0x21460261, 0x24e13137, 0x10b80862,
+    0x124010d8, 0x048c5237, 0x41349a66, 0x000c3704, 0x88927113, 0x31c8e189, 0x1c290524, 0x45119189,
+    0x91864044, 0x612a0303, 0x92192d48, 0x6a140204, 0x486a28d9, 0x36484082, 0x04420c32, 0xc1889604,
+    0xc0940530, 0x3190e409, 0xd4649080, 0x16834642, 0x04a40941, 0x4a888459, 0x96c410a4, 0x66421a85,
+    0x4c253450, 0x988131a8, 0x44328a2c, 0x1a30801a, 0x01198a43, 0x5184026d, 0xa06d28e0, 0x901b9196,
+    0x51a24251, 0xe38d2461, 0xa2928622, 0x64c64310, 0x1a290020, 0x411b88b4, 0x3018810e, 0x1904c4da,
+    0x86898244, 0x68886281, 0x1228b311, 0x469450b0, 0x1092d04a, 0x0b45c264, 0x361b0216, 0x7036e14a,
+    0xfbee1140, 0xd109b6fd, 0x1a528775, 0xb4e8a0f6, 0xc1146a50, 0x20a50fb4, 0x183b627c, 0x64a11f42,
+    0x4559f657, 0x837458e6, 0x61b5af00, 0xad3de725, 0x19af1514, 0x5c6cd570, 0x8a1d8cb7, 0x34980f2d,
+    0xae35d645, 0x09eda3cb, 0xf6f9a611, 0xf62443fe, 0xb096a005, 0x7384090b, 0x2e6b2ba2, 0x7147c150,
+    0xbd2f2b79, 0x4619825d, 0xed44235b, 0xcd476e63, 0xb5d3610f, 0xf5465061, 0xec146e1f, 0xeef7548f,
+    0xb6585b8a, 0x20d5341d, 0x10c41a2f, 0xb9bb4234, 0x6109c0c4, 0x918ccf07, 0xf1bb1bda, 0x4e4f4f9d,
+    0x6c7146b7, 0x77a645a8, 0xe393ec2e, 0x58b48fc2, 0xdb1b43ce, 0xec733686, 0xfec83906, 0x598d7958,
+    0x4e9ba29d, 0xdc578598, 0xa9529dbf, 0xb4f339a6, 0x36cbae4e, 0x3e5a8de6, 0x6e487af9, 0x1f50f5b8,
+    0xdfda2484, 0x74331ce2, 0x9a9af79d, 0x10a18332, 0xf2b91fe0, 0xd6301b44, 0xe5d9b6d9, 0x8a4d8d49,
+    0x40a38305, 0xe829e1b9, 0xeeeb61cf, 0x7f59fab6, 0xffa9aa18, 0xd2136401, 0x21dc3f55, 0xc470ee34,
+    0x9ffb6d89, 0xc1c92cae, 0x5210e83a, 0x9c9c48dd, 0x935268ea, 0x81de9067, 0x2fa48bda, 0x0e0c7885,
+    0x580a3bdb, 0xd339b86c, 0xd133c5a1, 0x255ff38c, 0x85b1b039, 0xefca4f28, 0xe3054cc6, 0x6ff067c4,
+    0x69facc0d, 0x9a30f86e, 0x7c7810cf, 0x12b63ad6, 0xb77be04b, 0x565159af, 0xf4aebe4c, 0xbaca5536,
+    0xefec5814, 0x162b987e, 0xc23f2075, 0x3bef3707, 0xe565583b, 0xfc2ffdfb, 0xb077128b, 0xa0a6ed66,
+    0x93725f77, 0x1016a6f9, 0x9b529a14, 0x70b5601e, 0x74be63b2, 0x8466fb5d, 0x2f9c666a, 0x615e9e75,
+    0x37e274b0, 0x080f51bf, 0x4df12ca1, 0xb2894cda, 0xe23262b0, 0x3795a30d, 0x7a4c4a9a, 0xce088268,
+    0x49efcad3, 0x7409ecf8, 0x7db7773d, 0x5dbd475d, 0xe33d7d63, 0xccff66a0, 0xb3ea418b, 0x82b860e5,
+    0x5d81a31d, 0xeda22497, 0xdddee8f1, 0x20e8ac5f, 0x0bf2e735, 0x699ca5f4, 0x557f3dc5, 0x42d8dbcf,
+    0xf24f386b, 0x15b000d4, 0x4e489e38, 0x4c846f4f, 0x3a6720a0, 0x5552744d, 0x4d063948, 0x4a775427,
+    0x0ad0ab1b, 0x91dedbee, 0x3ac85583, 0xbde0b4f3, 0xa6a89fc7, 0x379ed334, 0x1d039961, 0x17ad8270,
+    0xd726aa1c, 0x4e95fa93, 0x529a6885, 0x8c26192c, 0x10230345, 0xfeea737f, 0x9c9b82f2, 0x4e4a4de0,
+    0x3611a685, 0xcd087061, 0x30ab1a09, 0x3752465c, 0xfe7d27c3, 0xf5ff2e4b, 0x13400bdb, 0xc9062b25,
+    0xaf4bd7d4, 0xcc548ca0, 0x668c2dd5, 0x43afa2e2, 0x83219f4e, 0x54d269c1, 0xd771a138, 0x3e216c9d,
+    0x19cc8564, 0xd3570e3e, 0x9f598c5b, 0xb30dbd52, 0xb00ac23e, 0x1c744c72, 0x4f385ae8, 0x131ed361,
+    0xce8fba0e, 0x4a6ecb99, 0x95a8b78b, 0xa07c0991, 0x0affef9b, 0x4d2acb55, 0x9ee8dd16, 0xb506628c,
+    0x27d56723, 0xed8587a4, 0x8d3bf713, 0xa9ea7e62, 0x82a75f93, 0x76481853, 0xd0339ddb, 0xc7c06d73,
+    0xc9da8abd, 0xc4f32284, 0x06179007, 0x26ebf1a8, 0x0107f928, 0xa624e165, 0x249fa76b, 0x4ff50786,
+    0x6b7b49d3, 0x57ace213, 0x2e7c2f59, 0x60db5af1, 0xa3e4aa53, 0x95ee7307, 0xad4ac2bd, 0xb5788817,
+    0x2e57d5ae, 0x54227347, 0x6816ad84, 0x267ea735, 0x8dee5472, 0x23d3b6a4, 0x42bea4e0, 0x87b093bc,
+    0xad543090, 0x620cb16a, 0xd03cb490, 0xc913c5cc, 0x6ae80e3e, 0x17222c9a, 0x1ea37bfb, 0x8484c877,
+    0xa96f14b1, 0x41bb3651, 0x6c487575, 0x1d62a72a, 0x28a2114d, 0xd3361fb8, 0x8bd76d46, 0xb6f978f7,
+    0x10a1e375, 0xd234de8d, 0x6d1f8b22, 0x786bfc6c, 0x34912a22, 0x23252a81, 0xbcb329fc, 0x7d1185d7,
+    0x3b0f9343, 0xed5a7589, 0x3c9f4a18, 0x57e52f92, 0xef491922, 0xa1e18546, 0x425d8e53, 0xc193b20c,
+    0xe9affcc2, 0x3dfc6e71, 0x68c40d53, 0x6e919c8e, 0xa0184897, 0x32838acb, 0x87c60e90, 0x378d8b8a,
+    0x48d5effc, 0xc4b3415e, 0xaf08ff5a, 0xafa37d19, 0x716e3f91, 0x18bd7745, 0x181d6769, 0x4b1e42ff,
+    0x94bd423f, 0x60c977c9, 0xf8ce8929, 0x20c9585d, 0x157edb9b, 0x8d5425ea, 0xc49ab54e, 0xc81589f7,
+    0x47915594, 0x35f368a5, 0xb3c141e5, 0xbe4507e0, 0x9e8e545b, 0x6af686f6, 0x9e2f5dc9, 0xc56c8d0a,
+    0xecdde98d, 0x21295a5a, 0x1e5bcfbd, 0xafb9f765, 0xc42eb326, 0x597f6ffe, 0x75e01b6b, 0x9d34a5e8,
+    0x1c9496ba, 0x76196915, 0x3780983c, 0xdf369188, 0xa680cfdf, 0xe93008e8, 0x456c6a73, 0xd45eb443,
+    0x9e0cf702, 0x2c29b84e, 0x71a2531a, 0xa41b98b7, 0xf33280b4, 0x8537cc6e, 0xdcadd1b9, 0xab7dde3f,
+    0xcd432afd, 0x96f32a8b, 0xfd6f9449, 0x02344d40, 0x3f73665c, 0xfcd80492, 0x8a1cf1fc, 0x04691d56,
+    0x4704e463, 0x5f369761, 0x9e3c7348, 0xb9e09e92, 0xbfe35dba, 0x13bb5b59, 0xcde147e2, 0x6693e038,
+    0xb1242e91, 0x61184ae0, 0x479a34a5, 0x17a1f229, 0xaf12b30a, 0xb98de737, 0x08c08011, 0xb7f7d95e,
+    0xcbe29912, 0x185c3bc7, 0x30da6f63, 0x3608226e, 0x6b040a15, 0x16632a4c, 0x1821f28f, 0xa0677de1,
+    0x05c37a42, 0x30895914, 0x2c4a724d, 0x6694594d, 0x0d4a9df3, 0x8ae91153, 0xacbbd8ca, 0x6084b41a,
+    0x91edf7c3, 0xf76f06a8, 0xd65e619e, 0xa55c7af3, 0x56b4f398, 0xc9fe5d21, 0x9d9fc7cd, 0xc4747990,
+    0x67d3e00c, 0x6c7f8675, 0xf6f2b46b, 0x1902000b, 0x9f03eb71, 0x140e5225, 0x435d19c6, 0xa36086ba,
+    0xaa9be8c7, 0xaf0b968f, 0x45a1a0fc, 0xd3a06bd7, 0xacb4a311, 0xd17cec58, 0x7462d2f7, 0x30cb4376,
+    0x859cb6e7, 0xb10ab148, 0xed88be38, 0xe06c5de1, 0xe684b917, 0xe2d36316, 0xf847a903, 0x2c186108,
+    0x89070745, 0x1d4f412c, 0x3041c91b, 0xa01537e3, 0x12c890ea, 0x59b7ea3a, 0x552adf28, 0x203f1300,
+    0x6fbf4031, 0x0c6a77d7, 0xc8af9cf2, 0x79f540a1, 0x21ff7e99, 0x27d2319a, 0x8b9a6bc9, 0xe8a61c4c,
+    0x150af5ec, 0x2fa1089a, 0x7f5bc8f4, 0x4264caae, 0x2a112ac5, 0xdfd46d0c, 0x98ad99ab, 0xc278527f,
+    0x1887e48c, 0x1505ff95, 0xd3d1fb18, 0x085adfd5, 0x278bfe32, 0x9d785058, 0x5d799674, 0x49383e3c,
+    0x5c2d4ca4, 0xbe91a85f, 0x1a303a3a, 0xf282c13e, 0xf98203ee, 0xfa0b52f9, 0xf9e89814, 0xe84528cc,
+    0x5ab1f41e, 0xb4ecbe02, 0xc6887ecb, 0x5b3e9d3b, 0xefdf3a6a, 0xe557a899, 0x8392a2bd, 0x90c38aeb,
+    0xf6d95579, 0x5ded5a45, 0xec5ef55a, 0x2aedf730, 0x0c335f42, 0x9f3353f6, 0x338c9940, 0x225420dc,
+    0x6c672b2d, 0x9476e943, 0x2b05e18b, 0xa28afa7e, 0x9fbed615, 0x88f514e2, 0x059947c8, 0xabb198ca,
+    0x27e0aa0d, 0x9cd73408, 0x6b198526, 0x6f3268d3, 0xf7e7f767, 0xaf6cd4f5, 0x3c592794, 0xb388a024,
+    0x294867d5, 0x69a41eaf, 0x264471cf, 0x8e726797, 0x8ad7e560, 0x539684b8, 0x64486ea1, 0x138ffd10,
+    0x5ef3ba42, 0x91f2bf20, 0xeefb13e8, 0xca174710, 0xb789671f, 0x0ca64033, 0xfd6aaaf7, 0x9b1bbc2c,
+    0x969cf2de, 0x64f282ca, 0x109df43a, 0x5b2b73d3, 0xddddd0f7, 0xe0cf30c0, 0x9a01a3f9, 0xfe2f69a7,
+    0xbd09c66e, 0xb1966a13, 0x84a2d201, 0xb0ea0967, 0xe570f3c5, 0x7c1f2eea, 0x1e4a3c3e, 0x262beb6a,
+    0x6f842e27, 0xdd6bac4f, 0x051636c2, 0x12023bcb, 0x68d7c748, 0x4ac9c438, 0x18a554f9, 0x99f59c57,
+    0x82f5097d, 0xb31b0850, 0x56d0858d, 0xcdabe5dd, 0x3fe9deaa, 0x9e338319, 0x74a0835e, 0xe52814ef,
+    0xb8ea22a6, 0x407ee8e4, 0x12be6b50, 0x8a62c154, 0x882ef5cd, 0xfed94d04, 0x8ba4eb08, 0x906db1ac,
+    0x8a820590, 0x38773111, 0x016203bd, 0x92082fe6, 0x59e638a4, 0x491ef497, 0xd40c9058, 0xb085ec5d,
+    0x980c4e52, 0x74fcb396, 0xc4e17a26, 0x536c55c2, 0x0aa64817, 0x98e983b7, 0xd3f733eb, 0x97126f7d,
+    0x5f2d65b6, 0x9ba511d6, 0xd2a6514b, 0x169aef15, 0x8dc2ebe4, 0x12305299, 0xa7b5250f, 0x70f78375,
+    0xa30b8c16, 0xdc1db7d1, 0x05aeb04d, 0x683184a2, 0x91c65fd1, 0xac9d29d3, 0xd0352898, 0xe3e0b209,
+    0xc8d390c7, 0x123a3dfb, 0x94dcf26c, 0x961becea, 0x3bd13fc7, 0x54afd705, 0x5f057926, 0x42522424,
+    0x9b08106a, 0x9188230e, 0x9134cbff, 0x576836ce, 0x0c41eb69, 0x7899860c, 0xac0ae375, 0xfe0efbf7,
+    0xba2a252e, 0x790bd4f8, 0x186bc31d, 0xe1ca4e35, 0x2aab3fb9, 0x19685540, 0xdf9c5df6, 0x6aa7f7ca,
+    0x26b7863c, 0xdd12d3a2, 0xb7abf9bc, 0x48446069, 0xa082a98e, 0x6fc3e9ef, 0xf0ef3e15, 0x40b741aa,
+    0x1f6de873, 0x4c18647a, 0xb2a86066, 0xad94b1ae, 0x29e7b55c, 0x6b336bda, 0x3ed284e5, 0x81ec4ecf,
+    0x8c8086ee, 0x5e9d5993, 0x342f7eec, 0xb4d7f788, 0x4204cddd, 0x75241dbf, 0x0f50dcb4, 0xc9b69828,
+    0x2414a534, 0xbeff8aab, 0x28e8209a, 0x483dbc7b, 0x22886713, 0x8cdc91eb, 0x289200c3, 0x0025086e,
+    0x81ae52c1, 0x98d3e120, 0x3aecddbe, 0xfe8c5ad9, 0xbb7f38d8, 0xfc70a79f, 0x7073db9d, 0xf349e378,
+    0x066c0a69, 0x48db5fa1, 0xa70bfb4c, 0x65685b1d, 0xb9576a3e, 0xd008b607, 0x08e88b6e, 0x5353070e,
+    0x0ea87b6d, 0x5ca3b3d8, 0xb58ad14d, 0x45dea911, 0x0fb71aa5, 0x21814b3e, 0x702ef609, 0xccf4cb31,
+    0xbd95dce7, 0xa24aeca6, 0x79c43ee2, 0x556f45f8, 0x78b96c20, 0xcc07c886, 0x34351c57, 0x4f087649,
+    0xeb4e9e13, 0x69156c23, 0x145af01f, 0x578c0f79, 0x3dc60b23, 0x9adca27f, 0xe0f9de14, 0xaa281b3a,
+    0x59994d0e, 0xf7466940, 0x10742fab, 0x3d6adba3, 0xf75c3b01, 0xe89559c8, 0x2cbe4b02, 0xfb49610d,
+    0xd3bcfc06, 0x595cad7d, 0x1d99e281, 0xad24610b, 0xe3a392c9, 0xc1738cf5, 0x71d7969b, 0x9a8f175c,
+    0x93c05af8, 0xcdc55ad4, 0x5f7c4a80, 0x3ee94cab, 0x8a1b4838, 0x2c336ddd, 0xbd650da9, 0x59b20e73,
+    0x01ba1eed, 0xe6462bc8, 0xa43d1513, 0xabf1e735, 0x85be746d, 0xbeb00541, 0x2e33f84a, 0xcbbf1157,
+    0x9d0ff7ea, 0x926dcffc, 0x48e467b3, 0xa5f20707, 0x0b2cee5b, 0xc9b0e931, 0xc5d89e0b, 0xbbbc6093,
+    0x401151f9, 0x74f0b874, 0xb92795c0, 0x41795ecd, 0x99909979, 0xb66f055a, 0x2dd53e54, 0xbd550c6b,
+    0x1ec2eb11, 0xdf1a79ba, 0x1a506bab, 0x6fad667b, 0x66ed888c, 0x1917de4c, 0x99b59edc, 0x09d4cbee,
+    0x64b18e6b, 0xba72af36, 0x9f525a91, 0x0773fad9, 0x64a75b6b, 0x8f50a1b7, 0x386961d4, 0x761922fc,
+    0xa58f117d, 0xde08b085, 0xb992daed, 0xb29bf8ec, 0xfbc90f51, 0xd58739bc, 0x1ba654ee, 0x89cb3650,
+    0x5f5c1a9a, 0xec297986, 0xb8f0bdc7, 0x97043d8a, 0xf80658a9, 0xee69fa21, 0xf0d23975, 0x5348b95b,
+    0xd51d5e45, 0x8f092c2b, 0xaca82020, 0x5b86ec8b, 0xbf3ebe02, 0x045b3968, 0x300895f1, 0x42d16656,
+    0xe89ca9b6, 0x0d026683, 0x3d2e12af, 0xd7b13459, 0xc99237f3, 0xa28d0b0b, 0x2178dc07, 0x9b139013,
+    0xf3314af2, 0xba812da6, 0x29b2cfaa, 0x5d32399d, 0x796020d0, 0xe300fe4e, 0xcf66cbeb, 0x11581170,
+    0x6d988e2d, 0xaf2b34c1, 0x8f46cdbd, 0x2e8300af, 0x677f7352, 0x9a982d15, 0xa367600b, 0xcbdd23a5,
+    0xc41f0e68, 0xbe628717, 0xa41efb4e, 0x19a67441, 0x7de238ab, 0xec02f18a, 0x29e47e4d, 0xf13653c8,
+    0x61d93f95, 0xfebf0d74, 0x690ae24f, 0xd4364772, 0x8d6a3855, 0x2b4a69cf, 0x0f2984aa, 0xfda3df44,
+    0x26be3bcf, 0xd161e921, 0x94fa1f0f, 0x3dc5d9b3, 0xc7dda89f, 0x0d4a07bb, 0x867946bf, 0x1507f538,
+    0x5f983b12, 0x15252635, 0xfd808121, 0x23c2c73d, 0x018d3a48, 0xbf26a5c3, 0x5db9b982, 0xf60e0771,
+    0x8501cf47, 0xa04cf9cf, 0x6ff27b25, 0x9f034302, 0x9cd4b7e1, 0x4f907c34, 0xe232cdd0, 0x3e9b893c,
+    0xbba5c608, 0x3643b497, 0x9c0ecfc2, 0x7ebe485c, 0xd4b203b5, 0x81d42463, 0x509bc175, 0xe8bb0fc2,
+    0x42eefd74, 0xcd8d5c1c, 0x599a95db, 0xc5e2f821, 0x638ce9ec, 0xfaaf16ae, 0x0e5c8cad, 0xf14c40ee,
+    0x3eb1a4c3, 0xc1c0f8df, 0x148b633d, 0x26fd9d7e, 0x54cf3617, 0x1c118eab, 0x6678e2ae, 0x6d387abd,
+    0x6b6264c6, 0x3d8a20ba, 0xecfd69a1, 0x595f365f, 0xc8eeee60, 0x396d5940, 0x5c15e730, 0xd4614994,
+    0x39850ff0, 0x5bd88735, 0x0290c92f, 0x214ed5b3, 0xd1cd1e16, 0xd8d8d9d0, 0x471e872d, 0x63ba6d48,
+]);
+pub const VENDOR_MLDSA_KEY_2_PUBLIC: ImageMldsaPubKey = ImageMldsaPubKey([
+    0x9fd27201, 0xde96584c, 0x6ec8bf9c, 0x7463ec73, 0xcceb3164, 0xd3a8fdcd, 0xfef0032a, 0xb5af495e,
+    0xa442b58c, 0xd88bd5a9, 0x28e424d8, 0xf75651d3, 0x44ff8f62, 0x481cd1a5, 0x7412d81a, 0x457d98de,
+    0x2a24f5a9, 0x7c06b423, 0xc2bb3f02, 0xe7efaaf9, 0x4281ba44, 0x07a017c1, 0x3c51142e, 0x07e715a6,
+    0xd9eba710, 0xb0b8f66f, 0x827670f2, 0xf0ee16c6, 0x900bb6d6, 0xa46e055a, 0xe093095c, 0x7a131be5,
+    0x37caef4b, 0x1b78c25c, 0x2e3c4b68, 0xb8e20c60, 0x4c4f920a, 0x3d010734, 0x49d729bc, 0xaec0eb20,
+    0x606f7f66, 0xb62c5ffe, 0x73c402ec, 0x831a9023, 0xf2399d66, 0xc77cd8d4, 0xca553a43, 0xc559ba0b,
+    0x6b26d384, 0xca30434e, 0x97acefeb, 0x62e4697b, 0xce5acb14, 0xc88c0b83, 0x2b17a85d, 0x0de85b6d,
+    0xba1edad6, 0x38107307, 0x2beda21e, 0x25e0a90f, 0xdd14f439, 0xd0e3a205, 0xdf21563d, 0x7654f3b1,
+    0x760c6441, 0x251b956a, 0xa64e1bba, 0x3064c82e, 0xe37b9cf6, 0x507a7dcb, 0xf68b07a7, 0xd78335ec,
+    0xd5c086a9, 0x7c38a5d8, 0x687c4bfa, 0x90f778ff, 0x10330914, 0xedce7737, 0x11922f37, 0x36dfaa1d,
+    0xf02339e0, 0xdb1ea635, 0xcc0b28ed, 0x6b215ee2, 0xbf4d7afa, 0x37076557, 0x0a2c963c, 0x9654afaf,
+    0x673a99e1, 0x10fd2252, 0x481d35b4, 0x9f2df8e9, 0x7eb471fc, 0x904e932e, 0x816e296c, 0x934aab04,
+    0xd2a02e50, 0xbc69599a, 0x995d717a, 0x4a08b469, 0xcfdb88ba, 0x83c8c734, 0xf1b247cc, 0x51d4c1eb,
+    0xbd101f97, 0x84a83977, 0xb6df7642, 0x0ecbb38d, 0xc4ddecef, 0xb100d8d4, 0x842abd39, 0x0e680fd5,
+    0xf6247e13, 0x6b7e09cc, 0x00ec4314, 0xc3b7a190, 0xcfe23d52, 0x38d4f76b, 0xb08558a1, 0xa76967a9,
+    0x523feb9f, 0x944bef56, 0x1ff2d5c3, 0xac7403a6, 0xb43d6daa, 0x4a5f639f, 0x6f565c96, 0x5b1980b9,
+    0x836c212e, 0x33d4298e, 0x6c5e78f2, 0xd745f063, 0x39b85ce6, 0x90a4c4f1, 0x8bca4cf5, 0x7e79f734,
+    0x0fcc0d80, 0xb1a5b26f, 0xa967778b, 0x7a99a270, 0x5e5ccd61, 0xe4a9065a, 0xfc9b911a, 0x5d126c51,
+    0x817fecdf, 0x4913fc65, 0x8efcd6b0, 0x8edadcf3, 0x5314cb32, 0xf422df69, 0x4ed21b7b, 0xd60deb45,
+    0xe6149b86, 0xa3e15daa, 0x4dbfa15e, 0x12feff14, 0xa6baf541, 0x76a4954f, 0x6030bc7e, 0x6f8d4033,
+    0x72832ac0, 0xd6295ee8, 0x6671e197, 0xc9aa8427, 0x6d1ff373, 0x2b8eddcd, 0x5ce887e1, 0x665a8952,
+    0xbb2a32cc, 0x25e1ce9a, 0xf084a47a, 0x991136c2, 0x9c19e2ce, 0xe8aae5e4, 0x937484e9, 0x9ce26f7e,
+    0xd2248381, 0x4bda40aa, 0x891a22f5, 0x69054d5b, 0x8c98ec1d, 0x96750fd9, 0x9625f8c0, 0x6ba9d046,
+    0x96d2f065, 0x3345afe2, 0xa1b66163, 0x0d55763c, 0x57cfd64f, 0x57952043, 0x65e1ba0a, 0xf2082840,
+    0x431a3012, 0xc8d5d95a, 0xa20cd901, 0xc9dad4a6, 0x7598a637, 0x7f88d089, 0xca314738, 0xcde73f8c,
+    0x3ff5a890, 0x57b7aebf, 0x0ff87a35, 0x0a48f15a, 0xa4480673, 0x3120d881, 0x2c23dab3, 0xe53d694c,
+    0x7372f87b, 0x1ed09d22, 0x24eddb8d, 0xb96d3bcd, 0xbc115e03, 0x60ad4dbd, 0x70bd6d31, 0x4fc491d5,
+    0xbe84e3c7, 0x027c4f7e, 0x6ea382ab, 0x9a5c64f1, 0xa5586f2a, 0x6e3992b2, 0x0f92ad3c, 0xd397a5c0,
+    0x7923795c, 0x6a7326fd, 0x5987df2d, 0x169f6943, 0x3bec2c14, 0x63f4931c, 0xb459698a, 0x6e4ac873,
+    0x0430d7c6, 0xe3f2066e, 0xf55c0a4b, 0x1eaa501b, 0x9dfa218f, 0xf5ece264, 0x33fbe51b, 0x532fd4c9,
+    0x6305802d, 0x1a7ba333, 0xad582029, 0x5efeec32, 0x262d1326, 0x63bdb768, 0x4f25bc4a, 0xc48d2d5d,
+    0xdb74ef38, 0xb9f5c5a8, 0x9d6fc731, 0x9a6908d8, 0xf889cff1, 0x4dc1246f, 0x3d0aacb9, 0x56efccd3,
+    0x2d35338d, 0x2fa0c0db, 0xf92dd34c, 0xa842113b, 0x867ca505, 0x527b2e59, 0xe0ef8795, 0xfe1bd3d7,
+    0x5dc823fe, 0x08b200be, 0xbee50058, 0x2cba7db4, 0xdf3fe9af, 0x3fe9655b, 0x946111c6, 0x7a7b8dc5,
+    0x5c568d92, 0x45ca4267, 0x74bf1c27, 0x21b9a332, 0xb347bed1, 0x47fad532, 0x53490f9f, 0x99bce9cb,
+    0x9daeddd2, 0x8947d230, 0xb1f5df81, 0x6db87851, 0xb40df364, 0x97262334, 0x4fbee586, 0x7a72fcd2,
+    0x0cd1c0ff, 0xc2cce752, 0xb4fc14cb, 0x3acd92f3, 0xa4fd5444, 0x1a530b43, 0x859738bd, 0x0fe95bd1,
+    0xcb8b4c00, 0x066e6528, 0x17e0460a, 0x1d62d028, 0x7d849ce3, 0x5174c159, 0x7a1bbba2, 0xeab6780f,
+    0x6aaf338e, 0x5d9da552, 0x18c7c5f5, 0xd2a9f780, 0x6d1e711b, 0x2a730b14, 0xb22ce1ef, 0x1e7bfd1e,
+    0x8ae6a4f5, 0x13cd504e, 0x2279c605, 0xdcdd144f, 0x3681c920, 0x9f923967, 0xd38e2519, 0x6e548e9f,
+    0xb5c32e43, 0xf3dfde7a, 0x5cc03d0d, 0x0146a3e0, 0xb796e057, 0x1e60f236, 0xbd1a9449, 0xbab63b6a,
+    0x17ffc9f3, 0xab5004eb, 0xa03bb817, 0xfb55caa2, 0xf3aed327, 0xb4508684, 0x12aab70a, 0x4746c518,
+    0x48b7cd31, 0x5e15e0e1, 0xa26ad296, 0x29621a57, 0x63de5ce8, 0xaf62c981, 0xf20cdebb, 0x0266a7f3,
+    0x91be5a32, 0x6e9a0741, 0xaf7e143e, 0x280d58a2, 0x88c8cf63, 0x83f7f51c, 0x917329a8, 0x1f7c038b,
+    0xd4c1d2ff, 0x086e7d44, 0x323568a0, 0x42028c43, 0xb33f1c68, 0x22268ed1, 0x77f6427d, 0x5776a8c0,
+    0x176d90dc, 0x3116e05f, 0x93dbe16a, 0x2291f509, 0xa0fc3950, 0x8792eb9f, 0x79dd1930, 0x684362ed,
+    0x32331a9a, 0x9c1e5efa, 0x24bf2640, 0x91e042af, 0x5e5c6ab3, 0x037eb8da, 0x1a63eb91, 0x7deef573,
+    0xa7d3a741, 0xe82874f5, 0x49784e32, 0x37cf1e57, 0xe5e84ebd, 0x1bc35ffe, 0xe48d7196, 0xc6064f2d,
+    0xf77680b0, 0x9f4d24bd, 0xc04000a3, 0x5d387c21, 0xdd6dc96d, 0xf0c9f61b, 0xc9bfbeed, 0x219ce36e,
+    0x4cf0f111, 0x12808b8a, 0xd7add301, 0xa7175613, 0xc5692048, 0xf9b5dcf2, 0x050e6f27, 0x9f540737,
+    0x7dd4775e, 0x4d28c4e0, 0x8a719673, 0xfe48ea5a, 0x52209f90, 0x077f7297, 0xef4d494b, 0xd6a15fa8,
+    0x360789bc, 0x7593014d, 0xe3056b05, 0xedcec3e9, 0x4576c758, 0x1150b0cc, 0x9562eebb, 0xa8cdecc8,
+    0xa876099c, 0x022084e0, 0x97587d75, 0xa6f974f9, 0xb16ed66a, 0x80d32cc3, 0x0b03bd4a, 0x7103863d,
+    0x550cc852, 0xa9303df4, 0x9b5cafd8, 0xbb48569e, 0x261f775d, 0x0179884d, 0xc82bc488, 0x973d965e,
+    0xe6c07245, 0xd2729b25, 0x59b4fdf4, 0x35c39fa2, 0x17868ebf, 0x227a7b8b, 0xc7a9b07f, 0xe0c34d7b,
+    0xade1c8a3, 0xe80a4c4e, 0x0274fbe2, 0xcc5b4ca0, 0xafb22c0b, 0x7ac86af4, 0x17f232d7, 0xd6b287c9,
+    0x7e3deb1a, 0x260bb3dd, 0x92b0886b, 0xd137c284, 0x2cac10a2, 0x28a2d59e, 0x7c14c51e, 0x57006d4d,
+    0xd84c05c2, 0xfb4ac104, 0x00ca2a45, 0x595f6dc2, 0xdd03a152, 0xa26f3cf7, 0x54e00d81, 0x6e04b7ba,
+    0xf4c77eeb, 0x5ceb1d13, 0x38b62603, 0xce387ce9, 0x3ae93b8c, 0xa4ee20c8, 0x90d497f4, 0xca454ba9,
+    0x6895c225, 0xcd47a9f5, 0xf3ce1a8e, 0x097a08a2, 0xe8e6b6fc, 0xbb74f312, 0x27cc6e8f, 0x554fc71e,
+    0x349e6357, 0x3d16cea4, 0xbe96a17d, 0x95568c01, 0x91e46598, 0xdee25c9e, 0xdf287082, 0xc2679628,
+    0x8cdbcea3, 0xe8ad302e, 0xe98d7a23, 0x012387d9, 0xfdd968d8, 0x2b4d8cd1, 0xb149de50, 0xc8f07120,
+    0xd46fc1cb, 0xcc16f1a9, 0x74508e06, 0xf08806ef, 0xd7746914, 0x296ebb07, 0x1e073cf2, 0x16b497d1,
+    0xbd2b58fc, 0xbe6554d4, 0x4029d67a, 0xea4ac103, 0xd6742a0d, 0xe78b7cc5, 0x33a4b62b, 0xdce959cd,
+    0x5c5af8a1, 0xacaa601c, 0x4217a737, 0x59bd53d6, 0x1feeb20c, 0xe02ded8c, 0xafb18321, 0x388ddb73,
+    0x0856bef8, 0x3dbef311, 0xaffaa3e3, 0x871a1ea0, 0x937bd3c6, 0x54607548, 0x844dcefd, 0x07b60772,
+    0xcee1b44f, 0xd4681d31, 0xa49f18a4, 0x90818778, 0x309a85a6, 0x870b5c96, 0x1dfa4fb8, 0x9b66a9e1,
+    0x116985a3, 0x1fe61d14, 0xba0e92b0, 0xa2255658, 0x364f1166, 0x39078f70, 0x4dc464e3, 0x3d011f75,
+    0x17e53972, 0xf9392e4d, 0x13f35e00, 0x963cbe51, 0xa4d71d9d, 0x5a651b27, 0x19c34925, 0xc4728847,
+    0xd29c3cb9, 0xe937a640, 0x0aad298b, 0x288481fe, 0x19ca4344, 0x5dedf3c1, 0x459e5e03, 0x7cdece5f,
+    0x1c7a923a, 0x07e96984, 0x5d8486d5, 0x9d87ef9e, 0xed9861c0, 0x3da657f0, 0xecea4109, 0xddc471f4,
+    0xa6e9997c, 0xf4a504fa, 0x28b9fc66, 0x15328612, 0x7dfdf890, 0xc26c9cf7, 0x6b742219, 0xc4da25d4,
+    0xa877531c, 0xa7f24af2, 0x1c71f94f, 0x7e5df2fe, 0x154f98df, 0xd8e0033c, 0x7c19b842, 0xd4692f32,
+    0xf3413841, 0x34246537, 0x1636bb9a, 0xb2d0a9cd, 0x2b21d4d5, 0x98156372, 0x335fe54a, 0x9f2c2aba,
+    0xae4c2580, 0x9640ff75, 0x366ce7ca, 0xa928a56f, 0xb0ee64a3, 0x98e3b4e7, 0x04679f6a, 0xfd682082,
+    0x60e57311, 0x7e4d3c1c, 0xbd1d53d9, 0xade2ac3d, 0x57def6c4, 0x6a965e07, 0x8dfa3090, 0x2ecfa655,
+    0x0efbe18d, 0x95dfd302, 0xa4c3c6a9, 0x9847f056, 0xaa5ae341, 0x0983e776, 0xe214ee4d, 0x5f8905c9,
+    0x89b58e95, 0x81daea0f, 0x19e00002, 0x52d78b69, 0x90d45c2a, 0x8c86bbb5, 0x935c5095, 0xdc13c816,
+    0x2dbc3343, 0x059a600d, 0x4769abc8, 0x9b2f1067, 0xd851c8df, 0xf4c3dc29, 0x064633b6, 0x71e3bcca,
+    0x3630f3f5, 0x755e811d, 0x702e2766, 0x12ac5c83, 0x234ef9e8, 0x5362fa03, 0x1e3c5d99, 0xd808e324,
+    0x2494a540, 0x536a19ed, 0xfd1394d9, 0x8e4c08e7, 0xc1371a3d, 0xdbdbfbfb, 0x9991a694, 0xb9056bb2,
+]);
+pub const VENDOR_MLDSA_KEY_2_PRIVATE: ImageMldsaPrivKey = ImageMldsaPrivKey([
+    0x9fd27201, 0xde96584c, 0x6ec8bf9c, 0x7463ec73, 0xcceb3164, 0xd3a8fdcd, 0xfef0032a, 0xb5af495e,
+    0x3cdf41d3, 0xf8525004, 0xc215effd, 0x9a3b69d3, 0x5091d2c9, 0xa381f84e, 0x39c8086c, 0xab0eab97,
+    0xb6984840, 0x0ff8eb96, 0xe1895017, 0x20822c10, 0x5cdd679f, 0x07554881, 0xb405626d, 0x2c457a66,
+    0x7335cb76, 0xb85e304f, 0x6c48eb91, 0x41be7971, 0x76b2506a, 0x7364cd19, 0xd1dad122, 0xb021351a,
+    0x048106e4, 0x40638c24, 0x2022c964, 0xe4453509, 0x28049202, 0x2a369328, 0xd42500c0, 0xb5196e36,
+    0x6d149211, 0xe20d8900, 0x94c89142, 0x21388311, 0x8160b0c2, 0x34034e28, 0x4cb84845, 0xa421c6d8,
+    0xa8896084, 0x42144410, 0x12304648, 0x300c3009, 0x44365c09, 0x1071a891, 0x169890c8, 0x48490132,
+    0x139204e0, 0x06d42427, 0x50a24a69, 0xda910481, 0x32526882, 0x2d324025, 0x8868c60c, 0x168244c2,
+    0x2202228d, 0x216a0711, 0x44c42d85, 0x86225392, 0x8b6cb522, 0x02144004, 0x61948149, 0x2431c8d4,
+    0x10124e19, 0x8442d245, 0x132dc222, 0x004a8585, 0x09904408, 0xd900048a, 0xa0094642, 0x04280420,
+    0xcb0c3499, 0x960340c6, 0x2a484145, 0xa38c4051, 0xa45081a8, 0x6dc85b68, 0xc10e42c4, 0x00238e20,
+    0x11b51a04, 0x5c300044, 0xc9112432, 0x3123032c, 0x09480814, 0x80614a02, 0x66381850, 0x44702251,
+    0x86126210, 0x20270068, 0x130e3401, 0x10e484c2, 0x8d165b04, 0x204d2249, 0x92d30010, 0x0194246c,
+    0xd165a312, 0x98241146, 0x45891490, 0xa44e1724, 0xc7104106, 0x2408e005, 0xe070871a, 0x82a008b6,
+    0x90a68261, 0xe16c9711, 0x88a03220, 0x85a8ca69, 0x23661284, 0x188a2591, 0x4d34d266, 0x0b0c144b,
+    0xa8c80004, 0x10012001, 0x200ca898, 0x03091082, 0x4a00e165, 0x9b82240b, 0x25021112, 0x4e45204d,
+    0x0141a8a2, 0x00831225, 0x10971c62, 0xc1893490, 0x32088a32, 0x2c241962, 0x094238cc, 0x35186d38,
+    0x84930102, 0xd14cc4cb, 0x05228184, 0x4c381389, 0x8306445c, 0x20106834, 0x2dc8d911, 0xd984a21a,
+    0x86502506, 0x11071961, 0x088cc293, 0x02d88625, 0x9006d032, 0xc36ca694, 0x412485a8, 0x51b66245,
+    0x0c2c1223, 0x08114192, 0x20b28225, 0x430d1858, 0x170c51c4, 0x24246464, 0xc88c8614, 0xb6018498,
+    0x2a16da8c, 0x1c41a322, 0x08493021, 0x8c482366, 0x1c4e20d3, 0x212224a5, 0x6d98522d, 0x4032061b,
+    0xc6124208, 0x92225109, 0x83499894, 0xa4c004b0, 0x8102a20c, 0x00080718, 0x06cc4008, 0x04432451,
+    0xda5134d0, 0xc30c8640, 0x85229c31, 0xe4902053, 0x470a1114, 0x0886c922, 0xca4814c1, 0x10192a34,
+    0x81a90921, 0x0a862821, 0x34227042, 0x02041864, 0xe1502064, 0x49096d06, 0x8d44a06d, 0x80513519,
+    0xa8849118, 0x8838014c, 0xdc322049, 0x27048d86, 0x2a148888, 0x9a822454, 0xc41b0422, 0x0a06112d,
+    0x140d44a4, 0x94496c11, 0x28c60245, 0x0051c403, 0xb0097240, 0x20310060, 0xd98a2053, 0x488a01c8,
+    0x69440984, 0x0b063012, 0x06e250b8, 0x8188e188, 0x22644810, 0x38822d41, 0x9126a411, 0x224420dc,
+    0x40948543, 0x40c26466, 0x03122208, 0xc6917191, 0x11121241, 0x4c60a041, 0xa0918a08, 0x0e050929,
+    0x622e1298, 0x04231236, 0x28142162, 0x09661200, 0x42230236, 0x2d86194e, 0x01681219, 0xa8902512,
+    0x05182129, 0x090d34e0, 0x92208948, 0x25841191, 0x1c80b693, 0x86133140, 0x8d805241, 0x122a1080,
+    0xa0497134, 0x80388489, 0x9b2d9302, 0x28c464c2, 0x7246600a, 0x8165129c, 0x42597194, 0x06051a48,
+    0x88262719, 0x06016024, 0x2c942190, 0x6212168b, 0x24987182, 0x2142c006, 0x1410b200, 0xa70929a3,
+    0x90b8c804, 0x032830d8, 0xb0c31213, 0x25385328, 0xe30e041b, 0x90106586, 0x3034d111, 0x1970141b,
+    0x401a9007, 0x82188110, 0x196e2613, 0x108b91b1, 0x6c30cb69, 0x8189b0e1, 0xc90165c0, 0x88a46029,
+    0x83458900, 0xb2ca2d12, 0x2d86e380, 0x08890408, 0xb6422215, 0x66364a80, 0x544534db, 0x90e185b2,
+    0x45440860, 0x2060c41a, 0x00118d40, 0x91391825, 0xc2498320, 0x08182082, 0x0535012a, 0x6240b500,
+    0xb6c88598, 0x88868a64, 0x83883303, 0xb6446820, 0x4c10d064, 0xd100388a, 0x12180c34, 0x7094832a,
+    0x5c80a6d3, 0x89090638, 0x60021b0c, 0x109040c9, 0x06a04238, 0x0e44e244, 0x4b69c6a4, 0xb08820c4,
+    0x69308061, 0x132cc284, 0x252309a7, 0x4da8d486, 0x5b2938c8, 0x191a6dc4, 0x10941280, 0x814108a0,
+    0x02e16924, 0x60240330, 0x644cc8a1, 0x220a20a4, 0x2a241305, 0x4389c0e4, 0xb29a2440, 0x20a86101,
+    0x494e4483, 0x47130142, 0x09369184, 0xc805131c, 0x191c0da4, 0x06192410, 0x54649404, 0x82102590,
+    0x0ca49301, 0xc0410721, 0x98a20084, 0x0e28a369, 0x11802704, 0xa8422234, 0x89829124, 0xcc5034a2,
+    0xa40b3212, 0x4984da2c, 0x1012180c, 0x33108982, 0x0d989861, 0x1305470b, 0x44890017, 0x04380292,
+    0xc92e4918, 0x188b0432, 0x29b45c8c, 0x244104e0, 0x43092948, 0x4212cc4c, 0x4a3110cb, 0x36909248,
+    0x4db40160, 0x8a09b090, 0x24db68c4, 0x70951271, 0x5b05c2a0, 0x38486132, 0x4649180d, 0x018a051a,
+    0x928b6e20, 0x6e471c4c, 0x1c28b6db, 0x34626a18, 0x0646040d, 0x00912310, 0x84c49111, 0x44445941,
+    0x0a0d12e4, 0xc5042a20, 0x2836c22c, 0x890480c2, 0x24886e14, 0x5006146a, 0x612c9720, 0x260480a4,
+    0x09049112, 0x03124912, 0x82908c37, 0x4542c290, 0x4369a81b, 0x02614d18, 0x4618a171, 0xd825201c,
+    0x32897230, 0x0184e292, 0x1308961b, 0x105c0c24, 0x00340b6d, 0x220d0821, 0x22c98146, 0x20c0520d,
+    0x4083b963, 0xb645788a, 0x9224d1e8, 0x1d1a4c34, 0x50916834, 0xbd183e2f, 0xc30c9e97, 0x0c133ec4,
+    0xb77313fa, 0x9e2d1725, 0xfdf12d66, 0x19bf6728, 0xe017b8f6, 0xe54b858b, 0x4f751c45, 0x00976db9,
+    0xb5389b15, 0xf4996508, 0x5a151797, 0x5c4bb3a0, 0x332bcf52, 0x334e95a1, 0x4118dedf, 0x092aedd9,
+    0xd08dac14, 0xe6d2c389, 0x5576681e, 0x8257de0e, 0xe4780125, 0x19b3364b, 0xe703c647, 0x2b466121,
+    0x55a32659, 0xdc255005, 0xcc960235, 0x4d12ab00, 0x97d8c16d, 0x4120fb14, 0xb510dad4, 0xf84d3163,
+    0xfebec339, 0xa3d2e867, 0x87a9bbb9, 0x68400ffb, 0x02821ec7, 0x0187539d, 0x1c6593c4, 0x719b5bbb,
+    0x2f766f0c, 0x508c2ef8, 0x4725b660, 0xe55ab98d, 0x23f1101f, 0x8adfafa2, 0xf590192e, 0xc3884f3c,
+    0x2731bfb1, 0x05050474, 0x7e9a8667, 0x1db18d23, 0x924cedcf, 0x2458278b, 0x86d90586, 0xeb452c27,
+    0x530a6a58, 0x94c50c10, 0x477a67c1, 0xffae2d4a, 0x826ca28e, 0x7514f837, 0x31ec1c6b, 0x0ddbea7b,
+    0xe9291e7c, 0x06c33d53, 0xd03fcc63, 0x3b0ff307, 0x3cd48d46, 0x4a3e69c0, 0xe5b126fe, 0xc13de4b2,
+    0x5a0c1525, 0xd1e1ae08, 0xd027f5e7, 0x4c356458, 0xafc0d3d5, 0xe3062ffc, 0xc9800561, 0xfd00ccab,
+    0xd992e788, 0x8771df52, 0x4f47f301, 0x146289a8, 0x0fbaaa4a, 0x49f68d6e, 0x6b374747, 0x00261868,
+    0xa85a820f, 0xf182c6ec, 0x1d34aafc, 0xb662f662, 0x753cb097, 0x34c9c92e, 0x76f148ca, 0xe2374414,
+    0x0c18469b, 0xc8653173, 0x5b9aba87, 0x06b95954, 0x375eb73b, 0x0415709b, 0x84ef9c37, 0x0be1bc30,
+    0xd21b6a34, 0xb95850ea, 0x6c25f0c6, 0xbbb92421, 0x141da08f, 0x1e96c17a, 0x749753f7, 0xfc647fa0,
+    0xe5f325ae, 0x0e00d7e7, 0x432a3f93, 0xae457e65, 0xadeacf5e, 0x5f4d8c2c, 0xde1a0cb8, 0x481d8dda,
+    0xef61a614, 0xda0e5b7c, 0xc7a77642, 0xf7dbbd52, 0x1bf50b93, 0x708039e5, 0x352cc36e, 0x597baf15,
+    0x39d98728, 0xb0f63499, 0x43503a41, 0x1eb591ce, 0xf201cc87, 0x3e6d80b9, 0x0af71957, 0x32097ba6,
+    0x0bd9f7f4, 0x0f32efd0, 0x1e43a856, 0xa1d03ce3, 0x8fc225dc, 0x49970651, 0x60817992, 0x0ddf72b3,
+    0x91fc95b9, 0x51d2497a, 0xbe72a6cc, 0x75f3bc9c, 0x72247a6b, 0x2d82999a, 0x536c3a0a, 0x40dee781,
+    0x844ec0f6, 0xc90bd21c, 0xda4c9c7a, 0x66715c51, 0x11b9efa5, 0xe4031482, 0xb8d1cf14, 0x6c35de99,
+    0xdc5c1091, 0x53ba2b18, 0x056d2ae2, 0xcb6558d0, 0xb5fbd4e3, 0xb42a473a, 0x4d831f1e, 0x0612c7d5,
+    0x77e0d235, 0x991b0d7b, 0xa0b4a4dd, 0x8e58008f, 0xd983c441, 0xbb66ec7c, 0xa5f2886a, 0xa5e6b7c0,
+    0x9d003c81, 0xb88f41eb, 0x999e385b, 0xdf45bacd, 0x192b402e, 0x891a718c, 0xf61868a1, 0xcfbcf1bd,
+    0x319b1b8b, 0x2271317b, 0xe6cfa60f, 0x16ea2865, 0x269d5ac9, 0x7e2aef33, 0x0f6df156, 0x9c571b90,
+    0x8f888bdb, 0x75859a54, 0x58c40019, 0x370faab1, 0xb1a2e232, 0xd760f465, 0x0c0abfb2, 0x3e4aca1d,
+    0x34203c41, 0x92eb5ea6, 0xc713b6d3, 0xc1d04ac8, 0xfcabcbe7, 0x3d52fcdf, 0xa36f1fdd, 0xee085237,
+    0x81fef11e, 0x53f0ef79, 0x08a30dc8, 0xfc63e3f3, 0x4aabe1ac, 0x052fe2d5, 0xc892ba8f, 0x6cddc26e,
+    0xa640acdb, 0x4a569069, 0x91b7000e, 0xe03d1963, 0x05514452, 0xe17d0772, 0xcc5a539e, 0x4855dbdc,
+    0x51abca29, 0x5d57a722, 0x6cdca735, 0x3ecb4302, 0xb19e80f3, 0x6812d420, 0xf1c3c96f, 0xf68fed6b,
+    0x375a61fe, 0x5f598501, 0x949d9747, 0xd08769b9, 0xf09f9119, 0xb3687ccb, 0xfff83d3e, 0x7e617a13,
+    0x03674b5f, 0x75b69a53, 0x5e77b3a1, 0x945f511d, 0xbf5c199a, 0xa8fb822f, 0x94a11e2b, 0x52f7013d,
+    0xc8709424, 0xc1c1adb1, 0x64521db9, 0x0002a6aa, 0xdc8e3b82, 0x3546380f, 0xb79a50a9, 0xbad2af03,
+    0x8e403a24, 0xd3344add, 0x839329c3, 0xb19aabe4, 0x599a8406, 0x6029aec9, 0xea3ff71f, 0x9a591aee,
+    0x1d322b28, 0xbc44a794, 0x57c46bf6, 0xe560ad4f, 0xa30dc8a3, 0x18866c5f, 0xeb7dd180, 0x6b671c39,
+    0xcf30001e, 0xce9f0622, 0xdeabe2c4, 0xdead18d1, 0x2e1bfb2e, 0x67638024, 0x61aa40b4, 0x766f6ff7,
+    0x421f8998, 0x06dc063a, 0x256fe8a9, 0x2a3e653f, 0xbd4300e0, 0xc0382a3b, 0xf17e0631, 0xefc0e6da,
+    0xd95b5dde, 0x610480fa, 0xcbe71674, 0xec2e447d, 0xd8853cfe, 0xc75d6ceb, 0xac8c7c96, 0x009fc220,
+    0x929baaa0, 0x69c4572e, 0x5b301fbd, 0xd69153c2, 0xbac24ca3, 0xd004ec72, 0xefa012da, 0xf539058e,
+    0x649a25e5, 0x2f8d4dc4, 0x8b2834bd, 0xe6434d04, 0xcaa20fed, 0x6cbaff50, 0x12a25a7a, 0x8560649a,
+    0x8c5227ff, 0xb266cffa, 0x4865f92f, 0xae0d69a4, 0x16702ce5, 0x886fcd6c, 0xf2d9ed74, 0x5aa3930c,
+    0x359b37b9, 0x147826e8, 0xe13d9234, 0x7ba9be5b, 0xff58019c, 0x9460db18, 0x939fdd1b, 0x2b912dea,
+    0x8ba28aaa, 0x764c243b, 0xedef4f89, 0xf29e9afb, 0xe73a9a7f, 0x1e1c507f, 0x501f3a00, 0xc3f6eb0e,
+    0x3e0c693c, 0xdd9ea905, 0x20ee003d, 0xcf7f750a, 0x10ee401d, 0x82d06053, 0x86af002b, 0x81ed9e3e,
+    0xbc334f71, 0x5a8476e9, 0x052f998b, 0xcc575fef, 0xce7f629f, 0x93e7298a, 0x7fec2594, 0x53ae9854,
+    0xa4d46a21, 0x35dcf135, 0xa9ba9d09, 0x8fafb78c, 0x50900b71, 0xaaa6b648, 0x0a41a7bd, 0xe6bc3dde,
+    0x4a01fcea, 0xb90cda73, 0x30ddf8cf, 0xc0e8ed1f, 0x81ccf34b, 0x071f90b2, 0xfc6e5044, 0xc80b9655,
+    0x24397e2b, 0xd93b7c62, 0x52b2626d, 0x5687c08d, 0xff7f1fc4, 0x50ded47f, 0xa381421f, 0x8b0d057f,
+    0xa73dbaaf, 0x326bb4a9, 0xc42a7806, 0xbd2c1473, 0x22c4d0cd, 0xb8e88e23, 0xc6216bfd, 0x57304812,
+    0x88a11e55, 0x75816064, 0x92270997, 0x5743c991, 0x55f05869, 0xee948b9e, 0x0600df47, 0xac5faaee,
+    0x72c43f0c, 0xafc59c65, 0x22fbf95a, 0xa58b360d, 0xc4dda64b, 0xd34b167b, 0xa2829ca6, 0x299fed97,
+    0x59151886, 0x6ea575d1, 0xd60b8f21, 0xc670f4c3, 0x7713016a, 0xc4d90177, 0x22e5110a, 0x1986951e,
+    0x1ded110b, 0x508c4ea4, 0xe6fb388e, 0x56ffdf32, 0x53d4e837, 0x83600e71, 0x579b499b, 0x6beb9d9a,
+    0xe157d9e6, 0x7bb46d22, 0x7b52e2ef, 0xde3a6054, 0x46d8d55e, 0xe71b9bee, 0x8e38427c, 0x352fa22c,
+    0xccb574f7, 0x28d57b75, 0x92cf2735, 0x547aed2f, 0x8a89faf3, 0x96dc5177, 0xb4dd6e77, 0x134430e6,
+    0xbb9c7af8, 0x583be59a, 0x6f1a675e, 0x615c9cf8, 0x94326392, 0x0cb8aa01, 0x822fe9d0, 0x5998c585,
+    0x7ae29141, 0x3e1c2c45, 0xc3abd497, 0x0ed48564, 0x318283c9, 0xfe0d1454, 0xfb565082, 0x27bd8f3a,
+    0x1c417893, 0x20fc6280, 0x709d7c59, 0xec57aa88, 0x187681e9, 0x8115f370, 0xcc516ad7, 0x90c9615a,
+    0x667ac28f, 0x128edbc2, 0x47c7b14c, 0x0920ee16, 0x977e5781, 0x788ea42c, 0x5e978791, 0x9c83830f,
+    0xae8ac21c, 0x79deb083, 0x2894f967, 0x0af3c0fb, 0xa4699f47, 0x05eb2db9, 0x29053aba, 0xd8deb94f,
+    0x25b546b7, 0x7fdf206a, 0x4d7b0a9c, 0xb13ec281, 0x1d394978, 0x797ee477, 0x7af3bf56, 0xebd1be36,
+    0x92f4344e, 0xa308d543, 0x3ed9ea1f, 0x631fb942, 0xaac1ad42, 0xbb808968, 0xe7271b60, 0x9640346f,
+    0x64681159, 0x12d6b8f4, 0x3e0bbb73, 0xe04b4218, 0x3aa73f04, 0xbcc89950, 0xe4667574, 0xdb022dc1,
+    0x70b840d4, 0xcf1a4763, 0xbb9d413b, 0xb65bf464, 0xd9b166a1, 0x5220a1b8, 0x5efd4574, 0x1805e36a,
+    0x9d0b441d, 0x4bffbfb9, 0x05f9e4ca, 0x328f0f01, 0xe0269734, 0x3edb3af2, 0x5785c2e7, 0xfb188ddc,
+    0x753d5018, 0x79c3947d, 0xe9aed981, 0x726f113e, 0x139f250c, 0x5b597287, 0xc448501f, 0xecad5ca6,
+    0x24a7ed74, 0x34d773c5, 0x73ef7172, 0x0a24e0c1, 0xfcf8b0d9, 0x7d028a66, 0x2f5de092, 0x52643305,
+    0x54ee1b55, 0x04319a2e, 0x63fcf8ef, 0xacdfd55e, 0x535ebced, 0xebd89e0e, 0xf589c95c, 0x52fb15d2,
+    0x9613a1fe, 0xa611b90b, 0x969187fe, 0xc3e90214, 0x0d573e83, 0x71f08c33, 0x8332a621, 0xd945ca99,
+    0x80b420b0, 0x9698daa5, 0x0ac24334, 0x985b117c, 0xf2aadb0c, 0x6a039fd6, 0x240661d2, 0xb7ded933,
+    0xcaa221d8, 0x2f9387e8, 0xfa40ac73, 0xe2deef34, 0x0e5330e8, 0x1fffc6fe, 0x9bcf35cc, 0x2f369e39,
+    0x9bcd3d67, 0x18f38240, 0x74c32768, 0x6716da9a, 0x11d99aa0, 0x1c74ab7d, 0x91c939dc, 0x22420a4f,
+    0xee369482, 0x008c5c79, 0xd080734e, 0xed06078c, 0x481ab2c9, 0x11d8a230, 0x152a9d5e, 0x6332197b,
+    0x9080d900, 0xe2ed31a5, 0x87607db8, 0xc2edc22d, 0x144abc72, 0x7a3b9d58, 0xe941f5b3, 0x8fbb490d,
+    0x42736fee, 0x123c36b9, 0x7295bfac, 0xd4136c2d, 0xfaf5a7cc, 0x568db749, 0xee721663, 0xae783354,
+    0x7916e316, 0x52d4872c, 0x1bf6af7d, 0xeef2c5a3, 0x77b2fb55, 0xbf635007, 0xe9708867, 0xff7020eb,
+    0xdee70cb2, 0x658cf896, 0x5c61a0a0, 0x83916c98, 0x2cc07a76, 0xfa45e7c0, 0xd35ccd9b, 0x9df47b69,
+    0x9ca021c6, 0xc90889a4, 0x2bcf1733, 0x5c02d4c4, 0x3b1ac9d7, 0x2b7c0de5, 0x4181c4d3, 0x1ae4300d,
+    0xe88c331e, 0x0f9e6e09, 0xdcc6b79e, 0x01c9ffd8, 0x9be54158, 0x192d17a9, 0xf8978cdd, 0xf8b21e62,
+    0x44608e41, 0x4d6c456d, 0xf903c9ae, 0xe8d204df, 0x499ef9e6, 0x70e4fc4e, 0x4741c62e, 0x747d8948,
+    0x19aac617, 0x3c6b7f3f, 0x6c39a82f, 0x3f351c5a, 0x9e9ec24e, 0x860cb385, 0x3fed8422, 0x68deaff7,
+    0xce32b18b, 0x41a65b08, 0xe9098910, 0x95bfa891, 0xf042ce35, 0xae884e25, 0x6d4b5259, 0x27eb0e1e,
+    0xa8e6c8d7, 0x4c082ec0, 0x8409330e, 0xa67ca5cc, 0x579a2efa, 0x56be13b6, 0x2a506123, 0x663bf99a,
+    0xd058dacc, 0x3df05da0, 0xb46ee8f9, 0x26b17ce8, 0xe6761a38, 0x4ccdb35b, 0xffc4dd65, 0x45a552c2,
+    0xf8213596, 0xd9805c3f, 0xed3e1887, 0x4237a361, 0x5864b7a4, 0xd7fa6acd, 0x422c1855, 0x52cfd8e4,
+    0xbba9b822, 0x1c4f299e, 0x8cceb007, 0x51d48ab0, 0x5e89f098, 0x3099c65e, 0x62ed5a4c, 0xa1ffba4c,
+    0x085d1b9c, 0x3b70aa9b, 0xa108873f, 0x0afbabc9, 0xc1924431, 0xc1a4f30f, 0x30a55441, 0x738b655f,
+    0xf3dbd955, 0xe3e643e8, 0xef130a7d, 0xec94c532, 0xbfe3d4d8, 0x003097c6, 0x403b675f, 0xed05dc16,
+    0x611e78e7, 0xec5bb416, 0x68358c5a, 0xd67c66ec, 0xa6784d1a, 0xef0283c8, 0x78e904b2, 0xe87c0fd5,
+    0x452c74f0, 0x0de56885, 0xa76c7f8a, 0xd464a19a, 0xd01ffedb, 0xc80e018e, 0xf22b3544, 0xb1f711b1,
+    0x4d61c58c, 0x013558e4, 0x9663b6b3, 0x1d63361c, 0xa2f874e1, 0xecc7cdf0, 0x90437bb5, 0x1f07a5ca,
+    0xcd3ba7ce, 0x390edadb, 0xcf335270, 0x398ae2f1, 0xd58f4ead, 0x949494d7, 0x97cc8ea3, 0xad84a105,
+    0x73223f9c, 0xc43dbcce, 0xccbb5107, 0x1e47016e, 0xd231e470, 0x6d0293e3, 0x9d7b4686, 0x45950972,
+    0xe9046db4, 0xfc51257d, 0x7c45eca6, 0x249f5e8f, 0x9c3f1e32, 0xd957ae0d, 0x79b970dd, 0xe9c5b90f,
+    0x60f994ef, 0xf77ddbbb, 0x2f3f1301, 0x37e3ce89, 0x947f109a, 0xdcfbffc2, 0xaa00b356, 0x8e56d097,
+    0x0887e9e2, 0xdec19d26, 0x2bc17986, 0x6adb63ab, 0xee8ee0db, 0x0f76ae62, 0x794cbd6a, 0xe39074fb,
+    0x443f264f, 0x77b55f53, 0x4aa7d35e, 0x0854fb7f, 0x3beb56a5, 0x6a5a77ae, 0xceaa500c, 0x38f07b0e,
+    0x0b2d10ff, 0x921ed098, 0x6c5bae30, 0xcd6235d3, 0x6bec6e3b, 0x21e6e63b, 0x567db37c, 0x40ec16ae,
+    0xfc789f6e, 0xeaf643fc, 0x6e831d5d, 0xaba8599f, 0x81a631a6, 0x46164c30, 0x59c86e1a, 0x71a7e012,
+    0x02fa827b, 0xbc8f57d7, 0xa05e0c0e, 0x9a80a370, 0x9fab1f42, 0x136d2237, 0x1f6fb259, 0xb956106b,
+    0xb2fc240c, 0x2cfe0981, 0x8400c204, 0x7ecb0053, 0xdb23bb01, 0xdd931075, 0x0ff8f8fe, 0x3082c992,
+    0x7ce8f7e1, 0xa491c4b2, 0x97698ac8, 0xe5d1250f, 0xead41859, 0x9c0bb8f6, 0x76572905, 0x275a02ec,
+    0xa4ff3a8c, 0x93953e80, 0x54a7a051, 0x859dd47e, 0x71edbacf, 0xb8f87ac9, 0x313c00ab, 0x80b505e1,
+    0x09a3fe43, 0xe6463761, 0x74c1ee85, 0x8eb5c33e, 0x5b879e36, 0xb9332201, 0xed52be0d, 0xd8bf3ef0,
+]);
+pub const VENDOR_MLDSA_KEY_3_PUBLIC: ImageMldsaPubKey = ImageMldsaPubKey([
+    0x676a688f, 0xa1166d71, 0xb38a3640, 0x468f074c, 0x5e489d06, 0x0b001476, 0xa1797da9, 0xcfcccf9c,
+    0x3defe4ca, 0x4e86a9b4, 0x1361ccb0, 0xf21549b3, 0xf5d65c69, 0x94fee787, 0x88002ea2, 0xa91daed9,
+    0x62e94067, 0x71190bae, 0x1c121426, 0xf6578cb0, 0xb106e3c1, 0xb17287e2, 0xd101c466, 0xe0060f1a,
+    0x4f548f6b, 0x610bd9cb, 0x3590fea9, 0x651cda33, 0x8716ac92, 0xa18d20a4, 0xb78e0d6d, 0x788ac836,
+    0xcb2b08ae, 0xe6157efb, 0x64f1977a, 0xfe9c70fa, 0xa7273cab, 0x2b549fb8, 0xf459c782, 0xace5153a,
+    0xeeeff890, 0xdd52a982, 0xf7cbc30f, 0x3d2e91d6, 0x09755b58, 0xffafb834, 0xaeff80c8, 0x3e17bee7,
+    0x43d378c2, 0x55d4312c, 0x93bdd71c, 0x35e75c7f, 0x111b5e6c, 0x33ebc738, 0x52e22116, 0x7984691b,
+    0xbb1603f5, 0x7763cc9c, 0x58871a8e, 0xac7aa19c, 0xf4619c1e, 0x44a7536e, 0x57bdef27, 0xfe44f7ef,
+    0x87a4a6e9, 0xe9cd4e02, 0x959a5172, 0xb111a3c4, 0xa726516c, 0x812ae008, 0x6dbb033f, 0x4fe7a0ca,
+    0x59d62a5c, 0x3a698da2, 0x526e496c, 0xe56067c2, 0x52631ec7, 0xfaa935ab, 0x2e229080, 0x208c1ea6,
+    0xa5ece4e5, 0x33105a0e, 0x97da477c, 0x2a06a4b8, 0xdff4c8a7, 0x0f5b295c, 0xb0e4ab50, 0x20a67730,
+    0xa00d22ce, 0xb22bd6e3, 0x962112b2, 0xf05ee78f, 0x00b49810, 0xa70f7784, 0x30aa43a8, 0xb85704e7,
+    0x663d042e, 0xe1eeac47, 0x75868f3f, 0xbdd4a1a4, 0x4dc640a9, 0x31d407a8, 0x296bf987, 0x9eb939ff,
+    0xb7207aa3, 0x6a7a777e, 0x1c0dd941, 0xa69216d5, 0x9d730b55, 0x3c923988, 0x58145506, 0x420cb653,
+    0x8668c7e2, 0x1a5fbc44, 0xe345df80, 0xcd90ca28, 0x2b74f1fa, 0x6c6af515, 0x00be3c3f, 0x651595e0,
+    0x4500c7ff, 0x908e7135, 0xd9a8acf8, 0x5427f6ba, 0x8394ac2e, 0xc3624600, 0xf431a321, 0xb367d74f,
+    0x0bb3dd89, 0x3d04514d, 0x46d2fa49, 0x0084a1a3, 0x484297c3, 0xf593f372, 0x04d7a429, 0x7bc6a3a0,
+    0xf7994db0, 0x62bb29b7, 0x587e3bc9, 0xd2507445, 0x52c72564, 0x4ca289b1, 0x556cd339, 0x9a231289,
+    0x810e7f6b, 0x102cc8e2, 0xdf8ce6f7, 0xced30035, 0x1fb71c70, 0x6abf30b8, 0x1badc5e9, 0xf46a1aea,
+    0xe9ee6328, 0x5d0eeea8, 0xe29e1dd8, 0xd81a3ab0, 0xe7890f68, 0x30fd8d47, 0x4303777c, 0x175d26fb,
+    0x60dbeca3, 0x8ffdfba3, 0x465ebf14, 0x0d38e2c4, 0x22f9e14f, 0xc9c422cd, 0x71f3977d, 0xa0794ad6,
+    0x25292557, 0x679eee38, 0x542ee048, 0xfd3e6c5c, 0xdff46676, 0xa35c7c01, 0xa2453484, 0x35beb6a6,
+    0x5f8585c7, 0xca87c56d, 0xd425eb34, 0xcb624932, 0x5b8ae5f2, 0xe5b5d2d0, 0x7de5dbd6, 0xd65e6b18,
+    0x441f2175, 0x187996ab, 0x3063b3e2, 0x62e18354, 0xb87661b7, 0x3f63dc96, 0x838f50f1, 0xb4274ebd,
+    0xdd2d5920, 0x28d01467, 0x4bff35a4, 0xaf110191, 0xd46ac6ff, 0x41317aab, 0x5bde85a7, 0x110c6987,
+    0x0317cb5e, 0x4224faa2, 0x5b2107f8, 0x28be79b9, 0xdeb005b4, 0x4b190b91, 0xe2c441e5, 0x528e5d52,
+    0xda1742bc, 0xbfa50445, 0x946d4944, 0xf2d83406, 0x1f296989, 0x605e10d8, 0xe2ddd2b5, 0x16b5a4a5,
+    0xcd57e435, 0x09191237, 0xd46312b9, 0x13d26039, 0x01454414, 0xb2c82c85, 0x1985ebbf, 0x3bbde2de,
+    0xdfe27493, 0xd1779047, 0x695a58b8, 0x75d20a0b, 0xc8538b63, 0x513de03b, 0x62d2ccdb, 0x1febd2d9,
+    0x089834d6, 0x49157e08, 0x04b64733, 0x985d0959, 0xd291a6fb, 0x7d7150e6, 0xe102e169, 0x91057f7c,
+    0xc2049ef8, 0x1d3247e2, 0x9a05424e, 0xa4173172, 0xa736d61a, 0x8837921e, 0x70d35c97, 0x14a2c03f,
+    0xafda3c22, 0x4fd61097, 0xd228078b, 0x32cd5905, 0xdf7f2c5f, 0x2d00b3c1, 0x99cbe67f, 0x08493374,
+    0xea35014e, 0xec5ede5b, 0xd67253c4, 0x12ad02c6, 0x9dacd825, 0x3d576228, 0x36632229, 0xf37a1f90,
+    0xc922073d, 0x148e744b, 0x6e13ac7e, 0xb5fea884, 0x7eb2c847, 0xcc577784, 0x407d10d9, 0x09a66a2a,
+    0xdbc24efd, 0xd0ae5ebf, 0xbda9845b, 0x43481aba, 0x4ecd806c, 0xd9ff3f67, 0x95813cc1, 0xfdaed7aa,
+    0x2d548509, 0xaa5fbc4d, 0xe29ed9bc, 0x597acf9d, 0x4c803744, 0xca0d1336, 0x2cb284df, 0x1e38d927,
+    0xa1d97423, 0xe285dec5, 0xcb14fe1f, 0x27fbcc2c, 0xd294a446, 0x84f43e08, 0xb28b8c4b, 0x946a5ea4,
+    0x547a171b, 0x228778af, 0x7534b139, 0x5eb91f98, 0x8347fe4e, 0xeecf8d41, 0xdaaff184, 0x29597190,
+    0xe5128e12, 0x2d92687f, 0x0dfc17bf, 0x4d48cf60, 0x8d55f965, 0x4350bb22, 0x94ddd148, 0xb2d721a7,
+    0x071724ff, 0x6a80a339, 0x7c9bdcb7, 0x2a24837c, 0x4880d082, 0xc8918c79, 0xbfeca6f3, 0x0aa3c7f9,
+    0xa3375a3f, 0x953f22d3, 0x4b4a8bb2, 0x8b0dfaef, 0x3a3b588f, 0x3e030f87, 0xb0a81946, 0xf0468c34,
+    0x4c37ee92, 0xb3d58eca, 0x76b3626c, 0x4a407871, 0xe520bae2, 0x28e633ca, 0xf129fd3f, 0x78737a08,
+    0x3aa5c300, 0x3ea693f7, 0xa256a0e2, 0xfa8efe19, 0x03aec283, 0x4c4f60b8, 0x2d776924, 0x19dc8815,
+    0x095272aa, 0x08a29176, 0x50f529c3, 0x02eac67d, 0x44cb7cf3, 0x5bd9c06d, 0x9c99250a, 0x3b776cff,
+    0x867ea946, 0xade9aef3, 0xa4f59aff, 0x0827b357, 0xa5e217d1, 0xd0c0bfaf, 0x185bcf83, 0x2f771457,
+    0xdd387775, 0x537f6f5d, 0xefd05abe, 0xcaeece6f, 0x9087491f, 0x7eb5246b, 0x95bf3419, 0x608ee4e2,
+    0x716758f0, 0x0aee9035, 0xfadd9d8f, 0x25303249, 0xa41d7970, 0x3b33b8d1, 0xf9fff1ec, 0x2917ec61,
+    0x4210b426, 0xeed683c3, 0x4e2a3187, 0xb14b7d7e, 0x189b4e7f, 0x9d1db707, 0xa4887c66, 0xf9d8bf51,
+    0x0ca03d6f, 0xb3199224, 0xbb12153b, 0x771f4e99, 0x67348267, 0xde1243d1, 0xdb433fca, 0xff919b04,
+    0x5ce44de9, 0x6f5110d6, 0x2d7263b3, 0x5ade5c65, 0x6d84a212, 0x3d904229, 0xe2acf901, 0x3e377881,
+    0xf8511a6e, 0xbc1cbed0, 0x0acfbf04, 0x0ff56edb, 0x5d477427, 0x9266d1f6, 0x78bc256c, 0x623f2352,
+    0x8ef8cbeb, 0x733f325e, 0x521d9bc4, 0x379954b9, 0x4649e4f0, 0xfd745314, 0xd39da857, 0xf4ed4fda,
+    0xcff80541, 0x9ab39c03, 0xb5a040ed, 0xaa558ae9, 0xe8f8571d, 0x4a1dd786, 0x4b6b7271, 0xfcf0b9f1,
+    0x27fc6e4e, 0x75e76bcc, 0xf7248353, 0x248ca841, 0x095ec592, 0x399e9e1f, 0x27633d50, 0xc8e9157a,
+    0x384befc0, 0x43a49832, 0xd6a0937a, 0x0e78c6d1, 0xe1023c5f, 0x205b999e, 0x5873eb9c, 0x8df2f122,
+    0x9347e1c0, 0xbacb73c3, 0x68f2c705, 0x1b2799be, 0xd049ec52, 0xadb33e75, 0x75d0b83e, 0x4539c886,
+    0x59af5de2, 0xc0ffc845, 0xcf1884f3, 0x8c77792f, 0x5d967ce2, 0xa0d2d2a1, 0x3b21db01, 0xe7b9caef,
+    0xbd6e3821, 0xf4df3c43, 0x425bd02e, 0x14429955, 0x0f402745, 0xe1dabea4, 0x0624cd58, 0x583c0f1c,
+    0xd2002a12, 0xdee05e01, 0xecb1c7cb, 0x2d7c2abb, 0x9a6cd9ce, 0xb8b6100e, 0x65110a43, 0x3729e811,
+    0x76c8fde7, 0xe56b6d3a, 0x4498f335, 0x87512221, 0x8b1a9034, 0xaf79d18b, 0x8232512b, 0x6761a137,
+    0x04b95722, 0x2e821d77, 0x771dfcad, 0x6429541d, 0x7e81a1e9, 0xd6c5dc2f, 0x736961e3, 0xfbf98723,
+    0x8ddb07f8, 0x1417daa7, 0x07530f1a, 0xa92430af, 0x1156f8f6, 0x498b1b86, 0xe223d6c7, 0xaf406d50,
+    0x7c644f3d, 0x6352823b, 0x6d81cd98, 0x954212ae, 0x140bdcf8, 0x118ca742, 0x149d1668, 0x82f539a9,
+    0x2c42e714, 0x42d89593, 0x1c9b8f38, 0xf57d9fe7, 0x940e22af, 0xed8aa87b, 0x038642fc, 0xd4b4acc5,
+    0xeff6f609, 0x6f0740f5, 0x1485af81, 0xe33f07b9, 0xa9f85d4f, 0x4ab5ec88, 0x7c19e605, 0x13b39927,
+    0x123e4cc5, 0x3d0cfebf, 0x654a461b, 0xdbeee5f2, 0x159169e2, 0x16eaf905, 0x62646dbd, 0x5943c214,
+    0x63e7a8e3, 0x0e62091b, 0x22b6a900, 0x6eb06eeb, 0x736a1497, 0x9c089da8, 0xe1a3c941, 0x19e3d0ff,
+    0x0918e31f, 0xb3c7c97b, 0xea752ae9, 0x913a2715, 0xd3aac025, 0x8572bb1d, 0x8b734194, 0x931947df,
+    0xce8b5116, 0xa8e4e682, 0xa706ddea, 0x9ba43836, 0x81f012aa, 0x89acf5b4, 0x001965b2, 0xb15cf9e0,
+    0xc579ef4d, 0x67234c02, 0x52403bc9, 0x43cf9618, 0xc4a875d5, 0x57ba5d3e, 0xf8a50ec1, 0xcb245af6,
+    0x8cc37bf0, 0x0c140461, 0x6c5cacb9, 0x68da6cdc, 0xa1f962fd, 0x72bd53d5, 0x6004bbd5, 0x73650536,
+    0x13c81dd0, 0xb6f9f598, 0x0d767f9d, 0xd040f638, 0xd8da2d1f, 0x67e91966, 0xaa9f0232, 0x7322dafe,
+    0x78c1b0d6, 0xd61b3fe8, 0x285194c5, 0x68f54591, 0x8e7dfbd2, 0x284c668d, 0x8fd5b1ea, 0xc848185e,
+    0x5b8ec016, 0x62efa07b, 0xcd60e368, 0x1be2200f, 0xd1f77a59, 0x049e166d, 0x1ca0e6dc, 0x525d6458,
+    0x4a731166, 0xa8184062, 0x9d8a4bb8, 0x0e745ea5, 0xe395139d, 0xbd083d00, 0xd673a460, 0x867d84be,
+    0xf3836515, 0xdffb585c, 0xd5a25c71, 0x8ed43baa, 0x599637ac, 0x87a99570, 0xb1a3910c, 0x63d78c4e,
+    0x9efdf847, 0xbaefb22d, 0x56f4202f, 0xb203179d, 0x7d4f173a, 0x3ad27cb2, 0x30cda956, 0x3dd37835,
+    0x616b831f, 0x95752bc3, 0x272b8e65, 0x914b6575, 0xbee06ba4, 0xdff18460, 0x1704029f, 0x71cfea97,
+    0x627d646d, 0xcbfb871b, 0xb926d140, 0xa81a4920, 0xb8bad1fb, 0x6c049199, 0x1247b59d, 0x6cc3789c,
+    0xe2aa1433, 0x59b1a552, 0x963243d6, 0x72069530, 0x9f137e2d, 0x798e68a4, 0xb4730883, 0x51f6be6f,
+    0x02b68a05, 0x06e9e309, 0x2f2ca84c, 0x48eef495, 0x0155e404, 0x5021aa4d, 0xd6dbd370, 0xcf7c64ee,
+]);
+pub const VENDOR_MLDSA_KEY_3_PRIVATE: ImageMldsaPrivKey = ImageMldsaPrivKey([
+    0x676a688f, 0xa1166d71, 0xb38a3640, 0x468f074c, 0x5e489d06, 0x0b001476, 0xa1797da9, 0xcfcccf9c,
+    0x6187d38f, 0x7704299d, 0x9fd4b9b7, 0xe7124806, 0x059666dc, 0x92759448, 0xe6704c2d, 0x3f556659,
+    0x643518bf, 0xdc9cd944, 0x4c95ebe7, 0xafa203d0, 0x7f2ad561, 0x27b9a1f6, 0xf1c765f4, 0x8e92b593,
+    0x6a0bb395, 0x855c3da2, 0xe5d13091, 0xa6d25e81, 0x95bb2c35, 0x4ef7f3d7, 0x6a211bac, 0xe2e0432f,
+    0x998d404a, 0x210860a4, 0x61408108, 0x408988db, 0xb4202836, 0x0084e048, 0xe36822a0, 0xb4e18894,
+    0x1102c368, 0x132e2223, 0xa81444c1, 0x64b4506c, 0x000e2222, 0xc49a08b5, 0x04804881, 0x49258708,
+    0xc0cc04b0, 0x28c09a68, 0x18208800, 0x451a88b2, 0x92071346, 0x8828c442, 0x82193242, 0x60425b88,
+    0x09891608, 0x284c4610, 0x4426906e, 0x22520814, 0x82014535, 0x68b0c108, 0x1c08080b, 0x16234cc2,
+    0x05064a4d, 0xd98c484c, 0x10c28908, 0x4104d46e, 0xe2493521, 0x12634d36, 0x22206086, 0x4c1216e3,
+    0xc0900488, 0x22010361, 0x03504708, 0x36116d89, 0x6440a420, 0xc2283822, 0x35002dc8, 0x4126e280,
+    0x04803259, 0x40196017, 0x81a32192, 0x1a44a882, 0x45086810, 0x8c105c45, 0xa460b084, 0xa8c98990,
+    0x21885328, 0xc0023111, 0xc8842a24, 0x81910c65, 0x53000901, 0x972051c6, 0x29a4a431, 0x190c1863,
+    0xc6428821, 0x41a2094c, 0x412208c8, 0xb8d22828, 0x25101471, 0x8a218240, 0x911a31c4, 0x08a60811,
+    0x842e190a, 0x352301c4, 0x05271181, 0xe4291482, 0xa29b0a20, 0x09241b28, 0x5a4892a4, 0x442265c6,
+    0x04351230, 0x19652854, 0x30120880, 0x6632a121, 0x0c0814a4, 0x84233008, 0x6c102109, 0x128a2254,
+    0x41012096, 0x30241b4a, 0x8451c4a1, 0xb4940942, 0x4d885a2c, 0xc8853523, 0xb2088222, 0x62492480,
+    0x992204a2, 0x46d22d36, 0x4d969865, 0x192524da, 0x84032503, 0x89080844, 0xe3094723, 0x009a0012,
+    0x05929226, 0x0c4a0853, 0x48008031, 0x30a90b64, 0x89491104, 0x401c5030, 0x6dc06111, 0x2089b719,
+    0x800b2e36, 0x21345061, 0xdc44304c, 0x86932094, 0x8d990930, 0x219102d3, 0x985b9092, 0x0d360320,
+    0x1149a903, 0x229a0c21, 0x48329c48, 0x5a44085b, 0x20da2a46, 0x4c941012, 0xc151144a, 0x20d37026,
+    0x28801308, 0xa422328a, 0x164049a2, 0x45060470, 0x8400c81b, 0xa12325a8, 0x62404120, 0x22681854,
+    0x240a8905, 0x6a184872, 0x1404380a, 0x06993046, 0x84c8e24e, 0x53711690, 0xc4000118, 0x64071145,
+    0xd04da052, 0xc2a22c84, 0x0446a040, 0x83013122, 0x10db9042, 0x66465205, 0x63692483, 0x92a42802,
+    0x8ca4922d, 0x5c6088a4, 0x369409c0, 0x4c268480, 0x24418100, 0x20610197, 0x8a30a269, 0x02480724,
+    0x12d40035, 0x85941b04, 0x0a4e2894, 0xc44a28a6, 0x30042345, 0x1c6198ca, 0x32910903, 0x51104241,
+    0x636e0848, 0x28e389c4, 0x20204c0c, 0x11024814, 0x96622003, 0x0004d465, 0x63882410, 0x10420008,
+    0x6596a131, 0x8a608081, 0x231101b0, 0x8806d384, 0x19123288, 0xb41a7209, 0x20004a88, 0x13494911,
+    0x28d94035, 0x8a10dc29, 0x981040c1, 0x25042998, 0x20424269, 0x99491203, 0x40c40038, 0x29871c60,
+    0xca322010, 0x09048a28, 0x6932c225, 0x43722421, 0x88a20180, 0x00805331, 0xd9219624, 0x821209b0,
+    0x6824628d, 0x036d00d3, 0x00d12513, 0x8c431c8a, 0xca21045c, 0xc4cb8a06, 0x4d110185, 0x4c044918,
+    0x012440c0, 0x12429410, 0x53523519, 0x18592428, 0x4c228466, 0x1900c04a, 0x381829a7, 0x6db2134c,
+    0xc4693804, 0x80848606, 0x91829010, 0x616444cb, 0x05180d96, 0x4dc71402, 0xe1651843, 0x48007186,
+    0x61a2e472, 0x4a8d9900, 0x02db4610, 0x68449b4c, 0x9c0c46db, 0x26507008, 0x80b21b68, 0x5c29a322,
+    0x06d85018, 0x0c985441, 0x546114c9, 0x400a4c92, 0x2942938d, 0x04863241, 0xa7186443, 0x88949c65,
+    0x1900c01a, 0x271c6500, 0x85384c4c, 0x0b100423, 0x06896214, 0x7080c448, 0x1b0e3089, 0x87010a43,
+    0x2a26518d, 0xa445a221, 0x469c4436, 0x5086c129, 0x936a28d9, 0xb8186000, 0x8024a225, 0x14660204,
+    0xc1146c85, 0x26042421, 0x20241919, 0x465a2e28, 0x82269471, 0xdc0c364a, 0x12486d02, 0x72064300,
+    0x4331a258, 0xa2992d44, 0x60a6418c, 0x910210d2, 0xa3020d46, 0x3236994c, 0xd0524889, 0xa04b9224,
+    0x90945204, 0x5a312709, 0x22ca0136, 0x01292190, 0x2365c2a3, 0x070c04a0, 0x84c8d482, 0x042db8ca,
+    0x46d17233, 0x9240d98a, 0x2308b051, 0xc8d371b8, 0x25028260, 0x8c084844, 0x904a1020, 0x0448198c,
+    0xd14c0280, 0x172301c4, 0x20245488, 0x6028089c, 0x285c4486, 0x09970160, 0x118a391b, 0xa2223146,
+    0x7244d181, 0xc981409c, 0x27094546, 0x64261c71, 0x1900025b, 0xc4922543, 0x28b66064, 0x1c71329a,
+    0xb4516cc7, 0x10031b91, 0xc9502881, 0x03028a28, 0x41b0448e, 0xa245c299, 0x29137180, 0x51c10384,
+    0x0268b909, 0x40906992, 0x11c8a140, 0x9c0434c2, 0x160c84a2, 0x4db3046a, 0x608d3920, 0x084b8528,
+    0x44c29c6a, 0x64892804, 0x24418a22, 0x4e489932, 0x128604e3, 0x04828507, 0x61a0c826, 0x016da048,
+    0x34c82490, 0x6c22092d, 0x24011460, 0xa00c4804, 0x0484cc11, 0x24823040, 0x111830c8, 0x41c09988,
+    0x014da64c, 0x43004815, 0x9024600e, 0x924e24d8, 0x48435120, 0x8006926d, 0x1a608482, 0xa0227229,
+    0x91470a61, 0x4a503240, 0xa8117234, 0x0240d88d, 0xd86538d2, 0xa0c32922, 0x89940171, 0x04408100,
+    0x96910484, 0x4a16894c, 0x9920b0d1, 0x28214998, 0x0000610e, 0xd041c908, 0xa02404b6, 0x45c30930,
+    0xd700c3b4, 0x0f1da2b9, 0xb4cb090c, 0x232bc1a5, 0xb0b07b52, 0x928de7bd, 0x9649cbc9, 0x0a10a127,
+    0xb46e3b52, 0x3a4f1e99, 0xe62bdbaf, 0x6d5883d6, 0xe0cba7b2, 0x2a71899c, 0xe902ca39, 0x13ad15e2,
+    0x81a3709d, 0x1c81835a, 0x4fc526d2, 0xd5d1ff81, 0x969a93fc, 0xf7ccf9df, 0x3cfabe5d, 0x80bae580,
+    0x2c75c828, 0x57c2e627, 0x939519c2, 0x300dd8f9, 0x65c6dbd8, 0xf8a340a9, 0xaa3f1dad, 0x9a17849b,
+    0xca8ae999, 0xfba06904, 0x76758853, 0xdb737e09, 0xf6b94a46, 0xf4a24baa, 0xb66fc7f4, 0x29a5c6a9,
+    0x6c60959b, 0x5cd5c9ee, 0x0049743b, 0xf60202d9, 0x501f495e, 0x25737e91, 0x558a0fed, 0xc11f6357,
+    0x3c9a742c, 0x8bc141be, 0x616bfebc, 0x8d5eb19f, 0xd41e123a, 0x49338033, 0x8d63dce5, 0x72ab3d00,
+    0x417fa631, 0x25237084, 0xa91147f0, 0x8031880b, 0xa8cae675, 0x8c73249a, 0x367b3e0f, 0xacc60199,
+    0x90434dfe, 0x9d20c27a, 0x3ccff522, 0xdf3d16d7, 0x444426dd, 0x1624c9e8, 0xe2e4c69a, 0x10c6b49d,
+    0x6d3f0611, 0x9ea94c64, 0x50e095e0, 0xe5d87372, 0xbc231666, 0x0b5872de, 0x88d92ed1, 0x50b9f144,
+    0xdceef647, 0x43195414, 0x954e8f61, 0x0125174f, 0x5c0fe6f9, 0x19e29658, 0x4f20a99f, 0x572e8783,
+    0x7c865b46, 0x072ee841, 0x9cd8e69e, 0xc4b4940a, 0x5b07b28a, 0x862f1788, 0xd2cbebc4, 0x0cb412d6,
+    0xd19b8ee4, 0x35d314d5, 0xc3c0dace, 0xba0a444f, 0xb21c9dfb, 0xc3ddab93, 0x23c3a9ba, 0x3e3e63de,
+    0x2a84a961, 0xc2473f0c, 0x83a63695, 0x9ee55ad3, 0xa8bd4af6, 0x0ff5cb41, 0x79c49c34, 0xef822224,
+    0x4484657f, 0xff69cc11, 0xcd45db58, 0xa3eb389f, 0x004987dc, 0xf37a0c52, 0xe2b6ed34, 0xefd2e1f4,
+    0x9391f157, 0xabdc7da5, 0x52458674, 0x28e4d76a, 0x8af97599, 0xfe0cedfb, 0x9300e580, 0x99173d44,
+    0xe4264f98, 0xe78c6aaa, 0x35bc75db, 0x45d7a79b, 0x86b5fc6f, 0x21c4fa0a, 0x4acf11f7, 0x3997b059,
+    0xe08a2c6e, 0x55b98ce0, 0xa0f09c4a, 0x44c64741, 0xb4c011c3, 0x5bfbc959, 0x318517b0, 0x0a1c80c0,
+    0xe863a92e, 0xd3de059c, 0x3095f5a4, 0x1d4e59cc, 0xc45a2dbe, 0xb984ebb5, 0x381eeace, 0xfc53a007,
+    0xa03e94ef, 0x2f557ce3, 0x12453907, 0x1c180a7a, 0xc27c851f, 0xf21be57c, 0x670b4c90, 0xf1db58d0,
+    0x18a12e95, 0xe079f3ab, 0xa0d31817, 0x4a9f0410, 0x5b47323f, 0xd20ffb63, 0x211bf1c6, 0xa8329604,
+    0x4f5c5899, 0x2c40d1d1, 0x9f9c1a04, 0xa624af93, 0x5605fb99, 0x1d78f198, 0x006dee53, 0x7fbfbcd7,
+    0x836a3e82, 0x0ecb821c, 0x6a5c3dba, 0x5976d8ce, 0x236b0abf, 0x6cd34ebc, 0xd29172ad, 0x788bcd1b,
+    0x3b997819, 0x00f4dc6c, 0xc4b176b5, 0x063a119d, 0xc90a71f5, 0x525223e5, 0xf4b131d7, 0x0fa534c1,
+    0x6400c993, 0xb8da5f27, 0xcbf64990, 0xa5edde66, 0x40856f5e, 0x60895a14, 0x44d23f9a, 0x0e9d0e06,
+    0xf8ea86bc, 0xecdfce90, 0xdc3d4123, 0xbdbfb515, 0xdcca12b2, 0x14292975, 0xbcfa2cdf, 0xa90c9a00,
+    0xf859c616, 0xd9935a8d, 0xd09d14bb, 0xbeb27e33, 0x004a1cf0, 0x57a3be02, 0xcf569f9b, 0x0611b79d,
+    0x094ec4ee, 0xb885fe9b, 0x7c191341, 0xb9d76ae5, 0xad60058f, 0x286669fe, 0xa26bb7b0, 0xc56c54f9,
+    0x69620483, 0xa99ee9f2, 0xe9088159, 0x8de6a88c, 0x3e241fb8, 0xd5f80dd9, 0x5dd2a4d5, 0x0c5f3e7e,
+    0x0ca231b6, 0xb408e1ce, 0x6589b565, 0x11f3de12, 0x79ac8ca3, 0x92720459, 0x4e59e2e6, 0x372ba248,
+    0xae400dfd, 0x7b0e20a0, 0x457ecdf5, 0x212015c9, 0x5c85bfb9, 0xd7b860a1, 0x19e38b57, 0xbf175f34,
+    0x6f1098e0, 0x72df8260, 0xa640f0b5, 0x0e097e75, 0x51b31dea, 0x7f8856c6, 0xe43a0eb6, 0xaeb70859,
+    0xf6cc4600, 0x23077c6e, 0x5174a70b, 0x7fd2d9dc, 0x7e412001, 0x6944ff05, 0xae64b92f, 0xc9155344,
+    0x354eed94, 0x6fcb1182, 0xb5da370d, 0x845dba5e, 0x4f7d3ae3, 0x5656c840, 0x05439d63, 0xd52e2bc1,
+    0xf48f6461, 0xd408dabd, 0x894388cf, 0xb243eccc, 0xcfe294f9, 0x43afa76c, 0xfd7c8cf8, 0x9b10a8f9,
+    0x16ff0e7b, 0x1a12121d, 0x0c13cc55, 0xb0eb930a, 0xfeefeecb, 0x6cae2af8, 0x340e9f17, 0xc6dbf314,
+    0x950ac7e9, 0xc1dbee6c, 0x7f2b6f56, 0xc2464155, 0xf1617463, 0x5b737d2f, 0x5ce3e8d1, 0x788fe222,
+    0x1f72101f, 0xb4380243, 0x8ff9ac82, 0x4f3f0557, 0xeca2e977, 0xcd11f293, 0x4f1ee09d, 0xd432da07,
+    0xb631cc98, 0x1775b794, 0x6de62692, 0xea5c574c, 0xed4b86ba, 0x5eff6c76, 0x4d2d0dce, 0x66c03927,
+    0xa75a8507, 0x4eb747a5, 0x1b15e3b0, 0x47975aae, 0x7ee38689, 0xb19d049a, 0x78b3466f, 0x2c031896,
+    0xeb9d95ed, 0x1da0c255, 0xf9c4c93d, 0x4af3abf4, 0xcbc49565, 0x092aad12, 0x581c7b4c, 0x8cf691d6,
+    0x25b86012, 0x361e3297, 0x33a2cd62, 0x6414edeb, 0xbfe824d2, 0xed43cf31, 0xc737ed3d, 0x5f27fe16,
+    0x1f57d169, 0xfb56a483, 0xa364127e, 0xf562b93d, 0x40ef481e, 0x82fb1cd4, 0x519c5cfb, 0x50cba55e,
+    0x9aee4c5c, 0xc4a9c964, 0x94353cfb, 0x6f72792b, 0x459a08b6, 0x16867fec, 0xfc22d7ff, 0x9c5fac18,
+    0xe26a002e, 0xfa90097a, 0x5c5d2ea2, 0xadfc2c96, 0x191a8fc4, 0x921918d1, 0x0aba7404, 0xdc524b13,
+    0xa9454b90, 0x7add3749, 0xdeed786c, 0x8891a4ae, 0x599f4899, 0x52657236, 0x4c457a2b, 0x020a4641,
+    0xc2751818, 0x9f9eadf1, 0x666fc252, 0x62a64fc4, 0xf473c889, 0xabe4ea39, 0x420d4708, 0xc96e0db5,
+    0xbde9e54a, 0xd3186bc3, 0x37a1677e, 0x95fb72d2, 0x4ab274f1, 0x763ad9ed, 0xba5f9703, 0x5fa5c7c8,
+    0x0c98f884, 0xd504df2a, 0xf9fce8a8, 0xc007dfea, 0xef085a57, 0x136b9e43, 0xc6385663, 0xffa85a92,
+    0x720a215d, 0xaa8afe50, 0xb38e3cee, 0x6b3dd54a, 0x9f317263, 0xaae55d65, 0x33dced5b, 0xeeeb8732,
+    0x6ffeeefe, 0xb3fc438d, 0xa7a5a304, 0x9b8f011d, 0xe5393c4b, 0x30aa91c5, 0x1263c8aa, 0xa87faebb,
+    0x5bb36ca5, 0xd19f9e2d, 0x0cc2fd3c, 0x5c0875cf, 0x4863bef4, 0x26603318, 0xc5aeabd5, 0x13ef839d,
+    0x5e344655, 0xfdf16b3c, 0x7428a3cd, 0x5143238b, 0xb339d547, 0xfcbe2b41, 0xb2e53f71, 0xe2390d3b,
+    0x15e2cb07, 0x50facd7e, 0x16ff6f26, 0x0400862e, 0xe16097bd, 0x62b8a990, 0x1f252dff, 0x0cb9b75f,
+    0xeddf5166, 0x07ad1277, 0xb5f4389a, 0x5e5493b2, 0xa8560832, 0x73ff7b36, 0x1260c1d4, 0x9e518330,
+    0x3b22aefe, 0x88ae0a71, 0x29cd6d8c, 0x5ebff8c2, 0x5d2fed06, 0xb233992a, 0x907fae43, 0xad3bfe37,
+    0xeeea456e, 0xd3ec272d, 0x5aef67cd, 0xac58a092, 0x2c633e25, 0x98da5c53, 0xce794812, 0x661fa417,
+    0x914de8c6, 0xee20ef8b, 0x20b311bb, 0xfb570a15, 0xff742e90, 0x6de34941, 0x94550f8a, 0x5643646b,
+    0x543749e2, 0xb702176d, 0x1bd656f8, 0x66b16e25, 0x71f9762d, 0x2099338a, 0x1d2b7306, 0x67ab7157,
+    0x16f9162a, 0x0de9b387, 0x7a9ca8a7, 0x7b5ab7da, 0x0d42b7a6, 0x6504ca3a, 0xb016aa31, 0xf1844a60,
+    0xac8425d3, 0xfd6f053c, 0xc6979a50, 0x9d603ccd, 0xdf1bbe03, 0xd5d922f5, 0x0522c80c, 0x26b5d800,
+    0xee26e455, 0x63f0edec, 0xee818f94, 0x173e6b72, 0x04b6a826, 0xac350ad5, 0xfed9e6d7, 0x3ea3b65c,
+    0xdfeaafc6, 0xd21bc795, 0x6ddda99b, 0xc8872213, 0x07cf229c, 0xb6bdd014, 0x215dd0b5, 0xec86b47a,
+    0xbdc43e7b, 0xc083821a, 0x745ae197, 0xec7ded3c, 0x5a7eecd7, 0x13f24134, 0x6c70dbbf, 0x9fe0ec4a,
+    0xdc08b47c, 0x32c067cf, 0xfed17ddb, 0x0be81c6b, 0xc461d75a, 0xa98f6a32, 0x215b0601, 0xb6ebab2f,
+    0xed9c39d8, 0x85867e8b, 0x8875013d, 0x8997198e, 0x61efba6b, 0xb02c38b4, 0x2dd1adb7, 0x25af846c,
+    0xe5940fdf, 0xbb457d45, 0x8e9275d0, 0x9507c101, 0x8f0e81cd, 0x89df5a7d, 0x3308cc6d, 0x10efe54d,
+    0xc1721c55, 0xf1221a44, 0x8f46e8c8, 0xc4e34cae, 0xe252bb7b, 0xeb1fcb23, 0xd13c9c3c, 0x4823834e,
+    0x993cdc9a, 0x647f583c, 0x721ab3df, 0x0511b855, 0xce8267fe, 0x5e670e6e, 0xdd8db035, 0x3557e70b,
+    0x2fff20b9, 0x7409f102, 0x7418acbf, 0xb6cc2fc0, 0x785ebf71, 0x26acf5c3, 0x6894bdd8, 0x5fa9b6ba,
+    0x49896d0e, 0x2c5dda8e, 0x9953dde7, 0x3c2917bf, 0x5369a517, 0xe9838e72, 0xcdac0817, 0x50dee1bb,
+    0x26e6fc36, 0x464ad8bf, 0x64f97063, 0x09d05c8a, 0x11130450, 0x45804716, 0xce6bfa70, 0x48df209a,
+    0x9272440a, 0xfab006a7, 0xcf08b90f, 0xe8eb4139, 0x4a71c790, 0x68ef7036, 0x6b2c6355, 0x25c6209c,
+    0xa395fcca, 0x125976a4, 0x8b763988, 0x2e28c513, 0xfb892cf8, 0x92f27365, 0x465ec8b5, 0x188ad8da,
+    0xb94b5cba, 0x6d16e45c, 0x4305c7ec, 0x50ee46b1, 0xa9146f6f, 0xf0ba4dd9, 0xe9fc6e50, 0x58915c17,
+    0x52216de0, 0xdb225c14, 0x0a5ec3b3, 0x47a56096, 0xa6c69ad6, 0x868b1023, 0xdb21aa29, 0xc07241aa,
+    0xecf0150d, 0x1736ed96, 0x8aca4188, 0x2727e3be, 0xe167cee7, 0x89013425, 0xca5df40a, 0xb42ef619,
+    0x98fdec53, 0x8172bdcb, 0x4f6eb432, 0x03d5c8dc, 0x7b5cc717, 0x220622de, 0xbd8b0453, 0xed0d9d24,
+    0x0d5cd94e, 0xad4dcf55, 0x7f2b4df8, 0xcc39d856, 0x2d965d60, 0x41dd6d0b, 0x5027dbd2, 0xa404517a,
+    0xc23e9aff, 0x5c888176, 0xad02470f, 0x81515842, 0x34edb0f2, 0x41e1b82b, 0x2432227a, 0x23b092bc,
+    0xb175b9d6, 0x73356272, 0xa691390e, 0xb14f2728, 0x2dae3e9f, 0x01403827, 0x940964ea, 0xf56af016,
+    0x0637ba85, 0x471e6c1d, 0x339b6265, 0xde570477, 0x967f5cb1, 0xfda51615, 0x0e3e8840, 0x84b8b1b6,
+    0xd15c30c4, 0x148c0d07, 0xe69f0f23, 0xf1b9e6fe, 0xe73077ae, 0x2acefc92, 0x6177cd7f, 0x23c42688,
+    0xff494fff, 0x6dfbe66a, 0x63ebbe6b, 0x8d64eb70, 0x18247837, 0xd50f9e91, 0x748389a1, 0x60f49e85,
+    0x28499a7a, 0x79e79d14, 0x1ecd622b, 0xf6501016, 0x78b6f529, 0x967e77df, 0xf0bff985, 0x94ee7c86,
+    0x99e44862, 0x05e133bb, 0x45a0fb37, 0x5996a799, 0x564f2899, 0x33c1bc31, 0xcf544113, 0x056cb486,
+    0x35c6acd9, 0x19702f72, 0xef8e862d, 0xa57497df, 0x14b95bcb, 0xe3a2377f, 0x0bb1a811, 0xc0a7c4a5,
+    0xeb18cee3, 0x72753f31, 0x367ad9f3, 0xc7224eaa, 0xaa90345a, 0x908954fd, 0x148f09f5, 0x65bfc603,
+    0xcb46e639, 0xe685368d, 0x47ac47d9, 0xa6eedfc2, 0xccac7e8a, 0x09a398cb, 0x8098f500, 0x7cf34652,
+    0xfab12670, 0x1223ab6d, 0xfb0dce82, 0x573cab0e, 0xbcad4835, 0x221aebfb, 0x89d382ad, 0xb4ec600c,
+    0xeea93998, 0x4d977834, 0x09eab5bb, 0xeeee72a0, 0x4ec4d88f, 0xc8902ac2, 0x7e738178, 0x9ebed277,
+    0x685ce8d2, 0xc24e7aa7, 0x47cafb4c, 0xa178c554, 0xc5c1331e, 0x21ee508d, 0x48d61b51, 0x538b1260,
+    0x9f4e6d91, 0x002780c8, 0x07f9e513, 0x2483ec1d, 0xeeeda60c, 0x2941dd1b, 0x0d055e0d, 0x0dbb73a9,
+    0xfddd86e9, 0x57c2eebe, 0xe1ad5368, 0x2d1d7a26, 0xce4a482a, 0xae0614cd, 0xa086e13b, 0x2e089dba,
+    0x5c70da50, 0x2adbf5fc, 0x58e82b1f, 0x29730433, 0xf1fcd4e6, 0x37940724, 0x1604c4eb, 0xbc3189e2,
+    0x81e53e1b, 0xd31e4993, 0x1b26d899, 0x2c0c61f6, 0x38c46a84, 0x032d62a5, 0xc6cb4ea3, 0x62f41421,
+    0x8195b71d, 0x2522d611, 0xdc152f36, 0xd30fa23c, 0xbaa79c4b, 0x219c0b95, 0xdd76f29d, 0x32bafa2d,
+    0x6f262857, 0xb92434c5, 0x78e34aca, 0x124188e1, 0xd380e841, 0xc17f8085, 0x1f2b73f0, 0x0564a2fc,
+    0x8a71eee8, 0xcf6d4962, 0xc47f84f7, 0xc062d6b6, 0x14268a15, 0x743a5721, 0x7153d627, 0xab613b90,
+    0x85f92b05, 0x4d423c3b, 0x3d6c120a, 0xa0925ec8, 0xe2186de5, 0x36cc9792, 0x8affc8b6, 0x9f864431,
+    0x34ab809c, 0x77dcc747, 0xe3a25dad, 0xca486051, 0x851d3d6b, 0x111282ef, 0xfaf124c8, 0x35f0516b,
+    0xa18dfef0, 0x5c59ced5, 0x09f6de61, 0xf5d60f3e, 0x3e3dd94a, 0xe8d3b91d, 0xc78a5c0b, 0x7dbf7e86,
+    0x3a32b7f9, 0x3d350fc0, 0xb051dd7e, 0xcf74e2e1, 0x02d53586, 0x397eedd9, 0xb7a8ee0d, 0xae73a195,
+    0x9646e7e7, 0xee57232d, 0xdf4286cf, 0x7d3c491d, 0x22c1c05d, 0xe9c90b14, 0x357b6dd9, 0x89c586a6,
+]);
+pub const OWNER_MLDSA_KEY_PUBLIC: ImageMldsaPubKey = ImageMldsaPubKey([
+    0xba555e47, 0x3cf51435, 0xa8c3ff19, 0xecc45449, 0x28fd3492, 0x6bbdeca5, 0xfb26308a, 0x7363fed8,
+    0x559845cd, 0x0640a1aa, 0x1545b01c, 0xa278829f, 0xc912e342, 0x466b8ac9, 0x6858f560, 0xe8b34046,
+    0x096105e6, 0x4170eea3, 0xefee46a9, 0xa739b0dc, 0x9109268c, 0x1a0d2f19, 0x45f9ec21, 0xd9459f99,
+    0x18454719, 0x21ebc47d, 0x2c7c43d9, 0xe39108af, 0xf76a9eb7, 0xd8eb5f4f, 0x4a87249b, 0x02e3da1f,
+    0xfe030568, 0x490f735e, 0x24585e6a, 0xe2832e79, 0xcd45dc05, 0x490d08d2, 0x151ee72c, 0x4a5f6c72,
+    0xc5299c57, 0x09e2d235, 0xd77e7ec4, 0x2f6db113, 0x85183576, 0xe8096991, 0x33e776c8, 0xd205944c,
+    0x220df0ee, 0x77e41b95, 0xb0386afe, 0x2a6deb9b, 0x34354451, 0xb99f9e29, 0xbe953835, 0x5c0c585e,
+    0x13816219, 0x946ee4e9, 0xc29ce21b, 0x9e12c4e1, 0x38ea849f, 0xe0936efd, 0xa56dd5f9, 0xdea805ab,
+    0x0dd3c53e, 0xd0c0ace7, 0x7597fee6, 0x2f48c2b9, 0x3a5bab26, 0x881a8828, 0xf7f57329, 0xbc2844f3,
+    0xb634ee54, 0xc316973a, 0x2907220d, 0xd0e6c506, 0x4a1b5d96, 0x35b39fbf, 0xdfec9719, 0xd00434f2,
+    0x00e6e777, 0xcb822863, 0x1bb2cb0b, 0xdfa20ad0, 0x70a229a3, 0x8ee15806, 0xa50773d1, 0x49e69354,
+    0x015664b3, 0xc8e0ef9f, 0x919d8b09, 0x5acc482b, 0x49f446a8, 0x212c51f8, 0x17b398e4, 0xcb7b3943,
+    0x813e69ff, 0x947c41fd, 0x6464a892, 0x6cc1c846, 0xb3bcab8f, 0x84318a71, 0xf359b6d4, 0xf8205783,
+    0xb35ae690, 0x264513c9, 0xd73837ab, 0x8cb7c2b8, 0x1fac69b0, 0x698362d0, 0x0492332c, 0x8d669cce,
+    0xf57bf9ea, 0x92ae721f, 0x2f534d76, 0xca90f20b, 0xc0431fe7, 0x7f992df4, 0x4792b02d, 0x8f0139cb,
+    0x54b0a1eb, 0x3a816096, 0x0e8297e6, 0xeda5d13e, 0x550b1db2, 0xe6d673b6, 0xad40f423, 0x07d7ad75,
+    0x7e32a23a, 0x0ef41dce, 0x36202511, 0xed3eb316, 0x3e66d4c9, 0xe3d1b835, 0x959221cb, 0xeb10d5ab,
+    0xfd0138cb, 0xb43ce5d9, 0xbbc80e2a, 0xc41a0bc6, 0xdce8c5c3, 0x1922547f, 0xe2dcdf0a, 0xa40339d9,
+    0x4fe57b76, 0xd565c315, 0x32abd31a, 0x46099f54, 0xe1198395, 0x9fd062ab, 0x7a8e7aa3, 0x341faf55,
+    0x2895979b, 0x5b8a25ff, 0x3064f4b1, 0x0ea2a521, 0x321c28b6, 0x1b69d2a2, 0x14bb12a8, 0x470f4a42,
+    0x02136761, 0x6a7af20d, 0xc9357e4d, 0x47effc63, 0x2dbc8e66, 0x345bfe7b, 0x5a082ed4, 0x3e65f822,
+    0xe4b80be8, 0xfaca4790, 0x668271b5, 0xa3e820a3, 0x42570ebf, 0xcf1098d5, 0xa63e5238, 0xb63254a3,
+    0xe58ed9a1, 0x572a3fc5, 0x93be9fae, 0xca237c8b, 0xaf094a1f, 0x0726d66c, 0x38937277, 0x0bfc9975,
+    0x38423161, 0x85ef2ae5, 0x889c9cfa, 0x1f56ba2b, 0xcb72cf5e, 0x2139abc5, 0xf488e852, 0x13219eb0,
+    0x6b26953b, 0xf78c6ea8, 0x8c16359a, 0x5dd73557, 0x7b8b371e, 0xd9e56bcd, 0x8ddb7190, 0x5f08a11e,
+    0x8f6a8b33, 0xed7b5557, 0xf39dbc71, 0xeb898e8b, 0x8bd731b0, 0x71675b70, 0x9282bfdd, 0x68faec3b,
+    0xb0fa12cc, 0xa3750b44, 0xef17104b, 0xedbb4232, 0x106f4981, 0x3abcb3d6, 0xc1d1fd92, 0xe57e6007,
+    0x795cc5b0, 0x407b2412, 0xfc21f0ed, 0x4cd914f7, 0x284f7035, 0x1f8c3208, 0x7531b86f, 0x4d8f050d,
+    0xca4eb87b, 0xfa1b1375, 0x2219962d, 0x53a487e5, 0xab3eae8a, 0x4cec08ff, 0xe3795b4f, 0xa88dc89c,
+    0x3cccdbb1, 0x1434a0c1, 0xd27f5602, 0x7b9da589, 0xf8c1c33b, 0x1884ba2e, 0x260d415f, 0xac48d091,
+    0x9ef56154, 0xf65ae345, 0xd5cf1d30, 0x4d6548ca, 0x608e6ec9, 0x4de87251, 0x87014d50, 0x7b72d467,
+    0x273b2b5f, 0xe1e0f297, 0xe492e411, 0x4da1c90a, 0x9799c841, 0x99114589, 0xf1face04, 0xf3ac07ba,
+    0xa768f4cd, 0x711f34f5, 0x150a5cc3, 0x8d27e578, 0x7275764c, 0xf119e2f2, 0x6ce7df53, 0x88ca4446,
+    0xe32019b8, 0x6c664263, 0x5149ab0a, 0xf6b59495, 0xd494135d, 0x2eb1e545, 0xb02fe6cb, 0xac5cccc7,
+    0x32ffcdee, 0x9865dd63, 0xb1859020, 0x85c6c635, 0x44f826fb, 0x710714e9, 0xddd905c1, 0x3cfdc8a2,
+    0x81c9c6f3, 0x195d67b3, 0x3a701907, 0x3df382ec, 0xc6417230, 0xd07bc97f, 0x3cbe450c, 0xb401d037,
+    0xb6843480, 0xa0c4068f, 0xf015b1b3, 0x7b8a4342, 0xf36ce721, 0x55a4cc71, 0x6ae48264, 0x370de2a9,
+    0x122694b1, 0x044fd5a3, 0x179125ed, 0x016516de, 0x6b81adf0, 0x3daab5a5, 0x59c2baa1, 0x9e5c285c,
+    0xccde0fee, 0xa40ab73e, 0xfbc24e34, 0x6d9536a1, 0xc203cbd2, 0xa8ef30dc, 0x18cf5992, 0x38006c2f,
+    0x301968d9, 0xfcdab407, 0xb8d5b61f, 0xa77431f4, 0x098c4f7a, 0xfb30072a, 0x9389ab33, 0x5903fdab,
+    0x623f6a8f, 0x7274ee0d, 0xc61f5b79, 0xdf8ab6d7, 0xd3149d67, 0xd683feeb, 0xa0d6f3a1, 0xf31fd0db,
+    0x7666d042, 0x04715357, 0x72335724, 0xeaba508a, 0x57378009, 0xdf17812b, 0xfbf4c6f5, 0xed06eec4,
+    0xc9a0866a, 0x4cdc538e, 0x707bfa94, 0x37b37705, 0xf413caad, 0x81d39d08, 0xd630194c, 0x7409e1ba,
+    0x2eac3486, 0xfbce6ea8, 0x9be9409b, 0x321b44ae, 0x88ead2d6, 0xb97bc499, 0x552253c4, 0x0c58b56d,
+    0x59ee3e82, 0x050a6632, 0xbe0064be, 0xf2473ee4, 0xef83a94e, 0xb42a25e3, 0x53ba6a4c, 0x710ca452,
+    0xba06bb0e, 0x2747fd84, 0x3d6ea586, 0x6ea98330, 0x5e957eb5, 0xe6ff3b9e, 0x6e66d24c, 0x11335a95,
+    0xa27856eb, 0xfd23bd0a, 0x53a1e14d, 0x82415dac, 0x19a3fc2c, 0x918d04df, 0xcaf497c6, 0x7d57ae03,
+    0x2c62ba46, 0xa32528b7, 0xc10e08d3, 0x29ed48a6, 0xa94c1c99, 0x0a264ba7, 0xec13f98b, 0x154b231a,
+    0x17673c4c, 0x69f266aa, 0x32f3d150, 0x23933ce3, 0x56d4cdf2, 0xd5f3938c, 0x03bca8e0, 0x336d14dc,
+    0x2630d9b2, 0xf27ec984, 0xc9c56a7b, 0x530cdfe4, 0x38b5b5ba, 0xc9ec15a8, 0x4c1fe307, 0xa8118dd3,
+    0xed4cbc1d, 0xb2c1c649, 0x29c8f6eb, 0x33d81d49, 0xf026bd9b, 0xf256583c, 0x3324ff17, 0x30c75dae,
+    0x9dae93f9, 0xf84d2542, 0x0eb178a2, 0xc622d81a, 0x24ff5c28, 0x0ab624c4, 0x54bea731, 0x9c16c95c,
+    0x05b5f3b1, 0xd5eb86b4, 0xcf4f0513, 0x493189ee, 0xd47bfc10, 0x34dac171, 0xee95c98a, 0x67b6a7e0,
+    0x76d73d5d, 0x4cde857c, 0x057f1415, 0x5ccff588, 0x8fc2ffb6, 0xd45860dc, 0x3d74b045, 0xcfc5af89,
+    0xbf70c4fc, 0x5a83d793, 0x1138b739, 0xb4e5321f, 0xeeed19c0, 0x8d18c802, 0x09d1eb81, 0xf94dea24,
+    0x3f9bc213, 0x6e37797d, 0x758241bf, 0x624770f1, 0x0eca059c, 0xfbd96de2, 0x3ebf66ef, 0xfba3d2bf,
+    0xc5bab5c6, 0x343430a6, 0x5cb7a833, 0xbd625ba1, 0x401cece2, 0x50c301e7, 0x305d8721, 0xa4cb4a39,
+    0x259d37bd, 0x8b7b82fb, 0x37d91358, 0x2cfbceb8, 0xefa769f1, 0x6524c337, 0x1c0d1c30, 0xf8de9631,
+    0xd3540070, 0x1871402c, 0x19bb73a4, 0x15f76b45, 0xf34290c7, 0xeaae1a2f, 0xaa243247, 0x24320ace,
+    0x49f3123a, 0x90ab027f, 0xd446863e, 0xaf7c0a01, 0xf684e685, 0xabace97a, 0xfd6e5abc, 0x411dab54,
+    0x31d181f5, 0xc81a7c1e, 0xbefb3b27, 0xf9827d30, 0x0a045ed8, 0x5ef8caa7, 0x3b33e977, 0x417e6154,
+    0xbdaf3e07, 0x43e1e8bf, 0xe72d03cf, 0x36f35771, 0x1c29b259, 0x759b5662, 0x1e2e6731, 0xe24336f6,
+    0x65b97654, 0x90e2261e, 0x5d0fb4a8, 0x9c092c34, 0x26474b9e, 0xde3dd5ef, 0x78479afe, 0x3686216d,
+    0x9885b2fc, 0xad561995, 0xd8981fab, 0x586ddfe0, 0x8c802217, 0xe6475bb4, 0x190d6c34, 0xef57d56c,
+    0xcd6ce530, 0x4853c756, 0x6420fba8, 0x921fe5dd, 0xe86654d6, 0xa3b95a6c, 0x524b4549, 0xa3b4b7f8,
+    0x21760a64, 0xe86f8604, 0x1cd9b474, 0x1f128ebd, 0x5cb16094, 0x63b53df8, 0x4ff4ff1c, 0xf7bab054,
+    0x3b3290e9, 0x50497f5c, 0x77444d1a, 0x4603e0f3, 0xf6cd05a2, 0x58a03369, 0x931ac2aa, 0x20219de2,
+    0x810483d8, 0x38d9238f, 0x126e1ed8, 0x55eeeeef, 0xf8c80bf7, 0x8cb2e893, 0x4381369f, 0x2fdf3c29,
+    0xac17398a, 0x97dd694c, 0x19f3dda3, 0xd367a77c, 0xb9e3cb74, 0x4a1208ae, 0x7289d717, 0x2e0f0010,
+    0xa6cad7b3, 0xb10e6d2a, 0xb2667d60, 0xfd274242, 0xf32ce3db, 0x4541aa7e, 0x6ca3f48f, 0xeb1a7c1f,
+    0x6c219bf9, 0x407bb342, 0x265b0c63, 0x488ccc9e, 0x89b9f40e, 0xd1dbbba5, 0xb28e7431, 0x104a1dd1,
+    0x2761ae9d, 0xa8a98759, 0x48b6492e, 0x09c501ce, 0x05e0cb45, 0xcd3a5353, 0x0e3ee2d2, 0xf95988ac,
+    0x475f7c15, 0x178f5378, 0xc43d3c75, 0x9fd076b7, 0x63683818, 0x5669899d, 0xaf2b545f, 0x9ef4465d,
+    0x9e9d6d93, 0x1b4c1794, 0x2b927fc3, 0x6736b18d, 0xd9a1d3cd, 0xba8c1374, 0x1dd6b81b, 0x6cfae335,
+    0xce5bf24a, 0x7572c337, 0x7d3ff247, 0x28f4e7ef, 0x65af0b1f, 0xd9dc5581, 0xc9b89ac5, 0xf5b595c3,
+    0xa89f7633, 0x1fbb17c2, 0xe044b263, 0x6d3214b5, 0xc14d2d84, 0x173e4e2b, 0xfb4193d6, 0xa5ec93a9,
+    0xcea37169, 0xaff62deb, 0x29508188, 0x45e8a241, 0x0f2bb661, 0x5a68a738, 0x064c1ad3, 0xbc0824e1,
+    0x83d3ee5d, 0x11697b66, 0x18f71206, 0x570d69a5, 0x2034ba0d, 0xd564975d, 0x39761df3, 0x2cd4d34f,
+    0x93ee0b9c, 0x47ab1dee, 0x76a23e5d, 0x1a4e8a6e, 0x3d210c15, 0x9d4c1ca0, 0xbd3fb420, 0x46086a5d,
+    0xd73c22e2, 0x005b49f6, 0x3af992f5, 0x3f419b8f, 0x09f0ab00, 0xb6396508, 0x3c0b9efe, 0x74711f76,
+    0x56a973b9, 0xda8bcbc6, 0x476892b1, 0xe4a011a5, 0x4b32314f, 0x097a897e, 0x69d9df7d, 0xf6e25793,
+]);
+pub const OWNER_MLDSA_KEY_PRIVATE: ImageMldsaPrivKey = ImageMldsaPrivKey([
+    0xba555e47, 0x3cf51435, 0xa8c3ff19, 0xecc45449, 0x28fd3492, 0x6bbdeca5, 0xfb26308a, 0x7363fed8,
+    0xf1cb5414, 0xc6182fdf, 0x065f6ca0, 0x49bc26fd, 0x80353415, 0x5d128a8a, 0x520549dd, 0x2426183c,
+    0xece7b0cb, 0xd70c3c94, 0xd7d35a10, 0x56a4af43, 0xdf2eacf8, 0x69007902, 0x51a32c71, 0x9d14e091,
+    0x6360cb9c, 0x9f505966, 0x0a562889, 0x69010271, 0xf8d91e9e, 0x59f8e7b6, 0x794bebb2, 0x7d781fea,
+    0x446da249, 0x962008a6, 0x0cc2128d, 0x5c25c848, 0x30804202, 0x01a85a30, 0x2122011b, 0xa80a1040,
+    0x0dc29140, 0x4b91b4cc, 0x86d48596, 0x84066491, 0xe225a6d2, 0x329c0a20, 0x30b7004a, 0x0144124a,
+    0x04c86db4, 0x45c25311, 0x090522cb, 0x365a8030, 0x6182a386, 0x6450c2d9, 0xc84a0d84, 0x05084b30,
+    0x1391490c, 0x80536803, 0x29b2e34c, 0x126598db, 0x02632838, 0x2d410168, 0x1345c2c3, 0x46cb0c94,
+    0x6c326385, 0x4965a0ca, 0x07136428, 0x84091261, 0x0024c50b, 0xa6d35100, 0x30b05140, 0x8461c6da,
+    0x46994912, 0x84b0c951, 0x11200642, 0x201b6c40, 0x90b24188, 0x1048a820, 0x448c0095, 0x0dc2240d,
+    0x22902100, 0x009988b1, 0x31962052, 0x6324a220, 0x30c22e42, 0x8594ca91, 0x102216e1, 0x04e46534,
+    0x84871a21, 0x5b0c1410, 0x890288a4, 0x2a089c49, 0x83854098, 0x89145128, 0x48185470, 0xe20cc211,
+    0x010c6624, 0x20b21182, 0x10723914, 0x96c85101, 0x4db09831, 0x9a241608, 0x80120a30, 0x85030149,
+    0xd34e2094, 0x22a08010, 0x2c128108, 0x899008e3, 0x98592a32, 0x2c145064, 0x50060061, 0x30008908,
+    0x51a0530d, 0x208cb808, 0xb0614a21, 0x44368360, 0x93043009, 0x020c8242, 0x4d34d02c, 0xcb88b8d2,
+    0xb0832c10, 0x5220cb45, 0xa04120e1, 0x322225b6, 0x84a4db0d, 0x18922410, 0x04891145, 0x04a2908a,
+    0x594636e2, 0x949c6da0, 0x20c41845, 0x130d1721, 0x44884118, 0x49968192, 0xa48cb823, 0x31146d02,
+    0x0d40dc71, 0x8b25385c, 0x96d36d40, 0x8620dc4c, 0x8909025b, 0x285a4828, 0x6086db65, 0x422124d9,
+    0x82c94988, 0x2424e48c, 0x4b51c2da, 0x42404434, 0x62049162, 0xc88402a3, 0xa8a324b8, 0x82305028,
+    0x6091c113, 0x910a90c6, 0x45430b10, 0x440c0611, 0x17044406, 0x2227114c, 0x228cc308, 0xb90c0401,
+    0x04c80205, 0x8881b013, 0x491a4848, 0x28c10229, 0xcc082714, 0xa4e264c6, 0x2002a345, 0x63902624,
+    0x206181b4, 0x3224a388, 0xd16d849c, 0x804069b0, 0x89081985, 0x82810113, 0x28e265c8, 0x6248db26,
+    0x184ca81b, 0xa11a4639, 0x8048a440, 0x88000803, 0x12127222, 0x2824a302, 0xcc51c922, 0x16487240,
+    0x11461a2d, 0x89204063, 0xb4e43026, 0x41165b51, 0x094da513, 0x14820934, 0x41a80a72, 0x410c248a,
+    0xb8d00000, 0x0112c804, 0xc320900c, 0x425181a6, 0x8da29a66, 0x2308c0d2, 0xb2622422, 0x50989905,
+    0x006dc919, 0x88826cc8, 0x5090538c, 0x14002681, 0x171a4e48, 0x2896602c, 0xca252811, 0x04502644,
+    0x40972471, 0x2329a001, 0x29007036, 0x85284442, 0x2200b508, 0x951b6c25, 0x6e172001, 0x1072141c,
+    0x06636649, 0x30985844, 0x5101024a, 0x825850a4, 0x81a46068, 0x9825a2d2, 0x324b8808, 0x6402d452,
+    0x12688614, 0x20d40083, 0x72428170, 0x924e0213, 0x92c029a2, 0x3221216d, 0x0b8dc490, 0x16435112,
+    0x49c6812d, 0x4c05260b, 0x33135034, 0x0420632a, 0x02260892, 0x16e36534, 0x5082604a, 0x2269384b,
+    0x345a48c2, 0x21245280, 0xda08965b, 0x86418484, 0x6906dc25, 0x5801c092, 0xa4e36420, 0x0114a14c,
+    0xe171b8cc, 0x40944c46, 0x80b00b32, 0xe44418e4, 0x21114442, 0x31804271, 0x04910020, 0x90841001,
+    0x8a405c65, 0x8861c61b, 0xb6a32836, 0x6c970950, 0x108c34a3, 0x904a4105, 0x10012480, 0xe331371a,
+    0x24412e34, 0x81148b28, 0x61219924, 0x880241b2, 0x40989c2d, 0xc1003913, 0x340c28c6, 0x6c97004c,
+    0x8b60c920, 0x83112e10, 0x92331c4c, 0xc365a301, 0x440281c6, 0x30c85322, 0x4a4c3654, 0x36c350c6,
+    0x4028c000, 0xe02dc823, 0x00d97002, 0x1192094e, 0xe28db0ca, 0x18c14482, 0x8032c88e, 0x00103244,
+    0x100b0d20, 0x9132e400, 0xc94d8660, 0x96808888, 0x26185b88, 0x0b8c34dc, 0x22db1220, 0x2d930845,
+    0x14494022, 0x409c0c91, 0x6c928122, 0x0930c004, 0x18817188, 0x45351209, 0xc96d268b, 0xc4603096,
+    0x5226530d, 0x5a258309, 0x02634c96, 0x11b0820d, 0x624a2242, 0xa0641026, 0x0c429081, 0x0c6526c4,
+    0xa64289b2, 0x6cb66209, 0x4a05c4d8, 0xb0133036, 0x71949345, 0x1b610921, 0x245068a9, 0x92229364,
+    0x042c4409, 0x42622086, 0x68b64925, 0x4c71c100, 0x32a18c02, 0x6e06898e, 0x84483880, 0x32132e42,
+    0x6a144b10, 0x490d04e4, 0x92e02434, 0x4988d801, 0x1010945a, 0x486285b0, 0x41b4cc24, 0x9c44a810,
+    0x28c38024, 0x8c388206, 0xa15020ca, 0x12198142, 0x69c2214e, 0xc40d82cb, 0x82195190, 0x46045160,
+    0x9a6e26c1, 0x388a4110, 0x4626508d, 0x584e0404, 0x43140044, 0x2890844c, 0x24004604, 0x08c45034,
+    0x6d185c24, 0x5152328b, 0x93215192, 0x50c2186c, 0xe120c848, 0x02d20088, 0x0822c225, 0xe22c4609,
+    0xa5216608, 0x5042d22c, 0xcb25231c, 0x47190206, 0x9206204e, 0xd188920a, 0x21106400, 0x0048a426,
+    0xc9323314, 0x34120cb4, 0x31951250, 0x2169471c, 0x90a38006, 0x08342381, 0x23053203, 0xa8d92437,
+    0x85060181, 0xc944b221, 0xc4886198, 0x84b2c868, 0x8260980b, 0x04d444c8, 0x30b4c208, 0x11292888,
+    0xb6946a23, 0x45a2a210, 0x59844088, 0x071a6820, 0x4924c272, 0xc1882122, 0x27132928, 0x89b0e184,
+    0xacf84099, 0xa9a006be, 0x1033abbc, 0xa7cf6609, 0x4af74d0c, 0x7e521da0, 0x8826b945, 0xc3b73f96,
+    0x43420f65, 0xd22bc929, 0x5ffdf490, 0x86c859e7, 0xd3656a46, 0x803025f8, 0x572b4701, 0xfe8f979a,
+    0x5bb3b8e2, 0x9c8636f0, 0xd5531087, 0xa52c1132, 0x1ce5fd1d, 0x4a0fb740, 0xac4c2247, 0x3956c118,
+    0x6d320c52, 0x12e6a4a6, 0x880afa7b, 0xe7e82551, 0x26f75c54, 0x8e3e16e7, 0x3b77cb07, 0xe85c32d8,
+    0x076184bd, 0x8983c3e9, 0x61502a45, 0x6fb600ed, 0x7816b0a0, 0x9d3e9c4b, 0x58119b00, 0xfa488c2c,
+    0x32f73097, 0xa4cbbfe6, 0x1ad1133d, 0x24ac02a5, 0xafaf931c, 0xbc7cdce9, 0x4dcbe361, 0xf7b09105,
+    0xb355a0fc, 0xca48a119, 0x37303cc8, 0xc800faf4, 0x103f2470, 0xb44cf10b, 0x45d3ef98, 0x587c2cfd,
+    0x03e23af4, 0x9b1a38d7, 0x38a0292f, 0x02b45134, 0x2fda1912, 0xf1274ac8, 0x37c05a0c, 0x067513cb,
+    0x191d86f2, 0x7dabd1fd, 0xc2b73fa0, 0x7fcf725e, 0x862204d4, 0xb92160fb, 0x2e49c8e0, 0xf8d0fedc,
+    0x175997bb, 0x1622afd4, 0xd340d5bc, 0x94332630, 0xb25f03ae, 0xf711529c, 0xb1ae4c4f, 0x1f9b3027,
+    0x4808e392, 0xf88cd895, 0x0b1f36e7, 0x8d0d2bb4, 0x87bf8b7b, 0x374aa602, 0xe894c01a, 0x029bffda,
+    0x4237eb90, 0xd66ef259, 0xc0a4307e, 0x9ffbddc2, 0x0c7a9507, 0x8af17e28, 0xbf278daf, 0x6efca2d8,
+    0x67f947b0, 0x9fed7f93, 0x9d832465, 0x45f5046c, 0x83f0b9e6, 0xfeaa6a9c, 0x0d4544c1, 0xc489530b,
+    0xb4f6505b, 0x7ada9810, 0x28e6c066, 0xb58cc7c9, 0xd85327f6, 0x0d1409a2, 0xd0b17155, 0x2a75ac02,
+    0xfb0c1548, 0x9c6140d8, 0x1f154e47, 0x751958b8, 0x8374fed6, 0x1928b6e4, 0xdf9706a3, 0xcd9e99b1,
+    0x579ad014, 0x337509a5, 0x1b206624, 0xb12cb2fc, 0x8f7ea384, 0xb749f7d3, 0x99874ebb, 0x1bed878e,
+    0xa1a2e5ca, 0x4f291167, 0x5c438664, 0x644b57d5, 0x69bcb386, 0xbddfdb97, 0x8cc65e80, 0xa5de03b9,
+    0xe6db0bd3, 0x12fbfb25, 0xfa36b3e4, 0xd7426226, 0x94b3e1ba, 0xbfedf1cc, 0x089db394, 0x70675c9c,
+    0x50a87b73, 0xfecf8431, 0x23bc5587, 0x4653ba76, 0x0aba635c, 0xaf3e97e5, 0x7bc27903, 0xe711bc2d,
+    0x28535f04, 0x47653f4f, 0x2bb309c1, 0x37b19487, 0x47f7b394, 0x651a60b2, 0xfdafa025, 0xccf37840,
+    0x35c6a2f1, 0xab98da7f, 0xfe728987, 0x0b987086, 0x68c9db79, 0x18730c6c, 0xca87d513, 0x12cfc999,
+    0x8da8bfe7, 0x0f6c2d8c, 0xea981f11, 0x115f43a2, 0x36430ae7, 0x9b10837e, 0xb532019f, 0x30237fc3,
+    0x13b13f74, 0x913a0d89, 0x17ac5960, 0xaaec279e, 0x6615bd7e, 0x0696ede6, 0x3bc0e004, 0x3a1ff2cd,
+    0xae5aa337, 0xa589cdc6, 0xc742a6d8, 0x83af9ab1, 0x8fc3d6f5, 0xc077af57, 0x0c910ca7, 0x3ab194dd,
+    0x14a83e03, 0xff394560, 0xb33568c2, 0xff7c2315, 0x3ecff13c, 0xee93efa9, 0x22b1e24b, 0x51a8291f,
+    0x1d94ca7f, 0x5b783adc, 0x34d35250, 0x9af6fa9c, 0x3aa6cb4a, 0x528e0672, 0xb86f3d2c, 0x2336d06e,
+    0xaf6c5efe, 0xdc7571da, 0x8d1fd6b9, 0x01425c37, 0x12a21832, 0x3800002a, 0x5dcbf1d8, 0x80617e90,
+    0x1f238500, 0x63fad17a, 0x286e7a2a, 0x15357d73, 0xd7524bea, 0x7d2e3281, 0x50398f6b, 0x28b39a64,
+    0xa0a8e314, 0x81fc3b1b, 0x6df4a9ab, 0x0e7b4666, 0x32a2ca20, 0xd56e3773, 0xd59a88b0, 0xdd2d12a0,
+    0x8029fc02, 0x053c4777, 0x67b7933c, 0x3169f1a8, 0xfb5d003e, 0x1ea437c8, 0xcf7ed321, 0x5f663e17,
+    0xaa588fb1, 0x37756c5d, 0x56727405, 0xa96f5a60, 0x57114e78, 0x6f7f833b, 0xf6dad1ca, 0x9777968e,
+    0xb44d7291, 0x922fd9f9, 0xfcd8b1ef, 0x46ccbe0b, 0x7a52c2ae, 0xe9b169ed, 0xe64c02c6, 0x1e75dffb,
+    0x0507244c, 0x2b2cf30d, 0x2e172746, 0x7ca27ec3, 0xd482a235, 0x101db1e9, 0xc7f86d5e, 0xaad0c5f5,
+    0x9e90aa9b, 0xb65c2445, 0x64814bbf, 0xfc4f7cf3, 0xbfd3496b, 0x9b0d8261, 0xabf48c72, 0x56a0c22e,
+    0x4a4b03e7, 0x59894f41, 0x1450369f, 0x02c2ec34, 0xf70567bf, 0x27e7b643, 0x0caba5c4, 0xc071e6c7,
+    0xca66a7df, 0x361ce398, 0x6b7443f8, 0xcc6fb8a7, 0x85e190dd, 0x73f391f3, 0x49a797b4, 0xe447962d,
+    0x0cf31cf5, 0xf8497f99, 0x60a68b38, 0x47472417, 0x99eb7680, 0x714ad245, 0x4c5b8d80, 0x5c9fff26,
+    0xacf8399d, 0x1bd03c20, 0x6c45c97d, 0x5bef0e26, 0x38736fd0, 0x5c42b6d0, 0x00c06a47, 0x413eba9f,
+    0x82216cf9, 0x23db1f05, 0xf27dc907, 0x1927a5fe, 0xc381b16e, 0x676ddc82, 0x0fbb23d9, 0xe0c2b2c6,
+    0x028f7bd6, 0x9075e982, 0x81def572, 0x3976d8f3, 0x8fe6171c, 0xfee3c8d1, 0x2ddb5ade, 0x7b63d8d3,
+    0x0a4c9177, 0xd1187816, 0x7679022f, 0x447d6354, 0x3ad4bc55, 0x7aa7af8d, 0x2877bd80, 0xc1a1e337,
+    0xa47ef576, 0x86b0b609, 0xa4ebfcef, 0xc499c70e, 0x9f71f87e, 0x5035bfec, 0x00ad82bb, 0x1be15f45,
+    0xc4f8f7db, 0x573c55ac, 0xce16defa, 0x046390a5, 0xff804e78, 0x081b4b1d, 0x3ffce395, 0xfdf6877a,
+    0x6385a679, 0x98d4db22, 0xfbe2ab2e, 0x1a60878d, 0xb2970158, 0xfe8763a0, 0xf44b84ff, 0x0d728b8a,
+    0xfb51d936, 0xc4fda534, 0x6f3d0438, 0xc9f72c47, 0xbf87169d, 0x24110641, 0xc4981eaf, 0x7ec7dfee,
+    0x7509b841, 0xd4cdbbaa, 0xaa9980db, 0x0a9bd71f, 0xf869b9ba, 0x1f520b32, 0xb322a43c, 0x20dba684,
+    0xad1c9d56, 0x0ba148ce, 0xf8631ebc, 0x1ea018bd, 0xb09728be, 0x037f63f1, 0x01db03bf, 0xa58aa6f2,
+    0x425888ef, 0x34cfb9e0, 0xeccc0faf, 0x28e7d9ab, 0x18fbef6e, 0x1e4a0253, 0x22036d45, 0xaa7cf98a,
+    0xf7242971, 0x678fe39a, 0xeb6ce630, 0x8dc47589, 0x81908203, 0x0b283d41, 0xf958cbab, 0xdfb62fdb,
+    0x936f0a8a, 0x73c4ed44, 0xed633ae8, 0x5814555e, 0xca25b8c2, 0x85528657, 0x7f4643f8, 0x75d6d1e5,
+    0xa597c90e, 0xcb91bd17, 0xc90d30d5, 0x838f3339, 0xc1bf3687, 0x4bce4971, 0x74453cff, 0xb05c7851,
+    0x8cfe787d, 0xb8951723, 0x916cc824, 0xe91b31c8, 0x1a781886, 0xb365c6a9, 0x4394db83, 0xc5d70e5a,
+    0xfd2f6d67, 0x30c458f1, 0x681cd12d, 0x40cff514, 0x984d147a, 0x5ff95073, 0x18c069ff, 0xda150ed8,
+    0x9e9bb6d7, 0xa7613f95, 0x3c631747, 0x70d6ad32, 0x9ae14bec, 0x17dc8174, 0xfc8661da, 0xa80f1310,
+    0xd35f3838, 0xd2a40b94, 0xfc80c626, 0xc944e5aa, 0x3f0ed19a, 0xc0c826c6, 0x3261cef3, 0xa0462056,
+    0x5d9ddade, 0x51a29b94, 0x3f384aa1, 0xc71d8a7a, 0x12cbc39a, 0xb7eb8466, 0x7d102bc6, 0xf46a1e0c,
+    0xfb5dca03, 0x9826490f, 0x63a39362, 0x66bda50f, 0x78d7fd9d, 0x2a2d94ed, 0xf0ec211b, 0x416f7db6,
+    0x8c960d50, 0x7c61b1e1, 0x93b20346, 0x52e9f44b, 0x83be22fb, 0xeddb6ffe, 0x0e92ee97, 0x35514878,
+    0xfa6d19ff, 0xdb27e9ba, 0x5e47b18e, 0xb63a5e0f, 0x12ca712b, 0x416a77ca, 0x65e3c815, 0xf3d04065,
+    0x5a040f05, 0x6a64c49f, 0x218be633, 0xc932e03b, 0xc56609b1, 0x90eb8848, 0xa47904b0, 0x82601f73,
+    0x635b2a1a, 0xd8e96302, 0x7aec1422, 0xe3abbbec, 0x0b4ecd4e, 0x844b0875, 0x9155d54f, 0x694d0f85,
+    0x8e96f532, 0x4b3db08d, 0x93c39f0d, 0xeb8c07cf, 0x4799683f, 0x3b54b34b, 0x46813e3e, 0xa4f919af,
+    0xaf5b15ef, 0xfd1f2e61, 0x1a073517, 0x5cefe545, 0x1acfadfc, 0xbbbe3353, 0xfa6a2340, 0xb03dfd8f,
+    0xccac2f1d, 0xdf8dbc60, 0x3f714235, 0x9e91470e, 0x725d4aab, 0x0dc75767, 0xe698a1b4, 0xb5ae9520,
+    0x148730a0, 0xb1375845, 0xb58489f8, 0x54c34bca, 0x0e1e0ed7, 0x28e37955, 0xcee135b9, 0x8665f7be,
+    0x57d831ef, 0x5295b227, 0x630bbfaa, 0x56e4f86c, 0x9d4d6cd4, 0xc408f5f4, 0x8186b995, 0x97fe531b,
+    0x17380d0c, 0x06aa3e0d, 0xa1d1738e, 0x131d1738, 0xf8c91264, 0xa3bb918e, 0x02e3cd7d, 0x21846c9f,
+    0xf853a428, 0xfc07f7f1, 0x34c52b51, 0x0c53cbae, 0xade8a63f, 0xffb52e4d, 0x78f5ad12, 0x25e0216e,
+    0x2db08533, 0x7bc61180, 0xf60ccd0a, 0xe81c3647, 0x33333710, 0x6ba2ed22, 0x563f7d73, 0x01d69c5c,
+    0xd41d291b, 0xf33bb655, 0xf35ea741, 0x114da7d6, 0x53c85c6f, 0xf05fb580, 0xd877bc93, 0xd52a3785,
+    0xac5001c7, 0x04d9c2e5, 0xe0d793ac, 0x51f04923, 0x0da1d165, 0x128be25e, 0xeff7c132, 0xc2a7abec,
+    0x87ad820d, 0x4fc4cc12, 0x27292d9e, 0x346ce8f5, 0xe859be23, 0xdd8dd8b4, 0xdd7aea81, 0x5cb0ff60,
+    0xe25a0811, 0x84d75382, 0xd122239e, 0x4086a023, 0x78e1c451, 0xf4834fbd, 0x4c3a7799, 0x71dbaed7,
+    0x9b0538dc, 0xd794ae92, 0x03d9dddb, 0x4c5d4899, 0x44bbe62d, 0x12a29580, 0x55a4649e, 0x1352619a,
+    0xa2d4b39b, 0xbed8e738, 0xf91bb00d, 0x0e871777, 0xeb0818c2, 0x366a11b7, 0xcc63b027, 0x7da9d76c,
+    0xe8478960, 0x74edc67d, 0x45aae8ec, 0xb852636c, 0xa3776921, 0x27797faa, 0xb4190f49, 0xae02f11b,
+    0xf4e867ae, 0x6191a8d6, 0xc28a9a45, 0x6c46cfa3, 0x91c458ab, 0xd4eddf02, 0x51f5926d, 0xb7a342fa,
+    0xb24a6a51, 0x10d3a850, 0x7ed84116, 0x227f15b8, 0x88b3aba6, 0xd4b18b82, 0x8e3d1a27, 0xc0c56067,
+    0xccb250c0, 0xca806fb7, 0x6c8bf4fd, 0x419dbda4, 0x28e4588b, 0x43f6426d, 0x7c1b7492, 0x9c07444c,
+    0x5f744a33, 0x8b5466e0, 0x21c9d917, 0xd1581634, 0x6b902f0c, 0xb1fabb18, 0xc0ade662, 0x6201fead,
+    0x6855d8f7, 0xbac14070, 0xbd1f642e, 0xe9af0e1e, 0xb67720b9, 0x09262ad5, 0xfcf11b0b, 0x5a02d5f2,
+    0x2d195a18, 0x81e872d3, 0x12adcf99, 0x75f57a1a, 0x95f0c470, 0x7f915022, 0x618fea5a, 0xac0d707f,
+    0xcf5edb9c, 0x4beb513b, 0x683a1283, 0xfd42ff3f, 0x0b42d1c4, 0x58b08a39, 0x7de53dfc, 0xf2460e44,
+    0x5f5c118f, 0x6b4ad4b9, 0x3dd842ac, 0xd5e84a40, 0xa0c66c42, 0x6effd8c2, 0x23bfac7e, 0x50416536,
+    0x9d6eae4c, 0xaea7d5dc, 0x3297d662, 0x06470f8e, 0xc7a22f54, 0xa81c6c1b, 0x17860274, 0x8bf1073a,
+    0xff12f1a2, 0xdf71010f, 0x54123a38, 0x9a10ad91, 0x134778af, 0x6e86fff2, 0xb516eff3, 0x41930226,
+    0x631b4b5b, 0x46e75d1e, 0xf1d43773, 0x282d5136, 0xd7e52a84, 0xb9c7d203, 0xea7630eb, 0x86bfe4dd,
+    0x3b04e1de, 0x89f9c44e, 0x9e3868fd, 0x3cfaa1b5, 0xbe8d4a3a, 0xf568ea0f, 0xeb60b1b3, 0x501f7efb,
+    0xb250bacb, 0x8309ed11, 0x2036b06e, 0x26c96a99, 0x306d3430, 0xe614d7af, 0xe3b6a4f8, 0x03702e1e,
+    0x2fb3c947, 0xbf5ee393, 0xb0b2fd9c, 0x12784bcb, 0x071ec37d, 0x6607b8ab, 0x4fc0363a, 0x04e8df9e,
+    0xafdbb5bc, 0x74fea390, 0x75ec5930, 0x39c93ff1, 0x02956130, 0x0d0d9918, 0xb97db9d3, 0x4df5db3c,
+    0xff07e8eb, 0x534428f1, 0xe14633e7, 0x809a5e97, 0x44b776da, 0x9ec0b102, 0x79b42a49, 0xb98509dc,
+    0xa73fae5c, 0x2ba094c9, 0x808dd009, 0x3d4f4c36, 0x7df9b20a, 0xbc824116, 0xc685b0c2, 0xccd8fb45,
+    0x99bba9b7, 0xa75a3e3a, 0x5e30d4e4, 0xd16f4c01, 0x20526ba6, 0xac7c9f5f, 0x563cb1cc, 0x72c87a7d,
+    0x74c479c7, 0xc44fbaa8, 0xde2c542f, 0x079afe48, 0x18dc8035, 0xbb294ce4, 0xcdd54986, 0xde4cba2b,
+    0x0500b621, 0x0753d67c, 0xd1c5384f, 0x4326f8b6, 0xd627178b, 0x23b3f625, 0xf92b57ac, 0xe0855b16,
+    0xd2662c3a, 0x4bef01d2, 0xd9f61622, 0x036439b1, 0x913a6f92, 0x360c2657, 0xa320aff7, 0xba7c5d00,
+    0xc7bc6dd1, 0xc1d0840b, 0x17602d96, 0x1bcdc893, 0xf1ba9b0d, 0x439395f7, 0xccaf78c0, 0x6d8c6490,
+    0x33cf3924, 0x17b4a81a, 0x06ac06fa, 0xcb524c33, 0x094349aa, 0x937e5faf, 0x6adebf35, 0x85e98ba4,
+    0x481a153e, 0xe2f76d0d, 0xccf6faa8, 0xfbf1bbe4, 0x05b0b7f5, 0x267bf59b, 0x1e82b264, 0x5991b789,
+    0x55a5cd2b, 0x435e5d09, 0xdbd26644, 0x3a47e212, 0x3a6fb072, 0x0f7819bf, 0x81ffaa50, 0x48f6196c,
+    0x82981ec9, 0x71b5a5ef, 0x9b6b5fab, 0xfb3c7234, 0x25d273da, 0x486f9877, 0x010a203f, 0x4a372511,
+    0x929f8fac, 0x59f2c008, 0xfff3ed81, 0x4bf0cf9f, 0xf7fe2cb9, 0xe240027a, 0xb597c9d4, 0xf5bdb56f,
+    0x3e431eef, 0x7a4be699, 0xf483d942, 0xa81b4d82, 0x5e10e7de, 0x14d1f07a, 0xc4701b85, 0x8cfa5998,
+]);
+pub const VENDOR_MLDSA_PUB_KEY_DIGESTS: [ImageDigest; VENDOR_MLDSA_KEY_COUNT as usize] = [
+    [
+        0x6e395215, 0x16ef4d44, 0xddba87eb, 0xc16c7760, 0xa3454542, 0xafc14ec3, 0xcc38bf32,
+        0x394df9cc, 0x77bdf469, 0xd7bfd1bc, 0x4e1fd807, 0xc5997e97,
+    ],
+    [
+        0x48f5ab42, 0x8679fe8a, 0xa1ebd576, 0x54839132, 0x81197e97, 0x28e3fbcc, 0xa99215ed,
+        0xda63f9eb, 0xb2e6b933, 0x8405e09b, 0x5a4bbad8, 0x27c48d1a,
+    ],
+    [
+        0x050b9212, 0x4901ab8d, 0x9278da9a, 0x910a366c, 0x832ba8c8, 0xadd9cc49, 0x9bcde0a0,
+        0x69c65bfd, 0x0015d153, 0x7e02ab29, 0xc9c065ca, 0x4e919568,
+    ],
+    [
+        0x76038dcb, 0x4d852d05, 0x0f591f50, 0x2dd516f4, 0xfc7d6e14, 0x9f4753d3, 0xec04afb9,
+        0x0e669a7d, 0xe68601e4, 0x8457cf60, 0xf4efd557, 0x2259e866,
+    ],
+];
+pub const OWNER_MLDSA_PUB_KEY_DIGEST: ImageDigest = [
+    0x6ec5828f, 0x24161d5b, 0x35ae0d07, 0x24e60625, 0xcfe9ba47, 0xe2d769be, 0x0d2f1f3a, 0xbcbf0f94,
+    0x48335e93, 0x892799ac, 0x28b79129, 0xeac07ccd,
+];
 pub const VENDOR_PUBLIC_KEYS: ImageVendorPubKeys = ImageVendorPubKeys {
     ecc_pub_keys: [
         VENDOR_ECC_KEY_0_PUBLIC,
@@ -278,11 +1493,22 @@ pub const VENDOR_PUBLIC_KEYS: ImageVendorPubKeys = ImageVendorPubKeys {
         VENDOR_LMS_KEY_2_PUBLIC,
         VENDOR_LMS_KEY_3_PUBLIC,
     ],
+    #[cfg(not(feature = "hw-1.0"))]
+    mldsa_pub_key_digests: VENDOR_MLDSA_PUB_KEY_DIGESTS,
 };
 
+pub const VENDOR_MLDSA_PUBLIC_KEYS: [ImageMldsaPubKey; VENDOR_MLDSA_KEY_COUNT as usize] = [
+    VENDOR_MLDSA_KEY_0_PUBLIC,
+    VENDOR_MLDSA_KEY_1_PUBLIC,
+    VENDOR_MLDSA_KEY_2_PUBLIC,
+    VENDOR_MLDSA_KEY_3_PUBLIC,
+];
+
 pub const OWNER_PUBLIC_KEYS: ImageOwnerPubKeys = ImageOwnerPubKeys {
     ecc_pub_key: OWNER_ECC_KEY_PUBLIC,
     lms_pub_key: OWNER_LMS_KEY_PUBLIC,
+    #[cfg(not(feature = "hw-1.0"))]
+    mldsa_pub_key_digest: OWNER_MLDSA_PUB_KEY_DIGEST,
 };
 pub const VENDOR_PRIVATE_KEYS: ImageVendorPrivKeys = ImageVendorPrivKeys {
     ecc_priv_keys: [
@@ -325,17 +1551,26 @@ pub const VENDOR_PRIVATE_KEYS: ImageVendorPrivKeys = ImageVendorPrivKeys {
         VENDOR_LMS_KEY_2_PRIVATE,
         VENDOR_LMS_KEY_3_PRIVATE,
     ],
+    mldsa_priv_keys: [
+        VENDOR_MLDSA_KEY_0_PRIVATE,
+        VENDOR_MLDSA_KEY_1_PRIVATE,
+        VENDOR_MLDSA_KEY_2_PRIVATE,
+        VENDOR_MLDSA_KEY_3_PRIVATE,
+    ],
 };
 
 pub const OWNER_PRIVATE_KEYS: ImageOwnerPrivKeys = ImageOwnerPrivKeys {
     ecc_priv_key: OWNER_ECC_KEY_PRIVATE,
     lms_priv_key: OWNER_LMS_KEY_PRIVATE,
+    mldsa_priv_key: OWNER_MLDSA_KEY_PRIVATE,
 };
 
 pub const VENDOR_CONFIG_KEY_0: ImageGeneratorVendorConfig = ImageGeneratorVendorConfig {
     pub_keys: VENDOR_PUBLIC_KEYS,
     ecc_key_idx: 0,
     lms_key_idx: 0,
+    mldsa_key_idx: 0,
+    mldsa_pub_keys: VENDOR_MLDSA_PUBLIC_KEYS,
     priv_keys: Some(VENDOR_PRIVATE_KEYS),
     not_before: [0u8; 15],
     not_after: [0u8; 15],
@@ -345,29 +1580,31 @@ pub const VENDOR_CONFIG_KEY_0: ImageGeneratorVendorConfig = ImageGeneratorVendor
 pub const VENDOR_CONFIG_KEY_1: ImageGeneratorVendorConfig = ImageGeneratorVendorConfig {
     ecc_key_idx: 1,
     lms_key_idx: 1,
+    mldsa_key_idx: 1,
     ..VENDOR_CONFIG_KEY_0
 };
 
 pub const VENDOR_CONFIG_KEY_2: ImageGeneratorVendorConfig = ImageGeneratorVendorConfig {
     ecc_key_idx: 2,
     lms_key_idx: 2,
+    mldsa_key_idx: 2,
     ..VENDOR_CONFIG_KEY_0
 };
 
 pub const VENDOR_CONFIG_KEY_3: ImageGeneratorVendorConfig = ImageGeneratorVendorConfig {
     ecc_key_idx: 3,
     lms_key_idx: 3,
+    mldsa_key_idx: 3,
     ..VENDOR_CONFIG_KEY_0
 };
 
 pub const OWNER_CONFIG: ImageGeneratorOwnerConfig = ImageGeneratorOwnerConfig {
-    pub_keys: ImageOwnerPubKeys {
-        ecc_pub_key: OWNER_ECC_KEY_PUBLIC,
-        lms_pub_key: OWNER_LMS_KEY_PUBLIC,
-    },
+    pub_keys: OWNER_PUBLIC_KEYS,
+    mldsa_pub_key: OWNER_MLDSA_KEY_PUBLIC,
     priv_keys: Some(ImageOwnerPrivKeys {
         ecc_priv_key: OWNER_ECC_KEY_PRIVATE,
         lms_priv_key: OWNER_LMS_KEY_PRIVATE,
+        mldsa_priv_key: OWNER_MLDSA_KEY_PRIVATE,
     }),
     not_before: [0u8; 15],
     not_after: [0u8; 15],
@@ -421,3 +1658,49 @@ fn test_write_lms_keys() {
     file.write_all(OWNER_PUBLIC_KEYS.lms_pub_key.as_bytes())
         .unwrap();
 }
+
+#[test]
+#[ignore]
+fn test_write_mldsa_keys() {
+    let write = |name: String, bytes: &[u8]| {
+        fs::write(
+            format!("../../target/riscv32imc-unknown-none-elf/firmware/{name}"),
+            bytes,
+        )
+        .unwrap();
+    };
+    for (i, key) in VENDOR_PRIVATE_KEYS.mldsa_priv_keys.iter().enumerate() {
+        write(format!("vnd-mldsa-priv-key-{i}.pem"), key.as_bytes());
+    }
+    for (i, key) in VENDOR_MLDSA_PUBLIC_KEYS.iter().enumerate() {
+        write(format!("vnd-mldsa-pub-key-{i}.pem"), key.as_bytes());
+    }
+    write(
+        "own-mldsa-priv-key.pem".into(),
+        OWNER_PRIVATE_KEYS.mldsa_priv_key.as_bytes(),
+    );
+    write(
+        "own-mldsa-pub-key.pem".into(),
+        OWNER_MLDSA_KEY_PUBLIC.as_bytes(),
+    );
+}
+
+#[test]
+fn test_mldsa_pub_key_digests() {
+    use caliptra_image_crypto::OsslCrypto;
+    use caliptra_image_gen::ImageGeneratorCrypto;
+
+    let crypto = OsslCrypto::default();
+    for (key, digest) in VENDOR_MLDSA_PUBLIC_KEYS
+        .iter()
+        .zip(VENDOR_MLDSA_PUB_KEY_DIGESTS.iter())
+    {
+        assert_eq!(crypto.sha384_digest(key.as_bytes()).unwrap(), *digest);
+    }
+    assert_eq!(
+        crypto
+            .sha384_digest(OWNER_MLDSA_KEY_PUBLIC.as_bytes())
+            .unwrap(),
+        OWNER_MLDSA_PUB_KEY_DIGEST
+    );
+}
@@ -45,41 +45,96 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
     where
         E: ImageGenratorExecutable,
     {
-        let image_size =
-            IMAGE_MANIFEST_BYTE_SIZE as u32 + config.fmc.size() + config.runtime.size();
-        if image_size > IMAGE_BYTE_SIZE as u32 {
+        if config.data.len() > MAX_DATA_TOC_ENTRY_COUNT as usize {
             bail!(
-                "Image larger than {IMAGE_BYTE_SIZE} bytes; image size:{} bytes",
-                image_size
+                "Too many data blobs; max:{MAX_DATA_TOC_ENTRY_COUNT} count:{}",
+                config.data.len()
+            );
+        }
+        let data_size: u32 = config
+            .data
+            .iter()
+            .map(|data| data.content.len() as u32)
+            .sum();
+        if data_size > IMAGE_DATA_MAX_BYTE_SIZE {
+            bail!(
+                "Data larger than {IMAGE_DATA_MAX_BYTE_SIZE} bytes; data size:{} bytes",
+                data_size
             );
         }
 
-        // Create FMC TOC & Content
+        // Create FMC TOC & Content, placed after the manifest, extended TOC
+        // and ML-DSA preamble
         let id = ImageTocEntryId::Fmc;
-        let offset = IMAGE_MANIFEST_BYTE_SIZE as u32;
-        let (fmc_toc, fmc) = self.gen_image(&config.fmc, id, offset)?;
+        let offset = (IMAGE_MANIFEST_BYTE_SIZE
+            + IMAGE_EXTENDED_TOC_BYTE_SIZE
+            + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE) as u32;
+        let (fmc_toc, _, fmc) = self.gen_image(&config.fmc, id, offset, false)?;
 
         // Create Runtime TOC & Content
         let id = ImageTocEntryId::Runtime;
         let offset = offset + fmc_toc.size;
-        let (runtime_toc, runtime) = self.gen_image(&config.runtime, id, offset)?;
+        let (runtime_toc, runtime_expansion, runtime) =
+            self.gen_image(&config.runtime, id, offset, config.compress_runtime)?;
+
+        let image_size = (IMAGE_MANIFEST_BYTE_SIZE
+            + IMAGE_EXTENDED_TOC_BYTE_SIZE
+            + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE) as u32
+            + fmc_toc.size
+            + runtime_toc.size
+            + data_size;
+        if image_size > IMAGE_BYTE_SIZE as u32 {
+            bail!(
+                "Image larger than {IMAGE_BYTE_SIZE} bytes; image size:{} bytes",
+                image_size
+            );
+        }
 
         // Check if fmc and runtime image load address ranges don't overlap.
-        if fmc_toc.overlaps(&runtime_toc) {
+        // A compressed runtime occupies its expanded size in ICCM.
+        let runtime_load_toc = ImageTocEntry {
+            size: if runtime_toc.is_compressed() {
+                runtime_expansion.size
+            } else {
+                runtime_toc.size
+            },
+            ..runtime_toc
+        };
+        if fmc_toc.overlaps(&runtime_load_toc) {
             bail!(
                 "FMC:[{:#x?}:{:#x?}] and Runtime:[{:#x?}:{:#x?}] load address ranges overlap",
                 fmc_toc.load_addr,
                 fmc_toc.load_addr + fmc_toc.size - 1,
-                runtime_toc.load_addr,
-                runtime_toc.load_addr + runtime_toc.size - 1
+                runtime_load_toc.load_addr,
+                runtime_load_toc.load_addr + runtime_load_toc.size - 1
             );
         }
 
+        // Create Data TOCs & Content, placed after the runtime
+        let mut data_toc = [ImageTocEntry::default(); MAX_DATA_TOC_ENTRY_COUNT as usize];
+        let mut data = Vec::with_capacity(config.data.len());
+        let mut offset = offset + runtime_toc.size;
+        for (i, (toc, blob)) in data_toc.iter_mut().zip(config.data.iter()).enumerate() {
+            if config.data[..i].iter().any(|prev| prev.id == blob.id) {
+                bail!("Duplicate data id {:#x}", blob.id);
+            }
+            if blob.content.is_empty() {
+                bail!("Data {:#x} is empty", blob.id);
+            }
+            *toc = self.gen_data(blob, offset)?;
+            offset += toc.size;
+            data.push(blob.content.clone());
+        }
+
         let ecc_key_idx = config.vendor_config.ecc_key_idx;
         let lms_key_idx = config.vendor_config.lms_key_idx;
 
         // Create Header
-        let toc_digest = self.toc_digest(&fmc_toc, &runtime_toc)?;
+        let extended_toc = ImageExtendedToc {
+            data: data_toc,
+            runtime_expansion,
+        };
+        let toc_digest = self.toc_digest(&fmc_toc, &runtime_toc, &extended_toc)?;
         let header = self.gen_header(config, ecc_key_idx, lms_key_idx, toc_digest)?;
 
         // Create Preamable
@@ -92,6 +147,8 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             &header_digest_vendor,
             &header_digest_owner,
         )?;
+        #[cfg(not(feature = "hw-1.0"))]
+        let mldsa = self.gen_mldsa_preamble(config, &header_digest_vendor, &header_digest_owner)?;
 
         // Create Manifest
         let manifest = ImageManifest {
@@ -106,8 +163,12 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         // Create Image Bundle
         let image = ImageBundle {
             manifest,
+            extended_toc,
+            #[cfg(not(feature = "hw-1.0"))]
+            mldsa,
             fmc,
             runtime,
+            data,
         };
 
         Ok(image)
@@ -161,6 +222,8 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             vendor_pub_keys: config.vendor_config.pub_keys,
             vendor_ecc_pub_key_idx: ecc_vendor_key_idx,
             vendor_lms_pub_key_idx: lms_vendor_key_idx,
+            #[cfg(not(feature = "hw-1.0"))]
+            vendor_mldsa_pub_key_idx: config.vendor_config.mldsa_key_idx,
             vendor_sigs,
             owner_sigs,
             ..Default::default()
@@ -173,6 +236,52 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         Ok(preamble)
     }
 
+    /// Create ML-DSA preamble
+    ///
+    /// The ML-DSA keys are optional; they are only used if configured.
+    #[cfg(not(feature = "hw-1.0"))]
+    pub fn gen_mldsa_preamble<E>(
+        &self,
+        config: &ImageGeneratorConfig<E>,
+        digest_vendor: &ImageDigest,
+        digest_owner: &ImageDigest,
+    ) -> anyhow::Result<ImageMldsaPreamble>
+    where
+        E: ImageGenratorExecutable,
+    {
+        let key_idx = config.vendor_config.mldsa_key_idx as usize;
+        let mut mldsa = ImageMldsaPreamble {
+            vendor_pub_key: config
+                .vendor_config
+                .mldsa_pub_keys
+                .get(key_idx)
+                .copied()
+                .unwrap_or_default(),
+            ..Default::default()
+        };
+
+        if let Some(priv_keys) = config.vendor_config.priv_keys {
+            if mldsa.vendor_pub_key != ImageMldsaPubKey::default() {
+                mldsa.vendor_sig = self
+                    .crypto
+                    .mldsa87_sign(digest_vendor, &priv_keys.mldsa_priv_keys[key_idx])?;
+            }
+        }
+
+        if let Some(owner_config) = &config.owner_config {
+            mldsa.owner_pub_key = owner_config.mldsa_pub_key;
+            if let Some(priv_keys) = &owner_config.priv_keys {
+                if mldsa.owner_pub_key != ImageMldsaPubKey::default() {
+                    mldsa.owner_sig = self
+                        .crypto
+                        .mldsa87_sign(digest_owner, &priv_keys.mldsa_priv_key)?;
+                }
+            }
+        }
+
+        Ok(mldsa)
+    }
+
     /// Generate header
     fn gen_header<E>(
         &self,
@@ -187,8 +296,10 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         let mut header = ImageHeader {
             vendor_ecc_pub_key_idx: ecc_key_idx,
             vendor_lms_pub_key_idx: lms_key_idx,
+            #[cfg(not(feature = "hw-1.0"))]
+            vendor_mldsa_pub_key_idx: config.vendor_config.mldsa_key_idx,
             flags: Self::DEFAULT_FLAGS,
-            toc_len: MAX_TOC_ENTRY_COUNT,
+            toc_len: MIN_TOC_ENTRY_COUNT + config.data.len() as u32,
             toc_digest: digest,
             ..Default::default()
         };
@@ -210,6 +321,159 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         Ok(header)
     }
 
+    /// Get the data signed by the vendor and owner
+    ///
+    /// Used to sign an image generated without private keys outside of the
+    /// generator. The signatures are added with `insert_signatures`.
+    pub fn to_be_signed(&self, manifest: &ImageManifest) -> anyhow::Result<ImageToBeSigned> {
+        let offset = offset_of!(ImageHeader, owner_data);
+        let header = manifest.header.as_bytes();
+
+        Ok(ImageToBeSigned {
+            vendor: ToBeSigned {
+                data: header[..offset].to_vec(),
+                digest: self.header_digest_vendor(&manifest.header)?,
+            },
+            owner: ToBeSigned {
+                data: header.to_vec(),
+                digest: self.header_digest_owner(&manifest.header)?,
+            },
+        })
+    }
+
+    /// Insert externally produced signatures into the manifest
+    ///
+    /// Each signature is checked against the matching public key in the
+    /// preamble before the manifest is updated. An image with owner public keys
+    /// requires owner signatures, and an ML-DSA public key requires an ML-DSA
+    /// signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - Manifest of an image generated without private keys
+    /// * `mldsa` - ML-DSA preamble of the same image
+    /// * `vendor_sigs` - Signatures over the vendor data
+    /// * `owner_sigs` - Signatures over the owner data, if the image has an owner
+    pub fn insert_signatures(
+        &self,
+        manifest: &mut ImageManifest,
+        #[cfg(not(feature = "hw-1.0"))] mldsa: &mut ImageMldsaPreamble,
+        vendor_sigs: &DetachedSignatures,
+        owner_sigs: Option<&DetachedSignatures>,
+    ) -> anyhow::Result<()> {
+        let tbs = self.to_be_signed(manifest)?;
+        let preamble = &manifest.preamble;
+
+        let Some(ecc_pub_key) = preamble
+            .vendor_pub_keys
+            .ecc_pub_keys
+            .get(preamble.vendor_ecc_pub_key_idx as usize)
+        else {
+            bail!(
+                "Invalid vendor ECC public key index {}",
+                preamble.vendor_ecc_pub_key_idx
+            );
+        };
+        let Some(lms_pub_key) = preamble
+            .vendor_pub_keys
+            .lms_pub_keys
+            .get(preamble.vendor_lms_pub_key_idx as usize)
+        else {
+            bail!(
+                "Invalid vendor LMS public key index {}",
+                preamble.vendor_lms_pub_key_idx
+            );
+        };
+        self.verify_sigs(
+            "vendor",
+            &tbs.vendor.digest,
+            ecc_pub_key,
+            lms_pub_key,
+            &vendor_sigs.sigs,
+        )?;
+        #[cfg(not(feature = "hw-1.0"))]
+        self.verify_mldsa_sig(
+            "vendor",
+            &tbs.vendor.digest,
+            &mldsa.vendor_pub_key,
+            &vendor_sigs.mldsa_sig,
+        )?;
+
+        let has_owner = preamble.owner_pub_keys.as_bytes().iter().any(|&b| b != 0);
+        if has_owner && owner_sigs.is_none() {
+            bail!("Owner signatures required for an image with owner public keys");
+        }
+        if let Some(owner_sigs) = owner_sigs {
+            self.verify_sigs(
+                "owner",
+                &tbs.owner.digest,
+                &preamble.owner_pub_keys.ecc_pub_key,
+                &preamble.owner_pub_keys.lms_pub_key,
+                &owner_sigs.sigs,
+            )?;
+            #[cfg(not(feature = "hw-1.0"))]
+            {
+                self.verify_mldsa_sig(
+                    "owner",
+                    &tbs.owner.digest,
+                    &mldsa.owner_pub_key,
+                    &owner_sigs.mldsa_sig,
+                )?;
+                mldsa.owner_sig = owner_sigs.mldsa_sig;
+            }
+            manifest.preamble.owner_sigs = owner_sigs.sigs;
+        }
+        manifest.preamble.vendor_sigs = vendor_sigs.sigs;
+        #[cfg(not(feature = "hw-1.0"))]
+        {
+            mldsa.vendor_sig = vendor_sigs.mldsa_sig;
+        }
+
+        Ok(())
+    }
+
+    /// Verify ECC and LMS signatures over a digest
+    fn verify_sigs(
+        &self,
+        name: &str,
+        digest: &ImageDigest,
+        ecc_pub_key: &ImageEccPubKey,
+        lms_pub_key: &ImageLmsPublicKey,
+        sigs: &ImageSignatures,
+    ) -> anyhow::Result<()> {
+        if !self
+            .crypto
+            .ecdsa384_verify(digest, ecc_pub_key, &sigs.ecc_sig)?
+        {
+            bail!("Invalid {name} ECC signature");
+        }
+        if !self.crypto.lms_verify(digest, lms_pub_key, &sigs.lms_sig)? {
+            bail!("Invalid {name} LMS signature");
+        }
+        Ok(())
+    }
+
+    /// Verify an ML-DSA signature over a digest, if there is an ML-DSA public
+    /// key
+    #[cfg(not(feature = "hw-1.0"))]
+    fn verify_mldsa_sig(
+        &self,
+        name: &str,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> anyhow::Result<()> {
+        if *pub_key != ImageMldsaPubKey::default() {
+            if *sig == ImageMldsaSignature::default() {
+                bail!("{name} ML-DSA signature required for an image with an ML-DSA public key");
+            }
+            if !self.crypto.mldsa87_verify(digest, pub_key, sig)? {
+                bail!("Invalid {name} ML-DSA signature");
+            }
+        }
+        Ok(())
+    }
+
     /// Calculate header digest for vendor.
     /// Vendor digest is calculated upto the `owner_data` field.
     pub fn header_digest_vendor(&self, header: &ImageHeader) -> anyhow::Result<ImageDigest> {
@@ -236,17 +500,38 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
     }
 
     /// Generate image
+    ///
+    /// When `compress` is set the content is LZ4 compressed and the TOC digest
+    /// covers the compressed bytes; the returned expansion information
+    /// describes the image inflated into ICCM.
     fn gen_image<E>(
         &self,
         image: &E,
         id: ImageTocEntryId,
         offset: u32,
-    ) -> anyhow::Result<(ImageTocEntry, Vec<u8>)>
+        compress: bool,
+    ) -> anyhow::Result<(ImageTocEntry, ImageRuntimeExpansion, Vec<u8>)>
     where
         E: ImageGenratorExecutable,
     {
-        let image_type = ImageTocEntryType::Executable;
-        let digest = self.crypto.sha384_digest(image.content())?;
+        let (image_type, content, expansion) = if compress {
+            let expansion = ImageRuntimeExpansion {
+                size: image.content().len() as u32,
+                digest: self.crypto.sha384_digest(image.content())?,
+            };
+            (
+                ImageTocEntryType::CompressedExecutable,
+                compress_image(image.content()),
+                expansion,
+            )
+        } else {
+            (
+                ImageTocEntryType::Executable,
+                image.content().clone(),
+                ImageRuntimeExpansion::default(),
+            )
+        };
+        let digest = self.crypto.sha384_digest(&content)?;
 
         let entry = ImageTocEntry {
             id: id.into(),
@@ -258,22 +543,41 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             load_addr: image.load_addr(),
             entry_point: image.entry_point(),
             offset,
-            size: image.content().len() as u32,
+            size: content.len() as u32,
             digest,
         };
 
-        Ok((entry, image.content().clone()))
+        Ok((entry, expansion, content))
+    }
+
+    /// Generate data TOC entry
+    fn gen_data(&self, data: &ImageGeneratorData, offset: u32) -> anyhow::Result<ImageTocEntry> {
+        let digest = self.crypto.sha384_digest(&data.content)?;
+
+        Ok(ImageTocEntry {
+            id: data.id,
+            image_type: ImageTocEntryType::Data.into(),
+            version: data.version,
+            offset,
+            size: data.content.len() as u32,
+            digest,
+            ..Default::default()
+        })
     }
 
     /// Calculate TOC digest
+    ///
+    /// Covers the extended TOC, including the unused data TOC slots.
     pub fn toc_digest(
         &self,
         fmc_toc: &ImageTocEntry,
         rt_toc: &ImageTocEntry,
+        extended_toc: &ImageExtendedToc,
     ) -> anyhow::Result<ImageDigest> {
         let mut toc_content: Vec<u8> = Vec::new();
         toc_content.extend_from_slice(fmc_toc.as_bytes());
         toc_content.extend_from_slice(rt_toc.as_bytes());
+        toc_content.extend_from_slice(extended_toc.as_bytes());
         self.crypto.sha384_digest(&toc_content)
     }
 }
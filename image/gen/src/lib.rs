@@ -20,6 +20,11 @@ use caliptra_image_types::*;
 use serde_derive::Deserialize;
 use std::path::Path;
 
+/// Compress an executable with the LZ4 block format inflated by the ROM
+pub fn compress_image(content: &[u8]) -> Vec<u8> {
+    lz4_flex::block::compress(content)
+}
+
 /// Image Generator Executable
 pub trait ImageGenratorExecutable {
     /// Executable Version Number
@@ -83,6 +88,45 @@ pub trait ImageGeneratorCrypto {
         priv_key: &ImageLmsPrivKey,
     ) -> anyhow::Result<ImageLmsSignature>;
 
+    /// Calculate ML-DSA-87 Signature
+    fn mldsa87_sign(
+        &self,
+        _digest: &ImageDigest,
+        _priv_key: &ImageMldsaPrivKey,
+    ) -> anyhow::Result<ImageMldsaSignature> {
+        anyhow::bail!("ML-DSA-87 signing is not supported by this crypto backend")
+    }
+
+    /// Verify ECDSA Signature
+    fn ecdsa384_verify(
+        &self,
+        _digest: &ImageDigest,
+        _pub_key: &ImageEccPubKey,
+        _sig: &ImageEccSignature,
+    ) -> anyhow::Result<bool> {
+        anyhow::bail!("ECDSA verification is not supported by this crypto backend")
+    }
+
+    /// Verify LMS Signature
+    fn lms_verify(
+        &self,
+        _digest: &ImageDigest,
+        _pub_key: &ImageLmsPublicKey,
+        _sig: &ImageLmsSignature,
+    ) -> anyhow::Result<bool> {
+        anyhow::bail!("LMS verification is not supported by this crypto backend")
+    }
+
+    /// Verify ML-DSA-87 Signature
+    fn mldsa87_verify(
+        &self,
+        _digest: &ImageDigest,
+        _pub_key: &ImageMldsaPubKey,
+        _sig: &ImageMldsaSignature,
+    ) -> anyhow::Result<bool> {
+        anyhow::bail!("ML-DSA-87 verification is not supported by this crypto backend")
+    }
+
     /// Read ECC-384 Public Key from PEM file
     fn ecc_pub_key_from_pem(path: &Path) -> anyhow::Result<ImageEccPubKey>;
 
@@ -99,6 +143,14 @@ pub struct ImageGeneratorVendorConfig {
 
     pub lms_key_idx: u32,
 
+    #[serde(default)]
+    pub mldsa_key_idx: u32,
+
+    /// ML-DSA public keys. Only their digests are in `pub_keys`; the key at
+    /// `mldsa_key_idx` is placed in the ML-DSA preamble.
+    #[serde(default)]
+    pub mldsa_pub_keys: [ImageMldsaPubKey; VENDOR_MLDSA_KEY_COUNT as usize],
+
     pub priv_keys: Option<ImageVendorPrivKeys>,
 
     pub not_before: [u8; 15],
@@ -113,6 +165,11 @@ pub struct ImageGeneratorVendorConfig {
 pub struct ImageGeneratorOwnerConfig {
     pub pub_keys: ImageOwnerPubKeys,
 
+    /// ML-DSA public key. Only its digest is in `pub_keys`; the key is placed
+    /// in the ML-DSA preamble.
+    #[serde(default)]
+    pub mldsa_pub_key: ImageMldsaPubKey,
+
     pub priv_keys: Option<ImageOwnerPrivKeys>,
 
     pub not_before: [u8; 15],
@@ -122,6 +179,68 @@ pub struct ImageGeneratorOwnerConfig {
     pub epoch: [u8; 2],
 }
 
+/// Data to be signed outside of the generator, e.g. by a hardware security
+/// module
+#[derive(Default, Clone)]
+pub struct ToBeSigned {
+    /// Signed data
+    pub data: Vec<u8>,
+
+    /// SHA-384 digest of `data`
+    pub digest: ImageDigest,
+}
+
+impl ToBeSigned {
+    /// Big-endian bytes of `digest`. This is the input to the ECDSA signing
+    /// operation and the LMS message.
+    pub fn digest_bytes(&self) -> [u8; SHA384_DIGEST_BYTE_SIZE] {
+        let mut result = [0u8; SHA384_DIGEST_BYTE_SIZE];
+        for (chunk, word) in result.chunks_exact_mut(4).zip(self.digest.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        result
+    }
+}
+
+/// Signatures produced outside of the generator
+#[derive(Default, Clone)]
+pub struct DetachedSignatures {
+    /// ECC and LMS signatures, placed in the manifest preamble
+    pub sigs: ImageSignatures,
+
+    /// ML-DSA signature, placed in the ML-DSA preamble. Only needed for an
+    /// image with an ML-DSA public key.
+    #[cfg(not(feature = "hw-1.0"))]
+    pub mldsa_sig: ImageMldsaSignature,
+}
+
+/// Image data signed by the vendor and owner
+#[derive(Default, Clone)]
+pub struct ImageToBeSigned {
+    /// Signed with the vendor ECC, LMS and ML-DSA keys selected in the
+    /// preamble
+    pub vendor: ToBeSigned,
+
+    /// Signed with the owner ECC, LMS and ML-DSA keys
+    pub owner: ToBeSigned,
+}
+
+/// Image Generator Data
+///
+/// Non-executable data signed along with the FMC and runtime
+#[derive(Default, Clone, Deserialize)]
+pub struct ImageGeneratorData {
+    /// TOC entry ID
+    pub id: u32,
+
+    /// Data version
+    #[serde(default)]
+    pub version: u32,
+
+    /// Data content
+    pub content: Vec<u8>,
+}
+
 /// Image Generator Configuration
 #[derive(Default)]
 pub struct ImageGeneratorConfig<T>
@@ -135,4 +254,9 @@ where
     pub fmc: T,
 
     pub runtime: T,
+
+    pub data: Vec<ImageGeneratorData>,
+
+    /// Compress the runtime image
+    pub compress_runtime: bool,
 }
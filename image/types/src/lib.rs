@@ -29,7 +29,10 @@ use zeroize::Zeroize;
 pub const MANIFEST_MARKER: u32 = 0x4E414D43;
 pub const VENDOR_ECC_KEY_COUNT: u32 = 4;
 pub const VENDOR_LMS_KEY_COUNT: u32 = 32;
-pub const MAX_TOC_ENTRY_COUNT: u32 = 2;
+pub const VENDOR_MLDSA_KEY_COUNT: u32 = 4;
+pub const MIN_TOC_ENTRY_COUNT: u32 = 2;
+pub const MAX_DATA_TOC_ENTRY_COUNT: u32 = 4;
+pub const MAX_TOC_ENTRY_COUNT: u32 = MIN_TOC_ENTRY_COUNT + MAX_DATA_TOC_ENTRY_COUNT;
 pub const IMAGE_REVISION_BYTE_SIZE: usize = 20;
 pub const ECC384_SCALAR_WORD_SIZE: usize = 12;
 pub const ECC384_SCALAR_BYTE_SIZE: usize = 48;
@@ -40,12 +43,25 @@ pub const SHA384_DIGEST_WORD_SIZE: usize = 12;
 pub const SHA384_DIGEST_BYTE_SIZE: usize = 48;
 pub const IMAGE_LMS_OTS_P_PARAM: usize = 51;
 pub const IMAGE_LMS_KEY_HEIGHT: usize = 15;
+pub const MLDSA87_PUB_KEY_BYTE_SIZE: usize = 2592;
+pub const MLDSA87_PUB_KEY_WORD_SIZE: usize = 648;
+pub const MLDSA87_PRIV_KEY_BYTE_SIZE: usize = 4896;
+pub const MLDSA87_PRIV_KEY_WORD_SIZE: usize = 1224;
+pub const MLDSA87_SIGNATURE_BYTE_SIZE: usize = 4627;
+pub const MLDSA87_SIGNATURE_WORD_SIZE: usize = 1157;
+pub const MLDSA87_MSG_BYTE_SIZE: usize = 64;
 pub const IMAGE_BYTE_SIZE: usize = 128 * 1024;
+pub const IMAGE_DATA_MAX_BYTE_SIZE: u32 = 1024;
 // LMS-SHA192-H15
 pub const IMAGE_LMS_TREE_TYPE: LmsAlgorithmType = LmsAlgorithmType::LmsSha256N24H15;
 // LMOTS-SHA192-W4
 pub const IMAGE_LMS_OTS_TYPE: LmotsAlgorithmType = LmotsAlgorithmType::LmotsSha256N24W4;
 pub const IMAGE_MANIFEST_BYTE_SIZE: usize = core::mem::size_of::<ImageManifest>();
+pub const IMAGE_EXTENDED_TOC_BYTE_SIZE: usize = core::mem::size_of::<ImageExtendedToc>();
+#[cfg(not(feature = "hw-1.0"))]
+pub const IMAGE_MLDSA_PREAMBLE_BYTE_SIZE: usize = core::mem::size_of::<ImageMldsaPreamble>();
+#[cfg(feature = "hw-1.0")]
+pub const IMAGE_MLDSA_PREAMBLE_BYTE_SIZE: usize = 0;
 
 pub type ImageScalar = [u32; ECC384_SCALAR_WORD_SIZE];
 pub type ImageDigest = [u32; SHA384_DIGEST_WORD_SIZE];
@@ -107,6 +123,96 @@ pub type ImageLmsSignature =
     LmsSignature<SHA192_DIGEST_WORD_SIZE, IMAGE_LMS_OTS_P_PARAM, IMAGE_LMS_KEY_HEIGHT>;
 pub type ImageLmOTSSignature = LmotsSignature<SHA192_DIGEST_WORD_SIZE, IMAGE_LMS_OTS_P_PARAM>;
 
+/// ML-DSA-87 public key, in the FIPS 204 byte encoding. Each word holds four
+/// bytes of the encoding in little-endian order, as expected by the ML-DSA
+/// engine.
+#[repr(C)]
+#[derive(
+    IntoBytes, FromBytes, Immutable, KnownLayout, Debug, Copy, Clone, Eq, PartialEq, Zeroize,
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ImageMldsaPubKey(pub [u32; MLDSA87_PUB_KEY_WORD_SIZE]);
+
+impl Default for ImageMldsaPubKey {
+    fn default() -> Self {
+        Self([0; MLDSA87_PUB_KEY_WORD_SIZE])
+    }
+}
+
+/// ML-DSA-87 private key, in the FIPS 204 byte encoding.
+#[repr(C)]
+#[derive(
+    IntoBytes, FromBytes, Immutable, KnownLayout, Debug, Copy, Clone, Eq, PartialEq, Zeroize,
+)]
+pub struct ImageMldsaPrivKey(pub [u32; MLDSA87_PRIV_KEY_WORD_SIZE]);
+
+impl Default for ImageMldsaPrivKey {
+    fn default() -> Self {
+        Self([0; MLDSA87_PRIV_KEY_WORD_SIZE])
+    }
+}
+
+/// ML-DSA-87 signature, in the FIPS 204 byte encoding. The last byte of the
+/// last word is padding and must be zero.
+#[repr(C)]
+#[derive(
+    IntoBytes, FromBytes, Immutable, KnownLayout, Debug, Copy, Clone, Eq, PartialEq, Zeroize,
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ImageMldsaSignature(pub [u32; MLDSA87_SIGNATURE_WORD_SIZE]);
+
+impl Default for ImageMldsaSignature {
+    fn default() -> Self {
+        Self([0; MLDSA87_SIGNATURE_WORD_SIZE])
+    }
+}
+
+/// Returns the ML-DSA-87 message signed for a SHA-384 `digest`: the digest
+/// bytes followed by zeros, in the word order of the ML-DSA engine.
+pub fn mldsa_msg_from_digest(digest: &ImageDigest) -> [u32; MLDSA87_MSG_BYTE_SIZE / 4] {
+    let mut msg = [0u32; MLDSA87_MSG_BYTE_SIZE / 4];
+    for (msg, digest) in msg.iter_mut().zip(digest) {
+        *msg = digest.swap_bytes();
+    }
+    msg
+}
+
+// Deserialize the ML-DSA keys from byte arrays. This has to be done manually
+// because serde doesn't support arrays longer than 32 elements.
+#[cfg(feature = "std")]
+fn deserialize_words<'de, D, const N: usize>(deserializer: D) -> Result<[u32; N], D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    use serde::Deserialize;
+
+    let bytes = Vec::<u8>::deserialize(deserializer)?;
+    let mut words = [0u32; N];
+    if bytes.len() != words.as_bytes().len() {
+        return Err(D::Error::invalid_length(
+            bytes.len(),
+            &format!("an array of {} bytes", words.as_bytes().len()).as_str(),
+        ));
+    }
+    words.as_mut_bytes().copy_from_slice(&bytes);
+    Ok(words)
+}
+
+#[cfg(feature = "std")]
+impl<'de> serde::Deserialize<'de> for ImageMldsaPubKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(deserialize_words(deserializer)?))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> serde::Deserialize<'de> for ImageMldsaPrivKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(deserialize_words(deserializer)?))
+    }
+}
+
 /// Caliptra Image Bundle
 #[cfg(feature = "std")]
 #[derive(Debug, Default)]
@@ -114,11 +220,21 @@ pub struct ImageBundle {
     /// Manifest
     pub manifest: ImageManifest,
 
+    /// Data TOC entries and runtime expansion information
+    pub extended_toc: ImageExtendedToc,
+
+    /// ML-DSA keys and signatures
+    #[cfg(not(feature = "hw-1.0"))]
+    pub mldsa: ImageMldsaPreamble,
+
     /// FMC
     pub fmc: Vec<u8>,
 
     /// Runtime
     pub runtime: Vec<u8>,
+
+    /// Data blobs, in the order of the data TOC entries
+    pub data: Vec<Vec<u8>>,
 }
 
 #[cfg(feature = "std")]
@@ -127,6 +243,9 @@ impl ImageBundle {
         use std::io::ErrorKind;
         let mut result = vec![];
         result.extend_from_slice(self.manifest.as_bytes());
+        result.extend_from_slice(self.extended_toc.as_bytes());
+        #[cfg(not(feature = "hw-1.0"))]
+        result.extend_from_slice(self.mldsa.as_bytes());
         if self.manifest.fmc.offset as usize != result.len() {
             return Err(std::io::Error::new(
                 ErrorKind::Other,
@@ -153,6 +272,27 @@ impl ImageBundle {
             ));
         }
         result.extend_from_slice(&self.runtime);
+        if self.data.len() > self.extended_toc.data.len() {
+            return Err(std::io::Error::new(
+                ErrorKind::Other,
+                "too many data blobs for manifest",
+            ));
+        }
+        for (entry, data) in self.extended_toc.data.iter().zip(self.data.iter()) {
+            if entry.offset as usize != result.len() {
+                return Err(std::io::Error::new(
+                    ErrorKind::Other,
+                    "actual data offset does not match manifest",
+                ));
+            }
+            if entry.size as usize != data.len() {
+                return Err(std::io::Error::new(
+                    ErrorKind::Other,
+                    "actual data size does not match manifest",
+                ));
+            }
+            result.extend_from_slice(data);
+        }
         Ok(result)
     }
 }
@@ -214,13 +354,41 @@ impl ImageManifest {
         span.start as u32..span.end as u32
     }
 
-    /// Returns `Range<u32>` containing the table of contents
+    /// Returns `Range<u32>` in the image bundle containing the table of
+    /// contents, including the extended TOC that follows the manifest
     pub fn toc_range() -> Range<u32> {
         let span = span_of!(ImageManifest, fmc..=runtime);
-        span.start as u32..span.end as u32
+        span.start as u32..(IMAGE_MANIFEST_BYTE_SIZE + IMAGE_EXTENDED_TOC_BYTE_SIZE) as u32
+    }
+
+    /// Size of the runtime once loaded into ICCM
+    pub fn runtime_load_size(&self, toc: &ImageExtendedToc) -> u32 {
+        if self.runtime.is_compressed() {
+            toc.runtime_expansion.size
+        } else {
+            self.runtime.size
+        }
     }
 }
 
+/// Caliptra Image Extended Table of Contents
+///
+/// Follows the manifest in the image bundle and is covered by the TOC digest
+/// in the header. It is kept out of the manifest so that the manifest still
+/// fits in its slot in persistent data.
+#[repr(C)]
+#[derive(IntoBytes, Immutable, KnownLayout, FromBytes, Clone, Copy, Debug, Default, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ImageExtendedToc {
+    /// Data TOC Entries. Only the first `header.toc_len - MIN_TOC_ENTRY_COUNT`
+    /// entries are in use. The IDs are chosen by the SoC integrator.
+    pub data: [ImageTocEntry; MAX_DATA_TOC_ENTRY_COUNT as usize],
+
+    /// Runtime expansion information. Only used when the runtime TOC entry is
+    /// a `CompressedExecutable`; zero otherwise.
+    pub runtime_expansion: ImageRuntimeExpansion,
+}
+
 #[repr(C)]
 #[derive(IntoBytes, Immutable, KnownLayout, FromBytes, Default, Debug, Clone, Copy, Zeroize)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -229,6 +397,11 @@ pub struct ImageVendorPubKeys {
     pub ecc_pub_keys: [ImageEccPubKey; VENDOR_ECC_KEY_COUNT as usize],
     #[zeroize(skip)]
     pub lms_pub_keys: [ImageLmsPublicKey; VENDOR_LMS_KEY_COUNT as usize],
+    /// SHA-384 digests of the ML-DSA public keys. The active key is carried
+    /// in the ML-DSA preamble, as the keys are too large to include them all.
+    #[cfg(not(feature = "hw-1.0"))]
+    #[cfg_attr(feature = "std", serde(default))]
+    pub mldsa_pub_key_digests: [ImageDigest; VENDOR_MLDSA_KEY_COUNT as usize],
 }
 
 #[repr(C)]
@@ -238,6 +411,8 @@ pub struct ImageVendorPrivKeys {
     pub ecc_priv_keys: [ImageEccPrivKey; VENDOR_ECC_KEY_COUNT as usize],
     #[zeroize(skip)]
     pub lms_priv_keys: [ImageLmsPrivKey; VENDOR_LMS_KEY_COUNT as usize],
+    #[cfg_attr(feature = "std", serde(default))]
+    pub mldsa_priv_keys: [ImageMldsaPrivKey; VENDOR_MLDSA_KEY_COUNT as usize],
 }
 
 #[repr(C)]
@@ -248,6 +423,10 @@ pub struct ImageOwnerPubKeys {
     pub ecc_pub_key: ImageEccPubKey,
     #[zeroize(skip)]
     pub lms_pub_key: ImageLmsPublicKey,
+    /// SHA-384 digest of the ML-DSA public key in the ML-DSA preamble
+    #[cfg(not(feature = "hw-1.0"))]
+    #[cfg_attr(feature = "std", serde(default))]
+    pub mldsa_pub_key_digest: ImageDigest,
 }
 
 #[repr(C)]
@@ -257,6 +436,8 @@ pub struct ImageOwnerPrivKeys {
     pub ecc_priv_key: ImageEccPrivKey,
     #[zeroize(skip)]
     pub lms_priv_key: ImageLmsPrivKey,
+    #[cfg_attr(feature = "std", serde(default))]
+    pub mldsa_priv_key: ImageMldsaPrivKey,
 }
 
 #[repr(C)]
@@ -282,6 +463,10 @@ pub struct ImagePreamble {
     /// Vendor LMS Public Key Index
     pub vendor_lms_pub_key_idx: u32,
 
+    /// Vendor ML-DSA Public Key Index
+    #[cfg(not(feature = "hw-1.0"))]
+    pub vendor_mldsa_pub_key_idx: u32,
+
     /// Vendor Signatures
     pub vendor_sigs: ImageSignatures,
 
@@ -294,6 +479,49 @@ pub struct ImagePreamble {
     pub _rsvd: [u32; 2],
 }
 
+/// ML-DSA keys and signatures. They follow the manifest in the image bundle
+/// and are not part of the manifest copy kept by the ROM, as they are only
+/// needed to verify the image.
+#[repr(C)]
+#[derive(Clone, Copy, IntoBytes, Immutable, KnownLayout, FromBytes, Default, Debug, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ImageMldsaPreamble {
+    /// Vendor ML-DSA Public Key, whose digest is at `vendor_mldsa_pub_key_idx`
+    /// in the vendor public keys
+    pub vendor_pub_key: ImageMldsaPubKey,
+
+    /// Vendor ML-DSA Signature
+    pub vendor_sig: ImageMldsaSignature,
+
+    /// Owner ML-DSA Public Key, whose digest is in the owner public keys
+    pub owner_pub_key: ImageMldsaPubKey,
+
+    /// Owner ML-DSA Signature
+    pub owner_sig: ImageMldsaSignature,
+}
+
+impl ImageMldsaPreamble {
+    /// Returns `Range<u32>` in the image bundle containing the ML-DSA preamble
+    pub fn range() -> Range<u32> {
+        let offset = (IMAGE_MANIFEST_BYTE_SIZE + IMAGE_EXTENDED_TOC_BYTE_SIZE) as u32;
+        offset..offset + size_of::<ImageMldsaPreamble>() as u32
+    }
+
+    /// Returns `Range<u32>` in the image bundle containing the vendor public key
+    pub fn vendor_pub_key_range() -> Range<u32> {
+        let offset = (IMAGE_MANIFEST_BYTE_SIZE + IMAGE_EXTENDED_TOC_BYTE_SIZE) as u32;
+        let span = span_of!(ImageMldsaPreamble, vendor_pub_key);
+        span.start as u32 + offset..span.end as u32 + offset
+    }
+
+    /// Returns `Range<u32>` in the image bundle containing the owner public key
+    pub fn owner_pub_key_range() -> Range<u32> {
+        let offset = (IMAGE_MANIFEST_BYTE_SIZE + IMAGE_EXTENDED_TOC_BYTE_SIZE) as u32;
+        let span = span_of!(ImageMldsaPreamble, owner_pub_key);
+        span.start as u32 + offset..span.end as u32 + offset
+    }
+}
+
 #[repr(C)]
 #[derive(IntoBytes, Clone, Copy, FromBytes, Immutable, KnownLayout, Default, Debug, Zeroize)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -337,6 +565,10 @@ pub struct ImageHeader {
     /// Vendor LMS Public Key Index
     pub vendor_lms_pub_key_idx: u32,
 
+    /// Vendor ML-DSA Public Key Index
+    #[cfg(not(feature = "hw-1.0"))]
+    pub vendor_mldsa_pub_key_idx: u32,
+
     /// Flags
     /// Bit 0: Interpret the pl0_pauser field. If not set, all PAUSERs are PL1.
     pub flags: u32,
@@ -362,6 +594,13 @@ pub struct ImageHeader {
 pub enum ImageTocEntryType {
     /// First mutable code
     Executable = 1,
+
+    /// Non-executable data, such as SoC configuration
+    Data = 2,
+
+    /// Executable compressed with the LZ4 block format. The ROM inflates it
+    /// into ICCM. Only supported for the runtime.
+    CompressedExecutable = 3,
 }
 
 impl From<ImageTocEntryType> for u32 {
@@ -437,12 +676,28 @@ impl ImageTocEntry {
         self.size
     }
 
+    pub fn is_compressed(&self) -> bool {
+        self.image_type == ImageTocEntryType::CompressedExecutable as u32
+    }
+
     pub fn overlaps(&self, other: &ImageTocEntry) -> bool {
         self.load_addr < (other.load_addr + other.image_size())
             && (self.load_addr + self.image_size()) > other.load_addr
     }
 }
 
+/// Caliptra runtime expansion information
+#[repr(C)]
+#[derive(IntoBytes, Clone, Copy, FromBytes, Immutable, KnownLayout, Default, Debug, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ImageRuntimeExpansion {
+    /// Size of the compressed runtime once inflated into ICCM
+    pub size: u32,
+
+    /// Digest of the compressed runtime once inflated into ICCM
+    pub digest: ImageDigest,
+}
+
 /// Information about the ROM image.
 #[repr(C)]
 #[derive(IntoBytes, FromBytes, Immutable, KnownLayout, Default, Debug)]
@@ -463,6 +718,26 @@ mod tests {
     #[test]
     fn test_manifest_size() {
         assert_eq!(std::mem::size_of::<ImageManifest>() % 4, 0);
+        assert_eq!(std::mem::size_of::<ImageExtendedToc>() % 4, 0);
+        // The extended TOC must directly follow the runtime TOC entry so the
+        // TOC digest covers a single range.
+        assert_eq!(
+            span_of!(ImageManifest, runtime).end,
+            IMAGE_MANIFEST_BYTE_SIZE
+        );
+        assert_eq!(std::mem::size_of::<ImageMldsaPreamble>() % 4, 0);
+    }
+
+    #[test]
+    fn test_runtime_load_size() {
+        let mut manifest = ImageManifest::default();
+        let mut toc = ImageExtendedToc::default();
+        manifest.runtime.size = 50;
+        toc.runtime_expansion.size = 200;
+        assert_eq!(manifest.runtime_load_size(&toc), 50);
+
+        manifest.runtime.image_type = ImageTocEntryType::CompressedExecutable.into();
+        assert_eq!(manifest.runtime_load_size(&toc), 200);
     }
 
     #[test]
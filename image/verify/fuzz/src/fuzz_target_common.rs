@@ -25,9 +25,11 @@ struct TestEnv {
     fmc_digest: ImageDigest,
     verify_result: bool,
     verify_lms_result: bool,
+    verify_mldsa_result: bool,
     vendor_pub_key_digest: ImageDigest,
     vendor_ecc_pub_key_revocation: VendorPubKeyRevocation,
     vendor_lms_pub_key_revocation: u32,
+    vendor_mldsa_pub_key_revocation: u32,
     owner_pub_key_digest: ImageDigest,
     lifecycle: Lifecycle,
 }
@@ -41,9 +43,12 @@ impl Default for TestEnv {
             verify_result: true,
             // PATCHED
             verify_lms_result: true,
+            // PATCHED
+            verify_mldsa_result: true,
             vendor_pub_key_digest: ImageDigest::default(),
             vendor_ecc_pub_key_revocation: VendorPubKeyRevocation::default(),
             vendor_lms_pub_key_revocation: 0,
+            vendor_mldsa_pub_key_revocation: 0,
             owner_pub_key_digest: ImageDigest::default(),
             lifecycle: Lifecycle::Unprovisioned,
         }
@@ -81,6 +86,21 @@ impl ImageVerificationEnv for TestEnv {
         }
     }
 
+    fn mldsa87_verify(
+        &mut self,
+        _digest: &ImageDigest,
+        _pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> CaliptraResult<LEArray4x16> {
+        let mut verify_res = LEArray4x16::default();
+        if self.verify_mldsa_result {
+            verify_res.0.copy_from_slice(&sig.0[..16]);
+        } else {
+            verify_res.0 = [0xDEADBEEF; 16];
+        }
+        Ok(verify_res)
+    }
+
     fn vendor_pub_key_digest(&self) -> ImageDigest {
         self.vendor_pub_key_digest
     }
@@ -93,6 +113,10 @@ impl ImageVerificationEnv for TestEnv {
         self.vendor_lms_pub_key_revocation
     }
 
+    fn vendor_mldsa_pub_key_revocation(&self) -> u32 {
+        self.vendor_mldsa_pub_key_revocation
+    }
+
     fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
         self.owner_pub_key_digest
     }
@@ -113,6 +137,10 @@ impl ImageVerificationEnv for TestEnv {
         0
     }
 
+    fn vendor_mldsa_pub_key_idx_dv(&self) -> u32 {
+        0
+    }
+
     fn owner_pub_key_digest_dv(&self) -> ImageDigest {
         self.owner_pub_key_digest
     }
@@ -140,6 +168,10 @@ impl ImageVerificationEnv for TestEnv {
         true
     }
 
+    fn mldsa_verify_enabled(&self) -> bool {
+        true
+    }
+
     fn set_fw_extended_error(&mut self, _err: u32) {}
 }
 
@@ -149,22 +181,38 @@ pub fn harness_structured(reset_reason: ResetReason, manifest: ImageManifest) {
     let mut image_verifier = ImageVerifier::new(test_env);
 
     //println!("{:?}", fuzz_bundle);
-    let _result = image_verifier.verify(&manifest, IMAGE_BUNDLE_SIZE, reset_reason);
+    let _result = image_verifier.verify(
+        &manifest,
+        &ImageExtendedToc::default(),
+        MldsaPreambleSource::None,
+        IMAGE_BUNDLE_SIZE,
+        reset_reason,
+    );
     //println!("{:?}", _result);
 }
 
 #[cfg(not(feature = "struct-aware"))]
 pub fn harness_unstructured(reset_reason: ResetReason, data: &[u8]) {
     let typed_fuzz_manifest: &ImageManifest;
+    let typed_fuzz_toc: &ImageExtendedToc;
+    let mut typed_fuzz_mldsa = MldsaPreambleSource::None;
 
     // The null-case is too hard to fuzz (better statically)
     // - Or, if we initialise with the `default()`, it's the test-case
-    if data.len() < size_of::<ImageManifest>() {
+    let toc_offset = size_of::<ImageManifest>();
+    let mldsa_offset = toc_offset + size_of::<ImageExtendedToc>();
+    if data.len() < mldsa_offset {
         return;
     }
 
     unsafe {
         typed_fuzz_manifest = &*(data.as_ptr() as *const ImageManifest);
+        typed_fuzz_toc = &*(data[toc_offset..].as_ptr() as *const ImageExtendedToc);
+        if data.len() >= mldsa_offset + size_of::<ImageMldsaPreamble>() {
+            typed_fuzz_mldsa = MldsaPreambleSource::Image(
+                &*(data[mldsa_offset..].as_ptr() as *const ImageMldsaPreamble),
+            );
+        }
     }
 
     let test_env = TestEnv::default();
@@ -173,6 +221,8 @@ pub fn harness_unstructured(reset_reason: ResetReason, data: &[u8]) {
     //println!("{:?}", fuzz_bundle);
     let _result = image_verifier.verify(
         typed_fuzz_manifest,
+        typed_fuzz_toc,
+        typed_fuzz_mldsa,
         data.len().try_into().unwrap(),
         reset_reason,
     );
@@ -53,6 +53,22 @@ pub struct ImageVerificationExeInfo {
     pub digest: ImageDigest,
 }
 
+/// Image Verification Data Info
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ImageVerificationDataInfo {
+    /// ID from the TOC entry
+    pub id: u32,
+
+    /// Offset of the data in the image bundle
+    pub offset: u32,
+
+    /// Length
+    pub size: u32,
+
+    /// Digest of the data
+    pub digest: ImageDigest,
+}
+
 /// Information To Be Logged For The Verified Image
 #[derive(Default, Debug)]
 pub struct ImageVerificationLogInfo {
@@ -68,6 +84,12 @@ pub struct ImageVerificationLogInfo {
     /// Vendor LMS Public Key Revocation Fuse
     pub fuse_vendor_lms_pub_key_revocation: Option<u32>,
 
+    // ML-DSA Vendor Public Key Index
+    pub vendor_mldsa_pub_key_idx: Option<u32>,
+
+    /// Vendor ML-DSA Public Key Revocation Fuse
+    pub fuse_vendor_mldsa_pub_key_revocation: Option<u32>,
+
     /// First Mutable code's logging information
     pub fmc_log_info: ImageSvnLogInfo,
 
@@ -84,6 +106,9 @@ pub struct ImageVerificationInfo {
     /// Vendor LMS public key index
     pub vendor_lms_pub_key_idx: Option<u32>,
 
+    /// Vendor ML-DSA public key index
+    pub vendor_mldsa_pub_key_idx: Option<u32>,
+
     /// Digest of owner public keys that verified the image
     pub owner_pub_keys_digest: ImageDigest,
 
@@ -96,10 +121,34 @@ pub struct ImageVerificationInfo {
     /// Runtime
     pub runtime: ImageVerificationExeInfo,
 
+    /// Data blobs; only the first `data_count` entries are valid
+    pub data: [ImageVerificationDataInfo; MAX_DATA_TOC_ENTRY_COUNT as usize],
+
+    /// Number of data blobs
+    pub data_count: u32,
+
+    /// Digest of the ML-DSA preamble; `None` if ML-DSA verification is disabled
+    pub mldsa_preamble_digest: Option<ImageDigest>,
+
     /// Information Returned To Be Logged
     pub log_info: ImageVerificationLogInfo,
 }
 
+/// ML-DSA preamble of the image to verify
+#[derive(Clone, Copy, Debug)]
+pub enum MldsaPreambleSource<'a> {
+    /// No ML-DSA preamble. Verification fails if ML-DSA is enabled in the fuses.
+    None,
+
+    /// ML-DSA preamble following the manifest in the image
+    Image(&'a ImageMldsaPreamble),
+
+    /// Digest of the ML-DSA preamble that the ROM verified, for re-verifying an
+    /// image whose preamble was not kept after boot. The key indices are
+    /// checked again; the ML-DSA keys and signatures are not.
+    RomVerified(ImageDigest),
+}
+
 /// Image Verification Environment
 pub trait ImageVerificationEnv {
     /// Calculate SHA-384 Digest
@@ -121,6 +170,15 @@ pub trait ImageVerificationEnv {
         sig: &ImageLmsSignature,
     ) -> CaliptraResult<HashValue<SHA192_DIGEST_WORD_SIZE>>;
 
+    /// Perform ML-DSA-87 Verification of the message built from `digest`
+    /// by `mldsa_msg_from_digest()`
+    fn mldsa87_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> CaliptraResult<LEArray4x16>;
+
     /// Get Vendor Public Key Digest
     fn vendor_pub_key_digest(&self) -> ImageDigest;
 
@@ -130,6 +188,9 @@ pub trait ImageVerificationEnv {
     /// Get Vendor LMS Public Key Revocation list
     fn vendor_lms_pub_key_revocation(&self) -> u32;
 
+    /// Get Vendor ML-DSA Public Key Revocation list
+    fn vendor_mldsa_pub_key_revocation(&self) -> u32;
+
     /// Get Owner Public Key Digest from fuses
     fn owner_pub_key_digest_fuses(&self) -> ImageDigest;
 
@@ -145,6 +206,9 @@ pub trait ImageVerificationEnv {
     // Get the vendor LMS key index saved on cold boot in data vault
     fn vendor_lms_pub_key_idx_dv(&self) -> u32;
 
+    // Get the vendor ML-DSA key index saved on cold boot in data vault
+    fn vendor_mldsa_pub_key_idx_dv(&self) -> u32;
+
     // Get the owner key digest saved on cold boot in data vault
     fn owner_pub_key_digest_dv(&self) -> ImageDigest;
 
@@ -163,6 +227,9 @@ pub trait ImageVerificationEnv {
     // LMS Verification enabled
     fn lms_verify_enabled(&self) -> bool;
 
+    // ML-DSA Verification enabled
+    fn mldsa_verify_enabled(&self) -> bool;
+
     // Set the extended error code
     fn set_fw_extended_error(&mut self, err: u32);
 }
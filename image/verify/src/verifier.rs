@@ -35,12 +35,27 @@ struct HeaderInfo<'a> {
     vendor_ecc_info: (&'a ImageEccPubKey, &'a ImageEccSignature),
     vendor_lms_info: Option<(&'a ImageLmsPublicKey, &'a ImageLmsSignature)>,
     vendor_lms_pub_key_revocation: Option<u32>,
+    vendor_mldsa_pub_key_idx: Option<u32>,
+    vendor_mldsa_info: Option<(&'a ImageMldsaPubKey, &'a ImageMldsaSignature)>,
+    vendor_mldsa_pub_key_revocation: Option<u32>,
+    mldsa_preamble_digest: Option<ImageDigest>,
     owner_ecc_info: (&'a ImageEccPubKey, &'a ImageEccSignature),
     owner_lms_info: Option<(&'a ImageLmsPublicKey, &'a ImageLmsSignature)>,
+    owner_mldsa_info: Option<(&'a ImageMldsaPubKey, &'a ImageMldsaSignature)>,
     owner_pub_keys_digest: ImageDigest,
     owner_pub_keys_digest_in_fuses: bool,
 }
 
+/// ML-DSA Info
+#[derive(Default)]
+struct MldsaInfo<'a> {
+    vendor_pub_key_idx: Option<u32>,
+    vendor_pub_key_revocation: Option<u32>,
+    preamble_digest: Option<ImageDigest>,
+    vendor: Option<(&'a ImageMldsaPubKey, &'a ImageMldsaSignature)>,
+    owner: Option<(&'a ImageMldsaPubKey, &'a ImageMldsaSignature)>,
+}
+
 /// TOC Info
 struct TocInfo<'a> {
     len: u32,
@@ -51,6 +66,8 @@ struct TocInfo<'a> {
 struct ImageInfo<'a> {
     fmc: &'a ImageTocEntry,
     runtime: &'a ImageTocEntry,
+    runtime_load_size: u32,
+    data: &'a [ImageTocEntry],
 }
 
 /// Image Verifier
@@ -74,6 +91,8 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
     /// # Arguments
     ///
     /// * `manifest` - Image Manifest
+    /// * `toc`      - Extended TOC following the manifest in the image
+    /// * `mldsa`    - ML-DSA preamble of the image
     /// * `image`    - Image to verify
     /// * `reason`   - Reset Reason
     ///
@@ -85,6 +104,8 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
     pub fn verify(
         &mut self,
         manifest: &ImageManifest,
+        toc: &ImageExtendedToc,
+        mldsa: MldsaPreambleSource,
         img_bundle_sz: u32,
         reason: ResetReason,
     ) -> CaliptraResult<ImageVerificationInfo> {
@@ -100,7 +121,7 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
 
         // Verify the preamble
         let preamble = &manifest.preamble;
-        let header_info = self.verify_preamble(preamble, reason);
+        let header_info = self.verify_preamble(preamble, mldsa, reason);
         let header_info = okref(&header_info)?;
 
         // Verify Header
@@ -109,22 +130,30 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         let toc_info = okref(&toc_info)?;
 
         // Verify TOC
-        let image_info = self.verify_toc(manifest, toc_info, img_bundle_sz);
+        let image_info = self.verify_toc(manifest, toc, toc_info, img_bundle_sz);
         let image_info = okref(&image_info)?;
 
         // Verify FMC
         let (fmc_info, fmc_log_info) = self.verify_fmc(image_info.fmc, reason)?;
 
         // Verify Runtime
-        let (runtime_info, rt_log_info) = self.verify_runtime(image_info.runtime)?;
+        let (runtime_info, rt_log_info) =
+            self.verify_runtime(image_info.runtime, image_info.runtime_load_size)?;
+
+        // Verify Data
+        let data = self.verify_data(image_info.data)?;
 
         let info = ImageVerificationInfo {
             vendor_ecc_pub_key_idx: header_info.vendor_ecc_pub_key_idx,
             vendor_lms_pub_key_idx: header_info.vendor_lms_pub_key_idx,
+            vendor_mldsa_pub_key_idx: header_info.vendor_mldsa_pub_key_idx,
             owner_pub_keys_digest: header_info.owner_pub_keys_digest,
             owner_pub_keys_digest_in_fuses: header_info.owner_pub_keys_digest_in_fuses,
             fmc: fmc_info,
             runtime: runtime_info,
+            data,
+            data_count: image_info.data.len() as u32,
+            mldsa_preamble_digest: header_info.mldsa_preamble_digest,
             log_info: ImageVerificationLogInfo {
                 vendor_ecc_pub_key_idx: header_info.vendor_ecc_pub_key_idx,
                 fuse_vendor_ecc_pub_key_revocation: header_info.vendor_ecc_pub_key_revocation,
@@ -132,17 +161,67 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
                 rt_log_info,
                 fuse_vendor_lms_pub_key_revocation: header_info.vendor_lms_pub_key_revocation,
                 vendor_lms_pub_key_idx: header_info.vendor_lms_pub_key_idx,
+                fuse_vendor_mldsa_pub_key_revocation: header_info.vendor_mldsa_pub_key_revocation,
+                vendor_mldsa_pub_key_idx: header_info.vendor_mldsa_pub_key_idx,
             },
         };
 
         Ok(info)
     }
 
+    /// Verify the manifest and extended TOC of a Caliptra image
+    ///
+    /// Checks the preamble, the header signatures and the TOC digest, but not
+    /// the image sections. The caller checks the sections against the digests
+    /// in the verified TOC.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - Image Manifest
+    /// * `toc`      - Extended TOC following the manifest in the image
+    /// * `mldsa`    - ML-DSA preamble of the image
+    /// * `image`    - Image to verify
+    /// * `reason`   - Reset Reason
+    #[cfg_attr(all(not(test), not(feature = "no-cfi")), cfi_impl_fn)]
+    #[inline(never)]
+    pub fn verify_manifest(
+        &mut self,
+        manifest: &ImageManifest,
+        toc: &ImageExtendedToc,
+        mldsa: MldsaPreambleSource,
+        img_bundle_sz: u32,
+        reason: ResetReason,
+    ) -> CaliptraResult<()> {
+        // Check if manifest has required marker
+        if manifest.marker != MANIFEST_MARKER {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH)?;
+        }
+
+        // Check if manifest size is valid
+        if manifest.size as usize != core::mem::size_of::<ImageManifest>() {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_SIZE_MISMATCH)?;
+        }
+
+        // Verify the preamble
+        let header_info = self.verify_preamble(&manifest.preamble, mldsa, reason);
+        let header_info = okref(&header_info)?;
+
+        // Verify Header
+        let toc_info = self.verify_header(&manifest.header, header_info);
+        let toc_info = okref(&toc_info)?;
+
+        // Verify TOC
+        self.verify_toc(manifest, toc, toc_info, img_bundle_sz)?;
+
+        Ok(())
+    }
+
     /// Verify Preamble
     #[cfg_attr(all(not(test), not(feature = "no-cfi")), cfi_impl_fn)]
     fn verify_preamble<'a>(
         &mut self,
         preamble: &'a ImagePreamble,
+        mldsa: MldsaPreambleSource<'a>,
         reason: ResetReason,
     ) -> CaliptraResult<HeaderInfo<'a>> {
         // Verify Vendor Public Key Digest
@@ -181,6 +260,17 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             cfi_assert!(!self.env.lms_verify_enabled());
         }
 
+        // Verify ML-DSA Key Indices and Digests
+        #[cfg(not(feature = "hw-1.0"))]
+        let mldsa_info = self.verify_mldsa_preamble(preamble, mldsa, reason)?;
+
+        // There are no ML-DSA keys in 1.0 images
+        #[cfg(feature = "hw-1.0")]
+        let mldsa_info = {
+            let _ = mldsa;
+            MldsaInfo::default()
+        };
+
         // Owner Information
         let owner_ecc_info = (
             &preamble.owner_pub_keys.ecc_pub_key,
@@ -202,12 +292,17 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             vendor_lms_pub_key_idx,
             vendor_ecc_info,
             vendor_lms_info,
+            vendor_mldsa_pub_key_idx: mldsa_info.vendor_pub_key_idx,
+            vendor_mldsa_info: mldsa_info.vendor,
             owner_lms_info,
+            owner_mldsa_info: mldsa_info.owner,
             owner_pub_keys_digest,
             owner_pub_keys_digest_in_fuses,
             owner_ecc_info,
             vendor_ecc_pub_key_revocation,
             vendor_lms_pub_key_revocation,
+            vendor_mldsa_pub_key_revocation: mldsa_info.vendor_pub_key_revocation,
+            mldsa_preamble_digest: mldsa_info.preamble_digest,
         };
 
         Ok(info)
@@ -302,6 +397,158 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok((Some(key_idx), Some(revocation)))
     }
 
+    /// Verify the ML-DSA public keys against their digests in the preamble
+    #[cfg(not(feature = "hw-1.0"))]
+    fn verify_mldsa_preamble<'a>(
+        &mut self,
+        preamble: &ImagePreamble,
+        mldsa: MldsaPreambleSource<'a>,
+        reason: ResetReason,
+    ) -> CaliptraResult<MldsaInfo<'a>> {
+        if cfi_launder(self.env.mldsa_verify_enabled()) {
+            let (idx, revocation) = self.verify_vendor_mldsa_pk_idx(preamble, reason)?;
+
+            let mut info = MldsaInfo {
+                vendor_pub_key_idx: Some(idx),
+                vendor_pub_key_revocation: Some(revocation),
+                ..Default::default()
+            };
+            match mldsa {
+                MldsaPreambleSource::Image(mldsa) => {
+                    self.verify_vendor_mldsa_pk_digest(preamble, idx)?;
+                    self.verify_owner_mldsa_pk_digest(preamble)?;
+
+                    info.preamble_digest = Some(self.mldsa_preamble_digest()?);
+                    info.vendor = Some((&mldsa.vendor_pub_key, &mldsa.vendor_sig));
+                    info.owner = Some((&mldsa.owner_pub_key, &mldsa.owner_sig));
+                }
+                // The ROM checked the keys and signatures when it loaded the image
+                MldsaPreambleSource::RomVerified(digest) if digest != *ZERO_DIGEST => {
+                    info.preamble_digest = Some(digest);
+                }
+                _ => Err(CaliptraError::IMAGE_VERIFIER_ERR_MLDSA_PREAMBLE_MISSING)?,
+            }
+
+            Ok(info)
+        } else {
+            cfi_assert!(!self.env.mldsa_verify_enabled());
+            Ok(MldsaInfo::default())
+        }
+    }
+
+    /// Verify Vendor ML-DSA Public Key Index
+    #[cfg(not(feature = "hw-1.0"))]
+    fn verify_vendor_mldsa_pk_idx(
+        &mut self,
+        preamble: &ImagePreamble,
+        reason: ResetReason,
+    ) -> CaliptraResult<(u32, u32)> {
+        const SECOND_LAST_KEY_IDX: u32 = VENDOR_MLDSA_KEY_COUNT - 2;
+        const LAST_KEY_IDX: u32 = SECOND_LAST_KEY_IDX + 1;
+
+        let key_idx = preamble.vendor_mldsa_pub_key_idx;
+        let revocation = self.env.vendor_mldsa_pub_key_revocation();
+
+        match key_idx {
+            0..=SECOND_LAST_KEY_IDX => {
+                cfi_assert_le(cfi_launder(key_idx), SECOND_LAST_KEY_IDX);
+                if (cfi_launder(revocation) & (0x01u32 << key_idx)) != 0 {
+                    Err(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_REVOKED)?;
+                } else {
+                    cfi_assert_eq(revocation & (0x01u32 << key_idx), 0);
+                }
+            }
+            LAST_KEY_IDX => {
+                cfi_assert_eq(cfi_launder(key_idx), LAST_KEY_IDX);
+                // The last key is never revoked
+            }
+            _ => Err(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_OUT_OF_BOUNDS)?,
+        }
+
+        if cfi_launder(reason) == ResetReason::UpdateReset {
+            let expected = self.env.vendor_mldsa_pub_key_idx_dv();
+            if cfi_launder(expected) != key_idx {
+                Err(
+                    CaliptraError::IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_MLDSA_PUB_KEY_IDX_MISMATCH,
+                )?;
+            } else {
+                cfi_assert_eq(self.env.vendor_mldsa_pub_key_idx_dv(), key_idx);
+            }
+        } else {
+            cfi_assert_ne(reason, ResetReason::UpdateReset);
+        }
+
+        Ok((key_idx, revocation))
+    }
+
+    /// Verify the vendor ML-DSA public key in the ML-DSA preamble against its
+    /// digest in the vendor public keys
+    #[cfg(not(feature = "hw-1.0"))]
+    fn verify_vendor_mldsa_pk_digest(
+        &mut self,
+        preamble: &ImagePreamble,
+        key_idx: u32,
+    ) -> CaliptraResult<()> {
+        let expected = preamble
+            .vendor_pub_keys
+            .mldsa_pub_key_digests
+            .get(key_idx as usize)
+            .ok_or(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_OUT_OF_BOUNDS)?;
+
+        let range = ImageMldsaPreamble::vendor_pub_key_range();
+        let actual = &self
+            .env
+            .sha384_digest(range.start, range.len() as u32)
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_DIGEST_FAILURE
+            })?;
+
+        if cfi_launder(expected) != actual {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_DIGEST_MISMATCH)?;
+        } else {
+            caliptra_cfi_lib::cfi_assert_eq_12_words(expected, actual);
+        }
+
+        Ok(())
+    }
+
+    /// Calculate the digest of the ML-DSA preamble
+    #[cfg(not(feature = "hw-1.0"))]
+    fn mldsa_preamble_digest(&mut self) -> CaliptraResult<ImageDigest> {
+        let range = ImageMldsaPreamble::range();
+        self.env
+            .sha384_digest(range.start, range.len() as u32)
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_MLDSA_PREAMBLE_DIGEST_FAILURE
+            })
+    }
+
+    /// Verify the owner ML-DSA public key in the ML-DSA preamble against its
+    /// digest in the owner public keys
+    #[cfg(not(feature = "hw-1.0"))]
+    fn verify_owner_mldsa_pk_digest(&mut self, preamble: &ImagePreamble) -> CaliptraResult<()> {
+        let expected = &preamble.owner_pub_keys.mldsa_pub_key_digest;
+
+        let range = ImageMldsaPreamble::owner_pub_key_range();
+        let actual = &self
+            .env
+            .sha384_digest(range.start, range.len() as u32)
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_PUB_KEY_DIGEST_FAILURE
+            })?;
+
+        if cfi_launder(expected) != actual {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_PUB_KEY_DIGEST_MISMATCH)?;
+        } else {
+            caliptra_cfi_lib::cfi_assert_eq_12_words(expected, actual);
+        }
+
+        Ok(())
+    }
+
     /// Verify vendor public key digest
     fn verify_vendor_pk_digest(&mut self) -> Result<(), NonZeroU32> {
         // We skip vendor public key check in unprovisioned state
@@ -462,6 +709,31 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             cfi_assert!(info.vendor_lms_pub_key_idx.is_none());
         }
 
+        // Verify vendor ML-DSA signature
+        if let Some(mldsa_info) = info.vendor_mldsa_info {
+            self.verify_mldsa_sig(
+                &digest_vendor,
+                mldsa_info,
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE,
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID,
+            )?;
+        } else {
+            cfi_assert!(info.vendor_mldsa_info.is_none());
+        }
+
+        // Verify the ML-DSA public key index used to verify header signature is
+        // encoded in the header
+        #[cfg(not(feature = "hw-1.0"))]
+        if let Some(idx) = cfi_launder(info.vendor_mldsa_pub_key_idx) {
+            if cfi_launder(header.vendor_mldsa_pub_key_idx) != idx {
+                return Err(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_MISMATCH);
+            } else {
+                cfi_assert_eq(header.vendor_mldsa_pub_key_idx, idx);
+            }
+        } else {
+            cfi_assert!(info.vendor_mldsa_pub_key_idx.is_none());
+        }
+
         // Verify owner ECC signature
         let (owner_ecc_pub_key, owner_ecc_sig) = info.owner_ecc_info;
         self.verify_owner_ecc_sig(&digest_owner, owner_ecc_pub_key, owner_ecc_sig)?;
@@ -477,6 +749,18 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             cfi_assert!(info.owner_lms_info.is_none());
         }
 
+        // Verify owner ML-DSA signature
+        if let Some(mldsa_info) = info.owner_mldsa_info {
+            self.verify_mldsa_sig(
+                &digest_owner,
+                mldsa_info,
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_VERIFY_FAILURE,
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_SIGNATURE_INVALID,
+            )?;
+        } else {
+            cfi_assert!(info.owner_mldsa_info.is_none());
+        }
+
         let verif_info = TocInfo {
             len: header.toc_len,
             digest: &header.toc_digest,
@@ -628,19 +912,56 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok(())
     }
 
+    /// Verify vendor or owner ML-DSA Signature, returning `verify_failure` if
+    /// the verification could not be performed and `sig_invalid` if the
+    /// signature does not match.
+    fn verify_mldsa_sig(
+        &mut self,
+        digest: &ImageDigest,
+        mldsa_info: (&ImageMldsaPubKey, &ImageMldsaSignature),
+        verify_failure: CaliptraError,
+        sig_invalid: CaliptraError,
+    ) -> CaliptraResult<()> {
+        let (pub_key, sig) = mldsa_info;
+        let verify_res = self
+            .env
+            .mldsa87_verify(digest, pub_key, sig)
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                verify_failure
+            })?;
+
+        // The verification result matches the start of a valid signature
+        if cfi_launder(verify_res.0) != sig.0[..verify_res.0.len()] {
+            Err(sig_invalid)?;
+        } else {
+            caliptra_cfi_lib::cfi_assert_eq_12_words(
+                verify_res.0[..12].try_into().unwrap(),
+                sig.0[..12].try_into().unwrap(),
+            );
+        }
+
+        Ok(())
+    }
+
     /// Verify Table of Contents
     #[cfg_attr(all(not(test), not(feature = "no-cfi")), cfi_impl_fn)]
     fn verify_toc<'a>(
         &mut self,
         manifest: &'a ImageManifest,
+        toc: &'a ImageExtendedToc,
         verify_info: &TocInfo,
         img_bundle_sz: u32,
     ) -> CaliptraResult<ImageInfo<'a>> {
-        if cfi_launder(verify_info.len) != MAX_TOC_ENTRY_COUNT {
+        if cfi_launder(verify_info.len) < MIN_TOC_ENTRY_COUNT
+            || verify_info.len > MAX_TOC_ENTRY_COUNT
+        {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)?;
         } else {
-            cfi_assert_eq(verify_info.len, MAX_TOC_ENTRY_COUNT);
+            cfi_assert_ge(verify_info.len, MIN_TOC_ENTRY_COUNT);
+            cfi_assert_ge(MAX_TOC_ENTRY_COUNT, verify_info.len);
         }
+        let data = &toc.data[..(verify_info.len - MIN_TOC_ENTRY_COUNT) as usize];
 
         let range = ImageManifest::toc_range();
 
@@ -666,20 +987,30 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             caliptra_cfi_lib::cfi_assert_eq_12_words(verify_info.digest, &actual);
         }
 
+        // Only the runtime may be compressed.
+        if manifest.fmc.is_compressed() {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_FMC_TYPE_INVALID)?;
+        }
+
         // Verify the FMC size is not zero.
         if manifest.fmc.image_size() == 0 {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_FMC_SIZE_ZERO)?;
         }
 
-        // Verify the Runtime size is not zero.
-        if manifest.runtime.image_size() == 0 {
+        // Verify the Runtime size is not zero, both in the bundle and in ICCM.
+        let runtime_load_size = manifest.runtime_load_size(toc);
+        if manifest.runtime.image_size() == 0 || runtime_load_size == 0 {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_SIZE_ZERO)?;
         }
 
         // Image length does not exceed the Image Bundle size
+        let data_len: u64 = data.iter().map(|entry| entry.image_size() as u64).sum();
         let img_len: u64 = manifest.size as u64
+            + IMAGE_EXTENDED_TOC_BYTE_SIZE as u64
+            + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u64
             + manifest.fmc.image_size() as u64
-            + manifest.runtime.image_size() as u64;
+            + manifest.runtime.image_size() as u64
+            + data_len;
 
         if img_len > img_bundle_sz.into() {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE)?;
@@ -707,7 +1038,7 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
 
         let runtime_load_addr_start = manifest.runtime.load_addr;
         let (runtime_load_addr_end, overflow) =
-            runtime_load_addr_start.overflowing_add(manifest.runtime.image_size() - 1);
+            runtime_load_addr_start.overflowing_add(runtime_load_size - 1);
         if overflow {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDRESS_IMAGE_SIZE_ARITHMETIC_OVERFLOW)?;
         }
@@ -718,9 +1049,35 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_FMC_RUNTIME_LOAD_ADDR_OVERLAP)?;
         }
 
+        // Data follows the runtime section, in TOC order.
+        let mut prev_end = runtime_range.end;
+        for (i, entry) in data.iter().enumerate() {
+            if entry.image_type != u32::from(ImageTocEntryType::Data) {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_DATA_TYPE_INVALID)?;
+            }
+            if entry.image_size() == 0 {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_DATA_SIZE_ZERO)?;
+            }
+            let range = entry.image_range()?;
+            if range.start < prev_end {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_DATA_INCORRECT_ORDER)?;
+            }
+            if data[..i].iter().any(|prev| prev.id == entry.id) {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_DATA_ID_DUPLICATE)?;
+            }
+            prev_end = range.end;
+        }
+
+        // All data must fit in the area reserved for it.
+        if data_len > IMAGE_DATA_MAX_BYTE_SIZE.into() {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_DATA_TOO_LARGE)?;
+        }
+
         let info = ImageInfo {
             fmc: &manifest.fmc,
             runtime: &manifest.runtime,
+            runtime_load_size,
+            data,
         };
 
         Ok(info)
@@ -843,6 +1200,7 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
     fn verify_runtime(
         &mut self,
         verify_info: &ImageTocEntry,
+        load_size: u32,
     ) -> CaliptraResult<(ImageVerificationExeInfo, ImageSvnLogInfo)> {
         let range = verify_info.image_range()?;
 
@@ -873,7 +1231,7 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             || !self
                 .env
                 .iccm_range()
-                .contains(&(verify_info.load_addr + verify_info.size - 1))
+                .contains(&(verify_info.load_addr + load_size - 1))
         {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_INVALID)?;
         }
@@ -910,7 +1268,7 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             svn: verify_info.svn,
             effective_fuse_svn,
             digest: verify_info.digest,
-            size: verify_info.size,
+            size: load_size,
         };
 
         let log_info: ImageSvnLogInfo = ImageSvnLogInfo {
@@ -922,6 +1280,43 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok((info, log_info))
     }
 
+    /// Verify Data
+    #[cfg_attr(all(not(test), not(feature = "no-cfi")), cfi_impl_fn)]
+    fn verify_data(
+        &mut self,
+        entries: &[ImageTocEntry],
+    ) -> CaliptraResult<[ImageVerificationDataInfo; MAX_DATA_TOC_ENTRY_COUNT as usize]> {
+        let mut infos = [ImageVerificationDataInfo::default(); MAX_DATA_TOC_ENTRY_COUNT as usize];
+
+        for (entry, info) in entries.iter().zip(infos.iter_mut()) {
+            // Overflow is checked in verify_toc
+            let range = entry.image_range()?;
+
+            let actual = self
+                .env
+                .sha384_digest(range.start, range.len() as u32)
+                .map_err(|err| {
+                    self.env.set_fw_extended_error(err.into());
+                    CaliptraError::IMAGE_VERIFIER_ERR_DATA_DIGEST_FAILURE
+                })?;
+
+            if cfi_launder(entry.digest) != actual {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_DATA_DIGEST_MISMATCH)?;
+            } else {
+                caliptra_cfi_lib::cfi_assert_eq_12_words(&entry.digest, &actual);
+            }
+
+            *info = ImageVerificationDataInfo {
+                id: entry.id,
+                offset: entry.offset,
+                size: entry.size,
+                digest: entry.digest,
+            };
+        }
+
+        Ok(infos)
+    }
+
     /// Calculates the effective fuse SVN.
     ///
     /// If anti-rollback is disabled, the effective fuse-SVN is zero.
@@ -1015,7 +1410,11 @@ mod tests {
         let mut verifier = ImageVerifier::new(test_env);
         let preamble = ImagePreamble::default();
 
-        let result = verifier.verify_preamble(&preamble, ResetReason::UpdateReset);
+        let result = verifier.verify_preamble(
+            &preamble,
+            MldsaPreambleSource::None,
+            ResetReason::UpdateReset,
+        );
         assert!(result.is_ok());
     }
 
@@ -1084,7 +1483,11 @@ mod tests {
         let mut verifier = ImageVerifier::new(test_env);
         let preamble = ImagePreamble::default();
 
-        let result = verifier.verify_preamble(&preamble, ResetReason::UpdateReset);
+        let result = verifier.verify_preamble(
+            &preamble,
+            MldsaPreambleSource::None,
+            ResetReason::UpdateReset,
+        );
         assert!(result.is_ok());
     }
 
@@ -1092,12 +1495,30 @@ mod tests {
     fn test_manifest_marker() {
         let manifest = ImageManifest::default();
         let mut verifier = ImageVerifier::new(TestEnv::default());
-        let result = verifier.verify(&manifest, manifest.size, ResetReason::ColdReset);
+        let result = verifier.verify(
+            &manifest,
+            &ImageExtendedToc::default(),
+            MldsaPreambleSource::None,
+            manifest.size,
+            ResetReason::ColdReset,
+        );
         assert!(result.is_err());
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH)
         );
+
+        let result = verifier.verify_manifest(
+            &manifest,
+            &ImageExtendedToc::default(),
+            MldsaPreambleSource::None,
+            manifest.size,
+            ResetReason::UpdateReset,
+        );
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH)
+        );
     }
 
     #[test]
@@ -1108,12 +1529,30 @@ mod tests {
             ..Default::default()
         };
         let mut verifier = ImageVerifier::new(TestEnv::default());
-        let result = verifier.verify(&manifest, manifest.size, ResetReason::ColdReset);
+        let result = verifier.verify(
+            &manifest,
+            &ImageExtendedToc::default(),
+            MldsaPreambleSource::None,
+            manifest.size,
+            ResetReason::ColdReset,
+        );
         assert!(result.is_err());
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_SIZE_MISMATCH)
         );
+
+        let result = verifier.verify_manifest(
+            &manifest,
+            &ImageExtendedToc::default(),
+            MldsaPreambleSource::None,
+            manifest.size,
+            ResetReason::UpdateReset,
+        );
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_SIZE_MISMATCH)
+        );
     }
 
     #[test]
@@ -1124,7 +1563,8 @@ mod tests {
             ..Default::default()
         };
         let mut verifier = ImageVerifier::new(test_env);
-        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset);
+        let result =
+            verifier.verify_preamble(&preamble, MldsaPreambleSource::None, ResetReason::ColdReset);
         assert!(result.is_err());
         assert_eq!(
             result.err(),
@@ -1144,7 +1584,8 @@ mod tests {
         let mut verifier = ImageVerifier::new(test_env);
         let preamble = ImagePreamble::default();
 
-        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset);
+        let result =
+            verifier.verify_preamble(&preamble, MldsaPreambleSource::None, ResetReason::ColdReset);
         assert!(result.is_ok());
     }
 
@@ -1158,7 +1599,8 @@ mod tests {
         };
         let mut verifier = ImageVerifier::new(test_env);
         let preamble = ImagePreamble::default();
-        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset);
+        let result =
+            verifier.verify_preamble(&preamble, MldsaPreambleSource::None, ResetReason::ColdReset);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH)
@@ -1183,6 +1625,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&ecc_pubkey, &ecc_sig),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
@@ -1213,6 +1660,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&owner_ecc_pubkey, &owner_ecc_sig),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
@@ -1249,6 +1701,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&owner_ecc_pubkey, &owner_ecc_sig),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
@@ -1286,6 +1743,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&owner_ecc_pubkey, &owner_ecc_sig),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_lms_pub_key_revocation: Default::default(),
@@ -1319,6 +1781,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&owner_ecc_pubkey, &owner_ecc_sig),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
@@ -1353,6 +1820,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&owner_ecc_pubkey, &owner_ecc_sig),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
@@ -1387,6 +1859,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&owner_ecc_pubkey, &owner_ecc_sig),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
@@ -1420,6 +1897,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&OWNER_ECC_PUBKEY, &owner_ecc_sig),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
@@ -1456,6 +1938,11 @@ mod tests {
             vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
             owner_ecc_info: (&OWNER_ECC_PUBKEY, &OWNER_ECC_SIG),
             owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_mldsa_info: None,
+            vendor_mldsa_pub_key_idx: None,
+            vendor_mldsa_info: None,
+            vendor_mldsa_pub_key_revocation: None,
+            mldsa_preamble_digest: None,
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
@@ -1466,16 +1953,280 @@ mod tests {
         assert_eq!(toc_info.digest, &DUMMY_DATA);
     }
 
+    #[test]
+    #[cfg(not(feature = "hw-1.0"))]
+    fn test_vendor_mldsa_pk_idx_revoked() {
+        let test_env = TestEnv {
+            vendor_mldsa_pub_key_revocation: 0x2,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let preamble = ImagePreamble {
+            vendor_mldsa_pub_key_idx: 1,
+            ..Default::default()
+        };
+
+        let result = verifier.verify_vendor_mldsa_pk_idx(&preamble, ResetReason::ColdReset);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_REVOKED)
+        );
+
+        // The last key is never revoked
+        let mut verifier = ImageVerifier::new(TestEnv {
+            vendor_mldsa_pub_key_revocation: 0xf,
+            ..Default::default()
+        });
+        let preamble = ImagePreamble {
+            vendor_mldsa_pub_key_idx: VENDOR_MLDSA_KEY_COUNT - 1,
+            ..Default::default()
+        };
+        let result = verifier.verify_vendor_mldsa_pk_idx(&preamble, ResetReason::ColdReset);
+        assert_eq!(result, Ok((VENDOR_MLDSA_KEY_COUNT - 1, 0xf)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "hw-1.0"))]
+    fn test_vendor_mldsa_pk_idx_out_of_bounds() {
+        let mut verifier = ImageVerifier::new(TestEnv::default());
+        let preamble = ImagePreamble {
+            vendor_mldsa_pub_key_idx: VENDOR_MLDSA_KEY_COUNT,
+            ..Default::default()
+        };
+
+        let result = verifier.verify_vendor_mldsa_pk_idx(&preamble, ResetReason::ColdReset);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_OUT_OF_BOUNDS)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "hw-1.0"))]
+    fn test_vendor_mldsa_pk_idx_mismatch_update_rst() {
+        let mut verifier = ImageVerifier::new(TestEnv::default());
+        let preamble = ImagePreamble {
+            vendor_mldsa_pub_key_idx: 2,
+            ..Default::default()
+        };
+
+        let result = verifier.verify_vendor_mldsa_pk_idx(&preamble, ResetReason::UpdateReset);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_MLDSA_PUB_KEY_IDX_MISMATCH)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "hw-1.0"))]
+    fn test_vendor_mldsa_pk_digest() {
+        let test_env = TestEnv {
+            digest: DUMMY_DATA,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut preamble = ImagePreamble::default();
+
+        let result = verifier.verify_vendor_mldsa_pk_digest(&preamble, 1);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_DIGEST_MISMATCH)
+        );
+
+        preamble.vendor_pub_keys.mldsa_pub_key_digests[1] = DUMMY_DATA;
+        assert!(verifier.verify_vendor_mldsa_pk_digest(&preamble, 1).is_ok());
+    }
+
+    #[test]
+    #[cfg(not(feature = "hw-1.0"))]
+    fn test_owner_mldsa_pk_digest() {
+        let test_env = TestEnv {
+            digest: DUMMY_DATA,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut preamble = ImagePreamble::default();
+
+        let result = verifier.verify_owner_mldsa_pk_digest(&preamble);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_PUB_KEY_DIGEST_MISMATCH)
+        );
+
+        preamble.owner_pub_keys.mldsa_pub_key_digest = DUMMY_DATA;
+        assert!(verifier.verify_owner_mldsa_pk_digest(&preamble).is_ok());
+    }
+
+    #[test]
+    #[cfg(not(feature = "hw-1.0"))]
+    fn test_mldsa_preamble() {
+        let mut verifier = ImageVerifier::new(TestEnv {
+            digest: DUMMY_DATA,
+            mldsa_verify_enabled: true,
+            ..Default::default()
+        });
+        let mut preamble = ImagePreamble {
+            vendor_mldsa_pub_key_idx: 1,
+            ..Default::default()
+        };
+        let mldsa = ImageMldsaPreamble::default();
+
+        // ML-DSA is enabled in the fuses, so a preamble is required.
+        assert_eq!(
+            verifier
+                .verify_mldsa_preamble(&preamble, MldsaPreambleSource::None, ResetReason::ColdReset)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_MLDSA_PREAMBLE_MISSING)
+        );
+        assert_eq!(
+            verifier
+                .verify_mldsa_preamble(
+                    &preamble,
+                    MldsaPreambleSource::RomVerified(ImageDigest::default()),
+                    ResetReason::ColdReset
+                )
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_MLDSA_PREAMBLE_MISSING)
+        );
+
+        assert_eq!(
+            verifier
+                .verify_mldsa_preamble(
+                    &preamble,
+                    MldsaPreambleSource::Image(&mldsa),
+                    ResetReason::ColdReset
+                )
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_DIGEST_MISMATCH)
+        );
+
+        preamble.vendor_pub_keys.mldsa_pub_key_digests[1] = DUMMY_DATA;
+        assert_eq!(
+            verifier
+                .verify_mldsa_preamble(
+                    &preamble,
+                    MldsaPreambleSource::Image(&mldsa),
+                    ResetReason::ColdReset
+                )
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_PUB_KEY_DIGEST_MISMATCH)
+        );
+
+        preamble.owner_pub_keys.mldsa_pub_key_digest = DUMMY_DATA;
+        let info = verifier
+            .verify_mldsa_preamble(
+                &preamble,
+                MldsaPreambleSource::Image(&mldsa),
+                ResetReason::ColdReset,
+            )
+            .unwrap();
+        assert_eq!(info.vendor_pub_key_idx, Some(1));
+        assert_eq!(info.preamble_digest, Some(DUMMY_DATA));
+        assert!(info.vendor.is_some() && info.owner.is_some());
+
+        // Only the key index is checked against a preamble verified by the ROM.
+        let info = verifier
+            .verify_mldsa_preamble(
+                &preamble,
+                MldsaPreambleSource::RomVerified(DUMMY_DATA),
+                ResetReason::ColdReset,
+            )
+            .unwrap();
+        assert_eq!(info.vendor_pub_key_idx, Some(1));
+        assert_eq!(info.preamble_digest, Some(DUMMY_DATA));
+        assert!(info.vendor.is_none() && info.owner.is_none());
+
+        // Nothing is required when ML-DSA is disabled in the fuses.
+        let mut verifier = ImageVerifier::new(TestEnv::default());
+        let info = verifier
+            .verify_mldsa_preamble(&preamble, MldsaPreambleSource::None, ResetReason::ColdReset)
+            .unwrap();
+        assert!(info.vendor_pub_key_idx.is_none() && info.preamble_digest.is_none());
+    }
+
+    #[test]
+    #[cfg(not(feature = "hw-1.0"))]
+    fn test_header_mldsa() {
+        let header = ImageHeader {
+            vendor_mldsa_pub_key_idx: 1,
+            ..Default::default()
+        };
+        let vendor_mldsa_pubkey = ImageMldsaPubKey::default();
+        let vendor_mldsa_sig = ImageMldsaSignature([0xa5a5a5a5; MLDSA87_SIGNATURE_WORD_SIZE]);
+        let owner_mldsa_pubkey = ImageMldsaPubKey::default();
+        let owner_mldsa_sig = ImageMldsaSignature([0x5a5a5a5a; MLDSA87_SIGNATURE_WORD_SIZE]);
+        let header_info = |vendor_mldsa_pub_key_idx| HeaderInfo {
+            vendor_ecc_pub_key_idx: 0,
+            vendor_lms_pub_key_idx: None,
+            vendor_ecc_info: (&VENDOR_ECC_PUBKEY, &VENDOR_ECC_SIG),
+            vendor_lms_info: None,
+            owner_ecc_info: (&OWNER_ECC_PUBKEY, &OWNER_ECC_SIG),
+            owner_lms_info: None,
+            owner_mldsa_info: Some((&owner_mldsa_pubkey, &owner_mldsa_sig)),
+            vendor_mldsa_pub_key_idx: Some(vendor_mldsa_pub_key_idx),
+            vendor_mldsa_info: Some((&vendor_mldsa_pubkey, &vendor_mldsa_sig)),
+            vendor_mldsa_pub_key_revocation: Some(0),
+            mldsa_preamble_digest: None,
+            owner_pub_keys_digest: ImageDigest::default(),
+            owner_pub_keys_digest_in_fuses: false,
+            vendor_ecc_pub_key_revocation: Default::default(),
+            vendor_lms_pub_key_revocation: None,
+        };
+
+        let mut verifier = ImageVerifier::new(TestEnv {
+            verify_result: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            verifier.verify_header(&header, &header_info(1)).err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID)
+        );
+
+        let mut verifier = ImageVerifier::new(TestEnv {
+            verify_result: true,
+            verify_mldsa_result: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            verifier.verify_header(&header, &header_info(0)).err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_MISMATCH)
+        );
+        assert!(verifier.verify_header(&header, &header_info(1)).is_ok());
+
+        // Only the vendor signature is valid
+        let mut verifier = ImageVerifier::new(TestEnv {
+            verify_result: true,
+            verify_mldsa_result: true,
+            valid_mldsa_sig: Some(vendor_mldsa_sig),
+            ..Default::default()
+        });
+        assert_eq!(
+            verifier.verify_header(&header, &header_info(1)).err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_SIGNATURE_INVALID)
+        );
+    }
+
     #[test]
     fn test_toc_incorrect_length() {
         let manifest = ImageManifest::default();
+        let toc = ImageExtendedToc::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT / 2,
+            len: MIN_TOC_ENTRY_COUNT - 1,
             digest: &ImageDigest::default(),
         };
-        let result = verifier.verify_toc(&manifest, &toc_info, manifest.size);
+        let result = verifier.verify_toc(&manifest, &toc, &toc_info, manifest.size);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)
+        );
+
+        let toc_info = TocInfo {
+            len: MAX_TOC_ENTRY_COUNT + 1,
+            digest: &ImageDigest::default(),
+        };
+        let result = verifier.verify_toc(&manifest, &toc, &toc_info, manifest.size);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)
@@ -1485,13 +2236,14 @@ mod tests {
     #[test]
     fn test_toc_digest_mismatch() {
         let manifest = ImageManifest::default();
+        let toc = ImageExtendedToc::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &DUMMY_DATA,
         };
-        let result = verifier.verify_toc(&manifest, &toc_info, manifest.size);
+        let result = verifier.verify_toc(&manifest, &toc, &toc_info, manifest.size);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_TOC_DIGEST_MISMATCH)
@@ -1501,10 +2253,11 @@ mod tests {
     #[test]
     fn test_toc_fmc_rt_overlap() {
         let mut manifest = ImageManifest::default();
+        let toc = ImageExtendedToc::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1517,8 +2270,13 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1534,8 +2292,13 @@ mod tests {
         manifest.runtime.size = 200;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1551,8 +2314,13 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1568,8 +2336,13 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1585,8 +2358,13 @@ mod tests {
         manifest.fmc.size = 200;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1602,8 +2380,13 @@ mod tests {
         manifest.runtime.size = 200;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1619,8 +2402,13 @@ mod tests {
         manifest.fmc.size = 30;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1631,10 +2419,11 @@ mod tests {
     #[test]
     fn test_size_failure() {
         let mut manifest = ImageManifest::default();
+        let toc = ImageExtendedToc::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1643,7 +2432,7 @@ mod tests {
         manifest.fmc.size = 0;
         manifest.runtime.offset = 100;
         manifest.runtime.size = 200;
-        let result = verifier.verify_toc(&manifest, &toc_info, 500);
+        let result = verifier.verify_toc(&manifest, &toc, &toc_info, 500);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_FMC_SIZE_ZERO)
@@ -1654,7 +2443,7 @@ mod tests {
         manifest.fmc.size = 100;
         manifest.runtime.offset = 100;
         manifest.runtime.size = 0;
-        let result = verifier.verify_toc(&manifest, &toc_info, 500);
+        let result = verifier.verify_toc(&manifest, &toc, &toc_info, 500);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_SIZE_ZERO)
@@ -1666,7 +2455,7 @@ mod tests {
         manifest.fmc.size = 100;
         manifest.runtime.offset = 100;
         manifest.runtime.size = 200;
-        let result = verifier.verify_toc(&manifest, &toc_info, 100);
+        let result = verifier.verify_toc(&manifest, &toc, &toc_info, 100);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE)
@@ -1676,10 +2465,11 @@ mod tests {
     #[test]
     fn test_size_success() {
         let mut manifest = ImageManifest::default();
+        let toc = ImageExtendedToc::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1693,20 +2483,238 @@ mod tests {
         manifest.runtime.load_addr = 0x2000;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
 
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_toc_compressed_runtime() {
+        let mut manifest = ImageManifest::default();
+        let mut toc = ImageExtendedToc::default();
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let toc_info = TocInfo {
+            len: MIN_TOC_ENTRY_COUNT,
+            digest: &ImageDigest::default(),
+        };
+
+        manifest.fmc.offset = 0;
+        manifest.fmc.size = 100;
+        manifest.fmc.load_addr = 0x1000;
+        manifest.runtime.image_type = ImageTocEntryType::CompressedExecutable.into();
+        manifest.runtime.offset = 100;
+        manifest.runtime.size = 50;
+        toc.runtime_expansion.size = 200;
+        manifest.runtime.load_addr = 0x2000;
+        let img_len = manifest.size
+            + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+            + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+            + manifest.fmc.image_size()
+            + manifest.runtime.image_size();
+        assert!(verifier
+            .verify_toc(&manifest, &toc, &toc_info, img_len)
+            .is_ok());
+
+        // The bundle only needs to hold the compressed bytes.
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &toc, &toc_info, img_len - 1)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE)
+        );
+
+        // The expanded runtime must not overlap the FMC in ICCM.
+        manifest.runtime.load_addr = 0x1000 - 100;
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &toc, &toc_info, img_len)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_FMC_RUNTIME_LOAD_ADDR_OVERLAP)
+        );
+        manifest.runtime.load_addr = 0x2000;
+
+        toc.runtime_expansion.size = 0;
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &toc, &toc_info, img_len)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_SIZE_ZERO)
+        );
+        toc.runtime_expansion.size = 200;
+
+        // Only the runtime may be compressed.
+        manifest.fmc.image_type = ImageTocEntryType::CompressedExecutable.into();
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &toc, &toc_info, img_len)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_FMC_TYPE_INVALID)
+        );
+    }
+
+    #[test]
+    fn test_toc_data() {
+        let mut manifest = ImageManifest::default();
+        let mut toc = ImageExtendedToc::default();
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let toc_info = TocInfo {
+            len: MIN_TOC_ENTRY_COUNT + 2,
+            digest: &ImageDigest::default(),
+        };
+
+        manifest.fmc.offset = 0;
+        manifest.fmc.size = 100;
+        manifest.fmc.load_addr = 0x1000;
+        manifest.runtime.offset = 100;
+        manifest.runtime.size = 200;
+        manifest.runtime.load_addr = 0x2000;
+        toc.data[0] = ImageTocEntry {
+            id: 1,
+            image_type: ImageTocEntryType::Data.into(),
+            offset: 300,
+            size: 16,
+            ..Default::default()
+        };
+        toc.data[1] = ImageTocEntry {
+            id: 2,
+            image_type: ImageTocEntryType::Data.into(),
+            offset: 316,
+            size: 32,
+            ..Default::default()
+        };
+        let bundle_size = manifest.size
+            + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+            + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+            + 348;
+
+        let info = verifier
+            .verify_toc(&manifest, &toc, &toc_info, bundle_size)
+            .unwrap();
+        assert_eq!(info.data.len(), 2);
+
+        // Data entries past toc_len are ignored.
+        let result = verifier.verify_toc(
+            &manifest,
+            &toc,
+            &TocInfo {
+                len: MIN_TOC_ENTRY_COUNT + 1,
+                digest: &ImageDigest::default(),
+            },
+            bundle_size,
+        );
+        assert_eq!(result.unwrap().data.len(), 1);
+
+        let mut bad = toc;
+        bad.data[1].image_type = ImageTocEntryType::Executable.into();
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &bad, &toc_info, bundle_size)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_DATA_TYPE_INVALID)
+        );
+
+        let mut bad = toc;
+        bad.data[1].size = 0;
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &bad, &toc_info, bundle_size)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_DATA_SIZE_ZERO)
+        );
+
+        // Data overlaps the runtime
+        let mut bad = toc;
+        bad.data[0].offset = 299;
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &bad, &toc_info, bundle_size)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_DATA_INCORRECT_ORDER)
+        );
+
+        // Data entries out of order
+        let mut bad = toc;
+        bad.data[0].offset = 332;
+        bad.data[1].offset = 300;
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &bad, &toc_info, bundle_size)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_DATA_INCORRECT_ORDER)
+        );
+
+        let mut bad = toc;
+        bad.data[1].id = 1;
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &bad, &toc_info, bundle_size)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_DATA_ID_DUPLICATE)
+        );
+
+        let mut bad = toc;
+        bad.data[1].size = IMAGE_DATA_MAX_BYTE_SIZE;
+        assert_eq!(
+            verifier
+                .verify_toc(
+                    &manifest,
+                    &bad,
+                    &toc_info,
+                    bundle_size + IMAGE_DATA_MAX_BYTE_SIZE
+                )
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_DATA_TOO_LARGE)
+        );
+
+        assert_eq!(
+            verifier
+                .verify_toc(&manifest, &toc, &toc_info, bundle_size - 1)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_data_digest() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut entries = [ImageTocEntry {
+            id: 7,
+            image_type: ImageTocEntryType::Data.into(),
+            offset: 100,
+            size: 16,
+            ..Default::default()
+        }];
+
+        let info = verifier.verify_data(&entries).unwrap();
+        assert_eq!(info[0].id, 7);
+        assert_eq!(info[0].offset, 100);
+        assert_eq!(info[0].size, 16);
+
+        entries[0].digest = DUMMY_DATA;
+        assert_eq!(
+            verifier.verify_data(&entries).err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_DATA_DIGEST_MISMATCH)
+        );
+    }
+
     #[test]
     fn test_toc_fmc_rt_order() {
         let mut manifest = ImageManifest::default();
+        let toc = ImageExtendedToc::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1718,8 +2726,13 @@ mod tests {
         manifest.fmc.size = 200;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1730,10 +2743,11 @@ mod tests {
     #[test]
     fn test_fmc_rt_load_address_range_overlap() {
         let mut manifest = ImageManifest::default();
+        let toc = ImageExtendedToc::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1751,8 +2765,13 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
         assert_eq!(
             result.err(),
@@ -1768,8 +2787,13 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &toc,
             &toc_info,
-            manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
+            manifest.size
+                + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+                + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+                + manifest.fmc.image_size()
+                + manifest.runtime.image_size(),
         );
 
         assert_eq!(
@@ -1850,7 +2874,7 @@ mod tests {
             digest: DUMMY_DATA,
             ..Default::default()
         };
-        let result = verifier.verify_runtime(&verify_info);
+        let result = verifier.verify_runtime(&verify_info, verify_info.size);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH)
@@ -1868,7 +2892,7 @@ mod tests {
             ..Default::default()
         };
 
-        let result = verifier.verify_runtime(&verify_info);
+        let result = verifier.verify_runtime(&verify_info, verify_info.size);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_INVALID)
@@ -1881,8 +2905,23 @@ mod tests {
             ..Default::default()
         };
 
-        let result = verifier.verify_runtime(&verify_info);
+        let result = verifier.verify_runtime(&verify_info, verify_info.size);
         assert_eq!(result.err(), None);
+
+        // A compressed runtime must fit in ICCM once inflated.
+        let verify_info = ImageTocEntry {
+            image_type: ImageTocEntryType::CompressedExecutable.into(),
+            load_addr: ICCM_ORG,
+            entry_point: ICCM_ORG,
+            size: 100,
+            ..Default::default()
+        };
+
+        let result = verifier.verify_runtime(&verify_info, ICCM_SIZE + 1);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_INVALID)
+        );
     }
 
     #[test]
@@ -1896,7 +2935,7 @@ mod tests {
             size: 100,
             ..Default::default()
         };
-        let result = verifier.verify_runtime(&verify_info);
+        let result = verifier.verify_runtime(&verify_info, verify_info.size);
         assert!(result.is_ok());
         let (info, _log_info) = result.unwrap();
         assert_eq!(info.load_addr, ICCM_ORG);
@@ -1910,11 +2949,16 @@ mod tests {
         fmc_digest: ImageDigest,
         verify_result: bool,
         verify_lms_result: bool,
+        verify_mldsa_result: bool,
+        /// If set, only this ML-DSA signature verifies successfully
+        valid_mldsa_sig: Option<ImageMldsaSignature>,
         vendor_pub_key_digest: ImageDigest,
         vendor_ecc_pub_key_revocation: VendorPubKeyRevocation,
         vendor_lms_pub_key_revocation: u32,
+        vendor_mldsa_pub_key_revocation: u32,
         owner_pub_key_digest: ImageDigest,
         lifecycle: Lifecycle,
+        mldsa_verify_enabled: bool,
     }
 
     impl Default for TestEnv {
@@ -1924,11 +2968,15 @@ mod tests {
                 fmc_digest: ImageDigest::default(),
                 verify_result: false,
                 verify_lms_result: false,
+                verify_mldsa_result: false,
+                valid_mldsa_sig: None,
                 vendor_pub_key_digest: ImageDigest::default(),
                 vendor_ecc_pub_key_revocation: VendorPubKeyRevocation::default(),
                 vendor_lms_pub_key_revocation: 0,
+                vendor_mldsa_pub_key_revocation: 0,
                 owner_pub_key_digest: ImageDigest::default(),
                 lifecycle: Lifecycle::Unprovisioned,
+                mldsa_verify_enabled: false,
             }
         }
     }
@@ -1964,6 +3012,23 @@ mod tests {
             }
         }
 
+        fn mldsa87_verify(
+            &mut self,
+            _digest: &ImageDigest,
+            _pub_key: &ImageMldsaPubKey,
+            sig: &ImageMldsaSignature,
+        ) -> CaliptraResult<LEArray4x16> {
+            let valid = self.verify_mldsa_result
+                && self
+                    .valid_mldsa_sig
+                    .map_or(true, |valid_sig| valid_sig == *sig);
+            if valid {
+                Ok(LEArray4x16::new(sig.0[..16].try_into().unwrap()))
+            } else {
+                Ok(LEArray4x16::new([0xDEADBEEF; 16]))
+            }
+        }
+
         fn vendor_pub_key_digest(&self) -> ImageDigest {
             self.vendor_pub_key_digest
         }
@@ -1976,6 +3041,10 @@ mod tests {
             self.vendor_lms_pub_key_revocation
         }
 
+        fn vendor_mldsa_pub_key_revocation(&self) -> u32 {
+            self.vendor_mldsa_pub_key_revocation
+        }
+
         fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
             self.owner_pub_key_digest
         }
@@ -1996,6 +3065,10 @@ mod tests {
             0
         }
 
+        fn vendor_mldsa_pub_key_idx_dv(&self) -> u32 {
+            0
+        }
+
         fn owner_pub_key_digest_dv(&self) -> ImageDigest {
             self.owner_pub_key_digest
         }
@@ -2023,6 +3096,10 @@ mod tests {
             true
         }
 
+        fn mldsa_verify_enabled(&self) -> bool {
+            self.mldsa_verify_enabled
+        }
+
         fn set_fw_extended_error(&mut self, _err: u32) {}
     }
 }
@@ -18,6 +18,8 @@ mod ecc384_kat;
 mod hmac384kdf_kat;
 mod kats_env;
 mod lms_kat;
+#[cfg(not(feature = "hw-1.0"))]
+mod ml_dsa87_kat;
 mod sha1_kat;
 mod sha256_kat;
 mod sha2_512_384acc_kat;
@@ -28,6 +30,8 @@ pub use ecc384_kat::Ecc384Kat;
 pub use hmac384kdf_kat::Hmac384KdfKat;
 pub use kats_env::KatsEnv;
 pub use lms_kat::LmsKat;
+#[cfg(not(feature = "hw-1.0"))]
+pub use ml_dsa87_kat::MlDsa87Kat;
 pub use sha1_kat::Sha1Kat;
 pub use sha256_kat::Sha256Kat;
 pub use sha2_512_384acc_kat::Sha2_512_384AccKat;
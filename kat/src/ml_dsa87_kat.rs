@@ -0,0 +1,294 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    ml_dsa87_kat.rs
+
+Abstract:
+
+    File contains the Known Answer Tests (KAT) for ML-DSA-87 cryptography operations.
+
+--*/
+
+use caliptra_drivers::{
+    CaliptraError, CaliptraResult, LEArray4xN, MlDsa87, MlDsa87PubKey, MlDsa87Signature, Trng,
+};
+
+const KEY_GEN_PUB_KEY: MlDsa87PubKey = LEArray4xN([
+    0x65cb5f2f, 0x77e4b9c3, 0x89a16fde, 0x93051d2b, 0xf4d2833c, 0xef429f16, 0x4f15a72f, 0x0c42c3af,
+    0xea59b165, 0xbfb1e851, 0xbcc1a964, 0x8d471b21, 0x13e30bd6, 0xa8e38711, 0x0aa58eec, 0x0a5efb2c,
+    0xc27be22f, 0x95934e4f, 0xc830c501, 0xf77cae91, 0xd0ed668c, 0xae505f22, 0x6fa26323, 0x80e1ca43,
+    0xfefa3fbd, 0xcd55bc12, 0xb47a2791, 0xe4d84ddf, 0x79452e46, 0x4a18b31a, 0x7405ed0e, 0x3a92f5b0,
+    0xa57b7ef9, 0xb60df6c7, 0xb1291ecb, 0x2a85e342, 0xee6dec11, 0x26ec8be6, 0x90fbfaf9, 0xb7db002f,
+    0x617ff2e0, 0xe7ebde66, 0x7d8050e2, 0x12940bbb, 0x6cf0e006, 0x7423187c, 0x47f4fa42, 0xacc65b3f,
+    0x233c553b, 0x40ff1eaa, 0x63c04130, 0x718f1771, 0xfd5e806e, 0x88637082, 0x257c5a64, 0x91fea4ff,
+    0x6c0eb582, 0x49945cdf, 0xe102547b, 0x2670ad4c, 0x07b1c262, 0xf1e9035c, 0x0383f23a, 0xa04c8d4a,
+    0x6e28e8a7, 0x630d9bbd, 0xf8b3337f, 0x98d1c15c, 0x8fb4b79e, 0x7e33ebe6, 0x2941f878, 0x2a376ddf,
+    0xdb95784b, 0xb0ce4311, 0xae727a5d, 0x4eff8ddf, 0x05a969f2, 0xe99fd0b1, 0xd066b33c, 0x3b06a7e3,
+    0x3bc4bb24, 0x463e04dc, 0xb3e5950a, 0xe4c2c69b, 0x1044fbca, 0x178bf389, 0x835de457, 0x3c694712,
+    0xf2723ac0, 0xc1d45e03, 0x14227ca9, 0xae5595e4, 0x12703013, 0x0bbe142b, 0x79b5dbe9, 0x7857b859,
+    0x28fa7ef3, 0x7c46d6a6, 0xc31e5fe7, 0x1723f4bb, 0xd043f7f9, 0xb1e4b6f6, 0xe779c440, 0x5608a4f9,
+    0x8e10d412, 0xb305ad19, 0xee071fce, 0xfda45456, 0xc4bfcf58, 0xe9a76d70, 0xe2ad6680, 0x08d39d30,
+    0xf681f0b3, 0xce64a2a8, 0x0937d7ba, 0x73a75a23, 0x6f12b163, 0x5fb52bda, 0x071bb63e, 0xeff8fb1e,
+    0xf341dcac, 0x1abaa02e, 0x1b675cad, 0x9a48de02, 0xc7c2b778, 0xf43defa3, 0xdac511e1, 0x86af5086,
+    0x7c7c7f9d, 0xe7d63e10, 0xfbc5c3aa, 0xde6a6aaf, 0x398e9483, 0x3e9255d7, 0xd1941684, 0xf4f274c1,
+    0x0044d039, 0x6df74e7c, 0x78ac071e, 0x0325b979, 0x2346cc42, 0x2df4d17f, 0x8086ce4e, 0xd348bd5f,
+    0xfcdf3419, 0x04b18c00, 0x95c8d88f, 0x8d59d18c, 0x1539caa7, 0xc285c7f8, 0x8f5b9099, 0x9f40a3f8,
+    0xebcb1523, 0xec17f87a, 0x08a724e3, 0x96530a22, 0x1bfd3fdf, 0xc563d7df, 0x9d8d0df1, 0xd9ca0732,
+    0x920cce1f, 0xf7d15fde, 0xc39174a4, 0xad370d5e, 0xd7d68f3b, 0x1d96b579, 0xea7a8672, 0x8d04ccda,
+    0x0628ab9f, 0x36c3a3f5, 0x08aad275, 0xe971ee64, 0x12669de9, 0xbd75e0f8, 0x6ae95910, 0x804cb7ef,
+    0xff69b10a, 0x6db3dd8b, 0xb9a8326e, 0xd39a4cf3, 0xeb5f5c96, 0x2774fe7b, 0x197e65e6, 0x1e216ba8,
+    0xba030822, 0x132623b7, 0x6bf71da0, 0xb71c62e8, 0xbf7ad138, 0x426c67de, 0x06fcb113, 0xe20ba24c,
+    0x5a8931a9, 0x496ff40c, 0x7bfef719, 0xca89854e, 0x9248e005, 0x7b5688c6, 0xbd3994c4, 0x9ad86aca,
+    0xd69e0652, 0x335a6d6c, 0x03d07961, 0x512fe724, 0x41418a76, 0x4cb7289b, 0x5c370c17, 0x2bc3fc27,
+    0x4066a5eb, 0x5216101c, 0x1b9cafed, 0x19aeeed5, 0xe3302396, 0x0e9094d9, 0xf11c43fc, 0x6340401c,
+    0xbbb7e6f3, 0x96bb5d19, 0x814d3813, 0x40eff3a3, 0xa92a5891, 0x59b38949, 0xdbe1bc32, 0x272fa04a,
+    0xcdbfba9d, 0xc4df7a87, 0xa51c723b, 0xfab1ca31, 0x6160f30d, 0x3a51faff, 0x334db9ea, 0x5af4cae2,
+    0xd68686a1, 0xcdea7b8b, 0xc29bcd99, 0xb873c676, 0x33877fe8, 0xb6087245, 0x28857f94, 0x19372f45,
+    0x7c650132, 0xcbc71eb4, 0x516e1f62, 0x01cc55a2, 0x6f482768, 0x3558ff22, 0x318bfb18, 0x1645cc70,
+    0xce85bb67, 0xbaa39c11, 0x98365a0f, 0x8a5627de, 0x5c5bbf46, 0xfab5398f, 0xbf90250f, 0x3ec2b019,
+    0x19477a09, 0x8395f136, 0xb1469cf6, 0x68f5d2c4, 0x9e5ebe65, 0xd0e32cfe, 0x9e3f2a07, 0xf3c65610,
+    0xc9b22053, 0xff1eebe7, 0xa4c4ae96, 0x443b15a0, 0x7eadd04b, 0x109cdfe1, 0x877d8b25, 0xe3741e6d,
+    0x71c48fbd, 0x8f002679, 0xd10d7098, 0x33c8399c, 0x7cd0c363, 0xe9baf429, 0xc73d56e2, 0xbf173681,
+    0x3088ccd6, 0xbcb8cb45, 0xe6f1837b, 0xfb246563, 0x98169c6d, 0x166bbb8b, 0x4dc54b05, 0x9c26ba37,
+    0x38db05ef, 0x69bcac7d, 0x9e185d4c, 0x525c2ccc, 0x91aef17a, 0xde3a1af5, 0x80ee5b11, 0x2e465fe7,
+    0x4fd68344, 0x8f94174a, 0x1e1f18c0, 0x21a271e9, 0x078faf69, 0xe867fd8f, 0x8c9a8272, 0xd29e0ea2,
+    0x40f21a45, 0x54146f31, 0x78935b27, 0xde274a9a, 0x31ea3826, 0xf30d0310, 0x3a756627, 0x7b2af26f,
+    0x03517625, 0x199e4cac, 0x300efee4, 0x89613229, 0xcda39ec1, 0xb44d69c6, 0x31d81dfe, 0x7a3688c9,
+    0x1eda89cc, 0xb87ac1aa, 0x664d6d77, 0xfa007da0, 0x97d1718d, 0xa6c38544, 0x5e6a91b8, 0x5c2880b1,
+    0xbd713820, 0x417a2e25, 0x8e46e2a9, 0x3a87eda2, 0x9fb166a7, 0x159408d5, 0x4e72cebd, 0x9cb43611,
+    0xb443d9fa, 0x39c77bad, 0x3b595358, 0xcd945c32, 0x802757c1, 0x5597b788, 0x7d4494e3, 0x33219f7b,
+    0x1799360e, 0xff4a575a, 0xdb8912eb, 0x77317ad5, 0x7b7712a1, 0x13a7d379, 0xac0f60ee, 0xc8426687,
+    0x9309ba96, 0x186af3b3, 0x62474a77, 0x932ebdc7, 0xb6a8bfde, 0x59020e0d, 0xc8a84513, 0x3e754342,
+    0x21d8ec59, 0x809af64d, 0x938054d9, 0xb2ecf15b, 0xec9fb904, 0xf6912d4c, 0x066ee466, 0xdf9b85d5,
+    0xcd4d652d, 0x4ae35ecd, 0x1bd2d766, 0x536df584, 0x1d0bd438, 0x0123de6c, 0x1120629d, 0x337ca8dd,
+    0x8ce66a39, 0x7810e7aa, 0x6febf792, 0x76bf00fd, 0x2c3e5eb7, 0x037aa2a5, 0x33133910, 0xf6c940ae,
+    0xc839a465, 0x8341299f, 0x7786ae26, 0x89976d86, 0x45aa5961, 0x07db3637, 0xe878813a, 0x7f7870d3,
+    0xcedd78fc, 0x7b1eabac, 0xd4e354f0, 0x6a1ee18e, 0x71e32a8c, 0x2aac29ef, 0x328ec7d8, 0xde7ad272,
+    0x79aa3800, 0xf85d5009, 0xdade7c1e, 0x9bd729a7, 0x0841c540, 0x78ab8608, 0xedbd9aa4, 0xfb5a3753,
+    0xebf0ea3f, 0xf9527359, 0x8d8b8ac6, 0xb1819a7b, 0x4bc9b468, 0xbafbde10, 0xc59533a8, 0xe08c5767,
+    0x3de57228, 0xb0c6fd39, 0x427bcdbc, 0x2449489a, 0x9057321f, 0x690dda53, 0x7a0f9e56, 0x30389896,
+    0x06e735ab, 0xecb020bf, 0x60dfe23b, 0x9628064f, 0x4b8caf08, 0x3777e95c, 0x3ec23930, 0xc6ef0fb0,
+    0x50261672, 0xc95867ba, 0x184e6456, 0xa9302a7e, 0xb0fb0605, 0x2bffa532, 0xd90bd0ef, 0xd7afa347,
+    0x5159ec7a, 0xbd1ea8f8, 0xe8d9097f, 0x071903c2, 0x3618ba71, 0x136f4316, 0x61a15a79, 0xb11fe8d0,
+    0x1be7f5f6, 0x58caba59, 0x3a651676, 0x738369c9, 0x5a831edf, 0x4733f867, 0x8550b937, 0xd1cb7634,
+    0x3e13d188, 0x619e57c6, 0x68fe72ef, 0x15500693, 0xd54c693d, 0x14fa9920, 0x202deae8, 0x04eac271,
+    0x015cce27, 0x2c71cb16, 0xd29f5690, 0x4f0672ec, 0xac4d2421, 0xa4886392, 0x6ac20c0a, 0xf4247b90,
+    0xe843ccc9, 0x4616fbb7, 0x233f2454, 0xd3c5c8bb, 0x2059af54, 0x132ff4e6, 0x2f88b729, 0xb86dd9d5,
+    0x5d93bffb, 0xf54b5817, 0xbe2f2a26, 0xd82cd6e0, 0xef10f5c2, 0x04283dfd, 0x906de602, 0xec3cc166,
+    0xb14fd4cd, 0xc6bcf40a, 0x6c924c6e, 0x4c571426, 0x04e3f7a3, 0xee3b5eaa, 0x9cb3ebab, 0x499eff38,
+    0x32078b6f, 0xe43b70ce, 0xdc6f31fe, 0x90c1fb61, 0x0db5c2f4, 0x7c37d83d, 0x180f64c6, 0x1dd96814,
+    0xd75bee65, 0x15e081ec, 0x17ceb3ab, 0x881fef16, 0x52679c89, 0x84314608, 0x89e8f3d1, 0x8eee4916,
+    0x8aa1fa3b, 0xf09eb7ee, 0x4426a191, 0x6aaf9fa7, 0xe1803785, 0x51d31d88, 0x61fcb849, 0x68d5fb25,
+    0xf80dfdd5, 0xa387d187, 0x1955834c, 0xcb2e0a19, 0x43953f42, 0x2ef616d6, 0x310c0176, 0x47e6f13b,
+    0xfac543bf, 0x9eb917d0, 0xa6f89ff4, 0xb25838be, 0xdab8fcce, 0x8957a0f9, 0xfab89a53, 0xa3a06b45,
+    0xf54797f2, 0x884d03df, 0xc9b0d445, 0xbaf88c6b, 0x8055584d, 0x845cd1ff, 0x05cb0664, 0xd07fc67c,
+    0xc0c2c851, 0x99afc0d1, 0xbbc41640, 0x90dc8d11, 0x15bef148, 0x088b6b24, 0xcf8eda51, 0x7e7ab933,
+    0x5220ddf6, 0x83ffb37a, 0x3306cad4, 0x879a9643, 0x1e21e0ff, 0x46ca7e97, 0x249df1b9, 0x67d0c987,
+    0x1a1721a3, 0xe6f40845, 0x525c6d1c, 0x79ec5c2e, 0x26170535, 0xb4ccf4a6, 0x113f4859, 0xe5b00a76,
+    0xd3ec517e, 0xd88a2f50, 0x93e284eb, 0xe9efbad0, 0x395aa62d, 0x1479cd57, 0xcefb0265, 0x97b71f7c,
+    0x83bbd15c, 0xd5254bc8, 0xa336e705, 0x2edfd404, 0x04eea0c4, 0x35ec0d00, 0x1d7da2f6, 0x47ff616f,
+    0x50fd0129, 0x46a91122, 0xa21994b1, 0xb85e323f, 0xe805d9f6, 0xdd73ee7e, 0x41142acb, 0x8335acd7,
+    0x24ee0252, 0xe9323917, 0x484fb24e, 0xcc251c35, 0x8af54348, 0x97e70709, 0x767ddd5e, 0xba0ca9d0,
+    0xdba065df, 0xb07d02fe, 0x7c29ed80, 0x1e620c8e, 0x9bfa8a0b, 0x020d783e, 0xa9e8cda9, 0xac7d290f,
+    0x5d44dc7d, 0xbd7dec88, 0x60cb32e8, 0xc11f3bd5, 0xfac8acd5, 0x46269027, 0x5c59920c, 0x957eee50,
+    0x0416b179, 0x93193475, 0x3a32cb0b, 0x6269b981, 0xd751a571, 0x1aa0907b, 0x39d71288, 0xa41c0ad3,
+    0x397466e3, 0xe67e1e88, 0x9c1ed78a, 0x66f671d1, 0x9c1e1f49, 0xeece5260, 0x83be79b6, 0xad72aec5,
+    0xeddc3863, 0xcba44902, 0x7d0a74a2, 0x44863ba9, 0xfad8ead7, 0x5843df6d, 0x4c93f7fa, 0xb2e14492,
+    0x2fb6b55e, 0xc7914e91, 0x8136e0e5, 0x30cf3df7, 0x1eba71b4, 0x4f8a25d8, 0xa371b35f, 0xd81a86eb,
+]);
+
+const SIGNATURE: MlDsa87Signature = LEArray4xN([
+    0xecdc54d5, 0xb5d9dcba, 0xcde33c8f, 0x6a5dc597, 0x1646f640, 0xc809c31a, 0xbe575e81, 0x3867d82a,
+    0x374bd5d7, 0xecda2ded, 0x1aa16b13, 0x2171e61d, 0xdd1b1de5, 0xf770d671, 0x663518e9, 0x3ee2a773,
+    0xaf9d8a13, 0x26ff09e6, 0x63d977ae, 0x43629851, 0xb96f86a9, 0x388b5090, 0xd1e6de38, 0x3a050876,
+    0x2f7992a2, 0x0ab7e4bb, 0x7a9f306e, 0x6c101ff1, 0xfdcf1b53, 0xe08e685e, 0x40ade197, 0x9be402b1,
+    0x743673c1, 0x04ef04de, 0x08f4f970, 0x4e93400d, 0x13134b9c, 0x0855f874, 0xd4af02bc, 0x2acc3754,
+    0x934a77b0, 0x9df9c3b1, 0x6ceb6722, 0x8153b8e1, 0xf1d839aa, 0xffe5428f, 0x6a2ecd74, 0xb2901db9,
+    0xeb1fee84, 0x8ed36ce3, 0x2206d8e3, 0x492c05b8, 0x86667964, 0x36f15c13, 0x45dbfc6d, 0x2fd86f32,
+    0x34fb5480, 0xccc58d07, 0x6b81cbbf, 0xe15bfcbe, 0x4a7217d4, 0x999e48a6, 0x1326044d, 0xff59ebb4,
+    0x673dc3e5, 0x4d89e944, 0x07dd3246, 0xd662fce4, 0x3449026f, 0xfb7376a1, 0xe8d05d00, 0xa79ca2fa,
+    0x89259504, 0x0bf3c47c, 0x4ed9ad1c, 0x4af9b2bf, 0xf7e46aec, 0xc40588c2, 0xf69f66c8, 0xf19ffc38,
+    0xdc43cf80, 0x4a38cf82, 0xafd1b049, 0x982e7dce, 0xd1bb6b4a, 0x1a66a1e4, 0x85192a6f, 0x7b45bafd,
+    0x156106a1, 0x3f4ca98a, 0xd7131a98, 0x7c608f62, 0xd74deebc, 0xde778fbe, 0x1d0c9a57, 0x09398248,
+    0x20aef067, 0xe6230a7b, 0x68dd1bc8, 0x611670a6, 0x43214d5a, 0x9db7819d, 0xc96000c8, 0x4fb04209,
+    0x067ed1d6, 0x45b4f52d, 0x0505348d, 0x7709eeea, 0x6bf4c085, 0x55a1fd48, 0x56fcb03a, 0x22d73926,
+    0xcaf69d71, 0x433c908a, 0x6b3fb23e, 0xc4d6da80, 0x66628aca, 0xd85b006a, 0x6cc6fefa, 0xe11db1dd,
+    0x19a80a9b, 0x3dae3919, 0xf5d12756, 0xd4a6180c, 0xc6a1376f, 0xf437392c, 0x5bd640e0, 0x8fa53211,
+    0x97dca327, 0x75a64044, 0xb218be12, 0xc23b4f0c, 0x2696d774, 0x29f788da, 0x81543fef, 0xb7d4896c,
+    0x79f04b6d, 0xab94d865, 0xe0efd45e, 0x045f954c, 0x0bc3d5a5, 0xab7533d0, 0x6669a2ee, 0x3c2f6fd8,
+    0x78cd57c9, 0x0924fbbb, 0xd73dd699, 0x452bee0e, 0xdc1294c4, 0x876fadfa, 0x8f91e4be, 0xd0c89baf,
+    0xbc493498, 0x7159896a, 0x52eb2e32, 0x9c773c8b, 0x6ef437d3, 0x712846e5, 0x21391067, 0xf4af594b,
+    0xd34b22d1, 0xd6d74c44, 0x2ead8fa6, 0x45c06c1e, 0x95dd86e4, 0x1f1a2a68, 0x0c622531, 0x780373c8,
+    0xd83a008a, 0xd12f3451, 0x6c22edc1, 0x6b639e5a, 0x3fc79360, 0x0372d3d5, 0xd41b1ebf, 0x1981a726,
+    0xac98273a, 0x99362391, 0xce4e2f4a, 0xfb689471, 0x46ff494f, 0x8fac0bd1, 0x27351def, 0x90098cbd,
+    0xde57ec58, 0x1838f059, 0x2ac1b431, 0xcaddabc0, 0xf0edcade, 0xff8805a4, 0xcc33b261, 0x4b575169,
+    0xf6a0243c, 0x53c68297, 0x6061b6db, 0xf8f381c3, 0x225664f8, 0xf32423ac, 0xcc7fb7a9, 0x496393b9,
+    0x877506b9, 0xf940bdd0, 0xc1d2b80b, 0x66abad4b, 0xb679f9e0, 0xddf26620, 0x53079c89, 0xaea432f1,
+    0xcea9c86f, 0xc309d675, 0x512247b5, 0x1036408d, 0xfb3af0fd, 0x2af99f28, 0x04ca9d4c, 0xd6aa4083,
+    0x695c513b, 0x5c170630, 0xa2d7711e, 0x8202f0d8, 0x4fca6bcc, 0xb88b9442, 0x5215c1f7, 0x7cc319f3,
+    0x7cfc162e, 0x04073d0c, 0x508d1f60, 0xa84c09d8, 0x3d721b61, 0xf83abe24, 0x369a96ec, 0xf46b7f69,
+    0xb0f1d959, 0x2634da6b, 0x68674a10, 0xc46c3185, 0xaf10643b, 0x38915f52, 0xdb412727, 0x8e2aead1,
+    0x3778c7e7, 0xd342da3f, 0x2fa94f94, 0x15ed5bfa, 0x86dc2cd1, 0x609ab717, 0xab48ccde, 0x3ed6ecf7,
+    0x9aa1c2e7, 0x48f45d77, 0x70d2ba47, 0xbfa89a59, 0x9dd9d63d, 0xf37e715e, 0x37513b8b, 0xd58e9bdd,
+    0x043069e5, 0xc98015c9, 0xde48cbeb, 0x40c04f67, 0x82c9b2a0, 0x46978eea, 0x5fd4bf95, 0x9ae540d9,
+    0x43f3b634, 0xeb2a343c, 0xd80c65e8, 0xd444d226, 0x28cf05af, 0x4f2850ce, 0x267ff95c, 0x342bb5b7,
+    0x14ebedbf, 0x056031ef, 0x29a670fd, 0xfe6b5b5b, 0x9c24a65a, 0x6cc740f6, 0xa816ca13, 0x82ccce24,
+    0xfbbda641, 0xc3f19729, 0x93ab4ae6, 0x8f3f1269, 0xf4192b8c, 0x1d1c263e, 0x19f9b9b2, 0xf6a5ed6c,
+    0x52b88bd9, 0x92a6bc7d, 0xfac4ebda, 0x490d49ad, 0x60c09bb7, 0xd8589265, 0x9a861c6e, 0x2d07c709,
+    0xa25d6c60, 0xb0ac60ee, 0xef3568af, 0x7e757cad, 0xcade5047, 0x1f59a9c0, 0x701ee10c, 0xa5984fdd,
+    0x4e53700f, 0x75050eae, 0xb306bdaa, 0x1a7cfa82, 0xf537beba, 0x709e953a, 0x410e98f5, 0xf3e74130,
+    0x7ac73424, 0x30023168, 0xe50d1ae4, 0x6cbf457b, 0xa89773c7, 0x13ffed6f, 0xe5d6cc69, 0xedd01c40,
+    0x91fac064, 0x1a440d62, 0xe2757128, 0xd5cd19a8, 0x5c794a68, 0x012bc007, 0x1f4bd131, 0x38519aa9,
+    0x8af34821, 0x56243deb, 0x9ca100d1, 0xf5ede83d, 0x3cf31fce, 0xd81bf474, 0x878eecf1, 0x1a97f802,
+    0x3e8cbdd2, 0x7bcd4ec8, 0x82a15c93, 0x1370f1da, 0x590e280a, 0xb4a7dbda, 0xc62c255a, 0x2ab54214,
+    0xb948f02b, 0xddb6ded2, 0x51fb51e0, 0xdadaa0c8, 0x4a2d4b70, 0xfb8b31c4, 0x44cfd2b8, 0xee8a706a,
+    0x982314d8, 0xe8202354, 0x74942e56, 0xa08dae5a, 0x710f8437, 0x45b0c08e, 0x66147284, 0xe49ed24c,
+    0x9ba49ded, 0xb88e4c1f, 0xc41a998c, 0x1cc349e6, 0x2452582f, 0x7043dd14, 0x27138507, 0xea7e8326,
+    0x0b2dbe97, 0x93b100d8, 0x566ec8c2, 0x486df308, 0x9706bfc0, 0xc87fce05, 0x579e18ba, 0xace1ecc6,
+    0x22ec4c42, 0x758df15a, 0x7ba93c0c, 0x1cdd83db, 0xbaf2209f, 0xa2aa7f55, 0x4c91d3b2, 0xf1d1760d,
+    0x0ba1410d, 0xe26a8093, 0x7de6e6ee, 0xeeb796c7, 0x41bf64d2, 0xd9fa211f, 0x892796a0, 0x1d53733b,
+    0x08fe1ab7, 0xa3ca07b0, 0x49249e26, 0x05f35c58, 0xf91f10e9, 0x0d1c69dd, 0x0349ef12, 0xf6ef05dc,
+    0xc5aaaf63, 0xac8b4916, 0xde96941f, 0x970e5fcf, 0x358df3a8, 0x2bac32e4, 0x226587b6, 0xe901b8a1,
+    0x7f482ff7, 0x4f8d5315, 0x00a54c65, 0xd402e34d, 0x31c08960, 0xeefc686c, 0xdb61069b, 0x9be64b91,
+    0x9fd1d3f4, 0x37be046f, 0x212864f0, 0x587f7acc, 0x2cc8e3ce, 0x7acfe341, 0x5ee0b209, 0x04ae3770,
+    0x48525865, 0xe07a56d2, 0xae4f1e77, 0xc3084b5e, 0xad57ee9f, 0x7ace1253, 0x6316c733, 0x2d650e7b,
+    0xea4ffd62, 0x0fd0280d, 0x0c27ba80, 0xe0e7ad69, 0xe721ef02, 0x44325594, 0x2a31c916, 0xa4359680,
+    0xbdc969e2, 0xfe5526d8, 0x2fd3dc77, 0xfe3858b1, 0xea70d504, 0xae707b24, 0x2ef60849, 0x897fc543,
+    0xc1ea9dea, 0x24cb9655, 0x8a39393b, 0x9d6829ca, 0x86c91613, 0xb884467b, 0xf6d281ca, 0x8f1e861d,
+    0x5e45ecef, 0xedf08740, 0xc3715e46, 0x596729a2, 0x9a24c71c, 0xbd0a9c86, 0xb8ada41f, 0xfcfeb5f7,
+    0x319fb5f1, 0xcd7d929b, 0x1c609b5d, 0xbc65d648, 0xabf84741, 0x863b9819, 0xbc95d190, 0x28bf6f5d,
+    0x834e3bdf, 0xf7f532c6, 0x4dc0d149, 0x75c48266, 0xfeca994d, 0x237471ac, 0x082dee8c, 0x6f01ad82,
+    0x059244d9, 0xc626db9b, 0x4c0152c2, 0xa3ebc53f, 0xc56f6216, 0xd8da4f04, 0x180dfbb0, 0x651dc687,
+    0x05052536, 0xef14af83, 0x1dab55bc, 0xb4bd929c, 0x65213857, 0x417a0ccd, 0x8214e398, 0xcfad3d43,
+    0x6d738cee, 0xe746823b, 0x4dc97860, 0x55aa5cfb, 0x28e03bb4, 0xd165c096, 0xaf544e40, 0x964fb5f8,
+    0x859f0bd4, 0xba118509, 0xf2197598, 0x195992b1, 0x0b368e3a, 0xfd1896bf, 0xb0ab4e8c, 0xdc475f53,
+    0xbcd106ef, 0x007cbfbe, 0x3a8259a2, 0x098e511a, 0x6ab0e927, 0x48c32567, 0x8fe89c37, 0xeecf05c8,
+    0xaa17f857, 0x142a91f2, 0xb0427b86, 0xdcb226a8, 0xf7668264, 0x23bc930a, 0xc493fc02, 0xc444e2c7,
+    0xdcdc8d2e, 0x84d9742d, 0x9571361c, 0x523f5fea, 0x1ef4c03a, 0x329efd2f, 0xc2a8c7ba, 0x8cc216c9,
+    0xb1de4b25, 0x6be1ea96, 0xa4681f58, 0x9d59412f, 0x4bbecc90, 0xe64a0523, 0xc02d92e2, 0x824c7089,
+    0xa4f52506, 0xd8f1fcfa, 0x49d1f68f, 0x631bbd91, 0x425c347f, 0x02adf79e, 0x8ab92329, 0xb2ab5593,
+    0x4e63555d, 0x273d7a29, 0x7ca8981d, 0x297fb2e4, 0x2ac0f2d5, 0x83668f8d, 0x93e95099, 0xb1a8dfdc,
+    0x83d71e82, 0x4542ebff, 0x69e76231, 0xa2d82888, 0x58a051d1, 0xf8823bb6, 0x51a392a9, 0xc08e8a9c,
+    0x2afcd80a, 0x1904bdf1, 0x5795a2fd, 0xf6600db5, 0xb431794c, 0xc2dc7b57, 0x95672609, 0xa6155332,
+    0xda462c32, 0xee83dcd8, 0xd4cb8a6c, 0x6c61e167, 0x00b00a15, 0x6b78b13b, 0x3f919f4e, 0x05efcbac,
+    0x6f21241d, 0x75c0eb08, 0xd2c2e5e1, 0xf992a2a0, 0x3586b5c5, 0xacb05705, 0x16063206, 0xe30acf7c,
+    0x24aa29e5, 0xca8c32ad, 0x63cfdb9f, 0x0a3306c6, 0x27052d1a, 0xdecad4d8, 0xf5d258b0, 0xe622324f,
+    0xb48928f1, 0xeeb4bdda, 0x7766a364, 0x687b5851, 0x39b385ed, 0xd8b25567, 0x0ad09303, 0xf86ba4fa,
+    0x4847706c, 0xcbffb7ea, 0xc6894dbc, 0x6b4deda0, 0x363cd85d, 0x6b73df61, 0xc99bf96f, 0xfd46d8e8,
+    0xa655a215, 0xfa3315f2, 0x4dcc437d, 0xe175ec1b, 0xb85ccd19, 0x08fea861, 0xe0543c4d, 0xee2743e8,
+    0xf2a92053, 0x19fd4787, 0xabf65916, 0xdb23e472, 0x551923b4, 0xf1ca0a22, 0x5c86bb5e, 0x56405c56,
+    0x0870fb01, 0x5047e02e, 0xf100dc44, 0x2cf3139c, 0xb748c43b, 0x865c21d1, 0x7fe4c17d, 0x7b25529d,
+    0xbed8614c, 0xbc6bb558, 0x7dfd3324, 0x984cfcfd, 0x6bc078cd, 0x3991e0e5, 0x5476f164, 0xe90fcec2,
+    0xcc867499, 0x12e4f40d, 0xd0e713de, 0x9d783201, 0xcf8780a4, 0x0729ff7f, 0x54e19a95, 0x12096562,
+    0x7e8cef32, 0x1055ed3b, 0x09cbf05f, 0xc2781324, 0x2af418b3, 0xa692a50c, 0xbd556356, 0x61d56698,
+    0x521b7c3b, 0x3d075202, 0xa4b0e6bf, 0xf1f71ded, 0x2c51f5f5, 0x297d5539, 0x59088a22, 0x5d05a006,
+    0x724d6c94, 0xc651f757, 0x8a45bc0c, 0x7c8a1210, 0x2240a969, 0x483b6d32, 0x6abee89d, 0xf2d19599,
+    0xe3626d45, 0x802c6288, 0x45966b3c, 0x3d2a5baf, 0x3c0a64b2, 0x8c9b17bc, 0x10f7e1eb, 0x04ee4bba,
+    0xd5ce27f1, 0x814ddddd, 0xb96514a5, 0x988b20aa, 0xf5e68c69, 0xcab45079, 0x5fb362c6, 0xaf463add,
+    0xe385faf4, 0x672cf835, 0x60dc78c1, 0x5ff7ebcc, 0xc4a4563c, 0x8ce331ed, 0x35f0ea18, 0x0e2db8cb,
+    0xa7126cef, 0x66e5b69e, 0x90510ee5, 0xdb0bfefd, 0x342c7edd, 0xedfbe7f5, 0x01f0c76b, 0xe9744224,
+    0xb64242cf, 0xf9df1bd0, 0xc93c42b3, 0x7a139f56, 0x1386f3e4, 0xf9bfb70c, 0x3d125d48, 0x1838ed3f,
+    0x3967abbc, 0x4faf6e02, 0x526589c8, 0x65432733, 0x922324dd, 0x01b419db, 0x0c9694e2, 0xcad7f45e,
+    0x02da5c5f, 0xc42b08be, 0xaa74e6ce, 0xbf67d782, 0x1265090f, 0x58e5ce9e, 0x8116dcb2, 0x8d739199,
+    0x7795b0e6, 0x4bdafe70, 0x0c6b3eb4, 0xd9269c3c, 0x879be422, 0x285937d9, 0x4c99c7a2, 0xcffcb547,
+    0x8079da7a, 0x0d68e0b1, 0x34aa297f, 0x8c9e4ac3, 0x6c514fca, 0x16603868, 0x4b6ebf23, 0x35ec536c,
+    0xf04de7db, 0x0cde6e68, 0xa3c79b31, 0xbf12c5ab, 0xf34eafe2, 0x8248e4fe, 0xa9f9a2b7, 0x12f24894,
+    0x74db589b, 0x4b85644a, 0xdba789f1, 0x21dbac1c, 0x8fb895fb, 0x8521a174, 0x6b5f27e0, 0xcee4d7cd,
+    0x3abc439d, 0xc206d549, 0x1b0bb3da, 0x01053d35, 0xabfbaf3f, 0x60ce2906, 0xc2eda4eb, 0x82006d1d,
+    0xa82045ae, 0xe19d3719, 0x47698399, 0x6aa3afcf, 0xe75fc4bd, 0x22bee49d, 0xb305a66f, 0x06d23693,
+    0xd0286b08, 0x6a2c24af, 0xaefbeb2f, 0xb123f543, 0xe6d3a0c8, 0xce3a79f8, 0xa3795f38, 0x2ff00a63,
+    0x0758c672, 0xcc4c452f, 0x5d5d3137, 0xa260fa82, 0x7e5e7ec3, 0xa12a62bb, 0x46de9798, 0x519d965c,
+    0xdeed2d8b, 0xf1be17df, 0xba106709, 0xbb884be8, 0xa01808e6, 0x2532f105, 0x17ffdad6, 0x812d04ad,
+    0xf66bb8fd, 0xe2c2c316, 0xf5dbda66, 0x23820d28, 0x6e542bb1, 0xc30e6b80, 0x3f0d6ea8, 0x8847c883,
+    0x479f3a40, 0x52552b68, 0x8c27dd56, 0xed80e836, 0x3686195d, 0x400fabd3, 0x6c23532a, 0x07923197,
+    0x917778a2, 0xbee5d5ff, 0xe94da5da, 0xd9474ccb, 0x96ccc7f4, 0x26762b1e, 0x92bc8c64, 0x6e66c396,
+    0x9092fc83, 0xcb5bc49f, 0xc2893d7c, 0x06693d7b, 0x13f77b31, 0x29aa22e1, 0xd9a6ea49, 0xd9155ed5,
+    0x7d49a5d5, 0x12b5298a, 0xc447d893, 0x253a46ad, 0xfb64ddac, 0xf4352b66, 0x02975c89, 0x5d72e9ed,
+    0x774b5135, 0x4681fda5, 0x236c9c6d, 0x0ba0b233, 0xa03d81a8, 0xfa49a8ba, 0x6316a123, 0x2d74dd0c,
+    0x41de44eb, 0x06837daf, 0x019869e9, 0x3f1a8f1c, 0x1a7344eb, 0x28945e67, 0xf4607471, 0xd3389f76,
+    0x7882d8d1, 0xab274e82, 0x58fe64dc, 0x0f0b9d09, 0x18094c05, 0x2e9fc61d, 0xd089a162, 0x7b4cfd8e,
+    0xc0c9c063, 0x5baafdfb, 0xa0fc3bfd, 0x1609986b, 0xd1514eb2, 0x8cf31a24, 0x7d6a43b3, 0x57cf5f11,
+    0x122eb4ff, 0xbc66e600, 0x1393d21e, 0xe857ae6b, 0x3863cd67, 0x78fd9276, 0xd762bdaf, 0x69981a84,
+    0x54fd6a89, 0xee726ef1, 0x98e1052a, 0xa198faee, 0xfa9cce10, 0x487f4b8a, 0x541094ac, 0xe06ccd93,
+    0xa5478a20, 0x2321a607, 0xdb819cb1, 0x512763eb, 0xe5edf8d1, 0x8409d50b, 0x7be26be6, 0xb04f31fa,
+    0x6dee7e31, 0xdf816e4b, 0xa746e9ee, 0x9e2346e9, 0xe616c599, 0xe3f8cb52, 0xc8757049, 0xfeb940f5,
+    0x25666a34, 0x0e88d79b, 0x04460d14, 0x564b8c9f, 0x6d69a3e8, 0xf4b0a48f, 0xab605ee6, 0x296d8a16,
+    0xe17ddb7e, 0x66a2eafa, 0x9be2197d, 0xf022eb36, 0xa52c957d, 0x14cffab0, 0xf9b92aac, 0x84efa9e5,
+    0x353f9c27, 0xf73e549e, 0x80e3332e, 0xdffcc097, 0xa823f555, 0x7c212227, 0xd546a555, 0x86661e72,
+    0x6cf5481e, 0x7a96eb3d, 0x69d1c536, 0x989fbc2a, 0xc2d9b5ae, 0x243a911b, 0xbaae2102, 0x145d5240,
+    0xfdfc9069, 0x199740c7, 0x020bdcf3, 0xabc7e3a7, 0x799f6596, 0x30c8ffc4, 0xc31fe642, 0x056a9103,
+    0xb79a8a4c, 0x4df4db5a, 0x33bea3d8, 0x504dc6f0, 0x558f9e5f, 0xfbf5fafb, 0x69caf8cd, 0xf37f9001,
+    0xce1ab097, 0x0e996ac2, 0xa5ca549d, 0xc041f4a1, 0x9ceafbdb, 0x57164066, 0x374e285f, 0xa5b4718d,
+    0x05f2a636, 0xa8d294fd, 0xf9cccc47, 0xf86c19c0, 0xb6ff6102, 0xec86c154, 0x2044e3a0, 0xd02b9df8,
+    0xc4013559, 0x9f7ce8ac, 0x20293bfa, 0xa464b3cc, 0xa01984d6, 0x8fa9cdd9, 0xb8e4009a, 0xad89f48c,
+    0xe8482ce9, 0x0418dcc1, 0x1e992290, 0x016b2f9d, 0x84c224b9, 0x19177563, 0x8530e874, 0xa332b268,
+    0xed0f93c2, 0xf0ba8b82, 0xe9482bc0, 0xc365e590, 0x6032b88d, 0xec765240, 0xab588201, 0x56a8a67f,
+    0x10f637b5, 0x218561d1, 0x5f5609eb, 0x58a96841, 0x58f7238f, 0x5917f2e5, 0x4f488668, 0x87ed1556,
+    0xa392eb08, 0x1e82335d, 0x23833db2, 0x5fd43d64, 0xfc6fc5b1, 0xe4398d5b, 0x8de38fdc, 0x1026f226,
+    0x8f9c1fec, 0x47d5e727, 0x470522f4, 0x50969ff9, 0x122c1f5c, 0xb5becf38, 0x47c0e27c, 0xc3f538b1,
+    0x0d1fb830, 0xf5f6c8a6, 0xde45f0b0, 0x335c972e, 0x16916e40, 0x70cdba10, 0x1f38460c, 0x53bf631c,
+    0x56359fcd, 0xafec3114, 0x50e2d2ec, 0x59352c43, 0x846c1d86, 0x77bb2837, 0x81a91a0f, 0xceca7575,
+    0x72856565, 0xe61be5e6, 0xcf780219, 0x07609d42, 0x47df8930, 0x0a99e72a, 0xa131fd75, 0x351e9ac8,
+    0xa42b2ccb, 0x3b33aa02, 0xca1fe16c, 0x177543b9, 0x5ab132b8, 0x1fb3cd7b, 0x0f1afdf2, 0x6ba5b95a,
+    0x1d77534c, 0xc036a262, 0x51c760af, 0x0e63254d, 0x8d5997e8, 0xb2e096ea, 0x322d2d9c, 0xfc2111e7,
+    0x600d1e51, 0xf9bac722, 0x6aba826f, 0xf79f70d7, 0x22f38e3c, 0xc2ec8f59, 0x1dadc3ba, 0x6b5c41ac,
+    0x53f7f5e4, 0xdd14aefb, 0xace69c62, 0xf904baf1, 0x7d784129, 0xd0358c33, 0x37bd0ea2, 0x25b5e05d,
+    0x059e9961, 0xfe7ac764, 0x22ad0c3b, 0xb9948e7f, 0x25d43e26, 0x9db3ab8f, 0xe4a95596, 0x291e839f,
+    0x4de5f9c1, 0x613e59e7, 0xd0e9ddf0, 0x02aca118, 0x34208226, 0xdbde843d, 0x2c5591fb, 0xb4a484c8,
+    0x0fc07baa, 0xaaefb76a, 0x30fcbe50, 0x3561aad8, 0x88d756de, 0xaacd23d0, 0xd7adfcf5, 0x025854d3,
+    0x84b13b89, 0xc2ce120b, 0xa7bc43b2, 0x072c70c0, 0xfc6e6ad7, 0x83579694, 0x979358cf, 0x7be07ed0,
+    0xf42bfea2, 0x81548da4, 0xaef92bd8, 0x4d68202c, 0x9111825d, 0x84d47204, 0xb8a896a5, 0xcfb3d621,
+    0x512602ee, 0x96aa9e10, 0x43e41d27, 0xfccc74c3, 0x9ade623e, 0x18802f17, 0x29769ef5, 0x38e2ab99,
+    0x7af3b9d3, 0x90dc03c2, 0x236bdcde, 0xc24b7b51, 0xf839536b, 0x8f33c680, 0xee04eedd, 0x913d3ad1,
+    0x1c944543, 0x73613b33, 0xf9e3b879, 0x4e474507, 0xffd29b94, 0x64554812, 0xeeeca59e, 0x632801fd,
+    0xebdad380, 0x6f6d3927, 0x1914c792, 0x70552f28, 0x6640ed75, 0x0000c288, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x03000000, 0x241d140c, 0x0036322a,
+]);
+
+#[derive(Default, Debug)]
+pub struct MlDsa87Kat {}
+
+impl MlDsa87Kat {
+    /// This function executes the Known Answer Tests (aka KAT) for ML-DSA-87.
+    ///
+    /// Test vector source:
+    /// Zeroed seed, message and signing randomness, outputs generated with the
+    /// fips204 crate used by the emulator
+    ///
+    /// # Arguments
+    ///
+    /// * `mldsa` - ML-DSA-87 Driver
+    /// * `trng` - TRNG Driver
+    ///
+    /// # Returns
+    ///
+    /// * `CaliptraResult` - Result denoting the KAT outcome.
+    pub fn execute(&self, mldsa: &mut MlDsa87, trng: &mut Trng) -> CaliptraResult<()> {
+        self.kat_key_pair_gen_sign_and_verify(mldsa, trng)
+    }
+
+    fn kat_key_pair_gen_sign_and_verify(
+        &self,
+        mldsa: &mut MlDsa87,
+        trng: &mut Trng,
+    ) -> CaliptraResult<()> {
+        let mut pct_sig = MlDsa87Signature::default();
+
+        let pub_key = mldsa
+            .key_pair_for_fips_kat(trng, &mut pct_sig)
+            .map_err(|_| CaliptraError::KAT_MLDSA87_KEY_PAIR_GENERATE_FAILURE)?;
+
+        // NOTE: Signature verify step is performed in ML-DSA-87 driver sign function
+        if pub_key != KEY_GEN_PUB_KEY {
+            Err(CaliptraError::KAT_MLDSA87_KEY_PAIR_VERIFY_FAILURE)?;
+        }
+        if pct_sig != SIGNATURE {
+            Err(CaliptraError::KAT_MLDSA87_SIGNATURE_MISMATCH)?;
+        }
+
+        Ok(())
+    }
+}
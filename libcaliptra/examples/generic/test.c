@@ -752,6 +752,42 @@ int rt_test_all_commands(const test_info* info)
         printf("Quote PCRs: OK\n");
     }
 
+    // Get PCR log
+    struct caliptra_get_log_req get_log_req = {};
+    struct caliptra_get_log_resp get_log_resp;
+
+    status = caliptra_get_pcr_log(&get_log_req, &get_log_resp, false);
+
+    if (status) {
+        printf("Get PCR Log failed: 0x%x\n", status);
+        dump_caliptra_error_codes();
+        failure = 1;
+    } else {
+        printf("Get PCR Log: OK\n");
+    }
+
+    // Get measurement log
+    status = caliptra_get_measurement_log(&get_log_req, &get_log_resp, false);
+
+    if (status) {
+        printf("Get Measurement Log failed: 0x%x\n", status);
+        dump_caliptra_error_codes();
+        failure = 1;
+    } else {
+        printf("Get Measurement Log: OK\n");
+    }
+
+    // Get fuse log
+    status = caliptra_get_fuse_log(&get_log_req, &get_log_resp, false);
+
+    if (status) {
+        printf("Get Fuse Log failed: 0x%x\n", status);
+        dump_caliptra_error_codes();
+        failure = 1;
+    } else {
+        printf("Get Fuse Log: OK\n");
+    }
+
     // Extend PCR
     struct caliptra_extend_pcr_req extend_pcr_req = {};
     extend_pcr_req.pcr_idx = 0x4; // First non-reserved index
@@ -0,0 +1,235 @@
+//Licensed under the Apache-2.0 license
+
+// Caliptra interface functions for an emulator started with
+// `caliptra-emu --mbox-socket <ADDR>`. Every register access is sent as one
+// request frame and answered by one response frame; see
+// hw-model/src/soc_socket.rs for the protocol.
+
+#include <errno.h>
+#include <netdb.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/socket.h>
+#include <sys/un.h>
+#include <netinet/in.h>
+#include <netinet/tcp.h>
+#include <unistd.h>
+
+#include "caliptra_if.h"
+#include "interface.h"
+
+#define SOCKET_REQUEST_LEN  12
+#define SOCKET_RESPONSE_LEN 8
+
+#define SOCKET_OP_READ  1
+#define SOCKET_OP_WRITE 2
+
+#define SOCKET_STATUS_SUCCESS 0
+
+// Returned by the interface functions when the socket fails
+#define SOCKET_IO_ERROR -EIO
+
+static int sock = -1;
+
+static int connect_unix(const char *path)
+{
+    struct sockaddr_un addr = {.sun_family = AF_UNIX};
+
+    if (strlen(path) >= sizeof(addr.sun_path)) {
+        return -ENAMETOOLONG;
+    }
+    strcpy(addr.sun_path, path);
+
+    int fd = socket(AF_UNIX, SOCK_STREAM, 0);
+    if (fd < 0) {
+        return -errno;
+    }
+    if (connect(fd, (struct sockaddr *)&addr, sizeof(addr)) != 0) {
+        int err = -errno;
+        close(fd);
+        return err;
+    }
+    return fd;
+}
+
+static int connect_tcp(const char *host, const char *port)
+{
+    struct addrinfo hints = {.ai_family = AF_UNSPEC, .ai_socktype = SOCK_STREAM};
+    struct addrinfo *res;
+
+    if (getaddrinfo(host, port, &hints, &res) != 0) {
+        return -EHOSTUNREACH;
+    }
+
+    int fd = -ECONNREFUSED;
+    for (struct addrinfo *ai = res; ai != NULL; ai = ai->ai_next) {
+        fd = socket(ai->ai_family, ai->ai_socktype, ai->ai_protocol);
+        if (fd < 0) {
+            fd = -errno;
+            continue;
+        }
+        if (connect(fd, ai->ai_addr, ai->ai_addrlen) == 0) {
+            // Every request is a single small frame
+            int one = 1;
+            setsockopt(fd, IPPROTO_TCP, TCP_NODELAY, &one, sizeof(one));
+            break;
+        }
+        close(fd);
+        fd = -errno;
+    }
+    freeaddrinfo(res);
+    return fd;
+}
+
+int socket_connect(const char *addr)
+{
+    char host[256];
+    const char *port = strrchr(addr, ':');
+
+    if (sock >= 0) {
+        close(sock);
+        sock = -1;
+    }
+
+    // "host:port" is a TCP address, anything else a Unix socket path
+    int fd;
+    if (port != NULL && strchr(addr, '/') == NULL && (size_t)(port - addr) < sizeof(host)) {
+        memcpy(host, addr, port - addr);
+        host[port - addr] = '\0';
+        fd = connect_tcp(host, port + 1);
+    } else {
+        fd = connect_unix(addr);
+    }
+
+    if (fd < 0) {
+        return fd;
+    }
+    sock = fd;
+    return CALIPTRA_STATUS_OK;
+}
+
+void socket_disconnect(void)
+{
+    if (sock >= 0) {
+        close(sock);
+        sock = -1;
+    }
+}
+
+static int send_all(const uint8_t *buf, size_t len)
+{
+    while (len > 0) {
+        ssize_t n = send(sock, buf, len, MSG_NOSIGNAL);
+        if (n < 0 && errno == EINTR) {
+            continue;
+        }
+        if (n <= 0) {
+            return SOCKET_IO_ERROR;
+        }
+        buf += n;
+        len -= n;
+    }
+    return CALIPTRA_STATUS_OK;
+}
+
+static int recv_all(uint8_t *buf, size_t len)
+{
+    while (len > 0) {
+        ssize_t n = recv(sock, buf, len, 0);
+        if (n < 0 && errno == EINTR) {
+            continue;
+        }
+        if (n <= 0) {
+            return SOCKET_IO_ERROR;
+        }
+        buf += n;
+        len -= n;
+    }
+    return CALIPTRA_STATUS_OK;
+}
+
+static void put_u32(uint8_t *buf, uint32_t val)
+{
+    buf[0] = val;
+    buf[1] = val >> 8;
+    buf[2] = val >> 16;
+    buf[3] = val >> 24;
+}
+
+static uint32_t get_u32(const uint8_t *buf)
+{
+    return buf[0] | (buf[1] << 8) | (buf[2] << 16) | ((uint32_t)buf[3] << 24);
+}
+
+static int transact(uint8_t op, uint32_t address, uint32_t data, uint32_t *result)
+{
+    uint8_t req[SOCKET_REQUEST_LEN] = {op, sizeof(uint32_t)};
+    uint8_t resp[SOCKET_RESPONSE_LEN];
+
+    if (sock < 0) {
+        return SOCKET_IO_ERROR;
+    }
+
+    put_u32(&req[4], address);
+    put_u32(&req[8], data);
+
+    int status = send_all(req, sizeof(req));
+    if (status == CALIPTRA_STATUS_OK) {
+        status = recv_all(resp, sizeof(resp));
+    }
+    if (status != CALIPTRA_STATUS_OK) {
+        socket_disconnect();
+        return status;
+    }
+
+    if (result) {
+        *result = get_u32(&resp[4]);
+    }
+    return resp[0] == SOCKET_STATUS_SUCCESS ? CALIPTRA_STATUS_OK : resp[0];
+}
+
+// Memory
+
+/**
+ * caliptra_write_u32
+ *
+ * Writes a uint32_t value to the specified address.
+ *
+ * @param[in] address Memory address to write
+ * @param[in] data Data to write at address
+ *
+ * @return 0 if successful, the socket status byte if the access faulted or
+ *         -EIO if the socket failed
+ */
+int caliptra_write_u32(uint32_t address, uint32_t data)
+{
+    return transact(SOCKET_OP_WRITE, address, data, NULL);
+}
+
+/**
+ * caliptra_read_u32
+ *
+ * Reads a uint32_t value from the specified address.
+ *
+ * @param[in] address Memory address to read
+ * @param[in] data Pointer to a uint32_t to store the data
+ *
+ * @return 0 if successful, the socket status byte if the access faulted or
+ *         -EIO if the socket failed
+ */
+int caliptra_read_u32(uint32_t address, uint32_t *data)
+{
+    return transact(SOCKET_OP_READ, address, 0, data);
+}
+
+/**
+ * caliptra_wait
+ *
+ * Pend the current operation. The emulator runs on its own, so just give it
+ * some time.
+ */
+void caliptra_wait(void)
+{
+    usleep(10);
+}
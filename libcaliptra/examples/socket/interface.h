@@ -0,0 +1,20 @@
+//Licensed under the Apache-2.0 license
+#pragma once
+
+/**
+ * socket_connect
+ *
+ * Connects the interface functions to `caliptra-emu --mbox-socket <ADDR>`.
+ *
+ * @param[in] addr "host:port" for TCP, otherwise a Unix socket path
+ *
+ * @return 0 if successful, negative errno otherwise
+ */
+int socket_connect(const char *addr);
+
+/**
+ * socket_disconnect
+ *
+ * Closes the connection opened by socket_connect.
+ */
+void socket_disconnect(void);
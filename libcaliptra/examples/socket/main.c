@@ -0,0 +1,146 @@
+// Licensed under the Apache-2.0 license
+
+// Drives an emulator started with `caliptra-emu --mbox-socket <ADDR>`:
+// writes the fuses, sends CAPABILITIES and FIPS_VERSION to the ROM and, if an
+// image bundle is given, loads it and sends FW_INFO to the runtime.
+
+#include <errno.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "caliptra_api.h"
+#include "caliptra_types.h"
+#include "interface.h"
+
+static struct caliptra_buffer read_file_or_exit(const char* path)
+{
+    FILE *fp = fopen(path, "r");
+    if (!fp) {
+        printf("Cannot find file %s \n", path);
+        exit(-ENOENT);
+    }
+
+    struct caliptra_buffer buffer = {0};
+
+    fseek(fp, 0L, SEEK_END);
+    buffer.len = ftell(fp);
+    fseek(fp, 0L, SEEK_SET);
+
+    buffer.data = malloc(buffer.len);
+    if (!buffer.data) {
+        printf("Cannot allocate memory for buffer->data \n");
+        exit(-ENOMEM);
+    }
+
+    size_t bytes_read = fread((char *)buffer.data, 1, buffer.len, fp);
+    fclose(fp);
+    if (bytes_read != buffer.len) {
+        printf("Bytes read (%ld) does not match file size (%ld)\n", bytes_read, buffer.len);
+        exit(-EIO);
+    }
+
+    return buffer;
+}
+
+static int capabilities(void)
+{
+    struct caliptra_capabilities_resp resp;
+    int status = caliptra_capabilities(&resp, false);
+
+    if (status) {
+        printf("CAPABILITIES failed: 0x%x\n", status);
+        return status;
+    }
+
+    printf("CAPABILITIES = ");
+    for (size_t i = 0; i < sizeof(resp.capabilities); i++) {
+        printf("%02x", resp.capabilities[i]);
+    }
+    printf("\n");
+    return 0;
+}
+
+static int fips_version(void)
+{
+    struct caliptra_fips_version_resp version;
+    int status = caliptra_fips_version(&version, false);
+
+    if (status) {
+        printf("FIPS_VERSION failed: 0x%x\n", status);
+        return status;
+    }
+
+    version.name[sizeof(version.name) - 1] = 0;
+    printf("FIPS_VERSION = mode: 0x%x, fips_rev (0x%x, 0x%x, 0x%x), name %s \n", version.mode,
+        version.fips_rev[0], version.fips_rev[1], version.fips_rev[2], version.name);
+    return 0;
+}
+
+static int load_fw(const char *path)
+{
+    struct caliptra_buffer image_bundle = read_file_or_exit(path);
+    int status = caliptra_upload_fw(&image_bundle, false);
+
+    free((void *)image_bundle.data);
+    if (status) {
+        printf("FW Load Failed: 0x%x\n", status);
+        return status;
+    }
+    printf("FW Load: OK\n");
+
+    status = caliptra_ready_for_runtime();
+    if (status) {
+        printf("Runtime not ready: 0x%x\n", status);
+        return status;
+    }
+
+    struct caliptra_fw_info_resp fw_info;
+    status = caliptra_fw_info(&fw_info, false);
+    if (status) {
+        printf("FW_INFO failed: 0x%x\n", status);
+        return status;
+    }
+    printf("FW_INFO = runtime_svn: %u, min_runtime_svn: %u\n", fw_info.runtime_svn,
+        fw_info.min_runtime_svn);
+    return 0;
+}
+
+int main(int argc, char *argv[])
+{
+    if (argc < 2 || argc > 3) {
+        printf("Usage: %s <ADDR> [IMAGE_BUNDLE]\n", argv[0]);
+        return -EINVAL;
+    }
+
+    int status = socket_connect(argv[1]);
+    if (status) {
+        printf("Cannot connect to %s: %s\n", argv[1], strerror(-status));
+        return status;
+    }
+
+    if (caliptra_ready_for_fuses()) {
+        struct caliptra_fuses fuses = {0};
+        if ((status = caliptra_init_fuses(&fuses)) != 0) {
+            printf("Failed to init fuses: %d\n", status);
+            return status;
+        }
+    }
+
+    status = caliptra_ready_for_firmware();
+    if (status) {
+        printf("Not ready for firmware: 0x%x\n", status);
+        return status;
+    }
+
+    status = capabilities();
+    if (!status) {
+        status = fips_version();
+    }
+    if (!status && argc == 3) {
+        status = load_fw(argv[2]);
+    }
+
+    socket_disconnect();
+    return status;
+}
@@ -203,6 +203,18 @@ int caliptra_increment_pcr_reset_counter(struct caliptra_increment_pcr_reset_cou
 // Quote PCRs
 int caliptra_quote_pcrs(struct caliptra_quote_pcrs_req *req, struct caliptra_quote_pcrs_resp *resp, bool async);
 
+// Get PCR log
+int caliptra_get_pcr_log(struct caliptra_get_log_req *req, struct caliptra_get_log_resp *resp, bool async);
+
+// Get measurement log
+int caliptra_get_measurement_log(struct caliptra_get_log_req *req, struct caliptra_get_log_resp *resp, bool async);
+
+// Get fuse log
+int caliptra_get_fuse_log(struct caliptra_get_log_req *req, struct caliptra_get_log_resp *resp, bool async);
+
+// Get a data blob from the firmware image bundle
+int caliptra_get_image_data(struct caliptra_get_image_data_req *req, struct caliptra_get_image_data_resp *resp, bool async);
+
 // Extend PCR
 int caliptra_extend_pcr(struct caliptra_extend_pcr_req *req, bool async);
 
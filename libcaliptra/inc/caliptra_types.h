@@ -184,6 +184,29 @@ struct caliptra_quote_pcrs_resp {
     uint8_t signature_s[48];
 };
 
+struct caliptra_get_log_req {
+    struct caliptra_req_header hdr;
+    uint32_t start_index;
+};
+
+struct caliptra_get_log_resp {
+    struct caliptra_resp_header hdr;
+    uint32_t data_size;
+    uint8_t data[1024];
+};
+
+struct caliptra_get_image_data_req {
+    struct caliptra_req_header hdr;
+    uint32_t id;
+    uint32_t offset;
+};
+
+struct caliptra_get_image_data_resp {
+    struct caliptra_resp_header hdr;
+    uint32_t data_size;
+    uint8_t data[1024];
+};
+
 struct caliptra_extend_pcr_req {
     struct caliptra_req_header hdr;
     uint32_t pcr_idx;
@@ -1177,6 +1177,58 @@ int caliptra_quote_pcrs(struct caliptra_quote_pcrs_req *req, struct caliptra_quo
     return pack_and_execute_command(&p, async);
 }
 
+// Get PCR log
+int caliptra_get_pcr_log(struct caliptra_get_log_req *req, struct caliptra_get_log_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    CREATE_PARCEL(p, OP_GET_PCR_LOG, req, resp);
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get measurement log
+int caliptra_get_measurement_log(struct caliptra_get_log_req *req, struct caliptra_get_log_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    CREATE_PARCEL(p, OP_GET_MEASUREMENT_LOG, req, resp);
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get fuse log
+int caliptra_get_fuse_log(struct caliptra_get_log_req *req, struct caliptra_get_log_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    CREATE_PARCEL(p, OP_GET_FUSE_LOG, req, resp);
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get a data blob from the firmware image bundle
+int caliptra_get_image_data(struct caliptra_get_image_data_req *req, struct caliptra_get_image_data_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    CREATE_PARCEL(p, OP_GET_IMAGE_DATA, req, resp);
+
+    return pack_and_execute_command(&p, async);
+}
+
 // Extend PCR
 int caliptra_extend_pcr(struct caliptra_extend_pcr_req *req, bool async)
 {
@@ -54,6 +54,10 @@ enum mailbox_command {
     OP_GET_IDEV_CSR                = 0x49444352, // "IDCR"
     OP_SIGN_WITH_EXPORTED_ECDSA    = 0x53574545, // "SWEE"
     OP_REVOKE_EXPORTED_CDI_HANDLE  = 0x52564348, // "RVCH"
+    OP_GET_PCR_LOG                 = 0x504C4F47, // "PLOG"
+    OP_GET_MEASUREMENT_LOG         = 0x4D4C4F47, // "MLOG"
+    OP_GET_FUSE_LOG                = 0x464C4F47, // "FLOG"
+    OP_GET_IMAGE_DATA              = 0x49444154, // "IDAT"
 };
 
 struct parcel {
@@ -0,0 +1,21 @@
+// Licensed under the Apache-2.0 license.
+//
+// Hand-written: the caliptra-rtl revision in hw/latest/rtl reserves bit 11 of
+// the key vault write control registers, which the ML-DSA block uses as
+// mldsa_seed_dest_valid. Remove this once the generated
+// `KvWriteCtrlRegWriteVal` has the field.
+//
+use crate::regs::KvWriteCtrlRegWriteVal;
+
+/// `mldsa_seed_dest_valid` field of the key vault write control registers
+pub trait KvWriteCtrlMldsaSeed: Sized {
+    /// MLDSA SEED is a valid destination
+    fn mldsa_seed_dest_valid(self, val: bool) -> Self;
+}
+impl KvWriteCtrlMldsaSeed for KvWriteCtrlRegWriteVal {
+    #[inline(always)]
+    fn mldsa_seed_dest_valid(self, val: bool) -> Self {
+        let reg = u32::from(self);
+        Self::from((reg & !(1 << 11)) | (u32::from(val) << 11))
+    }
+}
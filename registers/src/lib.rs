@@ -7,3 +7,11 @@ pub use caliptra_registers_latest::*;
 
 #[cfg(feature = "hw-1.0")]
 pub use caliptra_registers_1_0::*;
+
+// Hand-written until the RTL in hw/latest/rtl has the ML-DSA block
+#[cfg(not(feature = "hw-1.0"))]
+pub mod kv_mldsa;
+#[cfg(not(feature = "hw-1.0"))]
+pub mod mldsa;
+#[cfg(not(feature = "hw-1.0"))]
+pub mod soc_ifc_mldsa;
@@ -0,0 +1,449 @@
+// Licensed under the Apache-2.0 license.
+//
+// Hand-written in the style of caliptra_registers_generator: the caliptra-rtl
+// revision in hw/latest/rtl has no ML-DSA block to generate this from.
+// Replace it with the generated file once the RTL has the block.
+//
+#![allow(clippy::erasing_op)]
+#![allow(clippy::identity_op)]
+/// A zero-sized type that represents ownership of this
+/// peripheral, used to get access to a Register lock. Most
+/// programs create one of these in unsafe code near the top of
+/// main(), and pass it to the driver responsible for managing
+/// all access to the hardware.
+pub struct MldsaReg {
+    _priv: (),
+}
+impl MldsaReg {
+    pub const PTR: *mut u32 = 0x10030000 as *mut u32;
+    /// # Safety
+    ///
+    /// Caller must ensure that all concurrent use of this
+    /// peripheral in the firmware is done so in a compatible
+    /// way. The simplest way to enforce this is to only call
+    /// this function once.
+    #[inline(always)]
+    pub unsafe fn new() -> Self {
+        Self { _priv: () }
+    }
+    /// Returns a register block that can be used to read
+    /// registers from this peripheral, but cannot write.
+    #[inline(always)]
+    pub fn regs(&self) -> RegisterBlock<ureg::RealMmio> {
+        RegisterBlock {
+            ptr: Self::PTR,
+            mmio: core::default::Default::default(),
+        }
+    }
+    /// Return a register block that can be used to read and
+    /// write this peripheral's registers.
+    #[inline(always)]
+    pub fn regs_mut(&mut self) -> RegisterBlock<ureg::RealMmioMut> {
+        RegisterBlock {
+            ptr: Self::PTR,
+            mmio: core::default::Default::default(),
+        }
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RegisterBlock<TMmio: ureg::Mmio + core::borrow::Borrow<TMmio>> {
+    ptr: *mut u32,
+    mmio: TMmio,
+}
+impl<TMmio: ureg::Mmio + core::default::Default> RegisterBlock<TMmio> {
+    /// # Safety
+    ///
+    /// The caller is responsible for ensuring that ptr is valid for
+    /// volatile reads and writes at any of the offsets in this register
+    /// block.
+    #[inline(always)]
+    pub unsafe fn new(ptr: *mut u32) -> Self {
+        Self {
+            ptr,
+            mmio: core::default::Default::default(),
+        }
+    }
+}
+impl<TMmio: ureg::Mmio> RegisterBlock<TMmio> {
+    /// # Safety
+    ///
+    /// The caller is responsible for ensuring that ptr is valid for
+    /// volatile reads and writes at any of the offsets in this register
+    /// block.
+    #[inline(always)]
+    pub unsafe fn new_with_mmio(ptr: *mut u32, mmio: TMmio) -> Self {
+        Self { ptr, mmio }
+    }
+    /// Two 32-bit read-only registers representing of the name
+    /// of MLDSA component.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn name(&self) -> ureg::Array<2, ureg::RegRef<crate::mldsa::meta::Name, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// Two 32-bit read-only registers representing of the version
+    /// of MLDSA component.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn version(&self) -> ureg::Array<2, ureg::RegRef<crate::mldsa::meta::Version, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(8 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component control register type definition
+    ///
+    /// Read value: [`mldsa::regs::CtrlReadVal`]; Write value: [`mldsa::regs::CtrlWriteVal`]
+    #[inline(always)]
+    pub fn ctrl(&self) -> ureg::RegRef<crate::mldsa::meta::Ctrl, &TMmio> {
+        unsafe {
+            ureg::RegRef::new_with_mmio(
+                self.ptr.wrapping_add(0x10 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component status register type definition
+    ///
+    /// Read value: [`mldsa::regs::StatusReadVal`]; Write value: [`mldsa::regs::StatusWriteVal`]
+    #[inline(always)]
+    pub fn status(&self) -> ureg::RegRef<crate::mldsa::meta::Status, &TMmio> {
+        unsafe {
+            ureg::RegRef::new_with_mmio(
+                self.ptr.wrapping_add(0x18 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component IV register type definition
+    /// 16 32-bit registers storing the 512-bit IV required for SCA countermeasures.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn iv(&self) -> ureg::Array<16, ureg::RegRef<crate::mldsa::meta::Iv, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x80 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component seed register type definition
+    /// 8 32-bit registers storing the 256-bit seed for keygen.
+    /// The seed is written as little-endian words of the FIPS 204 byte string.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn seed(&self) -> ureg::Array<8, ureg::RegRef<crate::mldsa::meta::Seed, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x100 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component sign_rnd register type definition
+    /// 8 32-bit registers storing the 256-bit random value for hedged signing.
+    /// All zeros selects deterministic signing.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn sign_rnd(&self) -> ureg::Array<8, ureg::RegRef<crate::mldsa::meta::SignRnd, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x180 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component msg register type definition
+    /// 16 32-bit registers storing the 512-bit message to be signed or verified.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn msg(&self) -> ureg::Array<16, ureg::RegRef<crate::mldsa::meta::Msg, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x200 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component verify result register type definition
+    /// 16 32-bit registers storing the result of the verification.
+    /// On success, this holds the first 512 bits of the signature.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn verify_res(
+        &self,
+    ) -> ureg::Array<16, ureg::RegRef<crate::mldsa::meta::VerifyRes, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x280 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component private key output register type definition
+    /// 1224 32-bit registers storing the private key generated by keygen.
+    /// Reads as zero if the seed was read from the key vault.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn privkey_out(
+        &self,
+    ) -> ureg::Array<1224, ureg::RegRef<crate::mldsa::meta::PrivkeyOut, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x300 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component private key input register type definition
+    /// 1224 32-bit registers storing the private key for signing.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn privkey_in(
+        &self,
+    ) -> ureg::Array<1224, ureg::RegRef<crate::mldsa::meta::PrivkeyIn, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x1620 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component public key register type definition
+    /// 648 32-bit registers storing the public key.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn pubkey(&self) -> ureg::Array<648, ureg::RegRef<crate::mldsa::meta::Pubkey, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x2940 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// MLDSA component signature register type definition
+    /// 1157 32-bit registers storing the 4627-byte signature; the last
+    /// byte of the last register is unused.
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn signature(
+        &self,
+    ) -> ureg::Array<1157, ureg::RegRef<crate::mldsa::meta::Signature, &TMmio>> {
+        unsafe {
+            ureg::Array::new_with_mmio(
+                self.ptr.wrapping_add(0x3400 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// Controls the Key Vault read access for this engine
+    ///
+    /// Read value: [`regs::KvReadCtrlRegReadVal`]; Write value: [`regs::KvReadCtrlRegWriteVal`]
+    #[inline(always)]
+    pub fn kv_rd_seed_ctrl(&self) -> ureg::RegRef<crate::mldsa::meta::KvRdSeedCtrl, &TMmio> {
+        unsafe {
+            ureg::RegRef::new_with_mmio(
+                self.ptr.wrapping_add(0x4620 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// Reports the Key Vault flow status for this engine
+    ///
+    /// Read value: [`regs::KvStatusRegReadVal`]; Write value: [`regs::KvStatusRegWriteVal`]
+    #[inline(always)]
+    pub fn kv_rd_seed_status(&self) -> ureg::RegRef<crate::mldsa::meta::KvRdSeedStatus, &TMmio> {
+        unsafe {
+            ureg::RegRef::new_with_mmio(
+                self.ptr.wrapping_add(0x4624 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+}
+pub mod regs {
+    //! Types that represent the values held by registers.
+    #[derive(Clone, Copy)]
+    pub struct CtrlWriteVal(u32);
+    impl CtrlWriteVal {
+        /// Control command field: This can be:
+        /// [br]             000 for NONE
+        /// [br]             001 for KEYGEN
+        /// [br]             010 for SIGNING
+        /// [br]             011 for VERIFYING
+        /// [br]             100 for KEYGEN + SIGNING
+        /// [br] After each software write, hardware will erase the register
+        #[inline(always)]
+        pub fn ctrl(
+            self,
+            f: impl FnOnce(super::enums::selector::CtrlSelector) -> super::enums::Ctrl,
+        ) -> Self {
+            Self((self.0 & !(7 << 0)) | (u32::from(f(super::enums::selector::CtrlSelector())) << 0))
+        }
+        /// Zeroize all internal registers: Zeroize all internal registers after MLDSA process, to avoid SCA leakage.
+        /// [br] Software write generates only a single-cycle pulse on the
+        /// hardware interface and then will be erased
+        #[inline(always)]
+        pub fn zeroize(self, val: bool) -> Self {
+            Self((self.0 & !(1 << 3)) | (u32::from(val) << 3))
+        }
+    }
+    impl From<u32> for CtrlWriteVal {
+        #[inline(always)]
+        fn from(val: u32) -> Self {
+            Self(val)
+        }
+    }
+    impl From<CtrlWriteVal> for u32 {
+        #[inline(always)]
+        fn from(val: CtrlWriteVal) -> u32 {
+            val.0
+        }
+    }
+    #[derive(Clone, Copy)]
+    pub struct StatusReadVal(u32);
+    impl StatusReadVal {
+        /// Status ready bit: ​Indicates if the core is ready to take
+        /// a control command and process the block.
+        #[inline(always)]
+        pub fn ready(&self) -> bool {
+            ((self.0 >> 0) & 1) != 0
+        }
+        /// Status valid bit: ​Indicates if the process is done and the
+        /// results are valid.
+        #[inline(always)]
+        pub fn valid(&self) -> bool {
+            ((self.0 >> 1) & 1) != 0
+        }
+    }
+    impl From<u32> for StatusReadVal {
+        #[inline(always)]
+        fn from(val: u32) -> Self {
+            Self(val)
+        }
+    }
+    impl From<StatusReadVal> for u32 {
+        #[inline(always)]
+        fn from(val: StatusReadVal) -> u32 {
+            val.0
+        }
+    }
+}
+pub mod enums {
+    //! Enumerations used by some register fields.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum Ctrl {
+        None = 0,
+        Keygen = 1,
+        Signing = 2,
+        Verifying = 3,
+        KeygenSign = 4,
+        Reserved5 = 5,
+        Reserved6 = 6,
+        Reserved7 = 7,
+    }
+    impl Ctrl {
+        #[inline(always)]
+        pub fn none(&self) -> bool {
+            *self == Self::None
+        }
+        #[inline(always)]
+        pub fn keygen(&self) -> bool {
+            *self == Self::Keygen
+        }
+        #[inline(always)]
+        pub fn signing(&self) -> bool {
+            *self == Self::Signing
+        }
+        #[inline(always)]
+        pub fn verifying(&self) -> bool {
+            *self == Self::Verifying
+        }
+        #[inline(always)]
+        pub fn keygen_sign(&self) -> bool {
+            *self == Self::KeygenSign
+        }
+    }
+    impl TryFrom<u32> for Ctrl {
+        type Error = ();
+        #[inline(always)]
+        fn try_from(val: u32) -> Result<Ctrl, ()> {
+            if val < 8 {
+                Ok(unsafe { core::mem::transmute(val) })
+            } else {
+                Err(())
+            }
+        }
+    }
+    impl From<Ctrl> for u32 {
+        fn from(val: Ctrl) -> Self {
+            val as u32
+        }
+    }
+    pub mod selector {
+        pub struct CtrlSelector();
+        impl CtrlSelector {
+            #[inline(always)]
+            pub fn none(&self) -> super::Ctrl {
+                super::Ctrl::None
+            }
+            #[inline(always)]
+            pub fn keygen(&self) -> super::Ctrl {
+                super::Ctrl::Keygen
+            }
+            #[inline(always)]
+            pub fn signing(&self) -> super::Ctrl {
+                super::Ctrl::Signing
+            }
+            #[inline(always)]
+            pub fn verifying(&self) -> super::Ctrl {
+                super::Ctrl::Verifying
+            }
+            #[inline(always)]
+            pub fn keygen_sign(&self) -> super::Ctrl {
+                super::Ctrl::KeygenSign
+            }
+        }
+    }
+}
+pub mod meta {
+    //! Additional metadata needed by ureg.
+    pub type Name = ureg::ReadOnlyReg32<u32>;
+    pub type Version = ureg::ReadOnlyReg32<u32>;
+    pub type Ctrl = ureg::WriteOnlyReg32<0, crate::mldsa::regs::CtrlWriteVal>;
+    pub type Status = ureg::ReadOnlyReg32<crate::mldsa::regs::StatusReadVal>;
+    pub type Iv = ureg::WriteOnlyReg32<0, u32>;
+    pub type Seed = ureg::WriteOnlyReg32<0, u32>;
+    pub type SignRnd = ureg::WriteOnlyReg32<0, u32>;
+    pub type Msg = ureg::WriteOnlyReg32<0, u32>;
+    pub type VerifyRes = ureg::ReadOnlyReg32<u32>;
+    pub type PrivkeyOut = ureg::ReadOnlyReg32<u32>;
+    pub type PrivkeyIn = ureg::WriteOnlyReg32<0, u32>;
+    pub type Pubkey = ureg::ReadWriteReg32<0, u32, u32>;
+    pub type Signature = ureg::ReadWriteReg32<0, u32, u32>;
+    pub type KvRdSeedCtrl = ureg::ReadWriteReg32<
+        0,
+        crate::regs::KvReadCtrlRegReadVal,
+        crate::regs::KvReadCtrlRegWriteVal,
+    >;
+    pub type KvRdSeedStatus = ureg::ReadOnlyReg32<crate::regs::KvStatusRegReadVal>;
+}
@@ -0,0 +1,133 @@
+// Licensed under the Apache-2.0 license.
+//
+// Hand-written in the style of caliptra_registers_generator: the ML-DSA fuse
+// registers of the soc_ifc block are not in the caliptra-rtl revision in
+// hw/latest/rtl. Replace this with the generated soc_ifc registers once the
+// RTL has them.
+//
+#![allow(clippy::erasing_op)]
+#![allow(clippy::identity_op)]
+/// The ML-DSA fuse registers of the soc_ifc block. Offsets are from the
+/// start of the soc_ifc block, so this uses the same pointer as
+/// [`crate::soc_ifc::RegisterBlock`].
+#[derive(Clone, Copy)]
+pub struct RegisterBlock<TMmio: ureg::Mmio + core::borrow::Borrow<TMmio>> {
+    ptr: *mut u32,
+    mmio: TMmio,
+}
+impl<TMmio: ureg::Mmio + core::default::Default> RegisterBlock<TMmio> {
+    /// # Safety
+    ///
+    /// The caller is responsible for ensuring that ptr is valid for
+    /// volatile reads and writes at any of the offsets in this register
+    /// block.
+    #[inline(always)]
+    pub unsafe fn new(ptr: *mut u32) -> Self {
+        Self {
+            ptr,
+            mmio: core::default::Default::default(),
+        }
+    }
+}
+impl<TMmio: ureg::Mmio> RegisterBlock<TMmio> {
+    /// # Safety
+    ///
+    /// The caller is responsible for ensuring that ptr is valid for
+    /// volatile reads and writes at any of the offsets in this register
+    /// block.
+    #[inline(always)]
+    pub unsafe fn new_with_mmio(ptr: *mut u32, mmio: TMmio) -> Self {
+        Self { ptr, mmio }
+    }
+    /// 0 - Verify Caliptra firmware images without ML-DSA; 1 - Verify Caliptra firmware images with ML-DSA in addition
+    /// [br]Caliptra Access: RO
+    /// [br]SOC Access:      RWL-S
+    ///
+    /// Read value: [`soc_ifc_mldsa::regs::FuseMldsaVerifyReadVal`]; Write value: [`soc_ifc_mldsa::regs::FuseMldsaVerifyWriteVal`]
+    #[inline(always)]
+    pub fn fuse_mldsa_verify(
+        &self,
+    ) -> ureg::RegRef<crate::soc_ifc_mldsa::meta::FuseMldsaVerify, &TMmio> {
+        unsafe {
+            ureg::RegRef::new_with_mmio(
+                self.ptr.wrapping_add(0x34c / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+    /// Bits for revoking ML-DSA public keys in the key manifest
+    /// [br]Caliptra Access: RO
+    /// [br]SOC Access:      RWL-S
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn fuse_mldsa_revocation(
+        &self,
+    ) -> ureg::RegRef<crate::soc_ifc_mldsa::meta::FuseMldsaRevocation, &TMmio> {
+        unsafe {
+            ureg::RegRef::new_with_mmio(
+                self.ptr.wrapping_add(0x350 / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
+}
+pub mod regs {
+    //! Types that represent the values held by registers.
+    #[derive(Clone, Copy)]
+    pub struct FuseMldsaVerifyReadVal(u32);
+    impl FuseMldsaVerifyReadVal {
+        ///
+        #[inline(always)]
+        pub fn mldsa_verify(&self) -> bool {
+            ((self.0 >> 0) & 1) != 0
+        }
+        /// Construct a WriteVal that can be used to modify the contents of this register value.
+        #[inline(always)]
+        pub fn modify(self) -> FuseMldsaVerifyWriteVal {
+            FuseMldsaVerifyWriteVal(self.0)
+        }
+    }
+    impl From<u32> for FuseMldsaVerifyReadVal {
+        #[inline(always)]
+        fn from(val: u32) -> Self {
+            Self(val)
+        }
+    }
+    impl From<FuseMldsaVerifyReadVal> for u32 {
+        #[inline(always)]
+        fn from(val: FuseMldsaVerifyReadVal) -> u32 {
+            val.0
+        }
+    }
+    #[derive(Clone, Copy)]
+    pub struct FuseMldsaVerifyWriteVal(u32);
+    impl FuseMldsaVerifyWriteVal {
+        ///
+        #[inline(always)]
+        pub fn mldsa_verify(self, val: bool) -> Self {
+            Self((self.0 & !(1 << 0)) | (u32::from(val) << 0))
+        }
+    }
+    impl From<u32> for FuseMldsaVerifyWriteVal {
+        #[inline(always)]
+        fn from(val: u32) -> Self {
+            Self(val)
+        }
+    }
+    impl From<FuseMldsaVerifyWriteVal> for u32 {
+        #[inline(always)]
+        fn from(val: FuseMldsaVerifyWriteVal) -> u32 {
+            val.0
+        }
+    }
+}
+pub mod meta {
+    //! Additional metadata needed by ureg.
+    pub type FuseMldsaVerify = ureg::ReadWriteReg32<
+        0,
+        crate::soc_ifc_mldsa::regs::FuseMldsaVerifyReadVal,
+        crate::soc_ifc_mldsa::regs::FuseMldsaVerifyWriteVal,
+    >;
+    pub type FuseMldsaRevocation = ureg::ReadWriteReg32<0, u32, u32>;
+}
@@ -14,6 +14,7 @@ Abstract:
 #[cfg(feature = "fake-rom")]
 use crate::flow::fake::FakeRomImageVerificationEnv;
 use crate::fuse::log_fuse_data;
+use crate::loader;
 use crate::pcr;
 use crate::rom_env::RomEnv;
 use crate::run_fips_tests;
@@ -32,7 +33,9 @@ use caliptra_common::PcrLogEntryId;
 use caliptra_common::{FuseLogEntryId, RomBootStatus::*};
 use caliptra_drivers::pcr_log::MeasurementLogEntry;
 use caliptra_drivers::*;
-use caliptra_image_types::{ImageManifest, IMAGE_BYTE_SIZE};
+use caliptra_image_types::{
+    ImageExtendedToc, ImageManifest, IMAGE_BYTE_SIZE, IMAGE_MLDSA_PREAMBLE_BYTE_SIZE,
+};
 use caliptra_image_verify::{ImageVerificationInfo, ImageVerificationLogInfo, ImageVerifier};
 use caliptra_kat::KatsEnv;
 use caliptra_x509::{NotAfter, NotBefore};
@@ -102,21 +105,25 @@ impl FirmwareProcessor {
         };
 
         // Load the manifest
-        let manifest = Self::load_manifest(&mut env.persistent_data, &mut txn);
-        let manifest = okref(&manifest)?;
+        Self::load_manifest(&mut env.persistent_data, &mut txn)?;
 
         let mut venv = FirmwareImageVerificationEnv {
             sha256: &mut env.sha256,
             sha384: &mut env.sha384,
             soc_ifc: &mut env.soc_ifc,
             ecc384: &mut env.ecc384,
+            #[cfg(not(feature = "hw-1.0"))]
+            mldsa87: &mut env.mldsa87,
             data_vault: &mut env.data_vault,
             pcr_bank: &mut env.pcr_bank,
             image: txn.raw_mailbox_contents(),
         };
 
         // Verify the image
-        let info = Self::verify_image(&mut venv, manifest, txn.dlen());
+        let info = {
+            let pdata = env.persistent_data.get();
+            Self::verify_image(&mut venv, &pdata.manifest1, &pdata.extended_toc, txn.dlen())
+        };
         let info = okref(&info)?;
 
         Self::update_fuse_log(&mut env.persistent_data.get_mut().fuse_log, &info.log_info)?;
@@ -128,8 +135,17 @@ impl FirmwareProcessor {
         pcr::extend_pcrs(&mut venv, info, &mut env.persistent_data)?;
         report_boot_status(FwProcessorExtendPcrComplete.into());
 
+        // Copy the verified data blobs to persistent data
+        loader::load_data(
+            info,
+            venv.image,
+            &mut env.persistent_data.get_mut().image_data,
+        )?;
+
         // Load the image
-        Self::load_image(manifest, &mut txn)?;
+        let pdata = env.persistent_data.get();
+        let manifest = &pdata.manifest1;
+        Self::load_image(manifest, &pdata.extended_toc, &mut txn, &mut env.sha384)?;
 
         // Complete the mailbox transaction indicating success.
         txn.complete(true)?;
@@ -274,6 +290,10 @@ impl FirmwareProcessor {
 
                         let mut capabilities = Capabilities::default();
                         capabilities |= Capabilities::ROM_BASE;
+                        // GET_IDEV_CSR only succeeds if the CSR was generated.
+                        if !persistent_data.idevid_csr.is_unprovisioned() {
+                            capabilities |= Capabilities::ROM_GET_IDEV_CSR;
+                        }
 
                         let mut resp = CapabilitiesResp {
                             hdr: MailboxRespHeader::default(),
@@ -341,20 +361,23 @@ impl FirmwareProcessor {
         }
     }
 
-    /// Load the manifest
+    /// Load the manifest and the extended TOC into persistent data
     ///
-    /// # Returns
-    ///
-    /// * `Manifest` - Caliptra Image Bundle Manifest
+    /// The manifest is not returned by value, as it is too large to copy
+    /// onto the ROM stack.
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
     fn load_manifest(
         persistent_data: &mut PersistentDataAccessor,
         txn: &mut MailboxRecvTxn,
-    ) -> CaliptraResult<ImageManifest> {
-        let manifest = &mut persistent_data.get_mut().manifest1;
-        txn.copy_request(manifest.as_mut_bytes())?;
+    ) -> CaliptraResult<()> {
+        let pdata = persistent_data.get_mut();
+        txn.copy_request(pdata.manifest1.as_mut_bytes())?;
+        txn.copy_request(pdata.extended_toc.as_mut_bytes())?;
+
+        // The verifier reads the ML-DSA preamble in place
+        txn.drop_words(IMAGE_MLDSA_PREAMBLE_BYTE_SIZE / 4)?;
         report_boot_status(FwProcessorManifestLoadComplete.into());
-        Ok(*manifest)
+        Ok(())
     }
 
     /// Verify the image
@@ -366,8 +389,11 @@ impl FirmwareProcessor {
     fn verify_image(
         venv: &mut FirmwareImageVerificationEnv,
         manifest: &ImageManifest,
+        toc: &ImageExtendedToc,
         img_bundle_sz: u32,
     ) -> CaliptraResult<ImageVerificationInfo> {
+        let mldsa = venv.mldsa_preamble()?;
+
         #[cfg(feature = "fake-rom")]
         let venv = &mut FakeRomImageVerificationEnv {
             sha256: venv.sha256,
@@ -375,6 +401,8 @@ impl FirmwareProcessor {
             soc_ifc: venv.soc_ifc,
             data_vault: venv.data_vault,
             ecc384: venv.ecc384,
+            #[cfg(not(feature = "hw-1.0"))]
+            mldsa87: venv.mldsa87,
             image: venv.image,
         };
 
@@ -385,7 +413,7 @@ impl FirmwareProcessor {
         CfiCounter::delay();
 
         let mut verifier = ImageVerifier::new(venv);
-        let info = verifier.verify(manifest, img_bundle_sz, ResetReason::ColdReset)?;
+        let info = verifier.verify(manifest, toc, mldsa, img_bundle_sz, ResetReason::ColdReset)?;
 
         cprintln!(
             "[fwproc] Img verified w/ Vendor ECC Key Idx {}",
@@ -486,6 +514,26 @@ impl FirmwareProcessor {
             )?;
         }
 
+        // Log VendorMlDsaPubKeyIndex
+        if let Some(vendor_mldsa_pub_key_idx) = log_info.vendor_mldsa_pub_key_idx {
+            log_fuse_data(
+                log,
+                FuseLogEntryId::VendorMlDsaPubKeyIndex,
+                vendor_mldsa_pub_key_idx.as_bytes(),
+            )?;
+        }
+
+        // Log VendorMlDsaPubKeyRevocation
+        if let Some(fuse_vendor_mldsa_pub_key_revocation) =
+            log_info.fuse_vendor_mldsa_pub_key_revocation
+        {
+            log_fuse_data(
+                log,
+                FuseLogEntryId::VendorMlDsaPubKeyRevocation,
+                fuse_vendor_mldsa_pub_key_revocation.as_bytes(),
+            )?;
+        }
+
         Ok(())
     }
 
@@ -493,13 +541,19 @@ impl FirmwareProcessor {
     ///
     /// # Arguments
     ///
-    /// * `env`      - ROM Environment
     /// * `manifest` - Manifest
+    /// * `toc`      - Extended TOC
     /// * `txn`      - Mailbox Receive Transaction
+    /// * `sha384`   - SHA384
     // Inlined to reduce ROM size
     #[inline(always)]
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
-    fn load_image(manifest: &ImageManifest, txn: &mut MailboxRecvTxn) -> CaliptraResult<()> {
+    fn load_image(
+        manifest: &ImageManifest,
+        toc: &ImageExtendedToc,
+        txn: &mut MailboxRecvTxn,
+        sha384: &mut Sha384,
+    ) -> CaliptraResult<()> {
         cprintln!(
             "[fwproc] Load FMC at address 0x{:08x} len {}",
             manifest.fmc.load_addr,
@@ -513,18 +567,22 @@ impl FirmwareProcessor {
 
         txn.copy_request(fmc_dest.as_mut_bytes())?;
 
-        cprintln!(
-            "[fwproc] Load Runtime at address 0x{:08x} len {}",
-            manifest.runtime.load_addr,
-            manifest.runtime.size
-        );
-
-        let runtime_dest = unsafe {
-            let addr = (manifest.runtime.load_addr) as *mut u32;
-            core::slice::from_raw_parts_mut(addr, manifest.runtime.size as usize / 4)
-        };
-
-        txn.copy_request(runtime_dest.as_mut_bytes())?;
+        if manifest.runtime.is_compressed() {
+            loader::inflate_runtime(manifest, toc, txn.raw_mailbox_contents(), sha384)?;
+        } else {
+            cprintln!(
+                "[fwproc] Load Runtime at address 0x{:08x} len {}",
+                manifest.runtime.load_addr,
+                manifest.runtime.size
+            );
+
+            let runtime_dest = unsafe {
+                let addr = (manifest.runtime.load_addr) as *mut u32;
+                core::slice::from_raw_parts_mut(addr, manifest.runtime.size as usize / 4)
+            };
+
+            txn.copy_request(runtime_dest.as_mut_bytes())?;
+        }
 
         report_boot_status(FwProcessorLoadImageComplete.into());
         Ok(())
@@ -565,8 +623,19 @@ impl FirmwareProcessor {
             info.vendor_lms_pub_key_idx.unwrap_or(u32::MAX),
         );
 
+        // Likewise if ML-DSA is not enabled.
+        data_vault.write_cold_reset_entry4(
+            ColdResetEntry4::MlDsaVendorPubKeyIndex,
+            info.vendor_mldsa_pub_key_idx.unwrap_or(u32::MAX),
+        );
+
         data_vault.write_warm_reset_entry48(WarmResetEntry48::RtTci, &info.runtime.digest.into());
 
+        data_vault.write_warm_reset_entry48(
+            WarmResetEntry48::MldsaPreambleDigest,
+            &info.mldsa_preamble_digest.unwrap_or_default().into(),
+        );
+
         data_vault.write_warm_reset_entry4(WarmResetEntry4::RtSvn, info.runtime.svn);
 
         data_vault.write_warm_reset_entry4(WarmResetEntry4::RtEntryPoint, info.runtime.entry_point);
@@ -717,7 +786,10 @@ impl FirmwareProcessor {
         stash_measurement: &StashMeasurementReq,
     ) -> CaliptraResult<()> {
         let fht = &mut persistent_data.fht;
-        let Some(dst) = persistent_data.measurement_log.get_mut(fht.meas_log_index as usize) else {
+        let Some(dst) = persistent_data
+            .measurement_log
+            .get_mut(fht.meas_log_index as usize)
+        else {
             return Err(CaliptraError::ROM_GLOBAL_MEASUREMENT_LOG_EXHAUSTED);
         };
 
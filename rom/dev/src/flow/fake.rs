@@ -36,6 +36,8 @@ use caliptra_image_types::*;
 use caliptra_image_verify::ImageVerificationEnv;
 use core::ops::Range;
 use fw_processor::FirmwareProcessor;
+#[cfg(not(feature = "hw-1.0"))]
+use zerocopy::{FromBytes, IntoBytes};
 
 const FAKE_LDEV_TBS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/ldev_tbs.der"));
 const FAKE_LDEV_PUB_KEY: Ecc384PubKey = Ecc384PubKey {
@@ -226,6 +228,8 @@ pub(crate) struct FakeRomImageVerificationEnv<'a, 'b> {
     pub(crate) soc_ifc: &'a mut SocIfc,
     pub(crate) data_vault: &'a mut DataVault,
     pub(crate) ecc384: &'a mut Ecc384,
+    #[cfg(not(feature = "hw-1.0"))]
+    pub(crate) mldsa87: &'a mut MlDsa87,
     pub image: &'b [u8],
 }
 
@@ -287,6 +291,37 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         }
     }
 
+    #[cfg(not(feature = "hw-1.0"))]
+    fn mldsa87_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> CaliptraResult<LEArray4x16> {
+        if self.soc_ifc.verify_in_fake_mode() {
+            let err = CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE;
+            let pub_key = MlDsa87PubKey::ref_from_bytes(pub_key.as_bytes()).map_err(|_| err)?;
+            let sig = MlDsa87Signature::ref_from_bytes(sig.as_bytes()).map_err(|_| err)?;
+            let msg = MlDsa87Msg::new(mldsa_msg_from_digest(digest));
+            self.mldsa87.verify_res(pub_key, &msg, sig)
+        } else {
+            // Mock verify, just always return success
+            let mut verify_res = LEArray4x16::default();
+            verify_res.0.copy_from_slice(&sig.0[..16]);
+            Ok(verify_res)
+        }
+    }
+
+    #[cfg(feature = "hw-1.0")]
+    fn mldsa87_verify(
+        &mut self,
+        _digest: &ImageDigest,
+        _pub_key: &ImageMldsaPubKey,
+        _sig: &ImageMldsaSignature,
+    ) -> CaliptraResult<LEArray4x16> {
+        Err(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE)
+    }
+
     /// Retrieve Vendor Public Key Digest
     fn vendor_pub_key_digest(&self) -> ImageDigest {
         self.soc_ifc.fuse_bank().vendor_pub_key_hash().into()
@@ -302,6 +337,17 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         self.soc_ifc.fuse_bank().vendor_lms_pub_key_revocation()
     }
 
+    /// Retrieve Vendor ML-DSA Public Key Revocation Bitmask
+    #[cfg(not(feature = "hw-1.0"))]
+    fn vendor_mldsa_pub_key_revocation(&self) -> u32 {
+        self.soc_ifc.fuse_bank().vendor_mldsa_pub_key_revocation()
+    }
+
+    #[cfg(feature = "hw-1.0")]
+    fn vendor_mldsa_pub_key_revocation(&self) -> u32 {
+        0
+    }
+
     /// Retrieve Owner Public Key Digest from fuses
     fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
         self.soc_ifc.fuse_bank().owner_pub_key_hash().into()
@@ -327,6 +373,11 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         self.data_vault.lms_vendor_pk_index()
     }
 
+    /// Get the vendor ML-DSA key index saved in data vault on cold boot
+    fn vendor_mldsa_pub_key_idx_dv(&self) -> u32 {
+        self.data_vault.mldsa_vendor_pk_index()
+    }
+
     /// Get the owner public key digest saved in the dv on cold boot
     fn owner_pub_key_digest_dv(&self) -> ImageDigest {
         self.data_vault.owner_pk_hash().into()
@@ -355,6 +406,16 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         self.soc_ifc.fuse_bank().lms_verify() == RomVerifyConfig::EcdsaAndLms
     }
 
+    #[cfg(not(feature = "hw-1.0"))]
+    fn mldsa_verify_enabled(&self) -> bool {
+        self.soc_ifc.fuse_bank().mldsa_verify()
+    }
+
+    #[cfg(feature = "hw-1.0")]
+    fn mldsa_verify_enabled(&self) -> bool {
+        false
+    }
+
     fn set_fw_extended_error(&mut self, err: u32) {
         self.soc_ifc.set_fw_extended_error(err);
     }
@@ -13,7 +13,7 @@ Abstract:
 --*/
 #[cfg(feature = "fake-rom")]
 use crate::flow::fake::FakeRomImageVerificationEnv;
-use crate::{cprintln, pcr, rom_env::RomEnv};
+use crate::{cprintln, loader, pcr, rom_env::RomEnv};
 use caliptra_common::verifier::FirmwareImageVerificationEnv;
 
 use caliptra_cfi_derive::cfi_impl_fn;
@@ -21,11 +21,12 @@ use caliptra_common::mailbox_api::CommandId;
 use caliptra_common::RomBootStatus::*;
 use caliptra_drivers::report_fw_error_non_fatal;
 use caliptra_drivers::{
-    okref, report_boot_status, MailboxRecvTxn, ResetReason, WarmResetEntry4, WarmResetEntry48,
+    okref, report_boot_status, MailboxRecvTxn, ResetReason, Sha384, WarmResetEntry4,
+    WarmResetEntry48,
 };
 use caliptra_drivers::{DataVault, PersistentData};
 use caliptra_error::{CaliptraError, CaliptraResult};
-use caliptra_image_types::ImageManifest;
+use caliptra_image_types::{ImageExtendedToc, ImageManifest, IMAGE_MLDSA_PREAMBLE_BYTE_SIZE};
 use caliptra_image_verify::{ImageVerificationInfo, ImageVerifier};
 use zerocopy::IntoBytes;
 
@@ -56,13 +57,21 @@ impl UpdateResetFlow {
             return Err(CaliptraError::ROM_UPDATE_RESET_FLOW_MAILBOX_ACCESS_FAILURE);
         };
 
+        // Only replaces the extended TOC in persistent data once the new
+        // manifest does, so the two always describe the same image.
+        let mut extended_toc = ImageExtendedToc::default();
+
         let mut process_txn = || -> CaliptraResult<()> {
             if recv_txn.cmd() != CommandId::FIRMWARE_LOAD.into() {
                 cprintln!("Invalid command 0x{:08x} recv", recv_txn.cmd());
                 return Err(CaliptraError::ROM_UPDATE_RESET_FLOW_INVALID_FIRMWARE_COMMAND);
             }
 
-            Self::load_manifest(env.persistent_data.get_mut(), &mut recv_txn)?;
+            Self::load_manifest(
+                env.persistent_data.get_mut(),
+                &mut extended_toc,
+                &mut recv_txn,
+            )?;
             report_boot_status(UpdateResetLoadManifestComplete.into());
 
             let mut venv = FirmwareImageVerificationEnv {
@@ -70,6 +79,8 @@ impl UpdateResetFlow {
                 sha384: &mut env.sha384,
                 soc_ifc: &mut env.soc_ifc,
                 ecc384: &mut env.ecc384,
+                #[cfg(not(feature = "hw-1.0"))]
+                mldsa87: &mut env.mldsa87,
                 data_vault: &mut env.data_vault,
                 pcr_bank: &mut env.pcr_bank,
                 image: recv_txn.raw_mailbox_contents(),
@@ -77,7 +88,7 @@ impl UpdateResetFlow {
 
             let info = {
                 let manifest = &env.persistent_data.get().manifest2;
-                Self::verify_image(&mut venv, manifest, recv_txn.dlen())
+                Self::verify_image(&mut venv, manifest, &extended_toc, recv_txn.dlen())
             };
             let info = okref(&info)?;
             report_boot_status(UpdateResetImageVerificationComplete.into());
@@ -89,13 +100,20 @@ impl UpdateResetFlow {
             pcr::extend_pcrs(&mut venv, info, &mut env.persistent_data)?;
             report_boot_status(UpdateResetExtendPcrComplete.into());
 
+            // Copy the verified data blobs to persistent data
+            loader::load_data(
+                info,
+                venv.image,
+                &mut env.persistent_data.get_mut().image_data,
+            )?;
+
             cprintln!(
                 "[update-reset] Img verified w/ Vendor ECC Key Index {}",
                 info.vendor_ecc_pub_key_idx
             );
 
             let manifest = &env.persistent_data.get().manifest2;
-            Self::load_image(manifest, &mut recv_txn)?;
+            Self::load_image(manifest, &extended_toc, &mut recv_txn, &mut env.sha384)?;
             Ok(())
         };
         if let Err(e) = process_txn() {
@@ -114,6 +132,7 @@ impl UpdateResetFlow {
         let persistent_data = env.persistent_data.get_mut();
         cprintln!("[update-reset] Copying MAN_2 To MAN_1");
         persistent_data.manifest1 = persistent_data.manifest2;
+        persistent_data.extended_toc = extended_toc;
         report_boot_status(UpdateResetOverwriteManifestComplete.into());
 
         // Set RT version. FMC does not change.
@@ -137,13 +156,17 @@ impl UpdateResetFlow {
     ///
     /// * `env` - ROM Environment
     /// * 'manifest'- Manifest
+    /// * 'toc'     - Extended TOC
     ///
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
     fn verify_image(
         env: &mut FirmwareImageVerificationEnv,
         manifest: &ImageManifest,
+        toc: &ImageExtendedToc,
         img_bundle_sz: u32,
     ) -> CaliptraResult<ImageVerificationInfo> {
+        let mldsa = env.mldsa_preamble()?;
+
         #[cfg(feature = "fake-rom")]
         let env = &mut FakeRomImageVerificationEnv {
             sha256: env.sha256,
@@ -151,12 +174,20 @@ impl UpdateResetFlow {
             soc_ifc: env.soc_ifc,
             data_vault: env.data_vault,
             ecc384: env.ecc384,
+            #[cfg(not(feature = "hw-1.0"))]
+            mldsa87: env.mldsa87,
             image: env.image,
         };
 
         let mut verifier = ImageVerifier::new(env);
 
-        let info = verifier.verify(manifest, img_bundle_sz, ResetReason::UpdateReset)?;
+        let info = verifier.verify(
+            manifest,
+            toc,
+            mldsa,
+            img_bundle_sz,
+            ResetReason::UpdateReset,
+        )?;
 
         Ok(info)
     }
@@ -165,26 +196,36 @@ impl UpdateResetFlow {
     ///
     /// # Arguments
     ///
-    /// * `env`      - ROM Environment
     /// * `manifest` - Manifest
+    /// * `toc`      - Extended TOC
     /// * `txn`      - Mailbox Receive Transaction
+    /// * `sha384`   - SHA384
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
-    fn load_image(manifest: &ImageManifest, txn: &mut MailboxRecvTxn) -> CaliptraResult<()> {
-        cprintln!(
-            "[update-reset] Loading Runtime at addr 0x{:08x} len {}",
-            manifest.runtime.load_addr,
-            manifest.runtime.size
-        );
+    fn load_image(
+        manifest: &ImageManifest,
+        toc: &ImageExtendedToc,
+        txn: &mut MailboxRecvTxn,
+        sha384: &mut Sha384,
+    ) -> CaliptraResult<()> {
+        if manifest.runtime.is_compressed() {
+            loader::inflate_runtime(manifest, toc, txn.raw_mailbox_contents(), sha384)?;
+        } else {
+            cprintln!(
+                "[update-reset] Loading Runtime at addr 0x{:08x} len {}",
+                manifest.runtime.load_addr,
+                manifest.runtime.size
+            );
 
-        // Throw away the FMC portion of the image
-        txn.drop_words(manifest.fmc.size as usize / 4)?;
+            // Throw away the FMC portion of the image
+            txn.drop_words(manifest.fmc.size as usize / 4)?;
 
-        let runtime_dest = unsafe {
-            let addr = (manifest.runtime.load_addr) as *mut u32;
-            core::slice::from_raw_parts_mut(addr, manifest.runtime.size as usize / 4)
-        };
+            let runtime_dest = unsafe {
+                let addr = (manifest.runtime.load_addr) as *mut u32;
+                core::slice::from_raw_parts_mut(addr, manifest.runtime.size as usize / 4)
+            };
 
-        txn.copy_request(runtime_dest.as_mut_bytes())?;
+            txn.copy_request(runtime_dest.as_mut_bytes())?;
+        }
 
         //Call the complete here to reset the execute bit
         txn.complete(true)?;
@@ -192,7 +233,7 @@ impl UpdateResetFlow {
         Ok(())
     }
 
-    /// Load the manifest
+    /// Load the manifest and the extended TOC
     ///
     /// # Returns
     ///
@@ -200,9 +241,14 @@ impl UpdateResetFlow {
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
     fn load_manifest(
         persistent_data: &mut PersistentData,
+        extended_toc: &mut ImageExtendedToc,
         txn: &mut MailboxRecvTxn,
     ) -> CaliptraResult<()> {
         txn.copy_request(persistent_data.manifest2.as_mut_bytes())?;
+        txn.copy_request(extended_toc.as_mut_bytes())?;
+
+        // The verifier reads the ML-DSA preamble in place
+        txn.drop_words(IMAGE_MLDSA_PREAMBLE_BYTE_SIZE / 4)?;
         Ok(())
     }
 
@@ -215,6 +261,11 @@ impl UpdateResetFlow {
     fn populate_data_vault(data_vault: &mut DataVault, info: &ImageVerificationInfo) {
         data_vault.write_warm_reset_entry48(WarmResetEntry48::RtTci, &info.runtime.digest.into());
 
+        data_vault.write_warm_reset_entry48(
+            WarmResetEntry48::MldsaPreambleDigest,
+            &info.mldsa_preamble_digest.unwrap_or_default().into(),
+        );
+
         data_vault.write_warm_reset_entry4(WarmResetEntry4::RtSvn, info.runtime.svn);
 
         data_vault.write_warm_reset_entry4(WarmResetEntry4::RtEntryPoint, info.runtime.entry_point);
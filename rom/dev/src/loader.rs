@@ -0,0 +1,107 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    loader.rs
+
+Abstract:
+
+    File contains the routines shared by the cold reset and update reset
+    flows to load the verified image contents.
+
+--*/
+
+use crate::cprintln;
+use caliptra_cfi_derive::cfi_mod_fn;
+use caliptra_cfi_lib::{cfi_assert_eq_12_words, cfi_launder};
+use caliptra_drivers::{lz4_decompress, CaliptraError, CaliptraResult, Sha384, IMAGE_DATA_SIZE};
+use caliptra_image_types::{ImageExtendedToc, ImageManifest};
+use caliptra_image_verify::ImageVerificationInfo;
+use zerocopy::IntoBytes;
+
+/// Copy the data blobs to DCCM, packed in TOC order
+///
+/// # Arguments
+///
+/// * `info`       - Image Verification Info
+/// * `image`      - Image bundle in the mailbox SRAM
+/// * `image_data` - Destination in persistent data
+#[cfg_attr(not(feature = "no-cfi"), cfi_mod_fn)]
+pub(crate) fn load_data(
+    info: &ImageVerificationInfo,
+    image: &[u8],
+    image_data: &mut [u8; IMAGE_DATA_SIZE as usize],
+) -> CaliptraResult<()> {
+    let err = CaliptraError::ROM_GLOBAL_IMAGE_DATA_LOAD_FAILURE;
+    let mut offset = 0;
+    for data in info.data.iter().take(info.data_count as usize) {
+        cprintln!("[loader] Load data {} len {}", data.id, data.size);
+
+        let size = data.size as usize;
+        let src = image
+            .get(data.offset as usize..)
+            .and_then(|src| src.get(..size))
+            .ok_or(err)?;
+        image_data
+            .get_mut(offset..)
+            .and_then(|dst| dst.get_mut(..size))
+            .ok_or(err)?
+            .copy_from_slice(src);
+        offset += size;
+    }
+    image_data[offset..].fill(0);
+
+    Ok(())
+}
+
+/// Inflate the compressed runtime into ICCM and check the expanded image
+/// against the runtime expansion information in the extended TOC
+///
+/// # Arguments
+///
+/// * `manifest` - Manifest
+/// * `toc`      - Extended TOC
+/// * `image`    - Image bundle in the mailbox SRAM
+/// * `sha384`   - SHA384
+#[cfg_attr(not(feature = "no-cfi"), cfi_mod_fn)]
+pub(crate) fn inflate_runtime(
+    manifest: &ImageManifest,
+    toc: &ImageExtendedToc,
+    image: &[u8],
+    sha384: &mut Sha384,
+) -> CaliptraResult<()> {
+    let runtime = &manifest.runtime;
+    let expansion = &toc.runtime_expansion;
+    cprintln!(
+        "[loader] Inflate Runtime at address 0x{:08x} len {}",
+        runtime.load_addr,
+        expansion.size
+    );
+
+    let range = runtime.image_range()?;
+    let src = image
+        .get(range.start as usize..range.end as usize)
+        .ok_or(CaliptraError::DRIVER_LZ4_INVALID_INPUT)?;
+
+    // The verifier checked that the expanded image lies within ICCM.
+    let expanded_size = expansion.size as usize;
+    let runtime_dest = unsafe {
+        let addr = (runtime.load_addr) as *mut u32;
+        core::slice::from_raw_parts_mut(addr, (expanded_size + 3) / 4)
+    };
+
+    if lz4_decompress(src, runtime_dest)? != expanded_size {
+        Err(CaliptraError::ROM_GLOBAL_RUNTIME_EXPANDED_SIZE_MISMATCH)?;
+    }
+
+    let actual = sha384.digest(&runtime_dest.as_bytes()[..expanded_size])?.0;
+    if cfi_launder(expansion.digest) != actual {
+        Err(CaliptraError::ROM_GLOBAL_RUNTIME_EXPANDED_DIGEST_MISMATCH)?;
+    } else {
+        cfi_assert_eq_12_words(&expansion.digest, &actual);
+    }
+
+    Ok(())
+}
@@ -78,6 +78,10 @@ fn lock_cold_reset_reg(env: &mut RomEnv) {
     env.data_vault
         .lock_cold_reset_entry4(ColdResetEntry4::LmsVendorPubKeyIndex);
 
+    // Lock the ML-DSA Vendor Public Key Index in data vault until next cold reset
+    env.data_vault
+        .lock_cold_reset_entry4(ColdResetEntry4::MlDsaVendorPubKeyIndex);
+
     // Lock Cold Reset Status register in data vault until next cold reset
     env.data_vault
         .lock_cold_reset_entry4(ColdResetEntry4::RomColdBootStatus);
@@ -94,6 +98,10 @@ fn lock_common_reg_set(env: &mut RomEnv) {
     env.data_vault
         .lock_warm_reset_entry48(WarmResetEntry48::RtTci);
 
+    // Lock the ML-DSA preamble digest in data vault until next reset
+    env.data_vault
+        .lock_warm_reset_entry48(WarmResetEntry48::MldsaPreambleDigest);
+
     // Lock the Runtime SVN in data vault until next reset
     env.data_vault
         .lock_warm_reset_entry4(WarmResetEntry4::RtSvn);
@@ -45,6 +45,7 @@ mod exception;
 mod fht;
 mod flow;
 mod fuse;
+mod loader;
 mod lock;
 mod pcr;
 mod rom_env;
@@ -28,7 +28,8 @@ use caliptra_common::{
     PcrLogEntry, PcrLogEntryId,
 };
 use caliptra_drivers::{
-    CaliptraError, CaliptraResult, PcrBank, PersistentData, PersistentDataAccessor, Sha384,
+    Array4x12, CaliptraError, CaliptraResult, PcrBank, PersistentData, PersistentDataAccessor,
+    Sha384,
 };
 use caliptra_image_verify::ImageVerificationInfo;
 
@@ -104,6 +105,13 @@ pub(crate) fn extend_pcrs(
         PcrLogEntryId::FmcTci,
     )?;
 
+    for data in info.data.iter().take(info.data_count as usize) {
+        pcr.extend(
+            &<[u8; 48]>::from(&Array4x12::from(data.digest)),
+            PcrLogEntryId::ImageData,
+        )?;
+    }
+
     Ok(())
 }
 
@@ -16,11 +16,15 @@ Abstract:
 --*/
 
 use crate::fht::FhtDataStore;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_drivers::MlDsa87;
 use caliptra_drivers::{
     DataVault, DeobfuscationEngine, Ecc384, Hmac384, KeyVault, Lms, Mailbox, PcrBank,
     PersistentDataAccessor, Sha1, Sha256, Sha2_512_384Acc, Sha384, SocIfc, Trng,
 };
 use caliptra_error::CaliptraResult;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_registers::mldsa::MldsaReg;
 use caliptra_registers::{
     csrng::CsrngReg, doe::DoeReg, dv::DvReg, ecc::EccReg, entropy_src::EntropySrcReg,
     hmac::HmacReg, kv::KvReg, mbox::MboxCsr, pv::PvReg, sha256::Sha256Reg, sha512::Sha512Reg,
@@ -53,6 +57,10 @@ pub struct RomEnv {
     /// LMS Engine
     pub lms: Lms,
 
+    /// ML-DSA-87 Engine
+    #[cfg(not(feature = "hw-1.0"))]
+    pub mldsa87: MlDsa87,
+
     /// Key Vault
     pub key_vault: KeyVault,
 
@@ -96,6 +104,8 @@ impl RomEnv {
             hmac384: Hmac384::new(HmacReg::new()),
             ecc384: Ecc384::new(EccReg::new()),
             lms: Lms::default(),
+            #[cfg(not(feature = "hw-1.0"))]
+            mldsa87: MlDsa87::new(MldsaReg::new()),
             key_vault: KeyVault::new(KvReg::new()),
             data_vault: DataVault::new(DvReg::new()),
             soc_ifc: SocIfc::new(SocIfcReg::new()),
@@ -3,9 +3,10 @@
 use caliptra_builder::ImageOptions;
 use caliptra_common::capabilities::Capabilities;
 use caliptra_common::mailbox_api::{
-    CapabilitiesResp, CommandId, MailboxReqHeader, MailboxRespHeader,
+    AuthorizeAndStashReq, CapabilitiesResp, CommandId, MailboxReqHeader, MailboxRespHeader,
 };
-use caliptra_hw_model::{Fuses, HwModel};
+use caliptra_drivers::MfgFlags;
+use caliptra_hw_model::{Fuses, HwModel, ModelError};
 use zerocopy::{FromBytes, IntoBytes};
 
 use crate::helpers;
@@ -42,4 +43,39 @@ fn test_capabilities() {
     // Verify Capabilities
     let caps = Capabilities::try_from(capabilities_resp.capabilities.as_bytes()).unwrap();
     assert!(caps.contains(Capabilities::ROM_BASE));
+    // No CSR was generated.
+    assert!(!caps.contains(Capabilities::ROM_GET_IDEV_CSR));
+    assert!(!caps.intersects(Capabilities::RT_BASE));
+}
+
+#[test]
+fn test_capabilities_idev_csr() {
+    let (mut hw, _image_bundle) =
+        helpers::build_hw_model_and_image_bundle(Fuses::default(), ImageOptions::default());
+
+    hw.soc_ifc()
+        .cptra_dbg_manuf_service_reg()
+        .write(|_| MfgFlags::GENERATE_IDEVID_CSR.bits());
+    helpers::get_csr(&mut hw).unwrap();
+
+    let caps = hw.capabilities().unwrap();
+    assert!(caps.contains(Capabilities::ROM_BASE));
+    assert!(caps.contains(Capabilities::ROM_GET_IDEV_CSR));
+}
+
+#[test]
+fn test_capabilities_gate_unsupported_command() {
+    let (mut hw, _image_bundle) =
+        helpers::build_hw_model_and_image_bundle(Fuses::default(), ImageOptions::default());
+
+    let caps = hw.capabilities().unwrap();
+    assert!(caps.contains(Capabilities::ROM_BASE));
+
+    // ROM does not support AUTHORIZE_AND_STASH; the request must not be sent.
+    assert_eq!(
+        hw.mailbox_execute_req_if_supported(caps, AuthorizeAndStashReq::default()),
+        Err(ModelError::MailboxCmdNotSupported(
+            CommandId::AUTHORIZE_AND_STASH.into()
+        ))
+    );
 }
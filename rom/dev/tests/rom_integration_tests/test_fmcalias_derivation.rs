@@ -20,7 +20,7 @@ use caliptra_error::CaliptraError;
 use caliptra_hw_model::{BootParams, Fuses, HwModel, InitParams, ModelError, SecurityState};
 use caliptra_image_crypto::OsslCrypto as Crypto;
 use caliptra_image_fake_keys::{OWNER_CONFIG, VENDOR_CONFIG_KEY_1};
-use caliptra_image_gen::ImageGenerator;
+use caliptra_image_gen::{ImageGenerator, ImageGeneratorData};
 use caliptra_image_types::IMAGE_BYTE_SIZE;
 use caliptra_test::swap_word_bytes;
 use openssl::hash::{Hasher, MessageDigest};
@@ -402,6 +402,73 @@ fn test_pcr_log_fmc_fuse_svn() {
     );
 }
 
+#[test]
+fn test_pcr_log_image_data() {
+    let gen = ImageGenerator::new(Crypto::default());
+    let image_options = ImageOptions {
+        data: vec![
+            ImageGeneratorData {
+                id: 0x1000,
+                content: vec![0x11; 100],
+                ..Default::default()
+            },
+            ImageGeneratorData {
+                id: 0x2000,
+                content: vec![0x22; 5],
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    let image_bundle = caliptra_builder::build_and_sign_image(
+        &TEST_FMC_INTERACTIVE,
+        &APP_WITH_UART,
+        image_options,
+    )
+    .unwrap();
+
+    let fuses = Fuses {
+        key_manifest_pk_hash: gen
+            .vendor_pubkey_digest(&image_bundle.manifest.preamble)
+            .unwrap(),
+        owner_pk_hash: gen
+            .owner_pubkey_digest(&image_bundle.manifest.preamble)
+            .unwrap(),
+        ..Default::default()
+    };
+    let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
+    let mut hw = caliptra_hw_model::new(
+        InitParams {
+            rom: &rom,
+            security_state: SecurityState::from(fuses.life_cycle as u32),
+            ..Default::default()
+        },
+        BootParams {
+            fuses,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+
+    let pcr_entry_arr = hw.mailbox_execute(0x1000_0000, &[]).unwrap().unwrap();
+
+    // The data blobs are measured after the FMC, in TOC order.
+    for (i, entry) in image_bundle.extended_toc.data[..2].iter().enumerate() {
+        check_pcr_log_entry(
+            &pcr_entry_arr,
+            4 + i,
+            PcrLogEntryId::ImageData,
+            PCR0_AND_PCR1_EXTENDED_ID,
+            swap_word_bytes(&entry.digest).as_bytes(),
+        );
+    }
+}
+
 fn hash_pcr_log_entry(entry: &PcrLogEntry, pcr: &mut [u8; 48]) {
     let mut hasher = Hasher::new(MessageDigest::sha384()).unwrap();
     hasher.update(pcr).unwrap();
@@ -603,6 +670,7 @@ fn test_fuse_log() {
         fmc_version: 0,
         app_svn: FMC_SVN,
         app_version: 0,
+        ..Default::default()
     };
     let image_bundle =
         caliptra_builder::build_and_sign_image(&TEST_FMC_WITH_UART, &APP_WITH_UART, image_options)
@@ -22,9 +22,11 @@ use caliptra_image_elf::ElfExecutable;
 use caliptra_image_fake_keys::{
     VENDOR_CONFIG_KEY_0, VENDOR_CONFIG_KEY_1, VENDOR_CONFIG_KEY_2, VENDOR_CONFIG_KEY_3,
 };
-use caliptra_image_gen::{ImageGenerator, ImageGeneratorConfig, ImageGeneratorVendorConfig};
+use caliptra_image_gen::{
+    ImageGenerator, ImageGeneratorConfig, ImageGeneratorData, ImageGeneratorVendorConfig,
+};
 use caliptra_image_types::{
-    ImageBundle, ImageManifest, VENDOR_ECC_KEY_COUNT, VENDOR_LMS_KEY_COUNT,
+    ImageBundle, ImageManifest, VENDOR_ECC_KEY_COUNT, VENDOR_LMS_KEY_COUNT, VENDOR_MLDSA_KEY_COUNT,
 };
 use openssl::asn1::Asn1Integer;
 use openssl::asn1::Asn1Time;
@@ -802,6 +804,223 @@ fn test_header_verify_vendor_lms_optional_no_pub_key_in_preamble_and_header_chec
     hw.step_until_boot_status(u32::from(ColdResetComplete), true);
 }
 
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_preamble_vendor_mldsa_pubkey_revocation() {
+    // this test is too slow to run in the verilator nightly
+    #![cfg_attr(all(not(feature = "slow_tests"), feature = "verilator"), ignore)]
+
+    let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
+    const LAST_KEY_IDX: u32 = VENDOR_MLDSA_KEY_COUNT - 1;
+
+    for idx in 0..VENDOR_MLDSA_KEY_COUNT {
+        let vendor_config = ImageGeneratorVendorConfig {
+            mldsa_key_idx: idx,
+            ..VENDOR_CONFIG_KEY_0
+        };
+        let image_options = ImageOptions {
+            vendor_config,
+            ..Default::default()
+        };
+
+        let fuses = caliptra_hw_model::Fuses {
+            mldsa_verify: true,
+            fuse_mldsa_revocation: 1u32 << idx,
+            ..Default::default()
+        };
+
+        let mut hw = caliptra_hw_model::new(
+            InitParams {
+                rom: &rom,
+                ..Default::default()
+            },
+            BootParams {
+                fuses,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let image_bundle =
+            caliptra_builder::build_and_sign_image(&FMC_WITH_UART, &APP_WITH_UART, image_options)
+                .unwrap();
+
+        if idx == LAST_KEY_IDX {
+            // Last key is never revoked.
+            hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+                .unwrap();
+            hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+        } else {
+            assert_eq!(
+                ModelError::MailboxCmdFailed(
+                    CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_REVOKED.into()
+                ),
+                hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+                    .unwrap_err()
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_preamble_vendor_mldsa_pubkey_out_of_bounds() {
+    let fuses = caliptra_hw_model::Fuses {
+        mldsa_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_hw_model_and_image_bundle(fuses, ImageOptions::default());
+    image_bundle.manifest.preamble.vendor_mldsa_pub_key_idx = VENDOR_MLDSA_KEY_COUNT;
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_OUT_OF_BOUNDS.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_preamble_vendor_mldsa_pubkey_digest_mismatch() {
+    let fuses = caliptra_hw_model::Fuses {
+        mldsa_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_hw_model_and_image_bundle(fuses, ImageOptions::default());
+
+    // Use a key that doesn't match the digest at the key index.
+    image_bundle.mldsa.vendor_pub_key.0[0] ^= 1;
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_DIGEST_MISMATCH.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_preamble_owner_mldsa_pubkey_digest_mismatch() {
+    let fuses = caliptra_hw_model::Fuses {
+        mldsa_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_hw_model_and_image_bundle(fuses, ImageOptions::default());
+
+    // Use a key that doesn't match the digest in the owner public keys.
+    image_bundle.mldsa.owner_pub_key.0[0] ^= 1;
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_PUB_KEY_DIGEST_MISMATCH.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_header_verify_vendor_mldsa_sig_mismatch() {
+    let fuses = caliptra_hw_model::Fuses {
+        mldsa_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_hw_model_and_image_bundle(fuses, ImageOptions::default());
+
+    // Modify the vendor signature.
+    image_bundle.mldsa.vendor_sig.0[0] ^= 1;
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+
+    assert_eq!(
+        hw.soc_ifc().cptra_boot_status().read(),
+        u32::from(FwProcessorManifestLoadComplete)
+    );
+}
+
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_header_verify_owner_mldsa_sig_mismatch() {
+    let fuses = caliptra_hw_model::Fuses {
+        mldsa_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_hw_model_and_image_bundle(fuses, ImageOptions::default());
+
+    // An all-zero signature is rejected too.
+    image_bundle.mldsa.owner_sig = Default::default();
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_SIGNATURE_INVALID.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_header_verify_vendor_mldsa_pub_key_in_preamble_and_header() {
+    let fuses = caliptra_hw_model::Fuses {
+        mldsa_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_hw_model_and_image_bundle(fuses, ImageOptions::default());
+
+    // Change vendor pubkey index.
+    image_bundle.manifest.header.vendor_mldsa_pub_key_idx =
+        image_bundle.manifest.preamble.vendor_mldsa_pub_key_idx + 1;
+    update_header(&mut image_bundle);
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_PUB_KEY_INDEX_MISMATCH.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "hw-1.0"))]
+fn test_mldsa_optional_no_verify() {
+    let fuses = caliptra_hw_model::Fuses {
+        mldsa_verify: false,
+        fuse_mldsa_revocation: 1,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_hw_model_and_image_bundle(fuses, ImageOptions::default());
+
+    // None of the ML-DSA fields are checked.
+    image_bundle.mldsa.vendor_pub_key.0[0] ^= 1;
+    image_bundle.mldsa.owner_pub_key.0[0] ^= 1;
+    image_bundle.mldsa.vendor_sig = Default::default();
+    image_bundle.mldsa.owner_sig = Default::default();
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
+
 #[test]
 fn test_header_verify_owner_sig_zero_fuses() {
     let image_bundle = caliptra_builder::build_and_sign_image(
@@ -1618,6 +1837,93 @@ fn test_runtime_digest_mismatch() {
     );
 }
 
+#[test]
+fn test_data_digest_mismatch() {
+    let (mut hw, mut image_bundle) = helpers::build_hw_model_and_image_bundle(
+        Fuses::default(),
+        ImageOptions {
+            data: vec![ImageGeneratorData {
+                id: 0x1000,
+                content: vec![0x11; 16],
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+    );
+
+    // Change the data blob.
+    image_bundle.data[0][0..4].copy_from_slice(0xDEADBEEFu32.as_bytes());
+    assert_eq!(
+        ModelError::MailboxCmdFailed(u32::from(
+            CaliptraError::IMAGE_VERIFIER_ERR_DATA_DIGEST_MISMATCH
+        )),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+
+    assert_eq!(
+        hw.soc_ifc().cptra_boot_status().read(),
+        u32::from(FwProcessorManifestLoadComplete)
+    );
+}
+
+#[test]
+fn test_compressed_runtime() {
+    let (mut hw, image_bundle) = helpers::build_hw_model_and_image_bundle(
+        Fuses::default(),
+        ImageOptions {
+            compress_runtime: true,
+            ..Default::default()
+        },
+    );
+    assert!(image_bundle.manifest.runtime.is_compressed());
+    assert!(image_bundle.manifest.runtime.size < image_bundle.extended_toc.runtime_expansion.size);
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(ColdResetComplete.into(), true);
+}
+
+#[test]
+fn test_compressed_runtime_expanded_size_mismatch() {
+    let (mut hw, mut image_bundle) = helpers::build_hw_model_and_image_bundle(
+        Fuses::default(),
+        ImageOptions {
+            compress_runtime: true,
+            ..Default::default()
+        },
+    );
+
+    image_bundle.extended_toc.runtime_expansion.size += 4;
+    let image = update_toc(&mut image_bundle);
+    assert_eq!(
+        ModelError::MailboxCmdFailed(u32::from(
+            CaliptraError::ROM_GLOBAL_RUNTIME_EXPANDED_SIZE_MISMATCH
+        )),
+        hw.upload_firmware(&image).unwrap_err()
+    );
+}
+
+#[test]
+fn test_compressed_runtime_expanded_digest_mismatch() {
+    let (mut hw, mut image_bundle) = helpers::build_hw_model_and_image_bundle(
+        Fuses::default(),
+        ImageOptions {
+            compress_runtime: true,
+            ..Default::default()
+        },
+    );
+
+    image_bundle.extended_toc.runtime_expansion.digest[0] ^= 1;
+    let image = update_toc(&mut image_bundle);
+    assert_eq!(
+        ModelError::MailboxCmdFailed(u32::from(
+            CaliptraError::ROM_GLOBAL_RUNTIME_EXPANDED_DIGEST_MISMATCH
+        )),
+        hw.upload_firmware(&image).unwrap_err()
+    );
+}
+
 #[test]
 fn test_runtime_invalid_load_addr_before_iccm() {
     let (mut hw, mut image_bundle) =
@@ -2020,6 +2326,8 @@ fn update_header(image_bundle: &mut ImageBundle) {
         runtime: ElfExecutable::default(),
         vendor_config: opts.vendor_config,
         owner_config: opts.owner_config,
+        data: vec![],
+        compress_runtime: false,
     };
 
     let gen = ImageGenerator::new(Crypto::default());
@@ -2039,6 +2347,12 @@ fn update_header(image_bundle: &mut ImageBundle) {
             &header_digest_owner,
         )
         .unwrap();
+    #[cfg(not(feature = "hw-1.0"))]
+    {
+        image_bundle.mldsa = gen
+            .gen_mldsa_preamble(&config, &header_digest_vendor, &header_digest_owner)
+            .unwrap();
+    }
 }
 
 fn update_fmc_runtime_ranges(
@@ -2057,7 +2371,30 @@ fn update_fmc_runtime_ranges(
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            &image_bundle.extended_toc,
+        )
+        .unwrap();
+
+    // Update Header.
+    update_header(image_bundle);
+
+    // Generate image bytes.
+    generate_image_bytes(image_bundle)
+}
+
+fn update_toc(image_bundle: &mut ImageBundle) -> Vec<u8> {
+    let gen = ImageGenerator::new(Crypto::default());
+
+    // Update TOC digest.
+    image_bundle.manifest.header.toc_digest = gen
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            &image_bundle.extended_toc,
+        )
         .unwrap();
 
     // Update Header.
@@ -2078,7 +2415,11 @@ fn update_load_addr(image_bundle: &mut ImageBundle, is_fmc: bool, new_load_addr:
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            &image_bundle.extended_toc,
+        )
         .unwrap();
 
     // Update Header.
@@ -2103,7 +2444,11 @@ fn update_entry_point(
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            &image_bundle.extended_toc,
+        )
         .unwrap();
 
     // Update Header.
@@ -2116,6 +2461,8 @@ fn update_entry_point(
 fn generate_image_bytes(image_bundle: &mut ImageBundle) -> Vec<u8> {
     let mut image = vec![];
     image.extend_from_slice(image_bundle.manifest.as_bytes());
+    #[cfg(not(feature = "hw-1.0"))]
+    image.extend_from_slice(image_bundle.mldsa.as_bytes());
     image.extend_from_slice(&image_bundle.fmc);
     image.extend_from_slice(&image_bundle.runtime);
     image
@@ -71,6 +71,54 @@ fn test_update_reset_success() {
     hw.step_until_exit_success().unwrap();
 }
 
+#[test]
+fn test_update_reset_compressed_runtime() {
+    let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
+    let image_bundle = caliptra_builder::build_and_sign_image(
+        &TEST_FMC_INTERACTIVE,
+        &APP_WITH_UART,
+        ImageOptions {
+            compress_runtime: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let mut hw = caliptra_hw_model::new(
+        InitParams {
+            rom: &rom,
+            ..Default::default()
+        },
+        BootParams {
+            fw_image: Some(&image_bundle.to_bytes().unwrap()),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    hw.step_until_boot_status(ColdResetComplete.into(), true);
+
+    // Trigger an update reset with the compressed firmware
+    hw.start_mailbox_execute(
+        CommandId::FIRMWARE_LOAD.into(),
+        &image_bundle.to_bytes().unwrap(),
+    )
+    .unwrap();
+
+    if cfg!(not(feature = "fpga_realtime")) {
+        hw.step_until_boot_status(UpdateResetStarted.into(), true);
+    }
+
+    assert_eq!(hw.finish_mailbox_execute(), Ok(None));
+
+    hw.step_until_boot_status(UpdateResetComplete.into(), true);
+
+    // Exit test-fmc with success
+    hw.mailbox_execute(0x1000_000C, &[]).unwrap();
+
+    hw.step_until_exit_success().unwrap();
+}
+
 #[test]
 fn test_update_reset_no_mailbox_cmd() {
     let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
@@ -5,6 +5,7 @@
 set -e
 
 cargo --config="target.'cfg(all())'.rustflags = [\"-Dwarnings\"]" test -p caliptra-image-fake-keys test_write_lms_keys -- --ignored >/dev/null
+cargo --config="target.'cfg(all())'.rustflags = [\"-Dwarnings\"]" test -p caliptra-image-fake-keys test_write_mldsa_keys -- --ignored >/dev/null
 
 for value in {0..3}
 do
@@ -29,7 +29,7 @@ use caliptra_common::mailbox_api::{
 use caliptra_drivers::{
     pcr_log::PCR_ID_STASH_MEASUREMENT, Array4x12, Array4xN, AuthManifestImageMetadataList,
     CaliptraError, CaliptraResult, Ecc384, Ecc384PubKey, Ecc384Signature, HashValue, Lms,
-    PersistentData, RomVerifyConfig, Sha256, Sha384, SocIfc,
+    PersistentData, RomVerifyConfig, Sha256, Sha384, ShaAccLockState, SocIfc,
 };
 use caliptra_image_types::{
     ImageDigest, ImageEccPubKey, ImageEccSignature, ImageLmsPublicKey, ImageLmsSignature,
@@ -48,16 +48,23 @@ use zerocopy::{FromBytes, IntoBytes};
 pub const IMAGE_AUTHORIZED: u32 = 0xDEADC0DE; // Either FW ID and image digest matched or 'ignore_auth_check' is set for the FW ID.
 pub const IMAGE_NOT_AUTHORIZED: u32 = 0x21523F21; // FW ID not found in the image metadata entry collection.
 pub const IMAGE_HASH_MISMATCH: u32 = 0x8BFB95CB; // FW ID matched, but image digest mismatched.
+pub const IMAGE_SVN_REVOKED: u32 = 0x3AC5E0B6; // FW ID matched, but the entry SVN is below the minimum SVN for the FW ID.
 
 pub struct AuthorizeAndStashCmd;
 impl AuthorizeAndStashCmd {
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
     #[inline(never)]
     pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
-        if let Ok(cmd) = AuthorizeAndStashReq::ref_from_bytes(cmd_args) {
-            if ImageHashSource::from(cmd.source) != ImageHashSource::InRequest {
-                Err(CaliptraError::RUNTIME_AUTH_AND_STASH_UNSUPPORTED_IMAGE_SOURCE)?;
-            }
+        if let Some(cmd) = AuthorizeAndStashReq::read_from_bytes_with_tail(cmd_args) {
+            let measurement = match ImageHashSource::from(cmd.source) {
+                ImageHashSource::InRequest => cmd.measurement,
+                ImageHashSource::ShaAcc => Self::digest_staged_image(drivers, &cmd)?,
+                ImageHashSource::Invalid => {
+                    return Err(CaliptraError::RUNTIME_AUTH_AND_STASH_UNSUPPORTED_IMAGE_SOURCE)
+                }
+            };
+
+            let anti_rollback_disable = drivers.soc_ifc.fuse_bank().anti_rollback_disable();
 
             // Check if firmware id is present in the image metadata entry collection.
             let persistent_data = drivers.persistent_data.get();
@@ -68,19 +75,35 @@ impl AuthorizeAndStashCmd {
             let auth_result = if let Some(metadata_entry) =
                 Self::find_metadata_entry(auth_manifest_image_metadata_col, cmd_fw_id)
             {
-                // If 'ignore_auth_check' is set, then skip the image digest comparison and authorize the image.
                 let flags = ImageMetadataFlags(metadata_entry.flags);
-                if flags.ignore_auth_check() {
-                    cfi_assert!(cfi_launder(flags.ignore_auth_check()));
-                    IMAGE_AUTHORIZED
-                } else if cfi_launder(metadata_entry.digest) == cmd.measurement {
-                    caliptra_cfi_lib_git::cfi_assert_eq_12_words(
-                        &Array4x12::from(metadata_entry.digest).0,
-                        &Array4x12::from(cmd.measurement).0,
-                    );
-                    IMAGE_AUTHORIZED
+                // Rollback protection follows the anti-rollback-disable fuse.
+                let min_svn = if anti_rollback_disable {
+                    0
+                } else {
+                    persistent_data
+                        .auth_manifest_image_min_svn_list
+                        .min_svn(cmd_fw_id)
+                };
+
+                // Reject entries whose SVN has been superseded by a newer manifest.
+                if cfi_launder(metadata_entry.svn) < min_svn {
+                    IMAGE_SVN_REVOKED
                 } else {
-                    IMAGE_HASH_MISMATCH
+                    cfi_assert!(metadata_entry.svn >= min_svn);
+
+                    // If 'ignore_auth_check' is set, then skip the image digest comparison and authorize the image.
+                    if flags.ignore_auth_check() {
+                        cfi_assert!(cfi_launder(flags.ignore_auth_check()));
+                        IMAGE_AUTHORIZED
+                    } else if cfi_launder(metadata_entry.digest) == measurement {
+                        caliptra_cfi_lib_git::cfi_assert_eq_12_words(
+                            &Array4x12::from(metadata_entry.digest).0,
+                            &Array4x12::from(measurement).0,
+                        );
+                        IMAGE_AUTHORIZED
+                    } else {
+                        IMAGE_HASH_MISMATCH
+                    }
                 }
             } else {
                 IMAGE_NOT_AUTHORIZED
@@ -90,11 +113,8 @@ impl AuthorizeAndStashCmd {
             if auth_result == IMAGE_AUTHORIZED {
                 let flags: AuthAndStashFlags = cmd.flags.into();
                 if !flags.contains(AuthAndStashFlags::SKIP_STASH) {
-                    let dpe_result = StashMeasurementCmd::stash_measurement(
-                        drivers,
-                        &cmd.fw_id,
-                        &cmd.measurement,
-                    )?;
+                    let dpe_result =
+                        StashMeasurementCmd::stash_measurement(drivers, &cmd.fw_id, &measurement)?;
                     if dpe_result != DpeErrorCode::NoError {
                         drivers
                             .soc_ifc
@@ -113,6 +133,52 @@ impl AuthorizeAndStashCmd {
         }
     }
 
+    /// Compute the SHA-384 digest of an image staged in the mailbox SRAM using
+    /// the SHA accelerator.
+    ///
+    /// The image must be non-empty, dword aligned and dword sized, follow the
+    /// `AuthorizeAndStashReq` and lie entirely within the data written by the
+    /// SoC for this command.
+    ///
+    /// # Arguments
+    ///
+    /// * `drivers` - Drivers
+    /// * `cmd` - AUTHORIZE_AND_STASH request describing the staged image
+    ///
+    /// # Returns
+    ///
+    /// * `[u8; SHA384_DIGEST_BYTE_SIZE]` - Digest of the staged image
+    ///
+    #[inline(never)]
+    fn digest_staged_image(
+        drivers: &mut Drivers,
+        cmd: &AuthorizeAndStashReq,
+    ) -> CaliptraResult<[u8; SHA384_DIGEST_BYTE_SIZE]> {
+        let image_end = cmd
+            .image_offset
+            .checked_add(cmd.image_size)
+            .ok_or(CaliptraError::RUNTIME_AUTH_AND_STASH_INVALID_IMAGE_RANGE)?;
+        if (cmd.image_offset as usize) < size_of::<AuthorizeAndStashReq>()
+            || cmd.image_offset % size_of::<u32>() as u32 != 0
+            || cmd.image_size == 0
+            || cmd.image_size % size_of::<u32>() as u32 != 0
+            || image_end > drivers.mbox.dlen()
+        {
+            Err(CaliptraError::RUNTIME_AUTH_AND_STASH_INVALID_IMAGE_RANGE)?;
+        }
+
+        let mut digest = Array4x12::default();
+        let Some(mut sha_acc_op) = drivers
+            .sha2_512_384_acc
+            .try_start_operation(ShaAccLockState::NotAcquired)?
+        else {
+            return Err(CaliptraError::RUNTIME_AUTH_AND_STASH_SHA_ACC_LOCK_FAILURE);
+        };
+        sha_acc_op.digest_384(cmd.image_size, cmd.image_offset, false, &mut digest)?;
+
+        Ok(digest.into())
+    }
+
     /// Search for a metadata entry in the sorted `AuthManifestImageMetadataCollection` that matches the firmware ID.
     ///
     /// This function performs a binary search on the `image_metadata_list` of the provided `AuthManifestImageMetadataCollection`.
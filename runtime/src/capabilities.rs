@@ -12,18 +12,48 @@ Abstract:
 
 --*/
 
+use crate::Drivers;
 use caliptra_common::{
     capabilities::Capabilities,
     mailbox_api::{CapabilitiesResp, MailboxResp, MailboxRespHeader},
 };
+use caliptra_drivers::{FmcAliasCsr, IdevIdCsr};
 use caliptra_error::CaliptraResult;
 
 pub struct CapabilitiesCmd;
 impl CapabilitiesCmd {
     #[inline(never)]
-    pub(crate) fn execute() -> CaliptraResult<MailboxResp> {
+    pub(crate) fn execute(drivers: &Drivers) -> CaliptraResult<MailboxResp> {
+        let persistent_data = drivers.persistent_data.get();
+
         let mut capabilities = Capabilities::default();
         capabilities |= Capabilities::RT_BASE;
+        capabilities |= Capabilities::RT_LMS_VERIFY;
+        capabilities |= Capabilities::RT_SET_AUTH_MANIFEST;
+        capabilities |= Capabilities::RT_AUTHORIZE_AND_STASH;
+        capabilities |= Capabilities::RT_AUTHORIZE_AND_STASH_SHA_ACC;
+        if !drivers.soc_ifc.fuse_bank().anti_rollback_disable() {
+            capabilities |= Capabilities::RT_AUTH_MANIFEST_IMAGE_SVN;
+        }
+
+        // The CSRs are only available if they were generated during boot.
+        let idevid_csr_len = persistent_data.idevid_csr.get_csr_len();
+        if idevid_csr_len != 0 && idevid_csr_len != IdevIdCsr::UNPROVISIONED_CSR {
+            capabilities |= Capabilities::RT_GET_IDEV_CSR;
+        }
+        let fmc_alias_csr_len = persistent_data.fmc_alias_csr.get_csr_len();
+        if fmc_alias_csr_len != 0 && fmc_alias_csr_len != FmcAliasCsr::UNPROVISIONED_CSR {
+            capabilities |= Capabilities::RT_GET_FMC_ALIAS_CSR;
+        }
+
+        capabilities |= Capabilities::RT_SIGN_WITH_EXPORTED_ECDSA;
+        capabilities |= Capabilities::RT_REVOKE_EXPORTED_CDI_HANDLE;
+        capabilities |= Capabilities::RT_GET_LOGS;
+        capabilities |= Capabilities::RT_GET_IMAGE_DATA;
+        #[cfg(feature = "fips_self_test")]
+        {
+            capabilities |= Capabilities::RT_FIPS_SELF_TEST;
+        }
 
         Ok(MailboxResp::Capabilities(CapabilitiesResp {
             hdr: MailboxRespHeader::default(),
@@ -29,6 +29,8 @@ use caliptra_cfi_derive_git::{cfi_impl_fn, cfi_mod_fn};
 use caliptra_cfi_lib_git::{cfi_assert, cfi_assert_eq, cfi_assert_eq_12_words, cfi_launder};
 use caliptra_common::mailbox_api::AddSubjectAltNameReq;
 use caliptra_drivers::KeyId;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_drivers::MlDsa87;
 use caliptra_drivers::{
     cprint, cprintln, pcr_log::RT_FW_JOURNEY_PCR, Array4x12, CaliptraError, CaliptraResult,
     DataVault, Ecc384, KeyVault, Lms, PersistentDataAccessor, Pic, ResetReason, Sha1, SocIfc,
@@ -40,6 +42,8 @@ use caliptra_drivers::{
 use caliptra_image_types::ImageManifest;
 use caliptra_registers::el2_pic_ctrl::El2PicCtrl;
 use caliptra_registers::mbox::enums::MboxStatusE;
+#[cfg(not(feature = "hw-1.0"))]
+use caliptra_registers::mldsa::MldsaReg;
 use caliptra_registers::{
     csrng::CsrngReg, dv::DvReg, ecc::EccReg, entropy_src::EntropySrcReg, hmac::HmacReg, kv::KvReg,
     mbox::MboxCsr, pv::PvReg, sha256::Sha256Reg, sha512::Sha512Reg, sha512_acc::Sha512AccCsr,
@@ -96,6 +100,10 @@ pub struct Drivers {
 
     pub lms: Lms,
 
+    /// ML-DSA-87 Engine
+    #[cfg(not(feature = "hw-1.0"))]
+    pub mldsa87: MlDsa87,
+
     pub sha1: Sha1,
 
     pub pcr_bank: PcrBank,
@@ -140,6 +148,8 @@ impl Drivers {
             ecc384: Ecc384::new(EccReg::new()),
             sha1: Sha1::default(),
             lms: Lms::default(),
+            #[cfg(not(feature = "hw-1.0"))]
+            mldsa87: MlDsa87::new(MldsaReg::new()),
             trng,
             persistent_data: PersistentDataAccessor::new(),
             pcr_bank: PcrBank::new(PvReg::new()),
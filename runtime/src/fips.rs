@@ -68,9 +68,12 @@ pub mod fips_self_test_cmd {
     use caliptra_cfi_lib_git::cfi_assert_eq_8_words;
     use caliptra_common::HexBytes;
     use caliptra_common::{verifier::FirmwareImageVerificationEnv, FMC_SIZE, RUNTIME_SIZE};
-    use caliptra_drivers::{ResetReason, ShaAccLockState};
-    use caliptra_image_types::{ImageTocEntry, RomInfo};
-    use caliptra_image_verify::ImageVerifier;
+    use caliptra_drivers::{ResetReason, Sha384, ShaAccLockState};
+    use caliptra_image_types::{
+        ImageDigest, ImageExtendedToc, ImageManifest, ImageTocEntry, RomInfo,
+        IMAGE_EXTENDED_TOC_BYTE_SIZE, IMAGE_MLDSA_PREAMBLE_BYTE_SIZE, MIN_TOC_ENTRY_COUNT,
+    };
+    use caliptra_image_verify::{ImageVerifier, MldsaPreambleSource};
     use zerocopy::IntoBytes;
 
     // Helper function to create a slice from a memory region
@@ -84,51 +87,110 @@ pub mod fips_self_test_cmd {
         Done,
     }
 
+    // Data blobs the ROM copied to persistent data, in TOC order
+    fn image_data_entries<'a>(
+        manifest: &ImageManifest,
+        toc: &'a ImageExtendedToc,
+    ) -> &'a [ImageTocEntry] {
+        let count = manifest.header.toc_len.saturating_sub(MIN_TOC_ENTRY_COUNT) as usize;
+        &toc.data[..count.min(toc.data.len())]
+    }
+
+    // Check a loaded image section against its digest in the verified TOC
+    fn verify_section(
+        sha384: &mut Sha384,
+        section: &[u8],
+        digest: &ImageDigest,
+    ) -> CaliptraResult<()> {
+        if sha384.digest(section)?.0 != *digest {
+            return Err(CaliptraError::RUNTIME_FIPS_SELF_TEST_IMAGE_DIGEST_MISMATCH);
+        }
+        Ok(())
+    }
+
     #[cfg_attr(not(feature = "no-cfi"), cfi_mod_fn)]
     fn copy_and_verify_image(env: &mut Drivers) -> CaliptraResult<()> {
-        env.mbox.write_cmd(0)?;
-        env.mbox.set_dlen(
-            env.persistent_data.get().manifest1.size
-                + env.persistent_data.get().manifest1.fmc.size
-                + env.persistent_data.get().manifest1.runtime.size,
-        );
-        env.mbox
-            .copy_bytes_to_mbox(env.persistent_data.get().manifest1.as_bytes())?;
-
-        let fmc_toc = &env.persistent_data.get().manifest1.fmc;
-        let rt_toc = &env.persistent_data.get().manifest1.runtime;
+        let pdata = env.persistent_data.get();
+        let manifest = &pdata.manifest1;
+        let toc = &pdata.extended_toc;
+        let fmc_toc = &manifest.fmc;
+        let rt_toc = &manifest.runtime;
+        let data_entries = image_data_entries(manifest, toc);
 
         if fmc_toc.size > FMC_SIZE {
             return Err(CaliptraError::RUNTIME_INVALID_FMC_SIZE);
         }
-        if rt_toc.size > RUNTIME_SIZE {
+        if manifest.runtime_load_size(toc) > RUNTIME_SIZE {
             return Err(CaliptraError::RUNTIME_INVALID_RUNTIME_SIZE);
         }
 
-        let fmc = unsafe { create_slice(&fmc_toc) };
-        let rt = unsafe { create_slice(&rt_toc) };
+        let data_size: u32 = data_entries.iter().map(|entry| entry.size).sum();
+        let image_size = manifest.size
+            + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32
+            + IMAGE_MLDSA_PREAMBLE_BYTE_SIZE as u32
+            + fmc_toc.size
+            + rt_toc.size
+            + data_size;
+
+        // Only the manifest and the extended TOC are verified from the
+        // mailbox. The loaded sections are then checked in place against the
+        // digests in the verified TOC.
+        env.mbox.write_cmd(0)?;
+        env.mbox
+            .set_dlen(manifest.size + IMAGE_EXTENDED_TOC_BYTE_SIZE as u32)?;
+        env.mbox.copy_bytes_to_mbox(manifest.as_bytes())?;
+        env.mbox.copy_bytes_to_mbox(toc.as_bytes())?;
 
-        env.mbox.copy_bytes_to_mbox(fmc.as_bytes())?;
-        env.mbox.copy_bytes_to_mbox(rt.as_bytes())?;
+        // The ML-DSA preamble is not kept after boot; check the image against
+        // the digest of the one the ROM verified instead.
+        #[cfg(not(feature = "hw-1.0"))]
+        let mldsa = MldsaPreambleSource::RomVerified(env.data_vault.mldsa_preamble_digest().into());
+        #[cfg(feature = "hw-1.0")]
+        let mldsa = MldsaPreambleSource::None;
 
         let mut venv = FirmwareImageVerificationEnv {
             sha256: &mut env.sha256,
             sha384: &mut env.sha384,
             soc_ifc: &mut env.soc_ifc,
             ecc384: &mut env.ecc384,
+            #[cfg(not(feature = "hw-1.0"))]
+            mldsa87: &mut env.mldsa87,
             data_vault: &mut env.data_vault,
             pcr_bank: &mut env.pcr_bank,
             image: env.mbox.raw_mailbox_contents(),
         };
 
         let mut verifier = ImageVerifier::new(&mut venv);
-        let _info = verifier.verify(
-            &env.persistent_data.get().manifest1,
-            env.persistent_data.get().manifest1.size
-                + env.persistent_data.get().manifest1.fmc.size
-                + env.persistent_data.get().manifest1.runtime.size,
-            ResetReason::UpdateReset,
-        )?;
+        verifier.verify_manifest(manifest, toc, mldsa, image_size, ResetReason::UpdateReset)?;
+
+        let fmc = unsafe { create_slice(fmc_toc) };
+        verify_section(&mut env.sha384, fmc, &fmc_toc.digest)?;
+
+        // The ROM inflated a compressed runtime into ICCM, so check it against
+        // the expansion digest instead of the digest of the signed bytes.
+        let rt = unsafe {
+            core::slice::from_raw_parts(
+                rt_toc.load_addr as *const u8,
+                manifest.runtime_load_size(toc) as usize,
+            )
+        };
+        let rt_digest = if rt_toc.is_compressed() {
+            &toc.runtime_expansion.digest
+        } else {
+            &rt_toc.digest
+        };
+        verify_section(&mut env.sha384, rt, rt_digest)?;
+
+        let mut offset = 0;
+        for entry in data_entries {
+            let data = pdata
+                .image_data
+                .get(offset..offset + entry.size as usize)
+                .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+            verify_section(&mut env.sha384, data, &entry.digest)?;
+            offset += entry.size as usize;
+        }
+
         cprintln!("[rt] Verify complete");
         Ok(())
     }
@@ -0,0 +1,59 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    image_data.rs
+
+Abstract:
+
+    File contains mailbox command to retrieve data blobs from the firmware image bundle.
+
+--*/
+
+use crate::Drivers;
+use caliptra_common::mailbox_api::{GetImageDataReq, GetImageDataResp, MailboxResp};
+use caliptra_drivers::{CaliptraError, CaliptraResult};
+use caliptra_image_types::MIN_TOC_ENTRY_COUNT;
+use core::cmp::min;
+use zerocopy::FromBytes;
+
+pub struct GetImageDataCmd;
+impl GetImageDataCmd {
+    #[inline(never)]
+    pub(crate) fn execute(drivers: &Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let cmd = GetImageDataReq::ref_from_bytes(cmd_args)
+            .map_err(|_| CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+
+        let pdata = drivers.persistent_data.get();
+        let manifest = &pdata.manifest1;
+
+        // The ROM packs the data blobs in TOC order, so a blob starts where
+        // the previous one ends.
+        let count = manifest.header.toc_len.saturating_sub(MIN_TOC_ENTRY_COUNT) as usize;
+        let mut start = 0;
+        let mut found = None;
+        for entry in pdata.extended_toc.data.iter().take(count) {
+            if entry.id == cmd.id {
+                found = Some(start..start + entry.size as usize);
+                break;
+            }
+            start += entry.size as usize;
+        }
+        let range = found.ok_or(CaliptraError::RUNTIME_GET_IMAGE_DATA_NOT_FOUND)?;
+
+        let data = pdata
+            .image_data
+            .get(range)
+            .ok_or(CaliptraError::RUNTIME_GET_IMAGE_DATA_NOT_FOUND)?
+            .get(cmd.offset as usize..)
+            .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+        let data = &data[..min(data.len(), GetImageDataResp::DATA_MAX_SIZE)];
+
+        let mut resp = GetImageDataResp::default();
+        resp.data[..data.len()].copy_from_slice(data);
+        resp.data_size = data.len() as u32;
+        Ok(MailboxResp::GetImageData(resp))
+    }
+}
@@ -26,8 +26,10 @@ mod get_fmc_alias_csr;
 mod get_idev_csr;
 pub mod handoff;
 mod hmac;
+mod image_data;
 pub mod info;
 mod invoke_dpe;
+mod logs;
 mod pcr;
 mod populate_idev;
 mod revoke_exported_cdi_handle;
@@ -52,7 +54,9 @@ pub use crate::hmac::Hmac;
 use crate::revoke_exported_cdi_handle::RevokeExportedCdiHandleCmd;
 use crate::sign_with_exported_ecdsa::SignWithExportedEcdsaCmd;
 pub use crate::subject_alt_name::AddSubjectAltNameCmd;
-pub use authorize_and_stash::{IMAGE_AUTHORIZED, IMAGE_HASH_MISMATCH, IMAGE_NOT_AUTHORIZED};
+pub use authorize_and_stash::{
+    IMAGE_AUTHORIZED, IMAGE_HASH_MISMATCH, IMAGE_NOT_AUTHORIZED, IMAGE_SVN_REVOKED,
+};
 pub use caliptra_common::fips::FipsVersionCmd;
 pub use dice::{GetFmcAliasCertCmd, GetLdevCertCmd, IDevIdCertCmd};
 pub use disable::DisableAttestationCmd;
@@ -65,8 +69,10 @@ pub use populate_idev::PopulateIDevIdCertCmd;
 
 pub use get_fmc_alias_csr::GetFmcAliasCsrCmd;
 pub use get_idev_csr::GetIdevCsrCmd;
+pub use image_data::GetImageDataCmd;
 pub use info::{FwInfoCmd, IDevIdInfoCmd};
 pub use invoke_dpe::InvokeDpeCmd;
+pub use logs::{GetFuseLogCmd, GetMeasurementLogCmd, GetPcrLogCmd};
 pub use pcr::IncrementPcrResetCounterCmd;
 pub use set_auth_manifest::SetAuthManifestCmd;
 pub use stash_measurement::StashMeasurementCmd;
@@ -210,7 +216,7 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
         CommandId::VERSION => {
             FipsVersionCmd::execute(&drivers.soc_ifc).map(MailboxResp::FipsVersion)
         }
-        CommandId::CAPABILITIES => CapabilitiesCmd::execute(),
+        CommandId::CAPABILITIES => CapabilitiesCmd::execute(drivers),
         #[cfg(feature = "fips_self_test")]
         CommandId::SELF_TEST_START => match drivers.self_test_status {
             SelfTestStatus::Idle => {
@@ -238,6 +244,10 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
         CommandId::REVOKE_EXPORTED_CDI_HANDLE => {
             RevokeExportedCdiHandleCmd::execute(drivers, cmd_bytes)
         }
+        CommandId::GET_PCR_LOG => GetPcrLogCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_MEASUREMENT_LOG => GetMeasurementLogCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_FUSE_LOG => GetFuseLogCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_IMAGE_DATA => GetImageDataCmd::execute(drivers, cmd_bytes),
         _ => Err(CaliptraError::RUNTIME_UNIMPLEMENTED_COMMAND),
     };
     let resp = okmutref(&mut resp)?;
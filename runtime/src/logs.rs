@@ -0,0 +1,84 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    logs.rs
+
+Abstract:
+
+    File contains mailbox commands to retrieve the PCR, measurement and fuse logs.
+
+--*/
+
+use crate::Drivers;
+use caliptra_common::mailbox_api::{
+    GetFuseLogReq, GetLogResp, GetMeasurementLogReq, GetPcrLogReq, MailboxResp,
+};
+use caliptra_drivers::{fuse_log::FuseLogEntryId, CaliptraError, CaliptraResult};
+use core::cmp::min;
+use core::mem::size_of;
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+/// Copies as many whole entries of `log`, starting at `start_index`, as fit
+/// in a single response.
+fn log_page<T: IntoBytes + Immutable>(log: &[T], start_index: u32) -> CaliptraResult<MailboxResp> {
+    let entries = log
+        .get(start_index as usize..)
+        .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+    let max_entries = GetLogResp::DATA_MAX_SIZE / size_of::<T>();
+    let bytes = entries[..min(entries.len(), max_entries)].as_bytes();
+
+    let mut resp = GetLogResp::default();
+    resp.data[..bytes.len()].copy_from_slice(bytes);
+    resp.data_size = bytes.len() as u32;
+    Ok(MailboxResp::GetLog(resp))
+}
+
+pub struct GetPcrLogCmd;
+impl GetPcrLogCmd {
+    #[inline(never)]
+    pub(crate) fn execute(drivers: &Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let cmd = GetPcrLogReq::ref_from_bytes(cmd_args)
+            .map_err(|_| CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+
+        let pdata = drivers.persistent_data.get();
+        let count = min(pdata.fht.pcr_log_index as usize, pdata.pcr_log.len());
+        log_page(&pdata.pcr_log[..count], cmd.start_index)
+    }
+}
+
+pub struct GetMeasurementLogCmd;
+impl GetMeasurementLogCmd {
+    #[inline(never)]
+    pub(crate) fn execute(drivers: &Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let cmd = GetMeasurementLogReq::ref_from_bytes(cmd_args)
+            .map_err(|_| CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+
+        let pdata = drivers.persistent_data.get();
+        let count = min(
+            pdata.fht.meas_log_index as usize,
+            pdata.measurement_log.len(),
+        );
+        log_page(&pdata.measurement_log[..count], cmd.start_index)
+    }
+}
+
+pub struct GetFuseLogCmd;
+impl GetFuseLogCmd {
+    #[inline(never)]
+    pub(crate) fn execute(drivers: &Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let cmd = GetFuseLogReq::ref_from_bytes(cmd_args)
+            .map_err(|_| CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+
+        // The fuse log has no index in the FHT; entries are filled in order
+        // by the ROM, so the log ends at the first unused entry.
+        let fuse_log = &drivers.persistent_data.get().fuse_log;
+        let count = fuse_log
+            .iter()
+            .take_while(|entry| entry.entry_id != FuseLogEntryId::Invalid as u32)
+            .count();
+        log_page(&fuse_log[..count], cmd.start_index)
+    }
+}
@@ -14,8 +14,11 @@ Abstract:
 
 use caliptra_drivers::CaliptraResult;
 
-use caliptra_common::mailbox_api::{MailboxReqHeader, MailboxResp};
+use caliptra_common::mailbox_api::{
+    AuthorizeAndStashReq, CommandId, ImageHashSource, MailboxReqHeader, MailboxResp,
+};
 use caliptra_drivers::CaliptraError;
+use core::cmp::min;
 use zerocopy::{FromBytes, IntoBytes};
 
 #[derive(Debug, Clone)]
@@ -42,7 +45,17 @@ impl Packet {
     pub fn copy_from_mbox(drivers: &mut crate::Drivers) -> CaliptraResult<Self> {
         let mbox = &mut drivers.mbox;
         let cmd = mbox.cmd();
-        let dlen_words = mbox.dlen_words() as usize;
+
+        // AUTHORIZE_AND_STASH with a ShaAcc source may be followed by an image
+        // staged in the mailbox for the SHA accelerator. Only the request itself
+        // is copied, but the checksum covers everything the SoC wrote.
+        let dlen = mbox.dlen() as usize;
+        let len = if cmd == CommandId::AUTHORIZE_AND_STASH {
+            min(dlen, core::mem::size_of::<AuthorizeAndStashReq>())
+        } else {
+            dlen
+        };
+        let dlen_words = (len + 3) / 4;
 
         if dlen_words > MAX_PAYLOAD_SIZE {
             return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
@@ -50,7 +63,7 @@ impl Packet {
 
         let mut packet = Packet {
             cmd: cmd.into(),
-            len: mbox.dlen() as usize,
+            len,
             ..Default::default()
         };
 
@@ -74,10 +87,26 @@ impl Packet {
         )
         .map_err(|_| CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
 
+        // Only a ShaAcc request has an image staged after it.
+        if len < dlen {
+            let (req, _) = AuthorizeAndStashReq::ref_from_prefix(payload_bytes)
+                .map_err(|_| CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+            if ImageHashSource::from(req.source) != ImageHashSource::ShaAcc {
+                return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+            }
+        }
+
+        let received_bytes = if len < dlen {
+            mbox.raw_mailbox_contents()
+                .get(..dlen)
+                .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?
+        } else {
+            payload_bytes
+        };
         if !caliptra_common::checksum::verify_checksum(
             req_hdr.chksum,
             packet.cmd,
-            &payload_bytes[core::mem::size_of_val(&req_hdr.chksum)..],
+            &received_bytes[core::mem::size_of_val(&req_hdr.chksum)..],
         ) {
             return Err(CaliptraError::RUNTIME_INVALID_CHECKSUM);
         }
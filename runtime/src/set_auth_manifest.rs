@@ -19,7 +19,8 @@ use crate::verify;
 use crate::{dpe_crypto::DpeCrypto, CptraDpeTypes, DpePlatform, Drivers};
 use caliptra_auth_man_types::{
     AuthManifestFlags, AuthManifestImageMetadata, AuthManifestImageMetadataCollection,
-    AuthManifestPreamble, AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT, AUTH_MANIFEST_MARKER,
+    AuthManifestImageMinSvn, AuthManifestImageMinSvnList, AuthManifestPreamble,
+    AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT, AUTH_MANIFEST_MARKER,
 };
 use caliptra_cfi_derive_git::cfi_impl_fn;
 use caliptra_cfi_lib_git::cfi_launder;
@@ -357,6 +358,7 @@ impl SetAuthManifestCmd {
         cmd_buf: &[u8],
         auth_manifest_preamble: &AuthManifestPreamble,
         metadata_persistent: &mut AuthManifestImageMetadataCollection,
+        min_svn_persistent: &mut AuthManifestImageMinSvnList,
         sha384: &mut Sha384,
         ecc384: &mut Ecc384,
         sha256: &mut Sha256,
@@ -421,6 +423,9 @@ impl SetAuthManifestCmd {
 
         Self::sort_and_check_duplicate_fwid(slice)?;
 
+        // Raise the minimum SVN of every firmware ID in the new manifest.
+        Self::update_min_svn_list(slice, min_svn_persistent)?;
+
         // Clear the previous image metadata collection.
         metadata_persistent.zeroize();
 
@@ -457,6 +462,66 @@ impl SetAuthManifestCmd {
         Ok(())
     }
 
+    /// Raise the minimum SVN of each firmware ID in `slice` to the SVN of its
+    /// image metadata entry. Minimum SVNs are never lowered, so entries of an
+    /// older manifest are rejected by AUTHORIZE_AND_STASH once a newer manifest
+    /// has been set.
+    ///
+    /// The list is left untouched if the new firmware IDs do not fit.
+    fn update_min_svn_list(
+        slice: &[AuthManifestImageMetadata],
+        min_svn_persistent: &mut AuthManifestImageMinSvnList,
+    ) -> CaliptraResult<()> {
+        let err = CaliptraError::RUNTIME_AUTH_MANIFEST_IMAGE_MIN_SVN_LIST_FULL;
+        let entry_count = min_svn_persistent.entry_count as usize;
+        let min_svn_list = min_svn_persistent
+            .min_svn_list
+            .get(..entry_count)
+            .ok_or(err)?;
+
+        // Only non-zero SVNs need to be recorded.
+        let new_entry_count = slice
+            .iter()
+            .filter(|metadata| {
+                metadata.svn != 0
+                    && !min_svn_list
+                        .iter()
+                        .any(|entry| entry.fw_id == metadata.fw_id)
+            })
+            .count();
+        if entry_count + new_entry_count > AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT {
+            Err(err)?;
+        }
+
+        for metadata in slice {
+            let svn = metadata.svn;
+            if svn == 0 {
+                continue;
+            }
+
+            let entry_count = min_svn_persistent.entry_count as usize;
+            if let Some(entry) = min_svn_persistent
+                .min_svn_list
+                .get_mut(..entry_count)
+                .ok_or(err)?
+                .iter_mut()
+                .find(|entry| entry.fw_id == metadata.fw_id)
+            {
+                entry.min_svn = core::cmp::max(entry.min_svn, svn);
+            } else {
+                *min_svn_persistent
+                    .min_svn_list
+                    .get_mut(entry_count)
+                    .ok_or(err)? = AuthManifestImageMinSvn {
+                    fw_id: metadata.fw_id,
+                    min_svn: svn,
+                };
+                min_svn_persistent.entry_count += 1;
+            }
+        }
+        Ok(())
+    }
+
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
     #[inline(never)]
     pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
@@ -530,6 +595,7 @@ impl SetAuthManifestCmd {
                 .ok_or(CaliptraError::RUNTIME_AUTH_MANIFEST_IMAGE_METADATA_LIST_INVALID_SIZE)?,
             auth_manifest_preamble,
             &mut persistent_data.auth_manifest_image_metadata_col,
+            &mut persistent_data.auth_manifest_image_min_svn_list,
             &mut drivers.sha384,
             &mut drivers.ecc384,
             &mut drivers.sha256,
@@ -566,16 +632,19 @@ mod tests {
             AuthManifestImageMetadata {
                 fw_id: 5,
                 flags: 0,
+                svn: 0,
                 digest: [0u8; 48],
             },
             AuthManifestImageMetadata {
                 fw_id: 127,
                 flags: 0,
+                svn: 0,
                 digest: [0u8; 48],
             },
             AuthManifestImageMetadata {
                 fw_id: 48,
                 flags: 0,
+                svn: 0,
                 digest: [0u8; 48],
             },
         ];
@@ -590,16 +659,19 @@ mod tests {
             AuthManifestImageMetadata {
                 fw_id: 127,
                 flags: 0,
+                svn: 0,
                 digest: [0u8; 48],
             },
             AuthManifestImageMetadata {
                 fw_id: 5,
                 flags: 0,
+                svn: 0,
                 digest: [0u8; 48],
             },
             AuthManifestImageMetadata {
                 fw_id: 127,
                 flags: 0,
+                svn: 0,
                 digest: [0u8; 48],
             },
         ];
@@ -609,4 +681,56 @@ mod tests {
             CaliptraError::RUNTIME_AUTH_MANIFEST_IMAGE_METADATA_LIST_DUPLICATE_FIRMWARE_ID
         );
     }
+
+    fn metadata_with_svn(fw_id: u32, svn: u32) -> AuthManifestImageMetadata {
+        AuthManifestImageMetadata {
+            fw_id,
+            svn,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_update_min_svn_list() {
+        let mut min_svn_list = AuthManifestImageMinSvnList::default();
+
+        let list = [metadata_with_svn(1, 3), metadata_with_svn(2, 0)];
+        let resp = SetAuthManifestCmd::update_min_svn_list(&list, &mut min_svn_list);
+        assert!(resp.is_ok());
+        assert_eq!(min_svn_list.entry_count, 1);
+        assert_eq!(min_svn_list.min_svn(1), 3);
+        assert_eq!(min_svn_list.min_svn(2), 0);
+
+        // A lower SVN does not lower the minimum.
+        let list = [metadata_with_svn(1, 2), metadata_with_svn(2, 5)];
+        let resp = SetAuthManifestCmd::update_min_svn_list(&list, &mut min_svn_list);
+        assert!(resp.is_ok());
+        assert_eq!(min_svn_list.entry_count, 2);
+        assert_eq!(min_svn_list.min_svn(1), 3);
+        assert_eq!(min_svn_list.min_svn(2), 5);
+    }
+
+    #[test]
+    fn test_update_min_svn_list_full() {
+        let mut min_svn_list = AuthManifestImageMinSvnList::default();
+
+        let mut list =
+            [AuthManifestImageMetadata::default(); AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT];
+        for (fw_id, metadata) in list.iter_mut().enumerate() {
+            *metadata = metadata_with_svn(fw_id as u32, 1);
+        }
+        let resp = SetAuthManifestCmd::update_min_svn_list(&list, &mut min_svn_list);
+        assert!(resp.is_ok());
+
+        let list = [metadata_with_svn(1000, 1)];
+        let resp = SetAuthManifestCmd::update_min_svn_list(&list, &mut min_svn_list);
+        assert_eq!(
+            resp.unwrap_err(),
+            CaliptraError::RUNTIME_AUTH_MANIFEST_IMAGE_MIN_SVN_LIST_FULL
+        );
+        assert_eq!(
+            min_svn_list.entry_count,
+            AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT as u32
+        );
+    }
 }
@@ -13,12 +13,15 @@ use caliptra_builder::{
     ImageOptions,
 };
 use caliptra_common::mailbox_api::{
-    AuthorizeAndStashReq, AuthorizeAndStashResp, CommandId, ImageHashSource, MailboxReq,
-    MailboxReqHeader, SetAuthManifestReq,
+    AuthAndStashFlags, AuthorizeAndStashReq, AuthorizeAndStashResp, CommandId, ImageHashSource,
+    MailboxReq, MailboxReqHeader, SetAuthManifestReq,
 };
-use caliptra_hw_model::{DefaultHwModel, HwModel};
+use caliptra_error::CaliptraError;
+use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
 use caliptra_runtime::RtBootStatus;
-use caliptra_runtime::{IMAGE_AUTHORIZED, IMAGE_HASH_MISMATCH, IMAGE_NOT_AUTHORIZED};
+use caliptra_runtime::{
+    IMAGE_AUTHORIZED, IMAGE_HASH_MISMATCH, IMAGE_NOT_AUTHORIZED, IMAGE_SVN_REVOKED,
+};
 use sha2::{Digest, Sha384};
 use zerocopy::{FromBytes, IntoBytes};
 
@@ -51,6 +54,12 @@ fn set_auth_manifest(auth_manifest: Option<AuthorizationManifest>) -> DefaultHwM
         create_auth_manifest(AuthManifestFlags::VENDOR_SIGNATURE_REQUIRED)
     };
 
+    send_auth_manifest(&mut model, &auth_manifest);
+
+    model
+}
+
+fn send_auth_manifest(model: &mut DefaultHwModel, auth_manifest: &AuthorizationManifest) {
     let buf = auth_manifest.as_bytes();
     let mut auth_manifest_slice = [0u8; SetAuthManifestReq::MAX_MAN_SIZE];
     auth_manifest_slice[..buf.len()].copy_from_slice(buf);
@@ -69,8 +78,6 @@ fn set_auth_manifest(auth_manifest: Option<AuthorizationManifest>) -> DefaultHwM
         )
         .unwrap()
         .expect("We should have received a response");
-
-    model
 }
 
 #[test]
@@ -314,6 +321,7 @@ fn test_authorize_and_stash_fwid_0() {
     let image_metadata = vec![AuthManifestImageMetadata {
         fw_id: 0,
         flags: flags.0,
+        svn: 0,
         digest: IMAGE_DIGEST1,
     }];
     let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
@@ -352,6 +360,7 @@ fn test_authorize_and_stash_fwid_127() {
     let image_metadata = vec![AuthManifestImageMetadata {
         fw_id: 127,
         flags: flags.0,
+        svn: 0,
         digest: IMAGE_DIGEST1,
     }];
     let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
@@ -415,6 +424,7 @@ fn test_authorize_and_stash_cmd_deny_second_bad_hash() {
         let image_metadata = vec![AuthManifestImageMetadata {
             fw_id: 1,
             flags: flags.0,
+            svn: 0,
             digest: IMAGE_DIGEST_BAD,
         }];
         let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
@@ -458,6 +468,7 @@ fn test_authorize_and_stash_after_update_reset() {
     let image_metadata = vec![AuthManifestImageMetadata {
         fw_id: 0,
         flags: flags.0,
+        svn: 0,
         digest: IMAGE_DIGEST1,
     }];
     let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
@@ -521,6 +532,7 @@ fn test_authorize_and_stash_after_update_reset_unauthorized_fw_id() {
     let image_metadata = vec![AuthManifestImageMetadata {
         fw_id: 0,
         flags: flags.0,
+        svn: 0,
         digest: IMAGE_DIGEST1,
     }];
     let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
@@ -590,6 +602,7 @@ fn test_authorize_and_stash_after_update_reset_bad_hash() {
     let image_metadata = vec![AuthManifestImageMetadata {
         fw_id: 0,
         flags: flags.0,
+        svn: 0,
         digest: IMAGE_DIGEST1,
     }];
     let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
@@ -710,6 +723,7 @@ fn test_authorize_and_stash_after_update_reset_multiple_set_manifest() {
     let image_metadata = vec![AuthManifestImageMetadata {
         fw_id: 0,
         flags: flags.0,
+        svn: 0,
         digest: IMAGE_DIGEST1,
     }];
     let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
@@ -822,6 +836,7 @@ fn test_authorize_and_stash_after_update_reset_multiple_set_manifest() {
     let image_metadata = vec![AuthManifestImageMetadata {
         fw_id: 127,
         flags: flags.0,
+        svn: 0,
         digest: IMAGE_DIGEST1,
     }];
     let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
@@ -892,3 +907,305 @@ fn test_authorize_and_stash_after_update_reset_multiple_set_manifest() {
         IMAGE_NOT_AUTHORIZED
     );
 }
+
+fn sha_acc_image() -> Vec<u8> {
+    (0..4096u32).map(|i| (i * 7 + 3) as u8).collect()
+}
+
+fn authorize_and_stash_sha_acc(
+    model: &mut DefaultHwModel,
+    fw_id: [u8; 4],
+    image: &[u8],
+    image_size: u32,
+) -> Result<Option<Vec<u8>>, ModelError> {
+    let image_offset = std::mem::size_of::<AuthorizeAndStashReq>() as u32;
+    let mut authorize_and_stash_cmd = MailboxReq::AuthorizeAndStash(AuthorizeAndStashReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        fw_id,
+        source: ImageHashSource::ShaAcc as u32,
+        flags: 0, // Don't skip stash
+        image_offset,
+        image_size,
+        ..Default::default()
+    });
+
+    // The image is staged in the mailbox right after the request, and the
+    // checksum covers both.
+    let mut payload = authorize_and_stash_cmd.as_bytes().unwrap().to_vec();
+    payload.extend_from_slice(image);
+    let chksum = caliptra_common::checksum::calc_checksum(
+        u32::from(CommandId::AUTHORIZE_AND_STASH),
+        &payload[4..],
+    );
+    payload[..4].copy_from_slice(&chksum.to_le_bytes());
+
+    model.mailbox_execute(u32::from(CommandId::AUTHORIZE_AND_STASH), &payload)
+}
+
+#[test]
+fn test_authorize_and_stash_sha_acc_success() {
+    let image = sha_acc_image();
+    let image_digest: [u8; 48] = Sha384::digest(&image).as_slice().try_into().unwrap();
+
+    let mut flags = ImageMetadataFlags(0);
+    flags.set_ignore_auth_check(false);
+    flags.set_image_source(ImageHashSource::ShaAcc as u32);
+
+    let image_metadata = vec![AuthManifestImageMetadata {
+        fw_id: 1,
+        flags: flags.0,
+        svn: 0,
+        digest: image_digest,
+    }];
+    let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
+    let mut model = set_auth_manifest(Some(auth_manifest));
+
+    let resp = authorize_and_stash_sha_acc(&mut model, FW_ID_1, &image, image.len() as u32)
+        .unwrap()
+        .expect("We should have received a response");
+
+    let authorize_and_stash_resp = AuthorizeAndStashResp::read_from_bytes(resp.as_slice()).unwrap();
+    assert_eq!(authorize_and_stash_resp.auth_req_result, IMAGE_AUTHORIZED);
+
+    // Trigger an update reset so we can use commands in mbox responder
+    update_fw(
+        &mut model,
+        &firmware::runtime_tests::MBOX,
+        ImageOptions::default(),
+    );
+
+    let rt_journey_pcr_resp = model.mailbox_execute(0x1000_0000, &[]).unwrap().unwrap();
+    let rt_journey_pcr: [u8; 48] = rt_journey_pcr_resp.as_bytes().try_into().unwrap();
+
+    let valid_pauser_hash_resp = model.mailbox_execute(0x2000_0000, &[]).unwrap().unwrap();
+    let valid_pauser_hash: [u8; 48] = valid_pauser_hash_resp.as_bytes().try_into().unwrap();
+
+    // The digest computed by the SHA accelerator must have been stashed in DPE
+    let mut hasher = Sha384::new();
+    hasher.update(rt_journey_pcr);
+    hasher.update(valid_pauser_hash);
+    hasher.update(image_digest);
+    let expected_measurement_hash = hasher.finalize();
+
+    let dpe_measurement_hash = model.mailbox_execute(0x3000_0000, &[]).unwrap().unwrap();
+    assert_eq!(expected_measurement_hash.as_bytes(), dpe_measurement_hash);
+}
+
+#[test]
+fn test_authorize_and_stash_sha_acc_hash_mismatch() {
+    let image = sha_acc_image();
+
+    let mut flags = ImageMetadataFlags(0);
+    flags.set_ignore_auth_check(false);
+    flags.set_image_source(ImageHashSource::ShaAcc as u32);
+
+    let image_metadata = vec![AuthManifestImageMetadata {
+        fw_id: 1,
+        flags: flags.0,
+        svn: 0,
+        digest: IMAGE_DIGEST1,
+    }];
+    let auth_manifest = create_auth_manifest_with_metadata(image_metadata);
+    let mut model = set_auth_manifest(Some(auth_manifest));
+
+    let resp = authorize_and_stash_sha_acc(&mut model, FW_ID_1, &image, image.len() as u32)
+        .unwrap()
+        .expect("We should have received a response");
+
+    let authorize_and_stash_resp = AuthorizeAndStashResp::read_from_bytes(resp.as_slice()).unwrap();
+    assert_eq!(
+        authorize_and_stash_resp.auth_req_result,
+        IMAGE_HASH_MISMATCH
+    );
+}
+
+#[test]
+fn test_authorize_and_stash_sha_acc_invalid_image_range() {
+    let image = sha_acc_image();
+    let mut model = set_auth_manifest(None);
+
+    // The image size extends past the data written to the mailbox.
+    let resp = authorize_and_stash_sha_acc(&mut model, FW_ID_1, &image, image.len() as u32 + 4);
+    assert_eq!(
+        resp,
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_AUTH_AND_STASH_INVALID_IMAGE_RANGE.into()
+        ))
+    );
+}
+
+#[test]
+fn test_authorize_and_stash_sha_acc_invalid_image_size() {
+    let image = sha_acc_image();
+    let mut model = set_auth_manifest(None);
+
+    for image_size in [0, 3] {
+        let resp = authorize_and_stash_sha_acc(&mut model, FW_ID_1, &image, image_size);
+        assert_eq!(
+            resp,
+            Err(ModelError::MailboxCmdFailed(
+                CaliptraError::RUNTIME_AUTH_AND_STASH_INVALID_IMAGE_RANGE.into()
+            ))
+        );
+    }
+}
+
+#[test]
+fn test_authorize_and_stash_sha_acc_checksum_covers_image() {
+    let image = sha_acc_image();
+    let mut model = set_auth_manifest(None);
+
+    let image_offset = std::mem::size_of::<AuthorizeAndStashReq>() as u32;
+    let mut authorize_and_stash_cmd = MailboxReq::AuthorizeAndStash(AuthorizeAndStashReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        fw_id: FW_ID_1,
+        source: ImageHashSource::ShaAcc as u32,
+        image_offset,
+        image_size: image.len() as u32,
+        ..Default::default()
+    });
+    // Checksum only the request.
+    authorize_and_stash_cmd.populate_chksum().unwrap();
+    let mut payload = authorize_and_stash_cmd.as_bytes().unwrap().to_vec();
+    payload.extend_from_slice(&image);
+
+    let resp = model.mailbox_execute(u32::from(CommandId::AUTHORIZE_AND_STASH), &payload);
+    assert_eq!(
+        resp,
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_INVALID_CHECKSUM.into()
+        ))
+    );
+}
+
+#[test]
+fn test_authorize_and_stash_without_optional_tail() {
+    let mut model = set_auth_manifest(None);
+
+    let authorize_and_stash_cmd = AuthorizeAndStashReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        fw_id: FW_ID_1,
+        measurement: IMAGE_DIGEST1,
+        source: ImageHashSource::InRequest as u32,
+        flags: AuthAndStashFlags::SKIP_STASH.bits(),
+        ..Default::default()
+    };
+    let mut payload =
+        authorize_and_stash_cmd.as_bytes()[..AuthorizeAndStashReq::BASE_SIZE].to_vec();
+    let chksum = caliptra_common::checksum::calc_checksum(
+        u32::from(CommandId::AUTHORIZE_AND_STASH),
+        &payload[4..],
+    );
+    payload[..4].copy_from_slice(&chksum.to_le_bytes());
+
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::AUTHORIZE_AND_STASH), &payload)
+        .unwrap()
+        .expect("We should have received a response");
+    let authorize_and_stash_resp = AuthorizeAndStashResp::read_from_bytes(resp.as_slice()).unwrap();
+    assert_eq!(authorize_and_stash_resp.auth_req_result, IMAGE_AUTHORIZED);
+
+    // Any length other than the two request sizes is rejected.
+    payload.truncate(AuthorizeAndStashReq::BASE_SIZE - 4);
+    let chksum = caliptra_common::checksum::calc_checksum(
+        u32::from(CommandId::AUTHORIZE_AND_STASH),
+        &payload[4..],
+    );
+    payload[..4].copy_from_slice(&chksum.to_le_bytes());
+    assert_eq!(
+        model.mailbox_execute(u32::from(CommandId::AUTHORIZE_AND_STASH), &payload),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_INSUFFICIENT_MEMORY.into()
+        ))
+    );
+}
+
+#[test]
+fn test_authorize_and_stash_in_request_trailing_data() {
+    let mut model = set_auth_manifest(None);
+
+    let authorize_and_stash_cmd = AuthorizeAndStashReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        fw_id: FW_ID_1,
+        measurement: IMAGE_DIGEST1,
+        source: ImageHashSource::InRequest as u32,
+        flags: AuthAndStashFlags::SKIP_STASH.bits(),
+        ..Default::default()
+    };
+    let mut payload = authorize_and_stash_cmd.as_bytes().to_vec();
+    payload.extend_from_slice(&[0xa5; 16]);
+    let chksum = caliptra_common::checksum::calc_checksum(
+        u32::from(CommandId::AUTHORIZE_AND_STASH),
+        &payload[4..],
+    );
+    payload[..4].copy_from_slice(&chksum.to_le_bytes());
+
+    assert_eq!(
+        model.mailbox_execute(u32::from(CommandId::AUTHORIZE_AND_STASH), &payload),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS.into()
+        ))
+    );
+}
+
+fn authorize_fw_id_1(model: &mut DefaultHwModel) -> u32 {
+    let mut authorize_and_stash_cmd = MailboxReq::AuthorizeAndStash(AuthorizeAndStashReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        fw_id: FW_ID_1,
+        measurement: IMAGE_DIGEST1,
+        source: ImageHashSource::InRequest as u32,
+        flags: AuthAndStashFlags::SKIP_STASH.bits(),
+        ..Default::default()
+    });
+    authorize_and_stash_cmd.populate_chksum().unwrap();
+
+    let resp = model
+        .mailbox_execute(
+            u32::from(CommandId::AUTHORIZE_AND_STASH),
+            authorize_and_stash_cmd.as_bytes().unwrap(),
+        )
+        .unwrap()
+        .expect("We should have received a response");
+
+    AuthorizeAndStashResp::read_from_bytes(resp.as_slice())
+        .unwrap()
+        .auth_req_result
+}
+
+fn auth_manifest_with_svn(svn: u32) -> AuthorizationManifest {
+    let mut flags = ImageMetadataFlags(0);
+    flags.set_image_source(ImageHashSource::InRequest as u32);
+
+    create_auth_manifest_with_metadata(vec![AuthManifestImageMetadata {
+        fw_id: 1,
+        flags: flags.0,
+        svn,
+        digest: IMAGE_DIGEST1,
+    }])
+}
+
+#[test]
+fn test_authorize_and_stash_svn_rollback() {
+    let mut model = set_auth_manifest(Some(auth_manifest_with_svn(2)));
+    assert_eq!(authorize_fw_id_1(&mut model), IMAGE_AUTHORIZED);
+
+    // An older manifest is still accepted, but its entries are revoked.
+    send_auth_manifest(&mut model, &auth_manifest_with_svn(1));
+    assert_eq!(authorize_fw_id_1(&mut model), IMAGE_SVN_REVOKED);
+
+    // A manifest with the same SVN authorizes the image again.
+    send_auth_manifest(&mut model, &auth_manifest_with_svn(2));
+    assert_eq!(authorize_fw_id_1(&mut model), IMAGE_AUTHORIZED);
+}
+
+#[test]
+fn test_authorize_and_stash_svn_after_update_reset() {
+    let mut model = set_auth_manifest(Some(auth_manifest_with_svn(3)));
+    assert_eq!(authorize_fw_id_1(&mut model), IMAGE_AUTHORIZED);
+
+    // The minimum SVN survives an update reset.
+    update_fw(&mut model, &APP_WITH_UART, ImageOptions::default());
+
+    send_auth_manifest(&mut model, &auth_manifest_with_svn(2));
+    assert_eq!(authorize_fw_id_1(&mut model), IMAGE_SVN_REVOKED);
+}
@@ -0,0 +1,91 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::{run_rt_test, RuntimeTestArgs, DEFAULT_APP_VERSION, DEFAULT_FMC_VERSION};
+use caliptra_builder::ImageOptions;
+use caliptra_common::mailbox_api::{
+    CommandId, GetImageDataReq, GetImageDataResp, MailboxReq, MailboxReqHeader,
+};
+use caliptra_error::CaliptraError;
+use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
+use caliptra_image_gen::ImageGeneratorData;
+use zerocopy::{FromZeros, IntoBytes};
+
+fn get_image_data(
+    model: &mut DefaultHwModel,
+    id: u32,
+    offset: u32,
+) -> Result<GetImageDataResp, ModelError> {
+    let mut cmd = MailboxReq::GetImageData(GetImageDataReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        id,
+        offset,
+    });
+    cmd.populate_chksum().unwrap();
+
+    let resp = model
+        .mailbox_execute(
+            u32::from(CommandId::GET_IMAGE_DATA),
+            cmd.as_bytes().unwrap(),
+        )?
+        .unwrap();
+    assert!(resp.len() <= std::mem::size_of::<GetImageDataResp>());
+    let mut data_resp = GetImageDataResp::new_zeroed();
+    data_resp.as_mut_bytes()[..resp.len()].copy_from_slice(&resp);
+    Ok(data_resp)
+}
+
+#[test]
+fn test_get_image_data() {
+    let blob_a: Vec<u8> = (0..1500).map(|i| i as u8).collect();
+    let blob_b = vec![0x5a; 16];
+
+    let mut image_options = ImageOptions {
+        data: vec![
+            ImageGeneratorData {
+                id: 0x1000,
+                content: blob_a.clone(),
+                ..Default::default()
+            },
+            ImageGeneratorData {
+                id: 0x2000,
+                content: blob_b.clone(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    image_options.vendor_config.pl0_pauser = Some(0x1);
+    image_options.fmc_version = DEFAULT_FMC_VERSION;
+    image_options.app_version = DEFAULT_APP_VERSION;
+
+    let mut model = run_rt_test(RuntimeTestArgs {
+        test_image_options: Some(image_options),
+        ..Default::default()
+    });
+
+    model.step_until(|m| m.soc_mbox().status().read().mbox_fsm_ps().mbox_idle());
+
+    // Read the first blob in pages.
+    let mut read = vec![];
+    loop {
+        let resp = get_image_data(&mut model, 0x1000, read.len() as u32).unwrap();
+        if resp.data_size == 0 {
+            break;
+        }
+        assert!(resp.data_size as usize <= GetImageDataResp::DATA_MAX_SIZE);
+        read.extend_from_slice(resp.data().unwrap());
+    }
+    assert_eq!(read, blob_a);
+
+    let resp = get_image_data(&mut model, 0x2000, 4).unwrap();
+    assert_eq!(resp.data().unwrap(), &blob_b[4..]);
+
+    assert_eq!(
+        get_image_data(&mut model, 0x3000, 0).unwrap_err(),
+        ModelError::MailboxCmdFailed(CaliptraError::RUNTIME_GET_IMAGE_DATA_NOT_FOUND.into())
+    );
+    assert_eq!(
+        get_image_data(&mut model, 0x2000, blob_b.len() as u32 + 1).unwrap_err(),
+        ModelError::MailboxCmdFailed(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS.into())
+    );
+}
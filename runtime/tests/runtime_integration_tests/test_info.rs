@@ -12,6 +12,7 @@ use caliptra_common::{
         MailboxRespHeader,
     },
 };
+use caliptra_drivers::MfgFlags;
 use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams};
 use caliptra_image_crypto::OsslCrypto as Crypto;
 use caliptra_image_gen::ImageGenerator;
@@ -200,4 +201,30 @@ fn test_capabilities() {
     let capabilities_resp = CapabilitiesResp::read_from_bytes(resp.as_slice()).unwrap();
     let capabilities = Capabilities::try_from(capabilities_resp.capabilities.as_bytes()).unwrap();
     assert!(capabilities.contains(Capabilities::RT_BASE));
+    assert!(capabilities.contains(Capabilities::RT_LMS_VERIFY));
+    assert!(capabilities.contains(Capabilities::RT_SET_AUTH_MANIFEST));
+    assert!(capabilities.contains(Capabilities::RT_AUTHORIZE_AND_STASH));
+    assert!(capabilities.contains(Capabilities::RT_AUTHORIZE_AND_STASH_SHA_ACC));
+    assert!(capabilities.contains(Capabilities::RT_AUTH_MANIFEST_IMAGE_SVN));
+    // The IDevID CSR is only generated if requested with the manufacturing flags.
+    assert!(!capabilities.contains(Capabilities::RT_GET_IDEV_CSR));
+    assert!(capabilities.contains(Capabilities::RT_GET_FMC_ALIAS_CSR));
+    assert!(capabilities.contains(Capabilities::RT_SIGN_WITH_EXPORTED_ECDSA));
+    assert!(capabilities.contains(Capabilities::RT_REVOKE_EXPORTED_CDI_HANDLE));
+    assert!(capabilities.contains(Capabilities::RT_GET_LOGS));
+    assert!(capabilities.contains(Capabilities::RT_GET_IMAGE_DATA));
+    assert!(!capabilities.intersects(Capabilities::ROM_BASE));
+
+    // The typed helper reports the same capabilities.
+    assert_eq!(model.capabilities().unwrap().bits(), capabilities.bits());
+}
+
+#[test]
+fn test_capabilities_idev_csr() {
+    let mut model = run_rt_test(RuntimeTestArgs {
+        test_mfg_flags: Some(MfgFlags::GENERATE_IDEVID_CSR),
+        ..Default::default()
+    });
+    let capabilities = model.capabilities().unwrap();
+    assert!(capabilities.contains(Capabilities::RT_GET_IDEV_CSR));
 }
@@ -692,33 +692,41 @@ fn test_pl0_unset_in_header() {
         .unwrap();
     let fmc_elf = build_firmware_elf(&FMC_WITH_UART).unwrap();
     let app_elf = build_firmware_elf(&APP_WITH_UART).unwrap();
-    let preamble = gen
+    let config = ImageGeneratorConfig {
+        fmc: ElfExecutable::new(
+            &fmc_elf,
+            opts.fmc_version as u32,
+            opts.fmc_svn,
+            *b"~~~~~NO_GIT_REVISION",
+        )
+        .unwrap(),
+        runtime: ElfExecutable::new(
+            &app_elf,
+            opts.app_version,
+            opts.app_svn,
+            *b"~~~~~NO_GIT_REVISION",
+        )
+        .unwrap(),
+        vendor_config: opts.vendor_config,
+        owner_config: opts.owner_config,
+        data: vec![],
+        compress_runtime: false,
+    };
+    image_bundle.manifest.preamble = gen
         .gen_preamble(
-            &ImageGeneratorConfig {
-                fmc: ElfExecutable::new(
-                    &fmc_elf,
-                    opts.fmc_version as u32,
-                    opts.fmc_svn,
-                    *b"~~~~~NO_GIT_REVISION",
-                )
-                .unwrap(),
-                runtime: ElfExecutable::new(
-                    &app_elf,
-                    opts.app_version,
-                    opts.app_svn,
-                    *b"~~~~~NO_GIT_REVISION",
-                )
-                .unwrap(),
-                vendor_config: opts.vendor_config,
-                owner_config: opts.owner_config,
-            },
+            &config,
             ecc_index,
             lms_index,
             &header_digest_vendor,
             &header_digest_owner,
         )
         .unwrap();
-    image_bundle.manifest.preamble = preamble;
+    #[cfg(not(feature = "hw-1.0"))]
+    {
+        image_bundle.mldsa = gen
+            .gen_mldsa_preamble(&config, &header_digest_vendor, &header_digest_owner)
+            .unwrap();
+    }
 
     model
         .upload_firmware(&image_bundle.to_bytes().unwrap())
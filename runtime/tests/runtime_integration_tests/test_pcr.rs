@@ -3,11 +3,16 @@
 use crate::common::{get_fmc_alias_cert, run_rt_test, RuntimeTestArgs};
 use caliptra_api::SocManager;
 
+use caliptra_api::mailbox::Request;
 use caliptra_common::mailbox_api::{
-    CommandId, ExtendPcrReq, IncrementPcrResetCounterReq, MailboxReq, MailboxReqHeader,
-    QuotePcrsReq, QuotePcrsResp,
+    CommandId, ExtendPcrReq, GetFuseLogReq, GetLogResp, GetMeasurementLogReq, GetPcrLogReq,
+    IncrementPcrResetCounterReq, MailboxReq, MailboxReqHeader, QuotePcrsReq, QuotePcrsResp,
+};
+use caliptra_drivers::{
+    fuse_log::{FuseLogEntry, FuseLogEntryId},
+    pcr_log::{MeasurementLogEntry, PcrLogEntry},
+    PcrId,
 };
-use caliptra_drivers::PcrId;
 use caliptra_error::CaliptraError;
 use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
 use openssl::{
@@ -194,3 +199,99 @@ fn test_extend_pcr_cmd_reserved_range() {
         );
     }
 }
+
+// Pages through one of the runtime logs and returns the concatenated entries.
+fn read_log<R: Request<Resp = GetLogResp>>(
+    model: &mut DefaultHwModel,
+    entry_size: usize,
+    req: impl Fn(u32) -> R,
+) -> Vec<u8> {
+    let mut log = vec![];
+    loop {
+        let start_index = u32::try_from(log.len() / entry_size).unwrap();
+        let resp = model.mailbox_execute_req(req(start_index)).unwrap();
+        let data = resp.data().unwrap();
+        if data.is_empty() {
+            return log;
+        }
+        assert_eq!(data.len() % entry_size, 0);
+        log.extend_from_slice(data);
+    }
+}
+
+#[test]
+fn test_pcr_log_replay() {
+    fn extend_pcr(current: &[u8; 48], data: &[u8]) -> [u8; 48] {
+        let mut h = Hasher::new(MessageDigest::sha384()).unwrap();
+        h.update(current).unwrap();
+        h.update(data).unwrap();
+        h.finish().unwrap().as_bytes().try_into().unwrap()
+    }
+
+    let mut model = run_rt_test(RuntimeTestArgs::default());
+
+    let pcr_log = read_log(
+        &mut model,
+        core::mem::size_of::<PcrLogEntry>(),
+        |start_index| GetPcrLogReq {
+            hdr: MailboxReqHeader { chksum: 0 },
+            start_index,
+        },
+    );
+    assert!(!pcr_log.is_empty());
+
+    // Replay every entry into the PCRs it was extended into
+    let mut replayed = [[0u8; 48]; 32];
+    for entry in pcr_log.chunks_exact(core::mem::size_of::<PcrLogEntry>()) {
+        let entry = PcrLogEntry::read_from_bytes(entry).unwrap();
+        for (i, pcr) in replayed.iter_mut().enumerate() {
+            if entry.pcr_ids & (1 << i) != 0 {
+                *pcr = extend_pcr(pcr, entry.measured_data());
+            }
+        }
+    }
+
+    // The log covers everything extended into the reserved PCRs during boot
+    let pcrs = get_model_pcrs(&mut model);
+    for pcr_id in [PcrId::PcrId0, PcrId::PcrId1, PcrId::PcrId2, PcrId::PcrId3] {
+        assert_eq!(replayed[pcr_id as usize], pcrs[pcr_id as usize]);
+    }
+
+    // No measurements were stashed with the ROM
+    let measurement_log = read_log(
+        &mut model,
+        core::mem::size_of::<MeasurementLogEntry>(),
+        |start_index| GetMeasurementLogReq {
+            hdr: MailboxReqHeader { chksum: 0 },
+            start_index,
+        },
+    );
+    assert!(measurement_log.is_empty());
+
+    let fuse_log = read_log(
+        &mut model,
+        core::mem::size_of::<FuseLogEntry>(),
+        |start_index| GetFuseLogReq {
+            hdr: MailboxReqHeader { chksum: 0 },
+            start_index,
+        },
+    );
+    let (first, _) = FuseLogEntry::read_from_prefix(&fuse_log).unwrap();
+    assert_eq!(first.entry_id, FuseLogEntryId::VendorEccPubKeyIndex as u32);
+}
+
+#[test]
+fn test_get_pcr_log_invalid_start_index() {
+    let mut model = run_rt_test(RuntimeTestArgs::default());
+
+    let resp = model.mailbox_execute_req(GetPcrLogReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        start_index: u32::MAX,
+    });
+    assert_eq!(
+        resp,
+        Err(ModelError::MailboxCmdFailed(u32::from(
+            CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS
+        )))
+    );
+}
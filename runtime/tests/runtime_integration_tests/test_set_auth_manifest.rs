@@ -87,11 +87,13 @@ pub fn create_auth_manifest(manifest_flags: AuthManifestFlags) -> AuthorizationM
         AuthManifestImageMetadata {
             fw_id: 1,
             flags: flags1.0,
+            svn: 0,
             digest: IMAGE_DIGEST1,
         },
         AuthManifestImageMetadata {
             fw_id: 2,
             flags: flags2.0,
+            svn: 0,
             digest: image_digest2,
         },
     ];
@@ -232,6 +234,7 @@ fn create_auth_manifest_of_metadata_size(metadata_size: usize) -> AuthorizationM
         image_metadata_list.push(AuthManifestImageMetadata {
             fw_id: id as u32,
             flags: flags.0,
+            svn: 0,
             digest,
         })
     }
@@ -395,8 +398,9 @@ fn test_set_auth_manifest_cmd_max_plus_one_metadata_entry_limit() {
 
     unsafe {
         *ptr.add(AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT) = AuthManifestImageMetadata {
-            fw_id: 127,
+            fw_id: 119,
             flags: flags.0,
+            svn: 0,
             digest: IMAGE_DIGEST1,
         };
     }
@@ -12,78 +12,240 @@ Abstract:
 
 --*/
 
+use crate::input_log::{InputLog, InputLogCursor};
+use caliptra_emu_bus::ClockSnapshot;
 use caliptra_emu_cpu::xreg_file::XReg;
-use caliptra_emu_cpu::StepAction;
-use caliptra_emu_cpu::{Cpu, WatchPtrKind};
-use caliptra_emu_periph::CaliptraRootBus;
+use caliptra_emu_cpu::{Cpu, CpuSnapshot, WatchPtrKind};
+use caliptra_emu_cpu::{RvInstr, StepAction};
+use caliptra_emu_periph::{CaliptraRootBus, CaliptraRootBusSnapshot};
 use caliptra_emu_types::RvSize;
 use gdbstub::arch::SingleStepGdbBehavior;
 use gdbstub::common::Signal;
 use gdbstub::stub::SingleThreadStopReason;
 use gdbstub::target;
+use gdbstub::target::ext::base::reverse_exec::ReplayLogPosition;
 use gdbstub::target::ext::base::singlethread::{SingleThreadBase, SingleThreadResume};
 use gdbstub::target::ext::base::BaseOps;
 use gdbstub::target::ext::breakpoints::WatchKind;
 use gdbstub::target::Target;
 use gdbstub::target::TargetResult;
 use gdbstub_arch;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// The number of cycles between checkpoints used for reverse execution
+const CHECKPOINT_INTERVAL: u64 = 1_000_000;
 
 pub enum ExecMode {
     Step,
     Continue,
+    ReverseStep,
+    ReverseContinue,
+}
+
+/// Machine state to re-execute from when running backwards
+struct Checkpoint {
+    now: u64,
+    clock: ClockSnapshot,
+    cpu: CpuSnapshot,
+    bus: CaliptraRootBusSnapshot,
+    input_log: InputLogCursor,
 }
 
 pub struct GdbTarget {
     cpu: Cpu<CaliptraRootBus>,
     exec_mode: ExecMode,
     breakpoints: Vec<u32>,
+    input_log: InputLog,
+    exit_code: Rc<Cell<Option<i32>>>,
+    checkpoints: Vec<Checkpoint>,
 }
 
 impl GdbTarget {
     // Create new instance of GdbTarget
-    pub fn new(cpu: Cpu<CaliptraRootBus>) -> Self {
-        Self {
+    pub fn new(
+        cpu: Cpu<CaliptraRootBus>,
+        input_log: InputLog,
+        exit_code: Rc<Cell<Option<i32>>>,
+    ) -> Self {
+        let mut target = Self {
             cpu,
             exec_mode: ExecMode::Continue,
             breakpoints: Vec::new(),
+            input_log,
+            exit_code,
+            checkpoints: Vec::new(),
+        };
+        target.checkpoint();
+        target
+    }
+
+    // Take a checkpoint if the peripherals can be captured
+    fn checkpoint(&mut self) {
+        let Ok(bus) = self.cpu.bus.snapshot() else {
+            return;
+        };
+        self.checkpoints.push(Checkpoint {
+            now: self.cpu.clock.now(),
+            clock: self.cpu.clock.snapshot(),
+            cpu: self.cpu.snapshot(),
+            bus,
+            input_log: self.input_log.cursor(),
+        });
+    }
+
+    // Return to checkpoint `index`
+    fn restore(&mut self, index: usize) {
+        let checkpoint = &self.checkpoints[index];
+        self.cpu.clock.restore(&checkpoint.clock);
+        self.cpu.restore(&checkpoint.cpu);
+        self.cpu.bus.restore(&checkpoint.bus);
+        self.input_log.seek(checkpoint.input_log);
+        self.exit_code.set(None);
+    }
+
+    // Forget the checkpoints after the current cycle, as the state has been
+    // modified and execution will diverge from them.
+    fn discard_future(&mut self) {
+        let now = self.cpu.clock.now();
+        self.checkpoints.retain(|checkpoint| checkpoint.now <= now);
+        self.input_log.forget_interrupts();
+    }
+
+    // Step forward, taking checkpoints along the way
+    fn step_forward(&mut self) -> StepAction {
+        let action = self.input_log.step(&mut self.cpu, None);
+        let last = self
+            .checkpoints
+            .last()
+            .map_or(0, |checkpoint| checkpoint.now);
+        if self.cpu.clock.now() >= last + CHECKPOINT_INTERVAL {
+            self.checkpoint();
+        }
+        action
+    }
+
+    // Returns the reason to stop after a step returning `action`, if any
+    fn stop_reason(&self, action: StepAction) -> Option<SingleThreadStopReason<u32>> {
+        match action {
+            StepAction::Continue if self.breakpoints.contains(&self.cpu.read_pc()) => {
+                Some(SingleThreadStopReason::SwBreak(()))
+            }
+            StepAction::Continue => None,
+            StepAction::Break => {
+                let watch = self.cpu.get_watchptr_hit().unwrap();
+                Some(SingleThreadStopReason::Watch {
+                    tid: (),
+                    kind: if watch.kind == WatchPtrKind::Write {
+                        WatchKind::Write
+                    } else {
+                        WatchKind::Read
+                    },
+                    addr: watch.addr,
+                })
+            }
+            StepAction::Fatal => Some(SingleThreadStopReason::Exited(0)),
         }
     }
 
     // Conditional Run (Private function)
     fn cond_run(&mut self) -> SingleThreadStopReason<u32> {
         loop {
-            match self.cpu.step(None) {
-                StepAction::Continue => {
-                    if self.breakpoints.contains(&self.cpu.read_pc()) {
-                        return SingleThreadStopReason::SwBreak(());
+            let action = self.step_forward();
+            if let Some(code) = self.exit_code.get() {
+                return SingleThreadStopReason::Exited(code as u8);
+            }
+            if let Some(reason) = self.stop_reason(action) {
+                return reason;
+            }
+        }
+    }
+
+    // Restore checkpoint `index` and execute up to cycle `end`, calling
+    // `visit` with the cycle after each step, the step's result and whether
+    // it executed an instruction.
+    fn replay(
+        &mut self,
+        index: usize,
+        end: u64,
+        mut visit: impl FnMut(&Self, u64, StepAction, bool),
+    ) {
+        self.restore(index);
+        while self.cpu.clock.now() < end {
+            let mut executed = false;
+            let mut tracer = |_: u32, _: RvInstr| executed = true;
+            let action = self.input_log.step(&mut self.cpu, Some(&mut tracer));
+            visit(self, self.cpu.clock.now(), action, executed);
+        }
+    }
+
+    // Run backwards to the last cycle before the current one at which `find`
+    // returns a stop reason, by re-executing from successively earlier
+    // checkpoints. Returns to the first checkpoint if there is none.
+    fn reverse_find(
+        &mut self,
+        find: impl Fn(&Self, u64, StepAction, bool) -> Option<(u64, SingleThreadStopReason<u32>)>,
+    ) -> SingleThreadStopReason<u32> {
+        let now = self.cpu.clock.now();
+        let mut end = now;
+        for index in (0..self.checkpoints.len()).rev() {
+            if self.checkpoints[index].now >= end {
+                continue;
+            }
+            let mut found = None;
+            self.replay(index, end, |target, after, action, executed| {
+                if let Some(hit) = find(target, after, action, executed) {
+                    if hit.0 < now {
+                        found = Some(hit);
                     }
                 }
-                StepAction::Break => {
-                    let watch = self.cpu.get_watchptr_hit().unwrap();
-                    return SingleThreadStopReason::Watch {
-                        tid: (),
-                        kind: if watch.kind == WatchPtrKind::Write {
-                            WatchKind::Write
-                        } else {
-                            WatchKind::Read
-                        },
-                        addr: watch.addr,
-                    };
-                }
-                _ => break,
+            });
+            if let Some((cycle, reason)) = found {
+                self.replay(index, cycle, |_, _, _, _| {});
+                return reason;
             }
+            end = self.checkpoints[index].now;
+        }
+        if !self.checkpoints.is_empty() {
+            self.restore(0);
         }
-        SingleThreadStopReason::Exited(0)
+        SingleThreadStopReason::ReplayLog {
+            tid: None,
+            pos: ReplayLogPosition::Begin,
+        }
+    }
+
+    // Run backwards to before the last instruction executed
+    fn run_reverse_step(&mut self) -> SingleThreadStopReason<u32> {
+        self.reverse_find(|_, after, _, executed| {
+            executed.then_some((after - 1, SingleThreadStopReason::DoneStep))
+        })
+    }
+
+    // Run backwards to the last breakpoint or watchpoint hit
+    fn run_reverse_cont(&mut self) -> SingleThreadStopReason<u32> {
+        self.reverse_find(|target, after, action, _| {
+            match action {
+                // Exits can't happen before the current cycle
+                StepAction::Fatal => None,
+                _ => target.stop_reason(action).map(|reason| (after, reason)),
+            }
+        })
     }
 
     // run the gdb target
     pub fn run(&mut self) -> SingleThreadStopReason<u32> {
         match self.exec_mode {
             ExecMode::Step => {
-                self.cpu.step(None);
-                SingleThreadStopReason::DoneStep
+                self.step_forward();
+                match self.exit_code.get() {
+                    Some(code) => SingleThreadStopReason::Exited(code as u8),
+                    None => SingleThreadStopReason::DoneStep,
+                }
             }
             ExecMode::Continue => self.cond_run(),
+            ExecMode::ReverseStep => self.run_reverse_step(),
+            ExecMode::ReverseContinue => self.run_reverse_cont(),
         }
     }
 }
@@ -131,6 +293,8 @@ impl SingleThreadBase for GdbTarget {
         &mut self,
         regs: &gdbstub_arch::riscv::reg::RiscvCoreRegs<u32>,
     ) -> TargetResult<(), Self> {
+        self.discard_future();
+
         // Write PC
         self.cpu.write_pc(regs.pc);
 
@@ -152,6 +316,7 @@ impl SingleThreadBase for GdbTarget {
     }
 
     fn write_addrs(&mut self, start_addr: u32, data: &[u8]) -> TargetResult<(), Self> {
+        self.discard_future();
         for (addr, val) in (start_addr..).zip(data.iter().copied()) {
             self.cpu.write_bus(RvSize::Byte, addr, val as u32).unwrap();
         }
@@ -194,6 +359,34 @@ impl SingleThreadResume for GdbTarget {
     ) -> Option<target::ext::base::singlethread::SingleThreadSingleStepOps<'_, Self>> {
         Some(self)
     }
+
+    #[inline(always)]
+    fn support_reverse_step(
+        &mut self,
+    ) -> Option<target::ext::base::reverse_exec::ReverseStepOps<'_, (), Self>> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn support_reverse_cont(
+        &mut self,
+    ) -> Option<target::ext::base::reverse_exec::ReverseContOps<'_, (), Self>> {
+        Some(self)
+    }
+}
+
+impl target::ext::base::reverse_exec::ReverseStep<()> for GdbTarget {
+    fn reverse_step(&mut self, _tid: ()) -> Result<(), Self::Error> {
+        self.exec_mode = ExecMode::ReverseStep;
+        Ok(())
+    }
+}
+
+impl target::ext::base::reverse_exec::ReverseCont<()> for GdbTarget {
+    fn reverse_cont(&mut self) -> Result<(), Self::Error> {
+        self.exec_mode = ExecMode::ReverseContinue;
+        Ok(())
+    }
 }
 
 impl target::ext::breakpoints::Breakpoints for GdbTarget {
@@ -267,3 +460,115 @@ impl target::ext::breakpoints::HwWatchpoint for GdbTarget {
         Ok(true)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use caliptra_emu_bus::Clock;
+    use caliptra_emu_periph::CaliptraRootBusArgs;
+
+    // loop: addi x1, x1, 1
+    //       j loop
+    const ROM: [u32; 2] = [0x0010_8093, 0xffdf_f06f];
+
+    fn target() -> GdbTarget {
+        let clock = Clock::new();
+        let bus = CaliptraRootBus::new(
+            &clock,
+            CaliptraRootBusArgs {
+                rom: ROM.iter().flat_map(|instr| instr.to_le_bytes()).collect(),
+                ..Default::default()
+            },
+        );
+        GdbTarget::new(
+            Cpu::new(bus, clock),
+            InputLog::new(),
+            Rc::new(Cell::new(None)),
+        )
+    }
+
+    // The cycle, PC and x1
+    fn state(target: &GdbTarget) -> (u64, u32, u32) {
+        (
+            target.cpu.clock.now(),
+            target.cpu.read_pc(),
+            target.cpu.read_xreg(XReg::X1).unwrap(),
+        )
+    }
+
+    // The state after `steps` instructions of ROM
+    fn expected(steps: u64) -> (u64, u32, u32) {
+        (steps, (steps as u32 % 2) * 4, (steps - steps / 2) as u32)
+    }
+
+    fn run(target: &mut GdbTarget, exec_mode: ExecMode) -> SingleThreadStopReason<u32> {
+        target.exec_mode = exec_mode;
+        target.run()
+    }
+
+    #[test]
+    fn test_reverse_step() {
+        let mut target = target();
+        for steps in 1..=5 {
+            assert_eq!(
+                run(&mut target, ExecMode::Step),
+                SingleThreadStopReason::DoneStep
+            );
+            assert_eq!(state(&target), expected(steps));
+        }
+        for steps in (0..5).rev() {
+            assert_eq!(
+                run(&mut target, ExecMode::ReverseStep),
+                SingleThreadStopReason::DoneStep
+            );
+            assert_eq!(state(&target), expected(steps));
+        }
+        assert_eq!(
+            run(&mut target, ExecMode::ReverseStep),
+            SingleThreadStopReason::ReplayLog {
+                tid: None,
+                pos: ReplayLogPosition::Begin,
+            }
+        );
+        assert_eq!(state(&target), expected(0));
+    }
+
+    #[test]
+    fn test_reverse_across_checkpoints() {
+        let mut target = target();
+        let steps = CHECKPOINT_INTERVAL + 3;
+        for _ in 0..steps {
+            run(&mut target, ExecMode::Step);
+        }
+        assert_eq!(state(&target), expected(steps));
+        assert_eq!(target.checkpoints.len(), 2);
+
+        for steps in (CHECKPOINT_INTERVAL - 2..steps).rev() {
+            assert_eq!(
+                run(&mut target, ExecMode::ReverseStep),
+                SingleThreadStopReason::DoneStep
+            );
+            assert_eq!(state(&target), expected(steps));
+        }
+
+        // The branch is hit at every odd cycle.
+        target.breakpoints.push(4);
+        assert_eq!(
+            run(&mut target, ExecMode::ReverseContinue),
+            SingleThreadStopReason::SwBreak(())
+        );
+        assert_eq!(state(&target), expected(CHECKPOINT_INTERVAL - 3));
+
+        // Running forward again re-executes the same instructions.
+        assert_eq!(
+            run(&mut target, ExecMode::Continue),
+            SingleThreadStopReason::SwBreak(())
+        );
+        assert_eq!(state(&target), expected(CHECKPOINT_INTERVAL - 1));
+        assert_eq!(
+            run(&mut target, ExecMode::Continue),
+            SingleThreadStopReason::SwBreak(())
+        );
+        assert_eq!(state(&target), expected(CHECKPOINT_INTERVAL + 1));
+    }
+}
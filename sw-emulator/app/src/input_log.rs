@@ -0,0 +1,373 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    input_log.rs
+
+Abstract:
+
+    File contains the log of the non-deterministic inputs of an emulator
+    run, used by the --record and --replay modes and by reverse execution in
+    the GDB stub.
+
+--*/
+
+use caliptra_emu_cpu::{Cpu, InstrTracer, StepAction};
+use caliptra_emu_periph::{CaliptraRootBus, MailboxRequester};
+use caliptra_hw_model::soc_socket::{handle_request, REQUEST_LEN};
+use caliptra_hw_model_types::EtrngResponse;
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Write};
+use std::path::Path;
+use std::rc::Rc;
+
+/// Number of iTRNG nibbles per line of a saved log
+const ITRNG_NIBBLES_PER_LINE: usize = 64;
+
+/// Position in each of the streams of an [`InputLog`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InputLogCursor {
+    itrng: usize,
+    etrng: usize,
+    soc: usize,
+    irq: usize,
+}
+
+#[derive(Default)]
+struct InputLogImpl {
+    itrng: Vec<u8>,
+    etrng: Vec<EtrngResponse>,
+    /// SoC requests and the cycle they were executed at
+    soc: Vec<(u64, [u8; REQUEST_LEN])>,
+    /// Cycles at which the CPU took an interrupt
+    irq: Vec<u64>,
+    cursor: InputLogCursor,
+    diverged: bool,
+}
+
+impl InputLogImpl {
+    fn diverge(&mut self, now: u64, what: &str) {
+        if !self.diverged {
+            println!("Replay diverged from the input log at cycle {now}: {what}");
+            self.diverged = true;
+        }
+    }
+}
+
+/// The inputs of an emulator run that don't follow from the ROM, firmware
+/// and command line: iTRNG and eTRNG data, requests from the SoC mailbox
+/// socket, and the cycles at which interrupts were taken.
+///
+/// Each stream has a cursor. Inputs before the end of a stream are replayed
+/// from the log; past the end, new inputs are appended. Seeking the cursors
+/// back along with a CPU and bus snapshot re-executes the run exactly.
+/// Interrupts follow from the other inputs, so they are only logged to
+/// detect a replay diverging.
+#[derive(Clone, Default)]
+pub struct InputLog(Rc<RefCell<InputLogImpl>>);
+
+impl InputLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a log saved with [`InputLog::save`], to be replayed from the
+    /// start.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let invalid = |line: &str| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid input log line {line:?}"),
+            )
+        };
+        let mut log = InputLogImpl::default();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            let fields: Vec<_> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                [comment, ..] if comment.starts_with('#') => {}
+                ["itrng", nibbles] => {
+                    for c in nibbles.chars() {
+                        let nibble = c.to_digit(16).ok_or_else(|| invalid(&line))?;
+                        log.itrng.push(nibble as u8);
+                    }
+                }
+                ["etrng", delay, data @ ..] if data.len() == 12 => {
+                    let mut response = EtrngResponse {
+                        delay: delay.parse().map_err(|_| invalid(&line))?,
+                        data: [0; 12],
+                    };
+                    for (word, field) in response.data.iter_mut().zip(data) {
+                        *word = u32::from_str_radix(field, 16).map_err(|_| invalid(&line))?;
+                    }
+                    log.etrng.push(response);
+                }
+                ["soc", cycle, req] => {
+                    let cycle = cycle.parse().map_err(|_| invalid(&line))?;
+                    let req = hex::decode(req)
+                        .ok()
+                        .and_then(|req| req.try_into().ok())
+                        .ok_or_else(|| invalid(&line))?;
+                    log.soc.push((cycle, req));
+                }
+                ["irq", cycle] => log.irq.push(cycle.parse().map_err(|_| invalid(&line))?),
+                _ => return Err(invalid(&line)),
+            }
+        }
+        Ok(Self(Rc::new(RefCell::new(log))))
+    }
+
+    /// Save every input logged so far to `path`, one per line.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let log = self.0.borrow();
+        let mut f = BufWriter::new(File::create(path)?);
+        writeln!(f, "# caliptra-emu input log")?;
+        for nibbles in log.itrng.chunks(ITRNG_NIBBLES_PER_LINE) {
+            let nibbles: String = nibbles.iter().map(|n| format!("{n:x}")).collect();
+            writeln!(f, "itrng {nibbles}")?;
+        }
+        for response in log.etrng.iter() {
+            write!(f, "etrng {}", response.delay)?;
+            for word in response.data {
+                write!(f, " {word:08x}")?;
+            }
+            writeln!(f)?;
+        }
+        for (cycle, req) in log.soc.iter() {
+            writeln!(f, "soc {cycle} {}", hex::encode(req))?;
+        }
+        for cycle in log.irq.iter() {
+            writeln!(f, "irq {cycle}")?;
+        }
+        f.flush()
+    }
+
+    /// Returns the position in each stream, to be restored with
+    /// [`InputLog::seek`] alongside a snapshot.
+    pub fn cursor(&self) -> InputLogCursor {
+        self.0.borrow().cursor
+    }
+
+    pub fn seek(&self, cursor: InputLogCursor) {
+        self.0.borrow_mut().cursor = cursor;
+    }
+
+    /// Returns iTRNG nibbles replayed from the log, then from `source`.
+    pub fn itrng_nibbles(
+        &self,
+        source: impl Iterator<Item = u8> + 'static,
+    ) -> Box<dyn Iterator<Item = u8>> {
+        Box::new(LoggedStream {
+            log: self.clone(),
+            source,
+            stream: itrng_stream,
+        })
+    }
+
+    /// Returns eTRNG responses replayed from the log, then from `source`.
+    pub fn etrng_responses(
+        &self,
+        source: impl Iterator<Item = EtrngResponse> + 'static,
+    ) -> Box<dyn Iterator<Item = EtrngResponse>> {
+        Box::new(LoggedStream {
+            log: self.clone(),
+            source,
+            stream: etrng_stream,
+        })
+    }
+
+    /// Forget the interrupts logged after the cursor, after the machine state
+    /// has been modified and they may no longer be taken.
+    pub fn forget_interrupts(&self) {
+        let mut log = self.0.borrow_mut();
+        let pos = log.cursor.irq;
+        log.irq.truncate(pos);
+    }
+
+    /// Log a request from the SoC mailbox socket executed at cycle `now`.
+    pub fn record_soc_request(&self, now: u64, req: &[u8; REQUEST_LEN]) {
+        let mut log = self.0.borrow_mut();
+        log.soc.push((now, *req));
+        log.cursor.soc = log.soc.len();
+    }
+
+    /// Step `cpu`, first executing the SoC requests logged for the current
+    /// cycle, and log or check the interrupts taken by the step.
+    pub fn step(
+        &self,
+        cpu: &mut Cpu<CaliptraRootBus>,
+        instr_tracer: Option<&mut InstrTracer>,
+    ) -> StepAction {
+        let now = cpu.clock.now();
+        loop {
+            let mut log = self.0.borrow_mut();
+            let req = match log.soc.get(log.cursor.soc).copied() {
+                Some((cycle, req)) if cycle == now => req,
+                Some((cycle, _)) if cycle < now => {
+                    log.diverge(now, "missed a SoC request");
+                    log.cursor.soc += 1;
+                    continue;
+                }
+                _ => break,
+            };
+            log.cursor.soc += 1;
+            drop(log);
+            handle_request(
+                &mut cpu.bus.soc_to_caliptra_bus(MailboxRequester::SocUser(1u32)),
+                &req,
+            );
+        }
+
+        let interrupts_taken = cpu.interrupts_taken();
+        let action = cpu.step(instr_tracer);
+        let now = cpu.clock.now();
+
+        let mut log = self.0.borrow_mut();
+        for _ in interrupts_taken..cpu.interrupts_taken() {
+            match log.irq.get(log.cursor.irq).copied() {
+                Some(cycle) if cycle == now => {}
+                Some(_) => log.diverge(now, "unexpected interrupt"),
+                None => log.irq.push(now),
+            }
+            log.cursor.irq += 1;
+        }
+        if matches!(log.irq.get(log.cursor.irq), Some(&cycle) if cycle <= now) {
+            log.diverge(now, "missed an interrupt");
+        }
+        action
+    }
+}
+
+fn itrng_stream(log: &mut InputLogImpl) -> (&mut Vec<u8>, &mut usize) {
+    (&mut log.itrng, &mut log.cursor.itrng)
+}
+
+fn etrng_stream(log: &mut InputLogImpl) -> (&mut Vec<EtrngResponse>, &mut usize) {
+    (&mut log.etrng, &mut log.cursor.etrng)
+}
+
+/// Iterator over one stream of an [`InputLog`]
+struct LoggedStream<T, I> {
+    log: InputLog,
+    source: I,
+    stream: fn(&mut InputLogImpl) -> (&mut Vec<T>, &mut usize),
+}
+
+impl<T: Copy, I: Iterator<Item = T>> Iterator for LoggedStream<T, I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let mut log = self.log.0.borrow_mut();
+        let (values, pos) = (self.stream)(&mut log);
+        let val = match values.get(*pos) {
+            Some(val) => *val,
+            None => {
+                let val = self.source.next()?;
+                values.push(val);
+                val
+            }
+        };
+        *pos += 1;
+        Some(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use caliptra_builder::firmware;
+    use caliptra_emu_bus::{Bus, Clock};
+    use caliptra_emu_cpu::xreg_file::XReg;
+    use caliptra_emu_periph::{CaliptraRootBusArgs, ReadyForFwCb, TbServicesCb};
+    use caliptra_emu_types::RvSize;
+    use caliptra_hw_model_types::{RandomEtrngResponses, RandomNibbles};
+    use std::cell::Cell;
+
+    /// Upper bound on the cycles for the ROM to ask for firmware
+    const MAX_BOOT_CYCLES: u64 = 100_000_000;
+
+    const DCCM_ORG: u32 = 0x5000_0000;
+
+    #[derive(Debug, PartialEq)]
+    struct FinalState {
+        now: u64,
+        pc: u32,
+        xregs: Vec<u32>,
+        dccm: Vec<u32>,
+        output: String,
+    }
+
+    /// Boot the ROM with inputs from `input_log`, for `cycles` cycles or until
+    /// it asks for firmware if `None`.
+    fn boot(rom: &[u8], input_log: &InputLog, cycles: Option<u64>) -> FinalState {
+        let output = Rc::new(RefCell::new(String::new()));
+        let tb_output = output.clone();
+        let ready_for_fw = Rc::new(Cell::new(false));
+        let cb_ready_for_fw = ready_for_fw.clone();
+        let clock = Clock::new();
+        let bus = CaliptraRootBus::new(
+            &clock,
+            CaliptraRootBusArgs {
+                rom: rom.to_vec(),
+                tb_services_cb: TbServicesCb::new(move |val| {
+                    tb_output.borrow_mut().push(val as char)
+                }),
+                ready_for_fw_cb: ReadyForFwCb::new(move |_| cb_ready_for_fw.set(true)),
+                itrng_nibbles: Some(input_log.itrng_nibbles(RandomNibbles::new_from_thread_rng())),
+                etrng_responses: input_log.etrng_responses(RandomEtrngResponses::new_from_stdrng()),
+                ..Default::default()
+            },
+        );
+        let mut cpu = Cpu::new(bus, clock);
+
+        let end = cycles.unwrap_or(MAX_BOOT_CYCLES);
+        while cpu.clock.now() < end && (cycles.is_some() || !ready_for_fw.get()) {
+            assert_eq!(input_log.step(&mut cpu, None), StepAction::Continue);
+        }
+        assert!(ready_for_fw.get(), "ROM did not ask for firmware");
+
+        let dccm = (DCCM_ORG..DCCM_ORG + CaliptraRootBus::DCCM_SIZE as u32)
+            .step_by(4)
+            .map(|addr| cpu.bus.read(RvSize::Word, addr).unwrap())
+            .collect();
+        let output = output.borrow().clone();
+        FinalState {
+            now: cpu.clock.now(),
+            pc: cpu.read_pc(),
+            xregs: (0u16..32)
+                .map(|i| cpu.read_xreg(XReg::from(i)).unwrap())
+                .collect(),
+            dccm,
+            output,
+        }
+    }
+
+    #[test]
+    fn test_record_replay_boot() {
+        let rom = caliptra_builder::build_firmware_rom(&firmware::ROM_WITH_UART).unwrap();
+        let path =
+            std::env::temp_dir().join(format!("caliptra-emu-input-log-{}.txt", std::process::id()));
+
+        let recorded_log = InputLog::new();
+        let recorded = boot(&rom, &recorded_log, None);
+        assert!(!recorded_log.0.borrow().itrng.is_empty());
+        recorded_log.save(&path).unwrap();
+
+        let replayed_log = InputLog::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let replayed = boot(&rom, &replayed_log, Some(recorded.now));
+        assert!(!replayed_log.0.borrow().diverged);
+
+        // The replay drew every input from the log instead of its own random
+        // sources.
+        let (recorded_log, replayed_log) = (recorded_log.0.borrow(), replayed_log.0.borrow());
+        assert_eq!(replayed_log.itrng, recorded_log.itrng);
+        assert_eq!(replayed_log.etrng.len(), recorded_log.etrng.len());
+        assert_eq!(replayed_log.irq, recorded_log.irq);
+        assert_eq!(replayed, recorded);
+    }
+}
@@ -14,14 +14,17 @@ Abstract:
 
 use caliptra_api_types::{DeviceLifecycle, SecurityState};
 use caliptra_emu_bus::Clock;
-use caliptra_emu_cpu::{Cpu, RvInstr, StepAction};
+use caliptra_emu_cpu::{Cpu, DebugModule, InstrTracer, Profiler, RvInstr, StepAction};
 use caliptra_emu_periph::soc_reg::DebugManufService;
 use caliptra_emu_periph::{
-    CaliptraRootBus, CaliptraRootBusArgs, DownloadIdevidCsrCb, MailboxInternal, MailboxRequester,
-    ReadyForFwCb, TbServicesCb, UploadUpdateFwCb,
+    CaliptraDmi, CaliptraRootBus, CaliptraRootBusArgs, DownloadIdevidCsrCb, MailboxInternal,
+    MailboxRequester, ReadyForFwCb, TbServicesCb, UploadUpdateFwCb,
 };
+use caliptra_hw_model::remote_bitbang::RemoteBitbangServer;
 use caliptra_hw_model::BusMmio;
+use caliptra_hw_model_types::{RandomEtrngResponses, RandomNibbles};
 use clap::{arg, value_parser, ArgAction};
+use std::cell::Cell;
 use std::fs::File;
 use std::io;
 use std::io::{Read, Write};
@@ -31,8 +34,12 @@ use std::rc::Rc;
 use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
 use tock_registers::registers::InMemoryRegister;
 mod gdb;
+mod input_log;
+mod mbox_socket;
 use crate::gdb::gdb_target::GdbTarget;
 use gdb::gdb_state;
+use input_log::InputLog;
+use mbox_socket::MboxSocketServer;
 
 use tock_registers::register_bitfields;
 
@@ -44,9 +51,50 @@ const FW_WRITE_TICKS: u64 = 1000;
 
 const EXPECTED_CALIPTRA_BOOT_TIME_IN_CYCLES: u64 = 20_000_000; // 20 million cycles
 
+/// The number of instructions executed between polls of the mailbox and JTAG
+/// sockets.
+const MBOX_SOCKET_POLL_INTERVAL: u64 = 256;
+
+/// Step the CPU, replaying or logging its inputs. Returns false once the
+/// CPU stops or the firmware has requested an exit.
+fn step(
+    cpu: &mut Cpu<CaliptraRootBus>,
+    input_log: &InputLog,
+    exit_code: &Cell<Option<i32>>,
+    instr_tracer: Option<&mut InstrTracer>,
+) -> bool {
+    input_log.step(cpu, instr_tracer) == StepAction::Continue && exit_code.get().is_none()
+}
+
 // CPU Main Loop (free_run no GDB)
-fn free_run(mut cpu: Cpu<CaliptraRootBus>, trace_path: Option<PathBuf>) {
-    if let Some(path) = trace_path {
+fn free_run(
+    mut cpu: Cpu<CaliptraRootBus>,
+    trace_path: Option<PathBuf>,
+    mut mbox_socket: Option<MboxSocketServer>,
+    mut jtag_server: Option<RemoteBitbangServer>,
+    input_log: &InputLog,
+    exit_code: &Cell<Option<i32>>,
+    profile_path: Option<&PathBuf>,
+) {
+    if mbox_socket.is_some() || jtag_server.is_some() {
+        let mut dm = DebugModule::new();
+        let mut steps = 0u64;
+        while step(&mut cpu, input_log, exit_code, None) {
+            steps += 1;
+            if steps % MBOX_SOCKET_POLL_INTERVAL == 0 {
+                if let Some(server) = &mut mbox_socket {
+                    server.poll(cpu.clock.now());
+                }
+                if let Some(server) = &mut jtag_server {
+                    server.poll(&mut CaliptraDmi {
+                        soc_reg: cpu.bus.soc_reg.clone(),
+                        cpu: &mut cpu,
+                        dm: &mut dm,
+                    });
+                }
+            }
+        }
+    } else if let Some(path) = trace_path {
         let mut f = File::create(path).unwrap();
         let trace_fn: &mut dyn FnMut(u32, RvInstr) = &mut |pc, instr| {
             let _ = write!(&mut f, "0x{:08x} ", pc);
@@ -61,10 +109,29 @@ fn free_run(mut cpu: Cpu<CaliptraRootBus>, trace_path: Option<PathBuf>) {
         };
 
         // Need to have the loop in the same scope as trace_fn to prevent borrowing rules violation
-        while let StepAction::Continue = cpu.step(Some(trace_fn)) {}
+        while step(&mut cpu, input_log, exit_code, Some(trace_fn)) {}
     } else {
-        while let StepAction::Continue = cpu.step(None) {}
+        while step(&mut cpu, input_log, exit_code, None) {}
     };
+
+    if let (Some(path), Some(profiler)) = (profile_path, cpu.take_profiler()) {
+        let mut f = File::create(path).unwrap();
+        profiler.write_folded(&mut f).unwrap();
+    }
+}
+
+/// Build a profiler that attributes cycles to the functions in `elfs`.
+fn profiler_from_elfs(interval: u64, elfs: &[&PathBuf]) -> io::Result<Profiler> {
+    let mut profiler = Profiler::new(interval);
+    for path in elfs {
+        let elf = std::fs::read(path)?;
+        for sym in caliptra_elf_symbols::elf_symbols(&elf)? {
+            if sym.ty == caliptra_elf_symbols::SymbolType::Func {
+                profiler.add_symbol(sym.name, sym.value as u32, sym.size as u32);
+            }
+        }
+    }
+    Ok(profiler)
 }
 
 fn words_from_bytes_le(arr: &[u8; 48]) -> [u32; 12] {
@@ -146,12 +213,53 @@ fn main() -> io::Result<()> {
                 .value_parser(value_parser!(String))
                 .default_value("unprovisioned"),
         )
+        .arg(
+            arg!(--"mbox-socket" <ADDR> "Serve the SoC mailbox, SHA accelerator and soc_ifc registers on a TCP address or Unix socket path")
+                .required(false)
+                .conflicts_with_all(&["gdb-port", "trace-instr"])
+        )
+        .arg(
+            arg!(--"jtag-bitbang" <ADDR> "Serve the JTAG port to OpenOCD's remote_bitbang adapter on a TCP address or Unix socket path")
+                .required(false)
+                .conflicts_with_all(&["gdb-port", "trace-instr"])
+        )
+        .arg(
+            arg!(--"record" <FILE> "Log the TRNG data, SoC requests and interrupts of the run to this file on exit")
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+        )
+        .arg(
+            arg!(--"replay" <FILE> "Replay the inputs logged by --record")
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .conflicts_with("mbox-socket")
+        )
         .arg(
             arg!(--"wdt-timeout" <U64> "Watchdog Timer Timeout in CPU Clock Cycles")
                 .required(false)
                 .value_parser(value_parser!(u64))
                 .default_value(&(EXPECTED_CALIPTRA_BOOT_TIME_IN_CYCLES.to_string()))
         )
+        .arg(
+            arg!(--"profile" <FILE> "Write a folded-stack CPU profile (flamegraph input) to this file on exit")
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+                .conflicts_with("gdb-port")
+        )
+        .arg(
+            arg!(--"profile-elf" <ELF> "ELF file providing function symbols for --profile; may be repeated")
+                .required(false)
+                .action(ArgAction::Append)
+                .value_parser(value_parser!(PathBuf))
+                .requires("profile")
+        )
+        .arg(
+            arg!(--"profile-interval" <U64> "Cycles between profiler samples")
+                .required(false)
+                .value_parser(value_parser!(u64))
+                .default_value("100")
+                .requires("profile")
+        )
         .get_matches();
 
     let args_rom = args.get_one::<PathBuf>("rom").unwrap();
@@ -176,6 +284,7 @@ fn main() -> io::Result<()> {
         }
     };
     let args_device_lifecycle = args.get_one::<String>("device-lifecycle").unwrap();
+    let args_mbox_socket = args.get_one::<String>("mbox-socket");
 
     if !Path::new(&args_rom).exists() {
         println!("ROM File {:?} does not exist", args_rom);
@@ -235,6 +344,7 @@ fn main() -> io::Result<()> {
 
     let req_idevid_csr = args.get_flag("req-idevid-csr");
     let req_ldevid_cert = args.get_flag("req-ldevid-cert");
+    let mbox_socket_mode = args_mbox_socket.is_some();
 
     let mut security_state = SecurityState::default();
     security_state.set_device_lifecycle(
@@ -249,16 +359,34 @@ fn main() -> io::Result<()> {
         },
     );
 
+    let input_log = match args.get_one::<PathBuf>("replay") {
+        Some(path) => match InputLog::load(path) {
+            Ok(input_log) => input_log,
+            Err(e) => {
+                println!("Failed to load input log {path:?}: {e}");
+                exit(-1);
+            }
+        },
+        None => InputLog::new(),
+    };
+    let exit_code = Rc::new(Cell::new(None));
+    let tb_exit_code = exit_code.clone();
+
     let bus_args = CaliptraRootBusArgs {
         rom: rom_buffer,
         log_dir: args_log_dir.clone(),
         tb_services_cb: TbServicesCb::new(move |val| match val {
-            0x01 => exit(0xFF),
-            0xFF => exit(0x00),
+            // Exit once the current step is complete
+            0x01 => tb_exit_code.set(Some(0xFF)),
+            0xFF => tb_exit_code.set(Some(0x00)),
             _ => print!("{}", val as char),
         }),
         ready_for_fw_cb: ReadyForFwCb::new(move |args| {
             let firmware_buffer = current_fw_buf.clone();
+            // Without --firmware, a mailbox socket client loads the firmware.
+            if firmware_buffer.is_empty() && mbox_socket_mode {
+                return;
+            }
             args.schedule_later(FW_WRITE_TICKS, move |mailbox: &mut MailboxInternal| {
                 upload_fw_to_mailbox(mailbox, firmware_buffer);
             });
@@ -276,6 +404,8 @@ fn main() -> io::Result<()> {
                 download_idev_id_csr(mailbox, log_dir.clone(), cptra_dbg_manuf_service_reg);
             },
         ),
+        itrng_nibbles: Some(input_log.itrng_nibbles(RandomNibbles::new_from_thread_rng())),
+        etrng_responses: input_log.etrng_responses(RandomEtrngResponses::new_from_stdrng()),
         ..Default::default()
     };
 
@@ -369,13 +499,61 @@ fn main() -> io::Result<()> {
             .write(|_| (*wdt_timeout >> 32) as u32);
     }
 
-    let cpu = Cpu::new(root_bus, clock);
+    let mbox_socket = match args_mbox_socket {
+        Some(addr) => match MboxSocketServer::bind(
+            addr,
+            root_bus.soc_to_caliptra_bus(MailboxRequester::SocUser(1u32)),
+            input_log.clone(),
+        ) {
+            Ok(server) => {
+                println!("Serving the SoC interface on {addr}");
+                Some(server)
+            }
+            Err(e) => {
+                println!("Failed to bind mailbox socket {addr}: {e}");
+                exit(-1);
+            }
+        },
+        None => None,
+    };
+
+    let jtag_server = match args.get_one::<String>("jtag-bitbang") {
+        Some(addr) => match RemoteBitbangServer::bind(addr) {
+            Ok(server) => {
+                println!("Serving JTAG on {addr}");
+                Some(server)
+            }
+            Err(e) => {
+                println!("Failed to bind JTAG socket {addr}: {e}");
+                exit(-1);
+            }
+        },
+        None => None,
+    };
+
+    let mut cpu = Cpu::new(root_bus, clock);
+
+    let args_profile = args.get_one::<PathBuf>("profile");
+    if args_profile.is_some() {
+        let elfs: Vec<_> = args
+            .get_many::<PathBuf>("profile-elf")
+            .map(|elfs| elfs.collect())
+            .unwrap_or_default();
+        let interval = *args.get_one::<u64>("profile-interval").unwrap();
+        match profiler_from_elfs(interval, &elfs) {
+            Ok(profiler) => cpu.set_profiler(profiler),
+            Err(e) => {
+                println!("Failed to read profile symbols: {e}");
+                exit(-1);
+            }
+        }
+    }
 
     // Check if Optional GDB Port is passed
     match args.get_one::<String>("gdb-port") {
         Some(port) => {
             // Create GDB Target Instance
-            let mut gdb_target = GdbTarget::new(cpu);
+            let mut gdb_target = GdbTarget::new(cpu, input_log.clone(), exit_code.clone());
 
             // Execute CPU through GDB State Machine
             gdb_state::wait_for_gdb_run(&mut gdb_target, port.parse().unwrap());
@@ -390,10 +568,27 @@ fn main() -> io::Result<()> {
             };
 
             // If no GDB Port is passed, Free Run
-            free_run(cpu, instr_trace);
+            free_run(
+                cpu,
+                instr_trace,
+                mbox_socket,
+                jtag_server,
+                &input_log,
+                &exit_code,
+                args_profile,
+            );
         }
     }
 
+    if let Some(path) = args.get_one::<PathBuf>("record") {
+        if let Err(e) = input_log.save(path) {
+            println!("Failed to save input log {path:?}: {e}");
+        }
+    }
+    if let Some(code) = exit_code.get() {
+        exit(code);
+    }
+
     Ok(())
 }
 
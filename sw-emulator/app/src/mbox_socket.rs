@@ -0,0 +1,104 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    mbox_socket.rs
+
+Abstract:
+
+    File contains the server for the --mbox-socket mode, which lets a SoC
+    manager in another process access the mailbox, SHA accelerator and
+    soc_ifc registers.
+
+--*/
+
+use crate::input_log::InputLog;
+use caliptra_emu_periph::SocToCaliptraBus;
+use caliptra_hw_model::soc_socket::{handle_request, SocketListener, SocketStream, REQUEST_LEN};
+use std::io::{self, ErrorKind, Read, Write};
+
+/// Serves one client at a time. The emulator calls `poll()` between CPU
+/// steps, so a client's accesses are interleaved with firmware execution.
+pub struct MboxSocketServer {
+    listener: SocketListener,
+    client: Option<SocketStream>,
+    pending: Vec<u8>,
+    bus: SocToCaliptraBus,
+    input_log: InputLog,
+}
+
+impl MboxSocketServer {
+    /// Bind to `addr`; requests are executed on `bus` and logged to
+    /// `input_log`.
+    pub fn bind(addr: &str, bus: SocToCaliptraBus, input_log: InputLog) -> io::Result<Self> {
+        let listener = SocketListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self {
+            listener,
+            client: None,
+            pending: Vec::with_capacity(REQUEST_LEN),
+            bus,
+            input_log,
+        })
+    }
+
+    /// Accepts a client if none is connected and executes any requests it
+    /// has sent, at cycle `now`. Never blocks waiting for the client.
+    pub fn poll(&mut self, now: u64) {
+        if self.client.is_none() {
+            match self.listener.accept() {
+                Ok(stream) => {
+                    if let Err(e) = stream.set_nonblocking(true) {
+                        println!("Failed to configure mailbox socket client: {e}");
+                        return;
+                    }
+                    self.pending.clear();
+                    self.client = Some(stream);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return,
+                Err(e) => {
+                    println!("Failed to accept mailbox socket client: {e}");
+                    return;
+                }
+            }
+        }
+        if let Err(e) = self.serve_client(now) {
+            if e.kind() != ErrorKind::UnexpectedEof {
+                println!("Mailbox socket client error: {e}");
+            }
+            self.client = None;
+        }
+    }
+
+    fn serve_client(&mut self, now: u64) -> io::Result<()> {
+        let Some(client) = self.client.as_mut() else {
+            return Ok(());
+        };
+        loop {
+            let mut buf = [0u8; REQUEST_LEN];
+            let len = match client.read(&mut buf[..REQUEST_LEN - self.pending.len()]) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(len) => len,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.pending.extend_from_slice(&buf[..len]);
+            if self.pending.len() < REQUEST_LEN {
+                continue;
+            }
+
+            let req: [u8; REQUEST_LEN] = self.pending[..].try_into().unwrap();
+            self.pending.clear();
+            self.input_log.record_soc_request(now, &req);
+            let resp = handle_request(&mut self.bus, &req);
+
+            // The response is tiny; block until it has been sent.
+            client.set_nonblocking(false)?;
+            client.write_all(&resp)?;
+            client.set_nonblocking(true)?;
+        }
+    }
+}
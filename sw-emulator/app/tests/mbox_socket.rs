@@ -0,0 +1,88 @@
+// Licensed under the Apache-2.0 license
+
+use caliptra_api::{Capabilities, SocManager};
+use caliptra_builder::firmware;
+use caliptra_hw_model::soc_socket::SocketSocManager;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+const EMU_BIN: &str = env!("CARGO_BIN_EXE_caliptra-emu");
+
+/// Upper bound on the time for the emulator to start and the ROM to boot
+const TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Kills the emulator and removes its files when the test ends.
+struct Emulator {
+    child: Child,
+    files: Vec<PathBuf>,
+}
+
+impl Drop for Emulator {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        for file in &self.files {
+            let _ = std::fs::remove_file(file);
+        }
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("caliptra-emu-{}-{name}", std::process::id()))
+}
+
+fn connect(emu: &mut Emulator, addr: &Path) -> SocketSocManager {
+    let start = Instant::now();
+    loop {
+        if let Ok(soc) = SocketSocManager::connect(addr.to_str().unwrap()) {
+            return soc;
+        }
+        if let Some(status) = emu.child.try_wait().unwrap() {
+            panic!("caliptra-emu exited with {status}");
+        }
+        assert!(start.elapsed() < TIMEOUT, "caliptra-emu did not listen");
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn test_mailbox_command_over_socket() {
+    let rom = caliptra_builder::build_firmware_rom(&firmware::ROM_WITH_UART).unwrap();
+    let rom_path = temp_path("rom.bin");
+    let addr = temp_path("mbox.sock");
+    let log_dir = temp_path("log");
+    std::fs::create_dir_all(&log_dir).unwrap();
+    std::fs::write(&rom_path, rom).unwrap();
+
+    let child = Command::new(EMU_BIN)
+        .arg("--rom")
+        .arg(&rom_path)
+        .arg("--mbox-socket")
+        .arg(&addr)
+        .arg("--log-dir")
+        .arg(&log_dir)
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut emu = Emulator {
+        child,
+        files: vec![rom_path, addr.clone()],
+    };
+
+    let mut soc = connect(&mut emu, &addr);
+
+    // Without --firmware the ROM waits for the client to load it.
+    let start = Instant::now();
+    while !soc.soc_ifc().cptra_flow_status().read().ready_for_fw() {
+        assert!(start.elapsed() < TIMEOUT, "ROM did not ask for firmware");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let caps = soc.get_capabilities().unwrap();
+    assert!(caps.contains(Capabilities::ROM_BASE));
+    assert!(soc.take_error().is_none());
+
+    drop(emu);
+    let _ = std::fs::remove_dir_all(&log_dir);
+}
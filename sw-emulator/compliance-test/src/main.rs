@@ -106,6 +106,26 @@ static TESTS_TO_RUN: &[TestInfo] = &[
     TestInfo {extension: "C", name: "cxor-01"},
 ];
 
+/// Tests for privilege modes and PMP, which riscv-arch-test doesn't cover
+/// for this configuration. They use the same memory layout and signature
+/// conventions as the riscv-arch-test tests.
+pub struct CustomTestInfo {
+    name: &'static str,
+    source: &'static [u8],
+    reference: &'static str,
+}
+macro_rules! custom_test {
+    ($name:literal) => {
+        CustomTestInfo {
+            name: $name,
+            source: include_bytes!(concat!("../custom-tests/", $name, ".S")),
+            reference: include_str!(concat!("../custom-tests/", $name, ".reference_output")),
+        }
+    };
+}
+static CUSTOM_TESTS: &[CustomTestInfo] =
+    &[custom_test!("priv-mret-ecall-01"), custom_test!("pmp-01")];
+
 fn into_io_error(err: impl Into<Box<dyn Error + Send + Sync>>) -> std::io::Error {
     std::io::Error::new(ErrorKind::Other, err)
 }
@@ -161,25 +181,33 @@ fn main() -> Result<(), Box<dyn Error>> {
         println!("Running test {}/{}", test.extension, test.name);
         let binary: Vec<u8> = builder.build_test_binary(test)?;
         let reference_txt = builder.get_reference_data(test)?;
+        run_test(binary, &reference_txt)?;
+    }
+    for test in CUSTOM_TESTS.iter() {
+        println!("Running test custom/{}", test.name);
+        let binary: Vec<u8> = builder.build_custom_test_binary(test)?;
+        run_test(binary, test.reference)?;
+    }
+    Ok(())
+}
 
-        let mut cpu = Cpu::new(Ram::new(binary), Clock::new());
-        cpu.write_pc(0x3000);
-        while !is_test_complete(&mut cpu.bus) {
-            match cpu.step(None) {
-                StepAction::Continue => continue,
-                _ => break,
-            }
+fn run_test(binary: Vec<u8>, reference_txt: &str) -> Result<(), Box<dyn Error>> {
+    let mut cpu = Cpu::new(Ram::new(binary), Clock::new());
+    cpu.write_pc(0x3000);
+    while !is_test_complete(&mut cpu.bus) {
+        match cpu.step(None) {
+            StepAction::Continue => continue,
+            _ => break,
         }
-        if !is_test_complete(&mut cpu.bus) {
-            return Err(std::io::Error::new(
-                ErrorKind::Other,
-                "test did not complete",
-            ))?;
-        }
-        check_reference_data(&reference_txt, &mut cpu.bus)?;
-        println!("PASSED");
-        drop(cpu);
     }
+    if !is_test_complete(&mut cpu.bus) {
+        return Err(std::io::Error::new(
+            ErrorKind::Other,
+            "test did not complete",
+        ))?;
+    }
+    check_reference_data(reference_txt, &mut cpu.bus)?;
+    println!("PASSED");
     Ok(())
 }
 
@@ -13,8 +13,8 @@ Abstract:
 --*/
 use crate::exec::exec;
 use crate::fs::{self, TempDir, TempFile};
-use crate::{into_io_error, TestInfo};
-use std::path::PathBuf;
+use crate::{into_io_error, CustomTestInfo, TestInfo};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[derive(Clone)]
@@ -49,6 +49,27 @@ impl TestBuilder {
         })
     }
     pub fn build_test_binary(&self, test: &TestInfo) -> std::io::Result<Vec<u8>> {
+        self.build_binary(
+            if test.extension == "C" {
+                "-march=rv32imc"
+            } else {
+                "-march=rv32im"
+            },
+            &self
+                .config
+                .test_root_path
+                .join("riscv-test-suite/rv32i_m")
+                .join(test.extension)
+                .join("src")
+                .join(format!("{}.S", test.name)),
+        )
+    }
+    pub fn build_custom_test_binary(&self, test: &CustomTestInfo) -> std::io::Result<Vec<u8>> {
+        let src_file = TempFile::with_extension(".S")?;
+        fs::write(&src_file, test.source)?;
+        self.build_binary("-march=rv32imc", src_file.path())
+    }
+    fn build_binary(&self, march: &str, src_path: &Path) -> std::io::Result<Vec<u8>> {
         let elf_file = TempFile::with_extension(".o")?;
         let bin_file = TempFile::with_extension(".bin")?;
         exec(
@@ -58,11 +79,7 @@ impl TestBuilder {
                 .arg("-DXLEN=32")
                 .arg("-static")
                 .arg("-mcmodel=medany")
-                .arg(if test.extension == "C" {
-                    "-march=rv32imc"
-                } else {
-                    "-march=rv32im"
-                })
+                .arg(march)
                 .arg("-mabi=ilp32")
                 .arg("-fvisibility=hidden")
                 .arg("-nostdlib")
@@ -73,14 +90,7 @@ impl TestBuilder {
                 .arg(self.include_dir.path())
                 .arg("-T")
                 .arg(self.linker_script.path())
-                .arg(
-                    self.config
-                        .test_root_path
-                        .join("riscv-test-suite/rv32i_m")
-                        .join(test.extension)
-                        .join("src")
-                        .join(format!("{}.S", test.name)),
-                )
+                .arg(src_path)
                 .arg("-o")
                 .arg(elf_file.path()),
         )?;
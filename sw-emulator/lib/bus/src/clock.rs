@@ -123,6 +123,21 @@ impl Timer {
     pub fn cancel(&self, handle: ActionHandle) {
         self.clock.cancel(handle)
     }
+
+    /// Recreates an `ActionHandle` captured with [`ActionHandle::snapshot`]
+    /// against this timer's clock. The action must have been restored into the
+    /// clock with [`Clock::restore`] first.
+    pub fn restore_action(&self, snapshot: ActionSnapshot) -> ActionHandle {
+        ActionHandleImpl {
+            time: snapshot.time,
+            id: TimerActionId {
+                timer_ptr: Rc::as_ptr(&self.clock),
+                id: snapshot.id,
+            },
+            action: snapshot.action,
+        }
+        .into()
+    }
 }
 
 pub struct Clock {
@@ -191,12 +206,83 @@ impl Clock {
         }
         fired_actions
     }
+
+    /// Captures the current time and all pending timer actions.
+    pub fn snapshot(&self) -> ClockSnapshot {
+        ClockSnapshot {
+            now: self.clock.now(),
+            next_action_id: self.clock.next_action_id.get(),
+            actions: self
+                .clock
+                .action_handles
+                .borrow()
+                .iter()
+                .map(ActionSnapshot::from)
+                .collect(),
+        }
+    }
+
+    /// Replaces the current time and pending timer actions with those from
+    /// `snapshot`. Any `ActionHandle` held by a peripheral must be recreated
+    /// with [`Timer::restore_action`], so the clock must be restored before
+    /// any peripheral or bus created with it.
+    pub fn restore(&self, snapshot: &ClockSnapshot) {
+        self.clock.now.set(snapshot.now);
+        self.clock.next_action_id.set(snapshot.next_action_id);
+        let mut actions = self.clock.action_handles.borrow_mut();
+        *actions = snapshot
+            .actions
+            .iter()
+            .map(|action| ActionHandleImpl {
+                time: action.time,
+                id: TimerActionId {
+                    timer_ptr: Rc::as_ptr(&self.clock),
+                    id: action.id,
+                },
+                action: action.action,
+            })
+            .collect();
+        self.clock.recompute_next_action_time(&actions);
+    }
+}
+
+/// Saved state of a [`Clock`], returned by [`Clock::snapshot`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClockSnapshot {
+    now: u64,
+    next_action_id: u64,
+    actions: Vec<ActionSnapshot>,
+}
+
+/// Saved state of an [`ActionHandle`], independent of the clock it was
+/// scheduled on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ActionSnapshot {
+    time: u64,
+    id: u64,
+    action: TimerAction,
+}
+impl From<&ActionHandleImpl> for ActionSnapshot {
+    fn from(val: &ActionHandleImpl) -> Self {
+        Self {
+            time: val.time,
+            id: val.id.id,
+            action: val.action,
+        }
+    }
 }
 
 /// Represents an action scheduled with a `Timer`. Returned by
 /// [`Timer::schedule_poll_at`] and passed to [`Timer::has_fired()`] or
 /// [`Timer::cancel`].
 pub struct ActionHandle(ActionHandleImpl);
+impl ActionHandle {
+    /// Captures this action so it can be recreated with
+    /// [`Timer::restore_action`].
+    pub fn snapshot(&self) -> ActionSnapshot {
+        ActionSnapshot::from(&self.0)
+    }
+}
 impl From<ActionHandleImpl> for ActionHandle {
     fn from(val: ActionHandleImpl) -> Self {
         ActionHandle(val)
@@ -486,6 +572,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_snapshot_restore() {
+        let clock = Clock::new();
+        let timer = clock.timer();
+        clock.increment(10);
+        let action0 = timer.schedule_poll_in(25);
+        let action1 = timer.schedule_action_in(40, TimerAction::WarmReset);
+        timer.cancel(action0);
+
+        let snapshot = clock.snapshot();
+        let action1 = action1.snapshot();
+
+        let restored = Clock::new();
+        let restored_timer = restored.timer();
+        restored.restore(&snapshot);
+        assert_eq!(restored.now(), 10);
+        assert_eq!(restored.snapshot(), snapshot);
+
+        let mut action1 = Some(restored_timer.restore_action(action1));
+        assert!(restored.increment(39).is_empty());
+        assert!(!restored_timer.fired(&mut action1));
+        assert_eq!(
+            restored.increment(1),
+            HashSet::from([TimerAction::WarmReset])
+        );
+        assert!(restored_timer.fired(&mut action1));
+
+        // The restored clock is independent of the original.
+        assert_eq!(clock.now(), 10);
+        let mut action2 = Some(restored_timer.schedule_poll_in(5));
+        restored_timer.cancel(action2.take().unwrap());
+        assert!(restored.increment(10).is_empty());
+    }
+
     #[test]
     fn test_increment_and_poll() {
         let clock = Clock::new();
@@ -21,7 +21,7 @@ mod register_array;
 mod rom;
 pub mod testing;
 
-pub use crate::clock::{ActionHandle, Clock, Timer, TimerAction};
+pub use crate::clock::{ActionHandle, ActionSnapshot, Clock, ClockSnapshot, Timer, TimerAction};
 pub use crate::dynamic_bus::DynamicBus;
 pub use crate::mmio::BusMmio;
 pub use crate::ram::Ram;
@@ -47,6 +47,7 @@ impl From<MemError> for BusError {
 
 /// Memory
 #[allow(dead_code)]
+#[derive(Clone)]
 pub struct Mem {
     /// Data storage
     data: Vec<u8>,
@@ -16,6 +16,7 @@ use crate::{mem::Mem, Bus, BusError};
 use caliptra_emu_types::{RvAddr, RvData, RvSize};
 
 /// Read Only Memory Device
+#[derive(Clone)]
 pub struct Ram {
     /// Inject double-bit ECC errors on read
     pub error_injection: u8,
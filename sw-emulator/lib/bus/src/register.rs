@@ -206,6 +206,12 @@ impl<T: UIntLike, R: RegisterLongName> ReadWriteRegister<T, R> {
     }
 }
 
+impl<T: UIntLike, R: RegisterLongName> Clone for ReadWriteRegister<T, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.reg.get())
+    }
+}
+
 impl<T: UIntLike + RvDataConverter<T>, R: RegisterLongName> Register for ReadWriteRegister<T, R> {
     const SIZE: usize = std::mem::size_of::<T>();
 
@@ -245,6 +251,12 @@ impl<T: UIntLike, R: RegisterLongName> ReadOnlyRegister<T, R> {
     }
 }
 
+impl<T: UIntLike, R: RegisterLongName> Clone for ReadOnlyRegister<T, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.reg.get())
+    }
+}
+
 impl<T: UIntLike + RvDataConverter<T>, R: RegisterLongName> Register for ReadOnlyRegister<T, R>
 where
     RvData: From<T>,
@@ -280,6 +292,12 @@ impl<T: UIntLike, R: RegisterLongName> WriteOnlyRegister<T, R> {
     }
 }
 
+impl<T: UIntLike, R: RegisterLongName> Clone for WriteOnlyRegister<T, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.reg.get())
+    }
+}
+
 impl<T: UIntLike + RvDataConverter<T>, R: RegisterLongName> Register for WriteOnlyRegister<T, R>
 where
     RvData: From<T>,
@@ -304,6 +322,7 @@ where
 }
 
 /// Fixed sized Read Write Memory
+#[derive(Clone)]
 pub struct ReadWriteMemory<const N: usize> {
     data: Mem,
 }
@@ -366,6 +385,7 @@ impl<const N: usize> Default for ReadWriteMemory<N> {
 }
 
 /// Fixed sized Read Only Memory
+#[derive(Clone)]
 pub struct ReadOnlyMemory<const N: usize> {
     data: Mem,
 }
@@ -425,6 +445,7 @@ impl<const N: usize> Default for ReadOnlyMemory<N> {
 }
 
 /// Fixed sized Write Only Memory
+#[derive(Clone)]
 pub struct WriteOnlyMemory<const N: usize> {
     data: Mem,
 }
@@ -40,6 +40,19 @@ impl<
         }
     }
 }
+impl<
+        T: UIntLike + Into<RvData> + TryFrom<RvData>,
+        const SIZE: usize,
+        R: Copy + RegisterLongName,
+    > Clone for ReadWriteRegisterArray<T, SIZE, R>
+{
+    fn clone(&self) -> Self {
+        Self {
+            regs: self.regs,
+            associated_register: PhantomData,
+        }
+    }
+}
 impl<T: UIntLike + Into<RvData> + TryFrom<RvData>, const SIZE: usize, R: RegisterLongName>
     ReadWriteRegisterArray<T, SIZE, R>
 {
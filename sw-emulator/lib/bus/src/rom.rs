@@ -17,6 +17,7 @@ use crate::{Bus, BusError};
 use caliptra_emu_types::{RvAddr, RvData, RvSize};
 
 /// Read Only Memory Device
+#[derive(Clone)]
 pub struct Rom {
     /// Read Only Data
     data: Mem,
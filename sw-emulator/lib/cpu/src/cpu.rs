@@ -13,8 +13,12 @@ Abstract:
 --*/
 
 use crate::csr_file::{Csr, CsrFile};
+use crate::fault::FaultInjector;
+use crate::hpm::HpmEvent;
 use crate::instr::Instr;
-use crate::types::{RvInstr, RvMEIHAP, RvMStatus};
+use crate::pmp::PmpAccess;
+use crate::profiler::Profiler;
+use crate::types::{RvInstr, RvMEIHAP, RvMStatus, RvPrivMode};
 use crate::xreg_file::{XReg, XRegFile};
 use bit_vec::BitVec;
 use caliptra_emu_bus::{Bus, BusError, Clock, TimerAction};
@@ -153,14 +157,17 @@ pub struct CoverageBitmaps<'a> {
     pub iccm: &'a bit_vec::BitVec,
 }
 
-const ICCM_SIZE: usize = 128 * 1024;
-const ICCM_ORG: usize = 0x40000000;
+pub(crate) const ICCM_SIZE: usize = 128 * 1024;
+pub(crate) const ICCM_ORG: usize = 0x40000000;
 const ICCM_UPPER: usize = ICCM_ORG + ICCM_SIZE - 1;
 
-const ROM_SIZE: usize = 48 * 1024;
-const ROM_ORG: usize = 0x00000000;
+pub(crate) const ROM_SIZE: usize = 48 * 1024;
+pub(crate) const ROM_ORG: usize = 0x00000000;
 const ROM_UPPER: usize = ROM_ORG + ROM_SIZE - 1;
 
+pub(crate) const DCCM_ORG: u32 = 0x5000_0000;
+pub(crate) const DCCM_SIZE: u32 = 128 * 1024;
+
 impl CodeCoverage {
     pub fn new(rom_capacity_in_bytes: usize, iccm_capacity_in_bytes: usize) -> Self {
         Self {
@@ -244,11 +251,14 @@ pub struct Cpu<TBus: Bus> {
     xregs: XRegFile,
 
     /// Configuration & status registers
-    csrs: CsrFile,
+    pub(crate) csrs: CsrFile,
 
     // Program counter
     pc: RvData,
 
+    /// Current privilege mode
+    priv_mode: RvPrivMode,
+
     /// The next program counter after the current instruction is finished executing.
     next_pc: RvData,
 
@@ -267,6 +277,9 @@ pub struct Cpu<TBus: Bus> {
     /// Halted state
     halted: bool,
 
+    /// Halted in Debug Mode
+    pub(crate) debug_mode: bool,
+
     // The bus the CPU uses to talk to memory and peripherals.
     pub bus: TBus,
 
@@ -280,6 +293,32 @@ pub struct Cpu<TBus: Bus> {
 
     pub code_coverage: CodeCoverage,
     stack_info: Option<StackInfo>,
+
+    pub(crate) fault_injector: FaultInjector,
+
+    pub(crate) profiler: Option<Profiler>,
+
+    /// Number of NMIs and external interrupts taken
+    interrupts_taken: u64,
+}
+
+/// Saved architectural state of a [`Cpu`], returned by [`Cpu::snapshot`].
+///
+/// Code coverage, stack tracking, watch pointers and the profiler are not
+/// part of the snapshot.
+#[derive(Clone)]
+pub struct CpuSnapshot {
+    xregs: XRegFile,
+    csrs: Vec<Csr>,
+    pc: RvData,
+    priv_mode: RvPrivMode,
+    next_pc: RvData,
+    nmivec: u32,
+    ext_int_vec: u32,
+    global_int_en: bool,
+    ext_int_en: bool,
+    halted: bool,
+    debug_mode: bool,
 }
 
 impl<TBus: Bus> Drop for Cpu<TBus> {
@@ -318,6 +357,7 @@ impl<TBus: Bus> Cpu<TBus> {
             xregs: XRegFile::new(),
             csrs: CsrFile::new(&clock),
             pc: Self::PC_RESET_VAL,
+            priv_mode: RvPrivMode::M,
             next_pc: Self::PC_RESET_VAL,
             bus,
             clock,
@@ -328,10 +368,14 @@ impl<TBus: Bus> Cpu<TBus> {
             global_int_en: false,
             ext_int_en: false,
             halted: false,
+            debug_mode: false,
             // TODO: Pass in code_coverage from the outside (as caliptra-emu-cpu
             // isn't supposed to know anything about the caliptra memory map)
             code_coverage: CodeCoverage::new(ROM_SIZE, ICCM_SIZE),
             stack_info: None,
+            fault_injector: FaultInjector::default(),
+            profiler: None,
+            interrupts_taken: 0,
         }
     }
 
@@ -339,6 +383,39 @@ impl<TBus: Bus> Cpu<TBus> {
         self.stack_info = Some(stack_info);
     }
 
+    /// Capture the register files, program counter and interrupt state of the
+    /// CPU. The clock and bus must be captured separately.
+    pub fn snapshot(&self) -> CpuSnapshot {
+        CpuSnapshot {
+            xregs: self.xregs.clone(),
+            csrs: self.csrs.snapshot(),
+            pc: self.pc,
+            priv_mode: self.priv_mode,
+            next_pc: self.next_pc,
+            nmivec: self.nmivec,
+            ext_int_vec: self.ext_int_vec,
+            global_int_en: self.global_int_en,
+            ext_int_en: self.ext_int_en,
+            halted: self.halted,
+            debug_mode: self.debug_mode,
+        }
+    }
+
+    /// Restore the CPU state captured with [`Cpu::snapshot`].
+    pub fn restore(&mut self, snapshot: &CpuSnapshot) {
+        self.xregs = snapshot.xregs.clone();
+        self.csrs.restore(&snapshot.csrs);
+        self.pc = snapshot.pc;
+        self.priv_mode = snapshot.priv_mode;
+        self.next_pc = snapshot.next_pc;
+        self.nmivec = snapshot.nmivec;
+        self.ext_int_vec = snapshot.ext_int_vec;
+        self.global_int_en = snapshot.global_int_en;
+        self.ext_int_en = snapshot.ext_int_en;
+        self.halted = snapshot.halted;
+        self.debug_mode = snapshot.debug_mode;
+    }
+
     /// Read the RISCV CPU Program counter
     ///
     ///  # Return
@@ -361,6 +438,30 @@ impl<TBus: Bus> Cpu<TBus> {
         self.pc = 0;
     }
 
+    /// Returns the current privilege mode
+    pub fn priv_mode(&self) -> RvPrivMode {
+        self.priv_mode
+    }
+
+    /// Set the current privilege mode
+    ///
+    /// Should only be set by instruction implementations and trap handling.
+    pub(crate) fn set_priv_mode(&mut self, mode: RvPrivMode) {
+        self.priv_mode = mode;
+    }
+
+    /// Returns the privilege mode used by loads and stores. In Machine mode
+    /// with `mstatus.MPRV` set, this is the mode in `mstatus.MPP`.
+    fn data_priv_mode(&self) -> RvPrivMode {
+        // Cannot panic; mstatus is a valid CSR
+        let status = RvMStatus(self.read_csr(Csr::MSTATUS).unwrap());
+        if self.priv_mode == RvPrivMode::M && status.mprv() == 1 {
+            RvPrivMode::from(status.mpp())
+        } else {
+            self.priv_mode
+        }
+    }
+
     /// Returns the next program counter after the current instruction is finished executing.
     pub fn next_pc(&self) -> RvData {
         self.next_pc
@@ -473,10 +574,23 @@ impl<TBus: Bus> Cpu<TBus> {
                     kind: WatchPtrKind::Read,
                 }),
                 false => None,
+            };
+            if !self
+                .csrs
+                .pmp_check(addr, size, PmpAccess::Read, self.data_priv_mode())
+            {
+                return Err(RvException::load_access_fault(addr));
             }
         }
 
         match self.bus.read(size, addr) {
+            Ok(val) if self.is_execute_instr => {
+                self.csrs.hpm_count_access(addr, size, false);
+                match self.fault_injector.take_read_mask() {
+                    Some(mask) => Ok(val ^ mask),
+                    None => Ok(val),
+                }
+            }
             Ok(val) => Ok(val),
             Err(exception) => match exception {
                 BusError::InstrAccessFault => Err(RvException::instr_access_fault(addr)),
@@ -514,10 +628,21 @@ impl<TBus: Bus> Cpu<TBus> {
                     kind: WatchPtrKind::Write,
                 }),
                 false => None,
+            };
+            if !self
+                .csrs
+                .pmp_check(addr, size, PmpAccess::Write, self.data_priv_mode())
+            {
+                return Err(RvException::store_access_fault(addr));
             }
         }
         match self.bus.write(size, addr, val) {
-            Ok(val) => Ok(val),
+            Ok(val) => {
+                if self.is_execute_instr {
+                    self.csrs.hpm_count_access(addr, size, true);
+                }
+                Ok(val)
+            }
             Err(exception) => match exception {
                 BusError::InstrAccessFault => Err(RvException::instr_access_fault(addr)),
                 BusError::LoadAccessFault => Err(RvException::load_access_fault(addr)),
@@ -537,11 +662,17 @@ impl<TBus: Bus> Cpu<TBus> {
     ///
     /// # Error
     ///
-    /// * `RvException` - Exception with cause `RvExceptionCause::LoadAccessFault`
-    ///                   or `RvExceptionCause::LoadAddrMisaligned`
+    /// * `RvException` - Exception with cause `RvExceptionCause::InstrAccessFault`
+    ///                   or `RvExceptionCause::InstrAddrMisaligned`
     pub fn read_instr(&mut self, size: RvSize, addr: RvAddr) -> Result<RvData, RvException> {
         match size {
             RvSize::Byte => Err(RvException::instr_access_fault(addr)),
+            _ if !self
+                .csrs
+                .pmp_check(addr, size, PmpAccess::Exec, self.priv_mode) =>
+            {
+                Err(RvException::instr_access_fault(addr))
+            }
             _ => match self.bus.read(size, addr) {
                 Ok(val) => Ok(val),
                 Err(exception) => match exception {
@@ -570,11 +701,15 @@ impl<TBus: Bus> Cpu<TBus> {
             match action_type {
                 TimerAction::WarmReset => {
                     self.halted = false;
+                    self.debug_mode = false;
+                    self.priv_mode = RvPrivMode::M;
                     self.reset_pc();
                     break;
                 }
                 TimerAction::UpdateReset => {
                     self.halted = false;
+                    self.debug_mode = false;
+                    self.priv_mode = RvPrivMode::M;
                     self.reset_pc();
                     break;
                 }
@@ -584,7 +719,11 @@ impl<TBus: Bus> Cpu<TBus> {
                 }
                 TimerAction::SetNmiVec { addr } => self.nmivec = *addr,
                 TimerAction::ExtInt { irq, can_wake } => {
-                    if self.global_int_en && self.ext_int_en && (!self.halted || *can_wake) {
+                    if self.global_int_en
+                        && self.ext_int_en
+                        && !self.debug_mode
+                        && (!self.halted || *can_wake)
+                    {
                         self.halted = false;
                         return self.handle_external_int(*irq);
                     }
@@ -597,20 +736,34 @@ impl<TBus: Bus> Cpu<TBus> {
             }
         }
 
+        // Halted by the debugger; only the debug module can resume execution
+        if self.debug_mode {
+            return StepAction::Continue;
+        }
+
         // We are in a halted state. Don't continue executing but poll the bus for interrupts
         if self.halted {
             self.set_next_pc(self.pc);
             return StepAction::Continue;
         }
 
-        match self.exec_instr(instr_tracer) {
+        self.csrs.hpm_tick();
+
+        if let Some(action) = self.apply_fault() {
+            return action;
+        }
+
+        let action = match self.exec_instr(instr_tracer) {
             Ok(result) => result,
             Err(exception) => self.handle_exception(exception),
-        }
+        };
+        self.check_single_step();
+        action
     }
 
     /// Handle synchronous exception
-    fn handle_exception(&mut self, exception: RvException) -> StepAction {
+    pub(crate) fn handle_exception(&mut self, exception: RvException) -> StepAction {
+        self.csrs.hpm_count_exception(exception.cause());
         let ret = self.handle_trap(
             self.read_pc(),
             exception.cause().into(),
@@ -626,6 +779,7 @@ impl<TBus: Bus> Cpu<TBus> {
 
     /// Handle non-maskable interrupt (VeeR-specific)
     fn handle_nmi(&mut self, cause: u32, info: u32) -> StepAction {
+        self.interrupts_taken += 1;
         let ret = self.handle_trap(self.read_pc(), cause, info, self.nmivec);
         match ret {
             Ok(_) => StepAction::Continue,
@@ -652,7 +806,9 @@ impl<TBus: Bus> Cpu<TBus> {
         let mut status = RvMStatus(self.read_csr(Csr::MSTATUS)?);
         status.set_mpie(status.mie());
         status.set_mie(0);
+        status.set_mpp(self.priv_mode.into());
         self.write_csr(Csr::MSTATUS, status.0)?;
+        self.priv_mode = RvPrivMode::M;
         // Don't rely on write_csr to disable global interrupts as the scheduled action could be
         // after a next interrupt
         self.global_int_en = false;
@@ -669,8 +825,6 @@ impl<TBus: Bus> Cpu<TBus> {
     fn handle_external_int(&mut self, irq: u8) -> StepAction {
         const REDIRECT_ENTRY_SIZE: u32 = 4;
         const MAX_IRQ: u32 = 32;
-        const DCCM_ORG: u32 = 0x5000_0000;
-        const DCCM_SIZE: u32 = 128 * 1024;
 
         let vec_table = self.ext_int_vec;
         if vec_table < DCCM_ORG || vec_table + MAX_IRQ * REDIRECT_ENTRY_SIZE > DCCM_ORG + DCCM_SIZE
@@ -687,6 +841,8 @@ impl<TBus: Bus> Cpu<TBus> {
         };
         let Ok(next_pc) = self.read_bus(RvSize::Word, next_pc_ptr) else { return StepAction::Fatal; };
         const MACHINE_EXTERNAL_INT: u32 = 0x8000_000B;
+        self.csrs.hpm_count(HpmEvent::ExtInterruptsTaken);
+        self.interrupts_taken += 1;
         let ret = self.handle_trap(self.read_pc(), MACHINE_EXTERNAL_INT, 0, next_pc);
         match ret {
             Ok(_) => StepAction::Continue,
@@ -717,6 +873,12 @@ impl<TBus: Bus> Cpu<TBus> {
     pub fn get_watchptr_hit(&self) -> Option<&WatchPtrHit> {
         self.watch_ptr_cfg.hit.as_ref()
     }
+
+    /// Returns the number of NMIs and external interrupts taken since the
+    /// CPU was created. Not part of [`CpuSnapshot`].
+    pub fn interrupts_taken(&self) -> u64 {
+        self.interrupts_taken
+    }
 }
 
 #[cfg(test)]
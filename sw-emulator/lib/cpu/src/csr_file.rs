@@ -12,7 +12,11 @@ Abstract:
 
 --*/
 
-use crate::types::{RvMIE, RvMPMC, RvMStatus};
+use crate::hpm::HPM_COUNTERS;
+use crate::pmp::PMP_ENTRIES;
+use crate::types::{
+    RvDcsr, RvDebugCause, RvMIE, RvMPMC, RvMStatus, RvPmpAddrMode, RvPmpCfg, RvPrivMode,
+};
 use caliptra_emu_bus::{Clock, Timer, TimerAction};
 use caliptra_emu_types::{RvAddr, RvData, RvException};
 
@@ -66,6 +70,24 @@ impl Csr {
     /// Interrupt Pending CSR
     pub const MIP: RvAddr = 0x344;
 
+    /// PMP Configuration CSR Start Address
+    pub const PMPCFG_START: RvAddr = 0x3A0;
+
+    /// PMP Configuration CSR End Address
+    pub const PMPCFG_END: RvAddr = 0x3A3;
+
+    /// PMP Address CSR Start Address
+    pub const PMPADDR_START: RvAddr = 0x3B0;
+
+    /// PMP Address CSR End Address
+    pub const PMPADDR_END: RvAddr = 0x3BF;
+
+    /// Debug Control and Status CSR
+    pub const DCSR: RvAddr = 0x7B0;
+
+    /// Debug Program Counter CSR
+    pub const DPC: RvAddr = 0x7B1;
+
     /// Power management const CSR
     pub const MPMC: RvAddr = 0x7C6;
 
@@ -78,6 +100,15 @@ impl Csr {
     /// Cycle High Counter CSR
     pub const MCYCLEH: RvAddr = 0xB80;
 
+    /// First Performance Monitor Counter Low CSR (mhpmcounter3-6)
+    pub const MHPMCOUNTER3: RvAddr = 0xB03;
+
+    /// First Performance Monitor Counter High CSR (mhpmcounter3h-6h)
+    pub const MHPMCOUNTER3H: RvAddr = 0xB83;
+
+    /// First Performance Monitor Event Selector CSR (mhpmevent3-6)
+    pub const MHPMEVENT3: RvAddr = 0x323;
+
     /// Instruction Retired High Counter CSR
     pub const MINSTRETH: RvAddr = 0xB82;
 
@@ -122,6 +153,17 @@ impl CsrFile {
         csrs
     }
 
+    /// Returns a copy of every configuration status register
+    pub(crate) fn snapshot(&self) -> Vec<Csr> {
+        self.csrs.to_vec()
+    }
+
+    /// Overwrite every configuration status register with `csrs`, without
+    /// scheduling any of the side effects of a CSR write
+    pub(crate) fn restore(&mut self, csrs: &[Csr]) {
+        self.csrs.copy_from_slice(csrs);
+    }
+
     /// Reset the CSR file
     fn reset(&mut self) {
         self.csrs[Csr::MISA as usize] = Csr::new(0x4000_1104, 0);
@@ -129,7 +171,7 @@ impl CsrFile {
         self.csrs[Csr::MARCHID as usize] = Csr::new(0x0000_0010, 0);
         self.csrs[Csr::MIMPIID as usize] = Csr::new(0x0000_0004, 0);
         self.csrs[Csr::MHARTID as usize] = Csr::new(0x0000_0000, 0);
-        self.csrs[Csr::MSTATUS as usize] = Csr::new(0x1800_0000, 0x0000_0088);
+        self.csrs[Csr::MSTATUS as usize] = Csr::new(0x1800_0000, 0x0002_1888);
         self.csrs[Csr::MIE as usize] = Csr::new(0x0000_0000, 0x7000_0888);
         self.csrs[Csr::MTVEC as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MCOUNTINHIBIT as usize] = Csr::new(0x0000_0000, 0x0000_007D);
@@ -139,12 +181,78 @@ impl CsrFile {
         self.csrs[Csr::MTVAL as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MIP as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MPMC as usize] = Csr::new(0x0000_0002, 0x0000_0002);
+        self.csrs[Csr::DCSR as usize] = Csr::new(0x4000_0003, 0x0000_8607);
+        self.csrs[Csr::DPC as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFE);
         self.csrs[Csr::MCYCLE as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MCYCLEH as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MINSTRET as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MINSTRETH as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MEIVT as usize] = Csr::new(0x0000_0000, 0xFFFF_FC00);
         self.csrs[Csr::MEIHAP as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFC);
+        for i in 0..HPM_COUNTERS {
+            self.csrs[(Csr::MHPMCOUNTER3 + i) as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
+            self.csrs[(Csr::MHPMCOUNTER3H + i) as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
+            self.csrs[(Csr::MHPMEVENT3 + i) as usize] = Csr::new(0x0000_0000, 0x0000_03FF);
+        }
+        for addr in Csr::PMPCFG_START..=Csr::PMPCFG_END {
+            self.csrs[addr as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
+        }
+        for addr in Csr::PMPADDR_START..=Csr::PMPADDR_END {
+            self.csrs[addr as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
+        }
+    }
+
+    /// Record entry into Debug Mode from `prv` with `cause` in `dcsr`, and
+    /// the address to resume at in `dpc`
+    pub(crate) fn enter_debug_mode(&mut self, cause: RvDebugCause, prv: RvPrivMode, pc: RvAddr) {
+        let dcsr = &mut self.csrs[Csr::DCSR as usize];
+        let mut val = RvDcsr(dcsr.val);
+        val.set_cause(cause.into());
+        val.set_prv(prv.into());
+        dcsr.val = val.0;
+        self.csrs[Csr::DPC as usize].val = pc;
+    }
+
+    /// Returns the configuration of PMP entry `entry`
+    pub(crate) fn pmp_cfg(&self, entry: usize) -> RvPmpCfg {
+        let val = self.csrs[Csr::PMPCFG_START as usize + entry / 4].val;
+        RvPmpCfg((val >> ((entry % 4) * 8)) & 0xFF)
+    }
+
+    /// Returns the address register of PMP entry `entry`
+    pub(crate) fn pmp_addr(&self, entry: usize) -> RvData {
+        self.csrs[Csr::PMPADDR_START as usize + entry].val
+    }
+
+    /// Legalize a write to a pmpcfg register. Locked entries keep their
+    /// value, the reserved bits read as zero and the reserved R=0/W=1
+    /// combination is mapped to no access.
+    fn legalize_pmpcfg(&self, addr: RvAddr, val: RvData) -> RvData {
+        let first = (addr - Csr::PMPCFG_START) as usize * 4;
+        (0..4).fold(0, |acc, i| {
+            let old = self.pmp_cfg(first + i);
+            let mut cfg = RvPmpCfg((val >> (i * 8)) & 0x9F);
+            if old.l() == 1 {
+                cfg = old;
+            } else if cfg.r() == 0 {
+                cfg.set_w(0);
+            }
+            acc | (cfg.0 << (i * 8))
+        })
+    }
+
+    /// Returns true if writes to the address register of PMP entry `entry`
+    /// are ignored: the entry is locked, or the next entry is a locked TOR
+    /// region using it as its base.
+    fn pmp_addr_locked(&self, entry: usize) -> bool {
+        if self.pmp_cfg(entry).l() == 1 {
+            return true;
+        }
+        if entry + 1 < PMP_ENTRIES {
+            let next = self.pmp_cfg(entry + 1);
+            return next.l() == 1 && RvPmpAddrMode::from(next.a()) == RvPmpAddrMode::Tor;
+        }
+        false
     }
 
     /// Read the specified configuration status register
@@ -184,6 +292,33 @@ impl CsrFile {
         const CSR_MAX: usize = CsrFile::CSR_COUNT - 1;
         match addr {
             0..=CSR_MAX => {
+                let val = match addr as RvAddr {
+                    Csr::PMPCFG_START..=Csr::PMPCFG_END => {
+                        self.legalize_pmpcfg(addr as RvAddr, val)
+                    }
+                    Csr::PMPADDR_START..=Csr::PMPADDR_END => {
+                        if self.pmp_addr_locked(addr - Csr::PMPADDR_START as usize) {
+                            return Ok(());
+                        }
+                        val
+                    }
+                    Csr::MSTATUS => {
+                        // Only Machine and User modes are implemented
+                        let mut mstatus = RvMStatus(val);
+                        if RvPrivMode::from(mstatus.mpp()) == RvPrivMode::Invalid {
+                            mstatus.set_mpp(RvMStatus(self.csrs[addr].val).mpp());
+                        }
+                        mstatus.0
+                    }
+                    Csr::DCSR => {
+                        let mut dcsr = RvDcsr(val);
+                        if RvPrivMode::from(dcsr.prv()) == RvPrivMode::Invalid {
+                            dcsr.set_prv(RvDcsr(self.csrs[addr].val).prv());
+                        }
+                        dcsr.0
+                    }
+                    _ => val,
+                };
                 let csr = &mut self.csrs[addr];
                 csr.val = (csr.val & !csr.mask) | (val & csr.mask);
 
@@ -263,10 +398,65 @@ mod tests {
 
         assert_eq!(csrs.read(Csr::MSTATUS).ok(), Some(0x1800_0000));
         assert_eq!(csrs.write(Csr::MSTATUS, u32::MAX).ok(), Some(()));
-        assert_eq!(csrs.read(Csr::MSTATUS).ok(), Some(0x1800_0088));
+        assert_eq!(csrs.read(Csr::MSTATUS).ok(), Some(0x1802_1888));
 
         assert_eq!(csrs.read(Csr::MCOUNTINHIBIT).ok(), Some(0x0000_0000));
         assert_eq!(csrs.write(Csr::MCOUNTINHIBIT, u32::MAX).ok(), Some(()));
         assert_eq!(csrs.read(Csr::MCOUNTINHIBIT).ok(), Some(0x0000_007D));
     }
+
+    #[test]
+    fn test_mstatus_mpp_warl() {
+        let clock = Clock::new();
+        let mut csrs = CsrFile::new(&clock);
+
+        assert_eq!(csrs.write(Csr::MSTATUS, 0x0000_0000).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::MSTATUS).ok(), Some(0x1800_0000));
+        // Supervisor mode isn't implemented, so MPP keeps its value
+        assert_eq!(csrs.write(Csr::MSTATUS, 0x0000_0800).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::MSTATUS).ok(), Some(0x1800_0000));
+        assert_eq!(csrs.write(Csr::MSTATUS, 0x0000_1800).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::MSTATUS).ok(), Some(0x1800_1800));
+    }
+
+    #[test]
+    fn test_dcsr() {
+        let clock = Clock::new();
+        let mut csrs = CsrFile::new(&clock);
+
+        assert_eq!(csrs.read(Csr::DCSR).ok(), Some(0x4000_0003));
+        // xdebugver and cause are read-only
+        assert_eq!(csrs.write(Csr::DCSR, 0xffff_fffc).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::DCSR).ok(), Some(0x4000_8604));
+        assert_eq!(csrs.write(Csr::DCSR, 0xffff_ffff).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::DCSR).ok(), Some(0x4000_8607));
+        // Supervisor mode isn't implemented, so prv keeps its value
+        assert_eq!(csrs.write(Csr::DCSR, 0x0000_0001).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::DCSR).ok(), Some(0x4000_0003));
+        assert_eq!(csrs.write(Csr::DCSR, 0x0000_0000).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::DCSR).ok(), Some(0x4000_0000));
+    }
+
+    #[test]
+    fn test_pmp_csrs() {
+        let clock = Clock::new();
+        let mut csrs = CsrFile::new(&clock);
+
+        // Reserved bits read as zero, and W without R is cleared
+        assert_eq!(csrs.write(Csr::PMPCFG_START, 0x0F02_6E07).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::PMPCFG_START).ok(), Some(0x0F00_0C07));
+
+        // Lock entry 1 as TOR: pmpcfg0 byte 1 and pmpaddr0..=1 can't change
+        assert_eq!(csrs.write(Csr::PMPADDR_START, 0x100).ok(), Some(()));
+        assert_eq!(csrs.write(Csr::PMPADDR_START + 1, 0x200).ok(), Some(()));
+        assert_eq!(csrs.write(Csr::PMPCFG_START, 0x0000_8900).ok(), Some(()));
+        assert_eq!(csrs.write(Csr::PMPCFG_START, 0x0000_0000).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::PMPCFG_START).ok(), Some(0x0000_8900));
+        assert_eq!(csrs.write(Csr::PMPADDR_START, 0x300).ok(), Some(()));
+        assert_eq!(csrs.write(Csr::PMPADDR_START + 1, 0x300).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::PMPADDR_START).ok(), Some(0x100));
+        assert_eq!(csrs.read(Csr::PMPADDR_START + 1).ok(), Some(0x200));
+        assert_eq!(csrs.write(Csr::PMPADDR_START + 2, 0x300).ok(), Some(()));
+        assert_eq!(csrs.read(Csr::PMPADDR_START + 2).ok(), Some(0x300));
+    }
 }
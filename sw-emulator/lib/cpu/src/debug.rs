@@ -0,0 +1,605 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    debug.rs
+
+Abstract:
+
+    File contains Debug Mode and the subset of the RISC-V Debug Module
+    (version 0.13) implemented by VeeR EL2.
+
+--*/
+
+use crate::cpu::{Cpu, ICCM_ORG, ICCM_SIZE};
+use crate::csr_file::Csr;
+use crate::types::{RvDcsr, RvDebugCause, RvPrivMode};
+use crate::xreg_file::XReg;
+use bitfield::bitfield;
+use caliptra_emu_bus::Bus;
+use caliptra_emu_types::{RvAddr, RvData, RvSize};
+
+impl<TBus: Bus> Cpu<TBus> {
+    /// Returns true if the hart is halted in Debug Mode
+    pub fn in_debug_mode(&self) -> bool {
+        self.debug_mode
+    }
+
+    /// Halt the hart in Debug Mode before the next instruction, as the Debug
+    /// Module does when the debugger sets `dmcontrol.haltreq`.
+    pub fn debug_halt(&mut self) {
+        if !self.debug_mode {
+            self.enter_debug_mode(RvDebugCause::HaltReq, self.read_pc());
+        }
+    }
+
+    /// Leave Debug Mode and continue at `dpc` in the privilege mode saved in
+    /// `dcsr.prv`.
+    pub fn debug_resume(&mut self) {
+        if !self.debug_mode {
+            return;
+        }
+        // Cannot panic; dcsr and dpc are valid CSRs
+        let dcsr = RvDcsr(self.read_csr(Csr::DCSR).unwrap());
+        let dpc = self.read_csr(Csr::DPC).unwrap();
+        self.set_priv_mode(RvPrivMode::from(dcsr.prv()));
+        self.write_pc(dpc);
+        self.set_next_pc(dpc);
+        self.debug_mode = false;
+    }
+
+    /// Enter Debug Mode, saving `pc` in `dpc`.
+    pub(crate) fn enter_debug_mode(&mut self, cause: RvDebugCause, pc: RvAddr) {
+        self.csrs.enter_debug_mode(cause, self.priv_mode(), pc);
+        self.set_priv_mode(RvPrivMode::M);
+        self.debug_mode = true;
+    }
+
+    /// Re-enter Debug Mode if the instruction just executed was single
+    /// stepped with `dcsr.step`.
+    pub(crate) fn check_single_step(&mut self) {
+        // Cannot panic; dcsr is a valid CSR
+        if !self.debug_mode && RvDcsr(self.read_csr(Csr::DCSR).unwrap()).step() == 1 {
+            self.enter_debug_mode(RvDebugCause::Step, self.read_pc());
+        }
+    }
+
+    /// Returns true if `ebreak` enters Debug Mode instead of raising a
+    /// breakpoint exception.
+    pub(crate) fn ebreak_enters_debug_mode(&self) -> bool {
+        // Cannot panic; dcsr is a valid CSR
+        self.priv_mode() == RvPrivMode::M
+            && RvDcsr(self.read_csr(Csr::DCSR).unwrap()).ebreakm() == 1
+    }
+}
+
+/// Debug Module register addresses on the Debug Module Interface (DMI)
+pub struct DmReg;
+
+impl DmReg {
+    /// Abstract Data 0
+    pub const DATA0: u32 = 0x04;
+
+    /// Abstract Data 1
+    pub const DATA1: u32 = 0x05;
+
+    /// Debug Module Control
+    pub const DMCONTROL: u32 = 0x10;
+
+    /// Debug Module Status
+    pub const DMSTATUS: u32 = 0x11;
+
+    /// Hart Info
+    pub const HARTINFO: u32 = 0x12;
+
+    /// Abstract Control and Status
+    pub const ABSTRACTCS: u32 = 0x16;
+
+    /// Abstract Command
+    pub const COMMAND: u32 = 0x17;
+
+    /// Abstract Command Autoexec
+    pub const ABSTRACTAUTO: u32 = 0x18;
+
+    /// System Bus Access Control and Status
+    pub const SBCS: u32 = 0x38;
+
+    /// System Bus Address 31:0
+    pub const SBADDRESS0: u32 = 0x39;
+
+    /// System Bus Data 31:0
+    pub const SBDATA0: u32 = 0x3C;
+
+    /// Halt Summary 0
+    pub const HALTSUM0: u32 = 0x40;
+}
+
+bitfield! {
+    /// Debug Module Control Register
+    #[derive(Clone, Copy)]
+    struct DmControl(u32);
+
+    haltreq, _: 31;
+    resumereq, _: 30;
+    ackhavereset, _: 28;
+    ndmreset, set_ndmreset: 1;
+    dmactive, set_dmactive: 0;
+}
+
+bitfield! {
+    /// Abstract Command Register
+    #[derive(Clone, Copy)]
+    struct DmCommand(u32);
+
+    u32, cmdtype, _: 31, 24;
+    u32, size, _: 22, 20;
+    postincrement, _: 19;
+    postexec, _: 18;
+    transfer, _: 17;
+    write, _: 16;
+    u32, regno, _: 15, 0;
+}
+
+bitfield! {
+    /// System Bus Access Control and Status Register
+    #[derive(Clone, Copy, Default)]
+    struct DmSbcs(u32);
+
+    sbbusyerror, set_sbbusyerror: 22;
+    sbreadonaddr, set_sbreadonaddr: 20;
+    u32, sbaccess, set_sbaccess: 19, 17;
+    sbautoincrement, set_sbautoincrement: 16;
+    sbreadondata, set_sbreadondata: 15;
+    u32, sberror, set_sberror: 14, 12;
+}
+
+/// `abstractcs.cmderr` values
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum CmdErr {
+    NotSupported = 2,
+    Exception = 3,
+    HaltResume = 4,
+    Bus = 5,
+}
+
+/// `sbcs.sberror` values
+const SBERROR_BAD_ADDR: u32 = 2;
+const SBERROR_ALIGNMENT: u32 = 3;
+const SBERROR_SIZE: u32 = 4;
+
+/// First abstract register number of the general purpose registers
+const REGNO_GPR: u32 = 0x1000;
+
+fn access_size(encoded: u32) -> Option<RvSize> {
+    match encoded {
+        0 => Some(RvSize::Byte),
+        1 => Some(RvSize::HalfWord),
+        2 => Some(RvSize::Word),
+        _ => None,
+    }
+}
+
+/// The Debug Module reaches memory through VeeR's DMA port, which only
+/// accepts word accesses to the ICCM.
+fn dma_access_allowed(size: RvSize, addr: RvAddr) -> bool {
+    size == RvSize::Word || !(ICCM_ORG..ICCM_ORG + ICCM_SIZE).contains(&(addr as usize))
+}
+
+/// The Debug Module of a single-hart VeeR EL2 core.
+///
+/// Supports halt, resume, single step through `dcsr`, abstract register and
+/// memory access commands, and System Bus Access. There is no program
+/// buffer.
+pub struct DebugModule {
+    control: DmControl,
+    data: [RvData; 2],
+    cmderr: u32,
+    resumeack: bool,
+    havereset: bool,
+    sbcs: DmSbcs,
+    sbaddress: RvAddr,
+    sbdata: RvData,
+}
+
+impl Default for DebugModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugModule {
+    pub fn new() -> Self {
+        Self {
+            control: DmControl(0),
+            data: [0; 2],
+            cmderr: 0,
+            resumeack: false,
+            havereset: true,
+            sbcs: DmSbcs::default(),
+            sbaddress: 0,
+            sbdata: 0,
+        }
+    }
+
+    /// Read the Debug Module register at DMI address `addr`. Registers read
+    /// as zero while `dmcontrol.dmactive` is clear.
+    pub fn dmi_read<TBus: Bus>(&mut self, cpu: &mut Cpu<TBus>, addr: u32) -> u32 {
+        if addr != DmReg::DMCONTROL && !self.control.dmactive() {
+            return 0;
+        }
+        let halted = cpu.in_debug_mode();
+        match addr {
+            DmReg::DATA0 => self.data[0],
+            DmReg::DATA1 => self.data[1],
+            DmReg::DMCONTROL => self.control.0 & 0x3,
+            DmReg::DMSTATUS => {
+                // version 0.13, authenticated
+                let mut val = 2 | (1 << 7);
+                val |= match halted {
+                    true => 0x3 << 8,
+                    false => 0x3 << 10,
+                };
+                if self.resumeack {
+                    val |= 0x3 << 16;
+                }
+                if self.havereset {
+                    val |= 0x3 << 18;
+                }
+                val
+            }
+            DmReg::ABSTRACTCS => (self.cmderr << 8) | self.data.len() as u32,
+            DmReg::SBCS => {
+                // sbversion 1, 32-bit addresses, 8, 16 and 32-bit accesses
+                self.sbcs.0 | (1 << 29) | (32 << 5) | 0x7
+            }
+            DmReg::SBADDRESS0 => self.sbaddress,
+            DmReg::SBDATA0 => {
+                let val = self.sbdata;
+                if self.sbcs.sbreadondata() {
+                    self.sb_read(cpu);
+                }
+                val
+            }
+            DmReg::HALTSUM0 => u32::from(halted),
+            // No memory-mapped data registers and no autoexec
+            DmReg::HARTINFO | DmReg::ABSTRACTAUTO => 0,
+            _ => 0,
+        }
+    }
+
+    /// Write `val` to the Debug Module register at DMI address `addr`.
+    pub fn dmi_write<TBus: Bus>(&mut self, cpu: &mut Cpu<TBus>, addr: u32, val: u32) {
+        if addr != DmReg::DMCONTROL && !self.control.dmactive() {
+            return;
+        }
+        match addr {
+            DmReg::DATA0 => self.data[0] = val,
+            DmReg::DATA1 => self.data[1] = val,
+            DmReg::DMCONTROL => self.write_control(cpu, DmControl(val)),
+            DmReg::ABSTRACTCS => self.cmderr &= !((val >> 8) & 0x7),
+            DmReg::COMMAND => {
+                // Commands are ignored until the previous error is cleared
+                if self.cmderr == 0 {
+                    if let Err(err) = self.execute(cpu, DmCommand(val)) {
+                        self.cmderr = err as u32;
+                    }
+                }
+            }
+            DmReg::SBCS => {
+                let new = DmSbcs(val);
+                if new.sbbusyerror() {
+                    self.sbcs.set_sbbusyerror(false);
+                }
+                self.sbcs.set_sberror(self.sbcs.sberror() & !new.sberror());
+                self.sbcs.set_sbreadonaddr(new.sbreadonaddr());
+                self.sbcs.set_sbaccess(new.sbaccess());
+                self.sbcs.set_sbautoincrement(new.sbautoincrement());
+                self.sbcs.set_sbreadondata(new.sbreadondata());
+            }
+            DmReg::SBADDRESS0 => {
+                self.sbaddress = val;
+                if self.sbcs.sbreadonaddr() {
+                    self.sb_read(cpu);
+                }
+            }
+            DmReg::SBDATA0 => {
+                self.sbdata = val;
+                self.sb_write(cpu);
+            }
+            _ => {}
+        }
+    }
+
+    fn write_control<TBus: Bus>(&mut self, cpu: &mut Cpu<TBus>, val: DmControl) {
+        if !val.dmactive() {
+            *self = Self {
+                havereset: self.havereset,
+                ..Self::new()
+            };
+            return;
+        }
+        self.control.set_dmactive(true);
+        if val.ackhavereset() {
+            self.havereset = false;
+        }
+        if val.ndmreset() && !self.control.ndmreset() {
+            cpu.warm_reset();
+            self.havereset = true;
+        }
+        self.control.set_ndmreset(val.ndmreset());
+        if val.haltreq() {
+            cpu.debug_halt();
+        } else if val.resumereq() {
+            self.resumeack = false;
+            if cpu.in_debug_mode() {
+                cpu.debug_resume();
+                self.resumeack = true;
+            }
+        }
+    }
+
+    fn execute<TBus: Bus>(&mut self, cpu: &mut Cpu<TBus>, cmd: DmCommand) -> Result<(), CmdErr> {
+        match cmd.cmdtype() {
+            0 => self.access_register(cpu, cmd),
+            2 => self.access_memory(cpu, cmd),
+            _ => Err(CmdErr::NotSupported),
+        }
+    }
+
+    fn access_register<TBus: Bus>(
+        &mut self,
+        cpu: &mut Cpu<TBus>,
+        cmd: DmCommand,
+    ) -> Result<(), CmdErr> {
+        if !cpu.in_debug_mode() {
+            return Err(CmdErr::HaltResume);
+        }
+        if cmd.postexec() || cmd.postincrement() {
+            return Err(CmdErr::NotSupported);
+        }
+        if !cmd.transfer() {
+            return Ok(());
+        }
+        if cmd.size() != 2 {
+            return Err(CmdErr::NotSupported);
+        }
+        let regno = cmd.regno();
+        let result = match (regno, cmd.write()) {
+            (0..=0xfff, true) => cpu.write_csr(regno, self.data[0]),
+            (0..=0xfff, false) => cpu.read_csr(regno).map(|val| self.data[0] = val),
+            (REGNO_GPR..=0x101f, true) => {
+                cpu.write_xreg(XReg::from(regno - REGNO_GPR), self.data[0])
+            }
+            (REGNO_GPR..=0x101f, false) => cpu
+                .read_xreg(XReg::from(regno - REGNO_GPR))
+                .map(|val| self.data[0] = val),
+            _ => return Err(CmdErr::NotSupported),
+        };
+        result.map_err(|_| CmdErr::Exception)
+    }
+
+    fn access_memory<TBus: Bus>(
+        &mut self,
+        cpu: &mut Cpu<TBus>,
+        cmd: DmCommand,
+    ) -> Result<(), CmdErr> {
+        if !cpu.in_debug_mode() {
+            return Err(CmdErr::HaltResume);
+        }
+        let size = access_size(cmd.size()).ok_or(CmdErr::NotSupported)?;
+        let addr = self.data[1];
+        if !dma_access_allowed(size, addr) {
+            return Err(CmdErr::Bus);
+        }
+        if cmd.write() {
+            cpu.bus
+                .write(size, addr, self.data[0])
+                .map_err(|_| CmdErr::Bus)?;
+        } else {
+            self.data[0] = cpu.bus.read(size, addr).map_err(|_| CmdErr::Bus)?;
+        }
+        if cmd.postincrement() {
+            self.data[1] = addr.wrapping_add(usize::from(size) as u32);
+        }
+        Ok(())
+    }
+
+    /// Check the pending system bus access, returning its size
+    fn sb_access_size(&mut self) -> Option<RvSize> {
+        if self.sbcs.sberror() != 0 {
+            return None;
+        }
+        let Some(size) = access_size(self.sbcs.sbaccess()) else {
+            self.sbcs.set_sberror(SBERROR_SIZE);
+            return None;
+        };
+        if self.sbaddress % usize::from(size) as u32 != 0 {
+            self.sbcs.set_sberror(SBERROR_ALIGNMENT);
+            return None;
+        }
+        if !dma_access_allowed(size, self.sbaddress) {
+            self.sbcs.set_sberror(SBERROR_BAD_ADDR);
+            return None;
+        }
+        Some(size)
+    }
+
+    fn sb_increment(&mut self, size: RvSize) {
+        if self.sbcs.sbautoincrement() {
+            self.sbaddress = self.sbaddress.wrapping_add(usize::from(size) as u32);
+        }
+    }
+
+    fn sb_read<TBus: Bus>(&mut self, cpu: &mut Cpu<TBus>) {
+        let Some(size) = self.sb_access_size() else {
+            return;
+        };
+        match cpu.bus.read(size, self.sbaddress) {
+            Ok(val) => {
+                self.sbdata = val;
+                self.sb_increment(size);
+            }
+            Err(_) => self.sbcs.set_sberror(SBERROR_BAD_ADDR),
+        }
+    }
+
+    fn sb_write<TBus: Bus>(&mut self, cpu: &mut Cpu<TBus>) {
+        let Some(size) = self.sb_access_size() else {
+            return;
+        };
+        match cpu.bus.write(size, self.sbaddress, self.sbdata) {
+            Ok(()) => self.sb_increment(size),
+            Err(_) => self.sbcs.set_sberror(SBERROR_BAD_ADDR),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::StepAction;
+    use crate::instr::test_encoder::tests::{addi, ebreak, sw};
+    use crate::{isa_test_cpu, text};
+
+    const HALTREQ: u32 = 1 << 31;
+    const RESUMEREQ: u32 = 1 << 30;
+    const DMACTIVE: u32 = 1;
+
+    fn access_register(regno: u32, write: bool) -> u32 {
+        (2 << 20) | (1 << 17) | (u32::from(write) << 16) | regno
+    }
+
+    fn access_memory(size: u32, write: bool) -> u32 {
+        (2 << 24) | (size << 20) | (1 << 19) | (u32::from(write) << 16)
+    }
+
+    #[test]
+    fn test_halt_resume_and_registers() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![
+            addi(XReg::X1, XReg::X1, 1);
+            addi(XReg::X1, XReg::X1, 1);
+            addi(XReg::X1, XReg::X1, 1);
+        ], 0x1000 => vec![0; 4]);
+        let mut dm = DebugModule::new();
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DMSTATUS), 0);
+        dm.dmi_write(&mut cpu, DmReg::DMCONTROL, DMACTIVE);
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DMSTATUS) & 0xf00, 0xc00);
+
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        dm.dmi_write(&mut cpu, DmReg::DMCONTROL, DMACTIVE | HALTREQ);
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DMSTATUS) & 0xf00, 0x300);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.read_pc(), 4);
+
+        // Read x1 and dpc, then change x1
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_register(0x1001, false));
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DATA0), 1);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_register(Csr::DPC, false));
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DATA0), 4);
+        assert_eq!(
+            cpu.read_csr(Csr::DCSR).map(|v| RvDcsr(v).cause()),
+            Ok(RvDebugCause::HaltReq.into())
+        );
+        dm.dmi_write(&mut cpu, DmReg::DATA0, 0x10);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_register(0x1001, true));
+        assert_eq!((dm.dmi_read(&mut cpu, DmReg::ABSTRACTCS) >> 8) & 7, 0);
+
+        // Single step, then resume
+        dm.dmi_write(&mut cpu, DmReg::DATA0, 0x4000_0007);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_register(Csr::DCSR, true));
+        dm.dmi_write(&mut cpu, DmReg::DMCONTROL, DMACTIVE | RESUMEREQ);
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DMSTATUS) & 0x30000, 0x30000);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert!(cpu.in_debug_mode());
+        assert_eq!(cpu.read_xreg(XReg::X1), Ok(0x11));
+        assert_eq!(cpu.read_csr(Csr::DPC), Ok(8));
+
+        // Registers can't be accessed while running
+        dm.dmi_write(&mut cpu, DmReg::DATA0, 0x4000_0003);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_register(Csr::DCSR, true));
+        dm.dmi_write(&mut cpu, DmReg::DMCONTROL, DMACTIVE | RESUMEREQ);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_register(0x1001, false));
+        assert_eq!((dm.dmi_read(&mut cpu, DmReg::ABSTRACTCS) >> 8) & 7, 4);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.read_xreg(XReg::X1), Ok(0x12));
+    }
+
+    #[test]
+    fn test_memory_access() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![
+            sw(XReg::X0, 0, XReg::X0);
+        ], 0x1000 => vec![0; 8]);
+        let mut dm = DebugModule::new();
+        dm.dmi_write(&mut cpu, DmReg::DMCONTROL, DMACTIVE | HALTREQ);
+
+        // Abstract memory access with post-increment
+        dm.dmi_write(&mut cpu, DmReg::DATA1, 0x1000);
+        dm.dmi_write(&mut cpu, DmReg::DATA0, 0x1234_5678);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_memory(2, true));
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DATA1), 0x1004);
+        dm.dmi_write(&mut cpu, DmReg::DATA1, 0x1001);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_memory(0, false));
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DATA0), 0x56);
+
+        // Unmapped addresses report a bus error until cleared
+        dm.dmi_write(&mut cpu, DmReg::DATA1, 0x2000);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_memory(2, false));
+        assert_eq!((dm.dmi_read(&mut cpu, DmReg::ABSTRACTCS) >> 8) & 7, 5);
+        dm.dmi_write(&mut cpu, DmReg::ABSTRACTCS, 7 << 8);
+        assert_eq!((dm.dmi_read(&mut cpu, DmReg::ABSTRACTCS) >> 8) & 7, 0);
+
+        // System bus access with auto-increment and read-on-data
+        dm.dmi_write(&mut cpu, DmReg::SBCS, (2 << 17) | (1 << 16));
+        dm.dmi_write(&mut cpu, DmReg::SBADDRESS0, 0x1004);
+        dm.dmi_write(&mut cpu, DmReg::SBDATA0, 0xaabb_ccdd);
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::SBADDRESS0), 0x1008);
+        dm.dmi_write(
+            &mut cpu,
+            DmReg::SBCS,
+            (1 << 20) | (2 << 17) | (1 << 16) | (1 << 15),
+        );
+        dm.dmi_write(&mut cpu, DmReg::SBADDRESS0, 0x1000);
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::SBDATA0), 0x1234_5678);
+        assert_eq!((dm.dmi_read(&mut cpu, DmReg::SBCS) >> 12) & 7, 0);
+        // Reading ahead past the end of the RAM fails
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::SBDATA0), 0xaabb_ccdd);
+        assert_eq!((dm.dmi_read(&mut cpu, DmReg::SBCS) >> 12) & 7, 2);
+    }
+
+    #[test]
+    fn test_iccm_word_access_only() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![
+            sw(XReg::X0, 0, XReg::X0);
+        ], 0x4000_0000 => vec![0x11, 0x22, 0x33, 0x44]);
+        let mut dm = DebugModule::new();
+        dm.dmi_write(&mut cpu, DmReg::DMCONTROL, DMACTIVE | HALTREQ);
+
+        dm.dmi_write(&mut cpu, DmReg::DATA1, 0x4000_0000);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_memory(2, false));
+        assert_eq!(dm.dmi_read(&mut cpu, DmReg::DATA0), 0x4433_2211);
+        dm.dmi_write(&mut cpu, DmReg::COMMAND, access_memory(0, false));
+        assert_eq!((dm.dmi_read(&mut cpu, DmReg::ABSTRACTCS) >> 8) & 7, 5);
+
+        dm.dmi_write(&mut cpu, DmReg::SBCS, (1 << 20) | (1 << 17));
+        dm.dmi_write(&mut cpu, DmReg::SBADDRESS0, 0x4000_0002);
+        assert_eq!((dm.dmi_read(&mut cpu, DmReg::SBCS) >> 12) & 7, 2);
+    }
+
+    #[test]
+    fn test_ebreakm() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![
+            ebreak();
+        ], 0x1000 => vec![0; 4]);
+        cpu.write_csr(Csr::DCSR, 0x0000_8003).unwrap();
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert!(cpu.in_debug_mode());
+        assert_eq!(cpu.read_pc(), 0);
+        assert_eq!(cpu.read_csr(Csr::DPC), Ok(0));
+        assert_eq!(
+            cpu.read_csr(Csr::DCSR).map(|v| RvDcsr(v).cause()),
+            Ok(RvDebugCause::Ebreak.into())
+        );
+    }
+}
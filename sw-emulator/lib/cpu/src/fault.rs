@@ -0,0 +1,305 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    fault.rs
+
+Abstract:
+
+    File contains deterministic fault injection for glitch and CFI testing.
+
+--*/
+
+use std::ops::Range;
+
+use crate::cpu::{Cpu, StepAction};
+use crate::instr::Instr;
+use crate::xreg_file::XReg;
+use caliptra_emu_bus::Bus;
+use caliptra_emu_types::{RvAddr, RvData, RvSize};
+
+/// When a [`Fault`] fires.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FaultTrigger {
+    /// Before the first instruction executed at or after this clock cycle.
+    Cycle(u64),
+
+    /// Before the `occurrence`th (counting from 0) execution of the
+    /// instruction at `pc`.
+    Pc { pc: RvAddr, occurrence: u32 },
+}
+
+/// The effect of a [`Fault`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FaultKind {
+    /// Advance the PC past the instruction without executing it.
+    SkipInstr,
+
+    /// Invert `bit` of a general purpose register.
+    FlipXreg { reg: XReg, bit: u32 },
+
+    /// Invert `bit` of a CSR. The write is subject to the CSR's write mask.
+    FlipCsr { csr: RvAddr, bit: u32 },
+
+    /// Invert `bit` of the aligned word at `addr`. Has no effect on
+    /// read-only memory such as the ROM.
+    FlipMem { addr: RvAddr, bit: u32 },
+
+    /// XOR `mask` into the value returned by the next load.
+    CorruptRead { mask: RvData },
+}
+
+/// A single fault to inject.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fault {
+    pub trigger: FaultTrigger,
+    pub kind: FaultKind,
+}
+
+/// Where and when an injected fault fired.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FaultHit {
+    pub cycle: u64,
+    pub pc: RvAddr,
+}
+
+#[derive(Default)]
+pub(crate) struct FaultInjector {
+    fault: Option<Fault>,
+    pc_hits: u32,
+    hit: Option<FaultHit>,
+    read_mask: Option<RvData>,
+    trace_range: Option<Range<RvAddr>>,
+    traced_pcs: Vec<RvAddr>,
+}
+
+impl FaultInjector {
+    /// Returns the fault to apply before executing the instruction at `pc`.
+    fn check(&mut self, pc: RvAddr, cycle: u64) -> Option<FaultKind> {
+        if let Some(range) = &self.trace_range {
+            if range.contains(&pc) {
+                self.traced_pcs.push(pc);
+            }
+        }
+        let fault = self.fault?;
+        let fire = match fault.trigger {
+            FaultTrigger::Cycle(at) => cycle >= at,
+            FaultTrigger::Pc {
+                pc: fault_pc,
+                occurrence,
+            } => {
+                if pc != fault_pc {
+                    return None;
+                }
+                self.pc_hits += 1;
+                self.pc_hits - 1 == occurrence
+            }
+        };
+        if !fire {
+            return None;
+        }
+        self.fault = None;
+        self.hit = Some(FaultHit { cycle, pc });
+        Some(fault.kind)
+    }
+
+    /// Returns the mask to apply to a load, if a read corruption is pending.
+    pub(crate) fn take_read_mask(&mut self) -> Option<RvData> {
+        self.read_mask.take()
+    }
+}
+
+impl<TBus: Bus> Cpu<TBus> {
+    /// Arm `fault`, replacing any fault that has not fired yet. Faults are
+    /// not part of a [`crate::CpuSnapshot`].
+    ///
+    /// # Panics
+    ///
+    /// If the fault flips a bit above 31.
+    pub fn inject_fault(&mut self, fault: Fault) {
+        if let FaultKind::FlipXreg { bit, .. }
+        | FaultKind::FlipCsr { bit, .. }
+        | FaultKind::FlipMem { bit, .. } = fault.kind
+        {
+            assert!(bit < 32, "bit {bit} out of range");
+        }
+        let injector = &mut self.fault_injector;
+        injector.fault = Some(fault);
+        injector.pc_hits = 0;
+        injector.hit = None;
+        injector.read_mask = None;
+    }
+
+    /// Disarm any pending fault.
+    pub fn clear_fault(&mut self) {
+        self.fault_injector.fault = None;
+        self.fault_injector.read_mask = None;
+    }
+
+    /// Returns where the last injected fault fired, or None if it hasn't.
+    pub fn fault_hit(&self) -> Option<FaultHit> {
+        self.fault_injector.hit
+    }
+
+    /// Record the PC of every instruction executed inside `range`, or stop
+    /// recording if `range` is None. Used to choose fault locations.
+    pub fn trace_pcs(&mut self, range: Option<Range<RvAddr>>) {
+        self.fault_injector.trace_range = range;
+        self.fault_injector.traced_pcs.clear();
+    }
+
+    /// Returns the PCs recorded since the last call, in execution order.
+    pub fn take_traced_pcs(&mut self) -> Vec<RvAddr> {
+        std::mem::take(&mut self.fault_injector.traced_pcs)
+    }
+
+    /// Apply an armed fault that triggers before the instruction at the
+    /// current PC. Returns Some if the instruction must not be executed.
+    pub(crate) fn apply_fault(&mut self) -> Option<StepAction> {
+        let pc = self.read_pc();
+        let kind = self.fault_injector.check(pc, self.clock.now())?;
+        match kind {
+            FaultKind::SkipInstr => {
+                let len = match self.fetch() {
+                    Ok(Instr::Compressed(_)) => 2,
+                    Ok(Instr::General(_)) => 4,
+                    Err(exception) => return Some(self.handle_exception(exception)),
+                };
+                self.set_next_pc(pc.wrapping_add(len));
+                self.write_pc(self.next_pc());
+                return Some(StepAction::Continue);
+            }
+            FaultKind::FlipXreg { reg, bit } => {
+                if let Ok(val) = self.read_xreg(reg) {
+                    let _ = self.write_xreg(reg, val ^ (1 << bit));
+                }
+            }
+            FaultKind::FlipCsr { csr, bit } => {
+                if let Ok(val) = self.read_csr(csr) {
+                    let _ = self.write_csr(csr, val ^ (1 << bit));
+                }
+            }
+            FaultKind::FlipMem { addr, bit } => {
+                let addr = addr & !3;
+                if let Ok(val) = self.bus.read(RvSize::Word, addr) {
+                    let _ = self.bus.write(RvSize::Word, addr, val ^ (1 << bit));
+                }
+            }
+            FaultKind::CorruptRead { mask } => self.fault_injector.read_mask = Some(mask),
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use caliptra_emu_bus::{Clock, DynamicBus, Ram, Rom};
+
+    // addi x1, x1, 1
+    const ADDI_X1_1: u32 = 0x0010_8093;
+    // lw x2, 0(x3)
+    const LW_X2_X3: u32 = 0x0001_a103;
+
+    fn cpu(program: &[u32]) -> Cpu<DynamicBus> {
+        let mut bus = DynamicBus::new();
+        let rom = Rom::new(program.iter().flat_map(|i| i.to_le_bytes()).collect());
+        bus.attach_dev("ROM", 0..=0xff, Box::new(rom)).unwrap();
+        bus.attach_dev("RAM", 0x1000..=0x10ff, Box::new(Ram::new(vec![0; 0x100])))
+            .unwrap();
+        Cpu::new(bus, Clock::new())
+    }
+
+    fn run(cpu: &mut Cpu<DynamicBus>, steps: usize) {
+        for _ in 0..steps {
+            assert_eq!(cpu.step(None), StepAction::Continue);
+        }
+    }
+
+    #[test]
+    fn test_skip_instr() {
+        let mut cpu = cpu(&[ADDI_X1_1; 4]);
+        cpu.inject_fault(Fault {
+            trigger: FaultTrigger::Pc {
+                pc: 4,
+                occurrence: 0,
+            },
+            kind: FaultKind::SkipInstr,
+        });
+        run(&mut cpu, 3);
+        assert_eq!(cpu.read_pc(), 12);
+        assert_eq!(cpu.read_xreg(XReg::X1), Ok(2));
+        assert_eq!(cpu.fault_hit(), Some(FaultHit { cycle: 2, pc: 4 }));
+    }
+
+    #[test]
+    fn test_flip_xreg_at_cycle() {
+        let mut cpu = cpu(&[ADDI_X1_1; 4]);
+        cpu.inject_fault(Fault {
+            trigger: FaultTrigger::Cycle(3),
+            kind: FaultKind::FlipXreg {
+                reg: XReg::X1,
+                bit: 4,
+            },
+        });
+        run(&mut cpu, 4);
+        assert_eq!(cpu.read_xreg(XReg::X1), Ok(0x14));
+        assert_eq!(cpu.fault_hit(), Some(FaultHit { cycle: 3, pc: 8 }));
+    }
+
+    #[test]
+    fn test_flip_mem_and_corrupt_read() {
+        let mut cpu = cpu(&[LW_X2_X3, LW_X2_X3]);
+        cpu.write_xreg(XReg::X3, 0x1004).unwrap();
+        cpu.inject_fault(Fault {
+            trigger: FaultTrigger::Pc {
+                pc: 0,
+                occurrence: 0,
+            },
+            kind: FaultKind::FlipMem {
+                addr: 0x1006,
+                bit: 31,
+            },
+        });
+        run(&mut cpu, 1);
+        assert_eq!(cpu.read_xreg(XReg::X2), Ok(0x8000_0000));
+
+        cpu.inject_fault(Fault {
+            trigger: FaultTrigger::Pc {
+                pc: 4,
+                occurrence: 0,
+            },
+            kind: FaultKind::CorruptRead { mask: 0xff },
+        });
+        run(&mut cpu, 1);
+        assert_eq!(cpu.read_xreg(XReg::X2), Ok(0x8000_00ff));
+        // The memory itself is unchanged
+        assert_eq!(cpu.read_bus(RvSize::Word, 0x1004), Ok(0x8000_0000));
+    }
+
+    #[test]
+    fn test_pc_occurrence_and_trace() {
+        // Loop of addi x1, x1, 1 followed by jal x0, -4
+        let mut cpu = cpu(&[ADDI_X1_1, 0xffdf_f06f]);
+        cpu.trace_pcs(Some(4..8));
+        cpu.inject_fault(Fault {
+            trigger: FaultTrigger::Pc {
+                pc: 4,
+                occurrence: 2,
+            },
+            kind: FaultKind::FlipXreg {
+                reg: XReg::X1,
+                bit: 1,
+            },
+        });
+        run(&mut cpu, 5);
+        assert_eq!(cpu.read_xreg(XReg::X1), Ok(3));
+        assert_eq!(cpu.fault_hit(), None);
+        run(&mut cpu, 1);
+        assert_eq!(cpu.read_xreg(XReg::X1), Ok(1));
+        assert_eq!(cpu.fault_hit(), Some(FaultHit { cycle: 6, pc: 4 }));
+        assert_eq!(cpu.take_traced_pcs(), vec![4, 4, 4]);
+    }
+}
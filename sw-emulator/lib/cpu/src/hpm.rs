@@ -0,0 +1,284 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    hpm.rs
+
+Abstract:
+
+    File contains the VeeR EL2 hardware performance monitor counters.
+
+--*/
+
+use crate::cpu::{Cpu, DCCM_ORG, DCCM_SIZE, ICCM_ORG, ICCM_SIZE, ROM_ORG, ROM_SIZE};
+use crate::csr_file::{Csr, CsrFile};
+use crate::types::{
+    RvInstr32B, RvInstr32I, RvInstr32OpFunct7, RvInstr32Opcode, RvInstr32R, RvInstr32SystemImm,
+};
+use caliptra_emu_bus::Bus;
+use caliptra_emu_types::{emu_enum, RvAddr, RvExceptionCause, RvSize};
+
+/// Number of programmable counters (mhpmcounter3-6)
+pub(crate) const HPM_COUNTERS: RvAddr = 4;
+
+emu_enum! {
+    /// VeeR EL2 performance monitor events, as written to `mhpmevent3-6`.
+    /// Events the emulator doesn't model never count.
+    #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+    pub HpmEvent;
+    u32;
+    {
+        /// Cycles clocks active
+        CyclesClocksActive = 1,
+
+        /// Instructions committed
+        InstrCommitted = 4,
+
+        /// 16-bit instructions committed
+        Instr16Committed = 5,
+
+        /// 32-bit instructions committed
+        Instr32Committed = 6,
+
+        /// Multiplies committed
+        MulsCommitted = 9,
+
+        /// Divides committed
+        DivsCommitted = 10,
+
+        /// Loads committed
+        LoadsCommitted = 11,
+
+        /// Stores committed
+        StoresCommitted = 12,
+
+        /// Misaligned loads
+        MisalignedLoads = 13,
+
+        /// Misaligned stores
+        MisalignedStores = 14,
+
+        /// ALU operations committed
+        AlusCommitted = 15,
+
+        /// Ebreak
+        Ebreak = 19,
+
+        /// Ecall
+        Ecall = 20,
+
+        /// Fence
+        Fence = 21,
+
+        /// Mret
+        Mret = 23,
+
+        /// Branches committed
+        BranchesCommitted = 24,
+
+        /// Branches taken
+        BranchesTaken = 26,
+
+        /// Exceptions taken
+        ExceptionsTaken = 37,
+
+        /// External interrupts taken
+        ExtInterruptsTaken = 39,
+
+        /// D-bus load/store transactions
+        DbusTransactions = 43,
+
+        /// D-bus stalls. Approximated as one cycle for each D-bus load, as
+        /// the core waits for the response while stores are posted.
+        DbusStalls = 48,
+    };
+    Invalid
+}
+
+/// Returns true if loads and stores at `addr` go over the D-bus rather than
+/// to a closely coupled memory or the ROM.
+fn is_dbus(addr: RvAddr) -> bool {
+    let addr = addr as usize;
+    let in_range = |org: usize, size: usize| (org..org + size).contains(&addr);
+    !(in_range(ROM_ORG, ROM_SIZE)
+        || in_range(ICCM_ORG, ICCM_SIZE)
+        || in_range(DCCM_ORG as usize, DCCM_SIZE as usize))
+}
+
+impl CsrFile {
+    /// Increment the 64-bit counter split across `lo` and `hi`
+    fn increment_counter(&mut self, lo: RvAddr, hi: RvAddr) {
+        // Cannot panic; the counters are valid CSRs
+        let val = self.read(lo).unwrap().wrapping_add(1);
+        self.write(lo, val).unwrap();
+        if val == 0 {
+            let val = self.read(hi).unwrap().wrapping_add(1);
+            self.write(hi, val).unwrap();
+        }
+    }
+
+    /// Returns true if bit `bit` of `mcountinhibit` is set
+    fn counter_inhibited(&self, bit: RvAddr) -> bool {
+        // Cannot panic; mcountinhibit is a valid CSR
+        self.read(Csr::MCOUNTINHIBIT).unwrap() & (1 << bit) != 0
+    }
+
+    /// Count one occurrence of `event` in every counter selecting it
+    pub(crate) fn hpm_count(&mut self, event: HpmEvent) {
+        for i in 0..HPM_COUNTERS {
+            // Cannot panic; mhpmevent is a valid CSR
+            if self.read(Csr::MHPMEVENT3 + i).unwrap() == u32::from(event)
+                && !self.counter_inhibited(i + 3)
+            {
+                self.increment_counter(Csr::MHPMCOUNTER3 + i, Csr::MHPMCOUNTER3H + i);
+            }
+        }
+    }
+
+    /// Advance `mcycle` by one active cycle
+    pub(crate) fn hpm_tick(&mut self) {
+        if !self.counter_inhibited(0) {
+            self.increment_counter(Csr::MCYCLE, Csr::MCYCLEH);
+        }
+        self.hpm_count(HpmEvent::CyclesClocksActive);
+    }
+
+    /// Count a committed instruction in `minstret`
+    pub(crate) fn hpm_retire(&mut self, compressed: bool) {
+        if !self.counter_inhibited(2) {
+            self.increment_counter(Csr::MINSTRET, Csr::MINSTRETH);
+        }
+        self.hpm_count(HpmEvent::InstrCommitted);
+        self.hpm_count(match compressed {
+            true => HpmEvent::Instr16Committed,
+            false => HpmEvent::Instr32Committed,
+        });
+    }
+
+    /// Count a load or store of `size` bytes at `addr`
+    pub(crate) fn hpm_count_access(&mut self, addr: RvAddr, size: RvSize, store: bool) {
+        let misaligned = addr % usize::from(size) as RvAddr != 0;
+        let (committed, misaligned_event) = match store {
+            true => (HpmEvent::StoresCommitted, HpmEvent::MisalignedStores),
+            false => (HpmEvent::LoadsCommitted, HpmEvent::MisalignedLoads),
+        };
+        self.hpm_count(committed);
+        if misaligned {
+            self.hpm_count(misaligned_event);
+        }
+        if is_dbus(addr) {
+            self.hpm_count(HpmEvent::DbusTransactions);
+            if !store {
+                self.hpm_count(HpmEvent::DbusStalls);
+            }
+        }
+    }
+
+    /// Count the exception or environment call with `cause`
+    pub(crate) fn hpm_count_exception(&mut self, cause: RvExceptionCause) {
+        match cause {
+            RvExceptionCause::EnvironmentCall | RvExceptionCause::EnvironmentCallUser => {
+                self.hpm_count(HpmEvent::Ecall)
+            }
+            RvExceptionCause::Breakpoint => self.hpm_count(HpmEvent::Ebreak),
+            _ => {}
+        }
+        self.hpm_count(HpmEvent::ExceptionsTaken);
+    }
+}
+
+impl<TBus: Bus> Cpu<TBus> {
+    /// Count the events of the committed 32-bit (or decompressed)
+    /// instruction `instr`. Loads and stores are counted on the bus.
+    pub(crate) fn hpm_count_instr(&mut self, instr: u32) {
+        let event = match RvInstr32I(instr).opcode() {
+            RvInstr32Opcode::Op => {
+                let instr = RvInstr32R(instr);
+                match RvInstr32OpFunct7::from(instr.funct7()) {
+                    RvInstr32OpFunct7::Mul if instr.funct3() < 4 => HpmEvent::MulsCommitted,
+                    RvInstr32OpFunct7::Mul => HpmEvent::DivsCommitted,
+                    _ => HpmEvent::AlusCommitted,
+                }
+            }
+            RvInstr32Opcode::OpImm | RvInstr32Opcode::Lui | RvInstr32Opcode::Auipc => {
+                HpmEvent::AlusCommitted
+            }
+            RvInstr32Opcode::Branch => {
+                let target = self.read_pc().wrapping_add(RvInstr32B(instr).imm());
+                if self.next_pc() == target {
+                    self.csrs.hpm_count(HpmEvent::BranchesTaken);
+                }
+                HpmEvent::BranchesCommitted
+            }
+            RvInstr32Opcode::Fence => HpmEvent::Fence,
+            RvInstr32Opcode::System
+                if RvInstr32I(instr).funct3() == 0
+                    && RvInstr32SystemImm::from(RvInstr32I(instr).uimm())
+                        == RvInstr32SystemImm::Mret =>
+            {
+                HpmEvent::Mret
+            }
+            _ => return,
+        };
+        self.csrs.hpm_count(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::StepAction;
+    use crate::instr::test_encoder::tests::{addi, beq, lw, mul, sw};
+    use crate::xreg_file::XReg;
+    use crate::{isa_test_cpu, text};
+
+    #[test]
+    fn test_counters() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![
+            lw(XReg::X2, 0, XReg::X1);
+            sw(XReg::X2, 4, XReg::X1);
+            mul(XReg::X3, XReg::X1, XReg::X1);
+            beq(XReg::X0, XReg::X0, 8);
+            addi(XReg::X0, XReg::X0, 0);
+            addi(XReg::X0, XReg::X0, 0);
+        ], 0x1000 => vec![0; 8]);
+        cpu.write_xreg(XReg::X1, 0x1000).unwrap();
+        cpu.write_csr(Csr::MHPMEVENT3, HpmEvent::LoadsCommitted.into())
+            .unwrap();
+        cpu.write_csr(Csr::MHPMEVENT3 + 1, HpmEvent::StoresCommitted.into())
+            .unwrap();
+        cpu.write_csr(Csr::MHPMEVENT3 + 2, HpmEvent::BranchesTaken.into())
+            .unwrap();
+        cpu.write_csr(Csr::MHPMEVENT3 + 3, HpmEvent::MulsCommitted.into())
+            .unwrap();
+        // Inhibit minstret
+        cpu.write_csr(Csr::MCOUNTINHIBIT, 0x4).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(cpu.step(None), StepAction::Continue);
+        }
+        assert_eq!(cpu.read_pc(), 0x18);
+        assert_eq!(cpu.read_csr(Csr::MCYCLE), Ok(5));
+        assert_eq!(cpu.read_csr(Csr::MINSTRET), Ok(0));
+        for i in 0..HPM_COUNTERS {
+            assert_eq!(cpu.read_csr(Csr::MHPMCOUNTER3 + i), Ok(1));
+        }
+    }
+
+    #[test]
+    fn test_counter_rollover() {
+        let clock = caliptra_emu_bus::Clock::new();
+        let mut csrs = CsrFile::new(&clock);
+        csrs.write(Csr::MHPMEVENT3, HpmEvent::Ecall.into()).unwrap();
+        csrs.write(Csr::MHPMCOUNTER3, u32::MAX).unwrap();
+        csrs.hpm_count_exception(RvExceptionCause::EnvironmentCall);
+        assert_eq!(csrs.read(Csr::MHPMCOUNTER3), Ok(0));
+        assert_eq!(csrs.read(Csr::MHPMCOUNTER3H), Ok(1));
+
+        csrs.write(Csr::MCOUNTINHIBIT, 0x8).unwrap();
+        csrs.hpm_count_exception(RvExceptionCause::EnvironmentCall);
+        assert_eq!(csrs.read(Csr::MHPMCOUNTER3), Ok(0));
+    }
+}
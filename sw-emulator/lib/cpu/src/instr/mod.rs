@@ -27,7 +27,7 @@ mod op;
 mod op_imm;
 mod store;
 mod system;
-mod test_encoder;
+pub(crate) mod test_encoder;
 mod test_macros;
 
 use crate::cpu::{Cpu, InstrTracer, StepAction};
@@ -73,6 +73,7 @@ impl<TBus: Bus> Cpu<TBus> {
                 self.exec_instr32(instr, instr_tracer)?;
             }
         }
+        self.csrs.hpm_retire(matches!(instr, Instr::Compressed(_)));
         self.write_pc(self.next_pc());
 
         self.is_execute_instr = false;
@@ -89,7 +90,7 @@ impl<TBus: Bus> Cpu<TBus> {
     ///
     /// * `RvException` - Exception with cause `RvExceptionCause::InstrAccessFault`
     ///                   or `RvExceptionCause::InstrAddrMisaligned`
-    fn fetch(&mut self) -> Result<Instr, RvException> {
+    pub(crate) fn fetch(&mut self) -> Result<Instr, RvException> {
         let instr = self.read_instr(RvSize::HalfWord, self.read_pc())?;
         match instr & 0b11 {
             0 | 1 | 2 => Ok(Instr::Compressed(instr as u16)),
@@ -148,6 +149,8 @@ impl<TBus: Bus> Cpu<TBus> {
             RvInstr32Opcode::Fence => self.exec_fence_instr(instr)?,
             _ => Err(RvException::illegal_instr(instr))?,
         }
+        self.hpm_count_instr(instr);
+        self.profile_instr(instr);
         Ok(())
     }
 }
@@ -15,10 +15,11 @@ Abstract:
 use crate::cpu::Cpu;
 use crate::csr_file::Csr;
 use crate::types::{
-    RvInstr32I, RvInstr32Opcode, RvInstr32SystemFunct3, RvInstr32SystemImm, RvMStatus,
+    RvDebugCause, RvInstr32I, RvInstr32Opcode, RvInstr32SystemFunct3, RvInstr32SystemImm,
+    RvMStatus, RvPrivMode,
 };
 use caliptra_emu_bus::Bus;
-use caliptra_emu_types::{RvData, RvException};
+use caliptra_emu_types::{RvAddr, RvData, RvException};
 
 impl<TBus: Bus> Cpu<TBus> {
     /// Execute system Instructions
@@ -37,15 +38,34 @@ impl<TBus: Bus> Cpu<TBus> {
 
         let imm = instr.uimm();
 
-        match instr.funct3().into() {
+        let funct3: RvInstr32SystemFunct3 = instr.funct3().into();
+        if funct3 != RvInstr32SystemFunct3::Priv {
+            self.check_csr_priv(imm, instr.0)?;
+        }
+
+        match funct3 {
             RvInstr32SystemFunct3::Priv => match imm.into() {
-                RvInstr32SystemImm::Ecall => Err(RvException::environment_call()),
+                RvInstr32SystemImm::Ecall => match self.priv_mode() {
+                    RvPrivMode::U => Err(RvException::environment_call_user()),
+                    _ => Err(RvException::environment_call()),
+                },
+                RvInstr32SystemImm::Ebreak if self.ebreak_enters_debug_mode() => {
+                    self.enter_debug_mode(RvDebugCause::Ebreak, self.read_pc());
+                    self.set_next_pc(self.read_pc());
+                    Ok(())
+                }
                 RvInstr32SystemImm::Ebreak => Err(RvException::breakpoint(self.read_pc())),
-                RvInstr32SystemImm::Mret => {
+                RvInstr32SystemImm::Mret if self.priv_mode() == RvPrivMode::M => {
                     let mut status = RvMStatus(self.read_csr(Csr::MSTATUS)?);
+                    let mode = RvPrivMode::from(status.mpp());
                     status.set_mie(status.mpie());
                     status.set_mpie(1);
+                    status.set_mpp(RvPrivMode::U.into());
+                    if mode != RvPrivMode::M {
+                        status.set_mprv(0);
+                    }
                     self.write_csr(Csr::MSTATUS, status.0)?;
+                    self.set_priv_mode(mode);
                     self.set_next_pc(self.read_csr(Csr::MEPC)?);
                     Ok(())
                 }
@@ -90,17 +110,31 @@ impl<TBus: Bus> Cpu<TBus> {
             _ => Err(RvException::illegal_instr(instr.0)),
         }
     }
+
+    /// Check the current privilege mode may access `csr`. Bits [9:8] of the
+    /// CSR address encode the lowest privilege mode allowed to access it.
+    fn check_csr_priv(&self, csr: RvAddr, instr: u32) -> Result<(), RvException> {
+        // Debug CSRs are only accessible in Debug Mode
+        let debug_csr = (0x7B0..=0x7BF).contains(&csr);
+        if (csr >> 8) & 0b11 > u32::from(self.priv_mode()) || (debug_csr && !self.in_debug_mode()) {
+            Err(RvException::illegal_instr(instr))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::cpu::StepAction;
     use crate::csr_file::Csr;
     use crate::instr::test_encoder::tests::{
-        csrrc, csrrci, csrrs, csrrsi, csrrw, csrrwi, ebreak, ecall,
+        csrrc, csrrci, csrrs, csrrsi, csrrw, csrrwi, ebreak, ecall, lw, mret, nop, sw,
     };
+    use crate::types::RvPrivMode;
     use crate::xreg_file::XReg;
     use crate::{isa_test, isa_test_cpu, text};
-    use caliptra_emu_types::RvException;
+    use caliptra_emu_types::{RvException, RvExceptionCause};
 
     #[test]
     fn test_ecall() {
@@ -252,4 +286,99 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_mret_to_user_mode() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![
+            csrrw(XReg::X0, XReg::X1, Csr::MEPC);
+            csrrw(XReg::X0, XReg::X0, Csr::MSTATUS);
+            mret();
+            nop();
+            csrrs(XReg::X3, XReg::X0, Csr::MSTATUS);
+            ecall();
+        ], 0x1000 => vec![0]);
+        // Grant User mode access to everything
+        cpu.write_csr(Csr::PMPADDR_START, u32::MAX).unwrap();
+        cpu.write_csr(Csr::PMPCFG_START, 0x1F).unwrap();
+        cpu.write_csr(Csr::MTVEC, 0xC).unwrap();
+        cpu.write_xreg(XReg::X1, 0x10).unwrap();
+
+        for _ in 0..3 {
+            assert_eq!(cpu.step(None), StepAction::Continue);
+        }
+        assert_eq!(cpu.priv_mode(), RvPrivMode::U);
+        assert_eq!(cpu.read_pc(), 0x10);
+        assert_eq!(cpu.read_csr(Csr::MSTATUS), Ok(0x1800_0080));
+
+        // Machine mode CSRs are not accessible from User mode
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.priv_mode(), RvPrivMode::M);
+        assert_eq!(cpu.read_pc(), 0xC);
+        assert_eq!(cpu.read_csr(Csr::MEPC), Ok(0x10));
+        assert_eq!(
+            cpu.read_csr(Csr::MCAUSE),
+            Ok(RvExceptionCause::IllegalInstr.into())
+        );
+        assert_eq!(cpu.read_csr(Csr::MSTATUS), Ok(0x1800_0000));
+
+        cpu.set_priv_mode(RvPrivMode::U);
+        cpu.write_pc(0x14);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.priv_mode(), RvPrivMode::M);
+        assert_eq!(
+            cpu.read_csr(Csr::MCAUSE),
+            Ok(RvExceptionCause::EnvironmentCallUser.into())
+        );
+    }
+
+    #[test]
+    fn test_mret_illegal_in_user_mode() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![mret();], 0x1000 => vec![0]);
+        cpu.write_csr(Csr::PMPADDR_START, u32::MAX).unwrap();
+        cpu.write_csr(Csr::PMPCFG_START, 0x1F).unwrap();
+        cpu.set_priv_mode(RvPrivMode::U);
+        assert_eq!(
+            cpu.exec_instr(None).err(),
+            Some(RvException::illegal_instr(mret()))
+        );
+    }
+
+    #[test]
+    fn test_pmp_access_faults() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![
+            lw(XReg::X2, 0, XReg::X1);
+            sw(XReg::X1, 0, XReg::X1);
+        ], 0x1000 => vec![0; 8]);
+        // [0x0000, 0x1000) X for User mode, [0x1000, 0x1004) R locked
+        cpu.write_csr(Csr::PMPADDR_START, 0x1000 >> 2).unwrap();
+        cpu.write_csr(Csr::PMPADDR_START + 1, 0x1000 >> 2).unwrap();
+        cpu.write_csr(Csr::PMPCFG_START, 0x0000_910C).unwrap();
+        cpu.write_xreg(XReg::X1, 0x1004).unwrap();
+
+        // User mode loads outside any region fault
+        cpu.set_priv_mode(RvPrivMode::U);
+        assert_eq!(
+            cpu.exec_instr(None).err(),
+            Some(RvException::load_access_fault(0x1004))
+        );
+
+        // Locked regions apply to Machine mode too
+        cpu.set_priv_mode(RvPrivMode::M);
+        cpu.write_pc(0x4);
+        cpu.write_xreg(XReg::X1, 0x1000).unwrap();
+        assert_eq!(
+            cpu.exec_instr(None).err(),
+            Some(RvException::store_access_fault(0x1000))
+        );
+        cpu.write_pc(0x0);
+        assert_eq!(cpu.exec_instr(None).ok(), Some(StepAction::Continue));
+
+        // User mode can't fetch outside the execute region
+        cpu.set_priv_mode(RvPrivMode::U);
+        cpu.write_pc(0x1000);
+        assert_eq!(
+            cpu.exec_instr(None).err(),
+            Some(RvException::instr_access_fault(0x1000))
+        );
+    }
 }
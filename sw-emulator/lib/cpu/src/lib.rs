@@ -14,15 +14,26 @@ Abstract:
 
 pub mod cpu;
 mod csr_file;
+mod debug;
+mod fault;
+mod hpm;
 mod instr;
 mod pic;
+mod pmp;
+mod profiler;
 mod types;
 pub mod xreg_file;
 
 pub use cpu::StepAction;
 pub use cpu::WatchPtrHit;
 pub use cpu::WatchPtrKind;
-pub use cpu::{CodeRange, CoverageBitmaps, Cpu, ImageInfo, InstrTracer, StackInfo, StackRange};
+pub use cpu::{
+    CodeRange, CoverageBitmaps, Cpu, CpuSnapshot, ImageInfo, InstrTracer, StackInfo, StackRange,
+};
 pub use csr_file::CsrFile;
-pub use pic::{IntSource, Irq, Pic, PicMmioRegisters};
-pub use types::RvInstr;
+pub use debug::{DebugModule, DmReg};
+pub use fault::{Fault, FaultHit, FaultKind, FaultTrigger};
+pub use hpm::HpmEvent;
+pub use pic::{IntSource, Irq, Pic, PicMmioRegisters, PicSnapshot};
+pub use profiler::Profiler;
+pub use types::{RvInstr, RvPrivMode};
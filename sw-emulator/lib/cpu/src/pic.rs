@@ -112,7 +112,36 @@ impl PicMmioRegisters {
             pic: self.pic.clone(),
         }
     }
+
+    /// Capture the PIC registers and the gateway state of every irq.
+    pub fn snapshot(&self) -> PicSnapshot {
+        PicSnapshot {
+            regs: self.pic.regs.borrow().clone(),
+            irq_levels: self.pic.irq_levels.clone(),
+            gw_pending_ff: self.pic.gw_pending_ff.clone(),
+        }
+    }
+
+    /// Restore the PIC state captured with [`PicMmioRegisters::snapshot`].
+    pub fn restore(&mut self, snapshot: &PicSnapshot) {
+        *self.pic.regs.borrow_mut() = snapshot.regs.clone();
+        self.pic.irq_levels.bits.set(snapshot.irq_levels.bits.get());
+        self.pic
+            .gw_pending_ff
+            .bits
+            .set(snapshot.gw_pending_ff.bits.get());
+        self.pic.refresh_order();
+    }
+}
+
+/// Saved state of the PIC, returned by [`PicMmioRegisters::snapshot`].
+#[derive(Clone)]
+pub struct PicSnapshot {
+    regs: PicImplRegs,
+    irq_levels: Bits32,
+    gw_pending_ff: Bits32,
 }
+
 impl Bus for PicMmioRegisters {
     fn read(&mut self, size: RvSize, addr: RvAddr) -> Result<RvData, BusError> {
         match addr {
@@ -239,7 +268,7 @@ register_bitfields! [
     ],
 ];
 
-#[derive(Bus)]
+#[derive(Bus, Clone)]
 struct PicImplRegs {
     // External interrupt priority level register. Irq id #1 starts at
     // meipl[1] (address 0x0004); meipl[0] is reserved.
@@ -276,6 +305,7 @@ impl PicImplRegs {
     }
 }
 
+#[derive(Clone)]
 struct Bits32 {
     bits: Cell<u32>,
 }
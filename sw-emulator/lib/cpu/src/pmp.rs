@@ -0,0 +1,161 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    pmp.rs
+
+Abstract:
+
+    File contains the VeeR EL2 Physical Memory Protection checks.
+
+--*/
+
+use crate::csr_file::CsrFile;
+use crate::types::{RvPmpAddrMode, RvPmpCfg, RvPrivMode};
+use caliptra_emu_types::{RvAddr, RvSize};
+
+/// Number of PMP entries implemented by VeeR EL2
+pub(crate) const PMP_ENTRIES: usize = 16;
+
+/// Memory access type checked by the PMP
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum PmpAccess {
+    Read,
+    Write,
+    Exec,
+}
+
+impl CsrFile {
+    /// Returns the byte range `[start, end)` matched by PMP entry `entry`,
+    /// or None if the entry is disabled.
+    fn pmp_range(&self, entry: usize, cfg: RvPmpCfg) -> Option<(u64, u64)> {
+        let addr = u64::from(self.pmp_addr(entry));
+        match RvPmpAddrMode::from(cfg.a()) {
+            RvPmpAddrMode::Tor => {
+                let start = match entry {
+                    0 => 0,
+                    _ => u64::from(self.pmp_addr(entry - 1)) << 2,
+                };
+                Some((start, addr << 2))
+            }
+            RvPmpAddrMode::Na4 => Some((addr << 2, (addr << 2) + 4)),
+            RvPmpAddrMode::Napot => {
+                // The number of trailing ones encodes a region of
+                // 2^(ones + 3) bytes.
+                let ones = addr.trailing_ones();
+                let size = 1u64 << (ones + 3);
+                let start = (addr & !((1u64 << ones) - 1)) << 2;
+                Some((start, start + size))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if the PMP allows `mode` to perform `access` on the
+    /// `size` bytes at `addr`.
+    ///
+    /// The lowest-numbered entry matching any byte of the access decides.
+    /// An access only partially covered by that entry fails. Machine mode
+    /// is only restricted by locked entries; User mode is denied if no
+    /// entry matches.
+    pub(crate) fn pmp_check(
+        &self,
+        addr: RvAddr,
+        size: RvSize,
+        access: PmpAccess,
+        mode: RvPrivMode,
+    ) -> bool {
+        let start = u64::from(addr);
+        let end = start + usize::from(size) as u64;
+        for entry in 0..PMP_ENTRIES {
+            let cfg = self.pmp_cfg(entry);
+            let Some((lo, hi)) = self.pmp_range(entry, cfg) else {
+                continue;
+            };
+            if end <= lo || start >= hi {
+                continue;
+            }
+            if start < lo || end > hi {
+                return false;
+            }
+            if mode == RvPrivMode::M && cfg.l() == 0 {
+                return true;
+            }
+            return match access {
+                PmpAccess::Read => cfg.r() == 1,
+                PmpAccess::Write => cfg.w() == 1,
+                PmpAccess::Exec => cfg.x() == 1,
+            };
+        }
+        mode == RvPrivMode::M
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csr_file::Csr;
+    use caliptra_emu_bus::Clock;
+
+    const R: u32 = 0x01;
+    const W: u32 = 0x02;
+    const X: u32 = 0x04;
+    const TOR: u32 = 0x08;
+    const NA4: u32 = 0x10;
+    const NAPOT: u32 = 0x18;
+    const L: u32 = 0x80;
+
+    fn csrs(cfg: &[u32], addr: &[u32]) -> CsrFile {
+        let mut csrs = CsrFile::new(&Clock::new());
+        for (i, a) in addr.iter().enumerate() {
+            csrs.write(Csr::PMPADDR_START + i as RvAddr, *a).unwrap();
+        }
+        let cfg0 = cfg
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, c)| acc | c << (i * 8));
+        csrs.write(Csr::PMPCFG_START, cfg0).unwrap();
+        csrs
+    }
+
+    #[test]
+    fn test_no_entries() {
+        let csrs = csrs(&[], &[]);
+        assert!(csrs.pmp_check(0x1000, RvSize::Word, PmpAccess::Write, RvPrivMode::M));
+        assert!(!csrs.pmp_check(0x1000, RvSize::Word, PmpAccess::Read, RvPrivMode::U));
+    }
+
+    #[test]
+    fn test_tor() {
+        // [0x1000, 0x2000) R, [0x2000, 0x3000) RWX
+        let csrs = csrs(&[0, TOR | R, TOR | R | W | X], &[0x400, 0x800, 0xc00]);
+        let u = RvPrivMode::U;
+        assert!(csrs.pmp_check(0x1ffc, RvSize::Word, PmpAccess::Read, u));
+        assert!(!csrs.pmp_check(0x1ffc, RvSize::Word, PmpAccess::Write, u));
+        assert!(csrs.pmp_check(0x2000, RvSize::Word, PmpAccess::Write, u));
+        assert!(csrs.pmp_check(0x2ffc, RvSize::Word, PmpAccess::Exec, u));
+        assert!(!csrs.pmp_check(0x3000, RvSize::Byte, PmpAccess::Read, u));
+        assert!(!csrs.pmp_check(0xffc, RvSize::Word, PmpAccess::Read, u));
+        // Straddles entries 1 and 2
+        assert!(!csrs.pmp_check(0x1ffe, RvSize::Word, PmpAccess::Read, u));
+        // Unlocked entries don't apply to Machine mode
+        assert!(csrs.pmp_check(0x1ffc, RvSize::Word, PmpAccess::Write, RvPrivMode::M));
+    }
+
+    #[test]
+    fn test_na4_napot_and_lock() {
+        // NA4 at 0x5000_0000, NAPOT 64 KiB at 0x4000_0000
+        let csrs = csrs(
+            &[NA4 | L, NAPOT | L | R | X],
+            &[0x5000_0000 >> 2, (0x4000_0000 >> 2) | 0x1fff],
+        );
+        let m = RvPrivMode::M;
+        assert!(!csrs.pmp_check(0x5000_0000, RvSize::Byte, PmpAccess::Read, m));
+        assert!(csrs.pmp_check(0x5000_0004, RvSize::Byte, PmpAccess::Write, m));
+        assert!(csrs.pmp_check(0x4000_fffc, RvSize::Word, PmpAccess::Exec, m));
+        assert!(!csrs.pmp_check(0x4000_0000, RvSize::Word, PmpAccess::Write, m));
+        assert!(csrs.pmp_check(0x4001_0000, RvSize::Word, PmpAccess::Write, m));
+    }
+}
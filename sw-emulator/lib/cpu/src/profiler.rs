@@ -0,0 +1,210 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    profiler.rs
+
+Abstract:
+
+    File contains a sampling profiler that attributes cycles to symbols.
+
+--*/
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::cpu::Cpu;
+use crate::types::{RvInstr32I, RvInstr32J, RvInstr32Opcode};
+use crate::xreg_file::XReg;
+use caliptra_emu_bus::Bus;
+use caliptra_emu_types::RvAddr;
+
+/// Calls nested deeper than this are attributed to the deepest frame.
+const MAX_STACK_DEPTH: usize = 256;
+
+/// Index into the profiler's symbols; `UNKNOWN` if no symbol matched.
+type SymbolIndex = usize;
+const UNKNOWN: SymbolIndex = usize::MAX;
+
+struct ProfileSymbol {
+    name: String,
+    start: RvAddr,
+    size: u32,
+}
+
+/// Samples the call stack of the CPU every `interval` cycles.
+///
+/// The call stack is tracked by watching for calls (`jal`/`jalr` that link
+/// to `ra` or `t0`) and returns (`jalr x0, 0(ra)`), so code that doesn't
+/// follow the calling convention, such as trap handlers, is attributed to
+/// whatever it interrupted.
+pub struct Profiler {
+    interval: u64,
+    next_sample: Option<u64>,
+    symbols: Vec<ProfileSymbol>,
+    stack: Vec<SymbolIndex>,
+    samples: HashMap<Vec<SymbolIndex>, u64>,
+}
+
+impl Profiler {
+    /// Create a profiler that takes a sample every `interval` cycles.
+    pub fn new(interval: u64) -> Self {
+        Self {
+            interval: interval.max(1),
+            next_sample: None,
+            symbols: vec![],
+            stack: vec![],
+            samples: HashMap::new(),
+        }
+    }
+
+    /// Add a function symbol covering `size` bytes from `start`.
+    pub fn add_symbol(&mut self, name: impl Into<String>, start: RvAddr, size: u32) {
+        self.symbols.push(ProfileSymbol {
+            name: name.into(),
+            start,
+            size: size.max(1),
+        });
+        self.symbols.sort_by_key(|s| s.start);
+    }
+
+    fn lookup(&self, pc: RvAddr) -> SymbolIndex {
+        match self.symbols.partition_point(|s| s.start <= pc) {
+            0 => UNKNOWN,
+            i if pc - self.symbols[i - 1].start < self.symbols[i - 1].size => i - 1,
+            _ => UNKNOWN,
+        }
+    }
+
+    /// Record the execution of `instr` at `pc`, which continues at `next_pc`.
+    fn record(&mut self, pc: RvAddr, instr: u32, next_pc: RvAddr, now: u64) {
+        let leaf = self.lookup(pc);
+        if self.stack.is_empty() {
+            self.stack.push(leaf);
+        }
+
+        let next_sample = self.next_sample.get_or_insert(now);
+        if now >= *next_sample {
+            let weight = ((now - *next_sample) / self.interval + 1) * self.interval;
+            *next_sample += weight;
+            let mut frames = self.stack.clone();
+            if frames.last() != Some(&leaf) {
+                frames.push(leaf);
+            }
+            *self.samples.entry(frames).or_default() += weight;
+        }
+
+        let is_link = |reg: XReg| reg == XReg::X1 || reg == XReg::X5;
+        let instr_i = RvInstr32I(instr);
+        match instr_i.opcode() {
+            RvInstr32Opcode::Jal if is_link(RvInstr32J(instr).rd()) => self.push(next_pc),
+            RvInstr32Opcode::Jalr if is_link(instr_i.rd()) => self.push(next_pc),
+            RvInstr32Opcode::Jalr
+                if instr_i.rd() == XReg::X0 && is_link(instr_i.rs()) && instr_i.imm() == 0 =>
+            {
+                if self.stack.len() > 1 {
+                    self.stack.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push(&mut self, target: RvAddr) {
+        if self.stack.len() < MAX_STACK_DEPTH {
+            self.stack.push(self.lookup(target));
+        }
+    }
+
+    /// Write the samples in the folded stack format consumed by
+    /// `flamegraph.pl` and `inferno`: one `caller;callee cycles` line per
+    /// distinct stack.
+    pub fn write_folded(&self, w: &mut impl Write) -> io::Result<()> {
+        let mut samples: Vec<_> = self.samples.iter().collect();
+        samples.sort();
+        for (frames, cycles) in samples {
+            let names: Vec<_> = frames
+                .iter()
+                .map(|&i| match i {
+                    UNKNOWN => "[unknown]",
+                    i => self.symbols[i].name.as_str(),
+                })
+                .collect();
+            writeln!(w, "{} {}", names.join(";"), cycles)?;
+        }
+        Ok(())
+    }
+}
+
+impl<TBus: Bus> Cpu<TBus> {
+    /// Start profiling with `profiler`, replacing any previous profiler.
+    pub fn set_profiler(&mut self, profiler: Profiler) {
+        self.profiler = Some(profiler);
+    }
+
+    /// Returns the active profiler.
+    pub fn profiler(&self) -> Option<&Profiler> {
+        self.profiler.as_ref()
+    }
+
+    /// Stop profiling and return the profiler with its samples.
+    pub fn take_profiler(&mut self) -> Option<Profiler> {
+        self.profiler.take()
+    }
+
+    /// Sample the executed 32-bit (or decompressed) instruction `instr`.
+    pub(crate) fn profile_instr(&mut self, instr: u32) {
+        let (pc, next_pc, now) = (self.read_pc(), self.next_pc(), self.clock.now());
+        if let Some(profiler) = &mut self.profiler {
+            profiler.record(pc, instr, next_pc, now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::StepAction;
+    use crate::instr::test_encoder::tests::{jal, jalr, nop};
+    use crate::{isa_test_cpu, text};
+
+    #[test]
+    fn test_folded_stacks() {
+        let mut cpu = isa_test_cpu!(0x0000 => text![
+            // main
+            jal(XReg::X1, 8);
+            jal(XReg::X0, 0);
+            // func
+            nop();
+            jalr(XReg::X0, XReg::X1, 0);
+        ], 0x1000 => vec![0; 4]);
+        let mut profiler = Profiler::new(1);
+        profiler.add_symbol("func", 8, 8);
+        profiler.add_symbol("main", 0, 8);
+        cpu.set_profiler(profiler);
+
+        for _ in 0..6 {
+            assert_eq!(cpu.step(None), StepAction::Continue);
+        }
+        let mut folded = vec![];
+        cpu.take_profiler()
+            .unwrap()
+            .write_folded(&mut folded)
+            .unwrap();
+        assert_eq!(String::from_utf8(folded).unwrap(), "main 4\nmain;func 2\n");
+    }
+
+    #[test]
+    fn test_unknown_and_interval() {
+        let mut profiler = Profiler::new(4);
+        profiler.add_symbol("main", 0, 8);
+        for now in 1..=10 {
+            profiler.record(0x100, nop(), 0x104, now);
+        }
+        let mut folded = vec![];
+        profiler.write_folded(&mut folded).unwrap();
+        assert_eq!(String::from_utf8(folded).unwrap(), "[unknown] 12\n");
+    }
+}
@@ -628,6 +628,12 @@ bitfield! {
 
     /// Machine Mode Previous Interrupt Enable
     pub u32, mpie, set_mpie: 7, 7;
+
+    /// Machine Mode Previous Privilege
+    pub u32, mpp, set_mpp: 12, 11;
+
+    /// Modify Privilege: loads and stores use the privilege in `mpp`
+    pub u32, mprv, set_mprv: 17, 17;
 }
 
 bitfield! {
@@ -662,3 +668,96 @@ bitfield! {
     /// Control interrupt enable
     pub u32, haltie, _: 1, 1;
 }
+
+emu_enum! {
+    /// RISCV Privilege Mode
+    #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+    pub RvPrivMode;
+    u32;
+    {
+        /// User Mode
+        U = 0b00,
+
+        /// Machine Mode
+        M = 0b11,
+    };
+    Invalid
+}
+
+emu_enum! {
+    /// PMP Address Matching Mode
+    #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+    pub RvPmpAddrMode;
+    u32;
+    {
+        /// Null region (disabled)
+        Off = 0b00,
+
+        /// Top of range
+        Tor = 0b01,
+
+        /// Naturally aligned four-byte region
+        Na4 = 0b10,
+
+        /// Naturally aligned power-of-two region, >= 8 bytes
+        Napot = 0b11,
+    };
+    Invalid
+}
+
+bitfield! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    /// Debug Control and Status Register
+    pub struct RvDcsr(u32);
+
+    /// Privilege mode the hart was in when it entered Debug Mode
+    pub u32, prv, set_prv: 1, 0;
+
+    /// Single step: re-enter Debug Mode after executing one instruction
+    pub u32, step, set_step: 2, 2;
+
+    /// Why Debug Mode was entered
+    pub u32, cause, set_cause: 8, 6;
+
+    /// Enter Debug Mode on `ebreak` in Machine mode
+    pub u32, ebreakm, set_ebreakm: 15, 15;
+}
+
+emu_enum! {
+    /// Reason for entering Debug Mode, as reported in `dcsr.cause`
+    #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+    pub RvDebugCause;
+    u32;
+    {
+        /// `ebreak` with `dcsr.ebreakm` set
+        Ebreak = 1,
+
+        /// Halt request from the debugger
+        HaltReq = 3,
+
+        /// Single step completed
+        Step = 4,
+    };
+    Invalid
+}
+
+bitfield! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    /// PMP Entry Configuration (one byte of a pmpcfg register)
+    pub struct RvPmpCfg(u32);
+
+    /// Read permission
+    pub u32, r, set_r: 0, 0;
+
+    /// Write permission
+    pub u32, w, set_w: 1, 1;
+
+    /// Execute permission
+    pub u32, x, set_x: 2, 2;
+
+    /// Address matching mode
+    pub u32, a, set_a: 4, 3;
+
+    /// Locked; also enforced in Machine mode
+    pub u32, l, set_l: 7, 7;
+}
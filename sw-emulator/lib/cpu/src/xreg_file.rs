@@ -16,7 +16,7 @@ use caliptra_emu_types::{emu_enum, RvAddr, RvData, RvException};
 
 emu_enum!(
     /// RISCV general purpose registers
-    #[derive(PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
+    #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
     pub XReg;
     RvAddr;
     {
@@ -67,6 +67,7 @@ impl From<XReg> for u16 {
 }
 
 /// RISCV General purpose register file
+#[derive(Clone)]
 pub struct XRegFile {
     /// Registers
     reg: [RvData; XRegFile::REG_COUNT],
@@ -51,6 +51,7 @@ impl From<Hmac512Mode> for Sha512Mode {
 }
 
 /// HMAC-512
+#[derive(Clone)]
 pub struct Hmac512<const KEY_SIZE: usize> {
     /// Hash One
     hash1: Sha512,
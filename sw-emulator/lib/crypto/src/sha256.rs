@@ -24,6 +24,7 @@ pub enum Sha256Mode {
 }
 
 /// SHA-256
+#[derive(Clone)]
 pub struct Sha256 {
     /// Hash
     hash: [u32; 8],
@@ -33,6 +33,7 @@ impl From<Sha512Mode> for u32 {
 }
 
 /// SHA-512
+#[derive(Clone)]
 pub struct Sha512 {
     /// Hash
     hash: [u64; 8],
@@ -14,7 +14,9 @@ Abstract:
 
 use crate::helpers::{bytes_from_words_le, words_from_bytes_le};
 use crate::{HashSha512, KeyUsage, KeyVault};
-use caliptra_emu_bus::{ActionHandle, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Timer};
+use caliptra_emu_bus::{
+    ActionHandle, ActionSnapshot, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Timer,
+};
 use caliptra_emu_crypto::{Ecc384, Ecc384PubKey, Ecc384Signature};
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvData, RvSize};
@@ -233,6 +235,37 @@ pub struct AsymEcc384 {
     op_key_write_complete_action: Option<ActionHandle>,
 }
 
+/// Saved state of the ECC-384 engine, returned by [`AsymEcc384::snapshot`]
+#[derive(Clone)]
+pub struct AsymEcc384Snapshot {
+    control: ReadWriteRegister<u32, Control::Register>,
+    status: ReadOnlyRegister<u32, Status::Register>,
+    sca_cfg: ReadWriteRegister<u32, Status::Register>,
+    seed: [u32; ECC384_SEED_SIZE / 4],
+    hash: [u32; ECC384_SEED_SIZE / 4],
+    priv_key_out: [u32; ECC384_COORD_SIZE / 4],
+    priv_key_in: [u32; ECC384_COORD_SIZE / 4],
+    pub_key_x: [u32; ECC384_COORD_SIZE / 4],
+    pub_key_y: [u32; ECC384_COORD_SIZE / 4],
+    sig_r: [u32; ECC384_COORD_SIZE / 4],
+    sig_s: [u32; ECC384_COORD_SIZE / 4],
+    verify_r: [u32; ECC384_COORD_SIZE / 4],
+    iv: [u32; ECC384_IV_SIZE / 4],
+    nonce: [u32; ECC384_NONCE_SIZE / 4],
+    key_read_ctrl: ReadWriteRegister<u32, KeyReadControl::Register>,
+    key_read_status: ReadOnlyRegister<u32, KeyReadStatus::Register>,
+    seed_read_ctrl: ReadWriteRegister<u32, KeyReadControl::Register>,
+    seed_read_status: ReadOnlyRegister<u32, KeyReadStatus::Register>,
+    key_write_ctrl: ReadWriteRegister<u32, KeyWriteControl::Register>,
+    key_write_status: ReadOnlyRegister<u32, KeyWriteStatus::Register>,
+    error_global_intr: ReadOnlyRegister<u32>,
+    error_internal_intr: ReadOnlyRegister<u32>,
+    op_complete_action: Option<ActionSnapshot>,
+    op_key_read_complete_action: Option<ActionSnapshot>,
+    op_seed_read_complete_action: Option<ActionSnapshot>,
+    op_key_write_complete_action: Option<ActionSnapshot>,
+}
+
 impl AsymEcc384 {
     /// NAME0 Register Value
     const NAME0_VAL: RvData = 0x73656370; //0x63737065; // secp
@@ -285,6 +318,85 @@ impl AsymEcc384 {
         }
     }
 
+    /// Capture the registers, inputs, outputs and pending operations
+    pub fn snapshot(&self) -> AsymEcc384Snapshot {
+        AsymEcc384Snapshot {
+            control: self.control.clone(),
+            status: self.status.clone(),
+            sca_cfg: self.sca_cfg.clone(),
+            seed: self.seed,
+            hash: self.hash,
+            priv_key_out: self.priv_key_out,
+            priv_key_in: self.priv_key_in,
+            pub_key_x: self.pub_key_x,
+            pub_key_y: self.pub_key_y,
+            sig_r: self.sig_r,
+            sig_s: self.sig_s,
+            verify_r: self.verify_r,
+            iv: self.iv,
+            nonce: self.nonce,
+            key_read_ctrl: self.key_read_ctrl.clone(),
+            key_read_status: self.key_read_status.clone(),
+            seed_read_ctrl: self.seed_read_ctrl.clone(),
+            seed_read_status: self.seed_read_status.clone(),
+            key_write_ctrl: self.key_write_ctrl.clone(),
+            key_write_status: self.key_write_status.clone(),
+            error_global_intr: self.error_global_intr.clone(),
+            error_internal_intr: self.error_internal_intr.clone(),
+            op_complete_action: self.op_complete_action.as_ref().map(ActionHandle::snapshot),
+            op_key_read_complete_action: self
+                .op_key_read_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_seed_read_complete_action: self
+                .op_seed_read_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_key_write_complete_action: self
+                .op_key_write_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+        }
+    }
+
+    /// Restore the state captured with [`AsymEcc384::snapshot`].
+    pub fn restore(&mut self, snapshot: &AsymEcc384Snapshot) {
+        self.control = snapshot.control.clone();
+        self.status = snapshot.status.clone();
+        self.sca_cfg = snapshot.sca_cfg.clone();
+        self.seed = snapshot.seed;
+        self.hash = snapshot.hash;
+        self.priv_key_out = snapshot.priv_key_out;
+        self.priv_key_in = snapshot.priv_key_in;
+        self.pub_key_x = snapshot.pub_key_x;
+        self.pub_key_y = snapshot.pub_key_y;
+        self.sig_r = snapshot.sig_r;
+        self.sig_s = snapshot.sig_s;
+        self.verify_r = snapshot.verify_r;
+        self.iv = snapshot.iv;
+        self.nonce = snapshot.nonce;
+        self.key_read_ctrl = snapshot.key_read_ctrl.clone();
+        self.key_read_status = snapshot.key_read_status.clone();
+        self.seed_read_ctrl = snapshot.seed_read_ctrl.clone();
+        self.seed_read_status = snapshot.seed_read_status.clone();
+        self.key_write_ctrl = snapshot.key_write_ctrl.clone();
+        self.key_write_status = snapshot.key_write_status.clone();
+        self.error_global_intr = snapshot.error_global_intr.clone();
+        self.error_internal_intr = snapshot.error_internal_intr.clone();
+        self.op_complete_action = snapshot
+            .op_complete_action
+            .map(|a| self.timer.restore_action(a));
+        self.op_key_read_complete_action = snapshot
+            .op_key_read_complete_action
+            .map(|a| self.timer.restore_action(a));
+        self.op_seed_read_complete_action = snapshot
+            .op_seed_read_complete_action
+            .map(|a| self.timer.restore_action(a));
+        self.op_key_write_complete_action = snapshot
+            .op_key_write_complete_action
+            .map(|a| self.timer.restore_action(a));
+    }
+
     /// On Write callback for `control` register
     ///
     /// # Arguments
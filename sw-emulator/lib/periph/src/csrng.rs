@@ -12,7 +12,7 @@ use std::mem;
 use tock_registers::interfaces::Readable;
 
 mod health_test;
-use health_test::HealthTester;
+use health_test::{HealthTester, HealthTesterSnapshot};
 
 mod ctr_drbg;
 use ctr_drbg::{Block, CtrDrbg, Instantiate, Seed};
@@ -106,6 +106,57 @@ impl Csrng {
         }
     }
 
+    /// Capture the registers, DRBG state, health test counters and the
+    /// position of the itrng iterator.
+    pub fn snapshot(&self) -> CsrngSnapshot {
+        CsrngSnapshot {
+            ctrl: self.ctrl,
+            sw_cmd_sts: self.sw_cmd_sts.clone(),
+            genbits_vld: self.genbits_vld.clone(),
+            genbits: self.genbits.clone(),
+            err_code: self.err_code.clone(),
+            module_enable: self.module_enable,
+            conf: self.conf,
+            health_test_windows: self.health_test_windows.clone(),
+            repcnt_thresholds: self.repcnt_thresholds,
+            adaptp_hi_thresholds: self.adaptp_hi_thresholds,
+            adaptp_lo_thresholds: self.adaptp_lo_thresholds,
+            alert_summary_fail_counts: self.alert_summary_fail_counts.clone(),
+            alert_fail_counts: self.alert_fail_counts.clone(),
+            main_sm_state: self.main_sm_state.clone(),
+            cmd_req_state: self.cmd_req_state.clone(),
+            seed: self.seed.clone(),
+            ctr_drbg: self.ctr_drbg.clone(),
+            words: self.words.clone(),
+            health_tester: self.health_tester.snapshot(),
+        }
+    }
+
+    /// Restore the state captured with [`Csrng::snapshot`]. The instance must
+    /// be freshly created with the same itrng nibble source for it to continue
+    /// with the same entropy.
+    pub fn restore(&mut self, snapshot: &CsrngSnapshot) {
+        self.ctrl = snapshot.ctrl;
+        self.sw_cmd_sts = snapshot.sw_cmd_sts.clone();
+        self.genbits_vld = snapshot.genbits_vld.clone();
+        self.genbits = snapshot.genbits.clone();
+        self.err_code = snapshot.err_code.clone();
+        self.module_enable = snapshot.module_enable;
+        self.conf = snapshot.conf;
+        self.health_test_windows = snapshot.health_test_windows.clone();
+        self.repcnt_thresholds = snapshot.repcnt_thresholds;
+        self.adaptp_hi_thresholds = snapshot.adaptp_hi_thresholds;
+        self.adaptp_lo_thresholds = snapshot.adaptp_lo_thresholds;
+        self.alert_summary_fail_counts = snapshot.alert_summary_fail_counts.clone();
+        self.alert_fail_counts = snapshot.alert_fail_counts.clone();
+        self.main_sm_state = snapshot.main_sm_state.clone();
+        self.cmd_req_state = snapshot.cmd_req_state.clone();
+        self.seed = snapshot.seed.clone();
+        self.ctr_drbg = snapshot.ctr_drbg.clone();
+        self.words = snapshot.words.clone();
+        self.health_tester.restore(&snapshot.health_tester);
+    }
+
     fn cmd_req_write(&mut self, _: RvSize, data: RvData) -> Result<(), BusError> {
         // Since the CMD_REQ register can be used to initiate new commands or
         // supply words to an existing command, we need to track which "state"
@@ -303,7 +354,31 @@ impl Csrng {
     }
 }
 
-#[derive(Default)]
+/// Saved state of the CSRNG, returned by [`Csrng::snapshot`]
+#[derive(Clone)]
+pub struct CsrngSnapshot {
+    ctrl: u32,
+    sw_cmd_sts: ReadOnlyRegister<u32>,
+    genbits_vld: ReadOnlyRegister<u32>,
+    genbits: ReadOnlyRegister<u32>,
+    err_code: ReadOnlyRegister<u32>,
+    module_enable: u32,
+    conf: u32,
+    health_test_windows: ReadOnlyRegister<u32>,
+    repcnt_thresholds: u32,
+    adaptp_hi_thresholds: u32,
+    adaptp_lo_thresholds: u32,
+    alert_summary_fail_counts: ReadOnlyRegister<u32>,
+    alert_fail_counts: ReadOnlyRegister<u32>,
+    main_sm_state: ReadOnlyRegister<u32>,
+    cmd_req_state: CmdReqState,
+    seed: Vec<u32>,
+    ctr_drbg: CtrDrbg,
+    words: Words,
+    health_tester: HealthTesterSnapshot,
+}
+
+#[derive(Clone, Default)]
 struct Words {
     block: Block,
     cursor: usize,
@@ -350,6 +425,7 @@ impl ExactSizeIterator for Words {
     }
 }
 
+#[derive(Clone)]
 enum CmdReqState {
     ExpectNewCommand,
     ExpectSeedWords { num_words: usize },
@@ -28,6 +28,7 @@ impl Default for Instantiate<'_> {
     }
 }
 
+#[derive(Clone)]
 pub struct CtrDrbg {
     v: Block,
     key: Key,
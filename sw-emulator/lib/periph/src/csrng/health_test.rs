@@ -12,6 +12,7 @@ pub struct HealthTester {
     pub repcnt: RepetitionCountTester,
     pub adaptp: AdaptiveProportionTester,
     boot_time_nibbles: Vec<u8>,
+    itrng_nibbles_consumed: u64,
 }
 
 impl HealthTester {
@@ -21,6 +22,7 @@ impl HealthTester {
             repcnt: RepetitionCountTester::new(),
             adaptp: AdaptiveProportionTester::new(),
             boot_time_nibbles: Vec::new(),
+            itrng_nibbles_consumed: 0,
         }
     }
 
@@ -38,6 +40,7 @@ impl HealthTester {
             .collect();
 
         assert_eq!(self.boot_time_nibbles.len(), NUM_NIBBLES, "itrng iterator should provide at least {NUM_NIBBLES} nibbles for boot-time health testing");
+        self.itrng_nibbles_consumed += NUM_NIBBLES as u64;
 
         // We'll want to pull these FIFO.
         self.boot_time_nibbles.reverse();
@@ -46,6 +49,40 @@ impl HealthTester {
     pub fn failures(&self) -> u32 {
         self.repcnt.failures() + self.adaptp.lo_failures() + self.adaptp.hi_failures()
     }
+
+    /// Captures the health test counters, any boot-time nibbles not yet
+    /// consumed and the number of nibbles read from the itrng iterator.
+    pub fn snapshot(&self) -> HealthTesterSnapshot {
+        HealthTesterSnapshot {
+            repcnt: self.repcnt.clone(),
+            adaptp: self.adaptp.clone(),
+            boot_time_nibbles: self.boot_time_nibbles.clone(),
+            itrng_nibbles_consumed: self.itrng_nibbles_consumed,
+        }
+    }
+
+    /// Restores the state captured with [`HealthTester::snapshot`]. The itrng
+    /// iterator is advanced to the captured position, so a fresh instance
+    /// given the same nibble source continues with the same entropy.
+    pub fn restore(&mut self, snapshot: &HealthTesterSnapshot) {
+        self.repcnt = snapshot.repcnt.clone();
+        self.adaptp = snapshot.adaptp.clone();
+        self.boot_time_nibbles = snapshot.boot_time_nibbles.clone();
+        while self.itrng_nibbles_consumed < snapshot.itrng_nibbles_consumed {
+            self.itrng_nibbles
+                .next()
+                .expect("itrng iterator ended before the snapshot position");
+            self.itrng_nibbles_consumed += 1;
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct HealthTesterSnapshot {
+    repcnt: RepetitionCountTester,
+    adaptp: AdaptiveProportionTester,
+    boot_time_nibbles: Vec<u8>,
+    itrng_nibbles_consumed: u64,
 }
 
 impl Iterator for HealthTester {
@@ -59,6 +96,7 @@ impl Iterator for HealthTester {
             // Then yield directly from the TRNG. Feed nibbles through health checks
             // for continuous testing.
             let nibble = self.itrng_nibbles.next()?;
+            self.itrng_nibbles_consumed += 1;
             self.repcnt.feed(nibble);
             self.adaptp.feed(nibble);
             Some(nibble)
@@ -72,6 +110,7 @@ enum Bit {
     One,
 }
 
+#[derive(Clone)]
 pub struct RepetitionCountTester {
     threshold: u32,
     prev_nibble: [Option<Bit>; BITS_PER_NIBBLE],
@@ -126,6 +165,7 @@ impl RepetitionCountTester {
     }
 }
 
+#[derive(Clone)]
 pub struct AdaptiveProportionTester {
     lo_threshold: u32,
     hi_threshold: u32,
@@ -187,3 +227,28 @@ impl AdaptiveProportionTester {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nibbles() -> Box<dyn Iterator<Item = u8>> {
+        Box::new((0..u64::MAX).map(|i| (i * 7 % 16) as u8))
+    }
+
+    #[test]
+    fn test_snapshot_restore_itrng_position() {
+        let mut tester = HealthTester::new(nibbles());
+        tester.test_boot_window();
+        let consumed: Vec<u8> = tester.by_ref().take(600).collect();
+        assert_eq!(consumed.len(), 600);
+
+        let snapshot = tester.snapshot();
+        let mut restored = HealthTester::new(nibbles());
+        restored.restore(&snapshot);
+
+        let expected: Vec<u8> = tester.by_ref().take(100).collect();
+        assert_eq!(restored.by_ref().take(100).collect::<Vec<u8>>(), expected);
+        assert_eq!(restored.failures(), tester.failures());
+    }
+}
@@ -15,7 +15,8 @@ Abstract:
 use crate::helpers::bytes_swap_word_endian;
 use crate::{KeyVault, SocRegistersInternal};
 use caliptra_emu_bus::{
-    ActionHandle, BusError, Clock, ReadOnlyRegister, ReadWriteMemory, ReadWriteRegister, Timer,
+    ActionHandle, ActionSnapshot, BusError, Clock, ReadOnlyRegister, ReadWriteMemory,
+    ReadWriteRegister, Timer,
 };
 use caliptra_emu_crypto::Aes256Cbc;
 use caliptra_emu_derive::Bus;
@@ -86,6 +87,15 @@ pub struct Doe {
     op_complete_action: Option<ActionHandle>,
 }
 
+/// Saved state of the deobfuscation engine, returned by [`Doe::snapshot`]
+#[derive(Clone)]
+pub struct DoeSnapshot {
+    iv: ReadWriteMemory<DOE_IV_SIZE>,
+    control: ReadWriteRegister<u32, Control::Register>,
+    status: ReadOnlyRegister<u32, Status::Register>,
+    op_complete_action: Option<ActionSnapshot>,
+}
+
 impl Doe {
     /// Create new instance of deobfuscation engine
     ///
@@ -110,6 +120,26 @@ impl Doe {
         }
     }
 
+    /// Capture the registers, IV and pending operation
+    pub fn snapshot(&self) -> DoeSnapshot {
+        DoeSnapshot {
+            iv: self.iv.clone(),
+            control: self.control.clone(),
+            status: self.status.clone(),
+            op_complete_action: self.op_complete_action.as_ref().map(ActionHandle::snapshot),
+        }
+    }
+
+    /// Restore the state captured with [`Doe::snapshot`].
+    pub fn restore(&mut self, snapshot: &DoeSnapshot) {
+        self.iv = snapshot.iv.clone();
+        self.control = snapshot.control.clone();
+        self.status = snapshot.status.clone();
+        self.op_complete_action = snapshot
+            .op_complete_action
+            .map(|a| self.timer.restore_action(a));
+    }
+
     /// On Write callback for `control` register
     ///
     /// # Arguments
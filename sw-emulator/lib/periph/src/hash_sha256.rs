@@ -13,8 +13,8 @@ Abstract:
 --*/
 
 use caliptra_emu_bus::{
-    ActionHandle, BusError, Clock, ReadOnlyMemory, ReadOnlyRegister, ReadWriteMemory,
-    ReadWriteRegister, Timer,
+    ActionHandle, ActionSnapshot, BusError, Clock, ReadOnlyMemory, ReadOnlyRegister,
+    ReadWriteMemory, ReadWriteRegister, Timer,
 };
 use caliptra_emu_crypto::{EndianessTransform, Sha256, Sha256Mode};
 use caliptra_emu_derive::Bus;
@@ -104,6 +104,18 @@ pub struct HashSha256 {
 
     op_complete_action: Option<ActionHandle>,
 }
+
+/// Saved state of the SHA-256 engine, returned by [`HashSha256::snapshot`]
+#[derive(Clone)]
+pub struct HashSha256Snapshot {
+    control: ReadWriteRegister<u32, Control::Register>,
+    status: ReadOnlyRegister<u32, Status::Register>,
+    block: ReadWriteMemory<SHA256_BLOCK_SIZE>,
+    hash: ReadOnlyMemory<SHA256_HASH_SIZE>,
+    sha256: Sha256,
+    op_complete_action: Option<ActionSnapshot>,
+}
+
 #[derive(Debug)]
 pub struct WntzParams {
     /// Enable/disable winterniz accel mode.
@@ -152,6 +164,30 @@ impl HashSha256 {
         }
     }
 
+    /// Capture the registers, SHA-256 engine state and pending operation
+    pub fn snapshot(&self) -> HashSha256Snapshot {
+        HashSha256Snapshot {
+            control: self.control.clone(),
+            status: self.status.clone(),
+            block: self.block.clone(),
+            hash: self.hash.clone(),
+            sha256: self.sha256.clone(),
+            op_complete_action: self.op_complete_action.as_ref().map(ActionHandle::snapshot),
+        }
+    }
+
+    /// Restore the state captured with [`HashSha256::snapshot`].
+    pub fn restore(&mut self, snapshot: &HashSha256Snapshot) {
+        self.control = snapshot.control.clone();
+        self.status = snapshot.status.clone();
+        self.block = snapshot.block.clone();
+        self.hash = snapshot.hash.clone();
+        self.sha256 = snapshot.sha256.clone();
+        self.op_complete_action = snapshot
+            .op_complete_action
+            .map(|a| self.timer.restore_action(a));
+    }
+
     pub fn hash_block(&mut self, block: &[u8; 64]) -> Result<(), BusError> {
         if self.control.reg.is_set(Control::INIT) || self.control.reg.is_set(Control::NEXT) {
             // Reset the Ready and Valid status bits
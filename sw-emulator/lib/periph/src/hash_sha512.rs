@@ -16,8 +16,8 @@ use crate::helpers::words_from_bytes_le;
 use crate::key_vault::KeyUsage;
 use crate::KeyVault;
 use caliptra_emu_bus::{
-    ActionHandle, Bus, BusError, Clock, ReadOnlyMemory, ReadOnlyRegister, ReadWriteRegister, Timer,
-    WriteOnlyRegister,
+    ActionHandle, ActionSnapshot, Bus, BusError, Clock, ReadOnlyMemory, ReadOnlyRegister,
+    ReadWriteRegister, Timer, WriteOnlyRegister,
 };
 use caliptra_emu_crypto::EndianessTransform;
 use caliptra_emu_crypto::{Sha512, Sha512Mode};
@@ -700,6 +700,71 @@ impl HashSha512 {
         }
     }
 
+    /// Capture the registers, SHA-512 engine state and pending operations
+    pub fn snapshot(&self) -> HashSha512Snapshot {
+        let regs = self.regs.borrow();
+        HashSha512Snapshot {
+            control: regs.control.clone(),
+            status: regs.status.clone(),
+            block: regs.block,
+            hash: regs.hash.clone(),
+            block_read_ctrl: regs.block_read_ctrl.clone(),
+            block_read_status: regs.block_read_status.clone(),
+            hash_write_ctrl: regs.hash_write_ctrl.clone(),
+            hash_write_status: regs.hash_write_status.clone(),
+            pcr_gen_hash_nonce: regs.pcr_gen_hash_nonce,
+            pcr_hash_control: regs.pcr_hash_control.clone(),
+            pcr_hash_status: regs.pcr_hash_status.clone(),
+            pcr_hash_digest: regs.pcr_hash_digest,
+            sha512: regs.sha512.clone(),
+            op_complete_action: regs.op_complete_action.as_ref().map(ActionHandle::snapshot),
+            op_block_read_complete_action: regs
+                .op_block_read_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_hash_write_complete_action: regs
+                .op_hash_write_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_pcr_gen_hash_complete_action: regs
+                .op_pcr_gen_hash_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            pcr_present: regs.pcr_present,
+        }
+    }
+
+    /// Restore the state captured with [`HashSha512::snapshot`].
+    pub fn restore(&mut self, snapshot: &HashSha512Snapshot) {
+        let mut regs = self.regs.borrow_mut();
+        regs.control = snapshot.control.clone();
+        regs.status = snapshot.status.clone();
+        regs.block = snapshot.block;
+        regs.hash = snapshot.hash.clone();
+        regs.block_read_ctrl = snapshot.block_read_ctrl.clone();
+        regs.block_read_status = snapshot.block_read_status.clone();
+        regs.hash_write_ctrl = snapshot.hash_write_ctrl.clone();
+        regs.hash_write_status = snapshot.hash_write_status.clone();
+        regs.pcr_gen_hash_nonce = snapshot.pcr_gen_hash_nonce;
+        regs.pcr_hash_control = snapshot.pcr_hash_control.clone();
+        regs.pcr_hash_status = snapshot.pcr_hash_status.clone();
+        regs.pcr_hash_digest = snapshot.pcr_hash_digest;
+        regs.sha512 = snapshot.sha512.clone();
+        regs.op_complete_action = snapshot
+            .op_complete_action
+            .map(|a| regs.timer.restore_action(a));
+        regs.op_block_read_complete_action = snapshot
+            .op_block_read_complete_action
+            .map(|a| regs.timer.restore_action(a));
+        regs.op_hash_write_complete_action = snapshot
+            .op_hash_write_complete_action
+            .map(|a| regs.timer.restore_action(a));
+        regs.op_pcr_gen_hash_complete_action = snapshot
+            .op_pcr_gen_hash_complete_action
+            .map(|a| regs.timer.restore_action(a));
+        regs.pcr_present = snapshot.pcr_present;
+    }
+
     /// Export the PCR hash digest
     pub fn pcr_hash_digest(&self) -> [u8; 48] {
         self.regs
@@ -727,6 +792,29 @@ impl Bus for HashSha512 {
     }
 }
 
+/// Saved state of the SHA-512 engine, returned by [`HashSha512::snapshot`]
+#[derive(Clone)]
+pub struct HashSha512Snapshot {
+    control: ReadWriteRegister<u32, Control::Register>,
+    status: ReadOnlyRegister<u32, Status::Register>,
+    block: [u32; SHA512_BLOCK_SIZE_WORDS],
+    hash: ReadOnlyMemory<SHA512_HASH_SIZE>,
+    block_read_ctrl: ReadWriteRegister<u32, BlockReadControl::Register>,
+    block_read_status: ReadOnlyRegister<u32, BlockReadStatus::Register>,
+    hash_write_ctrl: ReadWriteRegister<u32, HashWriteControl::Register>,
+    hash_write_status: ReadOnlyRegister<u32, HashWriteStatus::Register>,
+    pcr_gen_hash_nonce: [u32; 8],
+    pcr_hash_control: WriteOnlyRegister<u32, PcrHashControl::Register>,
+    pcr_hash_status: ReadOnlyRegister<u32, PcrHashStatus::Register>,
+    pcr_hash_digest: [u32; SHA384_HASH_SIZE / 4],
+    sha512: Sha512,
+    op_complete_action: Option<ActionSnapshot>,
+    op_block_read_complete_action: Option<ActionSnapshot>,
+    op_hash_write_complete_action: Option<ActionSnapshot>,
+    op_pcr_gen_hash_complete_action: Option<ActionSnapshot>,
+    pcr_present: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
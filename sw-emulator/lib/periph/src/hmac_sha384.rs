@@ -14,7 +14,9 @@ Abstract:
 
 use crate::helpers::bytes_from_words_le;
 use crate::{KeyUsage, KeyVault};
-use caliptra_emu_bus::{ActionHandle, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Timer};
+use caliptra_emu_bus::{
+    ActionHandle, ActionSnapshot, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Timer,
+};
 use caliptra_emu_crypto::EndianessTransform;
 use caliptra_emu_crypto::{Hmac512, Hmac512Mode};
 use caliptra_emu_derive::Bus;
@@ -206,6 +208,31 @@ pub struct HmacSha384 {
     op_tag_write_complete_action: Option<ActionHandle>,
 }
 
+/// Saved state of the HMAC engine, returned by [`HmacSha384::snapshot`]
+#[derive(Clone)]
+pub struct HmacSha384Snapshot {
+    control: ReadWriteRegister<u32, Control::Register>,
+    status: ReadOnlyRegister<u32, Status::Register>,
+    key: [u32; HMAC_KEY_SIZE / 4],
+    block: [u32; HMAC_BLOCK_SIZE / 4],
+    tag: [u32; HMAC_TAG_SIZE / 4],
+    lfsr_seed: [u32; HMAC_LFSR_SEED_SIZE / 4],
+    key_read_ctrl: ReadWriteRegister<u32, KeyReadControl::Register>,
+    key_read_status: ReadOnlyRegister<u32, KeyReadStatus::Register>,
+    block_read_ctrl: ReadWriteRegister<u32, KeyReadControl::Register>,
+    block_read_status: ReadOnlyRegister<u32, KeyReadStatus::Register>,
+    tag_write_ctrl: ReadWriteRegister<u32, TagWriteControl::Register>,
+    tag_write_status: ReadOnlyRegister<u32, TagWriteStatus::Register>,
+    key_from_kv: bool,
+    block_from_kv: bool,
+    hide_tag_from_cpu: bool,
+    hmac: Hmac512<HMAC_KEY_SIZE>,
+    op_complete_action: Option<ActionSnapshot>,
+    op_key_read_complete_action: Option<ActionSnapshot>,
+    op_block_read_complete_action: Option<ActionSnapshot>,
+    op_tag_write_complete_action: Option<ActionSnapshot>,
+}
+
 impl HmacSha384 {
     /// NAME0 Register Value
     const NAME0_VAL: RvData = 0x63616d68; // hmac
@@ -260,6 +287,73 @@ impl HmacSha384 {
         }
     }
 
+    /// Capture the registers, HMAC engine state and pending operations
+    pub fn snapshot(&self) -> HmacSha384Snapshot {
+        HmacSha384Snapshot {
+            control: self.control.clone(),
+            status: self.status.clone(),
+            key: self.key,
+            block: self.block,
+            tag: self.tag,
+            lfsr_seed: self.lfsr_seed,
+            key_read_ctrl: self.key_read_ctrl.clone(),
+            key_read_status: self.key_read_status.clone(),
+            block_read_ctrl: self.block_read_ctrl.clone(),
+            block_read_status: self.block_read_status.clone(),
+            tag_write_ctrl: self.tag_write_ctrl.clone(),
+            tag_write_status: self.tag_write_status.clone(),
+            key_from_kv: self.key_from_kv,
+            block_from_kv: self.block_from_kv,
+            hide_tag_from_cpu: self.hide_tag_from_cpu,
+            hmac: self.hmac.clone(),
+            op_complete_action: self.op_complete_action.as_ref().map(ActionHandle::snapshot),
+            op_key_read_complete_action: self
+                .op_key_read_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_block_read_complete_action: self
+                .op_block_read_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_tag_write_complete_action: self
+                .op_tag_write_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+        }
+    }
+
+    /// Restore the state captured with [`HmacSha384::snapshot`].
+    pub fn restore(&mut self, snapshot: &HmacSha384Snapshot) {
+        self.control = snapshot.control.clone();
+        self.status = snapshot.status.clone();
+        self.key = snapshot.key;
+        self.block = snapshot.block;
+        self.tag = snapshot.tag;
+        self.lfsr_seed = snapshot.lfsr_seed;
+        self.key_read_ctrl = snapshot.key_read_ctrl.clone();
+        self.key_read_status = snapshot.key_read_status.clone();
+        self.block_read_ctrl = snapshot.block_read_ctrl.clone();
+        self.block_read_status = snapshot.block_read_status.clone();
+        self.tag_write_ctrl = snapshot.tag_write_ctrl.clone();
+        self.tag_write_status = snapshot.tag_write_status.clone();
+        self.key_from_kv = snapshot.key_from_kv;
+        self.block_from_kv = snapshot.block_from_kv;
+        self.hide_tag_from_cpu = snapshot.hide_tag_from_cpu;
+        self.hmac = snapshot.hmac.clone();
+        self.op_complete_action = snapshot
+            .op_complete_action
+            .map(|a| self.timer.restore_action(a));
+        self.op_key_read_complete_action = snapshot
+            .op_key_read_complete_action
+            .map(|a| self.timer.restore_action(a));
+        self.op_block_read_complete_action = snapshot
+            .op_block_read_complete_action
+            .map(|a| self.timer.restore_action(a));
+        self.op_tag_write_complete_action = snapshot
+            .op_tag_write_complete_action
+            .map(|a| self.timer.restore_action(a));
+    }
+
     fn read_access_fault(&mut self, _size: RvSize, _index: usize) -> Result<u32, BusError> {
         Err(BusError::LoadAccessFault)
     }
@@ -47,6 +47,27 @@ impl Iccm {
     pub fn ram(&self) -> &RefCell<Ram> {
         &self.iccm.ram
     }
+
+    /// Capture the ICCM contents and lock state
+    pub fn snapshot(&self) -> IccmSnapshot {
+        IccmSnapshot {
+            ram: self.iccm.ram.borrow().clone(),
+            locked: self.iccm.locked.get(),
+        }
+    }
+
+    /// Restore the state captured with [`Iccm::snapshot`]
+    pub fn restore(&mut self, snapshot: &IccmSnapshot) {
+        *self.iccm.ram.borrow_mut() = snapshot.ram.clone();
+        self.iccm.locked.set(snapshot.locked);
+    }
+}
+
+/// Saved state of the ICCM, returned by [`Iccm::snapshot`]
+#[derive(Clone)]
+pub struct IccmSnapshot {
+    ram: Ram,
+    locked: bool,
 }
 
 struct IccmImpl {
@@ -0,0 +1,342 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    jtag.rs
+
+Abstract:
+
+    File contains the JTAG TAP and RISC-V Debug Transport Module that give a
+    debugger access to the Debug Module and the Caliptra JTAG registers.
+
+--*/
+
+use crate::SocRegistersInternal;
+use caliptra_emu_bus::Bus;
+use caliptra_emu_cpu::{Cpu, DebugModule, DmReg};
+
+/// A Debug Module Interface, as seen by the Debug Transport Module.
+pub trait Dmi {
+    fn dmi_read(&mut self, addr: u32) -> u32;
+    fn dmi_write(&mut self, addr: u32, val: u32);
+}
+
+/// The DMI of Caliptra's JTAG port: the VeeR Debug Module followed by the
+/// Caliptra JTAG registers.
+///
+/// When debug is locked, the Caliptra registers are only accessible in the
+/// Manufacturing lifecycle state, and the Debug Module is not accessible at
+/// all (except in Manufacturing on 1.0 hardware). Inaccessible registers read
+/// as zero and ignore writes.
+pub struct CaliptraDmi<'a, TBus: Bus> {
+    pub cpu: &'a mut Cpu<TBus>,
+    pub dm: &'a mut DebugModule,
+    /// The `soc_ifc` registers of the bus `cpu` is attached to
+    pub soc_reg: SocRegistersInternal,
+}
+
+impl<TBus: Bus> CaliptraDmi<'_, TBus> {
+    fn caliptra_regs_accessible(&self) -> bool {
+        !self.soc_reg.is_debug_locked() || self.soc_reg.is_manufacturing()
+    }
+
+    fn core_accessible(&self) -> bool {
+        !self.soc_reg.is_debug_locked()
+            || (cfg!(feature = "hw-1.0") && self.soc_reg.is_manufacturing())
+    }
+}
+
+impl<TBus: Bus> Dmi for CaliptraDmi<'_, TBus> {
+    fn dmi_read(&mut self, addr: u32) -> u32 {
+        if addr <= DmReg::HALTSUM0 {
+            match self.core_accessible() {
+                true => self.dm.dmi_read(self.cpu, addr),
+                false => 0,
+            }
+        } else if self.caliptra_regs_accessible() {
+            self.soc_reg.jtag_read(addr).unwrap_or(0)
+        } else {
+            0
+        }
+    }
+
+    fn dmi_write(&mut self, addr: u32, val: u32) {
+        if addr <= DmReg::HALTSUM0 {
+            if self.core_accessible() {
+                self.dm.dmi_write(self.cpu, addr, val);
+            }
+        } else if self.caliptra_regs_accessible() {
+            self.soc_reg.jtag_write(addr, val);
+        }
+    }
+}
+
+/// TAP controller states
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TapState {
+    TestLogicReset,
+    RunTestIdle,
+    SelectDrScan,
+    CaptureDr,
+    ShiftDr,
+    Exit1Dr,
+    PauseDr,
+    Exit2Dr,
+    UpdateDr,
+    SelectIrScan,
+    CaptureIr,
+    ShiftIr,
+    Exit1Ir,
+    PauseIr,
+    Exit2Ir,
+    UpdateIr,
+}
+
+impl TapState {
+    fn next(self, tms: bool) -> Self {
+        use TapState::*;
+        match (self, tms) {
+            (TestLogicReset, false) => RunTestIdle,
+            (TestLogicReset, true) => TestLogicReset,
+            (RunTestIdle, false) => RunTestIdle,
+            (RunTestIdle, true) => SelectDrScan,
+            (SelectDrScan, false) => CaptureDr,
+            (SelectDrScan, true) => SelectIrScan,
+            (CaptureDr, false) => ShiftDr,
+            (CaptureDr, true) => Exit1Dr,
+            (ShiftDr, false) => ShiftDr,
+            (ShiftDr, true) => Exit1Dr,
+            (Exit1Dr, false) => PauseDr,
+            (Exit1Dr, true) => UpdateDr,
+            (PauseDr, false) => PauseDr,
+            (PauseDr, true) => Exit2Dr,
+            (Exit2Dr, false) => ShiftDr,
+            (Exit2Dr, true) => UpdateDr,
+            (UpdateDr, false) => RunTestIdle,
+            (UpdateDr, true) => SelectDrScan,
+            (SelectIrScan, false) => CaptureIr,
+            (SelectIrScan, true) => TestLogicReset,
+            (CaptureIr, false) => ShiftIr,
+            (CaptureIr, true) => Exit1Ir,
+            (ShiftIr, false) => ShiftIr,
+            (ShiftIr, true) => Exit1Ir,
+            (Exit1Ir, false) => PauseIr,
+            (Exit1Ir, true) => UpdateIr,
+            (PauseIr, false) => PauseIr,
+            (PauseIr, true) => Exit2Ir,
+            (Exit2Ir, false) => ShiftIr,
+            (Exit2Ir, true) => UpdateIr,
+            (UpdateIr, false) => RunTestIdle,
+            (UpdateIr, true) => SelectDrScan,
+        }
+    }
+}
+
+/// JTAG instruction register width
+const IR_LEN: u32 = 5;
+
+/// JTAG instructions
+const IR_IDCODE: u32 = 0x01;
+const IR_DTMCS: u32 = 0x10;
+const IR_DMI: u32 = 0x11;
+
+/// Identifies the emulated TAP. Debuggers don't check it unless told to.
+const IDCODE: u32 = 0x1000_0001;
+
+/// Width of a DMI address
+const DMI_ABITS: u32 = 7;
+
+/// `dtmcs` value: version 0.13, `abits` and no idle cycles needed
+const DTMCS: u32 = (DMI_ABITS << 4) | 1;
+
+/// `dmi.op` values
+const DMI_OP_READ: u64 = 1;
+const DMI_OP_WRITE: u64 = 2;
+
+/// JTAG TAP controller with the RISC-V Debug Transport Module registers.
+pub struct JtagTap {
+    state: TapState,
+    ir: u32,
+    shift: u64,
+    /// Data returned by the last DMI operation
+    dmi_data: u32,
+    dmi_addr: u32,
+}
+
+impl Default for JtagTap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JtagTap {
+    pub fn new() -> Self {
+        Self {
+            state: TapState::TestLogicReset,
+            ir: IR_IDCODE,
+            shift: 0,
+            dmi_data: 0,
+            dmi_addr: 0,
+        }
+    }
+
+    /// Asynchronously reset the TAP, as `TRST` does
+    pub fn reset(&mut self) {
+        self.state = TapState::TestLogicReset;
+        self.ir = IR_IDCODE;
+    }
+
+    /// The value of `TDO` until the next rising edge of `TCK`
+    pub fn tdo(&self) -> bool {
+        matches!(self.state, TapState::ShiftDr | TapState::ShiftIr) && self.shift & 1 != 0
+    }
+
+    fn dr_len(&self) -> u32 {
+        match self.ir {
+            IR_IDCODE | IR_DTMCS => 32,
+            IR_DMI => DMI_ABITS + 34,
+            // BYPASS and unimplemented instructions
+            _ => 1,
+        }
+    }
+
+    /// Clock the TAP on a rising edge of `TCK`.
+    pub fn clock(&mut self, tms: bool, tdi: bool, dmi: &mut impl Dmi) {
+        match self.state {
+            TapState::ShiftDr => {
+                let len = self.dr_len();
+                self.shift = (self.shift >> 1) | (u64::from(tdi) << (len - 1));
+            }
+            TapState::ShiftIr => {
+                self.shift = (self.shift >> 1) | (u64::from(tdi) << (IR_LEN - 1));
+            }
+            _ => {}
+        }
+
+        self.state = self.state.next(tms);
+
+        match self.state {
+            TapState::TestLogicReset => self.ir = IR_IDCODE,
+            TapState::CaptureIr => self.shift = 0b00001,
+            TapState::UpdateIr => self.ir = self.shift as u32 & ((1 << IR_LEN) - 1),
+            TapState::CaptureDr => {
+                self.shift = match self.ir {
+                    IR_IDCODE => IDCODE.into(),
+                    IR_DTMCS => DTMCS.into(),
+                    IR_DMI => (u64::from(self.dmi_addr) << 34) | (u64::from(self.dmi_data) << 2),
+                    _ => 0,
+                }
+            }
+            // dmireset and dmihardreset in dtmcs have nothing to clear, as DMI
+            // operations complete immediately.
+            TapState::UpdateDr if self.ir == IR_DMI => self.update_dmi(dmi),
+            _ => {}
+        }
+    }
+
+    fn update_dmi(&mut self, dmi: &mut impl Dmi) {
+        let op = self.shift & 0x3;
+        let data = (self.shift >> 2) as u32;
+        let addr = (self.shift >> 34) as u32 & ((1 << DMI_ABITS) - 1);
+        match op {
+            DMI_OP_READ => {
+                self.dmi_addr = addr;
+                self.dmi_data = dmi.dmi_read(addr);
+            }
+            DMI_OP_WRITE => {
+                self.dmi_addr = addr;
+                dmi.dmi_write(addr, data);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct FakeDmi(HashMap<u32, u32>);
+
+    impl Dmi for FakeDmi {
+        fn dmi_read(&mut self, addr: u32) -> u32 {
+            self.0.get(&addr).copied().unwrap_or(0)
+        }
+        fn dmi_write(&mut self, addr: u32, val: u32) {
+            self.0.insert(addr, val);
+        }
+    }
+
+    fn clock_tms(tap: &mut JtagTap, dmi: &mut FakeDmi, tms: &[u8]) {
+        for &tms in tms {
+            tap.clock(tms != 0, false, dmi);
+        }
+    }
+
+    /// Shift `len` bits of `val` through the current shift state, returning
+    /// the bits shifted out, and leave through Exit1.
+    fn shift(tap: &mut JtagTap, dmi: &mut FakeDmi, val: u64, len: u32) -> u64 {
+        let mut out = 0;
+        for i in 0..len {
+            out |= u64::from(tap.tdo()) << i;
+            tap.clock(i == len - 1, (val >> i) & 1 != 0, dmi);
+        }
+        out
+    }
+
+    fn scan_ir(tap: &mut JtagTap, dmi: &mut FakeDmi, ir: u32) {
+        // Run-Test/Idle -> Shift-IR
+        clock_tms(tap, dmi, &[1, 1, 0, 0]);
+        shift(tap, dmi, ir.into(), IR_LEN);
+        // Exit1-IR -> Update-IR -> Run-Test/Idle
+        clock_tms(tap, dmi, &[1, 0]);
+    }
+
+    fn scan_dr(tap: &mut JtagTap, dmi: &mut FakeDmi, val: u64, len: u32) -> u64 {
+        // Run-Test/Idle -> Shift-DR
+        clock_tms(tap, dmi, &[1, 0, 0]);
+        let out = shift(tap, dmi, val, len);
+        // Exit1-DR -> Update-DR -> Run-Test/Idle
+        clock_tms(tap, dmi, &[1, 0]);
+        out
+    }
+
+    #[test]
+    fn test_idcode_and_dtmcs() {
+        let mut tap = JtagTap::new();
+        let mut dmi = FakeDmi::default();
+        clock_tms(&mut tap, &mut dmi, &[1, 1, 1, 1, 1, 0]);
+        assert_eq!(scan_dr(&mut tap, &mut dmi, 0, 32), IDCODE.into());
+        scan_ir(&mut tap, &mut dmi, IR_DTMCS);
+        assert_eq!(scan_dr(&mut tap, &mut dmi, 0, 32), 0x71);
+        // BYPASS
+        scan_ir(&mut tap, &mut dmi, 0x1f);
+        assert_eq!(scan_dr(&mut tap, &mut dmi, 1, 1), 0);
+    }
+
+    #[test]
+    fn test_dmi_access() {
+        let mut tap = JtagTap::new();
+        let mut dmi = FakeDmi::default();
+        clock_tms(&mut tap, &mut dmi, &[1, 1, 1, 1, 1, 0]);
+        scan_ir(&mut tap, &mut dmi, IR_DMI);
+
+        let dmi_scan = |addr: u64, data: u64, op: u64| (addr << 34) | (data << 2) | op;
+        scan_dr(
+            &mut tap,
+            &mut dmi,
+            dmi_scan(0x60, 0x1234_5678, DMI_OP_WRITE),
+            41,
+        );
+        assert_eq!(dmi.0.get(&0x60), Some(&0x1234_5678));
+
+        scan_dr(&mut tap, &mut dmi, dmi_scan(0x60, 0, DMI_OP_READ), 41);
+        // The result is captured by the next scan, with status 0 (success)
+        let out = scan_dr(&mut tap, &mut dmi, 0, 41);
+        assert_eq!(out, dmi_scan(0x60, 0x1234_5678, 0));
+    }
+}
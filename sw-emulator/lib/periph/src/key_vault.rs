@@ -207,7 +207,21 @@ impl KeyVault {
             .borrow_mut()
             .clear_with_debug_values(sel_debug_value);
     }
+
+    /// Capture the keys, PCRs, data vault and scratch registers
+    pub fn snapshot(&self) -> KeyVaultSnapshot {
+        KeyVaultSnapshot(self.regs.borrow().clone())
+    }
+
+    /// Restore the state captured with [`KeyVault::snapshot`]
+    pub fn restore(&mut self, snapshot: &KeyVaultSnapshot) {
+        *self.regs.borrow_mut() = snapshot.0.clone();
+    }
 }
+
+/// Saved state of the key vault, returned by [`KeyVault::snapshot`]
+#[derive(Clone)]
+pub struct KeyVaultSnapshot(KeyVaultRegs);
 impl Default for KeyVault {
     fn default() -> Self {
         Self::new()
@@ -253,6 +267,9 @@ bitfield! {
 
     /// Flag indicating if the key can be used aas ECC Key Generation Seed
     pub ecc_key_gen_seed, set_ecc_key_gen_seed: 4;
+
+    /// Flag indicating if the key can be used as ML-DSA-87 Key Generation Seed
+    pub mldsa_key_gen_seed, set_mldsa_key_gen_seed: 5;
 }
 
 impl From<KeyUsage> for u32 {
@@ -305,7 +322,7 @@ use constants::*;
 use crate::helpers::{bytes_from_words_le, words_from_bytes_le};
 
 /// Key Vault Peripheral
-#[derive(Bus)]
+#[derive(Bus, Clone)]
 #[warm_reset_fn(warm_reset)]
 #[update_reset_fn(update_reset)]
 pub struct KeyVaultRegs {
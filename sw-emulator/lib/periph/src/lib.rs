@@ -23,30 +23,35 @@ mod hash_sha512;
 mod helpers;
 mod hmac_sha384;
 mod iccm;
+mod jtag;
 mod key_vault;
 mod mailbox;
-#[cfg(feature = "hw-2.x")]
+#[cfg(all(feature = "hw-2.x", not(feature = "hw-1.0")))]
 mod ml_dsa87;
 mod root_bus;
 mod sha512_acc;
 pub mod soc_reg;
 mod uart;
 
-pub use asym_ecc384::AsymEcc384;
-pub use csrng::Csrng;
-pub use doe::Doe;
+pub use asym_ecc384::{AsymEcc384, AsymEcc384Snapshot};
+pub use csrng::{Csrng, CsrngSnapshot};
+pub use doe::{Doe, DoeSnapshot};
 pub use emu_ctrl::EmuCtrl;
-pub use hash_sha256::HashSha256;
-pub use hash_sha512::HashSha512;
-pub use hmac_sha384::HmacSha384;
-pub use iccm::Iccm;
+pub use hash_sha256::{HashSha256, HashSha256Snapshot};
+pub use hash_sha512::{HashSha512, HashSha512Snapshot};
+pub use hmac_sha384::{HmacSha384, HmacSha384Snapshot};
+pub use iccm::{Iccm, IccmSnapshot};
+pub use jtag::{CaliptraDmi, Dmi, JtagTap};
 pub use key_vault::KeyUsage;
 pub use key_vault::KeyVault;
-pub use mailbox::{MailboxExternal, MailboxInternal, MailboxRam, MailboxRequester};
+pub use key_vault::KeyVaultSnapshot;
+pub use mailbox::{
+    MailboxExternal, MailboxInternal, MailboxRam, MailboxRequester, MailboxSnapshot,
+};
 pub use root_bus::{
-    ActionCb, CaliptraRootBus, CaliptraRootBusArgs, DownloadIdevidCsrCb, ReadyForFwCb,
-    SocToCaliptraBus, TbServicesCb, UploadUpdateFwCb,
+    ActionCb, CaliptraRootBus, CaliptraRootBusArgs, CaliptraRootBusSnapshot, DownloadIdevidCsrCb,
+    ReadyForFwCb, SnapshotError, SocToCaliptraBus, TbServicesCb, UploadUpdateFwCb,
 };
-pub use sha512_acc::Sha512Accelerator;
-pub use soc_reg::SocRegistersInternal;
+pub use sha512_acc::{Sha512Accelerator, Sha512AcceleratorSnapshot};
+pub use soc_reg::{JtagReg, SocRegistersInternal};
 pub use uart::Uart;
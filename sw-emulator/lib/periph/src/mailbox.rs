@@ -18,7 +18,7 @@ use caliptra_emu_bus::{BusError, ReadOnlyRegister, ReadWriteRegister, WriteOnlyR
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvAddr, RvData, RvSize};
 use std::{cell::RefCell, rc::Rc};
-use tock_registers::interfaces::Writeable;
+use tock_registers::interfaces::{Readable, Writeable};
 use tock_registers::{register_bitfields, LocalRegisterCopy};
 
 /// Maximum mailbox capacity.
@@ -162,6 +162,96 @@ impl MailboxInternal {
         }
         false
     }
+
+    /// Capture the mailbox state machine, registers and SRAM contents
+    pub fn snapshot(&self) -> MailboxSnapshot {
+        let regs = self.regs.borrow();
+        let context = &regs.state_machine.context;
+        let ram = context.fifo.mailbox_ram.ram.borrow().clone();
+        MailboxSnapshot {
+            ram,
+            state: copy_state(&regs.state_machine.state),
+            locked: context.locked,
+            user: context.user,
+            exec: context.exec,
+            dlen: context.dlen,
+            status: context.status.get(),
+            cmd: context.cmd,
+            data_out: context.data_out,
+            unlock: context.unlock,
+            latched_dlen: context.fifo.latched_dlen,
+            read_index: context.fifo.read_index,
+            write_index: context.fifo.write_index,
+            execute: regs.execute.reg.get(),
+            requester: regs.requester,
+            irq: regs.irq,
+        }
+    }
+
+    /// Restore the state captured with [`MailboxInternal::snapshot`]
+    pub fn restore(&mut self, snapshot: &MailboxSnapshot) {
+        let mut regs = self.regs.borrow_mut();
+        regs.state_machine.state = copy_state(&snapshot.state);
+        regs.execute.reg.set(snapshot.execute);
+        regs.requester = snapshot.requester;
+        regs.irq = snapshot.irq;
+
+        let context = &mut regs.state_machine.context;
+        *context.fifo.mailbox_ram.ram.borrow_mut() = snapshot.ram.clone();
+        context.locked = snapshot.locked;
+        context.user = snapshot.user;
+        context.exec = snapshot.exec;
+        context.dlen = snapshot.dlen;
+        context.status.set(snapshot.status);
+        context.cmd = snapshot.cmd;
+        context.data_out = snapshot.data_out;
+        context.unlock = snapshot.unlock;
+        context.fifo.latched_dlen = snapshot.latched_dlen;
+        context.fifo.read_index = snapshot.read_index;
+        context.fifo.write_index = snapshot.write_index;
+    }
+}
+
+/// Saved state of the mailbox, returned by [`MailboxInternal::snapshot`]
+pub struct MailboxSnapshot {
+    ram: Ram,
+    state: States,
+    locked: u32,
+    user: MailboxRequester,
+    exec: bool,
+    dlen: u32,
+    status: u32,
+    cmd: u32,
+    data_out: u32,
+    unlock: u32,
+    latched_dlen: u32,
+    read_index: usize,
+    write_index: usize,
+    execute: u32,
+    requester: MailboxRequester,
+    irq: bool,
+}
+
+impl Clone for MailboxSnapshot {
+    fn clone(&self) -> Self {
+        Self {
+            ram: self.ram.clone(),
+            state: copy_state(&self.state),
+            ..*self
+        }
+    }
+}
+
+fn copy_state(state: &States) -> States {
+    match state {
+        States::Idle => States::Idle,
+        States::RdyForCmd => States::RdyForCmd,
+        States::RdyForDlen => States::RdyForDlen,
+        States::RdyForData => States::RdyForData,
+        States::ExecUc => States::ExecUc,
+        States::ExecSoc => States::ExecSoc,
+        States::Error => States::Error,
+    }
 }
 
 impl Bus for MailboxInternal {
@@ -12,7 +12,9 @@ File contains Ml_Dsa87 peripheral implementation.
 
 --*/
 
-use caliptra_emu_bus::{ActionHandle, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Timer};
+use caliptra_emu_bus::{
+    ActionHandle, ActionSnapshot, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Timer,
+};
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvData, RvSize};
 use fips204::ml_dsa_87::{try_keygen_with_rng, PrivateKey, PublicKey, PK_LEN, SIG_LEN, SK_LEN};
@@ -21,8 +23,10 @@ use rand::rngs::StdRng;
 use rand::SeedableRng;
 use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
 use tock_registers::register_bitfields;
+use tock_registers::registers::InMemoryRegister;
 
 use crate::helpers::{bytes_from_words_le, words_from_bytes_le};
+use crate::{KeyUsage, KeyVault};
 
 /// ML_DSA87 Initialization Vector size
 const ML_DSA87_IV_SIZE: usize = 64;
@@ -42,19 +46,22 @@ const ML_DSA87_VERIFICATION_SIZE: usize = 64;
 /// The number of CPU clock cycles it takes to perform Ml_Dsa87 operation
 const ML_DSA87_OP_TICKS: u64 = 1000;
 
+/// The number of CPU clock cycles read and write keys from key vault
+const KEY_RW_TICKS: u64 = 100;
+
 register_bitfields! [
     u32,
 
     /// Control Register Fields
     Control [
-        CTRL OFFSET(0) NUMBITS(2) [
-            IDLE = 0b00,
-            GEN_KEY = 0b01,
-            SIGN = 0b10,
-            VERIFY = 0b11,
+        CTRL OFFSET(0) NUMBITS(3) [
+            IDLE = 0b000,
+            GEN_KEY = 0b001,
+            SIGN = 0b010,
+            VERIFY = 0b011,
+            GEN_KEY_SIGN = 0b100,
         ],
-        ZEROIZE OFFSET(2) NUMBITS(1) [],
-//        PCR_SIGN OFFSET(3) NUMBITS(1) [] TODO remove right??
+        ZEROIZE OFFSET(3) NUMBITS(1) [],
     ],
 
     /// Status Register Fields
@@ -63,6 +70,26 @@ register_bitfields! [
         VALID OFFSET(1) NUMBITS(1) [],
         RSVD OFFSET(2) NUMBITS(30) [],
     ],
+
+    /// Key Control Register Fields
+    KeyReadControl[
+        KEY_READ_EN OFFSET(0) NUMBITS(1) [],
+        KEY_ID OFFSET(1) NUMBITS(5) [],
+        PCR_HASH_EXTEND OFFSET(6) NUMBITS(1) [],
+        RSVD OFFSET(7) NUMBITS(25) [],
+    ],
+
+    /// Key Status Register Fields
+    KeyReadStatus[
+        READY OFFSET(0) NUMBITS(1) [],
+        VALID OFFSET(1) NUMBITS(1) [],
+        ERROR OFFSET(2) NUMBITS(8) [
+            KV_SUCCESS = 0,
+            KV_READ_FAIL = 1,
+            KV_WRITE_FAIL= 2,
+        ],
+        RSVD OFFSET(10) NUMBITS(22) [],
+    ],
 ];
 
 #[derive(Bus)]
@@ -122,11 +149,50 @@ pub struct MlDsa87 {
     #[register_array(offset = 0x0000_3400)]
     signature: [u32; SIG_LEN / 4 + 1], // Signature len is unaligned
 
+    /// Seed Read Control Register
+    #[register(offset = 0x0000_4620, write_fn = on_write_seed_read_control)]
+    seed_read_ctrl: ReadWriteRegister<u32, KeyReadControl::Register>,
+
+    /// Seed Read Status Register
+    #[register(offset = 0x0000_4624)]
+    seed_read_status: ReadOnlyRegister<u32, KeyReadStatus::Register>,
+
+    /// The seed was read from the key vault, so the secret key must not be
+    /// made available to the uC
+    seed_from_kv: bool,
+
+    /// Key Vault
+    key_vault: KeyVault,
+
     /// Timer
     timer: Timer,
 
     /// Operation complete callback
     op_complete_action: Option<ActionHandle>,
+
+    /// Seed read complete action
+    op_seed_read_complete_action: Option<ActionHandle>,
+}
+
+/// Saved state of the ML-DSA-87 engine, returned by [`MlDsa87::snapshot`]
+#[derive(Clone)]
+pub struct MlDsa87Snapshot {
+    control: ReadWriteRegister<u32, Control::Register>,
+    status: ReadOnlyRegister<u32, Status::Register>,
+    iv: [u32; ML_DSA87_IV_SIZE / 4],
+    seed: [u32; ML_DSA87_SEED_SIZE / 4],
+    sign_rnd: [u32; ML_DSA87_SIGN_RND_SIZE / 4],
+    message: [u32; ML_DSA87_MSG_SIZE / 4],
+    verification_result: [u32; ML_DSA87_VERIFICATION_SIZE / 4],
+    sk_out: [u32; SK_LEN / 4],
+    sk_in: [u32; SK_LEN / 4],
+    pk: [u32; PK_LEN / 4],
+    signature: [u32; SIG_LEN / 4 + 1],
+    seed_read_ctrl: ReadWriteRegister<u32, KeyReadControl::Register>,
+    seed_read_status: ReadOnlyRegister<u32, KeyReadStatus::Register>,
+    seed_from_kv: bool,
+    op_complete_action: Option<ActionSnapshot>,
+    op_seed_read_complete_action: Option<ActionSnapshot>,
 }
 
 impl MlDsa87 {
@@ -142,7 +208,7 @@ impl MlDsa87 {
     /// VERSION1 Register Value TODO update when known
     const VERSION1_VAL: RvData = 0x00000000;
 
-    pub fn new(clock: &Clock) -> Self {
+    pub fn new(clock: &Clock, key_vault: KeyVault) -> Self {
         Self {
             name: [Self::NAME0_VAL, Self::NAME1_VAL],
             version: [Self::VERSION0_VAL, Self::VERSION1_VAL],
@@ -157,11 +223,65 @@ impl MlDsa87 {
             sk_in: [0; 1224],
             pk: [0; 648],
             signature: [0; 1157],
+            seed_read_ctrl: ReadWriteRegister::new(0),
+            seed_read_status: ReadOnlyRegister::new(KeyReadStatus::READY::SET.value),
+            seed_from_kv: false,
+            key_vault,
             timer: Timer::new(clock),
             op_complete_action: None,
+            op_seed_read_complete_action: None,
+        }
+    }
+
+    /// Capture the registers, keys, signature and pending operations
+    pub fn snapshot(&self) -> MlDsa87Snapshot {
+        MlDsa87Snapshot {
+            control: self.control.clone(),
+            status: self.status.clone(),
+            iv: self.iv,
+            seed: self.seed,
+            sign_rnd: self.sign_rnd,
+            message: self.message,
+            verification_result: self.verification_result,
+            sk_out: self.sk_out,
+            sk_in: self.sk_in,
+            pk: self.pk,
+            signature: self.signature,
+            seed_read_ctrl: self.seed_read_ctrl.clone(),
+            seed_read_status: self.seed_read_status.clone(),
+            seed_from_kv: self.seed_from_kv,
+            op_complete_action: self.op_complete_action.as_ref().map(ActionHandle::snapshot),
+            op_seed_read_complete_action: self
+                .op_seed_read_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
         }
     }
 
+    /// Restore the state captured with [`MlDsa87::snapshot`].
+    pub fn restore(&mut self, snapshot: &MlDsa87Snapshot) {
+        self.control = snapshot.control.clone();
+        self.status = snapshot.status.clone();
+        self.iv = snapshot.iv;
+        self.seed = snapshot.seed;
+        self.sign_rnd = snapshot.sign_rnd;
+        self.message = snapshot.message;
+        self.verification_result = snapshot.verification_result;
+        self.sk_out = snapshot.sk_out;
+        self.sk_in = snapshot.sk_in;
+        self.pk = snapshot.pk;
+        self.signature = snapshot.signature;
+        self.seed_read_ctrl = snapshot.seed_read_ctrl.clone();
+        self.seed_read_status = snapshot.seed_read_status.clone();
+        self.seed_from_kv = snapshot.seed_from_kv;
+        self.op_complete_action = snapshot
+            .op_complete_action
+            .map(|a| self.timer.restore_action(a));
+        self.op_seed_read_complete_action = snapshot
+            .op_seed_read_complete_action
+            .map(|a| self.timer.restore_action(a));
+    }
+
     fn read_access_fault(&self, _size: RvSize, _index: usize) -> Result<RvData, BusError> {
         Err(BusError::LoadAccessFault)
     }
@@ -175,8 +295,19 @@ impl MlDsa87 {
         Err(BusError::StoreAccessFault)
     }
 
-    // TODO Clear registers
-    fn zeroize(&mut self) {}
+    // Clear registers
+    fn zeroize(&mut self) {
+        self.iv.fill(0);
+        self.seed.fill(0);
+        self.sign_rnd.fill(0);
+        self.message.fill(0);
+        self.verification_result.fill(0);
+        self.sk_out.fill(0);
+        self.sk_in.fill(0);
+        self.pk.fill(0);
+        self.signature.fill(0);
+        self.seed_from_kv = false;
+    }
 
     /// On Write callback for `control` register
     ///
@@ -200,7 +331,8 @@ impl MlDsa87 {
         match self.control.reg.read_as_enum(Control::CTRL) {
             Some(Control::CTRL::Value::GEN_KEY)
             | Some(Control::CTRL::Value::SIGN)
-            | Some(Control::CTRL::Value::VERIFY) => {
+            | Some(Control::CTRL::Value::VERIFY)
+            | Some(Control::CTRL::Value::GEN_KEY_SIGN) => {
                 // Reset the Ready and Valid status bits
                 self.status
                     .reg
@@ -218,21 +350,115 @@ impl MlDsa87 {
         Ok(())
     }
 
-    fn gen_key(&mut self) {
+    /// On Write callback for `seed_read_control` register
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the write
+    /// * `val` - Data to write
+    ///
+    /// # Error
+    ///
+    /// * `BusError` - Exception with cause `BusError::StoreAccessFault` or `BusError::StoreAddrMisaligned`
+    pub fn on_write_seed_read_control(
+        &mut self,
+        size: RvSize,
+        val: RvData,
+    ) -> Result<(), BusError> {
+        // Writes have to be Word aligned
+        if size != RvSize::Word {
+            Err(BusError::StoreAccessFault)?
+        }
+
+        // Set the key control register
+        let seed_ctrl = InMemoryRegister::<u32, KeyReadControl::Register>::new(val);
+
+        self.seed_read_ctrl.reg.modify(
+            KeyReadControl::KEY_READ_EN.val(seed_ctrl.read(KeyReadControl::KEY_READ_EN))
+                + KeyReadControl::KEY_ID.val(seed_ctrl.read(KeyReadControl::KEY_ID)),
+        );
+
+        if seed_ctrl.is_set(KeyReadControl::KEY_READ_EN) {
+            self.seed_read_status.reg.modify(
+                KeyReadStatus::READY::CLEAR
+                    + KeyReadStatus::VALID::CLEAR
+                    + KeyReadStatus::ERROR::CLEAR,
+            );
+
+            self.op_seed_read_complete_action = Some(self.timer.schedule_poll_in(KEY_RW_TICKS));
+        }
+
+        Ok(())
+    }
+
+    fn seed_read_complete(&mut self) {
+        let key_id = self.seed_read_ctrl.reg.read(KeyReadControl::KEY_ID);
+
+        let mut key_usage = KeyUsage::default();
+        key_usage.set_mldsa_key_gen_seed(true);
+
+        let result = self.key_vault.read_key(key_id, key_usage);
+        let (seed_read_result, seed) = match result.err() {
+            Some(BusError::LoadAccessFault)
+            | Some(BusError::LoadAddrMisaligned)
+            | Some(BusError::InstrAccessFault) => (KeyReadStatus::ERROR::KV_READ_FAIL.value, None),
+            Some(BusError::StoreAccessFault) | Some(BusError::StoreAddrMisaligned) => {
+                (KeyReadStatus::ERROR::KV_WRITE_FAIL.value, None)
+            }
+            None => (
+                KeyReadStatus::ERROR::KV_SUCCESS.value,
+                Some(result.unwrap()),
+            ),
+        };
+
+        // The seed is the first 32 bytes of the key slot, in the same word
+        // order as when it is written by the uC.
+        if let Some(seed) = seed {
+            self.seed = words_from_bytes_le(
+                &<[u8; ML_DSA87_SEED_SIZE]>::try_from(&seed[..ML_DSA87_SEED_SIZE]).unwrap(),
+            );
+            self.seed_from_kv = true;
+        }
+
+        self.seed_read_status.reg.modify(
+            KeyReadStatus::READY::SET
+                + KeyReadStatus::VALID::SET
+                + KeyReadStatus::ERROR.val(seed_read_result),
+        );
+    }
+
+    fn key_pair(&self) -> (PublicKey, PrivateKey) {
         let seed_bytes = &bytes_from_words_le(&self.seed);
         let mut rng = StdRng::from_seed(*seed_bytes);
-        let (pk, sk) = try_keygen_with_rng(&mut rng).unwrap();
+        try_keygen_with_rng(&mut rng).unwrap()
+    }
+
+    fn gen_key(&mut self) {
+        let (pk, sk) = self.key_pair();
 
         self.pk = words_from_bytes_le(&pk.into_bytes());
-        self.sk_out = words_from_bytes_le(&sk.into_bytes());
+        // Keys derived from a key vault seed never leave the engine
+        if !self.seed_from_kv {
+            self.sk_out = words_from_bytes_le(&sk.into_bytes());
+        }
     }
 
     fn sign(&mut self) {
-        let seed_bytes = &bytes_from_words_le(&self.seed);
-        let mut rng = StdRng::from_seed(*seed_bytes);
-
         let secret_key_bytes = &bytes_from_words_le(&self.sk_in);
         let secret_key = PrivateKey::try_from_bytes(*secret_key_bytes).unwrap();
+        self.sign_with(&secret_key);
+    }
+
+    /// Generate the key pair from the seed and sign with the secret key,
+    /// without exposing it.
+    fn gen_key_sign(&mut self) {
+        let (_, secret_key) = self.key_pair();
+        self.sign_with(&secret_key);
+    }
+
+    fn sign_with(&mut self, secret_key: &PrivateKey) {
+        let sign_rnd_bytes = &bytes_from_words_le(&self.sign_rnd);
+        let mut rng = StdRng::from_seed(*sign_rnd_bytes);
 
         let message = &bytes_from_words_le(&self.message);
 
@@ -259,9 +485,20 @@ impl MlDsa87 {
 
         let result = public_key.verify(message, &signature[..SIG_LEN].try_into().unwrap());
 
-        self.verification_result
-            .iter_mut()
-            .for_each(|e| *e = if result { 1 } else { 0 });
+        // Like the ECC verify_r, the result is the commitment hash of a
+        // valid signature, which the uC compares against the signature. The
+        // hash computed for an invalid signature doesn't match it, which is
+        // modeled by inverting it, so that an all-zero signature can't match
+        // an all-zero result.
+        let commitment: [u32; ML_DSA87_VERIFICATION_SIZE / 4] = self.signature
+            [..ML_DSA87_VERIFICATION_SIZE / 4]
+            .try_into()
+            .unwrap();
+        self.verification_result = if result {
+            commitment
+        } else {
+            commitment.map(|word| !word)
+        };
     }
 
     fn op_complete(&mut self) {
@@ -269,6 +506,7 @@ impl MlDsa87 {
             Some(Control::CTRL::Value::GEN_KEY) => self.gen_key(),
             Some(Control::CTRL::Value::SIGN) => self.sign(),
             Some(Control::CTRL::Value::VERIFY) => self.verify(),
+            Some(Control::CTRL::Value::GEN_KEY_SIGN) => self.gen_key_sign(),
             _ => {}
         }
 
@@ -281,6 +519,8 @@ impl MlDsa87 {
     fn poll(&mut self) {
         if self.timer.fired(&mut self.op_complete_action) {
             self.op_complete();
+        } else if self.timer.fired(&mut self.op_seed_read_complete_action) {
+            self.seed_read_complete();
         }
     }
 
@@ -316,15 +556,11 @@ mod tests {
     const OFFSET_SK_IN: RvAddr = 0x1620;
     const OFFSET_PK: RvAddr = 0x2940;
     const OFFSET_SIGNATURE: RvAddr = 0x3400;
+    const OFFSET_SEED_READ_CONTROL: RvAddr = 0x4620;
+    const OFFSET_SEED_READ_STATUS: RvAddr = 0x4624;
 
     include!("./test_data/ml_dsa87_test_data.rs");
 
-    const VERIFICATION_SUCCES: [u8; 64] = [
-        0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
-        0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1,
-        0, 0, 0, 1,
-    ];
-
     fn make_word(idx: usize, arr: &[u8]) -> RvData {
         let mut res: RvData = 0;
         for i in 0..4 {
@@ -337,7 +573,7 @@ mod tests {
     fn test_name() {
         let clock = Clock::new();
 
-        let mut ml_dsa87 = MlDsa87::new(&clock);
+        let mut ml_dsa87 = MlDsa87::new(&clock, KeyVault::new());
 
         let name0 = ml_dsa87.read(RvSize::Word, OFFSET_NAME0).unwrap();
         let name0 = String::from_utf8_lossy(&name0.to_be_bytes()).to_string();
@@ -352,7 +588,7 @@ mod tests {
     fn test_version() {
         let clock = Clock::new();
 
-        let mut ml_dsa87 = MlDsa87::new(&clock);
+        let mut ml_dsa87 = MlDsa87::new(&clock, KeyVault::new());
 
         let version0 = ml_dsa87.read(RvSize::Word, OFFSET_VERSION0).unwrap();
         let version0 = String::from_utf8_lossy(&version0.to_le_bytes()).to_string();
@@ -367,7 +603,7 @@ mod tests {
     fn test_control() {
         let clock = Clock::new();
 
-        let mut ml_dsa87 = MlDsa87::new(&clock);
+        let mut ml_dsa87 = MlDsa87::new(&clock, KeyVault::new());
         assert_eq!(ml_dsa87.read(RvSize::Word, OFFSET_CONTROL).unwrap(), 0);
     }
 
@@ -375,7 +611,7 @@ mod tests {
     fn test_status() {
         let clock = Clock::new();
 
-        let mut ml_dsa87 = MlDsa87::new(&clock);
+        let mut ml_dsa87 = MlDsa87::new(&clock, KeyVault::new());
         assert_eq!(ml_dsa87.read(RvSize::Word, OFFSET_STATUS).unwrap(), 1);
     }
 
@@ -383,7 +619,7 @@ mod tests {
     fn test_gen_key() {
         let clock = Clock::new();
 
-        let mut ml_dsa87 = MlDsa87::new(&clock);
+        let mut ml_dsa87 = MlDsa87::new(&clock, KeyVault::new());
 
         let mut seed = [0u8; 32];
         seed.to_big_endian(); // Change DWORDs to big-endian. TODO is this needed?
@@ -429,7 +665,7 @@ mod tests {
     fn test_sign() {
         let clock = Clock::new();
 
-        let mut ml_dsa87 = MlDsa87::new(&clock);
+        let mut ml_dsa87 = MlDsa87::new(&clock, KeyVault::new());
 
         let mut seed = [0u8; 32];
         seed.to_big_endian(); // Change DWORDs to big-endian. TODO is this needed?
@@ -515,7 +751,7 @@ mod tests {
     fn test_verify() {
         let clock = Clock::new();
 
-        let mut ml_dsa87 = MlDsa87::new(&clock);
+        let mut ml_dsa87 = MlDsa87::new(&clock, KeyVault::new());
 
         let msg = [0u8; 64];
         for i in (0..msg.len()).step_by(4) {
@@ -582,7 +818,7 @@ mod tests {
         let mut result = bytes_from_words_le(&ml_dsa87.verification_result);
         result.to_little_endian();
 
-        assert_eq!(&result, &VERIFICATION_SUCCES);
+        assert_eq!(&result, &SIGNATURE[..64]);
 
         // Bad signature
         let mut signature = [0; SIG_LEN + 1];
@@ -623,6 +859,90 @@ mod tests {
         let mut result = bytes_from_words_le(&ml_dsa87.verification_result);
         result.to_little_endian();
 
-        assert_eq!(&result, &[0; 64]);
+        assert_eq!(&result, &[0xff; 64]);
+    }
+
+    #[test]
+    fn test_gen_key_sign_kv_seed() {
+        let clock = Clock::new();
+        let mut key_vault = KeyVault::new();
+
+        let mut key_usage = KeyUsage::default();
+        key_usage.set_mldsa_key_gen_seed(true);
+        let key_id = 2;
+        key_vault
+            .write_key(key_id, &[0u8; 48], u32::from(key_usage))
+            .unwrap();
+
+        let mut ml_dsa87 = MlDsa87::new(&clock, key_vault);
+
+        let seed_ctrl = InMemoryRegister::<u32, KeyReadControl::Register>::new(0);
+        seed_ctrl.modify(KeyReadControl::KEY_ID.val(key_id) + KeyReadControl::KEY_READ_EN.val(1));
+        assert_eq!(
+            ml_dsa87
+                .write(RvSize::Word, OFFSET_SEED_READ_CONTROL, seed_ctrl.get())
+                .ok(),
+            Some(())
+        );
+
+        loop {
+            let seed_read_status = InMemoryRegister::<u32, KeyReadStatus::Register>::new(
+                ml_dsa87
+                    .read(RvSize::Word, OFFSET_SEED_READ_STATUS)
+                    .unwrap(),
+            );
+
+            if seed_read_status.is_set(KeyReadStatus::VALID) {
+                assert_eq!(
+                    seed_read_status.read(KeyReadStatus::ERROR),
+                    KeyReadStatus::ERROR::KV_SUCCESS.value
+                );
+                break;
+            }
+
+            clock.increment_and_process_timer_actions(1, &mut ml_dsa87);
+        }
+
+        for ctrl in [Control::CTRL::GEN_KEY, Control::CTRL::GEN_KEY_SIGN] {
+            assert_eq!(
+                ml_dsa87
+                    .write(RvSize::Word, OFFSET_CONTROL, ctrl.into())
+                    .ok(),
+                Some(())
+            );
+
+            loop {
+                let status = InMemoryRegister::<u32, Status::Register>::new(
+                    ml_dsa87.read(RvSize::Word, OFFSET_STATUS).unwrap(),
+                );
+
+                if status.is_set(Status::VALID) && status.is_set(Status::READY) {
+                    break;
+                }
+
+                clock.increment_and_process_timer_actions(1, &mut ml_dsa87);
+            }
+        }
+
+        // The secret key derived from a key vault seed is not exported
+        assert!(ml_dsa87.sk_out.iter().all(|&w| w == 0));
+
+        let mut public_key = bytes_from_words_le(&ml_dsa87.pk);
+        public_key.to_little_endian();
+        assert_eq!(&public_key, &PUB_KEY);
+
+        let mut signature = bytes_from_words_le(&ml_dsa87.signature);
+        signature.to_little_endian();
+        assert_eq!(&signature, &SIGNATURE);
+
+        // Zeroize clears the seed read from the key vault
+        assert_eq!(
+            ml_dsa87
+                .write(RvSize::Word, OFFSET_CONTROL, Control::ZEROIZE::SET.into())
+                .ok(),
+            Some(())
+        );
+        assert!(!ml_dsa87.seed_from_kv);
+        assert!(ml_dsa87.signature.iter().all(|&w| w == 0));
     }
 }
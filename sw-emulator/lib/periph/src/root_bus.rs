@@ -12,21 +12,24 @@ Abstract:
 
 --*/
 
-#[cfg(feature = "hw-2.x")]
-use crate::ml_dsa87::MlDsa87;
+#[cfg(all(feature = "hw-2.x", not(feature = "hw-1.0")))]
+use crate::ml_dsa87::{MlDsa87, MlDsa87Snapshot};
 use crate::MailboxRequester;
 use crate::{
     helpers::words_from_bytes_be,
     iccm::Iccm,
-    soc_reg::{DebugManufService, SocRegistersExternal},
-    AsymEcc384, Csrng, Doe, EmuCtrl, HashSha256, HashSha512, HmacSha384, KeyVault, MailboxExternal,
-    MailboxInternal, MailboxRam, Sha512Accelerator, SocRegistersInternal, Uart,
+    soc_reg::{DebugManufService, SocRegistersExternal, SocRegistersSnapshot},
+    AsymEcc384, AsymEcc384Snapshot, Csrng, CsrngSnapshot, Doe, DoeSnapshot, EmuCtrl, HashSha256,
+    HashSha256Snapshot, HashSha512, HashSha512Snapshot, HmacSha384, HmacSha384Snapshot,
+    IccmSnapshot, KeyVault, KeyVaultSnapshot, MailboxExternal, MailboxInternal, MailboxRam,
+    MailboxSnapshot, Sha512Accelerator, Sha512AcceleratorSnapshot, SocRegistersInternal, Uart,
 };
 use caliptra_api_types::SecurityState;
 use caliptra_emu_bus::{Clock, Ram, Rom};
-use caliptra_emu_cpu::{Pic, PicMmioRegisters};
+use caliptra_emu_cpu::{Pic, PicMmioRegisters, PicSnapshot};
 use caliptra_emu_derive::Bus;
 use caliptra_hw_model_types::{EtrngResponse, RandomEtrngResponses, RandomNibbles};
+use std::fmt;
 use std::path::PathBuf;
 use tock_registers::registers::InMemoryRegister;
 
@@ -269,7 +272,7 @@ pub struct CaliptraRootBus {
     #[peripheral(offset = 0x1002_8000, mask = 0x0000_7fff)]
     pub sha256: HashSha256,
 
-    #[cfg(feature = "hw-2.x")]
+    #[cfg(all(feature = "hw-2.x", not(feature = "hw-1.0")))]
     #[peripheral(offset = 0x1003_0000, mask = 0x0000_7fff)] // TODO update when known
     pub ml_dsa87: MlDsa87,
 
@@ -334,8 +337,8 @@ impl CaliptraRootBus {
             key_vault: key_vault.clone(),
             sha512,
             sha256: HashSha256::new(clock),
-            #[cfg(feature = "hw-2.x")]
-            ml_dsa87: MlDsa87::new(clock),
+            #[cfg(all(feature = "hw-2.x", not(feature = "hw-1.0")))]
+            ml_dsa87: MlDsa87::new(clock, key_vault.clone()),
             iccm,
             dccm: Ram::new(vec![0; Self::DCCM_SIZE]),
             uart: Uart::new(),
@@ -349,6 +352,51 @@ impl CaliptraRootBus {
         }
     }
 
+    /// Capture the memories, key vault, mailbox, SOC registers, CSRNG, PIC and
+    /// cryptographic engines, including operations still in flight. Fails
+    /// only while a firmware upload is in progress.
+    pub fn snapshot(&self) -> Result<CaliptraRootBusSnapshot, SnapshotError> {
+        Ok(CaliptraRootBusSnapshot {
+            rom: self.rom.clone(),
+            doe: self.doe.snapshot(),
+            ecc384: self.ecc384.snapshot(),
+            hmac: self.hmac.snapshot(),
+            sha512: self.sha512.snapshot(),
+            sha256: self.sha256.snapshot(),
+            #[cfg(all(feature = "hw-2.x", not(feature = "hw-1.0")))]
+            ml_dsa87: self.ml_dsa87.snapshot(),
+            iccm: self.iccm.snapshot(),
+            dccm: self.dccm.clone(),
+            key_vault: self.key_vault.snapshot(),
+            mailbox: self.mailbox.snapshot(),
+            sha512_acc: self.sha512_acc.snapshot(),
+            soc_reg: self.soc_reg.snapshot()?,
+            csrng: self.csrng.snapshot(),
+            pic: self.pic_regs.snapshot(),
+        })
+    }
+
+    /// Restore the state captured with [`CaliptraRootBus::snapshot`] into a
+    /// freshly created bus.
+    pub fn restore(&mut self, snapshot: &CaliptraRootBusSnapshot) {
+        self.rom = snapshot.rom.clone();
+        self.doe.restore(&snapshot.doe);
+        self.ecc384.restore(&snapshot.ecc384);
+        self.hmac.restore(&snapshot.hmac);
+        self.sha512.restore(&snapshot.sha512);
+        self.sha256.restore(&snapshot.sha256);
+        #[cfg(all(feature = "hw-2.x", not(feature = "hw-1.0")))]
+        self.ml_dsa87.restore(&snapshot.ml_dsa87);
+        self.iccm.restore(&snapshot.iccm);
+        self.dccm = snapshot.dccm.clone();
+        self.key_vault.restore(&snapshot.key_vault);
+        self.mailbox.restore(&snapshot.mailbox);
+        self.sha512_acc.restore(&snapshot.sha512_acc);
+        self.soc_reg.restore(&snapshot.soc_reg);
+        self.csrng.restore(&snapshot.csrng);
+        self.pic_regs.restore(&snapshot.pic);
+    }
+
     pub fn soc_to_caliptra_bus(&self, soc_user: MailboxRequester) -> SocToCaliptraBus {
         SocToCaliptraBus {
             mailbox: self.mailbox.as_external(soc_user),
@@ -358,6 +406,43 @@ impl CaliptraRootBus {
     }
 }
 
+/// Saved state of the root bus, returned by [`CaliptraRootBus::snapshot`]
+#[derive(Clone)]
+pub struct CaliptraRootBusSnapshot {
+    rom: Rom,
+    doe: DoeSnapshot,
+    ecc384: AsymEcc384Snapshot,
+    hmac: HmacSha384Snapshot,
+    sha512: HashSha512Snapshot,
+    sha256: HashSha256Snapshot,
+    #[cfg(all(feature = "hw-2.x", not(feature = "hw-1.0")))]
+    ml_dsa87: MlDsa87Snapshot,
+    iccm: IccmSnapshot,
+    dccm: Ram,
+    key_vault: KeyVaultSnapshot,
+    mailbox: MailboxSnapshot,
+    sha512_acc: Sha512AcceleratorSnapshot,
+    soc_reg: SocRegistersSnapshot,
+    csrng: CsrngSnapshot,
+    pic: PicSnapshot,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotError {
+    /// The named peripheral has an operation in flight that can't be captured
+    PeripheralBusy(&'static str),
+}
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PeripheralBusy(name) => {
+                write!(f, "Cannot snapshot while {name} has an operation in flight")
+            }
+        }
+    }
+}
+impl std::error::Error for SnapshotError {}
+
 #[derive(Bus)]
 pub struct SocToCaliptraBus {
     #[peripheral(offset = 0x3002_0000, mask = 0x0000_0fff)]
@@ -373,9 +458,131 @@ pub struct SocToCaliptraBus {
 #[cfg(test)]
 mod tests {
     use crate::KeyUsage;
+    use caliptra_emu_bus::Bus;
+    use caliptra_emu_types::{RvAddr, RvSize};
 
     use super::*;
 
+    #[test]
+    fn test_snapshot_restore() {
+        let clock = Clock::new();
+        let mut root_bus = CaliptraRootBus::new(&clock, CaliptraRootBusArgs::default());
+        let mut key_usage = KeyUsage::default();
+        key_usage.set_hmac_key(true);
+
+        root_bus
+            .key_vault
+            .write_key(3, &[0x55; KeyVault::KEY_SIZE], key_usage.into())
+            .unwrap();
+        root_bus.key_vault.write_pcr(5, &[0x66; 48]).unwrap();
+        root_bus
+            .dccm
+            .write(RvSize::Word, 0x100, 0x1234_5678)
+            .unwrap();
+        root_bus
+            .iccm
+            .write(RvSize::Word, 0x200, 0x9abc_def0)
+            .unwrap();
+        root_bus
+            .mailbox_sram
+            .write(RvSize::Word, 0x40, 0xcafe_f00d)
+            .unwrap();
+        clock.increment(1000);
+
+        let clock_snapshot = clock.snapshot();
+        let snapshot = root_bus.snapshot().unwrap();
+
+        // Changes after the snapshot are not visible in the restored bus.
+        root_bus.dccm.write(RvSize::Word, 0x100, 0).unwrap();
+        root_bus.key_vault.write_pcr(5, &[0; 48]).unwrap();
+
+        let restored_clock = Clock::new();
+        let mut restored = CaliptraRootBus::new(&restored_clock, CaliptraRootBusArgs::default());
+        restored_clock.restore(&clock_snapshot);
+        restored.restore(&snapshot);
+
+        assert_eq!(restored_clock.now(), 1000);
+        assert_eq!(
+            restored.key_vault.read_key(3, key_usage).unwrap(),
+            [0x55; KeyVault::KEY_SIZE]
+        );
+        assert_eq!(restored.key_vault.read_pcr(5), [0x66; 48]);
+        assert_eq!(
+            restored.dccm.read(RvSize::Word, 0x100).unwrap(),
+            0x1234_5678
+        );
+        assert_eq!(
+            restored.iccm.read(RvSize::Word, 0x200).unwrap(),
+            0x9abc_def0
+        );
+        assert_eq!(
+            restored.mailbox_sram.read(RvSize::Word, 0x40).unwrap(),
+            0xcafe_f00d
+        );
+
+        // The restored bus is independent of the original.
+        assert_eq!(root_bus.dccm.read(RvSize::Word, 0x100).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_snapshot_restore_mid_operation() {
+        const OFFSET_CONTROL: RvAddr = 0x10;
+        const OFFSET_STATUS: RvAddr = 0x18;
+        const OFFSET_BLOCK: RvAddr = 0x80;
+        const OFFSET_HASH: RvAddr = 0x100;
+        const STATUS_VALID: u32 = 1 << 1;
+        const CONTROL_INIT_SHA512: u32 = 1 | (3 << 2);
+
+        fn hash(bus: &mut CaliptraRootBus) -> Vec<u32> {
+            (0..16)
+                .map(|i| bus.sha512.read(RvSize::Word, OFFSET_HASH + i * 4).unwrap())
+                .collect()
+        }
+
+        let clock = Clock::new();
+        let mut root_bus = CaliptraRootBus::new(&clock, CaliptraRootBusArgs::default());
+
+        // Single padded block holding "abc"
+        let mut block = [0u32; 32];
+        block[0] = 0x6162_6380;
+        block[31] = 24;
+        for (i, word) in block.iter().enumerate() {
+            root_bus
+                .sha512
+                .write(RvSize::Word, OFFSET_BLOCK + i as RvAddr * 4, *word)
+                .unwrap();
+        }
+        root_bus
+            .sha512
+            .write(RvSize::Word, OFFSET_CONTROL, CONTROL_INIT_SHA512)
+            .unwrap();
+        clock.increment_and_process_timer_actions(1, &mut root_bus);
+        assert_eq!(
+            root_bus.sha512.read(RvSize::Word, OFFSET_STATUS).unwrap() & STATUS_VALID,
+            0
+        );
+
+        let clock_snapshot = clock.snapshot();
+        let snapshot = root_bus.snapshot().unwrap();
+
+        let restored_clock = Clock::new();
+        let mut restored = CaliptraRootBus::new(&restored_clock, CaliptraRootBusArgs::default());
+        restored_clock.restore(&clock_snapshot);
+        restored.restore(&snapshot);
+
+        // Both buses finish the hash at the same time with the same result.
+        for (clock, bus) in [(&clock, &mut root_bus), (&restored_clock, &mut restored)] {
+            let mut ticks = 1;
+            while bus.sha512.read(RvSize::Word, OFFSET_STATUS).unwrap() & STATUS_VALID == 0 {
+                clock.increment_and_process_timer_actions(1, bus);
+                ticks += 1;
+            }
+            assert_eq!(ticks, 1000);
+        }
+        assert_eq!(hash(&mut restored), hash(&mut root_bus));
+        assert_eq!(&hash(&mut restored)[..2], [0xddaf_35a1, 0x9361_7aba]);
+    }
+
     #[test]
     fn test_keyvault_init_val_in_debug_unlocked_mode() {
         let clock = Clock::new();
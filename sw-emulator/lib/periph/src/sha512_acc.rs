@@ -13,7 +13,8 @@ Abstract:
 --*/
 use crate::MailboxRam;
 use caliptra_emu_bus::{
-    ActionHandle, Bus, BusError, Clock, ReadOnlyMemory, ReadOnlyRegister, ReadWriteRegister, Timer,
+    ActionHandle, ActionSnapshot, Bus, BusError, Clock, ReadOnlyMemory, ReadOnlyRegister,
+    ReadWriteRegister, Timer,
 };
 use caliptra_emu_crypto::{EndianessTransform, Sha512, Sha512Mode};
 use caliptra_emu_derive::Bus;
@@ -556,6 +557,52 @@ impl Sha512Accelerator {
             regs: Rc::new(RefCell::new(Sha512AcceleratorRegs::new(clock, mailbox_ram))),
         }
     }
+
+    /// Capture the registers, lock state, streamed hash and pending operation
+    pub fn snapshot(&self) -> Sha512AcceleratorSnapshot {
+        let regs = self.regs.borrow();
+        Sha512AcceleratorSnapshot {
+            _lock: regs._lock.clone(),
+            user: regs.user.clone(),
+            mode: regs.mode.clone(),
+            start_address: regs.start_address.clone(),
+            dlen: regs.dlen.clone(),
+            data_in: regs.data_in.clone(),
+            execute: regs.execute.clone(),
+            status: regs.status.clone(),
+            hash_lower: regs.hash_lower.clone(),
+            hash_upper: regs.hash_upper.clone(),
+            control: regs.control.clone(),
+            sha_stream: regs.sha_stream.clone(),
+            state: copy_state(&regs.state_machine.state),
+            locked: regs.state_machine.context.locked,
+            lock_user: regs.state_machine.context.user,
+            op_complete_action: regs.op_complete_action.as_ref().map(ActionHandle::snapshot),
+        }
+    }
+
+    /// Restore the state captured with [`Sha512Accelerator::snapshot`].
+    pub fn restore(&mut self, snapshot: &Sha512AcceleratorSnapshot) {
+        let mut regs = self.regs.borrow_mut();
+        regs._lock = snapshot._lock.clone();
+        regs.user = snapshot.user.clone();
+        regs.mode = snapshot.mode.clone();
+        regs.start_address = snapshot.start_address.clone();
+        regs.dlen = snapshot.dlen.clone();
+        regs.data_in = snapshot.data_in.clone();
+        regs.execute = snapshot.execute.clone();
+        regs.status = snapshot.status.clone();
+        regs.hash_lower = snapshot.hash_lower.clone();
+        regs.hash_upper = snapshot.hash_upper.clone();
+        regs.control = snapshot.control.clone();
+        regs.sha_stream = snapshot.sha_stream.clone();
+        regs.state_machine.state = copy_state(&snapshot.state);
+        regs.state_machine.context.locked = snapshot.locked;
+        regs.state_machine.context.user = snapshot.lock_user;
+        regs.op_complete_action = snapshot
+            .op_complete_action
+            .map(|a| regs.timer.restore_action(a));
+    }
 }
 
 impl Bus for Sha512Accelerator {
@@ -582,6 +629,54 @@ impl Bus for Sha512Accelerator {
     }
 }
 
+/// Saved state of the SHA-512 accelerator, returned by [`Sha512Accelerator::snapshot`]
+pub struct Sha512AcceleratorSnapshot {
+    _lock: ReadWriteRegister<u32, Lock::Register>,
+    user: ReadOnlyRegister<u32>,
+    mode: ReadWriteRegister<u32, ShaMode::Register>,
+    start_address: ReadWriteRegister<u32>,
+    dlen: ReadWriteRegister<u32>,
+    data_in: ReadWriteRegister<u32>,
+    execute: ReadWriteRegister<u32, Execute::Register>,
+    status: ReadOnlyRegister<u32, Status::Register>,
+    hash_lower: ReadOnlyMemory<SHA512_HASH_HALF_SIZE>,
+    hash_upper: ReadOnlyMemory<SHA512_HASH_HALF_SIZE>,
+    control: ReadWriteRegister<u32, Control::Register>,
+    sha_stream: Sha512,
+    state: States,
+    locked: u32,
+    lock_user: u32,
+    op_complete_action: Option<ActionSnapshot>,
+}
+
+impl Clone for Sha512AcceleratorSnapshot {
+    fn clone(&self) -> Self {
+        Self {
+            _lock: self._lock.clone(),
+            user: self.user.clone(),
+            mode: self.mode.clone(),
+            start_address: self.start_address.clone(),
+            dlen: self.dlen.clone(),
+            data_in: self.data_in.clone(),
+            execute: self.execute.clone(),
+            status: self.status.clone(),
+            hash_lower: self.hash_lower.clone(),
+            hash_upper: self.hash_upper.clone(),
+            control: self.control.clone(),
+            sha_stream: self.sha_stream.clone(),
+            state: copy_state(&self.state),
+            ..*self
+        }
+    }
+}
+
+fn copy_state(state: &States) -> States {
+    match state {
+        States::Idle => States::Idle,
+        States::RdyForExc => States::RdyForExc,
+    }
+}
+
 pub struct Owner(pub u32);
 
 statemachine! {
@@ -14,12 +14,12 @@ Abstract:
 
 use crate::helpers::{bytes_from_words_be, words_from_bytes_be};
 use crate::mailbox::MailboxRequester;
-use crate::root_bus::ReadyForFwCbArgs;
+use crate::root_bus::{ReadyForFwCbArgs, SnapshotError};
 use crate::{CaliptraRootBusArgs, Iccm, MailboxInternal};
 use caliptra_emu_bus::BusError::{LoadAccessFault, StoreAccessFault};
 use caliptra_emu_bus::{
-    ActionHandle, Bus, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Register, Timer,
-    TimerAction,
+    ActionHandle, ActionSnapshot, Bus, BusError, Clock, ReadOnlyRegister, ReadWriteRegister,
+    Register, Timer, TimerAction,
 };
 use caliptra_emu_cpu::{IntSource, Irq, Pic};
 use caliptra_emu_derive::Bus;
@@ -204,6 +204,12 @@ register_bitfields! [
         RSVD OFFSET(1) NUMBITS(31) [],
     ],
 
+    /// ML-DSA Verify
+    MldsaVerify [
+        MLDSA_VERIFY OFFSET(0) NUMBITS(1) [],
+        RSVD OFFSET(1) NUMBITS(31) [],
+    ],
+
     /// SoC Stepping ID
     SocSteppingId [
         SOC_STEPPING_ID OFFSET(0) NUMBITS(16) [],
@@ -301,12 +307,117 @@ register_bitfields! [
     ],
 ];
 
+/// Caliptra registers accessible over the JTAG DMI, above the Debug Module.
+/// The mailbox registers in this range aren't modeled.
+pub struct JtagReg;
+
+impl JtagReg {
+    /// Boot status
+    pub const BOOT_STATUS: u32 = 0x53;
+
+    /// Hardware error encoding
+    pub const CPTRA_HW_ERROR_ENC: u32 = 0x54;
+
+    /// Firmware error encoding
+    pub const CPTRA_FW_ERROR_ENC: u32 = 0x55;
+
+    /// Debug manufacturing service register
+    pub const CPTRA_DBG_MANUF_SERVICE_REG: u32 = 0x60;
+
+    /// Boot FSM go
+    pub const BOOTFSM_GO: u32 = 0x61;
+}
+
 /// SOC Register peripheral
 #[derive(Clone)]
 pub struct SocRegistersInternal {
     regs: Rc<RefCell<SocRegistersImpl>>,
 }
 
+/// Saved state of the SOC registers, returned by [`SocRegistersInternal::snapshot`]
+#[derive(Clone)]
+pub struct SocRegistersSnapshot {
+    cptra_hw_error_fatal: ReadWriteRegister<u32>,
+    cptra_hw_error_non_fatal: ReadWriteRegister<u32>,
+    cptra_fw_error_fatal: ReadWriteRegister<u32>,
+    cptra_fw_error_non_fatal: ReadWriteRegister<u32>,
+    cptra_hw_error_enc: ReadWriteRegister<u32>,
+    cptra_fw_error_enc: ReadWriteRegister<u32>,
+    cptra_fw_extended_error_info: [u32; CPTRA_FW_EXTENDED_ERROR_INFO_SIZE / 4],
+    cptra_boot_status: ReadWriteRegister<u32>,
+    cptra_flow_status: ReadWriteRegister<u32, FlowStatus::Register>,
+    cptra_reset_reason: ReadOnlyRegister<u32, ResetReason::Register>,
+    cptra_security_state: ReadOnlyRegister<u32, SecurityState::Register>,
+    cptra_mbox_valid_pauser: [u32; CPTRA_MBOX_VALID_PAUSER_SIZE / 4],
+    cptra_mbox_pauser_lock: [u32; CPTRA_MBOX_PAUSER_LOCK_SIZE / 4],
+    cptra_trng_valid_pauser: ReadWriteRegister<u32>,
+    cptra_trng_pauser_lock: ReadWriteRegister<u32>,
+    cptra_trng_data: [u32; CPTRA_TRNG_DATA_SIZE / 4],
+    cptra_trng_ctrl: u32,
+    cptra_trng_status: u32,
+    cptra_fuse_wr_done: u32,
+    cptra_timer_config: ReadWriteRegister<u32>,
+    cptra_bootfsm_go: u32,
+    cptra_dbg_manuf_service_reg: ReadWriteRegister<u32, DebugManufService::Register>,
+    cptra_clk_gating_en: ReadOnlyRegister<u32>,
+    cptra_generic_input_wires: [u32; CPTRA_GENERIC_INPUT_WIRES_SIZE / 4],
+    cptra_generic_output_wires: [u32; CPTRA_GENERIC_OUTPUT_WIRES_SIZE / 4],
+    cptra_hw_rev_id: ReadOnlyRegister<u32>,
+    cptra_fw_rev_id: [u32; 2],
+    cptra_hw_config: u32,
+    cptra_wdt_timer1_en: ReadWriteRegister<u32, WdtEnable::Register>,
+    cptra_wdt_timer1_ctrl: ReadWriteRegister<u32, WdtControl::Register>,
+    cptra_wdt_timer1_timeout_period: [u32; 2],
+    cptra_wdt_timer2_en: ReadWriteRegister<u32, WdtEnable::Register>,
+    cptra_wdt_timer2_ctrl: ReadWriteRegister<u32, WdtControl::Register>,
+    cptra_wdt_timer2_timeout_period: [u32; 2],
+    cptra_wdt_status: ReadOnlyRegister<u32, WdtStatus::Register>,
+    cptra_fuse_valid_pauser: ReadWriteRegister<u32>,
+    cptra_fuse_pauser_lock: ReadWriteRegister<u32>,
+    cptra_i_trng_entropy_config_0: u32,
+    cptra_i_trng_entropy_config_1: u32,
+    cptra_rsvd_reg: [u32; 2],
+    fuse_uds_seed: [u32; FUSE_UDS_SEED_SIZE / 4],
+    cptra_wdt_cfg: [u32; 2],
+    fuse_field_entropy: [u32; FUSE_FIELD_ENTROPY_SIZE / 4],
+    fuse_vendor_pk_hash: [u32; FUSE_VENDOR_PK_HASH_SIZE / 4],
+    fuse_vendor_pk_hash_mask: ReadWriteRegister<u32, VendorPubKeyMask::Register>,
+    fuse_owner_pk_hash: [u32; FUSE_OWNER_PK_HASH_SIZE / 4],
+    fuse_fmc_svn: u32,
+    fuse_runtime_svn: [u32; FUSE_RUNTIME_SVN_SIZE / 4],
+    fuse_anti_rollback_disable: u32,
+    fuse_idevid_cert_attr: [u32; FUSE_IDEVID_CERT_ATTR_SIZE / 4],
+    fuse_idevid_manuf_hsm_id: [u32; FUSE_IDEVID_MANUF_HSM_ID_SIZE / 4],
+    fuse_life_cycle: u32,
+    fuse_lms_verify: ReadWriteRegister<u32, LmsVerify::Register>,
+    fuse_lms_revocation: u32,
+    fuse_soc_stepping_id: ReadWriteRegister<u32, SocSteppingId::Register>,
+    fuse_mldsa_verify: ReadWriteRegister<u32, MldsaVerify::Register>,
+    fuse_mldsa_revocation: u32,
+    internal_obf_key: [u32; 8],
+    internal_iccm_lock: ReadWriteRegister<u32, IccmLock::Register>,
+    internal_fw_update_reset: ReadWriteRegister<u32, FwUpdateReset::Register>,
+    internal_fw_update_reset_wait_cycles: ReadWriteRegister<u32, FwUpdateResetWaitCycles::Register>,
+    internal_nmi_vector: ReadWriteRegister<u32>,
+    global_intr_en_r: ReadWriteRegister<u32, GlobalIntrEn::Register>,
+    error_intr_en_r: ReadWriteRegister<u32, ErrorIntrEn::Register>,
+    notif_intr_en_r: ReadWriteRegister<u32, NotifIntrEn::Register>,
+    error_global_intr_r: ReadWriteRegister<u32, ErrorGlobalIntr::Register>,
+    notif_global_intr_r: ReadWriteRegister<u32, NotifGlobalIntr::Register>,
+    error_internal_intr_r: ReadWriteRegister<u32, ErrorIntrT::Register>,
+    notif_internal_intr_r: ReadWriteRegister<u32, NotifIntrT::Register>,
+    error_intr_trig_r: ReadWriteRegister<u32, ErrIntrTrigT::Register>,
+    notif_intr_trig_r: ReadWriteRegister<u32, NotifIntrTrigT::Register>,
+    fuses_can_be_written: bool,
+    pending_etrng_response: Option<EtrngResponse>,
+    op_fw_read_complete_action: Option<ActionSnapshot>,
+    op_idevid_csr_read_complete_action: Option<ActionSnapshot>,
+    op_reset_trigger_action: Option<ActionSnapshot>,
+    op_wdt_timer1_expired_action: Option<ActionSnapshot>,
+    op_wdt_timer2_expired_action: Option<ActionSnapshot>,
+    op_pending_etrng_response_action: Option<ActionSnapshot>,
+}
+
 /// Caliptra Register Start Address
 const CALIPTRA_REG_START_ADDR: u32 = 0x00;
 
@@ -316,7 +427,7 @@ const CALIPTRA_REG_END_ADDR: u32 = 0x820;
 /// Caliptra Fuse start address
 const FUSE_START_ADDR: u32 = 0x200;
 /// Caliptra Fuse end address
-const FUSE_END_ADDR: u32 = 0x340;
+const FUSE_END_ADDR: u32 = 0x350;
 
 impl SocRegistersInternal {
     /// Create an instance of SOC register peripheral
@@ -365,6 +476,46 @@ impl SocRegistersInternal {
         }
     }
 
+    /// Returns true if the device is in the Manufacturing lifecycle state
+    pub fn is_manufacturing(&self) -> bool {
+        let reg = &self.regs.borrow().cptra_security_state.reg;
+        reg.matches_all(SecurityState::LIFE_CYCLE::MANUFACTURING)
+    }
+
+    /// Read the register at `addr` in the Caliptra range of the JTAG DMI,
+    /// or None if `addr` isn't one.
+    pub fn jtag_read(&self, addr: u32) -> Option<u32> {
+        let regs = self.regs.borrow();
+        match addr {
+            JtagReg::BOOT_STATUS => Some(regs.cptra_boot_status.reg.get()),
+            JtagReg::CPTRA_HW_ERROR_ENC => Some(regs.cptra_hw_error_enc.reg.get()),
+            JtagReg::CPTRA_FW_ERROR_ENC => Some(regs.cptra_fw_error_enc.reg.get()),
+            JtagReg::CPTRA_DBG_MANUF_SERVICE_REG => {
+                Some(regs.cptra_dbg_manuf_service_reg.reg.get())
+            }
+            JtagReg::BOOTFSM_GO => Some(regs.cptra_bootfsm_go),
+            _ => None,
+        }
+    }
+
+    /// Write the register at `addr` in the Caliptra range of the JTAG DMI.
+    /// Returns false if `addr` isn't one.
+    pub fn jtag_write(&mut self, addr: u32, val: u32) -> bool {
+        let mut regs = self.regs.borrow_mut();
+        match addr {
+            JtagReg::CPTRA_DBG_MANUF_SERVICE_REG => {
+                regs.cptra_dbg_manuf_service_reg.reg.set(val);
+            }
+            JtagReg::BOOTFSM_GO => {
+                // Cannot fail
+                let _ = regs.on_write_bootfsm_go(RvSize::Word, val);
+            }
+            JtagReg::BOOT_STATUS | JtagReg::CPTRA_HW_ERROR_ENC | JtagReg::CPTRA_FW_ERROR_ENC => {}
+            _ => return false,
+        }
+        true
+    }
+
     /// Clear secrets
     pub fn clear_secrets(&mut self) {
         self.regs.borrow_mut().clear_secrets();
@@ -374,6 +525,214 @@ impl SocRegistersInternal {
         self.regs.borrow_mut().cptra_hw_config = val.into();
     }
 
+    /// Capture the registers, fuses and pending timer actions. Fails if a
+    /// firmware upload is in progress, as the upload callback can't be
+    /// captured.
+    pub fn snapshot(&self) -> Result<SocRegistersSnapshot, SnapshotError> {
+        let regs = self.regs.borrow();
+        if regs.op_fw_write_complete_action.is_some() {
+            return Err(SnapshotError::PeripheralBusy("soc_reg"));
+        }
+        Ok(SocRegistersSnapshot {
+            cptra_hw_error_fatal: regs.cptra_hw_error_fatal.clone(),
+            cptra_hw_error_non_fatal: regs.cptra_hw_error_non_fatal.clone(),
+            cptra_fw_error_fatal: regs.cptra_fw_error_fatal.clone(),
+            cptra_fw_error_non_fatal: regs.cptra_fw_error_non_fatal.clone(),
+            cptra_hw_error_enc: regs.cptra_hw_error_enc.clone(),
+            cptra_fw_error_enc: regs.cptra_fw_error_enc.clone(),
+            cptra_fw_extended_error_info: regs.cptra_fw_extended_error_info,
+            cptra_boot_status: regs.cptra_boot_status.clone(),
+            cptra_flow_status: regs.cptra_flow_status.clone(),
+            cptra_reset_reason: regs.cptra_reset_reason.clone(),
+            cptra_security_state: regs.cptra_security_state.clone(),
+            cptra_mbox_valid_pauser: regs.cptra_mbox_valid_pauser,
+            cptra_mbox_pauser_lock: regs.cptra_mbox_pauser_lock,
+            cptra_trng_valid_pauser: regs.cptra_trng_valid_pauser.clone(),
+            cptra_trng_pauser_lock: regs.cptra_trng_pauser_lock.clone(),
+            cptra_trng_data: regs.cptra_trng_data,
+            cptra_trng_ctrl: regs.cptra_trng_ctrl,
+            cptra_trng_status: regs.cptra_trng_status,
+            cptra_fuse_wr_done: regs.cptra_fuse_wr_done,
+            cptra_timer_config: regs.cptra_timer_config.clone(),
+            cptra_bootfsm_go: regs.cptra_bootfsm_go,
+            cptra_dbg_manuf_service_reg: regs.cptra_dbg_manuf_service_reg.clone(),
+            cptra_clk_gating_en: regs.cptra_clk_gating_en.clone(),
+            cptra_generic_input_wires: regs.cptra_generic_input_wires,
+            cptra_generic_output_wires: regs.cptra_generic_output_wires,
+            cptra_hw_rev_id: regs.cptra_hw_rev_id.clone(),
+            cptra_fw_rev_id: regs.cptra_fw_rev_id,
+            cptra_hw_config: regs.cptra_hw_config,
+            cptra_wdt_timer1_en: regs.cptra_wdt_timer1_en.clone(),
+            cptra_wdt_timer1_ctrl: regs.cptra_wdt_timer1_ctrl.clone(),
+            cptra_wdt_timer1_timeout_period: regs.cptra_wdt_timer1_timeout_period,
+            cptra_wdt_timer2_en: regs.cptra_wdt_timer2_en.clone(),
+            cptra_wdt_timer2_ctrl: regs.cptra_wdt_timer2_ctrl.clone(),
+            cptra_wdt_timer2_timeout_period: regs.cptra_wdt_timer2_timeout_period,
+            cptra_wdt_status: regs.cptra_wdt_status.clone(),
+            cptra_fuse_valid_pauser: regs.cptra_fuse_valid_pauser.clone(),
+            cptra_fuse_pauser_lock: regs.cptra_fuse_pauser_lock.clone(),
+            cptra_i_trng_entropy_config_0: regs.cptra_i_trng_entropy_config_0,
+            cptra_i_trng_entropy_config_1: regs.cptra_i_trng_entropy_config_1,
+            cptra_rsvd_reg: regs.cptra_rsvd_reg,
+            fuse_uds_seed: regs.fuse_uds_seed,
+            cptra_wdt_cfg: regs.cptra_wdt_cfg,
+            fuse_field_entropy: regs.fuse_field_entropy,
+            fuse_vendor_pk_hash: regs.fuse_vendor_pk_hash,
+            fuse_vendor_pk_hash_mask: regs.fuse_vendor_pk_hash_mask.clone(),
+            fuse_owner_pk_hash: regs.fuse_owner_pk_hash,
+            fuse_fmc_svn: regs.fuse_fmc_svn,
+            fuse_runtime_svn: regs.fuse_runtime_svn,
+            fuse_anti_rollback_disable: regs.fuse_anti_rollback_disable,
+            fuse_idevid_cert_attr: regs.fuse_idevid_cert_attr,
+            fuse_idevid_manuf_hsm_id: regs.fuse_idevid_manuf_hsm_id,
+            fuse_life_cycle: regs.fuse_life_cycle,
+            fuse_lms_verify: regs.fuse_lms_verify.clone(),
+            fuse_lms_revocation: regs.fuse_lms_revocation,
+            fuse_soc_stepping_id: regs.fuse_soc_stepping_id.clone(),
+            fuse_mldsa_verify: regs.fuse_mldsa_verify.clone(),
+            fuse_mldsa_revocation: regs.fuse_mldsa_revocation,
+            internal_obf_key: regs.internal_obf_key,
+            internal_iccm_lock: regs.internal_iccm_lock.clone(),
+            internal_fw_update_reset: regs.internal_fw_update_reset.clone(),
+            internal_fw_update_reset_wait_cycles: regs.internal_fw_update_reset_wait_cycles.clone(),
+            internal_nmi_vector: regs.internal_nmi_vector.clone(),
+            global_intr_en_r: regs.global_intr_en_r.clone(),
+            error_intr_en_r: regs.error_intr_en_r.clone(),
+            notif_intr_en_r: regs.notif_intr_en_r.clone(),
+            error_global_intr_r: regs.error_global_intr_r.clone(),
+            notif_global_intr_r: regs.notif_global_intr_r.clone(),
+            error_internal_intr_r: regs.error_internal_intr_r.clone(),
+            notif_internal_intr_r: regs.notif_internal_intr_r.clone(),
+            error_intr_trig_r: regs.error_intr_trig_r.clone(),
+            notif_intr_trig_r: regs.notif_intr_trig_r.clone(),
+            fuses_can_be_written: regs.fuses_can_be_written,
+            pending_etrng_response: regs.pending_etrng_response,
+            op_fw_read_complete_action: regs
+                .op_fw_read_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_idevid_csr_read_complete_action: regs
+                .op_idevid_csr_read_complete_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_reset_trigger_action: regs
+                .op_reset_trigger_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_wdt_timer1_expired_action: regs
+                .op_wdt_timer1_expired_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_wdt_timer2_expired_action: regs
+                .op_wdt_timer2_expired_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+            op_pending_etrng_response_action: regs
+                .op_pending_etrng_response_action
+                .as_ref()
+                .map(ActionHandle::snapshot),
+        })
+    }
+
+    /// Restore the state captured with [`SocRegistersInternal::snapshot`].
+    pub fn restore(&mut self, snapshot: &SocRegistersSnapshot) {
+        let mut regs = self.regs.borrow_mut();
+        regs.cptra_hw_error_fatal = snapshot.cptra_hw_error_fatal.clone();
+        regs.cptra_hw_error_non_fatal = snapshot.cptra_hw_error_non_fatal.clone();
+        regs.cptra_fw_error_fatal = snapshot.cptra_fw_error_fatal.clone();
+        regs.cptra_fw_error_non_fatal = snapshot.cptra_fw_error_non_fatal.clone();
+        regs.cptra_hw_error_enc = snapshot.cptra_hw_error_enc.clone();
+        regs.cptra_fw_error_enc = snapshot.cptra_fw_error_enc.clone();
+        regs.cptra_fw_extended_error_info = snapshot.cptra_fw_extended_error_info;
+        regs.cptra_boot_status = snapshot.cptra_boot_status.clone();
+        regs.cptra_flow_status = snapshot.cptra_flow_status.clone();
+        regs.cptra_reset_reason = snapshot.cptra_reset_reason.clone();
+        regs.cptra_security_state = snapshot.cptra_security_state.clone();
+        regs.cptra_mbox_valid_pauser = snapshot.cptra_mbox_valid_pauser;
+        regs.cptra_mbox_pauser_lock = snapshot.cptra_mbox_pauser_lock;
+        regs.cptra_trng_valid_pauser = snapshot.cptra_trng_valid_pauser.clone();
+        regs.cptra_trng_pauser_lock = snapshot.cptra_trng_pauser_lock.clone();
+        regs.cptra_trng_data = snapshot.cptra_trng_data;
+        regs.cptra_trng_ctrl = snapshot.cptra_trng_ctrl;
+        regs.cptra_trng_status = snapshot.cptra_trng_status;
+        regs.cptra_fuse_wr_done = snapshot.cptra_fuse_wr_done;
+        regs.cptra_timer_config = snapshot.cptra_timer_config.clone();
+        regs.cptra_bootfsm_go = snapshot.cptra_bootfsm_go;
+        regs.cptra_dbg_manuf_service_reg = snapshot.cptra_dbg_manuf_service_reg.clone();
+        regs.cptra_clk_gating_en = snapshot.cptra_clk_gating_en.clone();
+        regs.cptra_generic_input_wires = snapshot.cptra_generic_input_wires;
+        regs.cptra_generic_output_wires = snapshot.cptra_generic_output_wires;
+        regs.cptra_hw_rev_id = snapshot.cptra_hw_rev_id.clone();
+        regs.cptra_fw_rev_id = snapshot.cptra_fw_rev_id;
+        regs.cptra_hw_config = snapshot.cptra_hw_config;
+        regs.cptra_wdt_timer1_en = snapshot.cptra_wdt_timer1_en.clone();
+        regs.cptra_wdt_timer1_ctrl = snapshot.cptra_wdt_timer1_ctrl.clone();
+        regs.cptra_wdt_timer1_timeout_period = snapshot.cptra_wdt_timer1_timeout_period;
+        regs.cptra_wdt_timer2_en = snapshot.cptra_wdt_timer2_en.clone();
+        regs.cptra_wdt_timer2_ctrl = snapshot.cptra_wdt_timer2_ctrl.clone();
+        regs.cptra_wdt_timer2_timeout_period = snapshot.cptra_wdt_timer2_timeout_period;
+        regs.cptra_wdt_status = snapshot.cptra_wdt_status.clone();
+        regs.cptra_fuse_valid_pauser = snapshot.cptra_fuse_valid_pauser.clone();
+        regs.cptra_fuse_pauser_lock = snapshot.cptra_fuse_pauser_lock.clone();
+        regs.cptra_i_trng_entropy_config_0 = snapshot.cptra_i_trng_entropy_config_0;
+        regs.cptra_i_trng_entropy_config_1 = snapshot.cptra_i_trng_entropy_config_1;
+        regs.cptra_rsvd_reg = snapshot.cptra_rsvd_reg;
+        regs.fuse_uds_seed = snapshot.fuse_uds_seed;
+        regs.cptra_wdt_cfg = snapshot.cptra_wdt_cfg;
+        regs.fuse_field_entropy = snapshot.fuse_field_entropy;
+        regs.fuse_vendor_pk_hash = snapshot.fuse_vendor_pk_hash;
+        regs.fuse_vendor_pk_hash_mask = snapshot.fuse_vendor_pk_hash_mask.clone();
+        regs.fuse_owner_pk_hash = snapshot.fuse_owner_pk_hash;
+        regs.fuse_fmc_svn = snapshot.fuse_fmc_svn;
+        regs.fuse_runtime_svn = snapshot.fuse_runtime_svn;
+        regs.fuse_anti_rollback_disable = snapshot.fuse_anti_rollback_disable;
+        regs.fuse_idevid_cert_attr = snapshot.fuse_idevid_cert_attr;
+        regs.fuse_idevid_manuf_hsm_id = snapshot.fuse_idevid_manuf_hsm_id;
+        regs.fuse_life_cycle = snapshot.fuse_life_cycle;
+        regs.fuse_lms_verify = snapshot.fuse_lms_verify.clone();
+        regs.fuse_lms_revocation = snapshot.fuse_lms_revocation;
+        regs.fuse_soc_stepping_id = snapshot.fuse_soc_stepping_id.clone();
+        regs.fuse_mldsa_verify = snapshot.fuse_mldsa_verify.clone();
+        regs.fuse_mldsa_revocation = snapshot.fuse_mldsa_revocation;
+        regs.internal_obf_key = snapshot.internal_obf_key;
+        regs.internal_iccm_lock = snapshot.internal_iccm_lock.clone();
+        regs.internal_fw_update_reset = snapshot.internal_fw_update_reset.clone();
+        regs.internal_fw_update_reset_wait_cycles =
+            snapshot.internal_fw_update_reset_wait_cycles.clone();
+        regs.internal_nmi_vector = snapshot.internal_nmi_vector.clone();
+        regs.global_intr_en_r = snapshot.global_intr_en_r.clone();
+        regs.error_intr_en_r = snapshot.error_intr_en_r.clone();
+        regs.notif_intr_en_r = snapshot.notif_intr_en_r.clone();
+        regs.error_global_intr_r = snapshot.error_global_intr_r.clone();
+        regs.notif_global_intr_r = snapshot.notif_global_intr_r.clone();
+        regs.error_internal_intr_r = snapshot.error_internal_intr_r.clone();
+        regs.notif_internal_intr_r = snapshot.notif_internal_intr_r.clone();
+        regs.error_intr_trig_r = snapshot.error_intr_trig_r.clone();
+        regs.notif_intr_trig_r = snapshot.notif_intr_trig_r.clone();
+        regs.fuses_can_be_written = snapshot.fuses_can_be_written;
+        regs.pending_etrng_response = snapshot.pending_etrng_response;
+        regs.op_fw_write_complete_action = None;
+        let timer = regs.timer.clone();
+        regs.op_fw_read_complete_action = snapshot
+            .op_fw_read_complete_action
+            .map(|a| timer.restore_action(a));
+        regs.op_idevid_csr_read_complete_action = snapshot
+            .op_idevid_csr_read_complete_action
+            .map(|a| timer.restore_action(a));
+        regs.op_reset_trigger_action = snapshot
+            .op_reset_trigger_action
+            .map(|a| timer.restore_action(a));
+        regs.op_wdt_timer1_expired_action = snapshot
+            .op_wdt_timer1_expired_action
+            .map(|a| timer.restore_action(a));
+        regs.op_wdt_timer2_expired_action = snapshot
+            .op_wdt_timer2_expired_action
+            .map(|a| timer.restore_action(a));
+        regs.op_pending_etrng_response_action = snapshot
+            .op_pending_etrng_response_action
+            .map(|a| timer.restore_action(a));
+    }
+
     pub fn external_regs(&self) -> SocRegistersExternal {
         SocRegistersExternal {
             regs: self.regs.clone(),
@@ -656,6 +1015,12 @@ struct SocRegistersImpl {
     #[register(offset = 0x348)]
     fuse_soc_stepping_id: ReadWriteRegister<u32, SocSteppingId::Register>,
 
+    #[register(offset = 0x34c)]
+    fuse_mldsa_verify: ReadWriteRegister<u32, MldsaVerify::Register>,
+
+    #[register(offset = 0x350)]
+    fuse_mldsa_revocation: u32,
+
     /// INTERNAL_OBF_KEY Register
     internal_obf_key: [u32; 8],
 
@@ -834,6 +1199,8 @@ impl SocRegistersImpl {
             fuse_lms_verify: ReadWriteRegister::new(0),
             fuse_lms_revocation: Default::default(),
             fuse_soc_stepping_id: ReadWriteRegister::new(0),
+            fuse_mldsa_verify: ReadWriteRegister::new(0),
+            fuse_mldsa_revocation: Default::default(),
             internal_obf_key: args.cptra_obf_key,
             internal_iccm_lock: ReadWriteRegister::new(0),
             internal_fw_update_reset: ReadWriteRegister::new(0),
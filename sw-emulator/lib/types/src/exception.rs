@@ -43,7 +43,10 @@ emu_enum! {
         /// Store access fault exception
         StoreAccessFault = 7,
 
-        /// Environment Call
+        /// Environment Call from User mode
+        EnvironmentCallUser = 8,
+
+        /// Environment Call from Machine mode
         EnvironmentCall = 11,
 
         /// Illegal Register exception
@@ -108,11 +111,16 @@ impl RvException {
         RvException::new(RvExceptionCause::IllegalRegister, 0)
     }
 
-    /// Create a new illegal register exception
+    /// Create a new machine mode environment call exception
     pub fn environment_call() -> Self {
         RvException::new(RvExceptionCause::EnvironmentCall, 0)
     }
 
+    /// Create a new user mode environment call exception
+    pub fn environment_call_user() -> Self {
+        RvException::new(RvExceptionCause::EnvironmentCallUser, 0)
+    }
+
     /// Returns the exception cause
     pub fn cause(&self) -> RvExceptionCause {
         self.cause
@@ -199,6 +207,13 @@ mod tests {
         assert_eq!(e.info(), 0);
     }
 
+    #[test]
+    fn test_environment_call_user() {
+        let e = RvException::environment_call_user();
+        assert_eq!(e.cause(), RvExceptionCause::EnvironmentCallUser);
+        assert_eq!(e.info(), 0);
+    }
+
     #[test]
     fn test_environment_call() {
         let e = RvException::environment_call();
@@ -0,0 +1,246 @@
+// Licensed under the Apache-2.0 license
+
+use caliptra_api_types::{DeviceLifecycle, Fuses};
+use caliptra_attestation::{
+    verify_evidence, CertChain, CertName, Evidence, ReferenceValues, VerifyError,
+};
+use caliptra_builder::{firmware, ImageOptions};
+use caliptra_common::mailbox_api::{
+    CertifyKeyExtendedFlags, CertifyKeyExtendedReq, GetFmcAliasCertReq, GetLdevCertReq,
+    GetPcrLogReq, GetRtAliasCertReq, MailboxReqHeader, QuotePcrsReq, QuotePcrsResp,
+    ResponseVarSize,
+};
+use caliptra_common::PcrLogEntry;
+use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams, SecurityState};
+use caliptra_test::{swap_word_bytes, swap_word_bytes_inplace};
+use dpe::{
+    commands::{CertifyKeyCmd, CertifyKeyFlags},
+    context::ContextHandle,
+    response::CertifyKeyResp,
+};
+use openssl::sha::sha384;
+use zerocopy::{FromBytes, IntoBytes};
+
+const NONCE: [u8; 32] = [0x5a; 32];
+
+struct CollectedEvidence {
+    ldevid: Vec<u8>,
+    fmc_alias: Vec<u8>,
+    rt_alias: Vec<u8>,
+    dpe_leaf: Vec<u8>,
+    quote: QuotePcrsResp,
+    pcr_log: Vec<u8>,
+    reference_toml: String,
+}
+
+impl CollectedEvidence {
+    fn evidence(&self) -> Evidence {
+        Evidence {
+            certs: CertChain {
+                ldevid: &self.ldevid,
+                fmc_alias: &self.fmc_alias,
+                rt_alias: &self.rt_alias,
+                dpe_leaf: Some(&self.dpe_leaf),
+            },
+            quote: &self.quote,
+            nonce: &NONCE,
+            pcr_log: &self.pcr_log,
+        }
+    }
+}
+
+fn idevid_pubkey() -> openssl::pkey::PKey<openssl::pkey::Public> {
+    let csr =
+        openssl::x509::X509Req::from_der(include_bytes!("smoke_testdata/idevid_csr.der")).unwrap();
+    csr.public_key().unwrap()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn read_pcr_log(hw: &mut DefaultHwModel) -> Vec<u8> {
+    let mut log = vec![];
+    loop {
+        let resp = hw
+            .mailbox_execute_req(GetPcrLogReq {
+                hdr: MailboxReqHeader { chksum: 0 },
+                start_index: (log.len() / core::mem::size_of::<PcrLogEntry>()) as u32,
+            })
+            .unwrap();
+        let data = resp.data().unwrap();
+        if data.is_empty() {
+            return log;
+        }
+        log.extend_from_slice(data);
+    }
+}
+
+fn collect_evidence() -> CollectedEvidence {
+    let rom = caliptra_builder::rom_for_fw_integration_tests().unwrap();
+    let image = caliptra_builder::build_and_sign_image(
+        &firmware::FMC_WITH_UART,
+        &firmware::APP_WITH_UART,
+        ImageOptions {
+            fmc_svn: 9,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let vendor_pk_hash = sha384(image.manifest.preamble.vendor_pub_keys.as_bytes());
+    let owner_pk_hash = sha384(image.manifest.preamble.owner_pub_keys.as_bytes());
+    let mut vendor_pk_hash_words: [u32; 12] = zerocopy::transmute!(vendor_pk_hash);
+    swap_word_bytes_inplace(&mut vendor_pk_hash_words);
+    let mut owner_pk_hash_words: [u32; 12] = zerocopy::transmute!(owner_pk_hash);
+    swap_word_bytes_inplace(&mut owner_pk_hash_words);
+
+    let mut hw = caliptra_hw_model::new(
+        InitParams {
+            rom: &rom,
+            security_state: *SecurityState::default()
+                .set_debug_locked(true)
+                .set_device_lifecycle(DeviceLifecycle::Production),
+            ..Default::default()
+        },
+        BootParams {
+            fuses: Fuses {
+                key_manifest_pk_hash: vendor_pk_hash_words,
+                owner_pk_hash: owner_pk_hash_words,
+                fmc_key_manifest_svn: 0b1111111,
+                lms_verify: true,
+                ..Default::default()
+            },
+            fw_image: Some(&image.to_bytes().unwrap()),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let ldevid = hw.mailbox_execute_req(GetLdevCertReq::default()).unwrap();
+    let fmc_alias = hw
+        .mailbox_execute_req(GetFmcAliasCertReq::default())
+        .unwrap();
+    let rt_alias = hw
+        .mailbox_execute_req(GetRtAliasCertReq::default())
+        .unwrap();
+
+    let certify_key_cmd = CertifyKeyCmd {
+        handle: ContextHandle::default(),
+        label: [0x4c; 48],
+        flags: CertifyKeyFlags::empty(),
+        format: CertifyKeyCmd::FORMAT_X509,
+    };
+    let certify_key_resp = hw
+        .mailbox_execute_req(CertifyKeyExtendedReq {
+            hdr: MailboxReqHeader { chksum: 0 },
+            certify_key_req: certify_key_cmd.as_bytes().try_into().unwrap(),
+            flags: CertifyKeyExtendedFlags::empty(),
+        })
+        .unwrap();
+    let certify_key_resp =
+        CertifyKeyResp::read_from_bytes(&certify_key_resp.certify_key_resp[..]).unwrap();
+
+    let quote = hw
+        .mailbox_execute_req(QuotePcrsReq {
+            hdr: MailboxReqHeader { chksum: 0 },
+            nonce: NONCE,
+        })
+        .unwrap();
+
+    CollectedEvidence {
+        ldevid: ldevid.data().unwrap().to_vec(),
+        fmc_alias: fmc_alias.data().unwrap().to_vec(),
+        rt_alias: rt_alias.data().unwrap().to_vec(),
+        dpe_leaf: certify_key_resp.cert[..certify_key_resp.cert_size as usize].to_vec(),
+        quote,
+        pcr_log: read_pcr_log(&mut hw),
+        reference_toml: format!(
+            "fmc_digest = \"{}\"\n\
+             rt_digest = \"{}\"\n\
+             vendor_pub_key_hash = \"{}\"\n\
+             owner_pub_key_hash = \"{}\"\n\
+             min_fmc_svn = 9\n\
+             min_rt_svn = 0\n",
+            to_hex(swap_word_bytes(&image.manifest.fmc.digest).as_bytes()),
+            to_hex(swap_word_bytes(&image.manifest.runtime.digest).as_bytes()),
+            to_hex(&vendor_pk_hash),
+            to_hex(&owner_pk_hash),
+        ),
+    }
+}
+
+#[test]
+fn test_verify_emulator_evidence() {
+    let collected = collect_evidence();
+    let reference = ReferenceValues::from_toml(&collected.reference_toml).unwrap();
+
+    assert_eq!(
+        verify_evidence(&collected.evidence(), &idevid_pubkey(), &reference),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_verify_emulator_evidence_failures() {
+    let collected = collect_evidence();
+    let reference = ReferenceValues::from_toml(&collected.reference_toml).unwrap();
+    let idevid_pubkey = idevid_pubkey();
+
+    // Wrong runtime digest
+    let mut wrong_rt = reference.clone();
+    wrong_rt.rt_digest.as_mut().unwrap()[0] ^= 1;
+    assert!(matches!(
+        verify_evidence(&collected.evidence(), &idevid_pubkey, &wrong_rt),
+        Err(VerifyError::Fwid {
+            cert: CertName::RtAlias,
+            ty: "RT_INFO",
+            ..
+        })
+    ));
+
+    // FMC SVN below the minimum
+    let high_svn = ReferenceValues {
+        min_fmc_svn: Some(10),
+        ..reference.clone()
+    };
+    assert_eq!(
+        verify_evidence(&collected.evidence(), &idevid_pubkey, &high_svn),
+        Err(VerifyError::Svn {
+            cert: CertName::FmcAlias,
+            ty: "FMC_INFO",
+            min: 10,
+            actual: 9,
+        })
+    );
+
+    // Chain does not lead back to the IDevID key
+    let ldevid_pubkey = openssl::x509::X509::from_der(&collected.ldevid)
+        .unwrap()
+        .public_key()
+        .unwrap();
+    assert_eq!(
+        verify_evidence(&collected.evidence(), &ldevid_pubkey, &reference),
+        Err(VerifyError::CertSignature {
+            cert: CertName::LDevId
+        })
+    );
+
+    // Quote for a different nonce
+    let mut evidence = collected.evidence();
+    evidence.nonce = &[0; 32];
+    assert_eq!(
+        verify_evidence(&evidence, &idevid_pubkey, &reference),
+        Err(VerifyError::QuoteNonce)
+    );
+
+    // Tampered PCR log
+    let mut pcr_log = collected.pcr_log.clone();
+    let last = pcr_log.len() - 1;
+    pcr_log[last] ^= 1;
+    let mut evidence = collected.evidence();
+    evidence.pcr_log = &pcr_log;
+    assert!(matches!(
+        verify_evidence(&evidence, &idevid_pubkey, &reference),
+        Err(VerifyError::PcrReplay { .. })
+    ));
+}
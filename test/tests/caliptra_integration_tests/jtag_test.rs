@@ -78,7 +78,7 @@ fn gdb_mem_test<R>(
 
 #[test]
 fn gdb_test() {
-    #![cfg_attr(not(feature = "fpga_realtime"), ignore)]
+    #![cfg_attr(feature = "verilator", ignore)]
 
     let security_state = *SecurityState::default()
         .set_debug_locked(false)
@@ -124,7 +124,6 @@ fn gdb_test() {
     hw.step_until_output_contains("[rt] RT listening for mailbox commands...\n")
         .unwrap();
 
-    #[cfg(feature = "fpga_realtime")]
     hw.launch_openocd().unwrap();
 
     let elf_path = get_elf_path(&firmware::APP_WITH_UART).unwrap();
@@ -136,52 +135,60 @@ fn gdb_test() {
         .spawn()
         .unwrap();
 
-    let mut stdin = gdb.stdin.take().expect("Failed to open stdin");
-    let mut stderr = BufReader::new(gdb.stderr.as_mut().unwrap());
+    // The emulator only services the JTAG port while it is stepped, so talk
+    // to GDB from another thread.
+    let gdb_thread = std::thread::spawn(move || {
+        let mut stdin = gdb.stdin.take().expect("Failed to open stdin");
+        let mut stderr = BufReader::new(gdb.stderr.take().unwrap());
 
-    stdin
-        .write_all(include_str!("smoke_testdata/gdb_script.txt").as_bytes())
-        .expect("Failed to write to stdin");
+        stdin
+            .write_all(include_str!("smoke_testdata/gdb_script.txt").as_bytes())
+            .expect("Failed to write to stdin");
 
-    loop {
-        let mut output = String::new();
-        stderr.read_line(&mut output).unwrap();
+        loop {
+            let mut output = String::new();
+            stderr.read_line(&mut output).unwrap();
 
-        if output.contains("GDB Launched") {
-            break;
+            if output.contains("GDB Launched") {
+                break;
+            }
         }
-    }
 
-    // Start of ROM
-    gdb_mem_test(&mut stdin, &mut stderr, 0x00000000, 1, RegAccess::RO);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x00000000, 2, RegAccess::RO);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x00000000, 4, RegAccess::RO);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x00000000, 8, RegAccess::RO);
-    // End of ROM
-    gdb_mem_test(&mut stdin, &mut stderr, 0x0000BFFF, 1, RegAccess::RO);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x0000BFFE, 2, RegAccess::RO);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x0000BFFC, 4, RegAccess::RO);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x0000BFF8, 8, RegAccess::RO);
-
-    // Start of ICCM
-    gdb_mem_test(&mut stdin, &mut stderr, 0x40000000, 1, RegAccess::Invalid);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x40000000, 2, RegAccess::Invalid);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x40000000, 4, RegAccess::RW);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x40000000, 8, RegAccess::RO);
-    // End of ICCM
-    gdb_mem_test(&mut stdin, &mut stderr, 0x4001FFFF, 1, RegAccess::Invalid);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x4001FFFE, 2, RegAccess::Invalid);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x4001FFFC, 4, RegAccess::RW);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x4001FFF8, 8, RegAccess::RO);
-
-    // Start of DCCM
-    gdb_mem_test(&mut stdin, &mut stderr, 0x50000000, 1, RegAccess::RW);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x50000000, 2, RegAccess::RW);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x50000000, 4, RegAccess::RW);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x50000000, 8, RegAccess::RW);
-    // End of DCCM
-    gdb_mem_test(&mut stdin, &mut stderr, 0x5001FFFF, 1, RegAccess::RW);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x5001FFFE, 2, RegAccess::RW);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x5001FFFC, 4, RegAccess::RW);
-    gdb_mem_test(&mut stdin, &mut stderr, 0x5001FFF8, 8, RegAccess::RW);
+        // Start of ROM
+        gdb_mem_test(&mut stdin, &mut stderr, 0x00000000, 1, RegAccess::RO);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x00000000, 2, RegAccess::RO);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x00000000, 4, RegAccess::RO);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x00000000, 8, RegAccess::RO);
+        // End of ROM
+        gdb_mem_test(&mut stdin, &mut stderr, 0x0000BFFF, 1, RegAccess::RO);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x0000BFFE, 2, RegAccess::RO);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x0000BFFC, 4, RegAccess::RO);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x0000BFF8, 8, RegAccess::RO);
+
+        // Start of ICCM
+        gdb_mem_test(&mut stdin, &mut stderr, 0x40000000, 1, RegAccess::Invalid);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x40000000, 2, RegAccess::Invalid);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x40000000, 4, RegAccess::RW);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x40000000, 8, RegAccess::RO);
+        // End of ICCM
+        gdb_mem_test(&mut stdin, &mut stderr, 0x4001FFFF, 1, RegAccess::Invalid);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x4001FFFE, 2, RegAccess::Invalid);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x4001FFFC, 4, RegAccess::RW);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x4001FFF8, 8, RegAccess::RO);
+
+        // Start of DCCM
+        gdb_mem_test(&mut stdin, &mut stderr, 0x50000000, 1, RegAccess::RW);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x50000000, 2, RegAccess::RW);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x50000000, 4, RegAccess::RW);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x50000000, 8, RegAccess::RW);
+        // End of DCCM
+        gdb_mem_test(&mut stdin, &mut stderr, 0x5001FFFF, 1, RegAccess::RW);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x5001FFFE, 2, RegAccess::RW);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x5001FFFC, 4, RegAccess::RW);
+        gdb_mem_test(&mut stdin, &mut stderr, 0x5001FFF8, 8, RegAccess::RW);
+    });
+    while !gdb_thread.is_finished() {
+        hw.step();
+    }
+    gdb_thread.join().unwrap();
 }
@@ -1,5 +1,6 @@
 // Licensed under the Apache-2.0 license
 
+mod attestation_test;
 mod fake_collateral_boot_test;
 mod jtag_test;
 mod smoke_test;
@@ -71,6 +71,7 @@ const ROM_EXP_1_2_0: RomExpVals = RomExpVals {
     ..ROM_EXP_1_1_0
 };
 
+// ROM_GET_IDEV_CSR is only reported if the CSR was generated.
 const ROM_EXP_CURRENT: RomExpVals = RomExpVals { ..ROM_EXP_1_2_0 };
 
 // ===  RUNTIME  ===
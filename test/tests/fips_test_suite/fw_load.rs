@@ -45,6 +45,8 @@ fn update_manifest(image_bundle: &mut ImageBundle, hdr_digest: HdrDigest, toc_di
         runtime: caliptra_image_elf::ElfExecutable::default(),
         vendor_config: opts.vendor_config,
         owner_config: opts.owner_config,
+        data: vec![],
+        compress_runtime: false,
     };
 
     let gen = ImageGenerator::new(Crypto::default());
@@ -52,7 +54,11 @@ fn update_manifest(image_bundle: &mut ImageBundle, hdr_digest: HdrDigest, toc_di
     // Update TOC digest
     if toc_digest == TocDigest::Update {
         image_bundle.manifest.header.toc_digest = gen
-            .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+            .toc_digest(
+                &image_bundle.manifest.fmc,
+                &image_bundle.manifest.runtime,
+                &image_bundle.extended_toc,
+            )
             .unwrap();
     }
 
@@ -74,6 +80,12 @@ fn update_manifest(image_bundle: &mut ImageBundle, hdr_digest: HdrDigest, toc_di
                 &header_digest_owner,
             )
             .unwrap();
+        #[cfg(not(feature = "hw-1.0"))]
+        {
+            image_bundle.mldsa = gen
+                .gen_mldsa_preamble(&config, &header_digest_vendor, &header_digest_owner)
+                .unwrap();
+        }
     }
 }
 
@@ -83,6 +95,8 @@ fn update_manifest(image_bundle: &mut ImageBundle, hdr_digest: HdrDigest, toc_di
 fn image_to_bytes_no_error_check(image_bundle: &ImageBundle) -> Vec<u8> {
     let mut image = vec![];
     image.extend_from_slice(image_bundle.manifest.as_bytes());
+    #[cfg(not(feature = "hw-1.0"))]
+    image.extend_from_slice(image_bundle.mldsa.as_bytes());
     image.extend_from_slice(&image_bundle.fmc);
     image.extend_from_slice(&image_bundle.runtime);
     image
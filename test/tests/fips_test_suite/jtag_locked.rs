@@ -2,7 +2,7 @@
 
 use crate::common::fips_test_init_to_rom;
 use caliptra_hw_model::OpenOcdError;
-use caliptra_hw_model::{InitParams, SecurityState};
+use caliptra_hw_model::{HwModel, InitParams, SecurityState};
 use caliptra_hw_model_types::DeviceLifecycle;
 
 fn check_jtag_accessible(
@@ -24,7 +24,6 @@ fn check_jtag_accessible(
         None,
     );
 
-    #[cfg(feature = "fpga_realtime")]
     assert_eq!(
         expect_result,
         hw.launch_openocd(),
@@ -34,7 +33,7 @@ fn check_jtag_accessible(
 
 #[test]
 fn jtag_locked() {
-    #![cfg_attr(not(feature = "fpga_realtime"), ignore)]
+    #![cfg_attr(feature = "verilator", ignore)]
 
     let rom = caliptra_builder::rom_for_fw_integration_tests().unwrap();
     // When debug is locked JTAG is only accesisble in Manufacturing mode.
@@ -1,7 +1,6 @@
 // Licensed under the Apache-2.0 license
 mod common;
 mod fw_load;
-#[cfg(feature = "fpga_realtime")]
 mod jtag_locked;
 mod security_parameters;
 mod self_tests;